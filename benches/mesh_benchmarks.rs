@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use meshmeshmesh::mesh::Mesh;
+use meshmeshmesh::testmeshes::{bim_slab, noisy_sphere, scan_like_terrain};
+
+fn welding(c: &mut Criterion) {
+    let slab = bim_slab(10.0, 6.0, 0.3, 20);
+    c.bench_function("weld bim slab", |b| b.iter(|| black_box(&slab).get_with_welded_vertices(0.001)));
+}
+
+fn analysis(c: &mut Criterion) {
+    let sphere = noisy_sphere(4, 0.01, 1);
+    c.bench_function("face normals noisy sphere", |b| b.iter(|| black_box(&sphere).get_face_normal_vectors_unitized()));
+    c.bench_function("non-manifold edges noisy sphere", |b| b.iter(|| black_box(&sphere).get_non_manifold_edges()));
+}
+
+fn processing(c: &mut Criterion) {
+    let sphere = noisy_sphere(3, 0.05, 2);
+    c.bench_function("split by face angle noisy sphere", |b| b.iter(|| black_box(&sphere).split_by_face_angle(0.3, Some(0.001))));
+
+    let terrain = scan_like_terrain(100, 100, 5.0, 3);
+    c.bench_function("decimate scan-like terrain", |b| b.iter(|| black_box(&terrain).get_decimated(0.05)));
+    c.bench_function("heightmap scan-like terrain", |b| b.iter(|| {
+        let values: Vec<f64> = terrain.coordinates.chunks(3).map(|vertex| vertex[2]).collect();
+        Mesh::from_heightmap(black_box(&values), 100, 100, 1.0)
+    }));
+}
+
+criterion_group!(benches, welding, analysis, processing);
+criterion_main!(benches);
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 
 /// Represents a three-dimensional bounding box (AABB: axis-aligned bounding box).
 ///
@@ -14,6 +15,7 @@
 /// assert_eq!(result.min_z, 3.9);
 /// assert_eq!(result.max_z, 4.1);
 /// ```
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
 pub struct BoundingBox {
     /// Minimum x value.
     pub min_x: f64,
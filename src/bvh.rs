@@ -0,0 +1,217 @@
+use crate::bounding_box::BoundingBox;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::triangle::Triangle;
+
+/// The maximum number of faces kept in a single leaf of the [Bvh].
+const MAX_FACES_IN_LEAF: usize = 4;
+
+/// Represents a bounding volume hierarchy (AABB tree) over faces of a [Mesh].
+///
+/// Every node keeps the Bounding Box of all the faces below it, so queries can skip whole
+/// branches instead of checking every face. It makes ray, closest point, box and sphere queries
+/// logarithmic instead of linear in the number of faces.
+///
+/// It's a snapshot of the [Mesh]: after editing the Mesh a new one has to be created.
+/// [Mesh::get_cached_bvh] builds it lazily and keeps it until the cache is invalidated.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::bvh::Bvh;
+/// use meshmeshmesh::mesh::Mesh;
+/// use meshmeshmesh::point::Point;
+/// use meshmeshmesh::ray::Ray;
+/// use meshmeshmesh::vector::Vector;
+///
+/// let mesh = Mesh::new(
+///     vec![0.0, 0.0, 0.0,
+///          10.0, 0.0, 0.0,
+///          10.0, 10.0, 0.0,
+///          0.0, 10.0, 0.0,
+///          ],
+///     vec![0, 1, 2, // first face
+///          0, 2, 3, // second face
+///          ]
+/// );
+///
+/// let bvh = Bvh::from_mesh(&mesh);
+///
+/// let ray = Ray::new(Point::new(2.0, 8.0, 5.0), Vector::new(0.0, 0.0, -1.0));
+/// assert_eq!(bvh.get_first_ray_intersection(&ray), Some((1, Point::new(2.0, 8.0, 0.0))));
+/// assert_eq!(bvh.get_closest_point(&Point::new(12.0, 5.0, 0.0)), Some((0, Point::new(10.0, 5.0, 0.0))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bvh {
+    /// Nodes of the tree, the root is the first one.
+    pub(crate) nodes: Vec<BvhNode>,
+    /// Ids of faces ordered so that each leaf refers to a continuous range of them.
+    pub(crate) face_ids: Vec<usize>,
+    /// Faces of the [Mesh], in the original order.
+    pub(crate) triangles: Vec<Triangle>,
+}
+
+/// Represents a single node of the [Bvh].
+#[derive(Debug, Clone)]
+pub(crate) struct BvhNode {
+    /// Bounding Box of all the faces below this node, slightly enlarged to be safe from
+    /// rounding errors.
+    pub(crate) bounding_box: BoundingBox,
+    pub(crate) content: BvhNodeContent,
+}
+
+/// Represents the content of the [BvhNode].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BvhNodeContent {
+    /// Start and end of the range of `face_ids` of the [Bvh].
+    Leaf(usize, usize),
+    /// Ids of the left and the right child node.
+    Inner(usize, usize),
+}
+
+impl Bvh {
+    /// Creates a [Bvh] over all the faces of the [Mesh].
+    ///
+    /// Faces are split recursively in half along the longest axis of their centroids, until
+    /// there are at most 4 faces in a leaf.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bvh::Bvh;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = Bvh::from_mesh(&mesh);
+    ///
+    /// assert_eq!(actual.get_number_of_faces(), 2);
+    /// ```
+    pub fn from_mesh(mesh: &Mesh) -> Bvh {
+        let triangles = mesh.to_triangles();
+        let face_bounding_boxes: Vec<BoundingBox> = triangles.iter().map(Bvh::get_padded_bounding_box).collect();
+        let centroids: Vec<Point> = triangles.iter().map(|triangle| triangle.get_centroid()).collect();
+
+        let mut face_ids: Vec<usize> = (0..triangles.len()).collect();
+        let mut nodes = Vec::new();
+        if !face_ids.is_empty() {
+            Bvh::build_node(&mut nodes, &mut face_ids, 0, &face_bounding_boxes, &centroids);
+        }
+
+        Bvh { nodes, face_ids, triangles }
+    }
+
+    /// Adds the node for given `face_ids` (starting at `offset` in all the Bvh's face ids), and
+    /// all the nodes below it. Returns the id of the added node.
+    fn build_node(nodes: &mut Vec<BvhNode>, face_ids: &mut [usize], offset: usize, face_bounding_boxes: &[BoundingBox], centroids: &[Point]) -> usize {
+        let mut bounding_box = face_bounding_boxes[face_ids[0]];
+        for face_id in face_ids.iter().skip(1) {
+            bounding_box = Bvh::get_union(&bounding_box, &face_bounding_boxes[*face_id]);
+        }
+
+        let node_id = nodes.len();
+        nodes.push(BvhNode { bounding_box, content: BvhNodeContent::Leaf(offset, offset + face_ids.len()) });
+        if face_ids.len() <= MAX_FACES_IN_LEAF {
+            return node_id;
+        }
+
+        let mut min = [f64::INFINITY; 3]; // Splitting along the longest axis of centroids
+        let mut max = [f64::NEG_INFINITY; 3];
+        for face_id in face_ids.iter() {
+            for (axis, coordinate) in Bvh::get_coordinates(&centroids[*face_id]).iter().enumerate() {
+                min[axis] = min[axis].min(*coordinate);
+                max[axis] = max[axis].max(*coordinate);
+            }
+        }
+        let extents = [max[0] - min[0], max[1] - min[1], max[2] - min[2]];
+        let split_axis = (1..3).fold(0, |longest, axis| if extents[axis] > extents[longest] { axis } else { longest });
+
+        let middle = face_ids.len() / 2;
+        face_ids.select_nth_unstable_by(middle, |a, b| {
+            Bvh::get_coordinates(&centroids[*a])[split_axis].total_cmp(&Bvh::get_coordinates(&centroids[*b])[split_axis])
+        });
+        let (left_face_ids, right_face_ids) = face_ids.split_at_mut(middle);
+        let left = Bvh::build_node(nodes, left_face_ids, offset, face_bounding_boxes, centroids);
+        let right = Bvh::build_node(nodes, right_face_ids, offset + middle, face_bounding_boxes, centroids);
+        nodes[node_id].content = BvhNodeContent::Inner(left, right);
+
+        node_id
+    }
+
+    /// Gets the Bounding Box of the [Triangle], enlarged a bit, so rounding errors of queries
+    /// don't skip faces touching its sides.
+    fn get_padded_bounding_box(triangle: &Triangle) -> BoundingBox {
+        let points = [triangle.first_point, triangle.second_point, triangle.third_point];
+        let min_x = points.iter().map(|point| point.x).fold(f64::INFINITY, f64::min);
+        let max_x = points.iter().map(|point| point.x).fold(f64::NEG_INFINITY, f64::max);
+        let min_y = points.iter().map(|point| point.y).fold(f64::INFINITY, f64::min);
+        let max_y = points.iter().map(|point| point.y).fold(f64::NEG_INFINITY, f64::max);
+        let min_z = points.iter().map(|point| point.z).fold(f64::INFINITY, f64::min);
+        let max_z = points.iter().map(|point| point.z).fold(f64::NEG_INFINITY, f64::max);
+
+        let largest_coordinate = [min_x, max_x, min_y, max_y, min_z, max_z].iter().fold(1.0, |a: f64, b| a.max(b.abs()));
+        let padding = largest_coordinate * 1e-9;
+
+        BoundingBox { min_x: min_x - padding, max_x: max_x + padding, min_y: min_y - padding, max_y: max_y + padding, min_z: min_z - padding, max_z: max_z + padding }
+    }
+
+    /// Gets the smallest Bounding Box containing both given ones.
+    fn get_union(a: &BoundingBox, b: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min_x: a.min_x.min(b.min_x), max_x: a.max_x.max(b.max_x),
+            min_y: a.min_y.min(b.min_y), max_y: a.max_y.max(b.max_y),
+            min_z: a.min_z.min(b.min_z), max_z: a.max_z.max(b.max_z),
+        }
+    }
+
+    fn get_coordinates(point: &Point) -> [f64; 3] {
+        [point.x, point.y, point.z]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_mesh_every_face_in_one_leaf() {
+        let mut coordinates = Vec::new();
+        let mut indices = Vec::new();
+        for i in 0..30 {
+            let x = i as f64;
+            coordinates.extend_from_slice(&[x, 0.0, 0.0, x + 1.0, 0.0, 0.0, x, 1.0, (i % 3) as f64]);
+            indices.extend_from_slice(&[i * 3, i * 3 + 1, i * 3 + 2]);
+        }
+        let mesh = Mesh::new(coordinates, indices);
+
+        let actual = Bvh::from_mesh(&mesh);
+
+        let mut face_ids_in_leaves = Vec::new();
+        for node in &actual.nodes {
+            if let BvhNodeContent::Leaf(start, end) = node.content {
+                assert!(end - start <= MAX_FACES_IN_LEAF);
+                for face_id in &actual.face_ids[start..end] {
+                    let triangle = &actual.triangles[*face_id];
+                    assert!(triangle.first_point.x >= node.bounding_box.min_x && triangle.second_point.x <= node.bounding_box.max_x);
+                    face_ids_in_leaves.push(*face_id);
+                }
+            }
+        }
+        face_ids_in_leaves.sort();
+        assert_eq!(face_ids_in_leaves, (0..30).collect::<Vec<usize>>());
+        assert_eq!(actual.nodes[0].bounding_box.min_x, -1e-9);
+    }
+
+    #[test]
+    fn test_from_mesh_empty() {
+        let mesh = Mesh::new(vec![], vec![]);
+
+        let actual = Bvh::from_mesh(&mesh);
+
+        assert!(actual.nodes.is_empty());
+        assert!(actual.face_ids.is_empty());
+    }
+}
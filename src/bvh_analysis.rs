@@ -0,0 +1,511 @@
+use crate::barycentric_coordinates::BarycentricCoordinates;
+use crate::bounding_box::BoundingBox;
+use crate::bvh::{Bvh, BvhNodeContent};
+use crate::point::Point;
+use crate::ray::Ray;
+use crate::ray_hit::RayHit;
+use crate::triangle::Triangle;
+
+impl Bvh {
+    /// Gets the number of faces in the [Bvh].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bvh::Bvh;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// assert_eq!(Bvh::from_mesh(&mesh).get_number_of_faces(), 1);
+    /// ```
+    pub fn get_number_of_faces(&self) -> usize {
+        self.triangles.len()
+    }
+
+    /// Gets all the intersections of the [Ray] with faces, as pairs of the face id and
+    /// the intersection [Point], sorted by face ids.
+    ///
+    /// It gives the same intersections as [Ray::get_intersections_with_mesh], but checks only
+    /// faces which Bounding Boxes are hit by the Ray.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bvh::Bvh;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::ray::Ray;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0,
+    ///          0.0, 0.0, 2.0, 4.0, 0.0, 2.0, 0.0, 4.0, 2.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    /// let bvh = Bvh::from_mesh(&mesh);
+    ///
+    /// let actual = bvh.get_ray_intersections(&Ray::new(Point::new(1.0, 1.0, 5.0), Vector::new(0.0, 0.0, -1.0)));
+    ///
+    /// assert_eq!(actual, vec![(0, Point::new(1.0, 1.0, 0.0)), (1, Point::new(1.0, 1.0, 2.0))]);
+    /// ```
+    pub fn get_ray_intersections(&self, ray: &Ray) -> Vec<(usize, Point)> {
+        let mut intersections = Vec::new();
+        self.visit_faces(|bounding_box| Bvh::does_ray_intersect_box(ray, bounding_box), |face_id, triangle| {
+            if let Some(point) = ray.get_intersection_with_triangle(triangle) {
+                intersections.push((face_id, point));
+            }
+        });
+        intersections.sort_by_key(|(face_id, _)| *face_id);
+
+        intersections
+    }
+
+    /// Gets all the [RayHit]s of the [Ray] with faces, sorted by distance from the Ray's origin
+    /// (and by face ids for equal distances).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bvh::Bvh;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::ray::Ray;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0,
+    ///          0.0, 0.0, 2.0, 4.0, 0.0, 2.0, 0.0, 4.0, 2.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    /// let bvh = Bvh::from_mesh(&mesh);
+    ///
+    /// let actual = bvh.get_ray_hits(&Ray::new(Point::new(1.0, 1.0, 5.0), Vector::new(0.0, 0.0, -1.0)));
+    ///
+    /// assert_eq!(actual.iter().map(|hit| hit.face_id).collect::<Vec<usize>>(), vec![1, 0]);
+    /// assert_eq!(actual.iter().map(|hit| hit.distance).collect::<Vec<f64>>(), vec![3.0, 5.0]);
+    /// ```
+    pub fn get_ray_hits(&self, ray: &Ray) -> Vec<RayHit> {
+        let mut hits = Vec::new();
+        self.visit_faces(|bounding_box| Bvh::does_ray_intersect_box(ray, bounding_box), |face_id, triangle| {
+            if let Some((distance, u, v)) = ray.get_intersection_parameters_with_triangle(triangle) {
+                hits.push(RayHit {
+                    distance,
+                    point: ray.get_point_at(distance),
+                    face_id,
+                    barycentric: BarycentricCoordinates::new(1.0 - u - v, u, v),
+                    normal: triangle.get_normal_vector_unitized(),
+                });
+            }
+        });
+        hits.sort_by(|a, b| a.distance.total_cmp(&b.distance).then(a.face_id.cmp(&b.face_id)));
+
+        hits
+    }
+
+    /// Gets the intersection of the [Ray] with faces which is the closest to the Ray's origin,
+    /// as the pair of the face id and the intersection [Point].
+    ///
+    /// `None` is returned if the Ray doesn't hit any face.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bvh::Bvh;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::ray::Ray;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0,
+    ///          0.0, 0.0, 2.0, 4.0, 0.0, 2.0, 0.0, 4.0, 2.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    /// let bvh = Bvh::from_mesh(&mesh);
+    ///
+    /// let actual = bvh.get_first_ray_intersection(&Ray::new(Point::new(1.0, 1.0, 5.0), Vector::new(0.0, 0.0, -1.0)));
+    ///
+    /// assert_eq!(actual, Some((1, Point::new(1.0, 1.0, 2.0))));
+    /// ```
+    pub fn get_first_ray_intersection(&self, ray: &Ray) -> Option<(usize, Point)> {
+        self.get_ray_intersections(ray).into_iter()
+            .min_by(|(_, a), (_, b)| ray.origin.get_distance_to_point(a).total_cmp(&ray.origin.get_distance_to_point(b)))
+    }
+
+    /// Checks if the [Ray] hits any face.
+    ///
+    /// It gives the same result as [Ray::does_intersect_with_mesh], but stops at the first hit
+    /// and checks only faces which Bounding Boxes are hit by the Ray.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bvh::Bvh;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::ray::Ray;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0], vec![0, 1, 2]);
+    /// let bvh = Bvh::from_mesh(&mesh);
+    ///
+    /// assert!(bvh.does_intersect_with_ray(&Ray::new(Point::new(1.0, 1.0, 5.0), Vector::new(0.0, 0.0, -1.0))));
+    /// assert!(!bvh.does_intersect_with_ray(&Ray::new(Point::new(1.0, 1.0, 5.0), Vector::new(0.0, 0.0, 1.0))));
+    /// ```
+    pub fn does_intersect_with_ray(&self, ray: &Ray) -> bool {
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_id) = stack.pop() {
+            let node = &self.nodes[node_id];
+            if !Bvh::does_ray_intersect_box(ray, &node.bounding_box) {
+                continue;
+            }
+            match node.content {
+                BvhNodeContent::Leaf(start, end) => {
+                    if self.face_ids[start..end].iter().any(|face_id| ray.get_intersection_with_triangle(&self.triangles[*face_id]).is_some()) {
+                        return true;
+                    }
+                },
+                BvhNodeContent::Inner(left, right) => {
+                    stack.push(left);
+                    stack.push(right);
+                },
+            }
+        }
+
+        false
+    }
+
+    /// Gets the closest [Point] on faces to the given one, as the pair of the face id and
+    /// the closest Point. If many faces are equally close, the one with the smallest id is taken.
+    ///
+    /// `None` is returned if there are no faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bvh::Bvh;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0,
+    ///          0.0, 0.0, 2.0, 4.0, 0.0, 2.0, 0.0, 4.0, 2.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    /// let bvh = Bvh::from_mesh(&mesh);
+    ///
+    /// assert_eq!(bvh.get_closest_point(&Point::new(1.0, 1.0, 1.5)), Some((1, Point::new(1.0, 1.0, 2.0))));
+    /// ```
+    pub fn get_closest_point(&self, point: &Point) -> Option<(usize, Point)> {
+        let mut closest: Option<(usize, Point)> = None;
+        let mut closest_distance = f64::INFINITY;
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_id) = stack.pop() {
+            let node = &self.nodes[node_id];
+            if Bvh::get_distance_to_box(point, &node.bounding_box) > closest_distance {
+                continue;
+            }
+            match node.content {
+                BvhNodeContent::Leaf(start, end) => {
+                    for face_id in &self.face_ids[start..end] {
+                        let current_point = self.triangles[*face_id].get_closest_point(point);
+                        let current_distance = current_point.get_distance_to_point(point);
+                        let is_closer = match closest {
+                            None => true,
+                            Some((closest_face_id, _)) => current_distance < closest_distance || (current_distance == closest_distance && *face_id < closest_face_id),
+                        };
+                        if is_closer {
+                            closest = Some((*face_id, current_point));
+                            closest_distance = current_distance;
+                        }
+                    }
+                },
+                BvhNodeContent::Inner(left, right) => { // Visiting the closer child first, so more branches get skipped
+                    if Bvh::get_distance_to_box(point, &self.nodes[left].bounding_box) < Bvh::get_distance_to_box(point, &self.nodes[right].bounding_box) {
+                        stack.push(right);
+                        stack.push(left);
+                    }
+                    else {
+                        stack.push(left);
+                        stack.push(right);
+                    }
+                },
+            }
+        }
+
+        closest
+    }
+
+    /// Gets ids of faces which Bounding Boxes overlap (or touch) the given [BoundingBox],
+    /// sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::bvh::Bvh;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0,
+    ///          0.0, 0.0, 2.0, 4.0, 0.0, 2.0, 0.0, 4.0, 2.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    /// let bvh = Bvh::from_mesh(&mesh);
+    ///
+    /// assert_eq!(bvh.get_face_ids_intersecting_box(&BoundingBox::new(1.0, 2.0, 1.0, 2.0, 1.5, 3.0)), vec![1]);
+    /// assert_eq!(bvh.get_face_ids_intersecting_box(&BoundingBox::new(1.0, 2.0, 1.0, 2.0, -1.0, 3.0)), vec![0, 1]);
+    /// ```
+    pub fn get_face_ids_intersecting_box(&self, bounding_box: &BoundingBox) -> Vec<usize> {
+        let mut face_ids = Vec::new();
+        self.visit_faces(|node_box| Bvh::do_boxes_overlap(node_box, bounding_box), |face_id, triangle| {
+            if Bvh::do_boxes_overlap(&Bvh::get_bounding_box_of_triangle(triangle), bounding_box) {
+                face_ids.push(face_id);
+            }
+        });
+        face_ids.sort();
+
+        face_ids
+    }
+
+    /// Gets ids of faces which have any point within the sphere (inside or on its surface),
+    /// sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bvh::Bvh;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0,
+    ///          0.0, 0.0, 2.0, 4.0, 0.0, 2.0, 0.0, 4.0, 2.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    /// let bvh = Bvh::from_mesh(&mesh);
+    ///
+    /// assert_eq!(bvh.get_face_ids_within_sphere(&Point::new(1.0, 1.0, 1.5), 1.0), vec![1]);
+    /// assert_eq!(bvh.get_face_ids_within_sphere(&Point::new(1.0, 1.0, 1.5), 1.5), vec![0, 1]);
+    /// ```
+    pub fn get_face_ids_within_sphere(&self, center: &Point, radius: f64) -> Vec<usize> {
+        let mut face_ids = Vec::new();
+        self.visit_faces(|node_box| Bvh::get_distance_to_box(center, node_box) <= radius, |face_id, triangle| {
+            if triangle.get_closest_point(center).get_distance_to_point(center) <= radius {
+                face_ids.push(face_id);
+            }
+        });
+        face_ids.sort();
+
+        face_ids
+    }
+
+    /// Calls `on_face` for every face in leaves reached through nodes accepted by `accept_box`.
+    fn visit_faces(&self, accept_box: impl Fn(&BoundingBox) -> bool, mut on_face: impl FnMut(usize, &Triangle)) {
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_id) = stack.pop() {
+            let node = &self.nodes[node_id];
+            if !accept_box(&node.bounding_box) {
+                continue;
+            }
+            match node.content {
+                BvhNodeContent::Leaf(start, end) => {
+                    for face_id in &self.face_ids[start..end] {
+                        on_face(*face_id, &self.triangles[*face_id]);
+                    }
+                },
+                BvhNodeContent::Inner(left, right) => {
+                    stack.push(left);
+                    stack.push(right);
+                },
+            }
+        }
+    }
+
+    /// Checks if the [Ray] hits the [BoundingBox] (slab method). Origin inside the box counts as
+    /// a hit.
+    fn does_ray_intersect_box(ray: &Ray, bounding_box: &BoundingBox) -> bool {
+        let slabs = [
+            (ray.origin.x, ray.direction.x, bounding_box.min_x, bounding_box.max_x),
+            (ray.origin.y, ray.direction.y, bounding_box.min_y, bounding_box.max_y),
+            (ray.origin.z, ray.direction.z, bounding_box.min_z, bounding_box.max_z),
+        ];
+
+        let mut t_min: f64 = 0.0;
+        let mut t_max = f64::INFINITY;
+        for (origin, direction, min, max) in slabs {
+            if direction == 0.0 {
+                if origin < min || origin > max {
+                    return false;
+                }
+                continue;
+            }
+            let t_first = (min - origin) / direction;
+            let t_second = (max - origin) / direction;
+            t_min = t_min.max(t_first.min(t_second));
+            t_max = t_max.min(t_first.max(t_second));
+            if t_min > t_max {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Gets the distance from the [Point] to the [BoundingBox], `0.0` if it's inside.
+    fn get_distance_to_box(point: &Point, bounding_box: &BoundingBox) -> f64 {
+        let dx = (bounding_box.min_x - point.x).max(point.x - bounding_box.max_x).max(0.0);
+        let dy = (bounding_box.min_y - point.y).max(point.y - bounding_box.max_y).max(0.0);
+        let dz = (bounding_box.min_z - point.z).max(point.z - bounding_box.max_z).max(0.0);
+
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    fn do_boxes_overlap(a: &BoundingBox, b: &BoundingBox) -> bool {
+        a.min_x <= b.max_x && b.min_x <= a.max_x &&
+            a.min_y <= b.max_y && b.min_y <= a.max_y &&
+            a.min_z <= b.max_z && b.min_z <= a.max_z
+    }
+
+    pub(crate) fn get_bounding_box_of_triangle(triangle: &Triangle) -> BoundingBox {
+        let points = [triangle.first_point, triangle.second_point, triangle.third_point];
+
+        BoundingBox {
+            min_x: points.iter().map(|point| point.x).fold(f64::INFINITY, f64::min),
+            max_x: points.iter().map(|point| point.x).fold(f64::NEG_INFINITY, f64::max),
+            min_y: points.iter().map(|point| point.y).fold(f64::INFINITY, f64::min),
+            max_y: points.iter().map(|point| point.y).fold(f64::NEG_INFINITY, f64::max),
+            min_z: points.iter().map(|point| point.z).fold(f64::INFINITY, f64::min),
+            max_z: points.iter().map(|point| point.z).fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mesh::Mesh;
+    use crate::vector::Vector;
+    use super::*;
+
+    fn get_wavy_grid(size: usize) -> Mesh {
+        let mut coordinates = Vec::new();
+        for i in 0..=size {
+            for j in 0..=size {
+                coordinates.extend_from_slice(&[i as f64, j as f64, (i as f64 * 0.7).sin() + (j as f64 * 0.3).cos()]);
+            }
+        }
+        let mut indices = Vec::new();
+        for i in 0..size {
+            for j in 0..size {
+                let a = i * (size + 1) + j;
+                let b = (i + 1) * (size + 1) + j;
+                indices.extend_from_slice(&[a, b, b + 1, a, b + 1, a + 1]);
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+
+    #[test]
+    fn test_queries_same_as_brute_force() {
+        let mesh = get_wavy_grid(12);
+        let bvh = Bvh::from_mesh(&mesh);
+        let triangles = mesh.to_triangles();
+
+        for (x, y) in [(0.3, 0.4), (5.5, 7.25), (11.9, 0.1), (6.0, 6.0), (15.0, -3.0)] {
+            let ray = Ray::new(Point::new(x, y, 10.0), Vector::new(0.1, -0.05, -1.0));
+            let expected: Vec<(usize, Point)> = triangles.iter().enumerate()
+                .filter_map(|(face_id, triangle)| ray.get_intersection_with_triangle(triangle).map(|point| (face_id, point)))
+                .collect();
+            assert_eq!(bvh.get_ray_intersections(&ray), expected);
+            assert_eq!(bvh.does_intersect_with_ray(&ray), !expected.is_empty());
+
+            let point = Point::new(x, y, 0.5);
+            let expected_distance = triangles.iter().map(|triangle| triangle.get_closest_point(&point).get_distance_to_point(&point)).fold(f64::INFINITY, f64::min);
+            let (_, actual_point) = bvh.get_closest_point(&point).unwrap();
+            assert_eq!(actual_point.get_distance_to_point(&point), expected_distance);
+
+            let expected_ids: Vec<usize> = (0..triangles.len())
+                .filter(|face_id| triangles[*face_id].get_closest_point(&point).get_distance_to_point(&point) <= 1.5)
+                .collect();
+            assert_eq!(bvh.get_face_ids_within_sphere(&point, 1.5), expected_ids);
+        }
+    }
+
+    #[test]
+    fn test_get_ray_hits_folded_grid() {
+        let mesh = get_wavy_grid(12);
+        let bvh = Bvh::from_mesh(&mesh);
+        let triangles = mesh.to_triangles();
+        let ray = Ray::new(Point::new(-1.0, 6.3, 0.3), Vector::new(1.0, 0.02, 0.0));
+
+        let actual = bvh.get_ray_hits(&ray);
+
+        assert!(actual.len() > 1);
+        let mut actual_intersections: Vec<(usize, Point)> = actual.iter().map(|hit| (hit.face_id, hit.point)).collect();
+        actual_intersections.sort_by_key(|(face_id, _)| *face_id);
+        assert_eq!(actual_intersections, bvh.get_ray_intersections(&ray));
+        for (hit, next_hit) in actual.iter().zip(actual.iter().skip(1)) {
+            assert!(hit.distance <= next_hit.distance);
+        }
+        for hit in &actual {
+            let triangle = &triangles[hit.face_id];
+            let barycentric = &hit.barycentric;
+            let point_from_barycentric = Point::new(
+                triangle.first_point.x * barycentric.u + triangle.second_point.x * barycentric.v + triangle.third_point.x * barycentric.w,
+                triangle.first_point.y * barycentric.u + triangle.second_point.y * barycentric.v + triangle.third_point.y * barycentric.w,
+                triangle.first_point.z * barycentric.u + triangle.second_point.z * barycentric.v + triangle.third_point.z * barycentric.w,
+            );
+            assert!(point_from_barycentric.get_distance_to_point(&hit.point) < 1e-9);
+            assert!((hit.point.get_distance_to_point(&ray.origin) - hit.distance).abs() < 1e-9);
+            assert_eq!(hit.normal, triangle.get_normal_vector_unitized());
+        }
+    }
+
+    #[test]
+    fn test_get_face_ids_intersecting_box_same_as_brute_force() {
+        let mesh = get_wavy_grid(10);
+        let bvh = Bvh::from_mesh(&mesh);
+        let bounding_box = BoundingBox::new(2.5, 4.0, 3.0, 7.5, 0.0, 0.5);
+
+        let expected: Vec<usize> = mesh.to_triangles().iter().enumerate()
+            .filter(|(_, triangle)| Bvh::do_boxes_overlap(&Bvh::get_bounding_box_of_triangle(triangle), &bounding_box))
+            .map(|(face_id, _)| face_id)
+            .collect();
+
+        assert!(!expected.is_empty());
+        assert_eq!(bvh.get_face_ids_intersecting_box(&bounding_box), expected);
+    }
+
+    #[test]
+    fn test_queries_empty() {
+        let bvh = Bvh::from_mesh(&Mesh::new(vec![], vec![]));
+        let ray = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+
+        assert!(bvh.get_ray_intersections(&ray).is_empty());
+        assert!(!bvh.does_intersect_with_ray(&ray));
+        assert_eq!(bvh.get_closest_point(&Point::new(0.0, 0.0, 0.0)), None);
+        assert!(bvh.get_face_ids_within_sphere(&Point::new(0.0, 0.0, 0.0), 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_does_ray_intersect_box_axis_parallel() {
+        let bounding_box = BoundingBox::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0);
+
+        assert!(Bvh::does_ray_intersect_box(&Ray::new(Point::new(0.5, 0.5, 5.0), Vector::new(0.0, 0.0, -1.0)), &bounding_box));
+        assert!(Bvh::does_ray_intersect_box(&Ray::new(Point::new(1.0, 0.5, 5.0), Vector::new(0.0, 0.0, -1.0)), &bounding_box));
+        assert!(!Bvh::does_ray_intersect_box(&Ray::new(Point::new(1.5, 0.5, 5.0), Vector::new(0.0, 0.0, -1.0)), &bounding_box));
+        assert!(!Bvh::does_ray_intersect_box(&Ray::new(Point::new(0.5, 0.5, 5.0), Vector::new(0.0, 0.0, 1.0)), &bounding_box));
+        assert!(Bvh::does_ray_intersect_box(&Ray::new(Point::new(0.5, 0.5, 0.5), Vector::new(1.0, 1.0, 1.0)), &bounding_box));
+    }
+}
@@ -0,0 +1,43 @@
+/// Describes what is done by [Mesh::get_cleaned](crate::mesh::Mesh::get_cleaned) besides
+/// removing duplicate faces, zero-area faces and unused vertices.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::cleanup_options::CleanupOptions;
+///
+/// let options = CleanupOptions::new(1e-9, Some(0.001), true);
+///
+/// assert_eq!(options.zero_area_tolerance, 1e-9);
+/// assert_eq!(options.weld_tolerance, Some(0.001));
+/// assert!(options.fix_winding);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CleanupOptions {
+    /// Faces with area smaller or equal to this value are removed as zero-area faces.
+    pub zero_area_tolerance: f64,
+    /// If set, vertices are welded with this tolerance before anything else, so faces collapsed
+    /// by welding are removed too.
+    pub weld_tolerance: Option<f64>,
+    /// If `true`, faces are flipped so the winding is consistent with their neighbours.
+    pub fix_winding: bool,
+}
+
+impl CleanupOptions {
+    /// Creates new [CleanupOptions].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::cleanup_options::CleanupOptions;
+    ///
+    /// let options = CleanupOptions::new(0.0, None, false);
+    ///
+    /// assert_eq!(options.zero_area_tolerance, 0.0);
+    /// assert_eq!(options.weld_tolerance, None);
+    /// assert!(!options.fix_winding);
+    /// ```
+    pub fn new(zero_area_tolerance: f64, weld_tolerance: Option<f64>, fix_winding: bool) -> CleanupOptions {
+        CleanupOptions { zero_area_tolerance, weld_tolerance, fix_winding }
+    }
+}
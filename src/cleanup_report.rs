@@ -0,0 +1,61 @@
+/// Describes what was changed by [Mesh::get_cleaned](crate::mesh::Mesh::get_cleaned).
+///
+/// Ids of removed faces refer to faces of the input [Mesh](crate::mesh::Mesh), ids of flipped
+/// faces refer to faces of the cleaned one.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::cleanup_report::CleanupReport;
+///
+/// let report = CleanupReport {
+///     number_of_welded_vertices: 0,
+///     zero_area_face_ids: vec![],
+///     duplicate_face_ids: vec![],
+///     number_of_removed_unused_vertices: 0,
+///     flipped_face_ids: vec![],
+/// };
+///
+/// assert!(report.is_unchanged());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CleanupReport {
+    /// The number of vertices removed, because they were welded into other vertices.
+    pub number_of_welded_vertices: usize,
+    /// Ids of removed faces with (nearly) zero area, including faces collapsed by welding.
+    pub zero_area_face_ids: Vec<usize>,
+    /// Ids of removed faces using the same vertices as one of the earlier faces, regardless of
+    /// their winding.
+    pub duplicate_face_ids: Vec<usize>,
+    /// The number of removed vertices not used by any of the kept faces.
+    pub number_of_removed_unused_vertices: usize,
+    /// Ids of faces flipped to make the winding consistent.
+    pub flipped_face_ids: Vec<usize>,
+}
+
+impl CleanupReport {
+    /// Checks if the cleanup didn't change anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::cleanup_report::CleanupReport;
+    ///
+    /// let report = CleanupReport {
+    ///     number_of_welded_vertices: 0,
+    ///     zero_area_face_ids: vec![],
+    ///     duplicate_face_ids: vec![3],
+    ///     number_of_removed_unused_vertices: 0,
+    ///     flipped_face_ids: vec![],
+    /// };
+    ///
+    /// assert!(!report.is_unchanged());
+    /// ```
+    pub fn is_unchanged(&self) -> bool {
+        self.number_of_welded_vertices == 0 &&
+            self.zero_area_face_ids.is_empty() &&
+            self.duplicate_face_ids.is_empty() &&
+            self.number_of_removed_unused_vertices == 0 &&
+            self.flipped_face_ids.is_empty()
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Represents a color using red, green, blue, and alpha (transparency) values.
-#[derive(Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Color {
     /// The red component of the color.
     pub r: i32,
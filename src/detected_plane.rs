@@ -0,0 +1,36 @@
+use crate::plane::Plane;
+
+/// Represents a near-planar region of a [Mesh](crate::mesh::Mesh) found by
+/// [Mesh::detect_planes](crate::mesh::Mesh::detect_planes).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::detected_plane::DetectedPlane;
+/// use meshmeshmesh::plane::Plane;
+/// use meshmeshmesh::point::Point;
+/// use meshmeshmesh::vector::Vector;
+///
+/// let result = DetectedPlane::new(Plane::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0)), vec![0, 1]);
+///
+/// assert_eq!(result.face_ids, vec![0, 1]);
+/// assert_eq!(result.plane.normal, Vector::new(0.0, 0.0, 1.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DetectedPlane {
+    /// The [Plane] fitted to all the vertices of the region.
+    pub plane: Plane,
+    /// Sorted ids of the faces belonging to the region.
+    pub face_ids: Vec<usize>,
+}
+
+impl PartialEq for DetectedPlane {
+    fn eq(&self, other: &Self) -> bool {
+        self.plane == other.plane && self.face_ids == other.face_ids
+    }
+}
+
+impl DetectedPlane {
+    /// Creates a new [DetectedPlane].
+    pub fn new(plane: Plane, face_ids: Vec<usize>) -> DetectedPlane { DetectedPlane { plane, face_ids } }
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use crate::edge::Edge;
+use crate::mesh::Mesh;
+
+/// Represents a map of undirected [Edge]s of a [Mesh] to the faces using them.
+///
+/// Edges are undirected here: edge 0 - 1 is same as 1 - 0. Every edge is kept with the smaller
+/// vertex index as `start`, so all the returned [Edge]s have `start <= end`.
+///
+/// It's the same information that [Mesh::get_edges_with_more_than_2_neighbours] and
+/// [Mesh::get_non_manifold_edges] are based on, exposed so it can be used to build other
+/// topology algorithms.
+///
+/// To check which faces share an edge it looks for same indexes of same vertices, that's why it's
+/// good to do welding of vertices before.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::edge::Edge;
+/// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+/// use meshmeshmesh::mesh::Mesh;
+///
+/// let mesh = Mesh::new(
+///     vec![0.0, 0.0, 0.0,
+///          1.0, 0.0, 0.0,
+///          1.0, 1.0, 0.0,
+///          0.0, 1.0, 0.0,
+///          ],
+///     vec![0, 1, 2, // first face
+///          0, 2, 3, // second face
+///          ]
+/// );
+///
+/// let adjacency = EdgeAdjacency::from_mesh(&mesh);
+///
+/// assert_eq!(adjacency.get_faces_of_edge(&Edge::new(2, 0)), &[0, 1]);
+/// assert_eq!(adjacency.get_boundary_edges().len(), 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EdgeAdjacency {
+    /// Ids of faces for every undirected edge, in ascending order.
+    pub(crate) edge_faces: HashMap<Edge, Vec<usize>>,
+    /// Edges of every face, in the order of face's winding.
+    pub(crate) face_edges: Vec<[Edge; 3]>,
+}
+
+impl PartialEq for EdgeAdjacency {
+    fn eq(&self, other: &Self) -> bool {
+        self.edge_faces.eq(&other.edge_faces) && self.face_edges.eq(&other.face_edges)
+    }
+}
+
+impl EdgeAdjacency {
+    /// Creates an [EdgeAdjacency] from [Mesh].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          2.5, 5.0, 0.0,
+    ///          5.0, 0.0, 0.0,
+    ///          7.5, 5.0, 0.0,
+    ///          ],
+    ///     vec![0, 2, 1, // first face
+    ///          1, 2, 3, // second face
+    ///          ]
+    /// );
+    ///
+    /// let actual = EdgeAdjacency::from_mesh(&mesh);
+    ///
+    /// assert_eq!(actual.get_number_of_edges(), 5);
+    /// assert_eq!(actual.get_faces_of_edge(&Edge::new(1, 2)), &[0, 1]);
+    /// assert_eq!(actual.get_faces_of_edge(&Edge::new(0, 2)), &[0]);
+    /// ```
+    pub fn from_mesh(mesh: &Mesh) -> EdgeAdjacency {
+        let number_of_faces = mesh.get_number_of_faces();
+        let mut edge_faces: HashMap<Edge, Vec<usize>> = HashMap::new();
+        let mut face_edges: Vec<[Edge; 3]> = Vec::with_capacity(number_of_faces);
+
+        for i in 0..number_of_faces {
+            let first = mesh.indices[i * 3];
+            let second = mesh.indices[i * 3 + 1];
+            let third = mesh.indices[i * 3 + 2];
+            let edges = [Edge::new(first, second), Edge::new(second, third), Edge::new(third, first)];
+
+            for edge in edges {
+                edge_faces.entry(EdgeAdjacency::get_undirected(&edge)).or_insert_with(Vec::new).push(i);
+            }
+            face_edges.push(edges);
+        }
+
+        EdgeAdjacency { edge_faces, face_edges }
+    }
+
+    /// Gets the undirected version of given [Edge], which is the one with `start <= end`.
+    pub(crate) fn get_undirected(edge: &Edge) -> Edge {
+        if edge.start <= edge.end {
+            *edge
+        }
+        else {
+            edge.get_reversed()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_mesh() {
+        let mesh = Mesh::new(
+            vec![0.0, 0.0, 0.0,
+                 2.5, 5.0, 0.0,
+                 5.0, 0.0, 0.0,
+                 7.5, 5.0, 0.0,
+                 10.0, 0.0, 0.0,
+                 5.0, 10.0, 0.0,
+                 5.0, 5.0, 3.0,
+                 ],
+            vec![0, 2, 1, // first face
+                 1, 2, 3, // second face
+                 2, 4, 3, // third face
+                 1, 3, 5, // fourth face
+                 1, 3, 6, // fifth face
+                 ]
+        );
+
+        let actual = EdgeAdjacency::from_mesh(&mesh);
+
+        let expected_edge_faces = HashMap::from([
+            (Edge::new(0, 2), vec![0]),
+            (Edge::new(1, 2), vec![0, 1]),
+            (Edge::new(0, 1), vec![0]),
+            (Edge::new(2, 3), vec![1, 2]),
+            (Edge::new(1, 3), vec![1, 3, 4]),
+            (Edge::new(2, 4), vec![2]),
+            (Edge::new(3, 4), vec![2]),
+            (Edge::new(3, 5), vec![3]),
+            (Edge::new(1, 5), vec![3]),
+            (Edge::new(3, 6), vec![4]),
+            (Edge::new(1, 6), vec![4]),
+        ]);
+
+        assert_eq!(actual.edge_faces, expected_edge_faces);
+        assert_eq!(actual.face_edges.len(), 5);
+        assert_eq!(actual.face_edges[1], [Edge::new(1, 2), Edge::new(2, 3), Edge::new(3, 1)]);
+    }
+
+    #[test]
+    fn test_from_mesh_empty() {
+        let mesh = Mesh::new(vec![], vec![]);
+
+        let actual = EdgeAdjacency::from_mesh(&mesh);
+
+        assert!(actual.edge_faces.is_empty());
+        assert!(actual.face_edges.is_empty());
+    }
+
+    #[test]
+    fn test_get_undirected() {
+        assert_eq!(EdgeAdjacency::get_undirected(&Edge::new(3, 1)), Edge::new(1, 3));
+        assert_eq!(EdgeAdjacency::get_undirected(&Edge::new(1, 3)), Edge::new(1, 3));
+        assert_eq!(EdgeAdjacency::get_undirected(&Edge::new(2, 2)), Edge::new(2, 2));
+    }
+}
@@ -0,0 +1,378 @@
+use crate::edge::Edge;
+use crate::edge_adjacency::EdgeAdjacency;
+
+impl EdgeAdjacency {
+    /// Gets the number of undirected edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = EdgeAdjacency::from_mesh(&mesh).get_number_of_edges();
+    ///
+    /// assert_eq!(actual, 5);
+    /// ```
+    pub fn get_number_of_edges(&self) -> usize {
+        self.edge_faces.len()
+    }
+
+    /// Gets the number of faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = EdgeAdjacency::from_mesh(&mesh).get_number_of_faces();
+    ///
+    /// assert_eq!(actual, 2);
+    /// ```
+    pub fn get_number_of_faces(&self) -> usize {
+        self.face_edges.len()
+    }
+
+    /// Gets all the undirected edges, sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = EdgeAdjacency::from_mesh(&mesh).get_edges();
+    ///
+    /// assert_eq!(actual, vec![Edge::new(0, 1), Edge::new(0, 2), Edge::new(0, 3), Edge::new(1, 2), Edge::new(2, 3)]);
+    /// ```
+    pub fn get_edges(&self) -> Vec<Edge> {
+        let mut edges: Vec<Edge> = self.edge_faces.keys().copied().collect();
+        edges.sort();
+
+        edges
+    }
+
+    /// Gets ids of faces which use given [Edge], in ascending order.
+    ///
+    /// The direction of given [Edge] doesn't matter. An empty slice is returned if there is
+    /// no such edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    /// let adjacency = EdgeAdjacency::from_mesh(&mesh);
+    ///
+    /// assert_eq!(adjacency.get_faces_of_edge(&Edge::new(0, 2)), &[0, 1]);
+    /// assert_eq!(adjacency.get_faces_of_edge(&Edge::new(2, 0)), &[0, 1]);
+    /// assert!(adjacency.get_faces_of_edge(&Edge::new(1, 3)).is_empty());
+    /// ```
+    pub fn get_faces_of_edge(&self, edge: &Edge) -> &[usize] {
+        match self.edge_faces.get(&EdgeAdjacency::get_undirected(edge)) {
+            Some(faces) => faces,
+            None => &[],
+        }
+    }
+
+    /// Gets 3 edges of the face with given id, in the order of face's winding.
+    ///
+    /// Unlike other methods these edges keep their direction, so they can be used to check the
+    /// orientation of faces. It panics if there is no face with such id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = EdgeAdjacency::from_mesh(&mesh).get_edges_of_face(1);
+    ///
+    /// assert_eq!(actual, [Edge::new(0, 2), Edge::new(2, 3), Edge::new(3, 0)]);
+    /// ```
+    pub fn get_edges_of_face(&self, face_id: usize) -> [Edge; 3] {
+        self.face_edges[face_id]
+    }
+
+    /// Gets ids of faces which share at least 1 edge with the face with given id, in ascending
+    /// order.
+    ///
+    /// It panics if there is no face with such id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          2.5, 5.0, 0.0,
+    ///          5.0, 0.0, 0.0,
+    ///          7.5, 5.0, 0.0,
+    ///          10.0, 0.0, 0.0,
+    ///          ],
+    ///     vec![0, 2, 1, // first face
+    ///          1, 2, 3, // second face
+    ///          2, 4, 3, // third face
+    ///          ]
+    /// );
+    ///
+    /// let actual = EdgeAdjacency::from_mesh(&mesh).get_neighbour_faces_of_face(1);
+    ///
+    /// assert_eq!(actual, vec![0, 2]);
+    /// ```
+    pub fn get_neighbour_faces_of_face(&self, face_id: usize) -> Vec<usize> {
+        let mut neighbours: Vec<usize> = Vec::new();
+        for edge in self.face_edges[face_id] {
+            for &other_face_id in self.get_faces_of_edge(&edge) {
+                if other_face_id != face_id {
+                    neighbours.push(other_face_id);
+                }
+            }
+        }
+        neighbours.sort();
+        neighbours.dedup();
+
+        neighbours
+    }
+
+    /// Gets undirected edges used by exactly 1 face, sorted.
+    ///
+    /// These are the edges of the holes and the outer border of open meshes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = EdgeAdjacency::from_mesh(&mesh).get_boundary_edges();
+    ///
+    /// assert_eq!(actual, vec![Edge::new(0, 1), Edge::new(0, 3), Edge::new(1, 2), Edge::new(2, 3)]);
+    /// ```
+    pub fn get_boundary_edges(&self) -> Vec<Edge> {
+        self.get_edges_by_number_of_faces(|number_of_faces| number_of_faces == 1)
+    }
+
+    /// Gets undirected edges used by more than 2 faces, sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.5, 0.5, 1.0],
+    ///     vec![0, 1, 2, 0, 2, 3, 0, 4, 2]
+    /// );
+    ///
+    /// let actual = EdgeAdjacency::from_mesh(&mesh).get_edges_with_more_than_2_faces();
+    ///
+    /// assert_eq!(actual, vec![Edge::new(0, 2)]);
+    /// ```
+    pub fn get_edges_with_more_than_2_faces(&self) -> Vec<Edge> {
+        self.get_edges_by_number_of_faces(|number_of_faces| number_of_faces > 2)
+    }
+
+    /// Checks if every edge is used by exactly 2 faces, so there are no holes and no
+    /// non-manifold edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    ///
+    /// assert!(EdgeAdjacency::from_mesh(&tetrahedron).is_closed());
+    /// ```
+    pub fn is_closed(&self) -> bool {
+        self.edge_faces.values().all(|faces| faces.len() == 2)
+    }
+
+    /// Gets undirected edges shared by exactly 2 faces, which use them in the same direction,
+    /// sorted.
+    ///
+    /// Neighbouring faces with consistent orientation use their shared edge in opposite
+    /// directions, so these edges show where the faces have inconsistent winding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::edge_adjacency::EdgeAdjacency;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 3, 2]
+    /// );
+    ///
+    /// let actual = EdgeAdjacency::from_mesh(&mesh).get_inconsistent_winding_edges();
+    ///
+    /// assert_eq!(actual, vec![Edge::new(0, 2)]);
+    /// ```
+    pub fn get_inconsistent_winding_edges(&self) -> Vec<Edge> {
+        let mut edges: Vec<Edge> = Vec::new();
+        for (edge, faces) in &self.edge_faces {
+            if faces.len() != 2 || faces[0] == faces[1] {
+                continue;
+            }
+            let is_forward = |face_id: usize| self.face_edges[face_id].contains(edge);
+            if is_forward(faces[0]) == is_forward(faces[1]) {
+                edges.push(*edge);
+            }
+        }
+        edges.sort();
+
+        edges
+    }
+
+    fn get_edges_by_number_of_faces(&self, predicate: impl Fn(usize) -> bool) -> Vec<Edge> {
+        let mut edges: Vec<Edge> = self.edge_faces.iter()
+            .filter(|(_, faces)| predicate(faces.len()))
+            .map(|(edge, _)| *edge)
+            .collect();
+        edges.sort();
+
+        edges
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mesh::Mesh;
+    use super::*;
+
+    fn get_open_mesh() -> Mesh {
+        Mesh::new(
+            vec![0.0, 0.0, 0.0,
+                 2.5, 5.0, 0.0,
+                 5.0, 0.0, 0.0,
+                 7.5, 5.0, 0.0,
+                 10.0, 0.0, 0.0,
+                 5.0, 10.0, 0.0,
+                 5.0, 5.0, 3.0,
+                 ],
+            vec![0, 2, 1, // first face
+                 1, 2, 3, // second face
+                 2, 4, 3, // third face
+                 1, 3, 5, // fourth face
+                 1, 3, 6, // fifth face
+                 ]
+        )
+    }
+
+    #[test]
+    fn test_get_faces_of_edge() {
+        let adjacency = EdgeAdjacency::from_mesh(&get_open_mesh());
+
+        assert_eq!(adjacency.get_faces_of_edge(&Edge::new(3, 1)), &[1, 3, 4]);
+        assert_eq!(adjacency.get_faces_of_edge(&Edge::new(1, 3)), &[1, 3, 4]);
+        assert_eq!(adjacency.get_faces_of_edge(&Edge::new(4, 2)), &[2]);
+        assert!(adjacency.get_faces_of_edge(&Edge::new(0, 6)).is_empty());
+    }
+
+    #[test]
+    fn test_get_edges_of_face() {
+        let adjacency = EdgeAdjacency::from_mesh(&get_open_mesh());
+
+        assert_eq!(adjacency.get_edges_of_face(0), [Edge::new(0, 2), Edge::new(2, 1), Edge::new(1, 0)]);
+        assert_eq!(adjacency.get_edges_of_face(4), [Edge::new(1, 3), Edge::new(3, 6), Edge::new(6, 1)]);
+    }
+
+    #[test]
+    fn test_get_neighbour_faces_of_face() {
+        let adjacency = EdgeAdjacency::from_mesh(&get_open_mesh());
+
+        assert_eq!(adjacency.get_neighbour_faces_of_face(0), vec![1]);
+        assert_eq!(adjacency.get_neighbour_faces_of_face(1), vec![0, 2, 3, 4]);
+        assert_eq!(adjacency.get_neighbour_faces_of_face(3), vec![1, 4]);
+    }
+
+    #[test]
+    fn test_get_boundary_edges() {
+        let adjacency = EdgeAdjacency::from_mesh(&get_open_mesh());
+
+        let actual = adjacency.get_boundary_edges();
+
+        let expected = vec![
+            Edge::new(0, 1),
+            Edge::new(0, 2),
+            Edge::new(1, 5),
+            Edge::new(1, 6),
+            Edge::new(2, 4),
+            Edge::new(3, 4),
+            Edge::new(3, 5),
+            Edge::new(3, 6),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_get_edges_with_more_than_2_faces() {
+        let adjacency = EdgeAdjacency::from_mesh(&get_open_mesh());
+
+        let actual = adjacency.get_edges_with_more_than_2_faces();
+
+        assert_eq!(actual, vec![Edge::new(1, 3)]);
+    }
+
+    #[test]
+    fn test_is_closed_false() {
+        let adjacency = EdgeAdjacency::from_mesh(&get_open_mesh());
+
+        assert!(!adjacency.is_closed());
+    }
+
+    #[test]
+    fn test_get_number_of_edges_and_faces() {
+        let adjacency = EdgeAdjacency::from_mesh(&get_open_mesh());
+
+        assert_eq!(adjacency.get_number_of_edges(), 11);
+        assert_eq!(adjacency.get_number_of_faces(), 5);
+    }
+}
@@ -1,6 +1,10 @@
+use std::collections::HashSet;
 use crate::element::Element;
 use crate::local_coordinate_system::LocalCoordinateSystem;
 
+/// The key of the [Element] `info` under which the name is stored.
+pub (crate) const ELEMENT_NAME_KEY: &str = "Name";
+
 impl Element {
     /// Compares given [Element] to other one, but with a `f64` tolerance for fields
     /// having `f64`.
@@ -95,6 +99,81 @@ impl Element {
         local_coordinate_system.origin = self.vector.to_point();
         local_coordinate_system
     }
+
+    /// Gets the name of the [Element] stored in its `info` under the `Name` key.
+    ///
+    /// The dotbim format doesn't have a separate field for the name, so this is just a
+    /// convention. It returns `None` if the name is not set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use meshmeshmesh::color::Color;
+    /// use meshmeshmesh::element::Element;
+    /// use meshmeshmesh::quaternion::Quaternion;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Element::new(
+    ///     0,
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Quaternion::new(0.0, 0.0, 0.0, 1.0),
+    ///     String::from("d4f28792-e1e9-4e31-bcee-740dbda61e20"),
+    ///     String::from("Plate"),
+    ///     Color::new(0, 120, 120, 255),
+    ///     None,
+    ///     HashMap::from([(String::from("Name"), String::from("Triangle"))]),
+    /// );
+    ///
+    /// assert_eq!(input.get_name(), Some("Triangle"));
+    /// ```
+    pub fn get_name(&self) -> Option<&str> {
+        self.info.get(ELEMENT_NAME_KEY).map(|name| name.as_str())
+    }
+
+    /// Gets `guid`s which are used by more than 1 of given [Element]s, sorted.
+    ///
+    /// Every `guid` should be unique, so an empty `Vec` means the [Element]s are valid. Many
+    /// [Element]s can still share the same `mesh_id`, which is how instances are represented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use meshmeshmesh::color::Color;
+    /// use meshmeshmesh::element::Element;
+    /// use meshmeshmesh::quaternion::Quaternion;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let get_element = |guid: &str| Element::new(
+    ///     0,
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Quaternion::new(0.0, 0.0, 0.0, 1.0),
+    ///     String::from(guid),
+    ///     String::from("Plate"),
+    ///     Color::new(0, 120, 120, 255),
+    ///     None,
+    ///     HashMap::new(),
+    /// );
+    ///
+    /// let input = vec![get_element("b"), get_element("a"), get_element("b"), get_element("c")];
+    ///
+    /// assert_eq!(Element::get_duplicate_guids(&input), vec![String::from("b")]);
+    /// ```
+    pub fn get_duplicate_guids(elements: &[Element]) -> Vec<String> {
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut duplicates: HashSet<&str> = HashSet::new();
+        for element in elements {
+            if !visited.insert(&element.guid) {
+                duplicates.insert(&element.guid);
+            }
+        }
+
+        let mut duplicates: Vec<String> = duplicates.into_iter().map(String::from).collect();
+        duplicates.sort();
+
+        duplicates
+    }
 }
 
 #[cfg(test)]
@@ -131,6 +210,28 @@ mod tests {
         assert!(expected.eq_with_tolerance(&actual, 0.0001));
     }
 
+    #[test]
+    fn test_get_name_none() {
+        let input = get_blue_test_element();
+
+        assert_eq!(input.get_name(), None);
+    }
+
+    #[test]
+    fn test_get_duplicate_guids() {
+        let get_element = |guid: &str| {
+            let mut element = get_blue_test_element();
+            element.guid = String::from(guid);
+            element
+        };
+
+        let input = vec![get_element("c"), get_element("a"), get_element("b"), get_element("a"), get_element("c"), get_element("a")];
+
+        assert_eq!(Element::get_duplicate_guids(&input), vec![String::from("a"), String::from("c")]);
+        assert!(Element::get_duplicate_guids(&[get_element("b")]).is_empty());
+        assert!(Element::get_duplicate_guids(&[]).is_empty());
+    }
+
     fn get_blue_test_element() -> Element {
         let mut info: HashMap<String, String> = HashMap::new();
         info.insert(String::from("Key"), String::from("Value"));
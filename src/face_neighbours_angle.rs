@@ -48,6 +48,12 @@ impl FaceNeighboursAngle {
     ///
     /// To make it correct both input lists (`face_neighbours` and `triangles`) have to match.
     ///
+    /// Degenerate (zero-area) [Triangle]s don't have a defined normal, so angles between them
+    /// and their neighbours are 0.0. Thanks to that they stay together with their neighbours
+    /// e.g. when splitting by angle. Use
+    /// [FaceNeighboursAngle::from_face_neighbours_and_triangles_with_degenerate_face_ids] to
+    /// also get the ids of these faces.
+    ///
     /// # Example
     ///
     /// ```
@@ -83,33 +89,68 @@ impl FaceNeighboursAngle {
     /// 
     /// ```
     pub fn from_face_neighbours_and_triangles(face_neighbours: &Vec<FaceNeighbours>, triangles: &Vec<Triangle>) -> Vec<FaceNeighboursAngle> {
+        Self::from_face_neighbours_and_triangles_with_degenerate_face_ids(face_neighbours, triangles).0
+    }
+
+    /// Creates a `vec` of [FaceNeighboursAngle]s from [FaceNeighbours] and [Triangle]s, together
+    /// with the ids of degenerate (zero-area) faces, for which the angles couldn't be measured.
+    ///
+    /// It works the same way as [FaceNeighboursAngle::from_face_neighbours_and_triangles], so
+    /// angles to the degenerate faces are 0.0, but returned ids tell which faces were skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::face_neighbours::FaceNeighbours;
+    /// use meshmeshmesh::face_neighbours_angle::FaceNeighboursAngle;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let triangles = vec![
+    ///     Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0)),
+    ///     Triangle::new(Point::new(10.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0), Point::new(5.0, 0.0, 0.0)), // collinear
+    /// ];
+    ///
+    /// let face_neighbours = vec![
+    ///     FaceNeighbours::new(Some(1), None, None),
+    ///     FaceNeighbours::new(Some(0), None, None),
+    /// ];
+    ///
+    /// let (angles, degenerate_face_ids) = FaceNeighboursAngle::from_face_neighbours_and_triangles_with_degenerate_face_ids(&face_neighbours, &triangles);
+    ///
+    /// assert_eq!(angles, vec![FaceNeighboursAngle::new(Some(0.0), None, None), FaceNeighboursAngle::new(Some(0.0), None, None)]);
+    /// assert_eq!(degenerate_face_ids, vec![1]);
+    /// ```
+    pub fn from_face_neighbours_and_triangles_with_degenerate_face_ids(face_neighbours: &[FaceNeighbours], triangles: &[Triangle]) -> (Vec<FaceNeighboursAngle>, Vec<usize>) {
         let number_of_faces = triangles.len();
         let mut face_neighbours_angles: Vec<FaceNeighboursAngle> = vec![FaceNeighboursAngle::new(None, None, None); number_of_faces];
         if number_of_faces != face_neighbours.len() {
             panic!("The input of the from_face_neighbours_and_triangles (for both FaceNeighbours and Triangles) should be the same length.")
         }
 
+        let degenerate_face_ids: Vec<usize> = (0..number_of_faces).filter(|face_id| triangles[*face_id].is_degenerate()).collect();
+
         for i in 0..number_of_faces {
             let current_face_neighbours = face_neighbours[i];
             let current_face = triangles[i];
 
             if current_face_neighbours.first.is_some() {
                 let neighbour_face = triangles[current_face_neighbours.first.unwrap()];
-                face_neighbours_angles[i].first = Some(current_face.get_normals_angle(&neighbour_face));
+                face_neighbours_angles[i].first = Some(current_face.get_normals_angle_checked(&neighbour_face).unwrap_or(0.0));
             }
 
             if current_face_neighbours.second.is_some() {
                 let neighbour_face = triangles[current_face_neighbours.second.unwrap()];
-                face_neighbours_angles[i].second = Some(current_face.get_normals_angle(&neighbour_face));
+                face_neighbours_angles[i].second = Some(current_face.get_normals_angle_checked(&neighbour_face).unwrap_or(0.0));
             }
 
             if current_face_neighbours.third.is_some() {
                 let neighbour_face = triangles[current_face_neighbours.third.unwrap()];
-                face_neighbours_angles[i].third = Some(current_face.get_normals_angle(&neighbour_face));
+                face_neighbours_angles[i].third = Some(current_face.get_normals_angle_checked(&neighbour_face).unwrap_or(0.0));
             }
         }
 
-        face_neighbours_angles
+        (face_neighbours_angles, degenerate_face_ids)
     }
 }
 
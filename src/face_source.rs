@@ -0,0 +1,48 @@
+/// Represents the origin of a face of a [Mesh](crate::mesh::Mesh) created by joining or
+/// splitting other Meshes.
+///
+/// It's created by [Mesh::join_with_provenance](crate::mesh::Mesh::join_with_provenance) and
+/// by `_with_provenance` variants of split methods, 1 per face of the result [Mesh], so every
+/// face can be traced back to the original [Element](crate::element::Element) or Mesh.
+///
+/// Methods which keep the order of faces (e.g. welding, flipping or transformations) keep it
+/// valid.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::face_source::FaceSource;
+///
+/// let source = FaceSource::new(2, Some(17), 5);
+///
+/// assert_eq!(source.mesh_index, 2);
+/// assert_eq!(source.mesh_id, Some(17));
+/// assert_eq!(source.face_id, 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FaceSource {
+    /// Position of the source Mesh in the joined list. It's always 0 for splits, which have
+    /// only 1 source Mesh.
+    pub mesh_index: usize,
+    /// The `id` of the source Mesh.
+    pub mesh_id: Option<usize>,
+    /// Id of the face in the source Mesh.
+    pub face_id: usize,
+}
+
+impl FaceSource {
+    /// Creates a new [FaceSource].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::face_source::FaceSource;
+    ///
+    /// let source = FaceSource::new(0, None, 3);
+    ///
+    /// assert_eq!(source.mesh_index, 0);
+    /// assert_eq!(source.mesh_id, None);
+    /// assert_eq!(source.face_id, 3);
+    /// ```
+    pub fn new(mesh_index: usize, mesh_id: Option<usize>, face_id: usize) -> FaceSource { FaceSource { mesh_index, mesh_id, face_id } }
+}
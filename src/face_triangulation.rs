@@ -0,0 +1,21 @@
+/// Describes how polygonal faces are split into triangles, see
+/// [PolygonMesh::to_mesh](crate::polygon_mesh::PolygonMesh::to_mesh).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::face_triangulation::FaceTriangulation;
+///
+/// let triangulation = FaceTriangulation::EarClipping;
+///
+/// assert_ne!(triangulation, FaceTriangulation::Fan);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaceTriangulation {
+    /// Each face is split into triangles sharing its first vertex. It's the fastest, but it's
+    /// valid only for convex faces.
+    Fan,
+    /// Each face is projected onto its plane and ears are clipped one by one, so concave faces
+    /// are triangulated correctly too.
+    EarClipping,
+}
@@ -0,0 +1,41 @@
+/// Describes a single hole of a [Mesh](crate::mesh::Mesh) filled by
+/// [Mesh::fill_holes](crate::mesh::Mesh::fill_holes).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::filled_hole::FilledHole;
+///
+/// let hole = FilledHole::new(vec![4, 5, 6, 7], vec![10, 11], true);
+///
+/// assert_eq!(hole.boundary_vertex_ids, vec![4, 5, 6, 7]);
+/// assert_eq!(hole.face_ids, vec![10, 11]);
+/// assert!(hole.is_planar);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilledHole {
+    /// Ids of vertices of the boundary loop around the hole, in the order of the loop.
+    pub boundary_vertex_ids: Vec<usize>,
+    /// Ids of the faces added to fill the hole.
+    pub face_ids: Vec<usize>,
+    /// Whether the boundary loop was planar, so it was filled with the polygon triangulation,
+    /// otherwise it was filled with the minimum area ear clipping.
+    pub is_planar: bool,
+}
+
+impl FilledHole {
+    /// Creates a new [FilledHole].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::filled_hole::FilledHole;
+    ///
+    /// let hole = FilledHole::new(vec![0, 1, 2], vec![3], false);
+    ///
+    /// assert_eq!(hole.boundary_vertex_ids, vec![0, 1, 2]);
+    /// assert_eq!(hole.face_ids, vec![3]);
+    /// assert!(!hole.is_planar);
+    /// ```
+    pub fn new(boundary_vertex_ids: Vec<usize>, face_ids: Vec<usize>, is_planar: bool) -> FilledHole { FilledHole { boundary_vertex_ids, face_ids, is_planar } }
+}
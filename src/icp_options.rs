@@ -0,0 +1,46 @@
+/// Describes how [Mesh::align_to](crate::mesh::Mesh::align_to) registers one
+/// [Mesh](crate::mesh::Mesh) to another.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::icp_options::IcpOptions;
+///
+/// let options = IcpOptions::new(50, 1000, 0.5, 1e-9);
+///
+/// assert_eq!(options.max_iterations, 50);
+/// assert_eq!(options.number_of_samples, 1000);
+/// assert_eq!(options.max_correspondence_distance, 0.5);
+/// assert_eq!(options.convergence_tolerance, 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IcpOptions {
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+    /// The maximum number of sampled points of the aligned Mesh.
+    pub number_of_samples: usize,
+    /// Sampled points further than this from the target Mesh are ignored, so outliers (e.g.
+    /// parts missing in the target) don't spoil the result.
+    pub max_correspondence_distance: f64,
+    /// Iterations stop when both the rotation angle (in radians) and the translation length of
+    /// the last step are smaller than this value.
+    pub convergence_tolerance: f64,
+}
+
+impl IcpOptions {
+    /// Creates new [IcpOptions].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::icp_options::IcpOptions;
+    ///
+    /// let options = IcpOptions::new(10, 200, f64::INFINITY, 0.0);
+    ///
+    /// assert_eq!(options.max_iterations, 10);
+    /// assert_eq!(options.max_correspondence_distance, f64::INFINITY);
+    /// ```
+    pub fn new(max_iterations: usize, number_of_samples: usize, max_correspondence_distance: f64, convergence_tolerance: f64) -> IcpOptions {
+        IcpOptions { max_iterations, number_of_samples, max_correspondence_distance, convergence_tolerance }
+    }
+}
@@ -0,0 +1,65 @@
+/// Describes the size of a single index in an index buffer.
+///
+/// Interop targets like glTF, GPU buffers or C APIs use 16 or 32 bit indices, while the
+/// [Mesh](crate::mesh::Mesh) uses `usize`. See
+/// [Mesh::get_index_width](crate::mesh::Mesh::get_index_width).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::index_width::IndexWidth;
+///
+/// assert!(IndexWidth::U16 < IndexWidth::U32);
+/// assert_eq!(IndexWidth::U32.get_number_of_bytes(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IndexWidth {
+    /// 16 bit indices, for up to 65 536 vertices.
+    U16,
+    /// 32 bit indices, for up to 4 294 967 296 vertices.
+    U32,
+    /// 64 bit indices.
+    U64,
+}
+
+impl IndexWidth {
+    /// Gets the number of bytes of a single index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::index_width::IndexWidth;
+    ///
+    /// assert_eq!(IndexWidth::U16.get_number_of_bytes(), 2);
+    /// assert_eq!(IndexWidth::U64.get_number_of_bytes(), 8);
+    /// ```
+    pub fn get_number_of_bytes(&self) -> usize {
+        match self {
+            IndexWidth::U16 => 2,
+            IndexWidth::U32 => 4,
+            IndexWidth::U64 => 8,
+        }
+    }
+
+    /// Gets the smallest [IndexWidth] which can store the given index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::index_width::IndexWidth;
+    ///
+    /// assert_eq!(IndexWidth::from_max_index(65535), IndexWidth::U16);
+    /// assert_eq!(IndexWidth::from_max_index(65536), IndexWidth::U32);
+    /// ```
+    pub fn from_max_index(max_index: usize) -> IndexWidth {
+        if u16::try_from(max_index).is_ok() {
+            IndexWidth::U16
+        }
+        else if u32::try_from(max_index).is_ok() {
+            IndexWidth::U32
+        }
+        else {
+            IndexWidth::U64
+        }
+    }
+}
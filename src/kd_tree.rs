@@ -0,0 +1,139 @@
+use crate::point::Point;
+
+/// Represents a kd-tree of [Point]s.
+///
+/// Points are recursively split in half by the median along the axis with the biggest extent,
+/// so nearest neighbour, k-nearest, radius and box queries only check a small part of them.
+/// The tree is balanced and kept in a single `Vec`: every range of it has its splitting
+/// Point in the middle, the smaller ones before it and the bigger ones after.
+///
+/// It's a snapshot of given Points: unlike [crate::octree::Octree], Points cannot be added
+/// later. Ids of Points are their indices in the slice the tree was created from.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::kd_tree::KdTree;
+/// use meshmeshmesh::point::Point;
+///
+/// let kd_tree = KdTree::from_points(&[
+///     Point::new(0.0, 0.0, 0.0),
+///     Point::new(5.0, 0.0, 0.0),
+///     Point::new(0.0, 5.0, 0.0),
+///     Point::new(1.0, 1.0, 0.0),
+/// ]);
+///
+/// assert_eq!(kd_tree.get_k_nearest_point_ids(&Point::new(0.2, 0.2, 0.0), 2), vec![0, 3]);
+/// assert_eq!(kd_tree.get_point_ids_within_distance(&Point::new(4.0, 0.0, 0.0), 1.5), vec![1]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KdTree {
+    /// Given Points, in the original order.
+    pub(crate) points: Vec<Point>,
+    /// Ids of Points ordered so that every range has its splitting Point in the middle.
+    pub(crate) point_ids: Vec<usize>,
+    /// Axis (`0` for x, `1` for y, `2` for z) of the split made by the Point at the same
+    /// position in `point_ids`.
+    pub(crate) split_axes: Vec<usize>,
+}
+
+impl KdTree {
+    /// Creates a [KdTree] of given [Point]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::kd_tree::KdTree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let actual = KdTree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 3.0)]);
+    ///
+    /// assert_eq!(actual.get_number_of_points(), 2);
+    /// ```
+    pub fn from_points(points: &[Point]) -> KdTree {
+        let mut point_ids: Vec<usize> = (0..points.len()).collect();
+        let mut split_axes = vec![0; points.len()];
+        KdTree::build(&mut point_ids, &mut split_axes, points);
+
+        KdTree { points: points.to_vec(), point_ids, split_axes }
+    }
+
+    /// Orders given range of `point_ids`, so its median along the longest axis is in the middle,
+    /// and does the same recursively for both halves.
+    fn build(point_ids: &mut [usize], split_axes: &mut [usize], points: &[Point]) {
+        if point_ids.is_empty() {
+            return;
+        }
+
+        let mut min = [f64::INFINITY; 3];
+        let mut max = [f64::NEG_INFINITY; 3];
+        for point_id in point_ids.iter() {
+            for (axis, coordinate) in KdTree::get_coordinates(&points[*point_id]).iter().enumerate() {
+                min[axis] = min[axis].min(*coordinate);
+                max[axis] = max[axis].max(*coordinate);
+            }
+        }
+        let extents = [max[0] - min[0], max[1] - min[1], max[2] - min[2]];
+        let split_axis = (1..3).fold(0, |longest, axis| if extents[axis] > extents[longest] { axis } else { longest });
+
+        let middle = point_ids.len() / 2;
+        point_ids.select_nth_unstable_by(middle, |a, b| {
+            KdTree::get_coordinates(&points[*a])[split_axis].total_cmp(&KdTree::get_coordinates(&points[*b])[split_axis])
+        });
+        split_axes[middle] = split_axis;
+
+        let (left_point_ids, right_point_ids) = point_ids.split_at_mut(middle);
+        let (left_split_axes, right_split_axes) = split_axes.split_at_mut(middle);
+        KdTree::build(left_point_ids, left_split_axes, points);
+        KdTree::build(&mut right_point_ids[1..], &mut right_split_axes[1..], points);
+    }
+
+    pub(crate) fn get_coordinates(point: &Point) -> [f64; 3] {
+        [point.x, point.y, point.z]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_points_every_range_split_by_middle() {
+        let points: Vec<Point> = (0..100).map(|i| Point::new(((i * 37) % 101) as f64, ((i * 53) % 89) as f64 * 0.1, (i % 7) as f64)).collect();
+
+        let actual = KdTree::from_points(&points);
+
+        let mut ranges = vec![(0, points.len())];
+        while let Some((start, end)) = ranges.pop() {
+            if start == end {
+                continue;
+            }
+            let middle = start + (end - start) / 2;
+            let axis = actual.split_axes[middle];
+            let split_coordinate = KdTree::get_coordinates(&points[actual.point_ids[middle]])[axis];
+            for position in start..end {
+                let coordinate = KdTree::get_coordinates(&points[actual.point_ids[position]])[axis];
+                if position < middle {
+                    assert!(coordinate <= split_coordinate);
+                }
+                else {
+                    assert!(coordinate >= split_coordinate);
+                }
+            }
+            ranges.push((start, middle));
+            ranges.push((middle + 1, end));
+        }
+
+        let mut sorted_point_ids = actual.point_ids.clone();
+        sorted_point_ids.sort();
+        assert_eq!(sorted_point_ids, (0..100).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn test_from_points_empty() {
+        let actual = KdTree::from_points(&[]);
+
+        assert!(actual.point_ids.is_empty());
+        assert!(actual.split_axes.is_empty());
+    }
+}
@@ -0,0 +1,268 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use crate::bounding_box::BoundingBox;
+use crate::kd_tree::KdTree;
+use crate::point::Point;
+
+/// One of the k-nearest candidates with its distance to the query Point.
+struct Neighbour {
+    distance: f64,
+    point_id: usize,
+}
+
+impl PartialEq for Neighbour {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Neighbour {}
+
+impl PartialOrd for Neighbour {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Neighbour {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The BinaryHeap pops the furthest candidate first, so it's easy to replace.
+        self.distance.total_cmp(&other.distance).then(self.point_id.cmp(&other.point_id))
+    }
+}
+
+impl KdTree {
+    /// Returns the number of [Point]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::kd_tree::KdTree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = KdTree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)]);
+    ///
+    /// assert_eq!(input.get_number_of_points(), 2);
+    /// ```
+    pub fn get_number_of_points(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Gets the id of the [Point] closest to the given one. If there are several equally close
+    /// Points, the one with the smallest id is returned.
+    ///
+    /// `None` is returned if there are no Points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::kd_tree::KdTree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = KdTree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0)]);
+    ///
+    /// assert_eq!(input.get_nearest_point_id(&Point::new(6.0, 1.0, 0.0)), Some(1));
+    /// assert_eq!(KdTree::from_points(&[]).get_nearest_point_id(&Point::new(6.0, 1.0, 0.0)), None);
+    /// ```
+    pub fn get_nearest_point_id(&self, point: &Point) -> Option<usize> {
+        self.get_k_nearest_point_ids(point, 1).first().copied()
+    }
+
+    /// Gets ids of `k` [Point]s closest to the given one, sorted from the closest. Equally close
+    /// Points are sorted by their ids.
+    ///
+    /// If there are less than `k` Points, all of them are returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::kd_tree::KdTree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = KdTree::from_points(&[
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(3.0, 0.0, 0.0),
+    ///     Point::new(1.0, 0.0, 0.0),
+    ///     Point::new(0.0, -2.0, 0.0),
+    /// ]);
+    ///
+    /// assert_eq!(input.get_k_nearest_point_ids(&Point::new(0.9, 0.0, 0.0), 3), vec![2, 0, 1]);
+    /// assert_eq!(input.get_k_nearest_point_ids(&Point::new(0.9, 0.0, 0.0), 10), vec![2, 0, 1, 3]);
+    /// ```
+    pub fn get_k_nearest_point_ids(&self, point: &Point, k: usize) -> Vec<usize> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut neighbours = BinaryHeap::with_capacity(k + 1);
+        self.visit_k_nearest(point, k, 0, self.point_ids.len(), &mut neighbours);
+
+        neighbours.into_sorted_vec().into_iter().map(|neighbour| neighbour.point_id).collect()
+    }
+
+    /// Gets ids of [Point]s within the `distance` from the `center` (including the ones exactly
+    /// at the `distance`), in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::kd_tree::KdTree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = KdTree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 1.0, 1.0), Point::new(0.0, 0.0, 3.0)]);
+    ///
+    /// let actual = input.get_point_ids_within_distance(&Point::new(0.0, 0.0, 1.0), 2.0);
+    ///
+    /// assert_eq!(actual, vec![0, 1, 2]);
+    /// assert_eq!(input.get_point_ids_within_distance(&Point::new(0.0, 0.0, 0.0), 1.5), vec![0]);
+    /// ```
+    pub fn get_point_ids_within_distance(&self, center: &Point, distance: f64) -> Vec<usize> {
+        let center_coordinates = KdTree::get_coordinates(center);
+        let mut point_ids = Vec::new();
+        self.visit_ranges(
+            |axis, split_coordinate| center_coordinates[axis] - distance <= split_coordinate,
+            |axis, split_coordinate| center_coordinates[axis] + distance >= split_coordinate,
+            |point_id, point| if point.get_distance_to_point(center) <= distance { point_ids.push(point_id) },
+        );
+        point_ids.sort();
+
+        point_ids
+    }
+
+    /// Gets ids of [Point]s inside the [BoundingBox] (including its sides), in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::kd_tree::KdTree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = KdTree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0)]);
+    ///
+    /// let actual = input.get_point_ids_in_box(&BoundingBox::new(0.5, 2.0, -1.0, 1.0, -1.0, 1.0));
+    ///
+    /// assert_eq!(actual, vec![1, 2]);
+    /// ```
+    pub fn get_point_ids_in_box(&self, bounding_box: &BoundingBox) -> Vec<usize> {
+        let min = [bounding_box.min_x, bounding_box.min_y, bounding_box.min_z];
+        let max = [bounding_box.max_x, bounding_box.max_y, bounding_box.max_z];
+        let mut point_ids = Vec::new();
+        self.visit_ranges(
+            |axis, split_coordinate| min[axis] <= split_coordinate,
+            |axis, split_coordinate| max[axis] >= split_coordinate,
+            |point_id, point| {
+                let coordinates = KdTree::get_coordinates(point);
+                if (0..3).all(|axis| min[axis] <= coordinates[axis] && coordinates[axis] <= max[axis]) {
+                    point_ids.push(point_id);
+                }
+            },
+        );
+        point_ids.sort();
+
+        point_ids
+    }
+
+    /// Calls `on_point` for splitting Points of all the ranges, going into the lower half
+    /// if `visit_lower` accepts the split, and into the upper one if `visit_upper` does.
+    fn visit_ranges(&self, visit_lower: impl Fn(usize, f64) -> bool, visit_upper: impl Fn(usize, f64) -> bool, mut on_point: impl FnMut(usize, &Point)) {
+        let mut ranges = vec![(0, self.point_ids.len())];
+        while let Some((start, end)) = ranges.pop() {
+            if start == end {
+                continue;
+            }
+            let middle = start + (end - start) / 2;
+            let point_id = self.point_ids[middle];
+            let point = &self.points[point_id];
+            let axis = self.split_axes[middle];
+            let split_coordinate = KdTree::get_coordinates(point)[axis];
+
+            on_point(point_id, point);
+            if visit_lower(axis, split_coordinate) {
+                ranges.push((start, middle));
+            }
+            if visit_upper(axis, split_coordinate) {
+                ranges.push((middle + 1, end));
+            }
+        }
+    }
+
+    /// Adds Points of given range to `neighbours` if they're closer than the furthest of them,
+    /// keeping at most `k` of them.
+    fn visit_k_nearest(&self, point: &Point, k: usize, start: usize, end: usize, neighbours: &mut BinaryHeap<Neighbour>) {
+        if start == end {
+            return;
+        }
+        let middle = start + (end - start) / 2;
+        let point_id = self.point_ids[middle];
+        let split_point = &self.points[point_id];
+        let axis = self.split_axes[middle];
+
+        let candidate = Neighbour { distance: split_point.get_distance_to_point(point), point_id };
+        if neighbours.len() < k {
+            neighbours.push(candidate);
+        }
+        else if neighbours.peek().is_some_and(|furthest| candidate < *furthest) {
+            neighbours.pop();
+            neighbours.push(candidate);
+        }
+
+        let difference = KdTree::get_coordinates(point)[axis] - KdTree::get_coordinates(split_point)[axis];
+        let (near, far) = if difference <= 0.0 { ((start, middle), (middle + 1, end)) } else { ((middle + 1, end), (start, middle)) };
+        self.visit_k_nearest(point, k, near.0, near.1, neighbours);
+        if neighbours.len() < k || neighbours.peek().is_some_and(|furthest| difference.abs() <= furthest.distance) {
+            self.visit_k_nearest(point, k, far.0, far.1, neighbours);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_scattered_points(number_of_points: usize) -> Vec<Point> {
+        (0..number_of_points).map(|i| Point::new(
+            ((i * 37) % 101) as f64 * 0.3 - 15.0,
+            ((i * 53) % 89) as f64 * 0.5,
+            ((i * 11) % 17) as f64 * -1.1,
+        )).collect()
+    }
+
+    #[test]
+    fn test_queries_same_as_brute_force() {
+        let points = get_scattered_points(500);
+        let input = KdTree::from_points(&points);
+
+        for query in get_scattered_points(60).iter().map(|point| Point::new(point.x * 1.3 + 0.17, point.y - 3.1, point.z + 0.4)) {
+            let mut expected_k_nearest: Vec<usize> = (0..points.len()).collect();
+            expected_k_nearest.sort_by(|a, b| points[*a].get_distance_to_point(&query).total_cmp(&points[*b].get_distance_to_point(&query)).then(a.cmp(b)));
+            expected_k_nearest.truncate(7);
+            assert_eq!(input.get_k_nearest_point_ids(&query, 7), expected_k_nearest);
+            assert_eq!(input.get_nearest_point_id(&query), Some(expected_k_nearest[0]));
+
+            let expected_within: Vec<usize> = (0..points.len()).filter(|i| points[*i].get_distance_to_point(&query) <= 4.0).collect();
+            assert_eq!(input.get_point_ids_within_distance(&query, 4.0), expected_within);
+
+            let bounding_box = BoundingBox::new(query.x - 2.0, query.x + 3.0, query.y - 5.0, query.y + 1.0, query.z - 2.0, query.z + 2.0);
+            let expected_in_box: Vec<usize> = (0..points.len()).filter(|i| {
+                let point = &points[*i];
+                point.x >= bounding_box.min_x && point.x <= bounding_box.max_x && point.y >= bounding_box.min_y && point.y <= bounding_box.max_y && point.z >= bounding_box.min_z && point.z <= bounding_box.max_z
+            }).collect();
+            assert_eq!(input.get_point_ids_in_box(&bounding_box), expected_in_box);
+        }
+    }
+
+    #[test]
+    fn test_get_k_nearest_point_ids_duplicates_sorted_by_id() {
+        let input = KdTree::from_points(&[Point::new(1.0, 1.0, 1.0); 6]);
+
+        assert_eq!(input.get_k_nearest_point_ids(&Point::new(0.0, 0.0, 0.0), 4), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_get_k_nearest_point_ids_zero() {
+        let input = KdTree::from_points(&get_scattered_points(10));
+
+        assert!(input.get_k_nearest_point_ids(&Point::new(0.0, 0.0, 0.0), 0).is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::bounding_box::BoundingBox;
+use crate::point::Point;
+
+/// Represents a control lattice for free-form deformation (FFD), see
+/// [Mesh::get_deformed_by_lattice](crate::mesh::Mesh::get_deformed_by_lattice).
+///
+/// The lattice spans the `bounding_box` with a regular grid of control points. Moving
+/// `control_points` away from their initial positions deforms everything inside the box
+/// smoothly (as a trivariate Bézier volume).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::bounding_box::BoundingBox;
+/// use meshmeshmesh::lattice::Lattice;
+/// use meshmeshmesh::point::Point;
+///
+/// let mut result = Lattice::new(BoundingBox::new(0.0, 2.0, 0.0, 1.0, 0.0, 1.0), [3, 2, 2]);
+///
+/// assert_eq!(result.control_points.len(), 12);
+/// assert_eq!(result.control_points[result.get_control_point_id(1, 0, 1)], Point::new(1.0, 0.0, 1.0));
+///
+/// let id = result.get_control_point_id(2, 1, 1);
+/// result.control_points[id].z += 0.5;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lattice {
+    /// The box deformed by the lattice.
+    pub bounding_box: BoundingBox,
+    /// The number of control points along x, y and z.
+    pub divisions: [usize; 3],
+    /// Control points, with x changing the fastest, then y, then z.
+    pub control_points: Vec<Point>,
+}
+
+impl Lattice {
+    /// Creates a new [Lattice] with control points spread evenly over the [BoundingBox], so
+    /// it doesn't deform anything yet.
+    ///
+    /// `divisions` are numbers of control points along x, y and z. More control points give
+    /// more local control.
+    pub fn new(bounding_box: BoundingBox, divisions: [usize; 3]) -> Lattice {
+        if divisions.iter().any(|division| *division < 2) {
+            panic!("Lattice should have at least 2 control points along each axis");
+        }
+
+        let mut control_points = Vec::with_capacity(divisions[0] * divisions[1] * divisions[2]);
+        for k in 0..divisions[2] {
+            for j in 0..divisions[1] {
+                for i in 0..divisions[0] {
+                    control_points.push(Point::new(
+                        bounding_box.min_x + (bounding_box.max_x - bounding_box.min_x) * i as f64 / (divisions[0] - 1) as f64,
+                        bounding_box.min_y + (bounding_box.max_y - bounding_box.min_y) * j as f64 / (divisions[1] - 1) as f64,
+                        bounding_box.min_z + (bounding_box.max_z - bounding_box.min_z) * k as f64 / (divisions[2] - 1) as f64,
+                    ));
+                }
+            }
+        }
+
+        Lattice { bounding_box, divisions, control_points }
+    }
+
+    /// Gets the id in `control_points` of the control point with given indices along x, y
+    /// and z.
+    pub fn get_control_point_id(&self, i: usize, j: usize, k: usize) -> usize {
+        i + self.divisions[0] * (j + self.divisions[1] * k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_corners() {
+        let actual = Lattice::new(BoundingBox::new(-1.0, 1.0, 2.0, 4.0, 0.0, 3.0), [2, 3, 4]);
+
+        assert_eq!(actual.control_points.len(), 24);
+        assert_eq!(actual.control_points[0], Point::new(-1.0, 2.0, 0.0));
+        assert_eq!(actual.control_points[23], Point::new(1.0, 4.0, 3.0));
+        assert_eq!(actual.control_points[actual.get_control_point_id(0, 1, 2)], Point::new(-1.0, 3.0, 2.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Lattice should have at least 2 control points along each axis")]
+    fn test_new_too_few_divisions_panic() {
+        Lattice::new(BoundingBox::new(0.0, 1.0, 0.0, 1.0, 0.0, 1.0), [2, 1, 2]);
+    }
+}
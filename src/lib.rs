@@ -4,8 +4,15 @@ pub mod bounding_area;
 mod bounding_area_analysis;
 pub mod bounding_box;
 mod bounding_box_analysis;
+pub mod bvh;
+mod bvh_analysis;
+pub mod cleanup_options;
+pub mod cleanup_report;
 pub mod color;
+pub mod detected_plane;
 pub mod edge;
+pub mod edge_adjacency;
+mod edge_adjacency_analysis;
 mod edge_analysis;
 mod edge_processing;
 mod edge_transformations;
@@ -14,44 +21,161 @@ mod element_analysis;
 pub mod face_neighbours;
 mod face_neighbours_analysis;
 pub mod face_neighbours_angle;
+pub mod face_source;
+pub mod face_triangulation;
+pub mod filled_hole;
 pub mod graph;
 mod graph_analysis;
+pub mod icp_options;
+pub mod index_width;
+pub mod kd_tree;
+mod kd_tree_analysis;
+pub mod lattice;
 pub mod mesh;
+pub mod mesh32;
+mod mesh32_analysis;
+mod mesh32_processing;
+mod mesh_ambient_occlusion;
 mod mesh_analysis;
+mod mesh_ball_pivoting;
+pub mod mesh_attributes;
+mod mesh_boolean;
+mod mesh_cache;
+mod mesh_cleanup;
+mod mesh_clipping;
+mod mesh_colors;
+mod mesh_decimation;
+mod mesh_deformation;
+mod mesh_displacement;
+mod mesh_dual_contouring;
+pub mod mesh_delta;
+pub mod mesh_diff;
+mod mesh_edge_flipping;
+mod mesh_extrusion;
+mod mesh_face_groups;
+mod mesh_generation;
+mod mesh_geodesics;
+mod mesh_gltf;
+mod mesh_heightmap;
+mod mesh_hole_filling;
+mod mesh_imprinting;
+mod mesh_indices;
+mod mesh_msh;
+mod mesh_normals;
+mod mesh_obj;
+mod mesh_offset;
+mod mesh_optimization;
+mod mesh_plane_detection;
+mod mesh_ply;
+mod mesh_point_insertion;
 mod mesh_processing;
+mod mesh_projection;
+mod mesh_provenance;
+mod mesh_ray_casting;
+mod mesh_registration;
+mod mesh_refinement;
+mod mesh_sdf_repair;
+mod mesh_sectioning;
+mod mesh_signed_distance;
+mod mesh_silhouette;
+mod mesh_smoothing;
+pub mod mesh_statistics;
+mod mesh_stitching;
+mod mesh_stl;
 mod mesh_transformations;
+mod mesh_uvs;
+mod mesh_uv_projection;
+mod mesh_uv_unwrapping;
+mod mesh_voxelization;
+mod mesh_vtk;
+pub mod mesh_view;
+mod mesh_view_analysis;
 pub mod local_coordinate_system;
 mod local_coordinate_system_analysis;
+mod local_coordinate_system_generation;
 mod local_coordinate_system_transformations;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop;
+pub mod octree;
+mod octree_analysis;
+pub mod offset_validation_report;
+mod perlin_noise;
+pub mod plane;
+mod plane_analysis;
+pub mod plane_side;
 pub mod point;
 pub mod point2d;
 mod point2d_analysis;
+pub mod point32;
 mod point_analysis;
+pub mod point_cloud;
+mod point_cloud_analysis;
+mod point_cloud_xyz;
+mod point_quantization;
 mod point_transformations;
 pub mod polygon2d;
 pub mod polygon;
 mod polygon2d_analysis;
+mod polygon2d_delaunay;
 mod polygon2d_processing;
+mod polygon2d_straight_skeleton;
 mod polygon2d_transformations;
 mod polygon2d_triangulation;
 mod polygon_analysis;
+mod polygon_extrusion;
+pub mod polygon_mesh;
+mod polygon_mesh_processing;
 mod polygon_transformations;
 mod polygon_triangulation;
+pub mod polyline;
+mod polyline_analysis;
+mod polyline_processing;
+pub mod projection_method;
+pub mod quality_report;
+pub mod quantized_mesh;
 pub mod quaternion;
 mod quaternion_analysis;
 mod quaternion_transformation;
+mod random;
 pub mod ray;
 mod ray_analysis;
+pub mod ray_hit;
 pub mod scene;
 mod scene_analysis;
+pub mod scene_graph;
+mod scene_graph_analysis;
 mod scene_processing;
+pub mod section_properties;
+pub mod straight_skeleton;
+pub mod surface_distance;
+#[cfg(test)]
+mod test_utils;
+#[cfg(feature = "testmeshes")]
+pub mod testmeshes;
 pub mod three_edge_group;
 mod three_edge_group_analysis;
+pub mod transform;
+mod transform_analysis;
+mod transform_transformations;
 pub mod triangle;
 mod triangle_analysis;
 mod triangle_transformations;
+pub mod units;
+pub mod uv_channel;
+pub mod validation_options;
+pub mod validation_report;
 pub mod vector;
+pub mod vector32;
 pub mod vector2d;
 mod vector2d_analysis;
 mod vector_analysis;
-mod vector_transformations;
\ No newline at end of file
+mod vector_quantization;
+mod vector_transformations;
+pub mod vertex_normal_weighting;
+mod vertex_welder;
+pub mod voxel_fill_mode;
+pub mod voxel_grid;
+mod voxel_grid_analysis;
+#[cfg(feature = "wasm")]
+pub mod wasm_mesh;
+pub mod weld_report;
\ No newline at end of file
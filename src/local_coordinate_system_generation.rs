@@ -0,0 +1,148 @@
+use std::f64::consts::PI;
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::quaternion::Quaternion;
+use crate::ray::Ray;
+use crate::vector::Vector;
+
+impl LocalCoordinateSystem {
+    /// Creates a linear array (series) of `count` [LocalCoordinateSystem]s, starting with this
+    /// one and moved by the `step` each.
+    ///
+    /// Together with [crate::mesh::Mesh::get_instances] it places copies of a base mesh, the
+    /// same way as [crate::mesh::Mesh::deduplicate] describes found instances.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = LocalCoordinateSystem::global();
+    ///
+    /// let actual = input.get_linear_array(&Vector::new(2.0, 0.0, 1.0), 3);
+    ///
+    /// assert_eq!(actual.len(), 3);
+    /// assert_eq!(actual[0], input);
+    /// assert_eq!(actual[2].origin, Point::new(4.0, 0.0, 2.0));
+    /// assert_eq!(actual[2].x, input.x);
+    /// ```
+    pub fn get_linear_array(&self, step: &Vector, count: usize) -> Vec<LocalCoordinateSystem> {
+        (0..count).map(|i| *self + *step * i as f64).collect()
+    }
+
+    /// Creates a rectangular array (grid) of `x_count` by `y_count` [LocalCoordinateSystem]s,
+    /// starting with this one and moved by the `x_step` and `y_step` between columns and rows.
+    ///
+    /// The result goes row by row: `x_count` systems of the first row, then of the second one...
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = LocalCoordinateSystem::global();
+    ///
+    /// let actual = input.get_rectangular_array(&Vector::new(2.0, 0.0, 0.0), &Vector::new(0.0, 3.0, 0.0), 3, 2);
+    ///
+    /// assert_eq!(actual.len(), 6);
+    /// assert_eq!(actual[1].origin, Point::new(2.0, 0.0, 0.0));
+    /// assert_eq!(actual[5].origin, Point::new(4.0, 3.0, 0.0));
+    /// ```
+    pub fn get_rectangular_array(&self, x_step: &Vector, y_step: &Vector, x_count: usize, y_count: usize) -> Vec<LocalCoordinateSystem> {
+        let mut array = Vec::with_capacity(x_count * y_count);
+        for row in self.get_linear_array(y_step, y_count) {
+            array.extend(row.get_linear_array(x_step, x_count));
+        }
+
+        array
+    }
+
+    /// Creates a polar (circular) array of `count` [LocalCoordinateSystem]s, starting with this
+    /// one and rotated around the `axis` by the `angle` (in radians) in total, so the copies
+    /// turn with the array.
+    ///
+    /// The rotation follows the right hand rule around the `axis` direction. For the full
+    /// rotation (`angle` of 2 PI or more) copies are spread evenly around the whole circle
+    /// without the last one repeating the first one, otherwise the last copy is rotated by the
+    /// `angle` exactly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::ray::Ray;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = LocalCoordinateSystem::new(Point::new(2.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+    /// let axis = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// let actual = input.get_polar_array(&axis, 4, 2.0 * PI);
+    ///
+    /// assert_eq!(actual.len(), 4);
+    /// assert!(actual[1].origin.eq_with_tolerance(&Point::new(0.0, 2.0, 0.0), 1e-12));
+    /// assert!(actual[1].x.eq_with_tolerance(&Vector::new(0.0, 1.0, 0.0), 1e-12));
+    /// assert!(actual[3].origin.eq_with_tolerance(&Point::new(0.0, -2.0, 0.0), 1e-12));
+    /// ```
+    pub fn get_polar_array(&self, axis: &Ray, count: usize, angle: f64) -> Vec<LocalCoordinateSystem> {
+        let step = if angle.abs() >= 2.0 * PI {
+            angle.signum() * 2.0 * PI / count as f64
+        } else if count > 1 {
+            angle / (count - 1) as f64
+        } else {
+            0.0
+        };
+        let from_axis = Vector::from_2_points(&axis.origin, &self.origin);
+
+        (0..count).map(|i| {
+            let quaternion = Quaternion::new_from_axis_angle(&axis.direction, step * i as f64);
+            LocalCoordinateSystem::new(
+                axis.origin + from_axis.get_rotated_by_quaternion(quaternion),
+                self.x.get_rotated_by_quaternion(quaternion),
+                self.y.get_rotated_by_quaternion(quaternion),
+            )
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point;
+    use super::*;
+
+    #[test]
+    fn test_get_linear_array_empty() {
+        let actual = LocalCoordinateSystem::global().get_linear_array(&Vector::new(1.0, 0.0, 0.0), 0);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_get_polar_array_partial_around_shifted_axis() {
+        let input = LocalCoordinateSystem::new(Point::new(1.0, 1.0, 5.0), Vector::new(0.0, 1.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        let axis = Ray::new(Point::new(1.0, 0.0, 0.0), Vector::new(0.0, 0.0, 2.0));
+
+        let actual = input.get_polar_array(&axis, 3, PI);
+
+        assert_eq!(actual.len(), 3);
+        assert!(actual[0].eq_with_tolerance(&input, 1e-12));
+        let expected_middle = LocalCoordinateSystem::new(Point::new(0.0, 0.0, 5.0), Vector::new(-1.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        assert!(actual[1].eq_with_tolerance(&expected_middle, 1e-12));
+        let expected_last = LocalCoordinateSystem::new(Point::new(1.0, -1.0, 5.0), Vector::new(0.0, -1.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        assert!(actual[2].eq_with_tolerance(&expected_last, 1e-12));
+    }
+
+    #[test]
+    fn test_get_polar_array_full_negative_angle() {
+        let input = LocalCoordinateSystem::new(Point::new(1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+        let axis = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = input.get_polar_array(&axis, 4, -2.0 * PI);
+
+        assert!(actual[1].origin.eq_with_tolerance(&Point::new(0.0, -1.0, 0.0), 1e-12));
+    }
+}
@@ -1,8 +1,12 @@
+use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 use crate::edge::Edge;
+use crate::mesh_attributes::MeshAttributes;
+use crate::mesh_cache::MeshCache;
 use crate::point::Point;
 use crate::three_edge_group::ThreeEdgeGroup;
 use crate::triangle::Triangle;
+use crate::uv_channel::UvChannel;
 
 /// Represents a Mesh object in three-dimensional space.
 ///
@@ -45,6 +49,49 @@ pub struct Mesh {
     pub coordinates: Vec<f64>,
     /// The list of indices for the mesh triangles.
     pub indices: Vec<usize>,
+    /// Optional group id for every face, e.g. the index of the source [Mesh] after
+    /// [Mesh::join_with_groups].
+    ///
+    /// If it's set, it should contain exactly 1 value per face.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub face_groups: Option<Vec<usize>>,
+    /// Additional information about the mesh, e.g. the source file or the name of the part.
+    ///
+    /// It's kept by the formats that support it (dotbim, glTF `extras`), so it survives the
+    /// round trip together with the `id`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
+    /// Optional normal for every vertex, as a flat list like the `coordinates`:
+    ///
+    /// [nx0, ny0, nz0, nx1, ny1, nz1, ... , nxN, nyN, nzN]
+    ///
+    /// It can be computed with [Mesh::compute_vertex_normals]. Other methods don't update it, so
+    /// compute it again after editing the geometry. Methods moving vertices without changing
+    /// faces (e.g. [Mesh::get_twisted], [Mesh::get_displaced] or [Mesh::get_projected_onto])
+    /// remove it, as it doesn't fit the new shape.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vertex_normals: Option<Vec<f64>>,
+    /// Optional texture coordinates, per vertex or per face corner, see [UvChannel].
+    ///
+    /// They're kept by welding, unwelding, joining and extracting parts, and exported to .obj
+    /// and glTF files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uvs: Option<UvChannel>,
+    /// Optional RGBA color for every vertex, as a flat list like the `face_colors` of the
+    /// [Element](crate::element::Element):
+    ///
+    /// [r0, g0, b0, a0, r1, g1, b1, a1, ... , rN, gN, bN, aN]
+    ///
+    /// Values are from 0 to 255. They're kept by welding, unwelding, joining and extracting
+    /// parts, and exported to .ply and glTF files.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vertex_colors: Option<Vec<i32>>,
+    /// Named per-vertex and per-face channels with any user data, see [MeshAttributes].
+    #[serde(default, skip_serializing_if = "MeshAttributes::is_empty")]
+    pub attributes: MeshAttributes,
+    /// Lazily computed face normals, areas and the bounding box, see [Mesh::invalidate_cache].
+    #[serde(skip)]
+    pub(crate) cache: MeshCache,
 }
 
 impl PartialEq for Mesh {
@@ -69,6 +116,24 @@ impl PartialEq for Mesh {
                 return false;
             }
         }
+        if self.face_groups != other.face_groups {
+            return false;
+        }
+        if self.metadata != other.metadata {
+            return false;
+        }
+        if self.vertex_normals != other.vertex_normals {
+            return false;
+        }
+        if self.uvs != other.uvs {
+            return false;
+        }
+        if self.vertex_colors != other.vertex_colors {
+            return false;
+        }
+        if self.attributes != other.attributes {
+            return false;
+        }
 
         true
     }
@@ -90,7 +155,7 @@ impl Mesh {
     ///                                    10.0, -15.0, 0.0]);
     /// assert_eq!(result.indices, vec![0, 1, 2]);
     /// ```
-    pub fn new(coordinates: Vec<f64>, indices: Vec<usize>) -> Mesh {Mesh {id: None, coordinates, indices}}
+    pub fn new(coordinates: Vec<f64>, indices: Vec<usize>) -> Mesh {Mesh {id: None, coordinates, indices, face_groups: None, metadata: HashMap::new(), vertex_normals: None, uvs: None, vertex_colors: None, attributes: MeshAttributes::new(), cache: MeshCache::default()}}
 
     /// Creates a new [Mesh] with already set identifier
     ///
@@ -108,7 +173,7 @@ impl Mesh {
     ///                                    10.0, -15.0, 0.0]);
     /// assert_eq!(result.indices, vec![0, 1, 2]);
     /// ```
-    pub fn new_with_id(id: Option<usize>, coordinates: Vec<f64>, indices: Vec<usize>) -> Mesh {Mesh {id, coordinates, indices}}
+    pub fn new_with_id(id: Option<usize>, coordinates: Vec<f64>, indices: Vec<usize>) -> Mesh {Mesh {id, coordinates, indices, face_groups: None, metadata: HashMap::new(), vertex_normals: None, uvs: None, vertex_colors: None, attributes: MeshAttributes::new(), cache: MeshCache::default()}}
 
     /// Converts [Mesh] into list of [Point]s
     ///
@@ -199,6 +264,99 @@ impl Mesh {
         triangles
     }
 
+    /// Iterates over vertices of the [Mesh] as [Point]s.
+    ///
+    /// It's the lazy version of [Mesh::to_points], so it can be used with iterator adapters
+    /// without collecting all the points first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 2.0], vec![0, 1, 2]);
+    ///
+    /// let max_z = input.vertices().map(|point| point.z).fold(f64::MIN, f64::max);
+    ///
+    /// assert_eq!(max_z, 2.0);
+    /// ```
+    pub fn vertices(&self) -> impl Iterator<Item = Point> + '_ {
+        self.coordinates.chunks_exact(3).map(|vertex| Point::new(vertex[0], vertex[1], vertex[2]))
+    }
+
+    /// Iterates over faces of the [Mesh] as tuples of 3 vertex indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual: Vec<(usize, usize, usize)> = input.faces().collect();
+    ///
+    /// assert_eq!(actual, vec![(0, 1, 2), (0, 2, 3)]);
+    /// ```
+    pub fn faces(&self) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+        self.indices.chunks_exact(3).map(|face| (face[0], face[1], face[2]))
+    }
+
+    /// Iterates over faces of the [Mesh] as [Triangle]s.
+    ///
+    /// It's the lazy version of [Mesh::to_triangles].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let area: f64 = input.triangles().map(|triangle| triangle.get_area()).sum();
+    ///
+    /// assert!((area - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn triangles(&self) -> impl Iterator<Item = Triangle> + '_ {
+        self.faces().map(|(first, second, third)| Triangle::new(
+            Point::new(self.coordinates[first * 3], self.coordinates[first * 3 + 1], self.coordinates[first * 3 + 2]),
+            Point::new(self.coordinates[second * 3], self.coordinates[second * 3 + 1], self.coordinates[second * 3 + 2]),
+            Point::new(self.coordinates[third * 3], self.coordinates[third * 3 + 1], self.coordinates[third * 3 + 2]),
+        ))
+    }
+
+    /// Iterates over unique edges of the [Mesh].
+    ///
+    /// Edges are treated as undirected, so an edge shared by several faces is returned only
+    /// once, in the direction of the first face which uses it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual: Vec<Edge> = input.edges().collect();
+    ///
+    /// assert_eq!(actual, vec![Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 0), Edge::new(2, 3), Edge::new(3, 0)]);
+    /// ```
+    pub fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
+        let mut visited: HashSet<Edge> = HashSet::new();
+        self.faces()
+            .flat_map(|(first, second, third)| [Edge::new(first, second), Edge::new(second, third), Edge::new(third, first)])
+            .filter(move |edge| visited.insert(Edge::new(edge.start.min(edge.end), edge.start.max(edge.end))))
+    }
+
     /// Creates [Mesh] from list of [Triangle]s
     ///
     /// # Example
@@ -394,6 +552,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_vertices() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+
+        let actual: Vec<Point> = input.vertices().collect();
+
+        assert_eq!(actual, input.to_points());
+    }
+
+    #[test]
+    fn test_triangles_pyramid() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0, 5.0, 5.0, 4.0],
+            vec![0, 1, 2, 0, 2, 3, 0, 1, 4, 1, 2, 4, 2, 3, 4, 3, 0, 4]
+        );
+
+        let actual: Vec<Triangle> = input.triangles().collect();
+
+        assert_eq!(actual, input.to_triangles());
+    }
+
+    #[test]
+    fn test_edges_pyramid() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0, 5.0, 5.0, 4.0],
+            vec![0, 1, 2, 0, 2, 3, 0, 1, 4, 1, 2, 4, 2, 3, 4, 3, 0, 4]
+        );
+
+        let actual: Vec<Edge> = input.edges().collect();
+
+        let expected = vec![
+            Edge::new(0, 1), Edge::new(1, 2), Edge::new(2, 0),
+            Edge::new(2, 3), Edge::new(3, 0),
+            Edge::new(1, 4), Edge::new(4, 0),
+            Edge::new(2, 4),
+            Edge::new(3, 4),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_to_triangles_1face() {
         let input = Mesh::new(vec![0.0, 0.0, 0.0,
@@ -755,4 +954,49 @@ mod tests {
                                  vec![0, 1, 2]);
         assert_eq!(expected.eq(&actual), true);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_partialeq_different_face_groups_false() {
+        let a = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+        let mut b = a.clone();
+        b.face_groups = Some(vec![0]);
+        assert!(!a.eq(&b));
+        assert!(!b.eq(&a));
+    }
+
+    #[test]
+    fn test_json_face_groups() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+        assert!(!to_string(&input).unwrap().contains("face_groups"));
+
+        input.face_groups = Some(vec![3]);
+        let json = to_string(&input).unwrap();
+        let actual: Mesh = from_str(&json).unwrap();
+
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_partialeq_different_metadata_false() {
+        let a = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+        let mut b = a.clone();
+        b.metadata.insert(String::from("Name"), String::from("Slab"));
+        assert!(!a.eq(&b));
+        assert!(!b.eq(&a));
+    }
+
+    #[test]
+    fn test_json_id_and_metadata() {
+        let mut input = Mesh::new_with_id(Some(7), vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+        assert!(!to_string(&input).unwrap().contains("metadata"));
+
+        input.metadata.insert(String::from("Name"), String::from("Slab"));
+        input.metadata.insert(String::from("Source"), String::from("scan_01.e57"));
+        let json = to_string(&input).unwrap();
+        let actual: Mesh = from_str(&json).unwrap();
+
+        assert_eq!(input, actual);
+        assert_eq!(actual.id, Some(7));
+        assert_eq!(actual.metadata.get("Source"), Some(&String::from("scan_01.e57")));
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::mesh::Mesh;
+use crate::mesh_attributes::MeshAttributes;
+use crate::uv_channel::UvChannel;
+use crate::vector32::Vector32;
+
+/// Represents a Mesh object in three-dimensional space using `f32` coordinates and `u32` indices.
+///
+/// It's the single precision version of the [Mesh], which takes half of the memory. It's useful
+/// for memory-constrained or GPU-bound use cases, where the data can be passed directly to
+/// vertex and index buffers.
+///
+/// Coordinates, indices and all the other channels are organized the same way as in the
+/// [Mesh]. Coordinates and `vertex_normals` are stored as `f32`, while `uvs`, `vertex_colors`,
+/// `face_groups`, `metadata` and `attributes` keep their [Mesh] types, so they're carried over
+/// unchanged by conversions in both directions.
+///
+/// Conversion from the [Mesh] loses precision of big coordinates, so for georeferenced models
+/// consider using [Mesh::rebase] first. Conversion to the [Mesh] is lossless.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::mesh32::Mesh32;
+///
+/// let result = Mesh32::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+/// assert_eq!(result.coordinates, vec![0.0, 0.0, 0.0,
+///                                    10.0, 0.0, 0.0,
+///                                    10.0, -15.0, 0.0]);
+/// assert_eq!(result.indices, vec![0, 1, 2]);
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Mesh32 {
+    /// Optional identifier
+    #[serde(rename(serialize = "mesh_id", deserialize = "mesh_id"))]
+    pub id: Option<usize>,
+    /// The list of coordinates for the mesh vertices.
+    pub coordinates: Vec<f32>,
+    /// The list of indices for the mesh triangles.
+    pub indices: Vec<u32>,
+    /// Optional group id for every face, see [Mesh::face_groups].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub face_groups: Option<Vec<usize>>,
+    /// Additional information about the mesh, see [Mesh::metadata].
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
+    /// Optional normal for every vertex, as a flat list like the `coordinates`, see
+    /// [Mesh::vertex_normals].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vertex_normals: Option<Vec<f32>>,
+    /// Optional texture coordinates, per vertex or per face corner, see [UvChannel].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uvs: Option<UvChannel>,
+    /// Optional RGBA color for every vertex, see [Mesh::vertex_colors].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vertex_colors: Option<Vec<i32>>,
+    /// Named per-vertex and per-face channels with any user data, see [MeshAttributes].
+    #[serde(default, skip_serializing_if = "MeshAttributes::is_empty")]
+    pub attributes: MeshAttributes,
+}
+
+impl PartialEq for Mesh32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.coordinates == other.coordinates && self.indices == other.indices &&
+            self.face_groups == other.face_groups && self.metadata == other.metadata &&
+            self.vertex_normals == other.vertex_normals && self.uvs == other.uvs &&
+            self.vertex_colors == other.vertex_colors && self.attributes == other.attributes
+    }
+}
+
+impl From<&Mesh> for Mesh32 {
+    fn from(mesh: &Mesh) -> Self {
+        if mesh.get_number_of_vertices() > u32::MAX as usize {
+            panic!("Cannot convert the Mesh with more vertices than u32::MAX into Mesh32");
+        }
+
+        Mesh32 {
+            id: mesh.id,
+            coordinates: mesh.coordinates.iter().map(|coordinate| *coordinate as f32).collect(),
+            indices: mesh.indices.iter().map(|index| *index as u32).collect(),
+            face_groups: mesh.face_groups.clone(),
+            metadata: mesh.metadata.clone(),
+            vertex_normals: mesh.vertex_normals.as_ref().map(|normals| normals.iter().map(|value| *value as f32).collect()),
+            uvs: mesh.uvs.clone(),
+            vertex_colors: mesh.vertex_colors.clone(),
+            attributes: mesh.attributes.clone(),
+        }
+    }
+}
+
+impl From<&Mesh32> for Mesh {
+    fn from(mesh: &Mesh32) -> Self {
+        let mut converted = Mesh::new_with_id(
+            mesh.id,
+            mesh.coordinates.iter().map(|coordinate| *coordinate as f64).collect(),
+            mesh.indices.iter().map(|index| *index as usize).collect(),
+        );
+        converted.face_groups = mesh.face_groups.clone();
+        converted.metadata = mesh.metadata.clone();
+        converted.vertex_normals = mesh.vertex_normals.as_ref().map(|normals| normals.iter().map(|value| *value as f64).collect());
+        converted.uvs = mesh.uvs.clone();
+        converted.vertex_colors = mesh.vertex_colors.clone();
+        converted.attributes = mesh.attributes.clone();
+
+        converted
+    }
+}
+
+impl Mesh32 {
+    /// Creates a new [Mesh32]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh32::Mesh32;
+    ///
+    /// let result = Mesh32::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    /// assert_eq!(result.id, None);
+    /// assert_eq!(result.indices, vec![0, 1, 2]);
+    /// ```
+    pub fn new(coordinates: Vec<f32>, indices: Vec<u32>) -> Mesh32 {Mesh32::new_with_id(None, coordinates, indices)}
+
+    /// Creates a new [Mesh32] with already set identifier
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh32::Mesh32;
+    ///
+    /// let result = Mesh32::new_with_id(Some(5), vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    /// assert_eq!(result.id, Some(5));
+    /// ```
+    pub fn new_with_id(id: Option<usize>, coordinates: Vec<f32>, indices: Vec<u32>) -> Mesh32 {Mesh32 {id, coordinates, indices, face_groups: None, metadata: HashMap::new(), vertex_normals: None, uvs: None, vertex_colors: None, attributes: MeshAttributes::new()}}
+
+    /// Returns the number of vertices of the [Mesh32].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh32::Mesh32;
+    ///
+    /// let input = Mesh32::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    /// assert_eq!(input.get_number_of_vertices(), 3);
+    /// ```
+    pub fn get_number_of_vertices(&self) -> usize {
+        self.coordinates.len() / 3
+    }
+
+    /// Returns the number of faces of the [Mesh32].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh32::Mesh32;
+    ///
+    /// let input = Mesh32::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    /// assert_eq!(input.get_number_of_faces(), 1);
+    /// ```
+    pub fn get_number_of_faces(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// Gets unitized normal [Vector32]s of all faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh32::Mesh32;
+    /// use meshmeshmesh::vector32::Vector32;
+    ///
+    /// let input = Mesh32::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// assert_eq!(input.get_face_normal_vectors_unitized(), vec![Vector32::new(0.0, 0.0, -1.0)]);
+    /// ```
+    pub fn get_face_normal_vectors_unitized(&self) -> Vec<Vector32> {
+        let mut normals = Vec::with_capacity(self.get_number_of_faces());
+        for face in self.indices.chunks(3) {
+            let a = self.get_vertex(face[0]);
+            let b = self.get_vertex(face[1]);
+            let c = self.get_vertex(face[2]);
+            let ab = Vector32::new(b.x - a.x, b.y - a.y, b.z - a.z);
+            let ac = Vector32::new(c.x - a.x, c.y - a.y, c.z - a.z);
+            normals.push(ab.get_cross_product(&ac).get_unitized());
+        }
+
+        normals
+    }
+
+    fn get_vertex(&self, index: u32) -> Vector32 {
+        let i = index as usize * 3;
+        Vector32::new(self.coordinates[i], self.coordinates[i + 1], self.coordinates[i + 2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+    use super::*;
+
+    #[test]
+    fn test_from_mesh_and_back() {
+        let input = Mesh::new_with_id(Some(3),
+            vec![
+                -2.0,1.0,0.0,
+                8.0,1.0,0.0,
+                8.0,11.0,0.0,
+                -2.0,11.0,0.0,
+                3.0,6.0,4.0
+            ],
+            vec![
+                0,1,2,
+                0,2,3,
+                0,1,4,
+                1,2,4,
+                2,3,4,
+                3,0,4
+            ]);
+
+        let converted = Mesh32::from(&input);
+        let actual = Mesh::from(&converted);
+
+        assert_eq!(converted.id, Some(3));
+        assert_eq!(converted.get_number_of_faces(), 6);
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_from_mesh_and_back_keeps_channels() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.face_groups = Some(vec![7]);
+        input.metadata.insert(String::from("name"), String::from("Slab"));
+        input.vertex_normals = Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+        input.vertex_colors = Some(vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255]);
+        input.attributes.face.insert(String::from("fire_rating"), crate::mesh_attributes::AttributeValues::F64(vec![60.0]));
+
+        let converted = Mesh32::from(&input);
+        let actual = Mesh::from(&converted);
+
+        assert_eq!(converted.vertex_normals, Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(converted.face_groups, Some(vec![7]));
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_from_mesh_loses_precision() {
+        let input = Mesh::new(vec![500000.123456, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        let actual = Mesh::from(&Mesh32::from(&input));
+
+        assert_ne!(input, actual);
+        assert!(input.eq_with_tolerance(&actual, 0.05));
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let input = Mesh32::new_with_id(Some(5), vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+
+        let json = to_string(&input).unwrap();
+        let actual: Mesh32 = from_str(&json).unwrap();
+
+        assert!(json.contains("mesh_id"));
+        assert_eq!(input, actual);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::bounding_box::BoundingBox;
+use crate::mesh32::Mesh32;
+use crate::point32::Point32;
+
+impl Mesh32 {
+    /// Converts [Mesh32] into list of [Point32]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh32::Mesh32;
+    /// use meshmeshmesh::point32::Point32;
+    ///
+    /// let input = Mesh32::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// assert_eq!(input.to_points(), vec![Point32::new(0.0, 0.0, 0.0), Point32::new(10.0, 0.0, 0.0), Point32::new(10.0, -15.0, 0.0)]);
+    /// ```
+    pub fn to_points(&self) -> Vec<Point32> {
+        self.coordinates.chunks_exact(3).map(|vertex| Point32::new(vertex[0], vertex[1], vertex[2])).collect()
+    }
+
+    /// Gets the [BoundingBox] of all the vertices, or `None` if there are no vertices.
+    ///
+    /// `f32` coordinates are converted into the `f64` ones of the [BoundingBox] without any loss.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::mesh32::Mesh32;
+    ///
+    /// let input = Mesh32::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 2.5], vec![0, 1, 2]);
+    ///
+    /// assert_eq!(input.get_bounding_box(), Some(BoundingBox::new(0.0, 10.0, -15.0, 0.0, 0.0, 2.5)));
+    /// assert_eq!(Mesh32::new(vec![], vec![]).get_bounding_box(), None);
+    /// ```
+    pub fn get_bounding_box(&self) -> Option<BoundingBox> {
+        if self.coordinates.len() < 3 {
+            return None;
+        }
+
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for vertex in self.coordinates.chunks_exact(3) {
+            for i in 0..3 {
+                min[i] = min[i].min(vertex[i]);
+                max[i] = max[i].max(vertex[i]);
+            }
+        }
+
+        Some(BoundingBox::new(min[0] as f64, max[0] as f64, min[1] as f64, max[1] as f64, min[2] as f64, max[2] as f64))
+    }
+
+    /// Calculates the area of all the faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh32::Mesh32;
+    ///
+    /// let input = Mesh32::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0, 0.0, -15.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    ///
+    /// assert_eq!(input.get_area(), 150.0);
+    /// ```
+    pub fn get_area(&self) -> f32 {
+        let points = self.to_points();
+
+        self.indices.chunks_exact(3)
+            .map(|face| {
+                let first = points[face[0] as usize];
+                let ab = first.get_vector_to_point(&points[face[1] as usize]);
+                let ac = first.get_vector_to_point(&points[face[2] as usize]);
+                ab.get_cross_product(&ac).get_length() / 2.0
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mesh::Mesh;
+    use super::*;
+
+    #[test]
+    fn test_get_area_same_as_mesh() {
+        let input = Mesh::new(
+            vec![-2.0, 1.0, 0.0, 8.0, 1.0, 0.0, 8.0, 11.0, 0.0, -2.0, 11.0, 0.0, 3.0, 6.0, 4.0],
+            vec![0, 1, 2, 0, 2, 3, 0, 1, 4, 1, 2, 4, 2, 3, 4, 3, 0, 4]);
+
+        let actual = Mesh32::from(&input).get_area();
+
+        assert!((actual as f64 - input.get_area()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_get_bounding_box_same_as_mesh() {
+        let input = Mesh::new(vec![-2.5, 1.0, 0.0, 8.0, 1.25, -3.0, 8.0, 11.0, 0.5], vec![0, 1, 2]);
+
+        assert_eq!(Mesh32::from(&input).get_bounding_box(), Some(input.get_bounding_box()));
+    }
+}
@@ -0,0 +1,84 @@
+use crate::mesh::Mesh;
+use crate::mesh32::Mesh32;
+use crate::vector32::Vector32;
+
+impl Mesh32 {
+    /// Creates a new [Mesh32] moved by the [Vector32].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh32::Mesh32;
+    /// use meshmeshmesh::vector32::Vector32;
+    ///
+    /// let input = Mesh32::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let actual = input.get_translated(&Vector32::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(actual.coordinates, vec![1.0, 2.0, 3.0, 11.0, 2.0, 3.0, 11.0, -13.0, 3.0]);
+    /// ```
+    pub fn get_translated(&self, vector: &Vector32) -> Mesh32 {
+        let mut translated = self.clone();
+        for vertex in translated.coordinates.chunks_exact_mut(3) {
+            vertex[0] += vector.x;
+            vertex[1] += vector.y;
+            vertex[2] += vector.z;
+        }
+
+        translated
+    }
+
+    /// Creates a new [Mesh32] with vertices closer than the `tolerance` welded, the same way as
+    /// [Mesh::get_with_welded_vertices] does, including all the other channels.
+    ///
+    /// Welded vertices keep their original `f32` coordinates, as the conversion to the [Mesh] and
+    /// back is lossless for them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh32::Mesh32;
+    ///
+    /// let input = Mesh32::new(
+    ///     vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    ///
+    /// let actual = input.get_with_welded_vertices(0.001);
+    ///
+    /// assert_eq!(actual.coordinates, vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0]);
+    /// assert_eq!(actual.indices, vec![0, 1, 2, 0, 2, 3]);
+    /// ```
+    pub fn get_with_welded_vertices(&self, tolerance: f32) -> Mesh32 {
+        Mesh32::from(&Mesh::from(self).get_with_welded_vertices(tolerance as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_with_welded_vertices_keeps_face_groups() {
+        let mut input = Mesh32::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0, 1, 2, 3, 4, 5]);
+        input.face_groups = Some(vec![1, 2]);
+
+        let actual = input.get_with_welded_vertices(0.001);
+
+        assert_eq!(actual.get_number_of_vertices(), 4);
+        assert_eq!(actual.face_groups, Some(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_get_translated_keeps_indices() {
+        let input = Mesh32::new_with_id(Some(4), vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+
+        let actual = input.get_translated(&Vector32::new(0.0, 0.0, -1.0));
+
+        assert_eq!(actual.id, Some(4));
+        assert_eq!(actual.indices, input.indices);
+        assert_eq!(actual.coordinates[8], -1.0);
+    }
+}
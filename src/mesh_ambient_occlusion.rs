@@ -0,0 +1,175 @@
+use std::f64::consts::PI;
+use crate::mesh::Mesh;
+use crate::mesh_attributes::AttributeValues;
+use crate::random::Random;
+use crate::ray::Ray;
+use crate::vector::Vector;
+
+impl Mesh {
+    /// Bakes ambient occlusion to the vertices, storing it as the `ambient_occlusion` vertex
+    /// channel of `attributes` (see [MeshAttributes](crate::mesh_attributes::MeshAttributes)),
+    /// with a single [AttributeValues::F64] value per vertex.
+    ///
+    /// For each vertex, `rays_per_vertex` [Ray]s are cast from the vertex into the hemisphere
+    /// around its area weighted normal (with the cosine weighted distribution), using the
+    /// [Bvh](crate::bvh::Bvh) (see [Mesh::get_cached_bvh]). The value is the fraction of Rays
+    /// which don't hit any face closer than `max_distance`: 1.0 for fully open vertices and 0.0
+    /// for fully occluded ones, so it can be multiplied with the vertex color for shading.
+    ///
+    /// Vertices without a normal (not used by any non-degenerate face) get 1.0, as does every
+    /// vertex if `rays_per_vertex` is 0. Sampling is deterministic: the same [Mesh] always gives
+    /// the same result.
+    ///
+    /// # Example
+    ///
+    /// Here is an example of a small floor under a large ceiling.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::mesh_attributes::AttributeValues;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+    ///          -50.0, -50.0, 0.1, 50.0, -50.0, 0.1, 50.0, 50.0, 0.1, -50.0, 50.0, 0.1],
+    ///     vec![0, 1, 2, 0, 2, 3, // floor facing up
+    ///          4, 6, 5, 4, 7, 6, // ceiling facing down
+    ///     ]);
+    ///
+    /// input.bake_ambient_occlusion(64, 10.0);
+    ///
+    /// let actual = match &input.attributes.vertex["ambient_occlusion"] {
+    ///     AttributeValues::F64(values) => values.clone(),
+    ///     _ => panic!(),
+    /// };
+    ///
+    /// assert_eq!(actual.len(), 8);
+    /// assert!(actual[0..4].iter().all(|value| *value < 0.05));
+    /// assert!(actual[4..8].iter().all(|value| *value == 1.0));
+    /// ```
+    pub fn bake_ambient_occlusion(&mut self, rays_per_vertex: usize, max_distance: f64) {
+        let values = self.get_ambient_occlusion(rays_per_vertex, max_distance);
+        self.attributes.vertex.insert(String::from("ambient_occlusion"), AttributeValues::F64(values));
+    }
+
+    fn get_ambient_occlusion(&self, rays_per_vertex: usize, max_distance: f64) -> Vec<f64> {
+        let points = self.to_points();
+        let mut values = vec![1.0; points.len()];
+        if rays_per_vertex == 0 || self.indices.is_empty() {
+            return values;
+        }
+
+        let mut normals = vec![Vector::zero(); points.len()];
+        for face in self.indices.chunks_exact(3) {
+            let first_side = Vector::from_2_points(&points[face[0]], &points[face[1]]);
+            let second_side = Vector::from_2_points(&points[face[0]], &points[face[2]]);
+            let area_weighted_normal = first_side.get_cross_product(&second_side);
+            for vertex_id in face {
+                normals[*vertex_id] = normals[*vertex_id] + area_weighted_normal;
+            }
+        }
+
+        // Rays start slightly above the surface, so they don't hit faces of their own vertex.
+        let bounding_box = self.get_bounding_box();
+        let diagonal = Vector::new(bounding_box.max_x - bounding_box.min_x, bounding_box.max_y - bounding_box.min_y, bounding_box.max_z - bounding_box.min_z);
+        let offset = diagonal.get_length() * 1e-6;
+
+        let bvh = self.get_cached_bvh();
+        for (vertex_id, (point, normal)) in points.iter().zip(normals.iter()).enumerate() {
+            if normal.is_absolute_zero_length() {
+                continue;
+            }
+            let normal = normal.get_unitized();
+            let tangent = normal.get_any_perpendicular().get_unitized();
+            let bitangent = normal.get_cross_product(&tangent);
+            let origin = *point + normal * offset;
+
+            let mut random = Random::new(vertex_id as u64);
+            let mut number_of_open_rays = 0;
+            for _ in 0..rays_per_vertex {
+                let radius_squared = (random.next() + 1.0) / 2.0;
+                let radius = radius_squared.sqrt();
+                let angle = (random.next() + 1.0) * PI;
+                let direction = tangent * (radius * angle.cos()) + bitangent * (radius * angle.sin()) + normal * (1.0 - radius_squared).sqrt();
+                let ray = Ray::new(origin, direction);
+                let is_occluded = bvh.get_first_ray_intersection(&ray)
+                    .is_some_and(|(_, hit)| origin.get_distance_to_point(&hit) <= max_distance);
+                if !is_occluded {
+                    number_of_open_rays += 1;
+                }
+            }
+            values[vertex_id] = number_of_open_rays as f64 / rays_per_vertex as f64;
+        }
+
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_floor_under_ceiling() -> Mesh {
+        Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+                 -50.0, -50.0, 0.1, 50.0, -50.0, 0.1, 50.0, 50.0, 0.1, -50.0, 50.0, 0.1],
+            vec![0, 1, 2, 0, 2, 3,
+                 4, 6, 5, 4, 7, 6])
+    }
+
+    fn get_ambient_occlusion_values(mesh: &Mesh) -> Vec<f64> {
+        match &mesh.attributes.vertex["ambient_occlusion"] {
+            AttributeValues::F64(values) => values.clone(),
+            _ => panic!("Ambient occlusion should be stored as F64"),
+        }
+    }
+
+    #[test]
+    fn test_bake_ambient_occlusion_floor_under_ceiling() {
+        let mut input = get_floor_under_ceiling();
+
+        input.bake_ambient_occlusion(100, 10.0);
+
+        let actual = get_ambient_occlusion_values(&input);
+        assert_eq!(actual.len(), 8);
+        assert!(actual[0..4].iter().all(|value| *value < 0.05));
+        assert!(actual[4..8].iter().all(|value| *value == 1.0));
+    }
+
+    #[test]
+    fn test_bake_ambient_occlusion_occluder_beyond_max_distance() {
+        let mut input = get_floor_under_ceiling();
+
+        input.bake_ambient_occlusion(100, 0.05);
+
+        assert_eq!(get_ambient_occlusion_values(&input), vec![1.0; 8]);
+    }
+
+    #[test]
+    fn test_bake_ambient_occlusion_single_face() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        input.bake_ambient_occlusion(50, f64::INFINITY);
+
+        assert_eq!(get_ambient_occlusion_values(&input), vec![1.0; 3]);
+    }
+
+    #[test]
+    fn test_bake_ambient_occlusion_deterministic() {
+        let mut first = get_floor_under_ceiling();
+        let mut second = get_floor_under_ceiling();
+
+        first.bake_ambient_occlusion(20, 10.0);
+        second.bake_ambient_occlusion(20, 10.0);
+
+        assert_eq!(first.attributes, second.attributes);
+    }
+
+    #[test]
+    fn test_bake_ambient_occlusion_no_rays() {
+        let mut input = get_floor_under_ceiling();
+
+        input.bake_ambient_occlusion(0, 10.0);
+
+        assert_eq!(get_ambient_occlusion_values(&input), vec![1.0; 8]);
+    }
+}
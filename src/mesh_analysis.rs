@@ -1,13 +1,22 @@
 use crate::bounding_box::BoundingBox;
 use crate::edge::Edge;
+use crate::edge_adjacency::EdgeAdjacency;
 use crate::graph::Graph;
 use crate::local_coordinate_system::LocalCoordinateSystem;
 use crate::mesh::Mesh;
+use crate::mesh_diff::MeshDiff;
+use crate::mesh_statistics::MeshStatistics;
+use crate::offset_validation_report::OffsetValidationReport;
 use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::quality_report::{QualityReport, RATIO_HISTOGRAM_BOUNDS};
+use crate::surface_distance::SurfaceDistance;
 use crate::three_edge_group::ThreeEdgeGroup;
 use crate::triangle::Triangle;
+use crate::validation_options::ValidationOptions;
+use crate::validation_report::ValidationReport;
 use crate::vector::Vector;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 impl Mesh {
 
@@ -140,6 +149,10 @@ impl Mesh {
     /// ```
     pub fn eq_with_tolerance_without_id(&self, other:&Mesh, tolerance: f64) -> bool {
 
+        if self.face_groups != other.face_groups {
+            return false;
+        }
+
         if self.indices.len() != other.indices.len() {
             return false;
         }
@@ -356,6 +369,203 @@ impl Mesh {
         sum
     }
 
+    /// Calculates the signed volume enclosed by the [Mesh], using the divergence theorem.
+    ///
+    /// It's positive if faces' normals point outside, and negative if they point inside.
+    ///
+    /// It returns `None` if the [Mesh] is not closed (see [Mesh::is_closed]), as open Meshes
+    /// don't enclose any volume. It doesn't check the winding of faces, so use
+    /// [Mesh::is_watertight] first if faces can have inconsistent orientation.
+    ///
+    /// # Example
+    ///
+    /// Here is an example with a tetrahedron with normals pointing outside.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          3.0, 0.0, 0.0,
+    ///          0.0, 3.0, 0.0,
+    ///          0.0, 0.0, 3.0,
+    ///          ],
+    ///     vec![0, 2, 1,
+    ///          0, 1, 3,
+    ///          1, 2, 3,
+    ///          0, 3, 2,
+    ///          ]
+    /// );
+    ///
+    /// let actual = input.get_volume().unwrap();
+    ///
+    /// assert!((actual - 4.5).abs() < 1e-12);
+    /// assert!(input.get_with_all_faces_flipped().get_volume().unwrap() < 0.0);
+    /// ```
+    pub fn get_volume(&self) -> Option<f64> {
+        if !self.is_closed() {
+            return None;
+        }
+
+        let sum: f64 = self.triangles().map(|triangle| {
+            let a = triangle.first_point;
+            let b = triangle.second_point;
+            let c = triangle.third_point;
+            a.x * (b.y * c.z - b.z * c.y) - a.y * (b.x * c.z - b.z * c.x) + a.z * (b.x * c.y - b.y * c.x)
+        }).sum();
+
+        Some(sum / 6.0)
+    }
+
+    /// Checks if the [Mesh] is closed: it has faces and every edge is used by exactly 2 faces.
+    ///
+    /// Edges are compared by indices of vertices, so the [Mesh] should be welded first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    /// let open = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3]
+    /// );
+    ///
+    /// assert!(tetrahedron.is_closed());
+    /// assert!(!open.is_closed());
+    /// ```
+    pub fn is_closed(&self) -> bool {
+        self.get_number_of_faces() > 0 && EdgeAdjacency::from_mesh(self).is_closed()
+    }
+
+    /// Checks if the [Mesh] is watertight: it's closed (see [Mesh::is_closed]) and all its
+    /// faces have consistent winding, so it encloses a volume without ambiguity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    /// let one_face_flipped = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 1, 2, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    ///
+    /// assert!(tetrahedron.is_watertight());
+    /// assert!(one_face_flipped.is_closed());
+    /// assert!(!one_face_flipped.is_watertight());
+    /// ```
+    pub fn is_watertight(&self) -> bool {
+        if self.get_number_of_faces() == 0 {
+            return false;
+        }
+        let adjacency = EdgeAdjacency::from_mesh(self);
+
+        adjacency.is_closed() && adjacency.get_inconsistent_winding_edges().is_empty()
+    }
+
+    /// Gets number of connected components of the [Mesh].
+    ///
+    /// Faces are connected if they share an edge, so 2 parts touching only with 1 vertex are
+    /// separate components. Edges are compared by indices of vertices, so the [Mesh] should be
+    /// welded first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          1.0, 0.0, 0.0,
+    ///          0.0, 1.0, 0.0,
+    ///          5.0, 0.0, 0.0,
+    ///          6.0, 0.0, 0.0,
+    ///          5.0, 1.0, 0.0,
+    ///          ],
+    ///     vec![0, 1, 2, // first part
+    ///          3, 4, 5, // second part
+    ///          ]
+    /// );
+    ///
+    /// assert_eq!(input.get_number_of_connected_components(), 2);
+    /// ```
+    pub fn get_number_of_connected_components(&self) -> usize {
+        self.get_face_ids_split_by_face_disconnected().len()
+    }
+
+    /// Gets Euler characteristic of the [Mesh]: `V - E + F`.
+    ///
+    /// `V` is the number of vertices used by faces (unused vertices are skipped), `E` is the
+    /// number of unique undirected edges and `F` is the number of faces.
+    ///
+    /// It's a cheap topology sanity check: e.g. closed sphere-like [Mesh] has 2, torus has 0
+    /// and disk-like patch has 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    ///
+    /// assert_eq!(tetrahedron.get_euler_characteristic(), 2);
+    /// ```
+    pub fn get_euler_characteristic(&self) -> i64 {
+        let number_of_used_vertices = self.indices.iter().collect::<HashSet<_>>().len() as i64;
+        let number_of_edges = EdgeAdjacency::from_mesh(self).get_number_of_edges() as i64;
+        let number_of_faces = self.get_number_of_faces() as i64;
+
+        number_of_used_vertices - number_of_edges + number_of_faces
+    }
+
+    /// Gets genus (number of handles) of the [Mesh].
+    ///
+    /// It's defined only for closed, orientable manifolds, so `None` is returned if the [Mesh] isn't
+    /// watertight (see [Mesh::is_watertight]) or it has non-manifold vertices. For many connected
+    /// components the sum of their genera is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    /// let open = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3]
+    /// );
+    ///
+    /// assert_eq!(tetrahedron.get_genus(), Some(0));
+    /// assert_eq!(open.get_genus(), None);
+    /// ```
+    pub fn get_genus(&self) -> Option<usize> {
+        if !self.is_watertight() || !self.get_non_manifold_vertices().is_empty() {
+            return None;
+        }
+
+        let doubled_genus = 2 * self.get_number_of_connected_components() as i64 - self.get_euler_characteristic(); // For each component: χ = 2 - 2g
+        if doubled_genus < 0 || doubled_genus % 2 != 0 {
+            return None;
+        }
+
+        Some((doubled_genus / 2) as usize)
+    }
+
     /// Calculates the Bounding Box (AABB) for given [Mesh]
     ///
     /// # Example
@@ -1146,117 +1356,1161 @@ impl Mesh {
 
         non_manifold_edges
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_is_connected_false() {
-        let input = Mesh::new(
-            vec![0.0, 0.0, 0.0,
-                 2.5, 5.0, 0.0,
-                 5.0, 0.0, 0.0,
-                 7.5, 5.0, 0.0,
-                 10.0, 0.0, 0.0,
-                 5.0, 10.0, 0.0,
-                 5.0, 5.0, 3.0,
-                 2.5, 5.0, 3.0,
-                 0.0, 0.0, 3.0,
-                 ],
-            vec![0, 2, 1, // first face
-                 1, 2, 3, // second face
-                 2, 4, 3, // third face
-                 1, 3, 5, // fourth face
-                 7, 8, 6, // fifth face separated
-                 ]
-        );
-        
-        assert!(!input.is_connected());
-    }
+    /// Gets ids of non-manifold vertices of the [Mesh].
+    ///
+    /// Vertex is non-manifold if it's a part of an edge used by more than 2 faces, or if faces
+    /// around it don't create a single fan connected through the edges coming out of this vertex
+    /// (e.g. 2 triangles touching only with 1 vertex, the so-called bow-tie).
+    ///
+    /// Returned ids are sorted. Boundary vertices with a single fan are manifold.
+    ///
+    /// To check which faces share an edge it looks for same indexes of same vertices, that's why it's
+    /// good to do welding of vertices before.
+    ///
+    /// # Example
+    ///
+    /// Here is an example with 2 triangles touching only with the vertex 2.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          1.0, 0.0, 0.0,
+    ///          1.0, 1.0, 0.0,
+    ///          2.0, 1.0, 0.0,
+    ///          2.0, 2.0, 0.0,
+    ///          ],
+    ///     vec![0, 1, 2, // first face
+    ///          2, 3, 4, // second face
+    ///          ]
+    /// );
+    ///
+    /// let actual = input.get_non_manifold_vertices();
+    ///
+    /// assert_eq!(actual, vec![2]);
+    /// ```
+    pub fn get_non_manifold_vertices(&self) -> Vec<usize> {
+        let adjacency = EdgeAdjacency::from_mesh(self);
+        let mut vertex_faces: Vec<Vec<usize>> = vec![Vec::new(); self.get_number_of_vertices()];
+        for (face_id, face) in self.indices.chunks_exact(3).enumerate() {
+            for vertex_id in face {
+                if !vertex_faces[*vertex_id].contains(&face_id) {
+                    vertex_faces[*vertex_id].push(face_id);
+                }
+            }
+        }
 
-    #[test]
-    fn test_is_connected_true() {
-        let input = Mesh::new(
-            vec![0.0, 0.0, 0.0,
-                 2.5, 5.0, 0.0,
-                 5.0, 0.0, 0.0,
-                 7.5, 5.0, 0.0,
-                 10.0, 0.0, 0.0,
-                 5.0, 10.0, 0.0,
-                 ],
-            vec![0, 2, 1, // first face
-                 1, 2, 3, // second face
-                 2, 4, 3, // third face
-                 1, 3, 5, // fourth face
-                 ]
-        );
-        
-        assert!(input.is_connected());
-    }
+        let mut non_manifold_vertices: HashSet<usize> = HashSet::new();
+        for edge in adjacency.get_edges_with_more_than_2_faces() {
+            non_manifold_vertices.insert(edge.start);
+            non_manifold_vertices.insert(edge.end);
+        }
 
-    #[test]
-    fn test_get_number_of_faces() {
-        let input = Mesh::new(
-            vec![
-                // Base
-                -2.0,1.0,0.0,
-                8.0,1.0,0.0,
-                8.0,11.0,0.0,
-                -2.0,11.0,0.0,
+        for (vertex_id, faces) in vertex_faces.iter().enumerate() {
+            if faces.len() < 2 || non_manifold_vertices.contains(&vertex_id) {
+                continue;
+            }
 
-                // Top
-                3.0,6.0,4.0
-            ],
-            vec![
-                // Base faces
-                0,1,2,
-                0,2,3,
+            let mut visited = vec![false; faces.len()]; // Walking around the fan starting from the first face
+            visited[0] = true;
+            let mut number_of_visited = 1;
+            let mut stack = vec![faces[0]];
+            while let Some(face_id) = stack.pop() {
+                for edge in adjacency.get_edges_of_face(face_id) {
+                    if edge.start != vertex_id && edge.end != vertex_id {
+                        continue;
+                    }
+                    for neighbour_face_id in adjacency.get_faces_of_edge(&edge) {
+                        let position = faces.iter().position(|f| f == neighbour_face_id).unwrap();
+                        if !visited[position] {
+                            visited[position] = true;
+                            number_of_visited += 1;
+                            stack.push(*neighbour_face_id);
+                        }
+                    }
+                }
+            }
 
-                // Side faces
-                0,1,4,
-                1,2,4,
-                2,3,4,
-                3,0,4
-            ]);
+            if number_of_visited != faces.len() {
+                non_manifold_vertices.insert(vertex_id);
+            }
+        }
 
-        let expected = 6;
-        let actual = input.get_number_of_faces();
-        assert_eq!(expected, actual);
+        let mut non_manifold_vertices: Vec<usize> = non_manifold_vertices.into_iter().collect();
+        non_manifold_vertices.sort();
+        non_manifold_vertices
     }
 
-    #[test]
-    fn test_get_number_of_vertices() {
-        let input = Mesh::new(
-            vec![
-                // Base
-                -2.0,1.0,0.0,
-                8.0,1.0,0.0,
-                8.0,11.0,0.0,
-                -2.0,11.0,0.0,
+    /// Gets boundary loops of the [Mesh] as [Polygon]s.
+    ///
+    /// Boundary loop is a closed chain of edges which have only 1 neighbour face. It's the same
+    /// extraction [Mesh::get_planar_simplify] uses to find outlines and holes of planar regions,
+    /// so it can be used to diagnose why such a region is refused.
+    ///
+    /// Loops are followed along the direction of boundary edges, so the [Mesh] should have
+    /// a consistent winding. It also expects every boundary vertex to be used by exactly 2 boundary
+    /// edges, chains which are shorter than 3 vertices are skipped. Closed [Mesh] gives no loops.
+    ///
+    /// To check which faces share an edge it looks for same indexes of same vertices, that's why it's
+    /// good to do welding of vertices before.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          1.0, 0.0, 0.0,
+    ///          1.0, 1.0, 0.0,
+    ///          0.0, 1.0, 0.0,
+    ///          ],
+    ///     vec![0, 1, 2, // first face
+    ///          0, 2, 3, // second face
+    ///          ]
+    /// );
+    ///
+    /// let actual = input.get_boundary_loops();
+    ///
+    /// assert_eq!(actual.len(), 1);
+    /// assert_eq!(actual[0].vertices.len(), 4);
+    /// ```
+    pub fn get_boundary_loops(&self) -> Vec<Polygon> {
+        let disconnected_parts = self.get_boundary_loops_vertex_ids();
+
+        let mut polygons = Vec::with_capacity(disconnected_parts.len()); // Converting grouped edges to Polygons
+        for disconnected_part in disconnected_parts {
+            let mut polygon_vertices = Vec::new();
+            for i in disconnected_part {
+                let vertex = Point::new(self.coordinates[i*3], self.coordinates[i*3+1], self.coordinates[i*3+2]);
+                polygon_vertices.push(vertex)
+            }
+            polygons.push(Polygon::new(polygon_vertices))
+        }
 
-                // Top
-                3.0,6.0,4.0
-            ],
-            vec![
-                // Base faces
-                0,1,2,
-                0,2,3,
+        polygons
+    }
 
-                // Side faces
-                0,1,4,
-                1,2,4,
-                2,3,4,
-                3,0,4
-            ]);
+    /// Gets boundary loops of the [Mesh] as ids of their vertices, in the order of boundary
+    /// edges, see [Mesh::get_boundary_loops].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = input.get_boundary_loops_vertex_ids();
+    ///
+    /// assert_eq!(actual.len(), 1);
+    /// assert_eq!(actual[0].len(), 4);
+    /// ```
+    pub fn get_boundary_loops_vertex_ids(&self) -> Vec<Vec<usize>> {
+        let edges = self.get_edges_with_missing_neighbour(); // Getting Graph and calculating grouped edges
+        let graph = Graph::new(self.get_number_of_vertices(), edges);
 
-        let expected = 5;
-        let actual = input.get_number_of_vertices();
-        assert_eq!(expected, actual);
+        graph.split_disconnected_loops()
     }
 
-    #[test]
+    /// Suggests seams (cut edges) for unwrapping the [Mesh], e.g. with LSCM.
+    ///
+    /// First the [Mesh] is split into charts along sharp edges: edges where the angle between
+    /// neighbouring faces' normals is higher than `max_angle` (in radians). Such high-curvature
+    /// edges are natural places for seams, as cutting there introduces little distortion and
+    /// seams are less visible. Then each chart is cut further, so it becomes a topological disk
+    /// (closed charts and charts with handles cannot be flattened otherwise).
+    ///
+    /// Returned [Edge]s are the edges between faces of different resulting patches. They can
+    /// be edited by the user before unwrapping. Each [Edge] has the orientation taken from the first
+    /// face using it, and the list is sorted.
+    ///
+    /// The input [Mesh] should be welded, boundary edges are never returned as they're already cut.
+    ///
+    /// # Example
+    ///
+    /// Here is an example of the closed pyramid. With `max_angle` bigger than any angle between
+    /// its faces it has to be cut anyway, as it's closed.
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         -2.0,1.0,0.0,
+    ///         8.0,1.0,0.0,
+    ///         8.0,11.0,0.0,
+    ///         -2.0,11.0,0.0,
+    ///         3.0,6.0,4.0
+    ///     ],
+    ///     vec![
+    ///         0,2,1,
+    ///         0,3,2,
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// let actual = input.suggest_seams(std::f64::consts::PI);
+    ///
+    /// let expected = vec![Edge::new(2, 4), Edge::new(3, 2), Edge::new(3, 4)];
+    ///
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn suggest_seams(&self, max_angle: f64) -> Vec<Edge> {
+        let number_of_faces = self.get_number_of_faces();
+        let triangles = self.to_triangles();
+        let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for face_id in 0..number_of_faces {
+            for edge in Self::get_face_edge_keys(&self.indices, face_id) {
+                edge_faces.entry(edge).or_default().push(face_id);
+            }
+        }
+
+        let mut charts: Vec<Option<usize>> = vec![None; number_of_faces]; // Flood fill over smooth edges
+        let mut number_of_charts = 0;
+        for seed in 0..number_of_faces {
+            if charts[seed].is_some() {
+                continue;
+            }
+            charts[seed] = Some(number_of_charts);
+            let mut stack = vec![seed];
+            while let Some(face_id) = stack.pop() {
+                for edge in Self::get_face_edge_keys(&self.indices, face_id) {
+                    let faces = &edge_faces[&edge];
+                    if faces.len() != 2 {
+                        continue;
+                    }
+                    let neighbour = if faces[0] == face_id { faces[1] } else { faces[0] };
+                    if charts[neighbour].is_none() && triangles[face_id].get_normals_angle(&triangles[neighbour]) <= max_angle {
+                        charts[neighbour] = Some(number_of_charts);
+                        stack.push(neighbour);
+                    }
+                }
+            }
+            number_of_charts += 1;
+        }
+        let charts: Vec<usize> = charts.into_iter().map(|chart| chart.unwrap()).collect();
+
+        let patches = self.get_disk_patches_face_ids_within_groups(&charts);
+        let mut face_patch = vec![0; number_of_faces];
+        for (patch_id, patch) in patches.iter().enumerate() {
+            for face_id in patch {
+                face_patch[*face_id] = patch_id;
+            }
+        }
+
+        let mut seams = Vec::new();
+        for (edge, faces) in &edge_faces {
+            if faces.len() < 2 || faces.iter().all(|face_id| face_patch[*face_id] == face_patch[faces[0]]) {
+                continue;
+            }
+            let first_face = faces[0];
+            let face = &self.indices[first_face * 3..first_face * 3 + 3];
+            let oriented = (0..3).map(|i| Edge::new(face[i], face[(i + 1) % 3])).find(|e| e.start.min(e.end) == edge.0 && e.start.max(e.end) == edge.1).unwrap();
+            seams.push(oriented);
+        }
+        seams.sort();
+
+        seams
+    }
+
+    /// Validates the [Mesh] which is an offset of the `original` one.
+    ///
+    /// It checks if:
+    /// - all the vertices and face centroids of this [Mesh] are located at `distance` from the
+    ///   `original` [Mesh] (± `tolerance`),
+    /// - there are no self-intersections, which usually means the offset folded over itself.
+    ///
+    /// Faces touching only at shared vertices or edges are not treated as intersecting.
+    ///
+    /// The result is the [OffsetValidationReport] with all the issues found.
+    ///
+    /// # Example
+    ///
+    /// Here is an example with the flat square offset correctly by 1.0 up.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let original = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         10.0,0.0,0.0,
+    ///         10.0,10.0,0.0,
+    ///         0.0,10.0,0.0,
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///         0,2,3
+    ///     ]);
+    ///
+    /// let offset = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,1.0,
+    ///         10.0,0.0,1.0,
+    ///         10.0,10.0,1.0,
+    ///         0.0,10.0,1.0,
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///         0,2,3
+    ///     ]);
+    ///
+    /// let actual = offset.validate_offset(&original, 1.0, 0.001);
+    ///
+    /// assert!(actual.is_valid());
+    /// assert!((actual.minimum_distance - 1.0).abs() < 0.001);
+    /// assert!((actual.maximum_distance - 1.0).abs() < 0.001);
+    /// ```
+    pub fn validate_offset(&self, original: &Mesh, distance: f64, tolerance: f64) -> OffsetValidationReport {
+        let original_triangles = original.to_triangles();
+        let expected_distance = distance.abs();
+        let get_distance_to_original = |point: &Point| -> f64 {
+            original_triangles.iter()
+                .map(|triangle| point.get_distance_to_point(&triangle.get_closest_point(point)))
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        let mut minimum_distance = f64::INFINITY;
+        let mut maximum_distance = f64::NEG_INFINITY;
+
+        let mut vertex_ids_out_of_bounds = Vec::new();
+        for (vertex_id, point) in self.to_points().iter().enumerate() {
+            let current_distance = get_distance_to_original(point);
+            minimum_distance = minimum_distance.min(current_distance);
+            maximum_distance = maximum_distance.max(current_distance);
+            if (current_distance - expected_distance).abs() > tolerance {
+                vertex_ids_out_of_bounds.push(vertex_id);
+            }
+        }
+
+        let triangles = self.to_triangles();
+        let mut face_ids_out_of_bounds = Vec::new();
+        for (face_id, triangle) in triangles.iter().enumerate() {
+            let current_distance = get_distance_to_original(&triangle.get_centroid());
+            minimum_distance = minimum_distance.min(current_distance);
+            maximum_distance = maximum_distance.max(current_distance);
+            if (current_distance - expected_distance).abs() > tolerance {
+                face_ids_out_of_bounds.push(face_id);
+            }
+        }
+
+        let self_intersecting_face_pairs = Self::get_intersecting_face_pairs(&triangles, tolerance);
+
+        OffsetValidationReport {
+            minimum_distance,
+            maximum_distance,
+            vertex_ids_out_of_bounds,
+            face_ids_out_of_bounds,
+            self_intersecting_face_pairs,
+        }
+    }
+
+    /// Validates the [Mesh] and gets the [ValidationReport] with all the issues found.
+    ///
+    /// It looks for:
+    /// - indices which don't make a full face, or point to vertices which don't exist,
+    /// - vertices with NaN or infinite coordinates,
+    /// - faces with area smaller or equal to `zero_area_tolerance` of [ValidationOptions],
+    /// - duplicate faces, using the same 3 vertices,
+    /// - non-manifold edges, used by more than 2 faces,
+    /// - edges between faces with inconsistent winding,
+    /// - boundary edges, unless open boundaries are allowed in [ValidationOptions].
+    ///
+    /// Faces with out of range indices or non-finite vertices are skipped when their area is
+    /// checked, so this method doesn't panic for any input.
+    ///
+    /// Same as other topology methods, it looks for same indexes of same vertices, that's why
+    /// it's good to do welding of vertices before.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::validation_options::ValidationOptions;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          1.0, 0.0, 0.0,
+    ///          1.0, 1.0, 0.0,
+    ///          0.0, 1.0, 0.0,
+    ///          ],
+    ///     vec![0, 1, 2, // first face
+    ///          0, 3, 2, // second face, flipped
+    ///          ]
+    /// );
+    ///
+    /// let actual = input.validate(ValidationOptions::new(0.0, true));
+    ///
+    /// assert!(!actual.is_valid());
+    /// assert_eq!(actual.inconsistent_winding_edges, vec![Edge::new(0, 2)]);
+    /// assert!(actual.boundary_edges.is_empty());
+    /// ```
+    pub fn validate(&self, options: ValidationOptions) -> ValidationReport {
+        let number_of_vertices = self.get_number_of_vertices();
+        let number_of_faces = self.get_number_of_faces();
+
+        let out_of_range_index_positions: Vec<usize> = (0..number_of_faces * 3)
+            .filter(|&position| self.indices[position] >= number_of_vertices)
+            .collect();
+
+        let non_finite_vertex_ids: Vec<usize> = (0..number_of_vertices)
+            .filter(|&vertex_id| self.coordinates[vertex_id * 3..vertex_id * 3 + 3].iter().any(|coordinate| !coordinate.is_finite()))
+            .collect();
+
+        let mut zero_area_face_ids = Vec::new();
+        let mut duplicate_face_pairs = Vec::new();
+        let mut first_faces: HashMap<[usize; 3], usize> = HashMap::new();
+        for (face_id, (first, second, third)) in self.faces().enumerate() {
+            let mut sorted = [first, second, third];
+            sorted.sort();
+            match first_faces.get(&sorted) {
+                Some(&first_face_id) => duplicate_face_pairs.push((first_face_id, face_id)),
+                None => { first_faces.insert(sorted, face_id); },
+            }
+
+            if sorted[2] >= number_of_vertices {
+                continue;
+            }
+            let get_point = |vertex_id: usize| Point::new(self.coordinates[vertex_id * 3], self.coordinates[vertex_id * 3 + 1], self.coordinates[vertex_id * 3 + 2]);
+            let area = Triangle::new(get_point(first), get_point(second), get_point(third)).get_area();
+            if area <= options.zero_area_tolerance {
+                zero_area_face_ids.push(face_id);
+            }
+        }
+
+        let adjacency = EdgeAdjacency::from_mesh(self);
+
+        ValidationReport {
+            number_of_dangling_indices: self.indices.len() % 3,
+            out_of_range_index_positions,
+            non_finite_vertex_ids,
+            zero_area_face_ids,
+            duplicate_face_pairs,
+            non_manifold_edges: adjacency.get_edges_with_more_than_2_faces(),
+            inconsistent_winding_edges: adjacency.get_inconsistent_winding_edges(),
+            boundary_edges: if options.allow_open_boundaries { Vec::new() } else { adjacency.get_boundary_edges() },
+        }
+    }
+
+    /// Gets the [QualityReport] of shapes of faces of the [Mesh]: histograms of their min angles,
+    /// skewness, aspect ratios and edge length ratios, together with the ids of
+    /// `number_of_worst_faces` worst faces for each of these metrics.
+    ///
+    /// Degenerate faces are counted as the worst possible ones. Faces with the same value of
+    /// the metric are listed in the order of their ids.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          2.0, 0.0, 0.0,
+    ///          1.0, f64::sqrt(3.0), 0.0,
+    ///          12.0, 0.0, 0.0,
+    ///          7.0, 0.1, 0.0,
+    ///          ],
+    ///     vec![0, 1, 2, // equilateral face
+    ///          1, 3, 4, // long and thin face
+    ///          ]
+    /// );
+    ///
+    /// let actual = input.get_quality_report(1);
+    ///
+    /// assert_eq!(actual.get_number_of_faces(), 2);
+    /// assert_eq!(actual.min_angle_histogram, [1, 0, 0, 0, 0, 1]);
+    /// assert_eq!(actual.worst_min_angle_face_ids, vec![1]);
+    /// assert_eq!(actual.worst_aspect_ratio_face_ids, vec![1]);
+    /// ```
+    pub fn get_quality_report(&self, number_of_worst_faces: usize) -> QualityReport {
+        let triangles = self.to_triangles();
+        let min_angles: Vec<f64> = triangles.iter().map(|triangle| triangle.get_min_angle()).collect();
+        let skewnesses: Vec<f64> = triangles.iter().map(|triangle| triangle.get_skewness()).collect();
+        let aspect_ratios: Vec<f64> = triangles.iter().map(|triangle| triangle.get_aspect_ratio()).collect();
+        let edge_length_ratios: Vec<f64> = triangles.iter().map(|triangle| triangle.get_edge_length_ratio()).collect();
+
+        let mut min_angle_histogram = [0; 6];
+        for min_angle in &min_angles {
+            min_angle_histogram[((min_angle.to_degrees() / 10.0) as usize).min(5)] += 1;
+        }
+        let mut skewness_histogram = [0; 10];
+        for skewness in &skewnesses {
+            skewness_histogram[((skewness * 10.0) as usize).min(9)] += 1;
+        }
+        let get_ratio_bin = |ratio: &f64| RATIO_HISTOGRAM_BOUNDS.iter().position(|bound| *ratio < *bound).unwrap_or(RATIO_HISTOGRAM_BOUNDS.len());
+        let mut aspect_ratio_histogram = [0; 6];
+        for aspect_ratio in &aspect_ratios {
+            aspect_ratio_histogram[get_ratio_bin(aspect_ratio)] += 1;
+        }
+        let mut edge_length_ratio_histogram = [0; 6];
+        for edge_length_ratio in &edge_length_ratios {
+            edge_length_ratio_histogram[get_ratio_bin(edge_length_ratio)] += 1;
+        }
+
+        QualityReport {
+            min_angle_histogram,
+            skewness_histogram,
+            aspect_ratio_histogram,
+            edge_length_ratio_histogram,
+            worst_min_angle_face_ids: Mesh::get_worst_face_ids(&min_angles, number_of_worst_faces, false),
+            worst_skewness_face_ids: Mesh::get_worst_face_ids(&skewnesses, number_of_worst_faces, true),
+            worst_aspect_ratio_face_ids: Mesh::get_worst_face_ids(&aspect_ratios, number_of_worst_faces, true),
+            worst_edge_length_ratio_face_ids: Mesh::get_worst_face_ids(&edge_length_ratios, number_of_worst_faces, true),
+        }
+    }
+
+    /// Gets ids of `number_of_worst_faces` faces with the worst values of the metric. The worst
+    /// values are the biggest ones if `bigger_is_worse` is `true`, otherwise the smallest ones.
+    fn get_worst_face_ids(values: &[f64], number_of_worst_faces: usize, bigger_is_worse: bool) -> Vec<usize> {
+        let mut face_ids: Vec<usize> = (0..values.len()).collect();
+        if bigger_is_worse {
+            face_ids.sort_by(|a, b| values[*b].total_cmp(&values[*a]));
+        }
+        else {
+            face_ids.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+        }
+        face_ids.truncate(number_of_worst_faces);
+
+        face_ids
+    }
+
+    /// Gets the [SurfaceDistance] between this [Mesh] and the `other` one: one-sided and
+    /// symmetric Hausdorff, mean and root mean square distances.
+    ///
+    /// Points are sampled on each face on a regular barycentric grid: every side of the face is
+    /// split into `number_of_subdivisions` segments (`1` means only vertices of faces are used).
+    /// For each sample the closest point on the other Mesh is found. Mean and RMS distances are
+    /// weighted by areas of faces, so they don't depend on how dense the triangulation is.
+    ///
+    /// Hausdorff distance is approximated from the samples, so more subdivisions make it more
+    /// accurate, but slower.
+    ///
+    /// `None` is returned if any of the Meshes has no faces.
+    ///
+    /// # Example
+    ///
+    /// Here is an example of the square and the same square moved 0.5 up.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    /// let other = Mesh::new(
+    ///     vec![0.0, 0.0, 0.5, 10.0, 0.0, 0.5, 10.0, 10.0, 0.5, 0.0, 10.0, 0.5],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = input.distance_to(&other, 4).unwrap();
+    ///
+    /// assert!((actual.get_hausdorff_distance() - 0.5).abs() < 1e-12);
+    /// assert!((actual.get_rms_distance() - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn distance_to(&self, other: &Mesh, number_of_subdivisions: usize) -> Option<SurfaceDistance> {
+        if self.get_number_of_faces() == 0 || other.get_number_of_faces() == 0 {
+            return None;
+        }
+
+        let (max_distance_to_other, mean_distance_to_other, rms_distance_to_other) = self.get_one_sided_distances_to(other, number_of_subdivisions);
+        let (max_distance_from_other, mean_distance_from_other, rms_distance_from_other) = other.get_one_sided_distances_to(self, number_of_subdivisions);
+
+        Some(SurfaceDistance {
+            max_distance_to_other,
+            mean_distance_to_other,
+            rms_distance_to_other,
+            max_distance_from_other,
+            mean_distance_from_other,
+            rms_distance_from_other,
+        })
+    }
+
+    /// Gets max, mean and RMS distances from points sampled on this [Mesh] to the `other` one,
+    /// see [Mesh::distance_to].
+    fn get_one_sided_distances_to(&self, other: &Mesh, number_of_subdivisions: usize) -> (f64, f64, f64) {
+        let number_of_subdivisions = number_of_subdivisions.max(1);
+        let other_triangles = other.to_triangles();
+        let triangles = self.to_triangles();
+        let areas: Vec<f64> = triangles.iter().map(|triangle| triangle.get_area().max(0.0)).collect(); // NaN for slightly degenerate faces becomes 0.0
+        let is_weighted_by_area = areas.iter().sum::<f64>() > 0.0;
+
+        let mut max_distance: f64 = 0.0;
+        let mut weighted_sum = 0.0;
+        let mut weighted_squared_sum = 0.0;
+        let mut sum_of_weights = 0.0;
+        for (triangle, area) in triangles.iter().zip(areas) {
+            let samples = Mesh::get_triangle_samples(triangle, number_of_subdivisions);
+            let weight = if is_weighted_by_area { area / samples.len() as f64 } else { 1.0 };
+            for sample in &samples {
+                let distance = other_triangles.iter()
+                    .map(|other_triangle| sample.get_distance_to_point(&other_triangle.get_closest_point(sample)))
+                    .fold(f64::INFINITY, f64::min);
+                max_distance = max_distance.max(distance);
+                weighted_sum += weight * distance;
+                weighted_squared_sum += weight * distance * distance;
+                sum_of_weights += weight;
+            }
+        }
+
+        (max_distance, weighted_sum / sum_of_weights, (weighted_squared_sum / sum_of_weights).sqrt())
+    }
+
+    /// Gets points of the regular barycentric grid on the [Triangle], with each side split into
+    /// `number_of_subdivisions` segments.
+    fn get_triangle_samples(triangle: &Triangle, number_of_subdivisions: usize) -> Vec<Point> {
+        let a = &triangle.first_point;
+        let b = &triangle.second_point;
+        let c = &triangle.third_point;
+        let n = number_of_subdivisions as f64;
+
+        let mut samples = Vec::with_capacity((number_of_subdivisions + 1) * (number_of_subdivisions + 2) / 2);
+        for i in 0..=number_of_subdivisions {
+            for j in 0..=(number_of_subdivisions - i) {
+                let u = i as f64 / n;
+                let v = j as f64 / n;
+                let w = 1.0 - u - v;
+                samples.push(Point::new(
+                    a.x * u + b.x * v + c.x * w,
+                    a.y * u + b.y * v + c.y * w,
+                    a.z * u + b.z * v + c.z * w,
+                ));
+            }
+        }
+
+        samples
+    }
+
+    /// Gets pairs of ids of intersecting [Triangle]s, sorted.
+    ///
+    /// It uses a sweep along X axis over bounding boxes to skip the pairs which are far apart.
+    pub(crate) fn get_intersecting_face_pairs(triangles: &[Triangle], epsilon: f64) -> Vec<(usize, usize)> {
+        let bounding_boxes: Vec<BoundingBox> = triangles.iter()
+            .map(|t| {
+                let points = [t.first_point, t.second_point, t.third_point];
+                BoundingBox::new(
+                    points.iter().map(|p| p.x).fold(f64::INFINITY, f64::min), points.iter().map(|p| p.x).fold(f64::NEG_INFINITY, f64::max),
+                    points.iter().map(|p| p.y).fold(f64::INFINITY, f64::min), points.iter().map(|p| p.y).fold(f64::NEG_INFINITY, f64::max),
+                    points.iter().map(|p| p.z).fold(f64::INFINITY, f64::min), points.iter().map(|p| p.z).fold(f64::NEG_INFINITY, f64::max),
+                )
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..triangles.len()).collect();
+        order.sort_by(|a, b| bounding_boxes[*a].min_x.total_cmp(&bounding_boxes[*b].min_x));
+
+        let mut pairs = Vec::new();
+        for (i, first) in order.iter().enumerate() {
+            let first_box = &bounding_boxes[*first];
+            for second in order.iter().skip(i + 1) {
+                let second_box = &bounding_boxes[*second];
+                if second_box.min_x > first_box.max_x + epsilon {
+                    break;
+                }
+                if second_box.min_y > first_box.max_y + epsilon || second_box.max_y < first_box.min_y - epsilon ||
+                    second_box.min_z > first_box.max_z + epsilon || second_box.max_z < first_box.min_z - epsilon {
+                    continue;
+                }
+                if triangles[*first].intersects_triangle(&triangles[*second], epsilon) {
+                    pairs.push(((*first).min(*second), (*first).max(*second)));
+                }
+            }
+        }
+        pairs.sort();
+
+        pairs
+    }
+
+    /// Gets unitized normal [Vector]s of all faces, with `None` for degenerate (zero-area)
+    /// faces, where the normal is not defined.
+    ///
+    /// See [Triangle::is_degenerate] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          10.0, 0.0, 0.0,
+    ///          10.0, 10.0, 0.0,
+    ///          20.0, 0.0, 0.0],
+    ///     vec![0, 1, 2, // correct
+    ///          0, 1, 3, // collinear
+    ///     ]);
+    ///
+    /// let actual = input.get_face_normal_vectors_unitized_checked();
+    ///
+    /// assert_eq!(actual, vec![Some(Vector::new(0.0, 0.0, 1.0)), None]);
+    /// ```
+    pub fn get_face_normal_vectors_unitized_checked(&self) -> Vec<Option<Vector>> {
+        let triangles = self.to_triangles();
+
+        triangles.iter().map(|triangle| triangle.get_normal_vector_unitized_checked()).collect()
+    }
+
+    /// Gets ids of the degenerate (zero-area) faces, which don't have a defined normal.
+    ///
+    /// These faces are skipped by normal-based analyses (e.g. their angles to neighbours are
+    /// treated as 0.0 in [Mesh::split_by_face_angle]).
+    ///
+    /// See [Triangle::is_degenerate] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          10.0, 0.0, 0.0,
+    ///          10.0, 10.0, 0.0,
+    ///          20.0, 0.0, 0.0],
+    ///     vec![0, 1, 2, // correct
+    ///          0, 1, 3, // collinear
+    ///          2, 2, 1, // repeated vertex
+    ///     ]);
+    ///
+    /// assert_eq!(input.get_degenerate_face_ids(), vec![1, 2]);
+    /// ```
+    pub fn get_degenerate_face_ids(&self) -> Vec<usize> {
+        self.to_triangles().iter().enumerate()
+            .filter(|(_, triangle)| triangle.is_degenerate())
+            .map(|(face_id, _)| face_id)
+            .collect()
+    }
+
+    /// Gets [MeshStatistics] of the [Mesh]: numbers of vertices, faces and edges, total area,
+    /// min, mean and max edge length, numbers of degenerate faces and boundary edges, and the
+    /// bounding box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          2.0, 0.0, 0.0,
+    ///          2.0, 2.0, 0.0,
+    ///          0.0, 2.0, 0.0],
+    ///     vec![0, 1, 2,
+    ///          0, 2, 3]);
+    ///
+    /// let actual = input.get_statistics();
+    ///
+    /// assert_eq!(actual.number_of_vertices, 4);
+    /// assert_eq!(actual.number_of_faces, 2);
+    /// assert_eq!(actual.number_of_edges, 5);
+    /// assert!((actual.area - 4.0).abs() < 1e-10);
+    /// assert_eq!(actual.min_edge_length, 2.0);
+    /// assert_eq!(actual.max_edge_length, 8.0_f64.sqrt());
+    /// assert_eq!(actual.number_of_degenerate_faces, 0);
+    /// assert_eq!(actual.number_of_boundary_edges, 4);
+    /// assert_eq!(actual.bounding_box, Some(BoundingBox::new(0.0, 2.0, 0.0, 2.0, 0.0, 0.0)));
+    /// ```
+    pub fn get_statistics(&self) -> MeshStatistics {
+        let adjacency = EdgeAdjacency::from_mesh(self);
+        let points = self.to_points();
+        let edge_lengths: Vec<f64> = adjacency.get_edges().iter()
+            .map(|edge| points[edge.start].get_distance_to_point(&points[edge.end]))
+            .collect();
+
+        let (min_edge_length, mean_edge_length, max_edge_length) = if edge_lengths.is_empty() {
+            (0.0, 0.0, 0.0)
+        } else {
+            (
+                edge_lengths.iter().copied().reduce(f64::min).unwrap(),
+                edge_lengths.iter().sum::<f64>() / edge_lengths.len() as f64,
+                edge_lengths.iter().copied().reduce(f64::max).unwrap(),
+            )
+        };
+
+        let bounding_box = if points.is_empty() {
+            None
+        } else {
+            Some(self.get_bounding_box())
+        };
+
+        MeshStatistics {
+            number_of_vertices: self.get_number_of_vertices(),
+            number_of_faces: self.get_number_of_faces(),
+            number_of_edges: edge_lengths.len(),
+            area: self.get_area(),
+            min_edge_length,
+            mean_edge_length,
+            max_edge_length,
+            number_of_degenerate_faces: self.get_degenerate_face_ids().len(),
+            number_of_boundary_edges: adjacency.get_boundary_edges().len(),
+            bounding_box,
+        }
+    }
+
+    /// Gets ids of the overhanging faces, which would need support when the [Mesh] is 3D
+    /// printed in the given `build_direction`.
+    ///
+    /// The overhang angle of a face is measured from the `build_direction`: vertical walls have
+    /// 0.0, faces looking straight down have PI/2. Faces looking down with the overhang angle
+    /// bigger than `max_angle` (in radians, usually PI/4) are returned. Faces lying on the build
+    /// plate (with all vertices at the lowest level of the [Mesh]) and degenerate faces are
+    /// skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          1.0, 0.0, 0.0,
+    ///          0.0, 1.0, 0.0,
+    ///          0.0, 0.0, 2.0,
+    ///          4.0, 0.0, 2.0,
+    ///          4.0, 4.0, 2.0,
+    ///          0.0, 4.0, 2.0],
+    ///     vec![0, 2, 1, // on the build plate
+    ///          3, 5, 4, // ceiling
+    ///          3, 6, 5, // ceiling
+    ///     ]);
+    ///
+    /// let actual = input.get_overhanging_faces(&Vector::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_4);
+    ///
+    /// assert_eq!(actual, vec![1, 2]);
+    /// ```
+    pub fn get_overhanging_faces(&self, build_direction: &Vector, max_angle: f64) -> Vec<usize> {
+        let heights: Vec<f64> = self.to_points().iter().map(|point| point.to_vector().get_dot_product(build_direction)).collect();
+        let lowest = heights.iter().copied().fold(f64::INFINITY, f64::min);
+        let highest = heights.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let plate_tolerance = (highest - lowest) * 1e-9;
+        let down = build_direction.get_reversed();
+        let max_angle_to_down = std::f64::consts::FRAC_PI_2 - max_angle;
+
+        self.get_face_normal_vectors_unitized_checked().iter().enumerate()
+            .filter(|(_, normal)| normal.is_some_and(|normal| normal.get_angle(&down) < max_angle_to_down))
+            .filter(|(face_id, _)| !self.indices[face_id * 3..face_id * 3 + 3].iter().all(|vertex_id| heights[*vertex_id] - lowest <= plate_tolerance))
+            .map(|(face_id, _)| face_id)
+            .collect()
+    }
+
+    /// Gets the total area of the overhanging faces, see [Mesh::get_overhanging_faces].
+    ///
+    /// It's a quick way to compare different print orientations: the smaller the area, the less
+    /// support is needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0,
+    ///          1.0, 0.0, 0.0,
+    ///          0.0, 1.0, 0.0,
+    ///          0.0, 0.0, 2.0,
+    ///          4.0, 0.0, 2.0,
+    ///          4.0, 4.0, 2.0,
+    ///          0.0, 4.0, 2.0],
+    ///     vec![0, 2, 1, // on the build plate
+    ///          3, 5, 4, // ceiling
+    ///          3, 6, 5, // ceiling
+    ///     ]);
+    ///
+    /// let actual = input.get_overhang_area(&Vector::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_4);
+    ///
+    /// assert!((actual - 16.0).abs() < 1e-9);
+    /// ```
+    pub fn get_overhang_area(&self, build_direction: &Vector, max_angle: f64) -> f64 {
+        let triangles = self.to_triangles();
+
+        self.get_overhanging_faces(build_direction, max_angle).iter().map(|face_id| triangles[*face_id].get_area()).sum()
+    }
+
+    /// Compares this [Mesh] with the `other` one, reporting where exactly they differ, see
+    /// [MeshDiff].
+    ///
+    /// Vertices are compared by their ids: vertices with the same id are moved if they are
+    /// further apart than the `tolerance`, surplus vertices at the end are added or removed.
+    ///
+    /// Faces are compared by their indices, regardless of which vertex the face starts with, but
+    /// keeping the winding (so flipped faces are removed and added). Faces of the `other` Mesh
+    /// existing in this one, but with any vertex moved, are moved.
+    ///
+    /// Changed faces are grouped through shared edges into regions, so the result tells where
+    /// the changes are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         -2.0,1.0,0.0,
+    ///         8.0,1.0,0.0,
+    ///         8.0,11.0,0.0,
+    ///         -2.0,11.0,0.0,
+    ///         3.0,6.0,4.0
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///         0,2,3,
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// let other = Mesh::new(
+    ///     vec![
+    ///         -2.0,1.0,0.0,
+    ///         8.0,1.0,0.0,
+    ///         8.0,11.0,0.0,
+    ///         -2.0,11.0,0.0,
+    ///         3.0,6.0,10.0 // Top of the pyramid moved up
+    ///     ],
+    ///     vec![
+    ///         1,4,0,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4 // Bottom faces removed
+    ///     ]);
+    ///
+    /// let actual = input.diff(&other, 0.001);
+    ///
+    /// assert_eq!(actual.moved_vertex_ids, vec![4]);
+    /// assert_eq!(actual.max_vertex_distance, 6.0);
+    /// assert_eq!(actual.removed_face_ids, vec![0, 1]);
+    /// assert!(actual.added_face_ids.is_empty());
+    /// assert_eq!(actual.moved_face_ids, vec![0, 1, 2, 3]);
+    /// assert_eq!(actual.changed_regions, vec![
+    ///     BoundingBox::new(-2.0, 8.0, 1.0, 11.0, 0.0, 10.0),
+    ///     BoundingBox::new(-2.0, 8.0, 1.0, 11.0, 0.0, 0.0),
+    /// ]);
+    /// ```
+    pub fn diff(&self, other: &Mesh, tolerance: f64) -> MeshDiff {
+        let number_of_vertices = self.get_number_of_vertices();
+        let other_number_of_vertices = other.get_number_of_vertices();
+        let points = self.to_points();
+        let other_points = other.to_points();
+
+        let mut is_moved = vec![false; other_number_of_vertices];
+        let mut moved_vertex_ids = Vec::new();
+        let mut max_vertex_distance: f64 = 0.0;
+        for (vertex_id, (point, other_point)) in points.iter().zip(other_points.iter()).enumerate() {
+            let distance = point.get_distance_to_point(other_point);
+            max_vertex_distance = max_vertex_distance.max(distance);
+            if distance > tolerance {
+                is_moved[vertex_id] = true;
+                moved_vertex_ids.push(vertex_id);
+            }
+        }
+
+        let get_face = |mesh: &Mesh, face_id: usize| {
+            let face = [mesh.indices[face_id * 3], mesh.indices[face_id * 3 + 1], mesh.indices[face_id * 3 + 2]];
+            let first = (0..3).min_by_key(|i| face[*i]).unwrap();
+            [face[first], face[(first + 1) % 3], face[(first + 2) % 3]]
+        };
+        let mut face_ids: HashMap<[usize; 3], Vec<usize>> = HashMap::new(); // Face -> its ids, descending, so the first one is popped from the end
+        for face_id in (0..self.get_number_of_faces()).rev() {
+            face_ids.entry(get_face(self, face_id)).or_default().push(face_id);
+        }
+
+        let mut added_face_ids = Vec::new();
+        let mut moved_face_ids = Vec::new();
+        let mut is_matched = vec![false; self.get_number_of_faces()];
+        for other_face_id in 0..other.get_number_of_faces() {
+            let face = get_face(other, other_face_id);
+            match face_ids.get_mut(&face).and_then(|ids| ids.pop()) {
+                Some(face_id) => {
+                    is_matched[face_id] = true;
+                    if face.iter().any(|vertex_id| is_moved.get(*vertex_id).copied().unwrap_or(false)) {
+                        moved_face_ids.push(other_face_id);
+                    }
+                },
+                None => added_face_ids.push(other_face_id),
+            }
+        }
+        let removed_face_ids: Vec<usize> = (0..self.get_number_of_faces()).filter(|face_id| !is_matched[*face_id]).collect();
+
+        let mut changed_face_ids: Vec<usize> = added_face_ids.iter().chain(moved_face_ids.iter()).copied().collect();
+        changed_face_ids.sort();
+        let mut changed_regions: Vec<BoundingBox> = other.get_edge_connected_face_groups(&changed_face_ids).iter()
+            .map(|group| other.get_part_by_face_ids(group).get_bounding_box())
+            .collect();
+        changed_regions.extend(self.get_edge_connected_face_groups(&removed_face_ids).iter()
+            .map(|group| self.get_part_by_face_ids(group).get_bounding_box()));
+
+        MeshDiff {
+            moved_vertex_ids,
+            added_vertex_ids: (number_of_vertices..other_number_of_vertices).collect(),
+            removed_vertex_ids: (other_number_of_vertices..number_of_vertices).collect(),
+            max_vertex_distance,
+            added_face_ids,
+            removed_face_ids,
+            moved_face_ids,
+            changed_regions,
+        }
+    }
+
+    /// Groups given faces into the groups connected through shared edges, each sorted
+    /// ascending, in the order of their first faces.
+    fn get_edge_connected_face_groups(&self, face_ids: &[usize]) -> Vec<Vec<usize>> {
+        let adjacency = EdgeAdjacency::from_mesh(self);
+        let mut is_selected = vec![false; self.get_number_of_faces()];
+        for face_id in face_ids {
+            is_selected[*face_id] = true;
+        }
+
+        let mut groups = Vec::new();
+        for face_id in face_ids {
+            if !is_selected[*face_id] {
+                continue;
+            }
+            is_selected[*face_id] = false;
+            let mut group = Vec::new();
+            let mut stack = vec![*face_id];
+            while let Some(current) = stack.pop() {
+                group.push(current);
+                for neighbour in adjacency.get_neighbour_faces_of_face(current) {
+                    if is_selected[neighbour] {
+                        is_selected[neighbour] = false;
+                        stack.push(neighbour);
+                    }
+                }
+            }
+            group.sort();
+            groups.push(group);
+        }
+
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_connected_false() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0,
+                 2.5, 5.0, 0.0,
+                 5.0, 0.0, 0.0,
+                 7.5, 5.0, 0.0,
+                 10.0, 0.0, 0.0,
+                 5.0, 10.0, 0.0,
+                 5.0, 5.0, 3.0,
+                 2.5, 5.0, 3.0,
+                 0.0, 0.0, 3.0,
+                 ],
+            vec![0, 2, 1, // first face
+                 1, 2, 3, // second face
+                 2, 4, 3, // third face
+                 1, 3, 5, // fourth face
+                 7, 8, 6, // fifth face separated
+                 ]
+        );
+        
+        assert!(!input.is_connected());
+    }
+
+    #[test]
+    fn test_is_connected_true() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0,
+                 2.5, 5.0, 0.0,
+                 5.0, 0.0, 0.0,
+                 7.5, 5.0, 0.0,
+                 10.0, 0.0, 0.0,
+                 5.0, 10.0, 0.0,
+                 ],
+            vec![0, 2, 1, // first face
+                 1, 2, 3, // second face
+                 2, 4, 3, // third face
+                 1, 3, 5, // fourth face
+                 ]
+        );
+        
+        assert!(input.is_connected());
+    }
+
+    #[test]
+    fn test_get_number_of_faces() {
+        let input = Mesh::new(
+            vec![
+                // Base
+                -2.0,1.0,0.0,
+                8.0,1.0,0.0,
+                8.0,11.0,0.0,
+                -2.0,11.0,0.0,
+
+                // Top
+                3.0,6.0,4.0
+            ],
+            vec![
+                // Base faces
+                0,1,2,
+                0,2,3,
+
+                // Side faces
+                0,1,4,
+                1,2,4,
+                2,3,4,
+                3,0,4
+            ]);
+
+        let expected = 6;
+        let actual = input.get_number_of_faces();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_get_number_of_vertices() {
+        let input = Mesh::new(
+            vec![
+                // Base
+                -2.0,1.0,0.0,
+                8.0,1.0,0.0,
+                8.0,11.0,0.0,
+                -2.0,11.0,0.0,
+
+                // Top
+                3.0,6.0,4.0
+            ],
+            vec![
+                // Base faces
+                0,1,2,
+                0,2,3,
+
+                // Side faces
+                0,1,4,
+                1,2,4,
+                2,3,4,
+                3,0,4
+            ]);
+
+        let expected = 5;
+        let actual = input.get_number_of_vertices();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
     fn test_get_area() {
         let input = Mesh::new(
            vec![
@@ -1832,4 +3086,636 @@ mod tests {
         assert_eq!(a.eq_with_tolerance(&b,0.0002), false);
         assert_eq!(b.eq_with_tolerance(&a, 0.0002), false);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_suggest_seams_cube() {
+        let input = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                10.0,0.0,0.0,
+                10.0,10.0,0.0,
+                0.0,10.0,0.0,
+                0.0,0.0,10.0,
+                10.0,0.0,10.0,
+                10.0,10.0,10.0,
+                0.0,10.0,10.0,
+            ],
+            vec![
+                0,2,1, 0,3,2, // bottom
+                4,5,6, 4,6,7, // top
+                0,1,5, 0,5,4, // front
+                1,2,6, 1,6,5, // right
+                2,3,7, 2,7,6, // back
+                3,0,4, 3,4,7, // left
+            ]);
+
+        let actual = input.suggest_seams(0.1);
+
+        assert_eq!(actual.len(), 12); // All cube's edges are sharp
+        for edge in &actual {
+            let start = input.to_points()[edge.start];
+            let end = input.to_points()[edge.end];
+            assert!((start.get_distance_to_point(&end) - 10.0).abs() < 0.0001); // No diagonals
+        }
+    }
+
+    #[test]
+    fn test_suggest_seams_open_smooth_patch_none() {
+        let input = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                1.0,0.0,0.1,
+                2.0,0.0,0.0,
+                0.0,1.0,0.1,
+                1.0,1.0,0.2,
+                2.0,1.0,0.1,
+            ],
+            vec![
+                0,1,4,
+                0,4,3,
+                1,2,5,
+                1,5,4
+            ]);
+
+        let actual = input.suggest_seams(0.5);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_suggest_seams_closed_smooth_needs_cut() {
+        let input = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                10.0,0.0,0.0,
+                10.0,10.0,0.0,
+                0.0,10.0,0.0,
+                0.0,0.0,10.0,
+                10.0,0.0,10.0,
+                10.0,10.0,10.0,
+                0.0,10.0,10.0,
+            ],
+            vec![
+                0,2,1, 0,3,2,
+                4,5,6, 4,6,7,
+                0,1,5, 0,5,4,
+                1,2,6, 1,6,5,
+                2,3,7, 2,7,6,
+                3,0,4, 3,4,7,
+            ]);
+
+        let actual = input.suggest_seams(std::f64::consts::PI);
+
+        assert!(actual.len() >= 3); // Closed surface needs at least a few cut edges to open it
+        let all_edges: HashSet<Edge> = input.to_edges().into_iter().collect();
+        for edge in &actual {
+            assert!(all_edges.contains(edge));
+        }
+    }
+
+    #[test]
+    fn test_get_volume_cube() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 2.0, 0.0,
+                 0.0, 0.0, 2.0, 2.0, 0.0, 2.0, 2.0, 2.0, 2.0, 0.0, 2.0, 2.0],
+            vec![0, 2, 1, 0, 3, 2, // bottom
+                 4, 5, 6, 4, 6, 7, // top
+                 0, 1, 5, 0, 5, 4, // front
+                 1, 2, 6, 1, 6, 5, // right
+                 2, 3, 7, 2, 7, 6, // back
+                 3, 0, 4, 3, 4, 7, // left
+                 ]
+        );
+
+        assert!(input.is_watertight());
+        assert!((input.get_volume().unwrap() - 8.0).abs() < 1e-12);
+        assert!(((input.clone() + Vector::new(100.0, -50.0, 3.0)).get_volume().unwrap() - 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_get_volume_open() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3]
+        );
+
+        assert!(!input.is_closed());
+        assert!(!input.is_watertight());
+        assert_eq!(input.get_volume(), None);
+    }
+
+    #[test]
+    fn test_is_closed_empty() {
+        let input = Mesh::new(vec![], vec![]);
+
+        assert!(!input.is_closed());
+        assert!(!input.is_watertight());
+        assert_eq!(input.get_volume(), None);
+    }
+
+    #[test]
+    fn test_get_genus_torus() {
+        let number_of_segments = 4;
+        let mut coordinates = Vec::new();
+        let mut indices = Vec::new();
+        for i in 0..number_of_segments {
+            let u = 2.0 * std::f64::consts::PI * i as f64 / number_of_segments as f64;
+            for j in 0..number_of_segments {
+                let v = 2.0 * std::f64::consts::PI * j as f64 / number_of_segments as f64;
+                coordinates.extend_from_slice(&[(3.0 + v.cos()) * u.cos(), (3.0 + v.cos()) * u.sin(), v.sin()]);
+
+                let a = i * number_of_segments + j;
+                let b = ((i + 1) % number_of_segments) * number_of_segments + j;
+                let c = ((i + 1) % number_of_segments) * number_of_segments + (j + 1) % number_of_segments;
+                let d = i * number_of_segments + (j + 1) % number_of_segments;
+                indices.extend_from_slice(&[a, b, c, a, c, d]);
+            }
+        }
+        let input = Mesh::new(coordinates, indices);
+
+        assert_eq!(input.get_euler_characteristic(), 0);
+        assert_eq!(input.get_number_of_connected_components(), 1);
+        assert_eq!(input.get_genus(), Some(1));
+    }
+
+    #[test]
+    fn test_get_genus_two_tetrahedrons() {
+        let tetrahedron = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+        );
+        let input = tetrahedron.get_by_joining_with(&(tetrahedron.clone() + Vector::new(5.0, 0.0, 0.0)));
+
+        assert_eq!(input.get_euler_characteristic(), 4);
+        assert_eq!(input.get_number_of_connected_components(), 2);
+        assert_eq!(input.get_genus(), Some(0));
+    }
+
+    #[test]
+    fn test_get_euler_characteristic_disk_with_unused_vertex() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 9.0, 9.0, 9.0],
+            vec![0, 1, 2, 0, 2, 3]
+        );
+
+        assert_eq!(input.get_euler_characteristic(), 1);
+        assert_eq!(input.get_genus(), None);
+    }
+
+    #[test]
+    fn test_get_number_of_connected_components_empty() {
+        let input = Mesh::new(vec![], vec![]);
+
+        assert_eq!(input.get_number_of_connected_components(), 0);
+        assert_eq!(input.get_euler_characteristic(), 0);
+        assert_eq!(input.get_genus(), None);
+    }
+
+    #[test]
+    fn test_get_quality_report() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0,
+                 2.0, 0.0, 0.0,
+                 1.0, f64::sqrt(3.0), 0.0,
+                 12.0, 0.0, 0.0,
+                 7.0, 0.1, 0.0,
+                 0.0, 2.0, 0.0,
+                 ],
+            vec![0, 1, 2, // equilateral face
+                 1, 3, 4, // long and thin face
+                 0, 1, 5, // right angled face
+                 0, 1, 1, // degenerate face
+                 ]
+        );
+
+        let actual = input.get_quality_report(2);
+
+        assert_eq!(actual.get_number_of_faces(), 4);
+        assert_eq!(actual.min_angle_histogram, [2, 0, 0, 0, 1, 1]);
+        assert_eq!(actual.skewness_histogram, [1, 0, 1, 0, 0, 0, 0, 0, 0, 2]);
+        assert_eq!(actual.aspect_ratio_histogram, [2, 0, 0, 0, 0, 2]);
+        assert_eq!(actual.edge_length_ratio_histogram, [2, 1, 0, 0, 0, 1]);
+        assert_eq!(actual.worst_min_angle_face_ids, vec![3, 1]);
+        assert_eq!(actual.worst_skewness_face_ids, vec![3, 1]);
+        assert_eq!(actual.worst_aspect_ratio_face_ids, vec![3, 1]);
+        assert_eq!(actual.worst_edge_length_ratio_face_ids, vec![3, 1]);
+    }
+
+    #[test]
+    fn test_get_quality_report_empty() {
+        let input = Mesh::new(vec![], vec![]);
+
+        let actual = input.get_quality_report(5);
+
+        assert_eq!(actual.get_number_of_faces(), 0);
+        assert!(actual.worst_min_angle_face_ids.is_empty());
+    }
+
+    #[test]
+    fn test_distance_to_smaller_patch() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3]
+        );
+        let other = Mesh::new(
+            vec![0.0, 0.0, 0.0, 5.0, 0.0, 0.0, 5.0, 5.0, 0.0, 0.0, 5.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3]
+        );
+
+        let actual = input.distance_to(&other, 2).unwrap();
+
+        assert!((actual.max_distance_to_other - f64::sqrt(50.0)).abs() < 1e-12);
+        assert!(actual.mean_distance_to_other > 0.0);
+        assert!(actual.max_distance_from_other < 1e-12);
+        assert!(actual.rms_distance_from_other < 1e-12);
+        assert!((actual.get_hausdorff_distance() - f64::sqrt(50.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_distance_to_empty() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0],
+            vec![0, 1, 2]
+        );
+        let empty = Mesh::new(vec![], vec![]);
+
+        assert_eq!(input.distance_to(&empty, 2), None);
+        assert_eq!(empty.distance_to(&input, 2), None);
+    }
+
+    #[test]
+    fn test_get_triangle_samples() {
+        let input = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(0.0, 2.0, 0.0));
+
+        let actual = Mesh::get_triangle_samples(&input, 2);
+
+        assert_eq!(actual.len(), 6);
+        assert!(actual.contains(&Point::new(0.0, 0.0, 0.0)));
+        assert!(actual.contains(&Point::new(2.0, 0.0, 0.0)));
+        assert!(actual.contains(&Point::new(0.0, 2.0, 0.0)));
+        assert!(actual.contains(&Point::new(1.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_get_non_manifold_vertices_fin() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 1.0],
+            vec![0, 1, 2, // first face
+                 1, 0, 3, // second face
+                 0, 1, 4, // third face, 3rd one using the edge 0 - 1
+                 ]
+        );
+
+        assert_eq!(input.get_non_manifold_vertices(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_get_non_manifold_vertices_closed() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+        );
+
+        assert!(input.get_non_manifold_vertices().is_empty());
+        assert!(input.get_boundary_loops().is_empty());
+    }
+
+    #[test]
+    fn test_get_boundary_loops_square_with_hole() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 3.0, 3.0, 0.0, 0.0, 3.0, 0.0, // outer
+                 1.0, 1.0, 0.0, 2.0, 1.0, 0.0, 2.0, 2.0, 0.0, 1.0, 2.0, 0.0, // hole
+                 ],
+            vec![0, 1, 5, 0, 5, 4,
+                 1, 2, 6, 1, 6, 5,
+                 2, 3, 7, 2, 7, 6,
+                 3, 0, 4, 3, 4, 7,
+                 ]
+        );
+
+        let actual = input.get_boundary_loops();
+
+        assert_eq!(actual.len(), 2);
+        let mut number_of_outer_loops = 0;
+        for polygon in &actual {
+            assert_eq!(polygon.vertices.len(), 4);
+            if polygon.vertices.contains(&Point::new(3.0, 3.0, 0.0)) {
+                number_of_outer_loops += 1;
+            }
+        }
+        assert_eq!(number_of_outer_loops, 1);
+        assert!(input.get_non_manifold_vertices().is_empty());
+    }
+
+    #[test]
+    fn test_validate_closed_tetrahedron_valid() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+        );
+
+        let actual = input.validate(ValidationOptions::new(0.0, false));
+
+        assert!(actual.is_valid());
+    }
+
+    #[test]
+    fn test_validate_open_square() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3]
+        );
+
+        assert!(input.validate(ValidationOptions::new(0.0, true)).is_valid());
+        assert_eq!(
+            input.validate(ValidationOptions::new(0.0, false)).boundary_edges,
+            vec![Edge::new(0, 1), Edge::new(0, 3), Edge::new(1, 2), Edge::new(2, 3)]
+        );
+    }
+
+    #[test]
+    fn test_validate_broken() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0,
+                 1.0, 0.0, 0.0,
+                 1.0, 1.0, 0.0,
+                 0.0, 1.0, 0.0,
+                 2.0, 0.0, 0.0,
+                 f64::NAN, 0.0, 0.0,
+                 0.5, 0.5, 1.0,
+                 ],
+            vec![0, 1, 2, // first face
+                 2, 1, 0, // duplicate of first face
+                 0, 1, 4, // zero area face
+                 0, 2, 6, // third face on the edge 0 - 2
+                 3, 5, 9, // NaN vertex and out of range index
+                 3, // dangling index
+                 ]
+        );
+
+        let actual = input.validate(ValidationOptions::new(1e-9, true));
+
+        let expected = ValidationReport {
+            number_of_dangling_indices: 1,
+            out_of_range_index_positions: vec![14],
+            non_finite_vertex_ids: vec![5],
+            zero_area_face_ids: vec![2],
+            duplicate_face_pairs: vec![(0, 1)],
+            non_manifold_edges: vec![Edge::new(0, 1), Edge::new(0, 2)],
+            inconsistent_winding_edges: vec![],
+            boundary_edges: vec![],
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate_offset_out_of_bounds_and_folded() {
+        let original = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                10.0,0.0,0.0,
+                10.0,10.0,0.0,
+                0.0,10.0,0.0,
+            ],
+            vec![
+                0,1,2,
+                0,2,3
+            ]);
+
+        let offset = Mesh::new(
+            vec![
+                0.0,0.0,1.0,
+                10.0,0.0,1.0,
+                10.0,10.0,1.0,
+                0.0,10.0,1.0,
+                8.0,2.0,1.0,
+            ],
+            vec![
+                0,1,2,
+                0,2,3,
+                0,1,4 // Folded over the first face
+            ]);
+
+        let actual = offset.validate_offset(&original, 1.0, 0.001);
+
+        assert!(!actual.is_valid());
+        assert!(actual.vertex_ids_out_of_bounds.is_empty());
+        assert_eq!(actual.self_intersecting_face_pairs, vec![(0, 2)]);
+
+        let actual_too_far = offset.validate_offset(&original, 0.5, 0.001);
+        assert_eq!(actual_too_far.vertex_ids_out_of_bounds, vec![0, 1, 2, 3, 4]);
+        assert_eq!(actual_too_far.face_ids_out_of_bounds, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_validate_offset_closed_box() {
+        let original = Mesh::new(
+            vec![
+                0.0,0.0,0.0, 10.0,0.0,0.0, 10.0,10.0,0.0, 0.0,10.0,0.0,
+                0.0,0.0,10.0, 10.0,0.0,10.0, 10.0,10.0,10.0, 0.0,10.0,10.0,
+            ],
+            vec![
+                0,2,1, 0,3,2, 4,5,6, 4,6,7,
+                0,1,5, 0,5,4, 1,2,6, 1,6,5,
+                2,3,7, 2,7,6, 3,0,4, 3,4,7,
+            ]);
+        let shrunk = Mesh::new(
+            vec![
+                1.0,1.0,1.0, 9.0,1.0,1.0, 9.0,9.0,1.0, 1.0,9.0,1.0,
+                1.0,1.0,9.0, 9.0,1.0,9.0, 9.0,9.0,9.0, 1.0,9.0,9.0,
+            ],
+            original.indices.clone());
+
+        let actual = shrunk.validate_offset(&original, -1.0, 0.001);
+
+        assert!(actual.is_valid());
+    }
+
+    #[test]
+    fn test_get_degenerate_face_ids_none() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+
+        assert!(input.get_degenerate_face_ids().is_empty());
+        assert!(input.get_face_normal_vectors_unitized_checked()[0].is_some());
+    }
+
+    fn get_plate_and_tilted_faces() -> Mesh {
+        let (sin_30, cos_30) = (0.5, 0.75_f64.sqrt());
+        Mesh::new(
+            vec![0.0, 0.0, 0.0,
+                 1.0, 0.0, 0.0,
+                 0.0, 1.0, 0.0,
+                 0.0, 0.0, 1.0,
+                 1.0, 0.0, 1.0,
+                 0.0, cos_30, 1.0 + sin_30, // 30 degrees from horizontal
+                 5.0, 0.0, 1.0,
+                 6.0, 0.0, 1.0,
+                 5.0, sin_30, 1.0 + cos_30, // 60 degrees from horizontal
+                 ],
+            vec![0, 2, 1, // on the build plate
+                 3, 5, 4, // overhang angle 60 degrees
+                 6, 8, 7, // overhang angle 30 degrees
+                 ])
+    }
+
+    #[test]
+    fn test_get_overhanging_faces_tilted() {
+        let input = get_plate_and_tilted_faces();
+
+        let actual = input.get_overhanging_faces(&Vector::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_4);
+
+        assert_eq!(actual, vec![1]);
+    }
+
+    #[test]
+    fn test_get_overhanging_faces_small_max_angle() {
+        let input = get_plate_and_tilted_faces();
+
+        let actual = input.get_overhanging_faces(&Vector::new(0.0, 0.0, 1.0), 0.1);
+
+        assert_eq!(actual, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_get_overhanging_faces_reversed_build_direction() {
+        let input = get_plate_and_tilted_faces();
+
+        let actual = input.get_overhanging_faces(&Vector::new(0.0, 0.0, -1.0), 0.1);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_get_overhanging_faces_only_build_plate() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 2.0, 4.0, 0.0, 2.0, 4.0, 4.0, 2.0, 0.0, 4.0, 2.0],
+            vec![0, 2, 1, 0, 3, 2]);
+
+        let actual = input.get_overhanging_faces(&Vector::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_4);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_get_overhang_area_tilted() {
+        let input = get_plate_and_tilted_faces();
+
+        let actual = input.get_overhang_area(&Vector::new(0.0, 0.0, 1.0), 0.1);
+
+        assert!((actual - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_statistics_with_degenerate_face() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0,
+                 1.0, 0.0, 0.0,
+                 0.0, 1.0, 0.0,
+                 0.0, 0.0, 1.0,
+                 2.0, 0.0, 0.0],
+            vec![0, 2, 1,
+                 0, 1, 3,
+                 1, 2, 3,
+                 0, 3, 2,
+                 0, 1, 4]);
+
+        let actual = input.get_statistics();
+
+        assert_eq!(actual.number_of_vertices, 5);
+        assert_eq!(actual.number_of_faces, 5);
+        assert_eq!(actual.number_of_edges, 8);
+        assert_eq!(actual.min_edge_length, 1.0);
+        assert_eq!(actual.max_edge_length, 2.0);
+        assert!((actual.mean_edge_length - (6.0 + 3.0 * 2.0_f64.sqrt()) / 8.0).abs() < 1e-12);
+        assert_eq!(actual.number_of_degenerate_faces, 1);
+        assert_eq!(actual.number_of_boundary_edges, 2);
+        assert_eq!(actual.bounding_box, Some(BoundingBox::new(0.0, 2.0, 0.0, 1.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_get_statistics_empty() {
+        let input = Mesh::new(vec![], vec![]);
+
+        let actual = input.get_statistics();
+
+        assert_eq!(actual.number_of_vertices, 0);
+        assert_eq!(actual.number_of_faces, 0);
+        assert_eq!(actual.number_of_edges, 0);
+        assert_eq!(actual.area, 0.0);
+        assert_eq!(actual.min_edge_length, 0.0);
+        assert_eq!(actual.mean_edge_length, 0.0);
+        assert_eq!(actual.max_edge_length, 0.0);
+        assert_eq!(actual.number_of_boundary_edges, 0);
+        assert_eq!(actual.bounding_box, None);
+    }
+
+    #[test]
+    fn test_get_statistics_json_round_trip() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 2.0], vec![0, 1, 2]);
+        let expected = input.get_statistics();
+
+        let json = serde_json::to_string(&expected).unwrap();
+        let actual: MeshStatistics = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_diff_added_vertices_and_faces() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        let other = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2, 1, 3, 2]);
+
+        let actual = input.diff(&other, 0.001);
+
+        assert!(actual.moved_vertex_ids.is_empty());
+        assert_eq!(actual.added_vertex_ids, vec![3]);
+        assert!(actual.removed_vertex_ids.is_empty());
+        assert_eq!(actual.added_face_ids, vec![1]);
+        assert!(actual.removed_face_ids.is_empty());
+        assert!(actual.moved_face_ids.is_empty());
+        assert_eq!(actual.changed_regions, vec![BoundingBox::new(0.0, 1.0, 0.0, 1.0, 0.0, 0.0)]);
+
+        let reversed = other.diff(&input, 0.001);
+
+        assert_eq!(reversed.removed_vertex_ids, vec![3]);
+        assert_eq!(reversed.removed_face_ids, vec![1]);
+        assert!(reversed.added_face_ids.is_empty());
+    }
+
+    #[test]
+    fn test_diff_flipped_face() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        let other = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 2, 1]);
+
+        let actual = input.diff(&other, 0.001);
+
+        assert_eq!(actual.added_face_ids, vec![0]);
+        assert_eq!(actual.removed_face_ids, vec![0]);
+        assert_eq!(actual.changed_regions.len(), 2);
+    }
+
+    #[test]
+    fn test_diff_separate_changed_regions() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+                 5.0, 0.0, 0.0, 6.0, 0.0, 0.0, 5.0, 1.0, 0.0,
+                 9.0, 0.0, 0.0, 10.0, 0.0, 0.0, 9.0, 1.0, 0.0],
+            vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut other = input.clone();
+        other.coordinates[2] = 0.5;
+        other.coordinates[26] = -0.5;
+
+        let actual = input.diff(&other, 0.001);
+
+        assert_eq!(actual.moved_vertex_ids, vec![0, 8]);
+        assert_eq!(actual.moved_face_ids, vec![0, 2]);
+        assert_eq!(actual.max_vertex_distance, 0.5);
+        assert_eq!(actual.changed_regions, vec![
+            BoundingBox::new(0.0, 1.0, 0.0, 1.0, 0.0, 0.5),
+            BoundingBox::new(9.0, 10.0, 0.0, 1.0, -0.5, 0.0),
+        ]);
+        assert!(!actual.is_empty());
+        assert!(input.diff(&other, 0.6).is_empty());
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::mesh::Mesh;
+
+/// Values of a single named attribute channel of the [Mesh](crate::mesh::Mesh).
+///
+/// A channel can have multiple components per vertex (or face), e.g. 3 for vectors, stored as
+/// a flat list like the `coordinates`. The number of components is the number of values divided
+/// by the number of vertices (or faces).
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum AttributeValues {
+    /// Double-precision floating-point values.
+    F64(Vec<f64>),
+    /// Single-precision floating-point values.
+    F32(Vec<f32>),
+    /// Unsigned integer values, e.g. classification ids.
+    U32(Vec<u32>),
+}
+
+impl AttributeValues {
+    /// Returns the number of values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh_attributes::AttributeValues;
+    ///
+    /// assert_eq!(AttributeValues::U32(vec![1, 2, 3]).len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        match self {
+            AttributeValues::F64(values) => values.len(),
+            AttributeValues::F32(values) => values.len(),
+            AttributeValues::U32(values) => values.len(),
+        }
+    }
+
+    /// Checks if there are no values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh_attributes::AttributeValues;
+    ///
+    /// assert!(AttributeValues::F64(vec![]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets values of given elements (vertices or faces), in the given order.
+    fn get_by_ids(&self, ids: &[usize], number_of_components: usize) -> AttributeValues {
+        fn pick<T: Copy>(values: &[T], ids: &[usize], number_of_components: usize) -> Vec<T> {
+            ids.iter().flat_map(|id| values[id * number_of_components..(id + 1) * number_of_components].iter().copied()).collect()
+        }
+        match self {
+            AttributeValues::F64(values) => AttributeValues::F64(pick(values, ids, number_of_components)),
+            AttributeValues::F32(values) => AttributeValues::F32(pick(values, ids, number_of_components)),
+            AttributeValues::U32(values) => AttributeValues::U32(pick(values, ids, number_of_components)),
+        }
+    }
+
+    /// Appends values of the same type, returns `false` if the types are different.
+    fn extend(&mut self, other: &AttributeValues) -> bool {
+        match (self, other) {
+            (AttributeValues::F64(values), AttributeValues::F64(other)) => values.extend_from_slice(other),
+            (AttributeValues::F32(values), AttributeValues::F32(other)) => values.extend_from_slice(other),
+            (AttributeValues::U32(values), AttributeValues::U32(other)) => values.extend_from_slice(other),
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Represents named per-vertex and per-face data attached to the [Mesh](crate::mesh::Mesh),
+/// e.g. thickness, classification or temperature.
+///
+/// Topology-changing operations (welding, unwelding, extracting parts, joining, splitting)
+/// remap the channels together with vertices and faces. When joining, only channels present in
+/// all the joined Meshes, with the same type and number of components, are kept.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::mesh::Mesh;
+/// use meshmeshmesh::mesh_attributes::AttributeValues;
+///
+/// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+/// input.attributes.vertex.insert(String::from("temperature"), AttributeValues::F64(vec![20.0, 21.0, 22.0, 23.0]));
+/// input.attributes.face.insert(String::from("classification"), AttributeValues::U32(vec![7, 9]));
+///
+/// let actual = input.get_part_by_face_ids(&vec![1]);
+///
+/// assert_eq!(actual.attributes.vertex["temperature"], AttributeValues::F64(vec![20.0, 22.0, 23.0]));
+/// assert_eq!(actual.attributes.face["classification"], AttributeValues::U32(vec![9]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct MeshAttributes {
+    /// Channels with values for every vertex.
+    #[serde(default)]
+    pub vertex: BTreeMap<String, AttributeValues>,
+    /// Channels with values for every face.
+    #[serde(default)]
+    pub face: BTreeMap<String, AttributeValues>,
+}
+
+impl MeshAttributes {
+    /// Creates new [MeshAttributes] without any channels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh_attributes::MeshAttributes;
+    ///
+    /// let result = MeshAttributes::new();
+    ///
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn new() -> MeshAttributes { MeshAttributes::default() }
+
+    /// Checks if there are no channels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh_attributes::{AttributeValues, MeshAttributes};
+    ///
+    /// let mut input = MeshAttributes::new();
+    /// input.face.insert(String::from("thickness"), AttributeValues::F32(vec![0.2]));
+    ///
+    /// assert!(!input.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.vertex.is_empty() && self.face.is_empty()
+    }
+
+    /// Gets the channels for the given vertices and faces of the source, in the given order.
+    pub(crate) fn get_by_ids(&self, vertex_ids: &[usize], number_of_vertices: usize, face_ids: &[usize], number_of_faces: usize) -> MeshAttributes {
+        MeshAttributes {
+            vertex: get_channels_by_ids(&self.vertex, vertex_ids, number_of_vertices),
+            face: get_channels_by_ids(&self.face, face_ids, number_of_faces),
+        }
+    }
+
+    /// Joins the channels in the given order. Each item is the attributes with the number of
+    /// vertices and the number of faces of its Mesh.
+    pub(crate) fn get_joined(attributes: &[(&MeshAttributes, usize, usize)]) -> MeshAttributes {
+        MeshAttributes {
+            vertex: get_joined_channels(&attributes.iter().map(|(attributes, number_of_vertices, _)| (&attributes.vertex, *number_of_vertices)).collect::<Vec<_>>()),
+            face: get_joined_channels(&attributes.iter().map(|(attributes, _, number_of_faces)| (&attributes.face, *number_of_faces)).collect::<Vec<_>>()),
+        }
+    }
+}
+
+impl Mesh {
+    /// Gets `attributes` for the given vertices and faces of this [Mesh], in the given order.
+    pub(crate) fn get_attributes_by_ids(&self, vertex_ids: &[usize], face_ids: &[usize]) -> MeshAttributes {
+        self.attributes.get_by_ids(vertex_ids, self.get_number_of_vertices(), face_ids, self.get_number_of_faces())
+    }
+
+    /// Extends per face data (`face_groups` and face `attributes`) for the faces added at the end
+    /// of `indices`, copying it from the given existing faces, one for each new face.
+    pub(crate) fn extend_face_data(&mut self, source_face_ids: &[usize]) {
+        let number_of_old_faces = self.get_number_of_faces() - source_face_ids.len();
+        let face_ids: Vec<usize> = (0..number_of_old_faces).chain(source_face_ids.iter().copied()).collect();
+        self.face_groups = self.face_groups.as_ref().map(|face_groups| face_ids.iter().map(|face_id| face_groups[*face_id]).collect());
+        self.attributes.face = get_channels_by_ids(&self.attributes.face, &face_ids, number_of_old_faces);
+    }
+
+    /// Gets `attributes` for the Meshes joined in the given order, without any vertex welding.
+    pub(crate) fn get_joined_attributes(meshes: &[&Mesh]) -> MeshAttributes {
+        MeshAttributes::get_joined(&meshes.iter().map(|mesh| (&mesh.attributes, mesh.get_number_of_vertices(), mesh.get_number_of_faces())).collect::<Vec<_>>())
+    }
+}
+
+fn get_number_of_components(values: &AttributeValues, number_of_elements: usize) -> usize {
+    values.len().checked_div(number_of_elements).unwrap_or(1)
+}
+
+fn get_channels_by_ids(channels: &BTreeMap<String, AttributeValues>, ids: &[usize], number_of_elements: usize) -> BTreeMap<String, AttributeValues> {
+    channels.iter()
+        .map(|(name, values)| (name.clone(), values.get_by_ids(ids, get_number_of_components(values, number_of_elements))))
+        .collect()
+}
+
+fn get_joined_channels(channels: &[(&BTreeMap<String, AttributeValues>, usize)]) -> BTreeMap<String, AttributeValues> {
+    let mut joined: BTreeMap<String, AttributeValues> = BTreeMap::new();
+    let (first, first_number_of_elements) = match channels.first() {
+        Some(first) => *first,
+        None => return joined,
+    };
+
+    'channels: for (name, values) in first {
+        let number_of_components = get_number_of_components(values, first_number_of_elements);
+        let mut joined_values = values.clone();
+        for (other, number_of_elements) in &channels[1..] {
+            match other.get(name) {
+                Some(other_values) if other_values.len() == number_of_elements * number_of_components && joined_values.extend(other_values) => {},
+                _ => continue 'channels,
+            }
+        }
+        joined.insert(name.clone(), joined_values);
+    }
+
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+    use super::*;
+
+    #[test]
+    fn test_get_by_ids_with_components() {
+        let mut input = MeshAttributes::new();
+        input.vertex.insert(String::from("direction"), AttributeValues::F32(vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]));
+
+        let actual = input.get_by_ids(&[1, 1, 0], 2, &[], 0);
+
+        assert_eq!(actual.vertex["direction"], AttributeValues::F32(vec![0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0]));
+    }
+
+    #[test]
+    fn test_get_joined_keeps_common_channels_only() {
+        let mut a = MeshAttributes::new();
+        a.face.insert(String::from("id"), AttributeValues::U32(vec![1]));
+        a.face.insert(String::from("only_a"), AttributeValues::U32(vec![1]));
+        a.face.insert(String::from("different_type"), AttributeValues::U32(vec![1]));
+        let mut b = MeshAttributes::new();
+        b.face.insert(String::from("id"), AttributeValues::U32(vec![2, 3]));
+        b.face.insert(String::from("different_type"), AttributeValues::F64(vec![1.0, 2.0]));
+
+        let actual = MeshAttributes::get_joined(&[(&a, 3, 1), (&b, 4, 2)]);
+
+        assert_eq!(actual.face.len(), 1);
+        assert_eq!(actual.face["id"], AttributeValues::U32(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_get_joined_different_number_of_components_dropped() {
+        let mut a = MeshAttributes::new();
+        a.vertex.insert(String::from("value"), AttributeValues::F64(vec![1.0, 2.0]));
+        let mut b = MeshAttributes::new();
+        b.vertex.insert(String::from("value"), AttributeValues::F64(vec![1.0, 2.0, 3.0, 4.0]));
+
+        let actual = MeshAttributes::get_joined(&[(&a, 2, 0), (&b, 2, 0)]);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_json() {
+        let mut input = MeshAttributes::new();
+        input.vertex.insert(String::from("t"), AttributeValues::F64(vec![1.5]));
+
+        let json = to_string(&input).unwrap();
+        let actual: MeshAttributes = from_str(&json).unwrap();
+
+        assert_eq!(json, "{\"vertex\":{\"t\":{\"F64\":[1.5]}},\"face\":{}}");
+        assert_eq!(actual, input);
+    }
+}
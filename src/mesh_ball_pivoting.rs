@@ -0,0 +1,304 @@
+use std::collections::{HashMap, VecDeque};
+use std::f64::consts::PI;
+use crate::kd_tree::KdTree;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::vector::Vector;
+
+/// Points closer to the ball's surface than this fraction of the radius are treated as lying
+/// on it, so e.g. 4 points on a common circle don't block each other.
+const BALL_TOLERANCE: f64 = 1e-9;
+
+impl Mesh {
+    /// Reconstructs the [Mesh] from the oriented point cloud with the ball-pivoting algorithm.
+    ///
+    /// A ball with the given `radius` is placed on 3 points, creating the first triangle. Then
+    /// it's rotated around edges of the growing [Mesh] until it touches another point, which
+    /// creates the next triangle, as long as the ball doesn't contain any other points. Triangles
+    /// are oriented according to `normals` (one per point), and points with normals pointing
+    /// the other way than the triangle are not connected.
+    ///
+    /// The `radius` should be a bit bigger than the typical spacing of points. Where points are
+    /// too sparse for the ball, holes are left.
+    ///
+    /// All the `points` become vertices of the [Mesh] in the same order, even the ones which
+    /// don't end up in any triangle, so the per-point attributes can still be used.
+    ///
+    /// It panics if the `radius` is not positive or the numbers of `points` and `normals` differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let points = vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(1.0, 1.1, 0.0), Point::new(0.0, 1.0, 0.0)];
+    /// let normals = vec![Vector::new(0.0, 0.0, 1.0); 4];
+    ///
+    /// let actual = Mesh::reconstruct_ball_pivoting(&points, &normals, 1.0);
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 4);
+    /// assert_eq!(actual.get_number_of_faces(), 2);
+    /// assert!(actual.get_face_normal_vectors_unitized().iter().all(|normal| normal.z > 0.99));
+    /// ```
+    pub fn reconstruct_ball_pivoting(points: &[Point], normals: &[Vector], radius: f64) -> Mesh {
+        if radius <= 0.0 {
+            panic!("Ball radius should be greater than 0.0");
+        }
+        if points.len() != normals.len() {
+            panic!("There should be exactly 1 normal for each point");
+        }
+
+        let mut pivoting = BallPivoting::new(points, normals, radius);
+        for seed in 0..points.len() {
+            if !pivoting.is_used[seed] && pivoting.add_seed_triangle(seed) {
+                pivoting.expand_front();
+            }
+        }
+
+        let coordinates = points.iter().flat_map(|point| [point.x, point.y, point.z]).collect();
+
+        Mesh::new(coordinates, pivoting.indices)
+    }
+}
+
+struct BallPivoting<'a> {
+    points: &'a [Point],
+    normals: &'a [Vector],
+    radius: f64,
+    kd_tree: KdTree,
+    indices: Vec<usize>,
+    is_used: Vec<bool>,
+    /// Directed edges (as in their triangle) which the ball can still pivot around, with the
+    /// center of the ball touching that triangle.
+    front: HashMap<(usize, usize), Point>,
+    queue: VecDeque<(usize, usize)>,
+    number_of_front_edges: Vec<usize>,
+    number_of_edge_faces: HashMap<(usize, usize), usize>,
+}
+
+impl BallPivoting<'_> {
+    fn new<'a>(points: &'a [Point], normals: &'a [Vector], radius: f64) -> BallPivoting<'a> {
+        BallPivoting {
+            points,
+            normals,
+            radius,
+            kd_tree: KdTree::from_points(points),
+            indices: Vec::new(),
+            is_used: vec![false; points.len()],
+            front: HashMap::new(),
+            queue: VecDeque::new(),
+            number_of_front_edges: vec![0; points.len()],
+            number_of_edge_faces: HashMap::new(),
+        }
+    }
+
+    /// Tries to find the first triangle with the `seed` and 2 other unused points, and adds it.
+    fn add_seed_triangle(&mut self, seed: usize) -> bool {
+        let neighbours: Vec<usize> = self.kd_tree.get_point_ids_within_distance(&self.points[seed], 2.0 * self.radius)
+            .into_iter()
+            .filter(|neighbour| *neighbour != seed && !self.is_used[*neighbour])
+            .collect();
+        for (i, first) in neighbours.iter().enumerate() {
+            for second in &neighbours[i + 1..] {
+                for triangle in [[seed, *first, *second], [seed, *second, *first]] {
+                    if let Some(center) = self.get_ball_center(triangle) {
+                        if self.is_ball_empty(&center, triangle) {
+                            self.add_triangle(triangle, center);
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Pivots the ball around edges of the front until none is left.
+    fn expand_front(&mut self) {
+        while let Some((start, end)) = self.queue.pop_front() {
+            let center = match self.front.get(&(start, end)) {
+                Some(center) => *center,
+                None => continue,
+            };
+            if let Some((point_id, new_center)) = self.pivot(start, end, &center) {
+                self.add_triangle([end, start, point_id], new_center);
+            } else {
+                self.remove_front_edge(start, end);
+            }
+        }
+    }
+
+    /// Finds the first point hit by the ball rotating around the edge from `start` to `end`,
+    /// together with the new center of the ball.
+    fn pivot(&self, start: usize, end: usize, center: &Point) -> Option<(usize, Point)> {
+        let (start_point, end_point) = (self.points[start], self.points[end]);
+        let middle = start_point + Vector::from_2_points(&start_point, &end_point) * 0.5;
+        let axis = Vector::from_2_points(&start_point, &end_point).get_unitized();
+        let from = Vector::from_2_points(&middle, center);
+
+        let mut best: Option<(f64, usize, Point)> = None;
+        for point_id in self.kd_tree.get_point_ids_within_distance(&middle, 2.0 * self.radius) {
+            if point_id == start || point_id == end {
+                continue;
+            }
+            if self.is_used[point_id] && self.number_of_front_edges[point_id] == 0 {
+                continue;
+            }
+            let triangle = [end, start, point_id];
+            let new_center = match self.get_ball_center(triangle) {
+                Some(new_center) => new_center,
+                None => continue,
+            };
+            let to = Vector::from_2_points(&middle, &new_center);
+            let mut angle = from.get_cross_product(&to).get_dot_product(&axis).atan2(from.get_dot_product(&to));
+            if angle < 0.0 {
+                angle += 2.0 * PI;
+            }
+            if best.is_some_and(|(best_angle, _, _)| best_angle <= angle) {
+                continue;
+            }
+            if self.can_add_triangle(triangle) && self.is_ball_empty(&new_center, triangle) {
+                best = Some((angle, point_id, new_center));
+            }
+        }
+
+        best.map(|(_, point_id, new_center)| (point_id, new_center))
+    }
+
+    /// Gets the center of the ball touching the triangle on the side of its normal, if the
+    /// triangle is small enough and its normal agrees with normals of its points.
+    fn get_ball_center(&self, triangle: [usize; 3]) -> Option<Point> {
+        let [a, b, c] = triangle.map(|point_id| self.points[point_id]);
+        let ab = Vector::from_2_points(&a, &b);
+        let ac = Vector::from_2_points(&a, &c);
+        let normal = ab.get_cross_product(&ac);
+        let normal_length_squared = normal.get_dot_product(&normal);
+        if normal_length_squared == 0.0 {
+            return None;
+        }
+        if triangle.iter().any(|point_id| self.normals[*point_id].get_dot_product(&normal) <= 0.0) {
+            return None;
+        }
+
+        let circumcenter_offset = (normal.get_cross_product(&ab) * ac.get_dot_product(&ac) + ac.get_cross_product(&normal) * ab.get_dot_product(&ab)) * (1.0 / (2.0 * normal_length_squared));
+        let height_squared = self.radius * self.radius - circumcenter_offset.get_dot_product(&circumcenter_offset);
+        if height_squared < 0.0 {
+            return None;
+        }
+
+        Some(a + circumcenter_offset + normal * (height_squared.sqrt() / normal_length_squared.sqrt()))
+    }
+
+    fn is_ball_empty(&self, center: &Point, triangle: [usize; 3]) -> bool {
+        self.kd_tree.get_point_ids_within_distance(center, self.radius * (1.0 - BALL_TOLERANCE))
+            .iter()
+            .all(|point_id| triangle.contains(point_id))
+    }
+
+    /// Checks if the triangle can be glued to the existing ones without making non-manifold or
+    /// inconsistently oriented edges.
+    fn can_add_triangle(&self, triangle: [usize; 3]) -> bool {
+        (0..3).all(|i| {
+            let (start, end) = (triangle[i], triangle[(i + 1) % 3]);
+            match self.number_of_edge_faces.get(&get_edge_key(start, end)) {
+                None => true,
+                Some(1) => self.front.contains_key(&(end, start)),
+                Some(_) => false,
+            }
+        })
+    }
+
+    fn add_triangle(&mut self, triangle: [usize; 3], center: Point) {
+        self.indices.extend_from_slice(&triangle);
+        for i in 0..3 {
+            let (start, end) = (triangle[i], triangle[(i + 1) % 3]);
+            self.is_used[start] = true;
+            *self.number_of_edge_faces.entry(get_edge_key(start, end)).or_insert(0) += 1;
+            if self.front.contains_key(&(end, start)) {
+                self.remove_front_edge(end, start);
+            } else {
+                self.front.insert((start, end), center);
+                self.queue.push_back((start, end));
+                self.number_of_front_edges[start] += 1;
+                self.number_of_front_edges[end] += 1;
+            }
+        }
+    }
+
+    fn remove_front_edge(&mut self, start: usize, end: usize) {
+        if self.front.remove(&(start, end)).is_some() {
+            self.number_of_front_edges[start] -= 1;
+            self.number_of_front_edges[end] -= 1;
+        }
+    }
+}
+
+fn get_edge_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b { (a, b) } else { (b, a) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    #[test]
+    fn test_reconstruct_ball_pivoting_jittered_grid() {
+        let mut random = Random::new(7);
+        let mut points = Vec::new();
+        for y in 0..8 {
+            for x in 0..8 {
+                points.push(Point::new(x as f64 + 0.1 * random.next(), y as f64 + 0.1 * random.next(), 0.05 * random.next()));
+            }
+        }
+        let normals = vec![Vector::new(0.0, 0.0, 1.0); points.len()];
+
+        let actual = Mesh::reconstruct_ball_pivoting(&points, &normals, 1.0);
+
+        assert_eq!(actual.get_number_of_faces(), 7 * 7 * 2);
+        assert!(actual.get_non_manifold_vertices().is_empty());
+        assert!(actual.get_face_normal_vectors_unitized().iter().all(|normal| normal.z > 0.9));
+        assert_eq!(actual.get_boundary_loops().len(), 1);
+    }
+
+    #[test]
+    fn test_reconstruct_ball_pivoting_sphere() {
+        // Fibonacci sphere
+        let number_of_points = 300;
+        let golden_angle = PI * (3.0 - 5.0_f64.sqrt());
+        let points: Vec<Point> = (0..number_of_points).map(|i| {
+            let z = 1.0 - 2.0 * (i as f64 + 0.5) / number_of_points as f64;
+            let ring_radius = (1.0 - z * z).sqrt();
+            let angle = golden_angle * i as f64;
+            Point::new(ring_radius * angle.cos(), ring_radius * angle.sin(), z)
+        }).collect();
+        let normals: Vec<Vector> = points.iter().map(|point| point.to_vector()).collect();
+
+        let actual = Mesh::reconstruct_ball_pivoting(&points, &normals, 0.3);
+
+        assert!(actual.get_edges_with_missing_neighbour().is_empty());
+        assert!(actual.get_non_manifold_edges().is_empty());
+        let volume = actual.get_volume().unwrap();
+        assert!(volume > 0.9 * 4.0 / 3.0 * PI && volume < 4.0 / 3.0 * PI);
+    }
+
+    #[test]
+    fn test_reconstruct_ball_pivoting_opposite_normals_not_connected() {
+        let points = vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)];
+        let normals = vec![Vector::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, -1.0)];
+
+        let actual = Mesh::reconstruct_ball_pivoting(&points, &normals, 1.0);
+
+        assert_eq!(actual.get_number_of_faces(), 0);
+        assert_eq!(actual.get_number_of_vertices(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "There should be exactly 1 normal for each point")]
+    fn test_reconstruct_ball_pivoting_missing_normals_panic() {
+        Mesh::reconstruct_ball_pivoting(&[Point::new(0.0, 0.0, 0.0)], &[], 1.0);
+    }
+}
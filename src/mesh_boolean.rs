@@ -0,0 +1,418 @@
+use crate::bounding_box::BoundingBox;
+use crate::bvh::Bvh;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::triangle::Triangle;
+use crate::vector::Vector;
+
+impl Mesh {
+    /// Gets the boolean union of this [Mesh] and the `other` one: a Mesh enclosing everything
+    /// enclosed by any of them.
+    ///
+    /// Both Meshes have to be watertight (see [Mesh::is_watertight]), otherwise an error is
+    /// returned.
+    ///
+    /// Faces of both Meshes are split along the lines where they intersect each other (faces
+    /// which can intersect are found with [Mesh::get_cached_bvh]). Then the split faces lying
+    /// outside the other Mesh are kept, which is checked with [Mesh::contains_point]. At the end
+    /// vertices closer than the `tolerance` are welded, so the result is watertight too.
+    ///
+    /// Faces which overlap with faces of the other Mesh (are coplanar to them within the
+    /// `tolerance`) are not supported, so an error is returned for them, e.g. for boxes
+    /// standing on each other.
+    ///
+    /// The result is built from the geometry only: `id`, `face_groups`, `uvs`,
+    /// `vertex_colors`, `vertex_normals`, attributes and metadata of both Meshes are not kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let box_a = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 2.0, 0.0,
+    ///          0.0, 0.0, 2.0, 2.0, 0.0, 2.0, 2.0, 2.0, 2.0, 0.0, 2.0, 2.0],
+    ///     vec![0, 2, 1, 0, 3, 2, 4, 5, 6, 4, 6, 7, 0, 1, 5, 0, 5, 4,
+    ///          1, 2, 6, 1, 6, 5, 2, 3, 7, 2, 7, 6, 3, 0, 4, 3, 4, 7]);
+    /// let box_b = Mesh::new(
+    ///     vec![1.0, 1.0, 1.0, 3.0, 1.0, 1.0, 3.0, 3.0, 1.0, 1.0, 3.0, 1.0,
+    ///          1.0, 1.0, 3.0, 3.0, 1.0, 3.0, 3.0, 3.0, 3.0, 1.0, 3.0, 3.0],
+    ///     box_a.indices.clone());
+    ///
+    /// let actual = box_a.boolean_union(&box_b, 1e-9).unwrap();
+    ///
+    /// assert!(actual.is_watertight());
+    /// assert!((actual.get_volume().unwrap() - 15.0).abs() < 1e-9);
+    /// ```
+    pub fn boolean_union(&self, other: &Mesh, tolerance: f64) -> Result<Mesh, String> {
+        if !self.is_watertight() {
+            return Err("The Mesh is not watertight".to_string());
+        }
+        if !other.is_watertight() {
+            return Err("The other Mesh is not watertight".to_string());
+        }
+
+        let (triangles, other_triangles) = self.get_triangles_split_by_intersections(other, tolerance)?;
+
+        let mut kept: Vec<Triangle> = triangles.into_iter()
+            .filter(|triangle| !other.contains_point(&triangle.get_centroid()))
+            .collect();
+        kept.extend(other_triangles.into_iter()
+            .filter(|triangle| !self.contains_point(&triangle.get_centroid())));
+
+        Ok(Mesh::from_triangles(kept).get_welded_without_collapsed_faces(tolerance))
+    }
+
+    /// Gets faces of this [Mesh] and the `other` one as [Triangle]s, with faces split along
+    /// the segments where they intersect faces of the other Mesh.
+    ///
+    /// Returns an error if any faces of both Meshes overlap in the same plane.
+    fn get_triangles_split_by_intersections(&self, other: &Mesh, tolerance: f64) -> Result<(Vec<Triangle>, Vec<Triangle>), String> {
+        let triangles = self.to_triangles();
+        let other_triangles = other.to_triangles();
+        let mut split_faces: Vec<SplitFace> = triangles.iter().map(SplitFace::new).collect();
+        let mut other_split_faces: Vec<SplitFace> = other_triangles.iter().map(SplitFace::new).collect();
+
+        let mut intersections = Vec::new();
+        let bvh = other.get_cached_bvh();
+        for (face_id, triangle) in triangles.iter().enumerate() {
+            let bounding_box = Bvh::get_bounding_box_of_triangle(triangle);
+            let search_box = BoundingBox::new(
+                bounding_box.min_x - tolerance, bounding_box.max_x + tolerance,
+                bounding_box.min_y - tolerance, bounding_box.max_y + tolerance,
+                bounding_box.min_z - tolerance, bounding_box.max_z + tolerance,
+            );
+            for other_face_id in bvh.get_face_ids_intersecting_box(&search_box) {
+                let other_triangle = &other_triangles[other_face_id];
+                if triangle.is_coplanar_to_triangle_with_epsilon(other_triangle, tolerance) && triangle.intersects_triangle(other_triangle, tolerance) {
+                    return Err(format!("The face {} of the Mesh overlaps the coplanar face {} of the other Mesh", face_id, other_face_id));
+                }
+                // Zero length segments (Triangles touching with a vertex) are kept too, so both
+                // faces sharing the touched edge get the same vertex
+                if let Some((start, end)) = triangle.get_intersection_with_triangle(other_triangle, tolerance) {
+                    split_faces[face_id].insert_segment(&start, &end, tolerance);
+                    other_split_faces[other_face_id].insert_segment(&start, &end, tolerance);
+                    intersections.push((face_id, other_face_id, start, end));
+                }
+            }
+        }
+
+        // Segments are split differently in both faces, so vertices on them are copied over
+        // until both sides of every segment match
+        loop {
+            let mut changed = false;
+            for (face_id, other_face_id, start, end) in &intersections {
+                changed |= split_faces[*face_id].copy_points_on_segment(&mut other_split_faces[*other_face_id], start, end, tolerance);
+                changed |= other_split_faces[*other_face_id].copy_points_on_segment(&mut split_faces[*face_id], start, end, tolerance);
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        Ok((split_faces.iter().flat_map(SplitFace::to_triangles).collect(), other_split_faces.iter().flat_map(SplitFace::to_triangles).collect()))
+    }
+
+    /// Welds vertices (see [Mesh::get_with_welded_vertices]) and removes faces collapsed by
+    /// welding, together with vertices not used anymore.
+    fn get_welded_without_collapsed_faces(&self, tolerance: f64) -> Mesh {
+        let welded = self.get_with_welded_vertices(tolerance);
+        let indices: Vec<usize> = welded.indices.chunks(3)
+            .filter(|face| face[0] != face[1] && face[1] != face[2] && face[2] != face[0])
+            .flatten()
+            .copied()
+            .collect();
+
+        Mesh::new(welded.coordinates, indices).get_compacted().0
+    }
+}
+
+/// Where the segment walked through a [SplitFace] leaves a triangle.
+enum Crossing {
+    /// Through the vertex with this id.
+    Vertex(usize),
+    /// Through the edge between vertices with these ids, at the given [Point].
+    Edge(usize, usize, Point),
+}
+
+/// A single face split into smaller triangles sharing vertices. All the triangles keep the
+/// winding of the original face.
+struct SplitFace {
+    points: Vec<Point>,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl SplitFace {
+    fn new(triangle: &Triangle) -> SplitFace {
+        SplitFace {
+            points: vec![triangle.first_point, triangle.second_point, triangle.third_point],
+            triangles: vec![[0, 1, 2]],
+        }
+    }
+
+    fn to_triangles(&self) -> Vec<Triangle> {
+        self.triangles.iter()
+            .map(|[a, b, c]| Triangle::new(self.points[*a], self.points[*b], self.points[*c]))
+            .collect()
+    }
+
+    /// Splits triangles, so the segment from `start` to `end` is made of their edges.
+    ///
+    /// The segment is walked from the `start`: every triangle it passes through gets split
+    /// where the segment leaves it, which creates the next edge along the segment.
+    fn insert_segment(&mut self, start: &Point, end: &Point, tolerance: f64) {
+        let start_id = self.insert_point(start, tolerance);
+        let end_id = self.insert_point(end, tolerance);
+
+        let mut current = start_id;
+        let max_steps = 4 * (self.triangles.len() + 1);
+        for _ in 0..max_steps {
+            if current == end_id || self.has_edge(current, end_id) {
+                return;
+            }
+            current = match self.get_next_crossing(current, end_id, tolerance) {
+                Some(Crossing::Vertex(vertex_id)) => vertex_id,
+                Some(Crossing::Edge(a, b, point)) => self.split_edge(a, b, point),
+                None => return,
+            };
+        }
+    }
+
+    /// Adds the [Point] as a vertex and returns its id. A vertex closer than the `tolerance`
+    /// is reused, a Point close to an edge splits that edge.
+    fn insert_point(&mut self, point: &Point, tolerance: f64) -> usize {
+        let closest_id = (0..self.points.len())
+            .min_by(|a, b| self.points[*a].get_distance_to_point(point).total_cmp(&self.points[*b].get_distance_to_point(point)))
+            .unwrap();
+        if self.points[closest_id].get_distance_to_point(point) <= tolerance {
+            return closest_id;
+        }
+
+        for triangle in &self.triangles {
+            for i in 0..3 {
+                let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+                let on_edge = point.get_closest_point_on_segment(&self.points[a], &self.points[b]);
+                if on_edge.get_distance_to_point(point) <= tolerance {
+                    return self.split_edge(a, b, on_edge);
+                }
+            }
+        }
+
+        for (i, [a, b, c]) in self.triangles.iter().copied().enumerate() {
+            let triangle = Triangle::new(self.points[a], self.points[b], self.points[c]);
+            if triangle.contains_point_with_epsilon(point, tolerance) {
+                let new_id = self.points.len();
+                self.points.push(triangle.get_closest_point(point));
+                self.triangles[i] = [a, b, new_id];
+                self.triangles.push([b, c, new_id]);
+                self.triangles.push([c, a, new_id]);
+                return new_id;
+            }
+        }
+
+        closest_id
+    }
+
+    /// Splits the edge between vertices `a` and `b` with a new vertex at the [Point] and
+    /// returns its id.
+    fn split_edge(&mut self, a: usize, b: usize, point: Point) -> usize {
+        let new_id = self.points.len();
+        self.points.push(point);
+
+        let mut triangles = Vec::with_capacity(self.triangles.len() + 2);
+        for triangle in &self.triangles {
+            let edge_position = (0..3).find(|i| {
+                let (first, second) = (triangle[*i], triangle[(*i + 1) % 3]);
+                (first == a && second == b) || (first == b && second == a)
+            });
+            match edge_position {
+                Some(i) => {
+                    let (first, second, third) = (triangle[i], triangle[(i + 1) % 3], triangle[(i + 2) % 3]);
+                    triangles.push([first, new_id, third]);
+                    triangles.push([new_id, second, third]);
+                },
+                None => triangles.push(*triangle),
+            }
+        }
+        self.triangles = triangles;
+
+        new_id
+    }
+
+    /// Inserts vertices of this face lying on the segment into the `other` face, returns `true`
+    /// if any was missing there.
+    fn copy_points_on_segment(&self, other: &mut SplitFace, start: &Point, end: &Point, tolerance: f64) -> bool {
+        let number_of_other_points = other.points.len();
+        for point in &self.points {
+            if point.get_closest_point_on_segment(start, end).get_distance_to_point(point) <= tolerance {
+                other.insert_point(point, tolerance);
+            }
+        }
+
+        other.points.len() > number_of_other_points
+    }
+
+    fn has_edge(&self, a: usize, b: usize) -> bool {
+        self.triangles.iter().any(|triangle| triangle.contains(&a) && triangle.contains(&b))
+    }
+
+    /// Finds where the segment from the vertex `from` to the vertex `to` leaves the triangles
+    /// around `from`.
+    fn get_next_crossing(&self, from: usize, to: usize, tolerance: f64) -> Option<Crossing> {
+        let origin = self.points[from];
+        let direction = Vector::from_2_points(&origin, &self.points[to]);
+
+        let mut closest: Option<(f64, Crossing)> = None;
+        for triangle in &self.triangles {
+            let position = match triangle.iter().position(|vertex_id| *vertex_id == from) {
+                Some(position) => position,
+                None => continue,
+            };
+            let (a, b) = (triangle[(position + 1) % 3], triangle[(position + 2) % 3]);
+            let side = Vector::from_2_points(&self.points[a], &self.points[b]);
+            let to_a = Vector::from_2_points(&origin, &self.points[a]);
+
+            // origin + direction * t = a + side * s, solved with cross products
+            let normal = direction.get_cross_product(&side);
+            let denominator = normal.get_dot_product(&normal);
+            if denominator <= direction.get_dot_product(&direction) * side.get_dot_product(&side) * 1e-18 {
+                continue;
+            }
+            let t = to_a.get_cross_product(&side).get_dot_product(&normal) / denominator;
+            let s = to_a.get_cross_product(&direction).get_dot_product(&normal) / denominator;
+            let side_tolerance = tolerance / side.get_length();
+            if t <= 0.0 || s < -side_tolerance || s > 1.0 + side_tolerance {
+                continue;
+            }
+            if closest.as_ref().is_some_and(|(closest_t, _)| *closest_t <= t) {
+                continue;
+            }
+
+            let point = self.points[a] + side * s.clamp(0.0, 1.0);
+            let crossing = if point.get_distance_to_point(&self.points[a]) <= tolerance {
+                Crossing::Vertex(a)
+            } else if point.get_distance_to_point(&self.points[b]) <= tolerance {
+                Crossing::Vertex(b)
+            } else {
+                Crossing::Edge(a, b, point)
+            };
+            closest = Some((t, crossing));
+        }
+
+        closest.map(|(_, crossing)| crossing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::get_cube;
+    use super::*;
+
+    #[test]
+    fn test_boolean_union_overlapping_cubes() {
+        let input = get_cube(0.0, 2.0);
+        let other = get_cube(1.0, 3.0);
+
+        let actual = input.boolean_union(&other, 1e-9).unwrap();
+
+        assert!(actual.is_watertight());
+        assert_eq!(actual.get_number_of_connected_components(), 1);
+        assert!((actual.get_volume().unwrap() - 15.0).abs() < 1e-9);
+        assert!((actual.get_area() - 42.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_boolean_union_face_sharing_boxes() {
+        let input = get_cube(0.0, 2.0);
+        let sharing_whole_face = get_cube(0.0, 2.0) + Vector::new(2.0, 0.0, 0.0);
+        let sharing_part_of_face = get_cube(0.0, 2.0) + Vector::new(2.0, 0.5, 0.5);
+
+        assert!(input.boolean_union(&sharing_whole_face, 1e-9).is_err());
+        assert!(input.boolean_union(&sharing_part_of_face, 1e-9).is_err());
+        assert!(sharing_part_of_face.boolean_union(&input, 1e-9).is_err());
+    }
+
+    #[test]
+    fn test_boolean_union_tilted_tetrahedron_through_cube() {
+        let input = get_cube(0.0, 2.0);
+        let other = Mesh::new(
+            vec![1.1, 0.9, 1.3, 3.7, 1.2, 1.1, 1.4, 3.6, 0.8, 1.6, 1.3, 3.9],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]);
+        let other_volume = other.get_volume().unwrap();
+        assert!(other_volume > 0.0);
+
+        let actual = input.boolean_union(&other, 1e-9).unwrap();
+
+        assert!(actual.is_watertight());
+        let volume = actual.get_volume().unwrap();
+        assert!(volume > 8.0 && volume < 8.0 + other_volume);
+        for point in actual.to_points() {
+            assert!(input.contains_point(&point) || other.contains_point(&point) ||
+                input.get_signed_distance(&point).abs() < 1e-6 || other.get_signed_distance(&point).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_boolean_union_disjoint() {
+        let input = get_cube(0.0, 1.0);
+        let other = get_cube(2.0, 3.0);
+
+        let actual = input.boolean_union(&other, 1e-9).unwrap();
+
+        assert!(actual.is_watertight());
+        assert_eq!(actual.get_number_of_faces(), 24);
+        assert_eq!(actual.get_number_of_connected_components(), 2);
+        assert!((actual.get_volume().unwrap() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_boolean_union_contained() {
+        let input = get_cube(0.0, 3.0);
+        let other = get_cube(1.0, 2.0);
+
+        let actual = input.boolean_union(&other, 1e-9).unwrap();
+
+        assert!(actual.is_watertight());
+        assert_eq!(actual.get_number_of_faces(), 12);
+        assert!((actual.get_volume().unwrap() - 27.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_boolean_union_not_watertight() {
+        let input = get_cube(0.0, 1.0);
+        let open = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        assert_eq!(input.boolean_union(&open, 1e-9), Err("The other Mesh is not watertight".to_string()));
+        assert_eq!(open.boolean_union(&input, 1e-9), Err("The Mesh is not watertight".to_string()));
+    }
+
+    #[test]
+    fn test_split_face_insert_segment_crossing_triangle() {
+        let triangle = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0), Point::new(0.0, 4.0, 0.0));
+        let mut split_face = SplitFace::new(&triangle);
+
+        split_face.insert_segment(&Point::new(1.0, 0.0, 0.0), &Point::new(1.0, 3.0, 0.0), 1e-9);
+        split_face.insert_segment(&Point::new(0.5, 0.5, 0.0), &Point::new(2.0, 1.0, 0.0), 1e-9);
+
+        let actual = split_face.to_triangles();
+
+        let area: f64 = actual.iter().map(|triangle| triangle.get_area()).sum();
+        assert!((area - 8.0).abs() < 1e-9);
+        for triangle in &actual {
+            assert!(triangle.get_normal_vector_unitized().eq_with_tolerance(&Vector::new(0.0, 0.0, 1.0), 1e-9));
+        }
+        assert!(is_segment_made_of_edges(&split_face, &Point::new(1.0, 0.0, 0.0), &Point::new(1.0, 3.0, 0.0)));
+        assert!(is_segment_made_of_edges(&split_face, &Point::new(0.5, 0.5, 0.0), &Point::new(2.0, 1.0, 0.0)));
+    }
+
+    fn is_segment_made_of_edges(split_face: &SplitFace, start: &Point, end: &Point) -> bool {
+        let direction = Vector::from_2_points(start, end);
+        let mut on_segment: Vec<(f64, usize)> = split_face.points.iter().enumerate()
+            .filter(|(_, point)| point.get_closest_point_on_segment(start, end).get_distance_to_point(point) < 1e-9)
+            .map(|(vertex_id, point)| (Vector::from_2_points(start, point).get_dot_product(&direction), vertex_id))
+            .collect();
+        on_segment.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        on_segment.windows(2).all(|pair| split_face.has_edge(pair[0].1, pair[1].1))
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+use std::sync::OnceLock;
+use crate::bounding_box::BoundingBox;
+use crate::bvh::Bvh;
+use crate::mesh::Mesh;
+use crate::vector::Vector;
+
+/// Lazily computed values of the [Mesh], see [Mesh::invalidate_cache].
+///
+/// Cloning gives an empty cache, so editing a cloned [Mesh] never reads stale values.
+#[derive(Default)]
+pub(crate) struct MeshCache {
+    face_normals: OnceLock<Vec<Option<Vector>>>,
+    face_areas: OnceLock<Vec<f64>>,
+    bounding_box: OnceLock<BoundingBox>,
+    bvh: OnceLock<Bvh>,
+}
+
+impl Clone for MeshCache {
+    fn clone(&self) -> Self {
+        MeshCache::default()
+    }
+}
+
+impl fmt::Debug for MeshCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeshCache")
+            .field("face_normals", &self.face_normals.get().is_some())
+            .field("face_areas", &self.face_areas.get().is_some())
+            .field("bounding_box", &self.bounding_box.get().is_some())
+            .field("bvh", &self.bvh.get().is_some())
+            .finish()
+    }
+}
+
+impl Mesh {
+    /// Returns unitized normals of all the faces, computed on the first call and cached.
+    ///
+    /// Degenerate faces have `None`, same as [Mesh::get_face_normal_vectors_unitized_checked].
+    ///
+    /// Methods of this crate which edit the [Mesh] in place clear the cache, but `coordinates`
+    /// and `indices` are public, so after editing them directly call [Mesh::invalidate_cache].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0], vec![0, 1, 2, 0, 1, 3]);
+    ///
+    /// let actual = input.get_cached_face_normals();
+    ///
+    /// assert_eq!(actual, &[Some(Vector::new(0.0, 0.0, 1.0)), None]);
+    /// ```
+    pub fn get_cached_face_normals(&self) -> &[Option<Vector>] {
+        self.cache.face_normals.get_or_init(|| self.get_face_normal_vectors_unitized_checked())
+    }
+
+    /// Returns areas of all the faces, computed on the first call and cached.
+    ///
+    /// See [Mesh::get_cached_face_normals] for the rules of caching.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 4.0, 0.0], vec![0, 1, 2, 0, 1, 3]);
+    ///
+    /// let actual = input.get_cached_face_areas();
+    ///
+    /// assert!((actual[0] - 0.5).abs() < 1e-12);
+    /// assert!((actual[1] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn get_cached_face_areas(&self) -> &[f64] {
+        self.cache.face_areas.get_or_init(|| self.to_triangles().iter().map(|triangle| triangle.get_area()).collect())
+    }
+
+    /// Returns the Bounding Box (AABB), computed on the first call and cached.
+    ///
+    /// See [Mesh::get_cached_face_normals] for the rules of caching. It panics for the [Mesh]
+    /// without vertices, same as [Mesh::get_bounding_box].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 2.0], vec![0, 1, 2]);
+    /// assert!(input.get_cached_bounding_box().eq(&BoundingBox::new(0.0, 1.0, 0.0, 1.0, 0.0, 2.0)));
+    ///
+    /// input.coordinates[0] = -5.0;
+    /// input.invalidate_cache();
+    ///
+    /// assert!(input.get_cached_bounding_box().eq(&BoundingBox::new(-5.0, 1.0, 0.0, 1.0, 0.0, 2.0)));
+    /// ```
+    pub fn get_cached_bounding_box(&self) -> BoundingBox {
+        *self.cache.bounding_box.get_or_init(|| self.get_bounding_box())
+    }
+
+    /// Returns the [Bvh] over faces, built on the first call and cached.
+    ///
+    /// See [Mesh::get_cached_face_normals] for the rules of caching. Ray queries of this crate
+    /// use it, so repeated queries against the same [Mesh] don't check every face.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let actual = input.get_cached_bvh().get_closest_point(&Point::new(0.25, 0.25, 3.0));
+    ///
+    /// assert_eq!(actual, Some((0, Point::new(0.25, 0.25, 0.0))));
+    /// ```
+    pub fn get_cached_bvh(&self) -> &Bvh {
+        self.cache.bvh.get_or_init(|| Bvh::from_mesh(self))
+    }
+
+    /// Clears all the cached values, so they're computed again on the next call.
+    ///
+    /// It should be called after editing `coordinates` or `indices` directly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// assert!((input.get_cached_face_areas()[0] - 0.5).abs() < 1e-12);
+    ///
+    /// input.coordinates[3] = 2.0;
+    /// input.invalidate_cache();
+    ///
+    /// assert!((input.get_cached_face_areas()[0] - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn invalidate_cache(&mut self) {
+        self.cache = MeshCache::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point;
+    use crate::units::Units;
+    use super::*;
+
+    #[test]
+    fn test_cache_is_reused() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        let first = input.get_cached_face_areas().as_ptr();
+        let second = input.get_cached_face_areas().as_ptr();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_bvh_rebuilt_after_invalidate_cache() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        assert_eq!(input.get_cached_bvh().get_number_of_faces(), 1);
+
+        input.coordinates.extend_from_slice(&[5.0, 5.0, 5.0]);
+        input.indices.extend_from_slice(&[1, 2, 3]);
+        input.invalidate_cache();
+
+        assert_eq!(input.get_cached_bvh().get_number_of_faces(), 2);
+    }
+
+    #[test]
+    fn test_clone_has_empty_cache() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.get_cached_bounding_box();
+
+        let mut actual = input.clone();
+        actual.coordinates[4] = 3.0;
+
+        assert_eq!(actual.get_cached_bounding_box().max_y, 3.0);
+        assert_eq!(input.get_cached_bounding_box().max_y, 1.0);
+    }
+
+    #[test]
+    fn test_convert_units_invalidates_cache() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        assert!((input.get_cached_face_areas()[0] - 0.5).abs() < 1e-12);
+
+        input.convert_units(Units::Meters, Units::Centimeters);
+
+        assert!((input.get_cached_face_areas()[0] - 5000.0).abs() < 1e-9);
+        assert_eq!(input.get_cached_bounding_box().max_x, 100.0);
+    }
+
+    #[test]
+    fn test_rebase_invalidates_cache() {
+        let mut input = vec![Mesh::new(vec![100.0, 0.0, 0.0, 101.0, 0.0, 0.0, 100.0, 1.0, 0.0], vec![0, 1, 2])];
+        assert_eq!(input[0].get_cached_bounding_box().min_x, 100.0);
+
+        Mesh::rebase(&mut input, &Point::new(100.0, 0.0, 0.0));
+
+        assert_eq!(input[0].get_cached_bounding_box().min_x, 0.0);
+        assert_eq!(input[0].get_cached_bvh().get_closest_point(&Point::new(0.0, 0.0, 1.0)), Some((0, Point::new(0.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn test_cache_ignored_by_eq_and_serialization() {
+        let a = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        let b = a.clone();
+        a.get_cached_face_normals();
+
+        assert_eq!(a, b);
+        assert_eq!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::cleanup_options::CleanupOptions;
+use crate::cleanup_report::CleanupReport;
+use crate::mesh::Mesh;
+
+impl Mesh {
+    /// Creates a new cleaned [Mesh] in one pass, together with the [CleanupReport] of what was
+    /// changed.
+    ///
+    /// Steps are done in this order:
+    /// 1. If `weld_tolerance` is set, vertices are welded, see [Mesh::get_with_welded_vertices].
+    /// 2. Faces with area not higher than `zero_area_tolerance` are removed, including faces
+    ///    collapsed by welding.
+    /// 3. Faces using the same vertices as one of the earlier faces are removed, regardless of
+    ///    their winding.
+    /// 4. Vertices not used by any face are removed, see [Mesh::get_compacted].
+    /// 5. If `fix_winding` is set, faces are flipped so each connected part has the winding
+    ///    consistent across its manifold edges. In each part the winding of the majority of
+    ///    faces is kept. Non-orientable parts can't be fully fixed.
+    ///
+    /// `uvs`, `vertex_colors`, `vertex_normals`, `face_groups` and `attributes` are kept for
+    /// the kept vertices and faces, together with `id` and `metadata`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::cleanup_options::CleanupOptions;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    /// vec![
+    ///     0.0, 0.0, 0.0,
+    ///     1.0, 0.0, 0.0,
+    ///     1.0, 1.0, 0.0,
+    ///     0.0, 1.0, 0.0,
+    ///     5.0, 5.0, 5.0, // unused
+    /// ],
+    /// vec![
+    ///     0, 1, 2,
+    ///     0, 2, 3,
+    ///     2, 1, 0, // duplicate of 0
+    ///     0, 1, 1, // zero-area
+    /// ]);
+    ///
+    /// let (actual, report) = input.get_cleaned(CleanupOptions::new(0.0, None, false));
+    ///
+    /// assert_eq!(actual.indices, vec![0, 1, 2, 0, 2, 3]);
+    /// assert_eq!(actual.get_number_of_vertices(), 4);
+    /// assert_eq!(report.duplicate_face_ids, vec![2]);
+    /// assert_eq!(report.zero_area_face_ids, vec![3]);
+    /// assert_eq!(report.number_of_removed_unused_vertices, 1);
+    /// ```
+    pub fn get_cleaned(&self, options: CleanupOptions) -> (Mesh, CleanupReport) {
+        let (welded, number_of_welded_vertices) = match options.weld_tolerance {
+            Some(tolerance) => {
+                let (mut welded, weld_report) = self.get_with_welded_vertices_with_report(tolerance, usize::MAX);
+                welded.id = self.id;
+                welded.metadata = self.metadata.clone();
+                (welded, weld_report.number_of_merged_vertices)
+            },
+            None => (self.clone(), 0),
+        };
+
+        let mut zero_area_face_ids = Vec::new();
+        let mut duplicate_face_ids = Vec::new();
+        let mut kept_face_ids = Vec::new();
+        let mut first_faces: HashSet<[usize; 3]> = HashSet::new();
+        for (face_id, triangle) in welded.to_triangles().iter().enumerate() {
+            let mut sorted = [welded.indices[face_id * 3], welded.indices[face_id * 3 + 1], welded.indices[face_id * 3 + 2]];
+            sorted.sort();
+            if sorted[0] == sorted[1] || sorted[1] == sorted[2] || triangle.get_area() <= options.zero_area_tolerance {
+                zero_area_face_ids.push(face_id);
+            } else if !first_faces.insert(sorted) {
+                duplicate_face_ids.push(face_id);
+            } else {
+                kept_face_ids.push(face_id);
+            }
+        }
+
+        let (mut cleaned, new_vertex_ids) = welded.get_compacted_part_by_face_ids(&kept_face_ids);
+        let number_of_removed_unused_vertices = new_vertex_ids.iter().filter(|new_vertex_id| new_vertex_id.is_none()).count();
+
+        let mut flipped_face_ids = Vec::new();
+        if options.fix_winding {
+            flipped_face_ids = cleaned.get_face_ids_to_flip_for_consistent_winding();
+            cleaned.flip_faces(&flipped_face_ids.iter().copied().collect());
+        }
+
+        (cleaned, CleanupReport {
+            number_of_welded_vertices,
+            zero_area_face_ids,
+            duplicate_face_ids,
+            number_of_removed_unused_vertices,
+            flipped_face_ids,
+        })
+    }
+
+    /// Gets sorted ids of faces, which should be flipped to make the winding of each connected
+    /// part consistent across its manifold edges, keeping the winding of the majority of faces.
+    fn get_face_ids_to_flip_for_consistent_winding(&self) -> Vec<usize> {
+        // Each undirected edge with faces using it, and whether they go from the lower vertex id
+        let mut edge_faces: HashMap<(usize, usize), Vec<(usize, bool)>> = HashMap::new();
+        for (face_id, face) in self.indices.chunks(3).enumerate() {
+            for i in 0..3 {
+                let (start, end) = (face[i], face[(i + 1) % 3]);
+                edge_faces.entry((start.min(end), start.max(end))).or_default().push((face_id, start < end));
+            }
+        }
+
+        let number_of_faces = self.get_number_of_faces();
+        let mut is_flipped: Vec<Option<bool>> = vec![None; number_of_faces];
+        let mut face_ids_to_flip = Vec::new();
+        for seed_face_id in 0..number_of_faces {
+            if is_flipped[seed_face_id].is_some() {
+                continue;
+            }
+            is_flipped[seed_face_id] = Some(false);
+            let mut part_face_ids = vec![seed_face_id];
+            let mut queue = VecDeque::from([seed_face_id]);
+            while let Some(face_id) = queue.pop_front() {
+                let face = &self.indices[face_id * 3..face_id * 3 + 3];
+                for i in 0..3 {
+                    let (start, end) = (face[i], face[(i + 1) % 3]);
+                    let faces = &edge_faces[&(start.min(end), start.max(end))];
+                    if faces.len() != 2 {
+                        continue;
+                    }
+                    let (&(current_face_id, current_forward), &(other_face_id, other_forward)) = if faces[0].0 == face_id { (&faces[0], &faces[1]) } else { (&faces[1], &faces[0]) };
+                    if other_face_id == current_face_id || is_flipped[other_face_id].is_some() {
+                        continue;
+                    }
+                    // Consistent neighbours use the shared edge in opposite directions
+                    let current_final_forward = current_forward != is_flipped[current_face_id].unwrap();
+                    is_flipped[other_face_id] = Some(other_forward == current_final_forward);
+                    part_face_ids.push(other_face_id);
+                    queue.push_back(other_face_id);
+                }
+            }
+
+            let number_of_flipped = part_face_ids.iter().filter(|face_id| is_flipped[**face_id].unwrap()).count();
+            let are_flipped_in_minority = number_of_flipped * 2 <= part_face_ids.len();
+            face_ids_to_flip.extend(part_face_ids.into_iter().filter(|face_id| is_flipped[*face_id].unwrap() == are_flipped_in_minority));
+        }
+        face_ids_to_flip.sort();
+
+        face_ids_to_flip
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edge_adjacency::EdgeAdjacency;
+    use crate::mesh_attributes::AttributeValues;
+
+    fn get_tetrahedron() -> Mesh {
+        Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2])
+    }
+
+    fn get_quad_with_issues() -> Mesh {
+        Mesh::new(
+            vec![
+                0.0, 0.0, 0.0,
+                1.0, 0.0, 0.0,
+                1.0, 1.0, 0.0,
+                0.0, 1.0, 0.0,
+                5.0, 5.0, 5.0, // unused
+                2.0, 0.0, 0.0, // collinear with 0 and 1
+            ],
+            vec![
+                0, 1, 2,
+                0, 2, 3,
+                2, 1, 0, // duplicate of 0
+                0, 1, 5, // zero-area
+                0, 2, 3, // duplicate of 1
+            ])
+    }
+
+    #[test]
+    fn test_get_cleaned() {
+        let mut input = get_quad_with_issues();
+        input.face_groups = Some(vec![0, 1, 2, 3, 4]);
+        input.attributes.vertex.insert(String::from("temperature"), AttributeValues::F64(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]));
+
+        let (actual, report) = input.get_cleaned(CleanupOptions::new(0.0, None, false));
+
+        assert_eq!(actual.coordinates, vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(actual.indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(actual.face_groups, Some(vec![0, 1]));
+        assert_eq!(actual.attributes.vertex["temperature"], AttributeValues::F64(vec![0.0, 1.0, 2.0, 3.0]));
+        assert_eq!(report, CleanupReport {
+            number_of_welded_vertices: 0,
+            zero_area_face_ids: vec![3],
+            duplicate_face_ids: vec![2, 4],
+            number_of_removed_unused_vertices: 2,
+            flipped_face_ids: vec![],
+        });
+    }
+
+    #[test]
+    fn test_get_cleaned_zero_area_tolerance() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.5, 0.001, 0.0],
+            vec![0, 1, 2, 0, 3, 1]);
+
+        let (actual, report) = input.get_cleaned(CleanupOptions::new(0.01, None, false));
+
+        assert_eq!(actual.indices, vec![0, 1, 2]);
+        assert_eq!(report.zero_area_face_ids, vec![1]);
+        assert_eq!(report.number_of_removed_unused_vertices, 1);
+    }
+
+    #[test]
+    fn test_get_cleaned_with_welding() {
+        // Unwelded quad, with a sliver face collapsing after welding
+        let input = Mesh::new(
+            vec![
+                0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+                1.0, 0.0, 0.0, 1.0, 0.0, 0.0005, 1.0, 1.0, 0.0,
+            ],
+            vec![0, 1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let (actual, report) = input.get_cleaned(CleanupOptions::new(0.0, Some(0.001), false));
+
+        assert_eq!(actual.get_number_of_vertices(), 4);
+        assert_eq!(actual.get_number_of_faces(), 2);
+        assert_eq!(report.number_of_welded_vertices, 5);
+        assert_eq!(report.zero_area_face_ids, vec![2]);
+        assert!(report.duplicate_face_ids.is_empty());
+        assert_eq!(report.number_of_removed_unused_vertices, 0);
+    }
+
+    #[test]
+    fn test_get_cleaned_fix_winding() {
+        let mut input = get_tetrahedron();
+        input.flip_faces(&HashSet::from([2]));
+
+        let (actual, report) = input.get_cleaned(CleanupOptions::new(0.0, None, true));
+
+        assert_eq!(report.flipped_face_ids, vec![2]);
+        assert!(EdgeAdjacency::from_mesh(&actual).get_inconsistent_winding_edges().is_empty());
+        assert_eq!(actual, get_tetrahedron());
+    }
+
+    #[test]
+    fn test_get_cleaned_fix_winding_keeps_majority() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0],
+            vec![0, 2, 1, 0, 2, 3, 1, 4, 2]); // first face in minority
+
+        let (actual, report) = input.get_cleaned(CleanupOptions::new(0.0, None, true));
+
+        assert_eq!(report.flipped_face_ids, vec![0]);
+        assert_eq!(actual.indices, vec![1, 2, 0, 0, 2, 3, 1, 4, 2]);
+    }
+
+    #[test]
+    fn test_get_cleaned_unchanged() {
+        let input = get_tetrahedron();
+
+        let (actual, report) = input.get_cleaned(CleanupOptions::new(0.0, Some(0.001), true));
+
+        assert!(report.is_unchanged());
+        assert_eq!(actual, input);
+    }
+}
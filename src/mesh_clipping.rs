@@ -0,0 +1,375 @@
+use std::collections::HashMap;
+use crate::kd_tree::KdTree;
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::mesh::Mesh;
+use crate::mesh_sectioning::SectionNodes;
+use crate::plane::Plane;
+use crate::plane_side::PlaneSide;
+use crate::point::Point;
+use crate::point2d::Point2D;
+use crate::polygon::Polygon;
+use crate::polygon2d::Polygon2D;
+
+impl Mesh {
+    /// Splits the [Mesh] with the [Plane], returning the part above it (on the side its normal
+    /// points to) and the part below it.
+    ///
+    /// Faces crossing the Plane are cut, faces lying on the Plane go to the part below, see
+    /// [Mesh::clip_by_plane] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    /// let plane = Plane::new(Point::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// let (above, below) = tetrahedron.split_by_plane(&plane, true);
+    ///
+    /// assert!(above.is_watertight());
+    /// assert!(below.is_watertight());
+    /// assert!((above.get_volume().unwrap() - 4.0 / 3.0).abs() < 1e-9);
+    /// assert!((below.get_volume().unwrap() - (4.5 - 4.0 / 3.0)).abs() < 1e-9);
+    /// ```
+    pub fn split_by_plane(&self, plane: &Plane, cap: bool) -> (Mesh, Mesh) {
+        let distances: Vec<f64> = self.to_points().iter().map(|point| plane.get_signed_distance_to_point(point)).collect();
+        let section = self.get_section_nodes(&distances);
+        let cap_triangles = if cap { get_cap_triangles(plane, &section) } else { Vec::new() };
+
+        (
+            self.get_plane_part(&distances, &section, &cap_triangles, PlaneSide::Above),
+            self.get_plane_part(&distances, &section, &cap_triangles, PlaneSide::Below),
+        )
+    }
+
+    /// Clips the [Mesh] with the [Plane], keeping only the part on the given side of it.
+    ///
+    /// Faces crossing the Plane are cut along it, keeping their winding. Vertices lying exactly
+    /// on the Plane are shared by both sides, and faces lying on the Plane are kept with the
+    /// part below it.
+    ///
+    /// If `cap` is `true`, the cut is closed with closed section curves (see
+    /// [Mesh::section_with_plane]) triangulated together with their holes, facing outside the
+    /// kept part. Caps share vertices with cut faces, so a welded watertight [Mesh] stays
+    /// watertight. Open section curves can't be capped.
+    ///
+    /// Only the geometry is kept: the result has no face groups, uvs, colors nor attributes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::plane_side::PlaneSide;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let square = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 4.0, 4.0, 0.0, 0.0, 4.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    /// let plane = Plane::new(Point::new(1.0, 0.0, 0.0), Vector::new(-1.0, 0.0, 0.0));
+    ///
+    /// let actual = square.clip_by_plane(&plane, PlaneSide::Below, false);
+    ///
+    /// assert!(actual.to_points().iter().all(|point| point.x >= 1.0));
+    /// assert!((actual.get_area() - 12.0).abs() < 1e-12);
+    /// ```
+    pub fn clip_by_plane(&self, plane: &Plane, keep_side: PlaneSide, cap: bool) -> Mesh {
+        let distances: Vec<f64> = self.to_points().iter().map(|point| plane.get_signed_distance_to_point(point)).collect();
+        let section = self.get_section_nodes(&distances);
+        let cap_triangles = if cap { get_cap_triangles(plane, &section) } else { Vec::new() };
+
+        self.get_plane_part(&distances, &section, &cap_triangles, keep_side)
+    }
+
+    /// Gets the part of the [Mesh] on the given side of the Plane, together with caps given as
+    /// node ids oriented along the Plane's normal.
+    fn get_plane_part(&self, distances: &[f64], section: &SectionNodes, cap_triangles: &[[usize; 3]], side: PlaneSide) -> Mesh {
+        let edge_nodes: HashMap<(usize, usize), usize> = section.edges.iter().enumerate().map(|(node, edge)| (*edge, node)).collect();
+        let is_kept = |vertex: usize| (distances[vertex] > 0.0) == (side == PlaneSide::Above);
+        let mut vertices = PlanePartVertices {
+            mesh: self,
+            distances,
+            section,
+            coordinates: Vec::new(),
+            vertex_ids: HashMap::new(),
+            node_vertex_ids: vec![None; section.points.len()],
+        };
+
+        let mut indices = Vec::new();
+        for face in self.indices.chunks_exact(3) {
+            let mut polygon: Vec<usize> = Vec::with_capacity(4);
+            for (first, second) in [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])] {
+                if is_kept(first) {
+                    polygon.push(vertices.get_vertex_id(first));
+                }
+                if (distances[first] > 0.0) != (distances[second] > 0.0) {
+                    polygon.push(vertices.get_node_vertex_id(edge_nodes[&(first.min(second), first.max(second))]));
+                }
+            }
+            polygon.dedup(); // Crossings at vertices on the Plane
+            if polygon.len() > 1 && polygon[0] == polygon[polygon.len() - 1] {
+                polygon.pop();
+            }
+            if polygon.len() > 2 {
+                for pair in polygon[1..].windows(2) {
+                    indices.extend([polygon[0], pair[0], pair[1]]);
+                }
+            }
+        }
+
+        for triangle in cap_triangles {
+            let [a, b, c] = triangle.map(|node| vertices.get_node_vertex_id(node));
+            match side {
+                PlaneSide::Above => indices.extend([a, c, b]),
+                PlaneSide::Below => indices.extend([a, b, c]),
+            }
+        }
+
+        Mesh::new(vertices.coordinates, indices)
+    }
+}
+
+/// Vertices of a part of a [Mesh] cut by a Plane, reusing original vertices and section nodes.
+struct PlanePartVertices<'a> {
+    mesh: &'a Mesh,
+    distances: &'a [f64],
+    section: &'a SectionNodes,
+    coordinates: Vec<f64>,
+    /// New ids of original vertices.
+    vertex_ids: HashMap<usize, usize>,
+    /// New ids of vertices at section nodes.
+    node_vertex_ids: Vec<Option<usize>>,
+}
+
+impl PlanePartVertices<'_> {
+    fn get_vertex_id(&mut self, vertex_id: usize) -> usize {
+        if let Some(new_vertex_id) = self.vertex_ids.get(&vertex_id) {
+            return *new_vertex_id;
+        }
+        let new_vertex_id = self.coordinates.len() / 3;
+        self.coordinates.extend_from_slice(&self.mesh.coordinates[vertex_id * 3..vertex_id * 3 + 3]);
+        self.vertex_ids.insert(vertex_id, new_vertex_id);
+
+        new_vertex_id
+    }
+
+    fn get_node_vertex_id(&mut self, node: usize) -> usize {
+        if let Some(new_vertex_id) = self.node_vertex_ids[node] {
+            return new_vertex_id;
+        }
+        let (first, second) = self.section.edges[node];
+        let new_vertex_id = if self.distances[first] == 0.0 { // The original vertex on the Plane
+            self.get_vertex_id(first)
+        }
+        else if self.distances[second] == 0.0 {
+            self.get_vertex_id(second)
+        }
+        else {
+            let point = self.section.points[node];
+            self.coordinates.extend([point.x, point.y, point.z]);
+            self.coordinates.len() / 3 - 1
+        };
+        self.node_vertex_ids[node] = Some(new_vertex_id);
+
+        new_vertex_id
+    }
+}
+
+/// Triangulates closed section curves, so they can cap parts of the [Mesh] cut by the Plane.
+/// Returns triangles as node ids, oriented along the Plane's normal.
+fn get_cap_triangles(plane: &Plane, section: &SectionNodes) -> Vec<[usize; 3]> {
+    let x = plane.normal.get_any_perpendicular();
+    let local_coordinate_system = LocalCoordinateSystem::new(plane.origin, x, plane.normal.get_cross_product(&x));
+    let polygons: Vec<Polygon2D> = section.closed_chains.iter()
+        .map(|chain| Polygon::new(chain.iter().map(|node| section.points[*node]).collect()).to_polygon2d(&local_coordinate_system))
+        .collect();
+
+    // Outer contours go counter-clockwise and holes clockwise, see Mesh::section_with_plane
+    let (outer_ids, hole_ids): (Vec<usize>, Vec<usize>) = (0..polygons.len()).partition(|polygon_id| !polygons[*polygon_id].is_clockwise());
+    let mut groups: Vec<Vec<usize>> = outer_ids.iter().map(|outer_id| vec![*outer_id]).collect(); // Outer contour first, then its holes
+    for hole_id in hole_ids {
+        let smallest_outer = (0..outer_ids.len())
+            .filter(|group_id| is_point_inside(&polygons[outer_ids[*group_id]], &polygons[hole_id].vertices[0]))
+            .min_by(|a, b| get_area(&polygons[outer_ids[*a]]).total_cmp(&get_area(&polygons[outer_ids[*b]])));
+        if let Some(group_id) = smallest_outer {
+            groups[group_id].push(hole_id);
+        }
+    }
+
+    let mut triangles = Vec::new();
+    for group in groups {
+        triangles.extend(get_group_cap_triangles(section, &polygons, &group));
+    }
+
+    triangles
+}
+
+/// Triangulates the outer contour together with its holes. Vertices skipped by the
+/// triangulation (e.g. on straight parts of curves) are brought back, so caps match cut faces.
+fn get_group_cap_triangles(section: &SectionNodes, polygons: &[Polygon2D], group: &[usize]) -> Vec<[usize; 3]> {
+    let mut nodes = Vec::new(); // Of all the curves, one after another
+    let mut points = Vec::new();
+    let mut next_positions = Vec::new();
+    for polygon_id in group {
+        let start = nodes.len();
+        let chain = &section.closed_chains[*polygon_id];
+        for (i, vertex) in polygons[*polygon_id].vertices.iter().enumerate() {
+            nodes.push(chain[i]);
+            points.push(Point::new(vertex.x, vertex.y, 0.0));
+            next_positions.push(start + (i + 1) % chain.len());
+        }
+    }
+    let holes: Vec<Polygon2D> = group[1..].iter().map(|hole_id| Polygon2D::new(polygons[*hole_id].vertices.clone())).collect();
+    let triangulated = polygons[group[0]].triangulate_raw_with_holes(&holes);
+
+    let kd_tree = KdTree::from_points(&points);
+    let positions: Vec<usize> = triangulated.to_points().iter()
+        .map(|point| kd_tree.get_nearest_point_id(point).unwrap()) // There are always some Points
+        .collect();
+    let mut is_used = vec![false; points.len()];
+    for position in &positions {
+        is_used[*position] = true;
+    }
+
+    let mut triangles = Vec::new();
+    for face in triangulated.indices.chunks_exact(3) {
+        let mut corners = [positions[face[0]], positions[face[1]], positions[face[2]]];
+        if corners[0] == corners[1] || corners[1] == corners[2] || corners[2] == corners[0] {
+            continue;
+        }
+        let [a, b, c] = corners.map(|position| points[position]);
+        if (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x) < 0.0 {
+            corners.swap(1, 2);
+        }
+
+        let mut outline = Vec::with_capacity(3);
+        let mut fan_corner = 0;
+        for (i, start) in corners.iter().enumerate() {
+            outline.push(*start);
+            let mut skipped = Vec::new();
+            let mut position = next_positions[*start];
+            while !is_used[position] {
+                skipped.push(position);
+                position = next_positions[position];
+            }
+            if position == corners[(i + 1) % 3] && !skipped.is_empty() { // The edge goes along the curve
+                outline.extend(skipped);
+                fan_corner = (i + 2) % 3;
+            }
+        }
+        let fan_start = outline.iter().position(|position| *position == corners[fan_corner]).unwrap_or(0);
+        for j in 1..outline.len() - 1 {
+            let second = outline[(fan_start + j) % outline.len()];
+            let third = outline[(fan_start + j + 1) % outline.len()];
+            triangles.push([nodes[outline[fan_start]], nodes[second], nodes[third]]);
+        }
+    }
+
+    triangles
+}
+
+fn is_point_inside(polygon: &Polygon2D, point: &Point2D) -> bool {
+    let vertices = &polygon.vertices;
+    let mut is_inside = false;
+    let mut previous = &vertices[vertices.len() - 1];
+    for vertex in vertices {
+        if (vertex.y > point.y) != (previous.y > point.y) && point.x < (previous.x - vertex.x) * (point.y - vertex.y) / (previous.y - vertex.y) + vertex.x {
+            is_inside = !is_inside;
+        }
+        previous = vertex;
+    }
+
+    is_inside
+}
+
+fn get_area(polygon: &Polygon2D) -> f64 {
+    let vertices = &polygon.vertices;
+    let mut sum = 0.0;
+    let mut previous = &vertices[vertices.len() - 1];
+    for vertex in vertices {
+        sum += previous.x * vertex.y - vertex.x * previous.y;
+        previous = vertex;
+    }
+
+    sum.abs() / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_cube;
+    use crate::vector::Vector;
+
+    #[test]
+    fn test_split_by_plane_cube_capped() {
+        let input = get_cube(0.0, 2.0);
+        let plane = Plane::new(Point::new(0.0, 0.0, 0.5), Vector::new(0.0, 0.0, 1.0));
+
+        let (above, below) = input.split_by_plane(&plane, true);
+
+        assert!(above.is_watertight());
+        assert!(below.is_watertight());
+        assert!((above.get_volume().unwrap() - 6.0).abs() < 1e-12);
+        assert!((below.get_volume().unwrap() - 2.0).abs() < 1e-12);
+        assert!(above.to_points().iter().all(|point| point.z >= 0.5));
+        assert!(below.to_points().iter().all(|point| point.z <= 0.5));
+    }
+
+    #[test]
+    fn test_clip_by_plane_cube_not_capped() {
+        let input = get_cube(0.0, 2.0);
+        let plane = Plane::new(Point::new(0.0, 0.0, 0.5), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = input.clip_by_plane(&plane, PlaneSide::Below, false);
+
+        assert_eq!(actual.get_number_of_faces(), 14);
+        assert!(!actual.is_closed());
+        assert!(actual.to_points().iter().all(|point| point.z <= 0.5));
+    }
+
+    #[test]
+    fn test_clip_by_plane_capped_with_hole() {
+        let input = Mesh::join(&vec![get_cube(0.0, 4.0), get_cube(1.0, 3.0).get_with_all_faces_flipped()]);
+        let plane = Plane::new(Point::new(0.0, 0.0, 2.0), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = input.clip_by_plane(&plane, PlaneSide::Below, true);
+
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - 28.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_clip_by_plane_through_vertices() {
+        let input = get_cube(0.0, 2.0);
+        let plane = Plane::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = input.clip_by_plane(&plane, PlaneSide::Above, true);
+
+        assert_eq!(actual.get_number_of_vertices(), 8);
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - 8.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_clip_by_plane_no_intersection() {
+        let input = get_cube(0.0, 2.0);
+        let plane = Plane::new(Point::new(0.0, 0.0, 3.0), Vector::new(0.0, 0.0, 1.0));
+
+        let below = input.clip_by_plane(&plane, PlaneSide::Below, true);
+        let above = input.clip_by_plane(&plane, PlaneSide::Above, true);
+
+        assert_eq!(below.get_number_of_faces(), 12);
+        assert!((below.get_volume().unwrap() - 8.0).abs() < 1e-12);
+        assert_eq!(above.get_number_of_faces(), 0);
+        assert_eq!(above.get_number_of_vertices(), 0);
+    }
+}
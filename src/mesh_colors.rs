@@ -0,0 +1,68 @@
+use crate::color::Color;
+use crate::mesh::Mesh;
+
+impl Mesh {
+    /// Gets the [Color] of the vertex from `vertex_colors`.
+    ///
+    /// It returns `None` if the [Mesh] has no `vertex_colors`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::color::Color;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// assert_eq!(input.get_vertex_color(0), None);
+    ///
+    /// input.vertex_colors = Some(vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 128]);
+    /// assert_eq!(input.get_vertex_color(2), Some(Color::new(0, 0, 255, 128)));
+    /// ```
+    pub fn get_vertex_color(&self, vertex_id: usize) -> Option<Color> {
+        let colors = self.vertex_colors.as_ref()?;
+        Some(Color::new(colors[vertex_id * 4], colors[vertex_id * 4 + 1], colors[vertex_id * 4 + 2], colors[vertex_id * 4 + 3]))
+    }
+
+    /// Gets `vertex_colors` of the given vertices, in the given order.
+    pub(crate) fn get_vertex_colors_by_vertex_ids(&self, vertex_ids: impl IntoIterator<Item = usize>) -> Option<Vec<i32>> {
+        let colors = self.vertex_colors.as_ref()?;
+        Some(vertex_ids.into_iter().flat_map(|vertex_id| colors[vertex_id * 4..vertex_id * 4 + 4].to_vec()).collect())
+    }
+
+    /// Gets `vertex_colors` for the Meshes joined in the given order, without any vertex
+    /// welding. If any of the Meshes has no colors, there are no colors.
+    pub(crate) fn get_joined_vertex_colors(meshes: &[&Mesh]) -> Option<Vec<i32>> {
+        let mut colors: Vec<i32> = Vec::new();
+        for mesh in meshes {
+            colors.extend_from_slice(mesh.vertex_colors.as_ref()?);
+        }
+        Some(colors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_vertex_colors_by_vertex_ids() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.vertex_colors = Some(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+
+        let actual = input.get_vertex_colors_by_vertex_ids([2, 0, 2]);
+
+        assert_eq!(actual, Some(vec![9, 10, 11, 12, 1, 2, 3, 4, 9, 10, 11, 12]));
+    }
+
+    #[test]
+    fn test_get_joined_vertex_colors() {
+        let mut a = Mesh::new(vec![0.0, 0.0, 0.0], vec![]);
+        a.vertex_colors = Some(vec![1, 2, 3, 4]);
+        let mut b = a.clone();
+        b.vertex_colors = Some(vec![5, 6, 7, 8]);
+        let c = Mesh::new(vec![0.0, 0.0, 0.0], vec![]);
+
+        assert_eq!(Mesh::get_joined_vertex_colors(&[&a, &b]), Some(vec![1, 2, 3, 4, 5, 6, 7, 8]));
+        assert_eq!(Mesh::get_joined_vertex_colors(&[&a, &c]), None);
+    }
+}
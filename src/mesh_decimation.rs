@@ -0,0 +1,435 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use crate::mesh::Mesh;
+use crate::point::Point;
+
+/// The weight of the quadrics keeping boundary edges in place.
+const BOUNDARY_WEIGHT: f64 = 1000.0;
+
+/// Symmetric 4x4 matrix of the quadric error metric, stored as its upper triangle.
+type Quadric = [f64; 10];
+
+/// Candidate collapse of the `from` vertex into the `to` vertex.
+struct Collapse {
+    cost: f64,
+    from: usize,
+    to: usize,
+    from_version: usize,
+    to_version: usize,
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Collapse {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, so the BinaryHeap pops the cheapest collapse first.
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+impl Mesh {
+    /// Creates a new decimated [Mesh], with the number of faces reduced as long as the
+    /// geometric error stays within `max_error`.
+    ///
+    /// It uses the quadric error metric (Garland-Heckbert) with half-edge collapses: vertices
+    /// are merged into their neighbours, so all the remaining vertices keep their original
+    /// coordinates. The error of a collapse is the square root of the sum of squared distances
+    /// to the planes of all the original faces merged into the vertex, so it approximates the
+    /// distance between the original and the decimated surface.
+    ///
+    /// Boundary edges are preserved: boundary vertices can only slide along straight parts of
+    /// the boundary. Collapses which would flip faces or make the Mesh non-manifold are
+    /// skipped.
+    ///
+    /// The input [Mesh] should be welded and manifold. Unused vertices are removed from the
+    /// result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Flat 4x4 grid
+    /// let mut coordinates = Vec::new();
+    /// for y in 0..5 {
+    ///     for x in 0..5 {
+    ///         coordinates.extend_from_slice(&[x as f64, y as f64, 0.0]);
+    ///     }
+    /// }
+    /// let mut indices = Vec::new();
+    /// for y in 0..4 {
+    ///     for x in 0..4 {
+    ///         let a = y * 5 + x;
+    ///         indices.extend_from_slice(&[a, a + 1, a + 6, a, a + 6, a + 5]);
+    ///     }
+    /// }
+    /// let input = Mesh::new(coordinates, indices);
+    ///
+    /// let actual = input.get_decimated(0.001);
+    ///
+    /// assert_eq!(actual.get_number_of_faces(), 2);
+    /// assert!((actual.get_area() - 16.0).abs() < 1e-9);
+    /// ```
+    pub fn get_decimated(&self, max_error: f64) -> Mesh {
+        self.get_decimated_with_optional_sizing_field(max_error, None)
+    }
+
+    /// Creates a new decimated [Mesh], see [Mesh::get_decimated], with the density driven by the
+    /// sizing field.
+    ///
+    /// `target_size` returns the longest allowed edge length at the given [Point]. A collapse is
+    /// performed only if all the edges it creates are not longer than the target size at their
+    /// ends and middle points (and the error stays within `max_error`). This way areas of interest keep
+    /// the detail, while flat far-away regions are reduced aggressively. Use `f64::MAX` as
+    /// `max_error` to be driven by the sizing field only.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// // Flat 8x8 grid
+    /// let input = Mesh::from_heightmap(&vec![0.0; 81], 9, 9, 1.0);
+    ///
+    /// // Keep 1.0 edges near the origin, allow huge faces far away
+    /// let actual = input.get_decimated_with_sizing_field(0.001, |point: &Point| {
+    ///     if point.x < 2.0 && point.y < 2.0 { 1.5 } else { 100.0 }
+    /// });
+    ///
+    /// assert!(actual.get_number_of_faces() < input.get_number_of_faces() / 2);
+    /// assert!(actual.to_points().iter().filter(|point| point.x <= 1.0 && point.y <= 1.0).count() == 4);
+    /// ```
+    pub fn get_decimated_with_sizing_field(&self, max_error: f64, target_size: impl Fn(&Point) -> f64) -> Mesh {
+        self.get_decimated_with_optional_sizing_field(max_error, Some(&target_size))
+    }
+
+    fn get_decimated_with_optional_sizing_field(&self, max_error: f64, target_size: Option<&dyn Fn(&Point) -> f64>) -> Mesh {
+        let number_of_vertices = self.get_number_of_vertices();
+        let mut faces: Vec<[usize; 3]> = self.indices.chunks(3).map(|face| [face[0], face[1], face[2]]).collect();
+        let mut is_face_alive = vec![true; faces.len()];
+        let positions: Vec<[f64; 3]> = self.coordinates.chunks(3).map(|vertex| [vertex[0], vertex[1], vertex[2]]).collect();
+
+        let mut vertex_faces: Vec<Vec<usize>> = vec![Vec::new(); number_of_vertices];
+        for (face_id, face) in faces.iter().enumerate() {
+            for vertex_id in face {
+                vertex_faces[*vertex_id].push(face_id);
+            }
+        }
+
+        let mut quadrics: Vec<Quadric> = vec![[0.0; 10]; number_of_vertices];
+        let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for (face_id, face) in faces.iter().enumerate() {
+            if let Some(normal) = get_face_normal(&positions, face) {
+                let quadric = get_plane_quadric(&normal, &positions[face[0]], 1.0);
+                for vertex_id in face {
+                    add_quadric(&mut quadrics[*vertex_id], &quadric);
+                }
+            }
+            for i in 0..3 {
+                let (a, b) = (face[i], face[(i + 1) % 3]);
+                edge_faces.entry((a.min(b), a.max(b))).or_default().push(face_id);
+            }
+        }
+
+        let mut is_boundary_vertex = vec![false; number_of_vertices];
+        let mut boundary_edges: HashSet<(usize, usize)> = HashSet::new();
+        for (edge, face_ids) in &edge_faces {
+            if face_ids.len() != 1 {
+                continue;
+            }
+            boundary_edges.insert(*edge);
+            is_boundary_vertex[edge.0] = true;
+            is_boundary_vertex[edge.1] = true;
+            if let Some(face_normal) = get_face_normal(&positions, &faces[face_ids[0]]) {
+                let edge_vector = subtract(&positions[edge.1], &positions[edge.0]);
+                if let Some(normal) = unitize(&cross(&edge_vector, &face_normal)) {
+                    let quadric = get_plane_quadric(&normal, &positions[edge.0], BOUNDARY_WEIGHT);
+                    add_quadric(&mut quadrics[edge.0], &quadric);
+                    add_quadric(&mut quadrics[edge.1], &quadric);
+                }
+            }
+        }
+
+        let max_cost = max_error * max_error;
+        let mut versions = vec![0_usize; number_of_vertices];
+        let mut is_vertex_removed = vec![false; number_of_vertices];
+        let mut heap: BinaryHeap<Collapse> = BinaryHeap::new();
+        for (a, b) in edge_faces.keys() {
+            push_collapse(&mut heap, &quadrics, &positions, &versions, *a, *b, max_cost);
+            push_collapse(&mut heap, &quadrics, &positions, &versions, *b, *a, max_cost);
+        }
+
+        while let Some(collapse) = heap.pop() {
+            let (from, to) = (collapse.from, collapse.to);
+            if is_vertex_removed[from] || is_vertex_removed[to] || versions[from] != collapse.from_version || versions[to] != collapse.to_version {
+                continue;
+            }
+            if is_boundary_vertex[from] && !boundary_edges.contains(&(from.min(to), from.max(to))) {
+                continue;
+            }
+            if !is_collapse_valid(&faces, &is_face_alive, &vertex_faces, &positions, from, to) {
+                continue;
+            }
+
+            let from_neighbours = get_vertex_neighbours(&faces, &is_face_alive, &vertex_faces, from);
+            if let Some(target_size) = target_size {
+                let is_too_long = from_neighbours.iter().filter(|neighbour| **neighbour != to).any(|neighbour| {
+                    let (start, end) = (&positions[to], &positions[*neighbour]);
+                    let edge = subtract(end, start);
+                    let length = dot(&edge, &edge).sqrt();
+                    [0.0, 0.5, 1.0].iter().any(|t| {
+                        length > target_size(&Point::new(start[0] + edge[0] * t, start[1] + edge[1] * t, start[2] + edge[2] * t))
+                    })
+                });
+                if is_too_long {
+                    continue;
+                }
+            }
+            let from_faces: Vec<usize> = vertex_faces[from].iter().copied().filter(|face_id| is_face_alive[*face_id]).collect();
+            for face_id in from_faces {
+                if faces[face_id].contains(&to) {
+                    is_face_alive[face_id] = false;
+                } else {
+                    for vertex_id in faces[face_id].iter_mut() {
+                        if *vertex_id == from {
+                            *vertex_id = to;
+                        }
+                    }
+                    vertex_faces[to].push(face_id);
+                }
+            }
+            vertex_faces[to].retain(|face_id| is_face_alive[*face_id]);
+
+            // Boundary edges of the removed vertex are merged into the new boundary edge(s).
+            if is_boundary_vertex[from] {
+                for other in from_neighbours {
+                    if boundary_edges.remove(&(from.min(other), from.max(other))) && other != to {
+                        boundary_edges.insert((other.min(to), other.max(to)));
+                    }
+                }
+            }
+
+            let from_quadric = quadrics[from];
+            add_quadric(&mut quadrics[to], &from_quadric);
+            is_vertex_removed[from] = true;
+            versions[to] += 1;
+
+            for neighbour in get_vertex_neighbours(&faces, &is_face_alive, &vertex_faces, to) {
+                push_collapse(&mut heap, &quadrics, &positions, &versions, to, neighbour, max_cost);
+                push_collapse(&mut heap, &quadrics, &positions, &versions, neighbour, to, max_cost);
+            }
+        }
+
+        let alive_face_ids: Vec<usize> = (0..faces.len()).filter(|face_id| is_face_alive[*face_id]).collect();
+        let mut decimated = Mesh::new(self.coordinates.clone(), faces.iter().flatten().copied().collect());
+        decimated = decimated.get_welded_part_by_face_ids(&alive_face_ids);
+        decimated.id = self.id;
+        decimated
+    }
+}
+
+fn push_collapse(heap: &mut BinaryHeap<Collapse>, quadrics: &[Quadric], positions: &[[f64; 3]], versions: &[usize], from: usize, to: usize, max_cost: f64) {
+    let mut quadric = quadrics[from];
+    add_quadric(&mut quadric, &quadrics[to]);
+    let cost = get_quadric_error(&quadric, &positions[to]).max(0.0);
+    if cost <= max_cost {
+        heap.push(Collapse { cost, from, to, from_version: versions[from], to_version: versions[to] });
+    }
+}
+
+fn get_vertex_neighbours(faces: &[[usize; 3]], is_face_alive: &[bool], vertex_faces: &[Vec<usize>], vertex_id: usize) -> HashSet<usize> {
+    let mut neighbours = HashSet::new();
+    for face_id in &vertex_faces[vertex_id] {
+        if is_face_alive[*face_id] {
+            for other in faces[*face_id] {
+                if other != vertex_id {
+                    neighbours.insert(other);
+                }
+            }
+        }
+    }
+    neighbours
+}
+
+/// Checks the link condition (to stay manifold) and that no face gets flipped or degenerate.
+fn is_collapse_valid(faces: &[[usize; 3]], is_face_alive: &[bool], vertex_faces: &[Vec<usize>], positions: &[[f64; 3]], from: usize, to: usize) -> bool {
+    let mut opposite_vertices: HashSet<usize> = HashSet::new();
+    for face_id in &vertex_faces[from] {
+        let face = faces[*face_id];
+        if is_face_alive[*face_id] && face.contains(&to) {
+            opposite_vertices.extend(face.iter().copied().filter(|vertex_id| *vertex_id != from && *vertex_id != to));
+        }
+    }
+    if opposite_vertices.is_empty() {
+        return false;
+    }
+
+    let from_neighbours = get_vertex_neighbours(faces, is_face_alive, vertex_faces, from);
+    let to_neighbours = get_vertex_neighbours(faces, is_face_alive, vertex_faces, to);
+    if from_neighbours.intersection(&to_neighbours).any(|vertex_id| !opposite_vertices.contains(vertex_id)) {
+        return false;
+    }
+
+    for face_id in &vertex_faces[from] {
+        let face = faces[*face_id];
+        if !is_face_alive[*face_id] || face.contains(&to) {
+            continue;
+        }
+        let old_normal = match get_face_normal(positions, &face) {
+            Some(normal) => normal,
+            None => continue,
+        };
+        let new_face = face.map(|vertex_id| if vertex_id == from { to } else { vertex_id });
+        match get_face_normal(positions, &new_face) {
+            Some(new_normal) if dot(&old_normal, &new_normal) > 0.0 => {},
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+fn get_face_normal(positions: &[[f64; 3]], face: &[usize; 3]) -> Option<[f64; 3]> {
+    let first = subtract(&positions[face[1]], &positions[face[0]]);
+    let second = subtract(&positions[face[2]], &positions[face[0]]);
+    unitize(&cross(&first, &second))
+}
+
+fn get_plane_quadric(normal: &[f64; 3], point: &[f64; 3], weight: f64) -> Quadric {
+    let [a, b, c] = *normal;
+    let d = -dot(normal, point);
+    [a * a, a * b, a * c, a * d, b * b, b * c, b * d, c * c, c * d, d * d].map(|value| value * weight)
+}
+
+fn add_quadric(quadric: &mut Quadric, other: &Quadric) {
+    for (value, other_value) in quadric.iter_mut().zip(other) {
+        *value += other_value;
+    }
+}
+
+fn get_quadric_error(q: &Quadric, point: &[f64; 3]) -> f64 {
+    let [x, y, z] = *point;
+    q[0] * x * x + 2.0 * q[1] * x * y + 2.0 * q[2] * x * z + 2.0 * q[3] * x
+        + q[4] * y * y + 2.0 * q[5] * y * z + 2.0 * q[6] * y
+        + q[7] * z * z + 2.0 * q[8] * z
+        + q[9]
+}
+
+fn subtract(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn unitize(vector: &[f64; 3]) -> Option<[f64; 3]> {
+    let length = dot(vector, vector).sqrt();
+    if length == 0.0 || !length.is_finite() {
+        return None;
+    }
+    Some([vector[0] / length, vector[1] / length, vector[2] / length])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_grid(size: usize, height: impl Fn(f64, f64) -> f64) -> Mesh {
+        let mut coordinates = Vec::new();
+        for y in 0..=size {
+            for x in 0..=size {
+                coordinates.extend_from_slice(&[x as f64, y as f64, height(x as f64, y as f64)]);
+            }
+        }
+        let mut indices = Vec::new();
+        for y in 0..size {
+            for x in 0..size {
+                let a = y * (size + 1) + x;
+                indices.extend_from_slice(&[a, a + 1, a + size + 2, a, a + size + 2, a + size + 1]);
+            }
+        }
+        Mesh::new(coordinates, indices)
+    }
+
+    #[test]
+    fn test_get_decimated_zero_error_keeps_curved_surface() {
+        let input = create_grid(6, |x, y| (x * x + y * y) * 0.1);
+
+        let actual = input.get_decimated(0.0);
+
+        assert_eq!(actual.get_number_of_faces(), input.get_number_of_faces());
+    }
+
+    #[test]
+    fn test_get_decimated_keeps_boundary_and_manifold() {
+        let input = create_grid(10, |x, y| if x > 5.0 { 0.5 * (x - 5.0) } else { 0.0 } + 0.0 * y);
+
+        let actual = input.get_decimated(0.001);
+
+        assert!(actual.get_number_of_faces() < input.get_number_of_faces() / 4);
+        assert_eq!(actual.get_non_manifold_edges().len(), actual.get_edges_with_missing_neighbour().len());
+        assert!(actual.get_bounding_box().eq_with_tolerance(&input.get_bounding_box(), 1e-12));
+        assert!((actual.get_area() - input.get_area()).abs() < 1e-9);
+        assert!(actual.get_degenerate_face_ids().is_empty());
+    }
+
+    #[test]
+    fn test_get_decimated_error_bound() {
+        let input = create_grid(12, |x, y| ((x * 0.5).sin() + (y * 0.5).cos()) * 0.3);
+
+        let actual = input.get_decimated(0.05);
+
+        assert!(actual.get_number_of_faces() < input.get_number_of_faces());
+        let triangles = actual.to_triangles();
+        for point in input.to_points() {
+            let distance = triangles.iter()
+                .map(|triangle| triangle.get_closest_point(&point).get_distance_to_point(&point))
+                .fold(f64::MAX, f64::min);
+            assert!(distance <= 0.05 + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_get_decimated_with_sizing_field_limits_edge_length() {
+        let input = create_grid(12, |x, y| if x > 6.0 { 0.0 } else { 0.0 * y });
+
+        let actual = input.get_decimated_with_sizing_field(f64::MAX, |_point: &Point| 3.0);
+
+        assert!(actual.get_number_of_faces() < input.get_number_of_faces());
+        for edge in actual.to_edges() {
+            let points = actual.to_points();
+            assert!(points[edge.start].get_distance_to_point(&points[edge.end]) <= 3.0 + 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_get_decimated_with_sizing_field_keeps_detail_in_region_of_interest() {
+        let input = create_grid(10, |x, y| ((x * 0.7).sin() + (y * 0.9).cos()) * 0.5);
+
+        let everywhere = input.get_decimated(0.2);
+        let actual = input.get_decimated_with_sizing_field(0.2, |point: &Point| if point.x <= 3.0 { 1.0 } else { f64::MAX });
+
+        let in_region = |mesh: &Mesh| mesh.to_points().iter().filter(|point| point.x <= 2.0).count();
+        assert_eq!(in_region(&actual), in_region(&input));
+        assert!(in_region(&everywhere) < in_region(&input));
+        assert!(actual.get_number_of_faces() < input.get_number_of_faces());
+    }
+}
@@ -0,0 +1,273 @@
+use crate::lattice::Lattice;
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::mesh::Mesh;
+use crate::point::Point;
+
+impl Mesh {
+    /// Returns a new [Mesh] deformed by the [Lattice] (free-form deformation).
+    ///
+    /// Vertices inside the Lattice's box are moved to the trivariate Bézier volume defined by
+    /// its control points, so the undeformed Lattice doesn't change anything, and vertices
+    /// outside the box stay where they are.
+    ///
+    /// Faces are kept, and [Mesh::vertex_normals] are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::lattice::Lattice;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 0.0, 1.0], vec![0, 1, 2]);
+    /// let mut lattice = Lattice::new(BoundingBox::new(0.0, 2.0, 0.0, 1.0, 0.0, 1.0), [2, 2, 2]);
+    /// for i in 0..2 {
+    ///     for j in 0..2 {
+    ///         let id = lattice.get_control_point_id(i, j, 1);
+    ///         lattice.control_points[id].z = 3.0;
+    ///     }
+    /// }
+    ///
+    /// let actual = input.get_deformed_by_lattice(&lattice);
+    ///
+    /// assert_eq!(actual.coordinates, vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 0.0, 3.0]);
+    /// ```
+    pub fn get_deformed_by_lattice(&self, lattice: &Lattice) -> Mesh {
+        let bounding_box = &lattice.bounding_box;
+        let minimums = [bounding_box.min_x, bounding_box.min_y, bounding_box.min_z];
+        let maximums = [bounding_box.max_x, bounding_box.max_y, bounding_box.max_z];
+
+        self.get_with_moved_vertices(|_, vertex| {
+            let coordinates = [vertex.x, vertex.y, vertex.z];
+            if (0..3).any(|axis| coordinates[axis] < minimums[axis] || coordinates[axis] > maximums[axis]) {
+                return vertex;
+            }
+            let weights: Vec<Vec<f64>> = (0..3).map(|axis| {
+                let extent = maximums[axis] - minimums[axis];
+                let parameter = if extent == 0.0 { 0.0 } else { (coordinates[axis] - minimums[axis]) / extent };
+                get_bernstein_weights(lattice.divisions[axis] - 1, parameter)
+            }).collect();
+
+            let mut deformed = Point::new(0.0, 0.0, 0.0);
+            for (k, weight_z) in weights[2].iter().enumerate() {
+                for (j, weight_y) in weights[1].iter().enumerate() {
+                    for (i, weight_x) in weights[0].iter().enumerate() {
+                        let weight = weight_x * weight_y * weight_z;
+                        let control_point = lattice.control_points[lattice.get_control_point_id(i, j, k)];
+                        deformed.x += weight * control_point.x;
+                        deformed.y += weight * control_point.y;
+                        deformed.z += weight * control_point.z;
+                    }
+                }
+            }
+
+            deformed
+        })
+    }
+
+    /// Returns a new [Mesh] twisted around the z axis of the [LocalCoordinateSystem]: each
+    /// vertex is rotated around the axis by the `angle_per_unit` (in radians, following the
+    /// right hand rule) multiplied by its local z coordinate.
+    ///
+    /// Faces are kept, and [Mesh::vertex_normals] are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = Mesh::new(vec![1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 2.0], vec![0, 1, 2]);
+    ///
+    /// let actual = input.get_twisted(&LocalCoordinateSystem::global(), PI / 2.0);
+    ///
+    /// assert!(actual.to_points()[1].eq_with_tolerance(&Point::new(0.0, 1.0, 1.0), 1e-12));
+    /// assert!(actual.to_points()[2].eq_with_tolerance(&Point::new(-1.0, 0.0, 2.0), 1e-12));
+    /// ```
+    pub fn get_twisted(&self, local_coordinate_system: &LocalCoordinateSystem, angle_per_unit: f64) -> Mesh {
+        self.get_deformed_in_local_coordinate_system(local_coordinate_system, |point| {
+            let (sin, cos) = (angle_per_unit * point.z).sin_cos();
+            Point::new(point.x * cos - point.y * sin, point.x * sin + point.y * cos, point.z)
+        })
+    }
+
+    /// Returns a new [Mesh] tapered along the z axis of the [LocalCoordinateSystem]: local x
+    /// and y coordinates of each vertex are scaled by `1.0 + factor_per_unit * z`, so the
+    /// Mesh gets narrower (negative factor) or wider (positive factor) going up the axis.
+    ///
+    /// Faces are kept, and [Mesh::vertex_normals] are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 4.0], vec![0, 1, 2]);
+    ///
+    /// let actual = input.get_tapered(&LocalCoordinateSystem::global(), -0.25);
+    ///
+    /// assert_eq!(actual.coordinates, vec![2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 4.0]);
+    /// ```
+    pub fn get_tapered(&self, local_coordinate_system: &LocalCoordinateSystem, factor_per_unit: f64) -> Mesh {
+        self.get_deformed_in_local_coordinate_system(local_coordinate_system, |point| {
+            let scale = 1.0 + factor_per_unit * point.z;
+            Point::new(point.x * scale, point.y * scale, point.z)
+        })
+    }
+
+    /// Returns a new [Mesh] bent along the z axis of the [LocalCoordinateSystem] towards its
+    /// x axis: the z axis becomes an arc turning by the `angle_per_unit` (in radians) per unit
+    /// of its length, and local x and y coordinates stay perpendicular to it, so lengths along
+    /// the axis are kept.
+    ///
+    /// Negative angles bend towards -x.
+    ///
+    /// Faces are kept, and [Mesh::vertex_normals] are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// // A thin strip 2.0 long along z
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0], vec![0, 1, 2]);
+    ///
+    /// // Quarter of a circle with the radius of 4.0 / PI
+    /// let actual = input.get_bent(&LocalCoordinateSystem::global(), PI / 4.0);
+    ///
+    /// assert!(actual.to_points()[2].eq_with_tolerance(&Point::new(4.0 / PI, 0.0, 4.0 / PI), 1e-12));
+    /// ```
+    pub fn get_bent(&self, local_coordinate_system: &LocalCoordinateSystem, angle_per_unit: f64) -> Mesh {
+        if angle_per_unit == 0.0 {
+            return self.get_deformed_in_local_coordinate_system(local_coordinate_system, |point| point);
+        }
+        let radius = 1.0 / angle_per_unit;
+
+        self.get_deformed_in_local_coordinate_system(local_coordinate_system, |point| {
+            let (sin, cos) = (angle_per_unit * point.z).sin_cos();
+            let distance_to_center = radius - point.x;
+            Point::new(radius - distance_to_center * cos, point.y, distance_to_center * sin)
+        })
+    }
+
+    /// Moves vertices given in local coordinates of the [LocalCoordinateSystem] by the
+    /// `deformation`, and moves them back into the global space.
+    fn get_deformed_in_local_coordinate_system(&self, local_coordinate_system: &LocalCoordinateSystem, deformation: impl Fn(Point) -> Point) -> Mesh {
+        self.get_with_moved_vertices(|_, vertex| {
+            deformation(vertex.get_in_global_coordinate_system(local_coordinate_system)).get_in_local_coordinate_system(local_coordinate_system)
+        })
+    }
+
+    /// Creates a new [Mesh] with each vertex moved by the `move_vertex` (getting the vertex id
+    /// and its [Point]), without [Mesh::vertex_normals].
+    pub(crate) fn get_with_moved_vertices(&self, move_vertex: impl Fn(usize, Point) -> Point) -> Mesh {
+        let mut deformed = self.clone();
+        for (i, vertex) in self.to_points().into_iter().enumerate() {
+            let moved = move_vertex(i, vertex);
+            deformed.coordinates[i * 3] = moved.x;
+            deformed.coordinates[i * 3 + 1] = moved.y;
+            deformed.coordinates[i * 3 + 2] = moved.z;
+        }
+        deformed.vertex_normals = None;
+
+        deformed
+    }
+}
+
+/// Gets weights of Bernstein polynomials of the `degree` at the `parameter` (0.0 - 1.0).
+fn get_bernstein_weights(degree: usize, parameter: f64) -> Vec<f64> {
+    let mut binomial = 1.0;
+    (0..=degree).map(|i| {
+        if i > 0 {
+            binomial = binomial * (degree + 1 - i) as f64 / i as f64;
+        }
+        binomial * parameter.powi(i as i32) * (1.0 - parameter).powi((degree - i) as i32)
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use crate::bounding_box::BoundingBox;
+    use crate::vector::Vector;
+    use super::*;
+
+    fn get_column() -> Mesh {
+        let mut coordinates = Vec::new();
+        let mut indices = Vec::new();
+        for level in 0..=10 {
+            let z = level as f64 * 0.5;
+            coordinates.extend_from_slice(&[-0.5, 0.0, z, 0.5, 0.0, z]);
+            if level > 0 {
+                let first = (level - 1) * 2;
+                indices.extend_from_slice(&[first, first + 1, first + 3, first, first + 3, first + 2]);
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+
+    #[test]
+    fn test_get_bernstein_weights_partition_of_unity() {
+        let actual = get_bernstein_weights(3, 0.3);
+
+        assert_eq!(actual.len(), 4);
+        assert!((actual.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!((actual[1] - 3.0 * 0.3 * 0.7 * 0.7).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_deformed_by_lattice_undeformed_keeps_mesh() {
+        let input = get_column();
+        let lattice = Lattice::new(BoundingBox::new(-1.0, 1.0, -1.0, 1.0, 0.0, 5.0), [3, 2, 4]);
+
+        let actual = input.get_deformed_by_lattice(&lattice);
+
+        assert!(actual.eq_with_tolerance(&input, 1e-12));
+    }
+
+    #[test]
+    fn test_get_deformed_by_lattice_outside_stays() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 5.0, 0.0, 0.0, 0.0, 5.0, 0.0], vec![0, 1, 2]);
+        let mut lattice = Lattice::new(BoundingBox::new(-1.0, 1.0, -1.0, 1.0, -1.0, 1.0), [2, 2, 2]);
+        for control_point in &mut lattice.control_points {
+            control_point.z += 1.0;
+        }
+
+        let actual = input.get_deformed_by_lattice(&lattice);
+
+        assert_eq!(actual.coordinates, vec![0.0, 0.0, 1.0, 5.0, 0.0, 0.0, 0.0, 5.0, 0.0]);
+    }
+
+    #[test]
+    fn test_get_bent_keeps_axis_length() {
+        let input = get_column();
+
+        let actual = input.get_bent(&LocalCoordinateSystem::global(), PI / 10.0);
+
+        // The axis (x = 0.0) is the quarter of a circle with the radius of 10.0 / PI
+        let points = actual.to_points();
+        let top_middle = Point::new((points[20].x + points[21].x) / 2.0, 0.0, (points[20].z + points[21].z) / 2.0);
+        assert!(top_middle.eq_with_tolerance(&Point::new(10.0 / PI, 0.0, 10.0 / PI), 1e-12));
+        assert!((points[20].get_distance_to_point(&points[21]) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_twisted_in_local_coordinate_system() {
+        let input = get_column();
+        let local_coordinate_system = LocalCoordinateSystem::new(Point::new(0.0, 0.0, 2.0), Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+
+        let actual = input.get_twisted(&local_coordinate_system, PI / 6.0);
+
+        let points = actual.to_points();
+        assert!(points[8].eq_with_tolerance(&Point::new(-0.5, 0.0, 2.0), 1e-12));
+        assert!(points[21].eq_with_tolerance(&Point::new(0.0, 0.5, 5.0), 1e-12));
+        assert!(points[1].eq_with_tolerance(&Point::new(0.25, -0.75_f64.sqrt() / 2.0, 0.0), 1e-12));
+    }
+}
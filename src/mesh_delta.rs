@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a difference between two [Mesh](crate::mesh::Mesh)es.
+///
+/// It can be used to transmit small updates of a [Mesh] (e.g. in collaborative or streamed
+/// editing) instead of sending the whole geometry again.
+///
+/// It is described by:
+///
+/// *Vertex moves* - ids of the source vertices which changed their position, together with their
+/// new coordinates.
+///
+/// *Vertex count change* - the number of vertices of the updated [Mesh]. If it's smaller than the
+/// source one, the last vertices are removed. If it's bigger, new vertices are appended at the
+/// end using *added coordinates*.
+///
+/// *Face removals* - ids of the source faces which should be removed.
+///
+/// *Face additions* - new faces together with their final positions (ids) in the updated [Mesh].
+///
+/// Use [Mesh::compute_delta](crate::mesh::Mesh::compute_delta) to create it and
+/// [Mesh::apply_delta](crate::mesh::Mesh::apply_delta) to apply it.
+///
+/// [Mesh]: crate::mesh::Mesh
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MeshDelta {
+    /// The number of vertices of the [Mesh](crate::mesh::Mesh) the delta should be applied to.
+    pub source_number_of_vertices: usize,
+    /// The number of faces of the [Mesh](crate::mesh::Mesh) the delta should be applied to.
+    pub source_number_of_faces: usize,
+    /// The number of vertices after applying the delta.
+    pub number_of_vertices: usize,
+    /// The ids of the moved vertices.
+    pub moved_vertex_ids: Vec<usize>,
+    /// The new coordinates of the moved vertices, organized as [x0, y0, z0, x1, y1, z1, ...].
+    pub moved_coordinates: Vec<f64>,
+    /// The coordinates of vertices appended at the end, organized as [x0, y0, z0, x1, y1, z1, ...].
+    pub added_coordinates: Vec<f64>,
+    /// The ids of source faces to remove, sorted ascending.
+    pub removed_face_ids: Vec<usize>,
+    /// The ids of the added faces in the updated Mesh, sorted ascending.
+    pub added_face_ids: Vec<usize>,
+    /// The indices of the added faces, 3 per face.
+    pub added_indices: Vec<usize>,
+}
+
+impl PartialEq for MeshDelta {
+    fn eq(&self, other: &Self) -> bool {
+        self.source_number_of_vertices == other.source_number_of_vertices &&
+            self.source_number_of_faces == other.source_number_of_faces &&
+            self.number_of_vertices == other.number_of_vertices &&
+            self.moved_vertex_ids == other.moved_vertex_ids &&
+            self.moved_coordinates == other.moved_coordinates &&
+            self.added_coordinates == other.added_coordinates &&
+            self.removed_face_ids == other.removed_face_ids &&
+            self.added_face_ids == other.added_face_ids &&
+            self.added_indices == other.added_indices
+    }
+}
+
+impl MeshDelta {
+    /// Returns a new [MeshDelta] which doesn't change anything in a
+    /// [Mesh](crate::mesh::Mesh) with given number of vertices and faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh_delta::MeshDelta;
+    ///
+    /// let result = MeshDelta::new_empty(5, 6);
+    ///
+    /// assert_eq!(result.source_number_of_vertices, 5);
+    /// assert_eq!(result.source_number_of_faces, 6);
+    /// assert_eq!(result.number_of_vertices, 5);
+    /// assert!(result.is_empty());
+    /// ```
+    pub fn new_empty(source_number_of_vertices: usize, source_number_of_faces: usize) -> MeshDelta {
+        MeshDelta {
+            source_number_of_vertices,
+            source_number_of_faces,
+            number_of_vertices: source_number_of_vertices,
+            moved_vertex_ids: Vec::new(),
+            moved_coordinates: Vec::new(),
+            added_coordinates: Vec::new(),
+            removed_face_ids: Vec::new(),
+            added_face_ids: Vec::new(),
+            added_indices: Vec::new(),
+        }
+    }
+
+    /// Checks if the [MeshDelta] doesn't change anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh_delta::MeshDelta;
+    ///
+    /// let mut delta = MeshDelta::new_empty(5, 6);
+    /// assert!(delta.is_empty());
+    ///
+    /// delta.removed_face_ids.push(2);
+    /// assert!(!delta.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.number_of_vertices == self.source_number_of_vertices &&
+            self.moved_vertex_ids.is_empty() &&
+            self.added_coordinates.is_empty() &&
+            self.removed_face_ids.is_empty() &&
+            self.added_face_ids.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+    use super::*;
+
+    #[test]
+    fn test_partialeq_true() {
+        let a = MeshDelta::new_empty(5, 6);
+        let b = MeshDelta::new_empty(5, 6);
+        assert!(a.eq(&b));
+        assert!(b.eq(&a));
+    }
+
+    #[test]
+    fn test_partialeq_different_false() {
+        let a = MeshDelta::new_empty(5, 6);
+        let mut b = MeshDelta::new_empty(5, 6);
+        b.moved_vertex_ids.push(1);
+        b.moved_coordinates.extend_from_slice(&[1.0, 2.0, 3.0]);
+        assert!(!a.eq(&b));
+        assert!(!b.eq(&a));
+    }
+
+    #[test]
+    fn test_is_empty_false_for_vertex_count_change() {
+        let mut delta = MeshDelta::new_empty(5, 6);
+        delta.number_of_vertices = 4;
+        assert!(!delta.is_empty());
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let mut input = MeshDelta::new_empty(5, 6);
+        input.number_of_vertices = 6;
+        input.moved_vertex_ids.push(4);
+        input.moved_coordinates.extend_from_slice(&[3.0, 6.0, 5.0]);
+        input.added_coordinates.extend_from_slice(&[0.0, 0.0, -1.0]);
+        input.removed_face_ids.push(0);
+        input.added_face_ids.push(5);
+        input.added_indices.extend_from_slice(&[0, 1, 5]);
+
+        let json = to_string(&input).unwrap();
+        let actual: MeshDelta = from_str(&json).unwrap();
+
+        assert_eq!(input, actual);
+    }
+}
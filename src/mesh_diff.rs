@@ -0,0 +1,80 @@
+use crate::bounding_box::BoundingBox;
+
+/// Describes where two [Mesh](crate::mesh::Mesh)es differ.
+///
+/// It's created by [Mesh::diff](crate::mesh::Mesh::diff). Unlike
+/// [Mesh::eq_with_tolerance](crate::mesh::Mesh::eq_with_tolerance), which only tells that the
+/// Meshes are different, it lists what exactly was added, removed or moved, and where.
+///
+/// Ids of `added` and `moved` elements refer to the other [Mesh](crate::mesh::Mesh), ids of
+/// `removed` elements refer to the first one.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::mesh::Mesh;
+///
+/// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+///
+/// let actual = input.diff(&input, 0.001);
+///
+/// assert!(actual.is_empty());
+/// assert_eq!(actual.max_vertex_distance, 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MeshDiff {
+    /// Ids of vertices existing in both Meshes, but further apart than the tolerance.
+    pub moved_vertex_ids: Vec<usize>,
+    /// Ids of vertices existing only in the other Mesh (the surplus at the end).
+    pub added_vertex_ids: Vec<usize>,
+    /// Ids of vertices existing only in the first Mesh (the surplus at the end).
+    pub removed_vertex_ids: Vec<usize>,
+    /// The biggest distance between vertices with the same id.
+    pub max_vertex_distance: f64,
+    /// Ids of faces of the other Mesh, which indices don't exist in the first one.
+    pub added_face_ids: Vec<usize>,
+    /// Ids of faces of the first Mesh, which indices don't exist in the other one.
+    pub removed_face_ids: Vec<usize>,
+    /// Ids of faces of the other Mesh existing in both Meshes, but with at least one vertex
+    /// moved.
+    pub moved_face_ids: Vec<usize>,
+    /// Bounding Boxes of the connected groups of changed faces: added and moved faces of the
+    /// other Mesh first, then removed faces of the first Mesh.
+    pub changed_regions: Vec<BoundingBox>,
+}
+
+impl PartialEq for MeshDiff {
+    fn eq(&self, other: &Self) -> bool {
+        self.moved_vertex_ids == other.moved_vertex_ids &&
+            self.added_vertex_ids == other.added_vertex_ids &&
+            self.removed_vertex_ids == other.removed_vertex_ids &&
+            self.max_vertex_distance == other.max_vertex_distance &&
+            self.added_face_ids == other.added_face_ids &&
+            self.removed_face_ids == other.removed_face_ids &&
+            self.moved_face_ids == other.moved_face_ids &&
+            self.changed_regions == other.changed_regions
+    }
+}
+
+impl MeshDiff {
+    /// Checks if nothing was added, removed or moved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let other = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.5], vec![0, 1, 2]);
+    ///
+    /// assert!(input.diff(&other, 0.6).is_empty());
+    /// assert!(!input.diff(&other, 0.1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.moved_vertex_ids.is_empty() &&
+            self.added_vertex_ids.is_empty() &&
+            self.removed_vertex_ids.is_empty() &&
+            self.added_face_ids.is_empty() &&
+            self.removed_face_ids.is_empty()
+    }
+}
@@ -0,0 +1,127 @@
+use crate::mesh::Mesh;
+use crate::perlin_noise::PerlinNoise;
+use crate::vector::Vector;
+
+impl Mesh {
+    /// Returns a new [Mesh] with each vertex moved along its normal by its value of the scalar
+    /// `field` multiplied by the `scale`, e.g. for applying height maps.
+    ///
+    /// `vertex_normals` are used if the [Mesh] has them, otherwise normals of faces around each
+    /// vertex are averaged (weighted by areas). Vertices without a normal (e.g. unused ones)
+    /// stay where they are.
+    ///
+    /// Faces are kept, and [Mesh::vertex_normals] are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let actual = input.get_displaced(&[1.0, 0.0, -2.0], 0.5);
+    ///
+    /// assert_eq!(actual.coordinates, vec![0.0, 0.0, 0.5, 1.0, 0.0, 0.0, 0.0, 1.0, -1.0]);
+    /// ```
+    pub fn get_displaced(&self, field: &[f64], scale: f64) -> Mesh {
+        if field.len() != self.get_number_of_vertices() {
+            panic!("There should be exactly 1 value of the field for each vertex");
+        }
+
+        let normals = self.get_displacement_normals();
+
+        self.get_with_moved_vertices(|vertex_id, vertex| match normals[vertex_id] {
+            Some(normal) => vertex + normal * (field[vertex_id] * scale),
+            None => vertex,
+        })
+    }
+
+    /// Returns a new [Mesh] with vertices moved along their normals by the Perlin noise, e.g.
+    /// for detailing terrains, or generating test data.
+    ///
+    /// The noise is evaluated at positions of vertices multiplied by the `frequency`, so it
+    /// changes about once per 1.0 / `frequency` units, and moves vertices by up to the
+    /// `amplitude`. The same `seed` always gives the same result.
+    ///
+    /// See [Mesh::get_displaced] for the details about normals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 10.0, 0.0, 3.3, 3.3, 0.0], vec![0, 1, 3, 1, 2, 3]);
+    ///
+    /// let actual = input.get_displaced_by_noise(0.5, 0.3, 7);
+    ///
+    /// assert_eq!(actual, input.get_displaced_by_noise(0.5, 0.3, 7));
+    /// assert_eq!(actual.get_x_coordinates(), input.get_x_coordinates());
+    /// assert!(actual.get_z_coordinates().iter().all(|z| z.abs() <= 0.5));
+    /// assert_ne!(actual.coordinates[11], 0.0);
+    /// ```
+    pub fn get_displaced_by_noise(&self, amplitude: f64, frequency: f64, seed: u64) -> Mesh {
+        let noise = PerlinNoise::new(seed);
+        let field: Vec<f64> = self.to_points().iter()
+            .map(|point| noise.get_value(point.x * frequency, point.y * frequency, point.z * frequency))
+            .collect();
+
+        self.get_displaced(&field, amplitude)
+    }
+
+    fn get_displacement_normals(&self) -> Vec<Option<Vector>> {
+        let number_of_vertices = self.get_number_of_vertices();
+        let normals: Vec<Vector> = match &self.vertex_normals {
+            Some(vertex_normals) if vertex_normals.len() == number_of_vertices * 3 => {
+                vertex_normals.chunks(3).map(|normal| Vector::new(normal[0], normal[1], normal[2])).collect()
+            },
+            _ => {
+                let mut normals = vec![Vector::new(0.0, 0.0, 0.0); number_of_vertices];
+                for (face, triangle) in self.indices.chunks_exact(3).zip(self.to_triangles()) {
+                    // The cross product is twice as long as the face's area, so bigger faces weigh more.
+                    let normal = Vector::from_2_points(&triangle.first_point, &triangle.second_point).get_cross_product(&Vector::from_2_points(&triangle.first_point, &triangle.third_point));
+                    for vertex_id in face {
+                        normals[*vertex_id] = normals[*vertex_id] + normal;
+                    }
+                }
+                normals
+            },
+        };
+
+        normals.iter().map(|normal| if normal.get_length() == 0.0 { None } else { Some(normal.get_unitized()) }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_displaced_uses_vertex_normals() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.vertex_normals = Some(vec![2.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+
+        let actual = input.get_displaced(&[1.0, 1.0, 1.0], 2.0);
+
+        assert_eq!(actual.coordinates, vec![2.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 1.0, 0.0]);
+        assert_eq!(actual.vertex_normals, None);
+    }
+
+    #[test]
+    fn test_get_displaced_closed_box_grows() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]);
+
+        let actual = input.get_displaced(&[1.0; 4], 0.1);
+
+        assert!(actual.get_volume().unwrap() > input.get_volume().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "There should be exactly 1 value of the field for each vertex")]
+    fn test_get_displaced_wrong_field_length_panic() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        input.get_displaced(&[1.0, 1.0], 1.0);
+    }
+}
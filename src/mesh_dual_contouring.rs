@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use crate::bvh::Bvh;
+use crate::mesh::Mesh;
+use crate::mesh_sdf_repair::get_repair_signed_distances;
+use crate::plane_analysis::get_symmetric_eigen_decomposition;
+use crate::point::Point;
+use crate::vector::Vector;
+
+/// Eigenvalues of the quadratic error function smaller than this fraction of the biggest one
+/// are ignored, so flat and curved areas don't produce vertices far away from the surface.
+const QEF_EIGENVALUE_TOLERANCE: f64 = 0.1;
+
+/// How far outside its cell (as a fraction of the cell size) the vertex can be. Edges crossing
+/// the cell don't always see all the planes meeting in the corner inside it, so the corner can
+/// be found by a neighbouring cell instead.
+const CELL_MARGIN: f64 = 1.0;
+
+/// The 12 edges of a grid cube as pairs of its corners.
+///
+/// Cube corners are numbered with bits: x = 1, y = 2, z = 4.
+const CUBE_EDGES: [[usize; 2]; 12] = [
+    [0, 1], [2, 3], [4, 5], [6, 7],
+    [0, 2], [1, 3], [4, 6], [5, 7],
+    [0, 4], [1, 5], [2, 6], [3, 7],
+];
+
+impl Mesh {
+    /// Creates a new watertight [Mesh] approximating the given one, rebuilt from its signed
+    /// distance field with dual contouring.
+    ///
+    /// The signed distance field is the same as in [Mesh::get_repaired_via_sdf], but the surface
+    /// is extracted differently: each grid cell crossed by it gets 1 vertex placed where the
+    /// planes of the closest faces meet (minimizing the quadratic error function). Thanks to
+    /// that sharp edges and corners, e.g. of BIM elements, are kept instead of getting bevelled.
+    ///
+    /// The result is welded, closed and oriented outside, but where the surface passes between
+    /// cells touching only with an edge it can be non-manifold.
+    ///
+    /// It panics if the `cell_size` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         2.0,0.0,0.0,
+    ///         2.0,2.0,0.0,
+    ///         0.0,2.0,0.0,
+    ///         0.0,0.0,2.0,
+    ///         2.0,0.0,2.0,
+    ///         2.0,2.0,2.0,
+    ///         0.0,2.0,2.0,
+    ///     ],
+    ///     vec![
+    ///         0,2,1, 0,3,2,
+    ///         4,5,6, 4,6,7,
+    ///         0,1,5, 0,5,4,
+    ///         1,2,6, 1,6,5,
+    ///         2,3,7, 2,7,6,
+    ///         3,0,4, 3,4,7,
+    ///     ]);
+    ///
+    /// let actual = input.get_repaired_via_dual_contouring(0.5);
+    ///
+    /// assert!(actual.get_edges_with_missing_neighbour().is_empty());
+    /// assert!((actual.get_volume().unwrap() - 8.0).abs() < 1e-9);
+    /// ```
+    pub fn get_repaired_via_dual_contouring(&self, cell_size: f64) -> Mesh {
+        if cell_size <= 0.0 {
+            panic!("Cell size should be greater than 0.0");
+        }
+
+        let bvh = Bvh::from_mesh(self);
+        let triangles = self.to_triangles();
+        let (origin, number_of_cells, values) = get_repair_signed_distances(self, &bvh, cell_size);
+
+        // Exact zeros would create ambiguous crossings, so they're nudged outside.
+        let nudge = cell_size * 1e-6;
+        let values: Vec<f64> = values.iter().map(|value| if value.abs() < nudge { nudge } else { *value }).collect();
+
+        let number_of_nodes = [number_of_cells[0] + 1, number_of_cells[1] + 1, number_of_cells[2] + 1];
+        let get_node_id = |node: [usize; 3]| (node[2] * number_of_nodes[1] + node[1]) * number_of_nodes[0] + node[0];
+        let get_point = |node: [usize; 3]| Point::new(origin.x + node[0] as f64 * cell_size, origin.y + node[1] as f64 * cell_size, origin.z + node[2] as f64 * cell_size);
+
+        // Hermite data: where the surface crosses the edge of the grid and the normal there
+        let mut crossings: HashMap<(usize, usize), (Point, Option<Vector>)> = HashMap::new();
+        let mut get_crossing = |start: [usize; 3], end: [usize; 3]| -> (Point, Option<Vector>) {
+            let (start_value, end_value) = (values[get_node_id(start)], values[get_node_id(end)]);
+            *crossings.entry((get_node_id(start), get_node_id(end))).or_insert_with(|| {
+                let (start_point, end_point) = (get_point(start), get_point(end));
+                let t = start_value / (start_value - end_value);
+                let point = start_point + Vector::from_2_points(&start_point, &end_point) * t;
+                match bvh.get_closest_point(&point) {
+                    Some((face_id, closest_point)) if closest_point.get_distance_to_point(&point) < cell_size => {
+                        (closest_point, triangles[face_id].get_normal_vector_unitized_checked())
+                    },
+                    _ => (point, None),
+                }
+            })
+        };
+
+        let mut coordinates: Vec<f64> = Vec::new();
+        let mut cell_vertex_ids: HashMap<[usize; 3], usize> = HashMap::new();
+        for z in 0..number_of_cells[2] {
+            for y in 0..number_of_cells[1] {
+                for x in 0..number_of_cells[0] {
+                    let corners: [[usize; 3]; 8] = std::array::from_fn(|corner| [x + (corner & 1), y + ((corner >> 1) & 1), z + ((corner >> 2) & 1)]);
+                    let hermite_data: Vec<(Point, Option<Vector>)> = CUBE_EDGES.iter()
+                        .filter(|[start, end]| (values[get_node_id(corners[*start])] < 0.0) != (values[get_node_id(corners[*end])] < 0.0))
+                        .map(|[start, end]| get_crossing(corners[*start], corners[*end]))
+                        .collect();
+                    if hermite_data.is_empty() {
+                        continue;
+                    }
+                    let vertex = get_cell_vertex(&hermite_data, &get_point(corners[0]), &get_point(corners[7]));
+                    coordinates.extend_from_slice(&[vertex.x, vertex.y, vertex.z]);
+                    cell_vertex_ids.insert([x, y, z], coordinates.len() / 3 - 1);
+                }
+            }
+        }
+
+        // Each edge of the grid crossed by the surface gets a quad connecting the 4 cells around it
+        let mut indices: Vec<usize> = Vec::new();
+        for z in 0..number_of_cells[2] {
+            for y in 0..number_of_cells[1] {
+                for x in 0..number_of_cells[0] {
+                    let node = [x, y, z];
+                    let is_inside = values[get_node_id(node)] < 0.0;
+                    for axis in 0..3 {
+                        // Other axes in cyclic order, so the quad goes anticlockwise around the axis
+                        let (first_axis, second_axis) = ((axis + 1) % 3, (axis + 2) % 3);
+                        let mut end = node;
+                        end[axis] += 1;
+                        if node[first_axis] == 0 || node[second_axis] == 0 || (values[get_node_id(end)] < 0.0) == is_inside {
+                            continue;
+                        }
+                        let mut cells = [node; 4];
+                        cells[0][first_axis] -= 1;
+                        cells[0][second_axis] -= 1;
+                        cells[1][second_axis] -= 1;
+                        cells[3][first_axis] -= 1;
+                        let mut ids = cells.map(|cell| cell_vertex_ids[&cell]);
+                        if !is_inside {
+                            ids.reverse();
+                        }
+                        indices.extend_from_slice(&[ids[0], ids[1], ids[2], ids[0], ids[2], ids[3]]);
+                    }
+                }
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+}
+
+/// Calculates the vertex of the cell minimizing the quadratic error function of the planes
+/// given by the hermite data, with the least-squares solution closest to their mass point.
+///
+/// If the result is too far outside the cell (from `min` to `max`), the mass point is used
+/// instead.
+fn get_cell_vertex(hermite_data: &[(Point, Option<Vector>)], min: &Point, max: &Point) -> Point {
+    let mut mass_point = Vector::zero();
+    for (point, _) in hermite_data {
+        mass_point = mass_point + point.to_vector();
+    }
+    let mass_point = (mass_point * (1.0 / hermite_data.len() as f64)).to_point();
+
+    // A^T A and A^T (b - A m) of planes n · x = n · p, relative to the mass point m
+    let mut ata = [[0.0; 3]; 3];
+    let mut atb = [0.0; 3];
+    for (point, normal) in hermite_data {
+        if let Some(normal) = normal {
+            let n = [normal.x, normal.y, normal.z];
+            let distance = normal.get_dot_product(&Vector::from_2_points(&mass_point, point));
+            for row in 0..3 {
+                for column in 0..3 {
+                    ata[row][column] += n[row] * n[column];
+                }
+                atb[row] += n[row] * distance;
+            }
+        }
+    }
+
+    let (eigenvalues, eigenvectors) = get_symmetric_eigen_decomposition(ata);
+    let biggest = eigenvalues.iter().copied().fold(0.0, f64::max);
+    let mut offset = Vector::zero();
+    for i in 0..3 {
+        if biggest > 0.0 && eigenvalues[i] > QEF_EIGENVALUE_TOLERANCE * biggest {
+            let eigenvector = Vector::new(eigenvectors[0][i], eigenvectors[1][i], eigenvectors[2][i]);
+            let projection = eigenvector.x * atb[0] + eigenvector.y * atb[1] + eigenvector.z * atb[2];
+            offset = offset + eigenvector * (projection / eigenvalues[i]);
+        }
+    }
+
+    let vertex = mass_point + offset;
+    let margin = (max.x - min.x) * CELL_MARGIN;
+    let is_in_cell = vertex.x >= min.x - margin && vertex.x <= max.x + margin
+        && vertex.y >= min.y - margin && vertex.y <= max.y + margin
+        && vertex.z >= min.z - margin && vertex.z <= max.z + margin;
+    if is_in_cell { vertex } else { mass_point }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::local_coordinate_system::LocalCoordinateSystem;
+    use crate::test_utils::get_cube;
+
+    fn get_volume(mesh: &Mesh) -> f64 {
+        mesh.to_triangles().iter().map(|triangle| {
+            triangle.first_point.to_vector().get_dot_product(&triangle.second_point.to_vector().get_cross_product(&triangle.third_point.to_vector())) / 6.0
+        }).sum()
+    }
+
+    #[test]
+    fn test_get_repaired_via_dual_contouring_keeps_corners_of_rotated_box() {
+        let input = get_cube(0.0, 3.0).get_in_global_coordinate_system(&LocalCoordinateSystem::new(
+            Point::new(0.1, 0.2, 0.3),
+            Vector::new(3.0, 4.0, 0.0).get_unitized(),
+            Vector::new(-0.48, 0.36, 0.8),
+        ));
+
+        let actual = input.get_repaired_via_dual_contouring(0.4);
+
+        assert!(actual.get_edges_with_missing_neighbour().is_empty());
+        assert!((get_volume(&actual) - 27.0).abs() < 0.3);
+        let points = actual.to_points();
+        for corner in input.to_points() {
+            assert!(points.iter().any(|point| point.get_distance_to_point(&corner) < 0.05));
+        }
+    }
+
+    #[test]
+    fn test_get_repaired_via_dual_contouring_sharper_than_marching_tetrahedra() {
+        let input = get_cube(0.0, 4.0);
+
+        let dual_contouring = input.get_repaired_via_dual_contouring(0.5).get_volume().unwrap();
+        let marching_tetrahedra = input.get_repaired_via_sdf(0.5).get_volume().unwrap();
+
+        assert!((dual_contouring - 64.0).abs() < 1e-9);
+        assert!((marching_tetrahedra - 64.0).abs() > 0.1);
+    }
+
+    #[test]
+    fn test_get_cell_vertex_corner() {
+        let hermite_data = vec![
+            (Point::new(1.0, 0.2, 0.3), Some(Vector::new(1.0, 0.0, 0.0))),
+            (Point::new(0.2, 1.0, 0.3), Some(Vector::new(0.0, 1.0, 0.0))),
+            (Point::new(0.3, 0.2, 1.0), Some(Vector::new(0.0, 0.0, 1.0))),
+        ];
+
+        let actual = get_cell_vertex(&hermite_data, &Point::new(0.5, 0.5, 0.5), &Point::new(1.5, 1.5, 1.5));
+
+        assert!(actual.get_distance_to_point(&Point::new(1.0, 1.0, 1.0)) < 1e-12);
+    }
+
+    #[test]
+    fn test_get_cell_vertex_flat_stays_near_mass_point() {
+        let hermite_data = vec![
+            (Point::new(0.0, 0.0, 1.0), Some(Vector::new(0.0, 0.0, 1.0))),
+            (Point::new(1.0, 1.0, 1.0), Some(Vector::new(0.0, 0.0, 1.0))),
+        ];
+
+        let actual = get_cell_vertex(&hermite_data, &Point::new(0.0, 0.0, 0.0), &Point::new(1.0, 1.0, 2.0));
+
+        assert!(actual.get_distance_to_point(&Point::new(0.5, 0.5, 1.0)) < 1e-12);
+    }
+}
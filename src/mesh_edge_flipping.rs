@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::triangle::Triangle;
+use crate::uv_channel::UvChannel;
+
+/// The maximum number of passes over all edges done by [Mesh::get_with_improved_triangulation].
+const MAX_EDGE_FLIPPING_PASSES: usize = 100;
+/// The minimum increase of the smallest angle (in radians) for an edge to be flipped, so
+/// nearly equal configurations don't flip back and forth.
+const MIN_ANGLE_IMPROVEMENT: f64 = 1e-9;
+
+impl Mesh {
+    /// Creates a new [Mesh] with edges flipped to maximize the minimum angles of faces in
+    /// near-planar regions.
+    ///
+    /// An edge shared by 2 faces is replaced by the other diagonal of the quad made of them
+    /// (so it's flipped) if it increases the smallest angle of these faces. It's the same rule
+    /// which makes a planar triangulation Delaunay, so it improves skinny triangles produced e.g.
+    /// by [Polygon::triangulate_raw](crate::polygon::Polygon::triangulate_raw) or
+    /// [Mesh::get_planar_simplify].
+    ///
+    /// Edges are flipped only if the angle between normals of both faces is not higher than
+    /// `angle_tolerance` (in radians) before and after the flip, so the shape of the [Mesh]
+    /// doesn't change noticeably. Edges between faces of different `face_groups` are kept.
+    ///
+    /// Vertices and the number of faces stay the same. Flipped faces keep their ids, so per
+    /// face data is kept, but per corner `uvs` are removed if any edge is flipped.
+    ///
+    /// The input [Mesh] should be welded with consistent winding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Rhombus split along its long diagonal
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, -1.0, 0.0, 8.0, 0.0, 0.0, 4.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]);
+    ///
+    /// let actual = input.get_with_improved_triangulation(0.01);
+    ///
+    /// // Split along the short diagonal
+    /// assert_eq!(actual.indices, vec![2, 3, 1, 3, 0, 1]);
+    /// ```
+    pub fn get_with_improved_triangulation(&self, angle_tolerance: f64) -> Mesh {
+        let points = self.to_points();
+        let mut indices = self.indices.clone();
+        let number_of_faces = self.get_number_of_faces();
+        let mut is_any_flipped = false;
+
+        for _ in 0..MAX_EDGE_FLIPPING_PASSES {
+            let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+            for (face_id, face) in indices.chunks(3).enumerate() {
+                for i in 0..3 {
+                    let (start, end) = (face[i], face[(i + 1) % 3]);
+                    edge_faces.entry((start.min(end), start.max(end))).or_default().push(face_id);
+                }
+            }
+
+            // Faces changed in this pass, their old edges in `edge_faces` are outdated
+            let mut is_changed = vec![false; number_of_faces];
+            let mut is_any_flipped_in_pass = false;
+            for face_id in 0..number_of_faces {
+                for i in 0..3 {
+                    if is_changed[face_id] {
+                        break;
+                    }
+                    let (a, b, c) = (indices[face_id * 3 + i], indices[face_id * 3 + (i + 1) % 3], indices[face_id * 3 + (i + 2) % 3]);
+                    let faces = &edge_faces[&(a.min(b), a.max(b))];
+                    if faces.len() != 2 {
+                        continue;
+                    }
+                    let other_face_id = if faces[0] == face_id { faces[1] } else { faces[0] };
+                    if is_changed[other_face_id] || !self.has_same_face_group(face_id, other_face_id) {
+                        continue;
+                    }
+                    let other_face = &indices[other_face_id * 3..other_face_id * 3 + 3];
+                    // The other face has to use the edge in the opposite direction: b, a, d
+                    let Some(j) = (0..3).find(|&j| other_face[j] == b && other_face[(j + 1) % 3] == a) else {
+                        continue;
+                    };
+                    let d = other_face[(j + 2) % 3];
+                    if c == d || edge_faces.contains_key(&(c.min(d), c.max(d))) {
+                        continue;
+                    }
+
+                    if is_flip_improving(&points, [a, b, c, d], angle_tolerance) {
+                        indices[face_id * 3..face_id * 3 + 3].copy_from_slice(&[a, d, c]);
+                        indices[other_face_id * 3..other_face_id * 3 + 3].copy_from_slice(&[d, b, c]);
+                        // So the new edge isn't created again by another flip
+                        edge_faces.insert((c.min(d), c.max(d)), vec![face_id, other_face_id]);
+                        is_changed[face_id] = true;
+                        is_changed[other_face_id] = true;
+                        is_any_flipped_in_pass = true;
+                    }
+                }
+            }
+
+            if !is_any_flipped_in_pass {
+                break;
+            }
+            is_any_flipped = true;
+        }
+
+        let mut improved = self.clone();
+        improved.indices = indices;
+        if is_any_flipped {
+            if let Some(UvChannel::PerCorner(_)) = improved.uvs {
+                improved.uvs = None;
+            }
+            improved.invalidate_cache();
+        }
+
+        improved
+    }
+
+    fn has_same_face_group(&self, face_id: usize, other_face_id: usize) -> bool {
+        match &self.face_groups {
+            Some(face_groups) => face_groups[face_id] == face_groups[other_face_id],
+            None => true,
+        }
+    }
+}
+
+/// Checks if replacing faces (a, b, c) and (b, a, d) with (a, d, c) and (d, b, c) increases
+/// the smallest angle, keeping both pairs of faces within the `angle_tolerance`.
+fn is_flip_improving(points: &[Point], [a, b, c, d]: [usize; 4], angle_tolerance: f64) -> bool {
+    let current = [Triangle::new(points[a], points[b], points[c]), Triangle::new(points[b], points[a], points[d])];
+    let flipped = [Triangle::new(points[a], points[d], points[c]), Triangle::new(points[d], points[b], points[c])];
+
+    let get_normals_angle = |triangles: &[Triangle; 2]| -> Option<f64> {
+        let first = triangles[0].get_normal_vector_unitized_checked()?;
+        let second = triangles[1].get_normal_vector_unitized_checked()?;
+        Some(first.get_angle(&second))
+    };
+    let (Some(current_angle), Some(flipped_angle)) = (get_normals_angle(&current), get_normals_angle(&flipped)) else {
+        return false;
+    };
+    if current_angle > angle_tolerance || flipped_angle > angle_tolerance {
+        return false;
+    }
+
+    // Flipping an edge of the concave quad creates a face pointing the other way
+    let current_normal = current[0].get_normal_vector_unitized() + current[1].get_normal_vector_unitized();
+    if flipped.iter().any(|triangle| triangle.get_normal_vector_unitized().get_dot_product(&current_normal) <= 0.0) {
+        return false;
+    }
+
+    let current_min_angle = current[0].get_min_angle().min(current[1].get_min_angle());
+    let flipped_min_angle = flipped[0].get_min_angle().min(flipped[1].get_min_angle());
+    flipped_min_angle > current_min_angle + MIN_ANGLE_IMPROVEMENT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edge_adjacency::EdgeAdjacency;
+
+    /// Gets a grid of `size` x `size` sheared quads, each split along its long diagonal.
+    fn get_sheared_grid(size: usize) -> Mesh {
+        let mut coordinates = Vec::new();
+        for y in 0..=size {
+            for x in 0..=size {
+                coordinates.extend_from_slice(&[x as f64 + y as f64 * 0.8, y as f64 * 0.6, 0.0]);
+            }
+        }
+        let mut indices = Vec::new();
+        for y in 0..size {
+            for x in 0..size {
+                let first = y * (size + 1) + x;
+                indices.extend_from_slice(&[first, first + 1, first + size + 2, first, first + size + 2, first + size + 1]);
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+
+    fn get_min_angle(mesh: &Mesh) -> f64 {
+        mesh.to_triangles().iter().map(|triangle| triangle.get_min_angle()).fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_get_with_improved_triangulation_rhombus() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 4.0, -1.0, 0.0, 8.0, 0.0, 0.0, 4.0, 1.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3]);
+
+        let actual = input.get_with_improved_triangulation(0.01);
+
+        assert_eq!(actual.indices, vec![2, 3, 1, 3, 0, 1]);
+        assert_eq!(actual.coordinates, input.coordinates);
+        assert!((actual.get_area() - input.get_area()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_with_improved_triangulation_already_good() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 4.0, -1.0, 0.0, 8.0, 0.0, 0.0, 4.0, 1.0, 0.0],
+            vec![1, 3, 0, 3, 1, 2]);
+
+        let actual = input.get_with_improved_triangulation(0.01);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_get_with_improved_triangulation_fold_kept() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 4.0, -1.0, 1.0, 8.0, 0.0, 0.0, 4.0, 1.0, 1.0],
+            vec![0, 1, 2, 0, 2, 3]);
+
+        let actual = input.get_with_improved_triangulation(0.1);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_get_with_improved_triangulation_concave_kept() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 4.0, -1.0, 0.0, 8.0, 0.0, 0.0, 4.0, -0.5, 0.0],
+            vec![0, 1, 2, 0, 2, 3]);
+
+        let actual = input.get_with_improved_triangulation(0.01);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_get_with_improved_triangulation_face_groups_kept() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 4.0, -1.0, 0.0, 8.0, 0.0, 0.0, 4.0, 1.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3]);
+        input.face_groups = Some(vec![0, 1]);
+
+        let actual = input.get_with_improved_triangulation(0.01);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_get_with_improved_triangulation_grid() {
+        let input = get_sheared_grid(6);
+
+        let actual = input.get_with_improved_triangulation(0.01);
+
+        assert_eq!(actual.get_number_of_faces(), input.get_number_of_faces());
+        assert!((actual.get_area() - input.get_area()).abs() < 1e-12);
+        assert!(EdgeAdjacency::from_mesh(&actual).get_edges_with_more_than_2_faces().is_empty());
+        assert!(get_min_angle(&actual) > get_min_angle(&input) + 0.1);
+        // Already improved Mesh doesn't change
+        assert_eq!(actual.get_with_improved_triangulation(0.01), actual);
+    }
+}
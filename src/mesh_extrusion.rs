@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+use crate::mesh::Mesh;
+use crate::uv_channel::UvChannel;
+use crate::vector::Vector;
+
+impl Mesh {
+    /// Extrudes the faces with given ids: pulls them outward by the `distance` and stitches them
+    /// with the rest of the [Mesh] with new side walls.
+    ///
+    /// Each vertex of the selection is moved along the average normal of the selected faces
+    /// around it. Vertices on the border of the selection (or used by not selected faces) are
+    /// copied, so the original ones stay in place for the not selected faces and the side walls.
+    /// Other vertices are just moved. Each border edge of the selection gets 2 side wall faces,
+    /// with the winding consistent with the extruded faces. A negative `distance` pushes the
+    /// faces inward.
+    ///
+    /// Extruded faces keep their ids, side walls are added at the end and take per face data
+    /// (`face_groups`, face `attributes`) from the extruded face they're attached to. Copied
+    /// vertices take per vertex data from the original ones. Per corner `uvs` are removed, as
+    /// they don't fit the new faces, and so are [Mesh::vertex_normals].
+    ///
+    /// The input [Mesh] should be welded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Square made of 2 faces
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]);
+    ///
+    /// input.extrude_faces(&[0, 1], 2.0);
+    ///
+    /// assert_eq!(input.get_number_of_vertices(), 8);
+    /// assert_eq!(input.get_number_of_faces(), 2 + 8);
+    /// assert_eq!(input.to_points()[4].z, 2.0);
+    /// assert_eq!(input.get_boundary_loops().len(), 1);
+    /// ```
+    pub fn extrude_faces(&mut self, face_ids: &[usize], distance: f64) {
+        let number_of_vertices = self.get_number_of_vertices();
+        let number_of_faces = self.get_number_of_faces();
+        let mut selected_face_ids = face_ids.to_vec();
+        selected_face_ids.sort();
+        selected_face_ids.dedup();
+        let selected: HashSet<usize> = selected_face_ids.iter().copied().collect();
+
+        let points = self.to_points();
+        let triangles = self.to_triangles();
+        let mut vertex_normals = vec![Vector::zero(); number_of_vertices];
+        let mut is_used_by_not_selected = vec![false; number_of_vertices];
+        let mut selected_edges: HashSet<(usize, usize)> = HashSet::new();
+        for (face_id, face) in self.indices.chunks(3).enumerate() {
+            if selected.contains(&face_id) {
+                let normal = triangles[face_id].get_normal_vector_unitized();
+                for i in 0..3 {
+                    vertex_normals[face[i]] = vertex_normals[face[i]] + normal;
+                    selected_edges.insert((face[i], face[(i + 1) % 3]));
+                }
+            } else {
+                for vertex_id in face {
+                    is_used_by_not_selected[*vertex_id] = true;
+                }
+            }
+        }
+
+        // Directed edges of selected faces without the opposite edge in the selection, with
+        // the face they belong to
+        let mut border_edges: Vec<(usize, usize, usize)> = Vec::new();
+        let mut is_on_border = vec![false; number_of_vertices];
+        for &face_id in &selected_face_ids {
+            for i in 0..3 {
+                let (start, end) = (self.indices[face_id * 3 + i], self.indices[face_id * 3 + (i + 1) % 3]);
+                if !selected_edges.contains(&(end, start)) {
+                    border_edges.push((start, end, face_id));
+                    is_on_border[start] = true;
+                    is_on_border[end] = true;
+                }
+            }
+        }
+
+        // Ids of vertices used by extruded faces, None for not selected vertices
+        let mut new_vertex_ids: Vec<Option<usize>> = vec![None; number_of_vertices];
+        let mut copied_vertex_ids: Vec<usize> = Vec::new();
+        for vertex_id in 0..number_of_vertices {
+            let normal = vertex_normals[vertex_id];
+            if normal.get_length() == 0.0 {
+                continue;
+            }
+            let moved = points[vertex_id] + normal.get_unitized() * distance;
+            if is_on_border[vertex_id] || is_used_by_not_selected[vertex_id] {
+                new_vertex_ids[vertex_id] = Some(number_of_vertices + copied_vertex_ids.len());
+                copied_vertex_ids.push(vertex_id);
+                self.coordinates.extend_from_slice(&[moved.x, moved.y, moved.z]);
+            } else {
+                new_vertex_ids[vertex_id] = Some(vertex_id);
+                self.coordinates[vertex_id * 3..vertex_id * 3 + 3].copy_from_slice(&[moved.x, moved.y, moved.z]);
+            }
+        }
+
+        for &face_id in &selected_face_ids {
+            for position in face_id * 3..face_id * 3 + 3 {
+                self.indices[position] = new_vertex_ids[self.indices[position]].unwrap();
+            }
+        }
+        let mut wall_source_face_ids: Vec<usize> = Vec::with_capacity(border_edges.len() * 2);
+        for (start, end, face_id) in border_edges {
+            let (moved_start, moved_end) = (new_vertex_ids[start].unwrap(), new_vertex_ids[end].unwrap());
+            self.indices.extend_from_slice(&[start, end, moved_end, start, moved_end, moved_start]);
+            wall_source_face_ids.extend_from_slice(&[face_id, face_id]);
+        }
+
+        let source_vertex_ids: Vec<usize> = (0..number_of_vertices).chain(copied_vertex_ids).collect();
+        let source_face_ids: Vec<usize> = (0..number_of_faces).chain(wall_source_face_ids).collect();
+        self.uvs = match &self.uvs {
+            Some(UvChannel::PerVertex(uvs)) => Some(UvChannel::PerVertex(source_vertex_ids.iter().flat_map(|vertex_id| [uvs[vertex_id * 2], uvs[vertex_id * 2 + 1]]).collect())),
+            _ => None,
+        };
+        self.vertex_colors = self.get_vertex_colors_by_vertex_ids(source_vertex_ids.iter().copied());
+        self.vertex_normals = None;
+        self.face_groups = self.get_face_groups_by_face_ids(&source_face_ids);
+        self.attributes = self.attributes.get_by_ids(&source_vertex_ids, number_of_vertices, &source_face_ids, number_of_faces);
+        self.invalidate_cache();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_attributes::AttributeValues;
+    use crate::point::Point;
+    use crate::test_utils::get_box;
+
+    #[test]
+    fn test_extrude_faces_top_of_box() {
+        let mut input = get_box(1.0, 1.0, 1.0);
+
+        input.extrude_faces(&[2, 3], 0.5);
+
+        assert_eq!(input.get_number_of_vertices(), 12);
+        assert_eq!(input.get_number_of_faces(), 12 + 8);
+        assert_eq!(input.to_points()[8], Point::new(0.0, 0.0, 1.5));
+        assert!(input.is_watertight());
+        assert!((input.get_volume().unwrap() - 1.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_extrude_faces_inward() {
+        let mut input = get_box(1.0, 1.0, 1.0);
+
+        input.extrude_faces(&[2, 3], -0.25);
+
+        assert!(input.is_watertight());
+        assert!((input.get_volume().unwrap() - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_extrude_faces_moves_inner_vertices() {
+        // Grid of 3 x 3 vertices, all faces extruded, so the middle vertex is just moved
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0,
+                 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 2.0, 1.0, 0.0,
+                 0.0, 2.0, 0.0, 1.0, 2.0, 0.0, 2.0, 2.0, 0.0],
+            vec![0, 1, 4, 0, 4, 3, 1, 2, 5, 1, 5, 4,
+                 3, 4, 7, 3, 7, 6, 4, 5, 8, 4, 8, 7]);
+
+        input.extrude_faces(&[0, 1, 2, 3, 4, 5, 6, 7], 1.0);
+
+        assert_eq!(input.get_number_of_vertices(), 9 + 8);
+        assert_eq!(input.get_number_of_faces(), 8 + 16);
+        assert_eq!(input.to_points()[4], Point::new(1.0, 1.0, 1.0));
+        assert!(input.get_non_manifold_vertices().is_empty());
+    }
+
+    #[test]
+    fn test_extrude_faces_keeps_data() {
+        let mut input = get_box(1.0, 1.0, 1.0);
+        input.face_groups = Some(vec![0, 0, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2]);
+        input.attributes.vertex.insert(String::from("temperature"), AttributeValues::F64(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]));
+
+        input.extrude_faces(&[2, 3], 0.5);
+
+        assert_eq!(input.face_groups, Some(vec![0, 0, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1]));
+        assert_eq!(input.attributes.vertex["temperature"], AttributeValues::F64(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 4.0, 5.0, 6.0, 7.0]));
+    }
+
+    #[test]
+    fn test_extrude_faces_nothing_selected() {
+        let mut input = get_box(1.0, 1.0, 1.0);
+        let expected = input.clone();
+
+        input.extrude_faces(&[], 0.5);
+
+        assert_eq!(input, expected);
+    }
+}
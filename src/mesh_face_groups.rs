@@ -0,0 +1,227 @@
+use crate::mesh::Mesh;
+
+impl Mesh {
+    /// Assigns the `group_id` to the given faces in `face_groups`, e.g. to set the material slot
+    /// of these faces.
+    ///
+    /// If the [Mesh] has no `face_groups` yet, all the other faces get the group 0.
+    ///
+    /// It panics if any of the face ids is out of range, or if the number of `face_groups` is
+    /// different than the number of faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3, 1, 4, 2]);
+    ///
+    /// input.assign_face_group(&[1, 2], 3);
+    ///
+    /// assert_eq!(input.face_groups, Some(vec![0, 3, 3]));
+    /// ```
+    pub fn assign_face_group(&mut self, face_ids: &[usize], group_id: usize) {
+        let face_groups = self.get_face_groups_mut();
+        for face_id in face_ids {
+            if *face_id >= face_groups.len() {
+                panic!("The face id {} is out of range", face_id);
+            }
+            face_groups[*face_id] = group_id;
+        }
+    }
+
+    /// Assigns `face_groups` using given sets of face ids, e.g. the result of splitting the
+    /// [Mesh] into parts. Faces from each set get the group id equal to the position of the set.
+    ///
+    /// Faces which are not in any of the sets keep their current group, or get the group 0 if
+    /// the [Mesh] has no `face_groups` yet.
+    ///
+    /// It panics if any of the face ids is out of range, or if the number of `face_groups` is
+    /// different than the number of faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3, 1, 4, 2]);
+    ///
+    /// input.assign_face_groups(&[vec![2], vec![0, 1]]);
+    ///
+    /// assert_eq!(input.face_groups, Some(vec![1, 1, 0]));
+    /// ```
+    pub fn assign_face_groups(&mut self, face_ids_per_group: &[Vec<usize>]) {
+        for (group_id, face_ids) in face_ids_per_group.iter().enumerate() {
+            self.assign_face_group(face_ids, group_id);
+        }
+    }
+
+    /// Assigns `face_groups` using parts which are not connected with each other, the same way
+    /// as [Mesh::split_by_face_disconnected] splits the [Mesh], but without splitting it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 5.0, 0.0, 0.0, 6.0, 0.0, 0.0, 6.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 4, 5, 6, 0, 2, 3]);
+    ///
+    /// input.assign_face_groups_by_face_disconnected();
+    ///
+    /// assert_eq!(input.face_groups, Some(vec![0, 1, 0]));
+    /// ```
+    pub fn assign_face_groups_by_face_disconnected(&mut self) {
+        let face_ids_per_group = self.get_face_ids_split_by_face_disconnected();
+        self.assign_face_groups(&face_ids_per_group);
+    }
+
+    /// Assigns `face_groups` using parts split where the angle between faces' normals is higher
+    /// than the `max_angle` (in radians), the same way as [Mesh::split_by_face_angle] splits the
+    /// [Mesh], but without splitting it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 1, 2, 0, 2, 3, 0, 4, 1]);
+    ///
+    /// input.assign_face_groups_by_face_angle(0.1);
+    ///
+    /// assert_eq!(input.face_groups, Some(vec![1, 1, 0]));
+    /// ```
+    pub fn assign_face_groups_by_face_angle(&mut self, max_angle: f64) {
+        let face_ids_per_group = self.get_face_ids_split_by_face_angle(max_angle);
+        self.assign_face_groups(&face_ids_per_group);
+    }
+
+    /// Gets face ids for every group id: from 0 up to the highest group id from `face_groups`.
+    ///
+    /// It panics if the [Mesh] has no `face_groups`, or if their number is different than the
+    /// number of faces.
+    pub(crate) fn get_face_ids_per_face_group(&self) -> Vec<Vec<usize>> {
+        let face_groups = match &self.face_groups {
+            Some(face_groups) => face_groups,
+            None => panic!("Cannot split the Mesh by face groups, because it has no face groups"),
+        };
+        if face_groups.len() != self.get_number_of_faces() {
+            panic!("The number of face groups should be equal to the number of faces");
+        }
+
+        let number_of_groups = face_groups.iter().max().map_or(0, |max| max + 1);
+        let mut face_ids_per_group: Vec<Vec<usize>> = vec![Vec::new(); number_of_groups];
+        for (face_id, group_id) in face_groups.iter().enumerate() {
+            face_ids_per_group[*group_id].push(face_id);
+        }
+
+        face_ids_per_group
+    }
+
+    /// Gets `face_groups` of the given faces, in the given order.
+    pub(crate) fn get_face_groups_by_face_ids(&self, face_ids: &[usize]) -> Option<Vec<usize>> {
+        let face_groups = self.face_groups.as_ref()?;
+        Some(face_ids.iter().map(|face_id| face_groups[*face_id]).collect())
+    }
+
+    /// Gets `face_groups` for the Meshes joined in the given order. If none of the Meshes has
+    /// groups, there are no groups, otherwise faces of Meshes without groups get the group 0.
+    pub(crate) fn get_joined_face_groups(meshes: &[&Mesh]) -> Option<Vec<usize>> {
+        if meshes.iter().all(|mesh| mesh.face_groups.is_none()) {
+            return None;
+        }
+
+        let mut face_groups: Vec<usize> = Vec::new();
+        for mesh in meshes {
+            match &mesh.face_groups {
+                Some(mesh_face_groups) => face_groups.extend_from_slice(mesh_face_groups),
+                None => face_groups.extend(std::iter::repeat_n(0, mesh.get_number_of_faces())),
+            }
+        }
+        Some(face_groups)
+    }
+
+    fn get_face_groups_mut(&mut self) -> &mut Vec<usize> {
+        let number_of_faces = self.get_number_of_faces();
+        let face_groups = self.face_groups.get_or_insert_with(|| vec![0; number_of_faces]);
+        if face_groups.len() != number_of_faces {
+            panic!("The number of face groups should be equal to the number of faces");
+        }
+        face_groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_strip() -> Mesh {
+        Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3, 1, 4, 2])
+    }
+
+    #[test]
+    fn test_assign_face_group_keeps_other_groups() {
+        let mut input = get_strip();
+        input.face_groups = Some(vec![4, 5, 6]);
+
+        input.assign_face_group(&[0], 1);
+
+        assert_eq!(input.face_groups, Some(vec![1, 5, 6]));
+    }
+
+    #[test]
+    #[should_panic(expected = "The face id 3 is out of range")]
+    fn test_assign_face_group_out_of_range_panic() {
+        let mut input = get_strip();
+        input.assign_face_group(&[3], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "The number of face groups should be equal to the number of faces")]
+    fn test_assign_face_group_wrong_number_of_groups_panic() {
+        let mut input = get_strip();
+        input.face_groups = Some(vec![0]);
+        input.assign_face_group(&[0], 1);
+    }
+
+    #[test]
+    fn test_assign_face_groups_and_split_back() {
+        let mut input = get_strip();
+
+        input.assign_face_groups(&[vec![1], vec![0, 2]]);
+
+        assert_eq!(input.face_groups, Some(vec![1, 0, 1]));
+        let split = input.split_by_face_groups();
+        assert_eq!(split.len(), 2);
+        assert_eq!(split[0].get_number_of_faces(), 1);
+        assert_eq!(split[1].get_number_of_faces(), 2);
+    }
+
+    #[test]
+    fn test_get_face_ids_per_face_group() {
+        let mut input = get_strip();
+        input.face_groups = Some(vec![2, 0, 2]);
+
+        assert_eq!(input.get_face_ids_per_face_group(), vec![vec![1], vec![], vec![0, 2]]);
+    }
+
+    #[test]
+    fn test_get_joined_face_groups() {
+        let mut a = get_strip();
+        let b = get_strip();
+
+        assert_eq!(Mesh::get_joined_face_groups(&[&a, &b]), None);
+
+        a.face_groups = Some(vec![1, 2, 3]);
+        assert_eq!(Mesh::get_joined_face_groups(&[&b, &a]), Some(vec![0, 0, 0, 1, 2, 3]));
+    }
+}
@@ -0,0 +1,712 @@
+use std::f64::consts::PI;
+use crate::mesh::Mesh;
+use crate::mesh_hole_filling::get_newell_normal;
+use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::polygon2d::Polygon2D;
+use crate::polyline::Polyline;
+use crate::ray::Ray;
+use crate::vector::Vector;
+
+/// Profile vertices closer to the axis than this part of the profile's length are treated as
+/// lying on the axis by [Mesh::revolve].
+const ON_AXIS_TOLERANCE: f64 = 1e-9;
+
+/// Relative lengths along perimeters of sections closer than this are treated as the same by
+/// [Mesh::loft].
+const LOFT_PARAMETER_TOLERANCE: f64 = 1e-9;
+
+impl Mesh {
+    /// Creates a new [Mesh] by revolving (lathing) the `profile` around the `axis` by the
+    /// `angle` (in radians), split into `segments`.
+    ///
+    /// The rotation follows the right hand rule around the `axis` direction. For the full
+    /// rotation (`angle` of 2 PI or more) the last ring of vertices is welded with the first
+    /// one, so the [Mesh] is closed around the axis. Profile vertices lying on the axis are
+    /// shared by all the rings (poles), so e.g. a profile starting and ending on the axis creates
+    /// a closed solid.
+    ///
+    /// Faces point away from the axis if the profile goes along the `axis` direction (for the
+    /// positive `angle`), reverse the profile to get the opposite orientation.
+    ///
+    /// Vertices go ring by ring, in the order of the profile's vertices. Vertices on the axis are
+    /// only in the first ring.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    /// use meshmeshmesh::ray::Ray;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// // Closed cylinder with radius 1.0 and height 2.0
+    /// let profile = Polyline::new(vec![
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(1.0, 0.0, 0.0),
+    ///     Point::new(1.0, 0.0, 2.0),
+    ///     Point::new(0.0, 0.0, 2.0),
+    /// ]);
+    /// let axis = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// let actual = Mesh::revolve(&profile, &axis, 2.0 * PI, 64);
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 2 + 2 * 64);
+    /// assert!(actual.is_watertight());
+    /// assert!((actual.get_volume().unwrap() - 2.0 * PI).abs() < 0.02);
+    /// ```
+    pub fn revolve(profile: &Polyline, axis: &Ray, angle: f64, segments: usize) -> Mesh {
+        let direction = axis.direction.get_unitized();
+        let is_full = angle.abs() >= 2.0 * PI;
+        let number_of_rings = if is_full { segments } else { segments + 1 };
+        let profile_length: f64 = profile.vertices.windows(2).map(|pair| pair[0].get_distance_to_point(&pair[1])).sum();
+
+        let radial_vectors: Vec<Vector> = profile.vertices.iter()
+            .map(|vertex| {
+                let from_origin = Vector::from_2_points(&axis.origin, vertex);
+                from_origin - direction * from_origin.get_dot_product(&direction)
+            })
+            .collect();
+
+        let mut coordinates: Vec<f64> = Vec::new();
+        let mut ring_vertex_ids: Vec<Vec<usize>> = Vec::with_capacity(number_of_rings);
+        for ring in 0..number_of_rings {
+            let mut vertex_ids = Vec::with_capacity(profile.vertices.len());
+            for (i, vertex) in profile.vertices.iter().enumerate() {
+                let is_on_axis = radial_vectors[i].get_length() <= profile_length * ON_AXIS_TOLERANCE;
+                if is_on_axis && ring > 0 {
+                    vertex_ids.push(ring_vertex_ids[0][i]);
+                    continue;
+                }
+                let rotated = axis.origin + Vector::from_2_points(&axis.origin, vertex).get_rotated(&direction, angle * ring as f64 / segments as f64);
+                vertex_ids.push(coordinates.len() / 3);
+                coordinates.extend_from_slice(&[rotated.x, rotated.y, rotated.z]);
+            }
+            ring_vertex_ids.push(vertex_ids);
+        }
+
+        let mut indices: Vec<usize> = Vec::new();
+        for ring in 0..segments {
+            let (current, next) = (&ring_vertex_ids[ring], &ring_vertex_ids[(ring + 1) % number_of_rings]);
+            for i in 0..profile.vertices.len() - 1 {
+                let (a, b, c, d) = (current[i], current[i + 1], next[i + 1], next[i]);
+                let mut triangles = Vec::with_capacity(2);
+                if a != d {
+                    triangles.push([a, d, c]);
+                }
+                if b != c {
+                    triangles.push([a, c, b]);
+                }
+                for [first, second, third] in triangles {
+                    if angle < 0.0 {
+                        indices.extend_from_slice(&[first, third, second]);
+                    } else {
+                        indices.extend_from_slice(&[first, second, third]);
+                    }
+                }
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+
+    /// Creates a new closed [Mesh] by sweeping the `profile` along the `path`, with both ends
+    /// capped.
+    ///
+    /// The profile is placed at each vertex of the path, perpendicular to the average of the
+    /// directions of neighbouring segments. Its local x and y axes are carried along the path
+    /// with the least possible twist (parallel transport), starting from an axis perpendicular
+    /// to the first segment. Caps are triangulated with [Polygon2D::triangulate_raw].
+    ///
+    /// Faces point outside regardless of the orientation of the `profile`. Vertices go ring by
+    /// ring, in the order of the path's vertices.
+    ///
+    /// The `path` should have at least 2 vertices, and it shouldn't bend more than the profile
+    /// can take without self-intersections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::polygon2d::Polygon2D;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let profile = Polygon2D::new(vec![
+    ///     Point2D::new(-0.5, -0.5),
+    ///     Point2D::new(0.5, -0.5),
+    ///     Point2D::new(0.5, 0.5),
+    ///     Point2D::new(-0.5, 0.5),
+    /// ]);
+    /// let path = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 5.0)]);
+    ///
+    /// let actual = Mesh::sweep(&profile, &path);
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 8);
+    /// assert!(actual.is_watertight());
+    /// assert!((actual.get_volume().unwrap() - 5.0).abs() < 1e-9);
+    /// ```
+    pub fn sweep(profile: &Polygon2D, path: &Polyline) -> Mesh {
+        let mut profile_points = profile.vertices.clone();
+        if profile.is_clockwise() {
+            profile_points.reverse();
+        }
+        let number_of_profile_vertices = profile_points.len();
+        let number_of_path_vertices = path.vertices.len();
+
+        let segment_directions: Vec<Vector> = path.vertices.windows(2)
+            .map(|pair| Vector::from_2_points(&pair[0], &pair[1]).get_unitized())
+            .collect();
+        let tangents: Vec<Vector> = (0..number_of_path_vertices)
+            .map(|i| match (i.checked_sub(1).map(|previous| segment_directions[previous]), segment_directions.get(i)) {
+                (Some(previous), Some(next)) => (previous + *next).get_unitized(),
+                (Some(previous), None) => previous,
+                (None, Some(next)) => *next,
+                (None, None) => panic!("The path should have at least 2 vertices"),
+            })
+            .collect();
+
+        let mut coordinates: Vec<f64> = Vec::with_capacity(number_of_path_vertices * number_of_profile_vertices * 3);
+        let mut x_axis = get_perpendicular(&tangents[0]);
+        for (i, path_vertex) in path.vertices.iter().enumerate() {
+            if i > 0 {
+                x_axis = get_parallel_transported(&x_axis, &tangents[i - 1], &tangents[i]);
+            }
+            let y_axis = tangents[i].get_cross_product(&x_axis);
+            for profile_point in &profile_points {
+                let point = *path_vertex + x_axis * profile_point.x + y_axis * profile_point.y;
+                coordinates.extend_from_slice(&[point.x, point.y, point.z]);
+            }
+        }
+
+        let mut indices: Vec<usize> = Vec::new();
+        for i in 0..number_of_path_vertices - 1 {
+            let (current, next) = (i * number_of_profile_vertices, (i + 1) * number_of_profile_vertices);
+            for k in 0..number_of_profile_vertices {
+                let k_next = (k + 1) % number_of_profile_vertices;
+                indices.extend_from_slice(&[current + k, current + k_next, next + k_next, current + k, next + k_next, next + k]);
+            }
+        }
+
+        // Triangulation reorders vertices, so they're matched back with the profile
+        let cap = profile.triangulate_raw();
+        let cap_vertex_ids: Vec<usize> = cap.to_points().iter()
+            .map(|cap_point| (0..number_of_profile_vertices)
+                .min_by(|a, b| {
+                    let get_distance = |k: &usize| (profile_points[*k].x - cap_point.x).powi(2) + (profile_points[*k].y - cap_point.y).powi(2);
+                    get_distance(a).total_cmp(&get_distance(b))
+                })
+                .unwrap())
+            .collect();
+        let end = (number_of_path_vertices - 1) * number_of_profile_vertices;
+        for face in cap.indices.chunks(3) {
+            let [first, second, third] = [cap_vertex_ids[face[0]], cap_vertex_ids[face[1]], cap_vertex_ids[face[2]]];
+            let (a, b, c) = (profile_points[first], profile_points[second], profile_points[third]);
+            let is_anticlockwise = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x) > 0.0;
+            // Start cap points against the path, end cap along it
+            if is_anticlockwise {
+                indices.extend_from_slice(&[first, third, second, end + first, end + second, end + third]);
+            } else {
+                indices.extend_from_slice(&[first, second, third, end + first, end + third, end + second]);
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+
+    /// Creates a new [Mesh] by lofting through the `sections` in the given order, so it builds
+    /// the transition between them.
+    ///
+    /// Sections can have different numbers of vertices, their vertices are matched this way:
+    /// * each section is oriented anticlockwise, looking against the loft direction (from the
+    ///   previous section to the next one),
+    /// * its first vertex is matched with the previous section: for the same number of vertices
+    ///   the shift with the smallest sum of squared distances between corresponding vertices is
+    ///   used, otherwise the vertex closest to the first vertex of the previous section (both
+    ///   relative to the sections' centroids),
+    /// * neighbouring sections are connected with triangles following the relative length along
+    ///   their perimeters, so vertices without a counterpart get fans of triangles. Quads between
+    ///   corresponding vertices are split along the shorter diagonal.
+    ///
+    /// If `closed` is `true`, the first and the last sections are capped with
+    /// [Polygon::triangulate_raw], so the [Mesh] is closed. Faces point outside.
+    ///
+    /// Vertices go section by section, starting from the matched first vertex, in the matched
+    /// direction.
+    ///
+    /// It returns an error if there are less than 2 sections, or any section has less than 3
+    /// vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polygon::Polygon;
+    ///
+    /// // Transition from the square to the triangle
+    /// let sections = vec![
+    ///     Polygon::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(2.0, 2.0, 0.0), Point::new(0.0, 2.0, 0.0)]),
+    ///     Polygon::new(vec![Point::new(0.0, 0.0, 3.0), Point::new(2.0, 0.0, 3.0), Point::new(1.0, 2.0, 3.0)]),
+    /// ];
+    ///
+    /// let actual = Mesh::loft(&sections, false).unwrap();
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 7);
+    /// assert_eq!(actual.get_number_of_faces(), 4 + 3);
+    /// assert_eq!(actual.get_boundary_loops().len(), 2);
+    /// ```
+    pub fn loft(sections: &[Polygon], closed: bool) -> Result<Mesh, String> {
+        if sections.len() < 2 {
+            return Err(String::from("There should be at least 2 sections"));
+        }
+        if sections.iter().any(|section| section.vertices.len() < 3) {
+            return Err(String::from("Each section should have at least 3 vertices"));
+        }
+
+        let centroids: Vec<Point> = sections.iter().map(|section| get_centroid(&section.vertices)).collect();
+        let get_direction = |i: usize| Vector::from_2_points(&centroids[i.saturating_sub(1)], &centroids[(i + 1).min(sections.len() - 1)]);
+        let mut matched_sections: Vec<Vec<Point>> = Vec::with_capacity(sections.len());
+        for (i, section) in sections.iter().enumerate() {
+            let mut vertices = section.vertices.clone();
+            if get_newell_normal(&vertices).get_dot_product(&get_direction(i)) < 0.0 {
+                vertices.reverse();
+            }
+            if let Some(previous) = matched_sections.last() {
+                let shift = get_matching_shift(previous, &centroids[i - 1], &vertices, &centroids[i]);
+                vertices.rotate_left(shift);
+            }
+            matched_sections.push(vertices);
+        }
+
+        let mut coordinates: Vec<f64> = Vec::new();
+        let mut first_vertex_ids: Vec<usize> = Vec::with_capacity(sections.len());
+        for vertices in &matched_sections {
+            first_vertex_ids.push(coordinates.len() / 3);
+            for vertex in vertices {
+                coordinates.extend_from_slice(&[vertex.x, vertex.y, vertex.z]);
+            }
+        }
+
+        let mut indices: Vec<usize> = Vec::new();
+        for i in 0..sections.len() - 1 {
+            add_strip_indices(&mut indices, first_vertex_ids[i], &matched_sections[i], first_vertex_ids[i + 1], &matched_sections[i + 1]);
+        }
+
+        if closed {
+            // First cap points against the loft direction, the last one along it
+            for (i, is_along) in [(0, false), (sections.len() - 1, true)] {
+                let vertices = &matched_sections[i];
+                let direction = get_direction(i);
+                for [first, second, third] in Polygon::new(vertices.clone()).get_triangulation_vertex_ids(&Vec::new()) {
+                    let normal = Vector::from_2_points(&vertices[first], &vertices[second])
+                        .get_cross_product(&Vector::from_2_points(&vertices[first], &vertices[third]));
+                    let first_vertex_id = first_vertex_ids[i];
+                    if (normal.get_dot_product(&direction) > 0.0) == is_along {
+                        indices.extend_from_slice(&[first_vertex_id + first, first_vertex_id + second, first_vertex_id + third]);
+                    } else {
+                        indices.extend_from_slice(&[first_vertex_id + first, first_vertex_id + third, first_vertex_id + second]);
+                    }
+                }
+            }
+        }
+
+        Ok(Mesh::new(coordinates, indices))
+    }
+
+    /// Creates a new [Mesh] of a pipe (tube) with the given `radius` along the `path`.
+    ///
+    /// The cross-section is a regular polygon with the number of vertices equal to `segments`.
+    /// It's carried along the segments of the path with the least possible twist (parallel
+    /// transport), like the profile of [Mesh::sweep]. At the corners it's placed in the miter
+    /// plane (halving the angle between segments) and stretched across the bend, so straight
+    /// parts of the pipe keep the `radius` and meet without gaps.
+    ///
+    /// If `cap_ends` is `true`, both ends are closed with fans of triangles around new vertices
+    /// at the ends of the path, so the [Mesh] is closed. Faces point outside.
+    ///
+    /// Vertices go ring by ring, in the order of the path's vertices, followed by the start and
+    /// the end cap vertices if `cap_ends` is `true`.
+    ///
+    /// The `path` should have at least 2 vertices and shouldn't turn back on itself. The
+    /// `segments` should be at least 3.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let path = Polyline::new(vec![
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(0.0, 0.0, 4.0),
+    ///     Point::new(3.0, 0.0, 4.0),
+    /// ]);
+    ///
+    /// let actual = Mesh::pipe(&path, 0.5, 16, true);
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 3 * 16 + 2);
+    /// assert_eq!(actual.get_number_of_faces(), 2 * 2 * 16 + 2 * 16);
+    /// assert!(actual.is_watertight());
+    /// ```
+    pub fn pipe(path: &Polyline, radius: f64, segments: usize, cap_ends: bool) -> Mesh {
+        let number_of_path_vertices = path.vertices.len();
+        let segment_directions: Vec<Vector> = path.vertices.windows(2)
+            .map(|pair| Vector::from_2_points(&pair[0], &pair[1]).get_unitized())
+            .collect();
+
+        let mut coordinates: Vec<f64> = Vec::with_capacity((number_of_path_vertices * segments + 2) * 3);
+        // Perpendicular to the incoming segment, carried along segments, so straight parts
+        // aren't twisted
+        let mut segment_x_axis = get_perpendicular(&segment_directions[0]);
+        for (i, path_vertex) in path.vertices.iter().enumerate() {
+            let previous = i.checked_sub(1).map(|previous| segment_directions[previous]);
+            let next = segment_directions.get(i).copied();
+            let tangent = match (previous, next) {
+                (Some(previous), Some(next)) => (previous + next).get_unitized(),
+                (Some(previous), None) => previous,
+                (None, Some(next)) => next,
+                (None, None) => panic!("The path should have at least 2 vertices"),
+            };
+            let incoming = previous.or(next).unwrap();
+            let x_axis = get_parallel_transported(&segment_x_axis, &incoming, &tangent);
+            let y_axis = tangent.get_cross_product(&x_axis);
+
+            // Offsets across the bend are stretched, so the ring lies where both straight parts meet
+            let (bend_normal, stretch) = match (previous, next) {
+                (Some(previous), Some(next)) if previous.get_cross_product(&next).get_length() > f64::EPSILON => {
+                    (Some(previous.get_cross_product(&next).get_unitized()), 1.0 / (previous.get_angle(&next) / 2.0).cos())
+                },
+                _ => (None, 1.0),
+            };
+            for k in 0..segments {
+                let angle = 2.0 * PI * k as f64 / segments as f64;
+                let mut offset = x_axis * (radius * angle.cos()) + y_axis * (radius * angle.sin());
+                if let Some(bend_normal) = bend_normal {
+                    let along_bend_normal = bend_normal * offset.get_dot_product(&bend_normal);
+                    offset = along_bend_normal + (offset - along_bend_normal) * stretch;
+                }
+                let point = *path_vertex + offset;
+                coordinates.extend_from_slice(&[point.x, point.y, point.z]);
+            }
+            if let (Some(previous), Some(next)) = (previous, next) {
+                segment_x_axis = get_parallel_transported(&segment_x_axis, &previous, &next);
+            }
+        }
+
+        let mut indices: Vec<usize> = Vec::new();
+        for i in 0..number_of_path_vertices - 1 {
+            let (current, next) = (i * segments, (i + 1) * segments);
+            for k in 0..segments {
+                let k_next = (k + 1) % segments;
+                indices.extend_from_slice(&[current + k, current + k_next, next + k_next, current + k, next + k_next, next + k]);
+            }
+        }
+
+        if cap_ends {
+            let (start_center, end_center) = (number_of_path_vertices * segments, number_of_path_vertices * segments + 1);
+            for center in [path.vertices[0], path.vertices[number_of_path_vertices - 1]] {
+                coordinates.extend_from_slice(&[center.x, center.y, center.z]);
+            }
+            let end = (number_of_path_vertices - 1) * segments;
+            // Start cap points against the path, end cap along it
+            for k in 0..segments {
+                let k_next = (k + 1) % segments;
+                indices.extend_from_slice(&[start_center, k_next, k, end_center, end + k, end + k_next]);
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+}
+
+/// Gets any unit [Vector] perpendicular to the given unit [Vector].
+fn get_perpendicular(vector: &Vector) -> Vector {
+    let helper = if vector.z.abs() < 0.9 { Vector::new(0.0, 0.0, 1.0) } else { Vector::new(1.0, 0.0, 0.0) };
+    helper.get_cross_product(vector).get_unitized()
+}
+
+/// Gets the unit [Vector] perpendicular to `tangent` by rotating `axis` perpendicular to
+/// `previous_tangent` with the smallest rotation taking `previous_tangent` to `tangent`.
+fn get_parallel_transported(axis: &Vector, previous_tangent: &Vector, tangent: &Vector) -> Vector {
+    let rotation_axis = previous_tangent.get_cross_product(tangent);
+    let rotated = if rotation_axis.get_length() > f64::EPSILON {
+        axis.get_rotated(&rotation_axis, previous_tangent.get_angle(tangent))
+    } else {
+        *axis
+    };
+
+    (rotated - *tangent * rotated.get_dot_product(tangent)).get_unitized()
+}
+
+/// Gets the average of the given [Point]s.
+fn get_centroid(points: &[Point]) -> Point {
+    let mut sum = Vector::zero();
+    for point in points {
+        sum = sum + Vector::new(point.x, point.y, point.z);
+    }
+    let average = sum * (1.0 / points.len() as f64);
+
+    Point::new(average.x, average.y, average.z)
+}
+
+/// Gets the number of positions by which `vertices` should be rotated left, so their first
+/// vertex matches the first vertex of the `previous` section, see [Mesh::loft].
+fn get_matching_shift(previous: &[Point], previous_centroid: &Point, vertices: &[Point], centroid: &Point) -> usize {
+    let relative_previous: Vec<Vector> = previous.iter().map(|vertex| Vector::from_2_points(previous_centroid, vertex)).collect();
+    let relative: Vec<Vector> = vertices.iter().map(|vertex| Vector::from_2_points(centroid, vertex)).collect();
+    let number_of_vertices = vertices.len();
+    if previous.len() == number_of_vertices {
+        let get_cost = |shift: &usize| -> f64 {
+            (0..number_of_vertices).map(|k| (relative_previous[k] - relative[(k + shift) % number_of_vertices]).get_length().powi(2)).sum()
+        };
+        (0..number_of_vertices).min_by(|a, b| get_cost(a).total_cmp(&get_cost(b))).unwrap()
+    } else {
+        let get_distance = |j: &usize| (relative_previous[0] - relative[*j]).get_length();
+        (0..number_of_vertices).min_by(|a, b| get_distance(a).total_cmp(&get_distance(b))).unwrap()
+    }
+}
+
+/// Gets the relative lengths along the closed loop of vertices at each vertex, from 0.0 at the
+/// first vertex to 1.0 back at the first vertex.
+fn get_perimeter_parameters(vertices: &[Point]) -> Vec<f64> {
+    let number_of_vertices = vertices.len();
+    let mut parameters = Vec::with_capacity(number_of_vertices + 1);
+    let mut length = 0.0;
+    parameters.push(length);
+    for i in 0..number_of_vertices {
+        length += vertices[i].get_distance_to_point(&vertices[(i + 1) % number_of_vertices]);
+        parameters.push(length);
+    }
+
+    parameters.iter().map(|parameter| parameter / length).collect()
+}
+
+/// Adds triangles connecting 2 closed loops of vertices, advancing on the loop with the smaller
+/// relative length to its next vertex, or on the one giving the shorter diagonal if both are
+/// the same.
+fn add_strip_indices(indices: &mut Vec<usize>, first_vertex_id: usize, first_loop: &[Point], second_vertex_id: usize, second_loop: &[Point]) {
+    let (n, m) = (first_loop.len(), second_loop.len());
+    let (first_parameters, second_parameters) = (get_perimeter_parameters(first_loop), get_perimeter_parameters(second_loop));
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let is_advancing_first = if i == n {
+            false
+        } else if j == m {
+            true
+        } else if (first_parameters[i + 1] - second_parameters[j + 1]).abs() > LOFT_PARAMETER_TOLERANCE {
+            first_parameters[i + 1] < second_parameters[j + 1]
+        } else {
+            first_loop[(i + 1) % n].get_distance_to_point(&second_loop[j]) <= first_loop[i].get_distance_to_point(&second_loop[(j + 1) % m])
+        };
+        if is_advancing_first {
+            indices.extend_from_slice(&[first_vertex_id + i, first_vertex_id + (i + 1) % n, second_vertex_id + j % m]);
+            i += 1;
+        } else {
+            indices.extend_from_slice(&[first_vertex_id + i % n, second_vertex_id + (j + 1) % m, second_vertex_id + j]);
+            j += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edge_adjacency::EdgeAdjacency;
+    use crate::point2d::Point2D;
+
+    fn get_z_axis() -> Ray {
+        Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0))
+    }
+
+    fn get_square_profile() -> Polygon2D {
+        Polygon2D::new(vec![
+            Point2D::new(-0.5, -0.5),
+            Point2D::new(0.5, -0.5),
+            Point2D::new(0.5, 0.5),
+            Point2D::new(-0.5, 0.5),
+        ])
+    }
+
+    #[test]
+    fn test_revolve_closed() {
+        let profile = Polyline::new(vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(2.0, 0.0, 3.0),
+            Point::new(0.0, 0.0, 3.0),
+        ]);
+
+        let actual = Mesh::revolve(&profile, &get_z_axis(), 2.0 * PI, 16);
+
+        assert_eq!(actual.get_number_of_vertices(), 2 + 2 * 16);
+        assert_eq!(actual.get_number_of_faces(), 16 * 4);
+        assert!(actual.is_watertight());
+        // Volume of the prism with the regular 16-gon base
+        let expected_volume = 16.0 / 2.0 * 4.0 * (2.0 * PI / 16.0).sin() * 3.0;
+        assert!((actual.get_volume().unwrap() - expected_volume).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_revolve_negative_angle() {
+        let profile = Polyline::new(vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(2.0, 0.0, 3.0),
+            Point::new(0.0, 0.0, 3.0),
+        ]);
+
+        let actual = Mesh::revolve(&profile, &get_z_axis(), -2.0 * PI, 16);
+
+        assert!(actual.is_watertight());
+        assert!(actual.get_volume().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_revolve_partial() {
+        let profile = Polyline::new(vec![Point::new(1.0, 0.0, 0.0), Point::new(1.0, 0.0, 1.0)]);
+
+        let actual = Mesh::revolve(&profile, &get_z_axis(), PI / 2.0, 4);
+
+        assert_eq!(actual.get_number_of_vertices(), 2 * 5);
+        assert_eq!(actual.get_number_of_faces(), 4 * 2);
+        assert!(actual.to_points()[8].eq_with_tolerance(&Point::new(0.0, 1.0, 0.0), 1e-12));
+        // Faces point away from the axis
+        let triangle = actual.to_triangles()[0];
+        assert!(triangle.get_normal_vector_unitized().get_dot_product(&Vector::from_2_points(&Point::new(0.0, 0.0, 0.0), &triangle.get_centroid())) > 0.0);
+    }
+
+    #[test]
+    fn test_sweep_straight() {
+        let path = Polyline::new(vec![Point::new(1.0, 2.0, 3.0), Point::new(4.0, 2.0, 3.0), Point::new(7.0, 2.0, 3.0)]);
+
+        let actual = Mesh::sweep(&get_square_profile(), &path);
+
+        assert_eq!(actual.get_number_of_vertices(), 12);
+        assert_eq!(actual.get_number_of_faces(), 2 * 8 + 2 * 2);
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sweep_clockwise_profile_bent_path() {
+        let mut profile = get_square_profile();
+        profile.vertices.reverse();
+        let path = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 4.0), Point::new(4.0, 0.0, 8.0)]);
+
+        let actual = Mesh::sweep(&profile, &path);
+
+        assert!(actual.is_watertight());
+        assert!(actual.get_volume().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_get_parallel_transported() {
+        let actual = get_parallel_transported(&Vector::new(1.0, 0.0, 0.0), &Vector::new(0.0, 0.0, 1.0), &Vector::new(1.0, 0.0, 0.0));
+
+        assert!(actual.eq_with_tolerance(&Vector::new(0.0, 0.0, -1.0), 1e-12));
+    }
+
+    fn get_square_section(size: f64, z: f64) -> Polygon {
+        Polygon::new(vec![
+            Point::new(0.0, 0.0, z),
+            Point::new(size, 0.0, z),
+            Point::new(size, size, z),
+            Point::new(0.0, size, z),
+        ])
+    }
+
+    #[test]
+    fn test_loft_matching() {
+        // Second section starts in another corner and goes the other way
+        let second = Polygon::new(vec![
+            Point::new(3.0, 1.0, 4.0),
+            Point::new(3.0, 3.0, 4.0),
+            Point::new(1.0, 3.0, 4.0),
+            Point::new(1.0, 1.0, 4.0),
+        ].into_iter().rev().collect());
+        let sections = vec![get_square_section(2.0, 0.0), second, get_square_section(2.0, 8.0)];
+
+        let actual = Mesh::loft(&sections, false).unwrap();
+
+        assert_eq!(actual.get_number_of_vertices(), 12);
+        assert_eq!(actual.get_number_of_faces(), 2 * 8);
+        // Matched vertices are connected, so side faces are not twisted
+        assert_eq!(actual.to_points()[4], Point::new(1.0, 1.0, 4.0));
+        assert_eq!(actual.to_points()[5], Point::new(3.0, 1.0, 4.0));
+        let points = actual.to_points();
+        assert!(EdgeAdjacency::from_mesh(&actual).get_edges().iter().all(|edge| points[edge.start].get_distance_to_point(&points[edge.end]) < 4.5));
+        assert_eq!(actual.get_boundary_loops().len(), 2);
+    }
+
+    #[test]
+    fn test_loft_closed() {
+        let sections = vec![get_square_section(2.0, 0.0), get_square_section(2.0, 3.0)];
+
+        let actual = Mesh::loft(&sections, true).unwrap();
+
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_loft_different_numbers_of_vertices() {
+        let circle = Polygon::new((0..12).map(|i| {
+            let angle = 2.0 * PI * i as f64 / 12.0;
+            Point::new(1.0 + angle.cos(), 1.0 + angle.sin(), 5.0)
+        }).collect());
+        let sections = vec![get_square_section(2.0, 0.0), circle];
+
+        let actual = Mesh::loft(&sections, false).unwrap();
+
+        assert_eq!(actual.get_number_of_faces(), 4 + 12);
+        assert_eq!(actual.get_boundary_loops().len(), 2);
+        assert!(actual.get_non_manifold_vertices().is_empty());
+    }
+
+    #[test]
+    fn test_loft_errors() {
+        assert_eq!(Mesh::loft(&[get_square_section(1.0, 0.0)], false), Err(String::from("There should be at least 2 sections")));
+        let triangle = Polygon::new(vec![Point::new(0.0, 0.0, 1.0), Point::new(1.0, 0.0, 1.0)]);
+        assert_eq!(Mesh::loft(&[get_square_section(1.0, 0.0), triangle], false), Err(String::from("Each section should have at least 3 vertices")));
+    }
+
+    #[test]
+    fn test_pipe_straight() {
+        let path = Polyline::new(vec![Point::new(1.0, 2.0, 3.0), Point::new(1.0, 2.0, 6.0)]);
+
+        let actual = Mesh::pipe(&path, 2.0, 4, false);
+
+        assert_eq!(actual.get_number_of_vertices(), 8);
+        assert_eq!(actual.get_number_of_faces(), 8);
+        assert_eq!(actual.get_boundary_loops().len(), 2);
+        assert!(actual.to_points().iter().all(|point| (point.get_distance_to_point(&Point::new(1.0, 2.0, point.z)) - 2.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_pipe_mitered_corners() {
+        let path = Polyline::new(vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(0.0, 0.0, 4.0),
+            Point::new(3.0, 0.0, 4.0),
+            Point::new(3.0, 5.0, 4.0),
+        ]);
+        let segments = 12;
+
+        let actual = Mesh::pipe(&path, 0.5, segments, true);
+
+        assert_eq!(actual.get_number_of_vertices(), 4 * segments + 2);
+        assert!(actual.is_watertight());
+        // Mitered straight parts take the volume of the cross-section along the whole path
+        let cross_section_area = segments as f64 / 2.0 * 0.5 * 0.5 * (2.0 * PI / segments as f64).sin();
+        assert!((actual.get_volume().unwrap() - cross_section_area * 12.0).abs() < 1e-9);
+        // Walls of the straight part keep the radius
+        let points = actual.to_points();
+        assert!(points[segments..2 * segments].iter().all(|point| point.z <= 4.5 + 1e-12));
+        assert!(points[segments..2 * segments].iter().any(|point| (point.z - 4.5).abs() < 1e-12));
+    }
+}
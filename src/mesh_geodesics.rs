@@ -0,0 +1,247 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::triangle::Triangle;
+
+/// The number of additional points on each edge, which the shortest path can go through.
+const NUMBER_OF_STEINER_POINTS_PER_EDGE: usize = 3;
+
+/// Candidate node of the shortest path with its distance from the start.
+struct Visit {
+    distance: f64,
+    node: usize,
+}
+
+impl PartialEq for Visit {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+impl Eq for Visit {}
+
+impl PartialOrd for Visit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Visit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, so the BinaryHeap pops the closest node first.
+        other.distance.total_cmp(&self.distance)
+    }
+}
+
+impl Mesh {
+    /// Gets the shortest path between 2 [Point]s, constrained to the surface of the [Mesh].
+    ///
+    /// `start` and `end` are first moved to the closest points on the Mesh, so they can lie
+    /// inside faces. The path is the polyline going through faces' interiors: every segment lies
+    /// inside a single face, and segments meet at vertices or at points evenly distributed
+    /// along edges (3 per edge). Because of that the path is an approximation of the true
+    /// geodesic, which gets better with the denser Mesh.
+    ///
+    /// The result starts at the projected `start` and ends at the projected `end`. `None` is
+    /// returned if the Mesh has no faces, or the end cannot be reached from the start (e.g. they
+    /// are on disconnected parts).
+    ///
+    /// The input [Mesh] should be welded, otherwise path cannot cross edges between faces
+    /// with different vertices.
+    ///
+    /// # Example
+    ///
+    /// Here is an example of the square, with the path crossing the diagonal edge.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = input.get_shortest_path(&Point::new(9.0, 1.0, 1.0), &Point::new(1.0, 9.0, -1.0)).unwrap();
+    ///
+    /// assert_eq!(actual.len(), 3);
+    /// assert!(actual[0].get_distance_to_point(&Point::new(9.0, 1.0, 0.0)) < 1e-12);
+    /// assert_eq!(actual[1], Point::new(5.0, 5.0, 0.0));
+    /// assert!(actual[2].get_distance_to_point(&Point::new(1.0, 9.0, 0.0)) < 1e-12);
+    /// ```
+    pub fn get_shortest_path(&self, start: &Point, end: &Point) -> Option<Vec<Point>> {
+        let triangles = self.to_triangles();
+        let (start_face_id, start_point) = Mesh::get_closest_face_and_point(&triangles, start)?;
+        let (end_face_id, end_point) = Mesh::get_closest_face_and_point(&triangles, end)?;
+
+        let mut node_points = self.to_points(); // Nodes: vertices, Steiner points on edges, start and end
+        let mut edge_first_nodes: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut face_nodes: Vec<Vec<usize>> = Vec::with_capacity(triangles.len());
+        for face in self.indices.chunks_exact(3) {
+            let mut nodes = face.to_vec();
+            for (first, second) in [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])] {
+                let key = (first.min(second), first.max(second));
+                let first_node = *edge_first_nodes.entry(key).or_insert_with(|| {
+                    let first_node = node_points.len();
+                    let a = node_points[key.0];
+                    let b = node_points[key.1];
+                    for i in 1..=NUMBER_OF_STEINER_POINTS_PER_EDGE {
+                        let t = i as f64 / (NUMBER_OF_STEINER_POINTS_PER_EDGE + 1) as f64;
+                        node_points.push(Point::new(a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t, a.z + (b.z - a.z) * t));
+                    }
+                    first_node
+                });
+                nodes.extend(first_node..first_node + NUMBER_OF_STEINER_POINTS_PER_EDGE);
+            }
+            face_nodes.push(nodes);
+        }
+        let start_node = node_points.len();
+        node_points.push(start_point);
+        face_nodes[start_face_id].push(start_node);
+        let end_node = node_points.len();
+        node_points.push(end_point);
+        face_nodes[end_face_id].push(end_node);
+
+        let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); node_points.len()]; // Nodes of the same face are connected with straight segments
+        for nodes in &face_nodes {
+            for (i, first) in nodes.iter().enumerate() {
+                for second in nodes.iter().skip(i + 1) {
+                    adjacency[*first].push(*second);
+                    adjacency[*second].push(*first);
+                }
+            }
+        }
+
+        let mut distances = vec![f64::INFINITY; node_points.len()];
+        let mut previous_nodes: Vec<Option<usize>> = vec![None; node_points.len()];
+        let mut heap = BinaryHeap::new();
+        distances[start_node] = 0.0;
+        heap.push(Visit { distance: 0.0, node: start_node });
+        while let Some(Visit { distance, node }) = heap.pop() {
+            if node == end_node {
+                break;
+            }
+            if distance > distances[node] {
+                continue;
+            }
+            for neighbour in &adjacency[node] {
+                let new_distance = distance + node_points[node].get_distance_to_point(&node_points[*neighbour]);
+                if new_distance < distances[*neighbour] {
+                    distances[*neighbour] = new_distance;
+                    previous_nodes[*neighbour] = Some(node);
+                    heap.push(Visit { distance: new_distance, node: *neighbour });
+                }
+            }
+        }
+
+        if !distances[end_node].is_finite() {
+            return None;
+        }
+
+        let mut path = vec![node_points[end_node]];
+        let mut current = end_node;
+        while let Some(previous) = previous_nodes[current] {
+            if node_points[previous] != *path.last().unwrap() { // Skipping zero length segments, e.g. when the start is at the vertex
+                path.push(node_points[previous]);
+            }
+            current = previous;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Gets the id of the face closest to the `point` and the closest point on it. `None` is
+    /// returned if there are no faces.
+    fn get_closest_face_and_point(triangles: &[Triangle], point: &Point) -> Option<(usize, Point)> {
+        let mut closest_face_id = None;
+        let mut closest_point = *point;
+        let mut closest_distance = f64::INFINITY;
+        for (face_id, triangle) in triangles.iter().enumerate() {
+            let current_point = triangle.get_closest_point(point);
+            let current_distance = current_point.get_distance_to_point(point);
+            if current_distance < closest_distance {
+                closest_face_id = Some(face_id);
+                closest_point = current_point;
+                closest_distance = current_distance;
+            }
+        }
+
+        closest_face_id.map(|face_id| (face_id, closest_point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_path_length(path: &[Point]) -> f64 {
+        path.windows(2).map(|segment| segment[0].get_distance_to_point(&segment[1])).sum()
+    }
+
+    #[test]
+    fn test_get_shortest_path_over_fold() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0, // floor
+                 10.0, 0.0, 10.0, 10.0, 10.0, 10.0, // wall
+                 ],
+            vec![0, 1, 2, 0, 2, 3,
+                 1, 4, 5, 1, 5, 2,
+                 ]
+        );
+
+        let actual = input.get_shortest_path(&Point::new(5.0, 5.0, 0.0), &Point::new(10.0, 5.0, 5.0)).unwrap();
+
+        assert!((get_path_length(&actual) - 10.0).abs() < 1e-9);
+        assert!(actual[0].get_distance_to_point(&Point::new(5.0, 5.0, 0.0)) < 1e-12);
+        assert!(actual.contains(&Point::new(10.0, 5.0, 0.0)));
+        assert!(actual[actual.len() - 1].get_distance_to_point(&Point::new(10.0, 5.0, 5.0)) < 1e-12);
+    }
+
+    #[test]
+    fn test_get_shortest_path_same_face() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 10.0, 0.0],
+            vec![0, 1, 2]
+        );
+
+        let actual = input.get_shortest_path(&Point::new(1.0, 1.0, 2.0), &Point::new(2.0, 3.0, 2.0)).unwrap();
+
+        assert_eq!(actual.len(), 2);
+        assert!(actual[0].get_distance_to_point(&Point::new(1.0, 1.0, 0.0)) < 1e-12);
+        assert!(actual[1].get_distance_to_point(&Point::new(2.0, 3.0, 0.0)) < 1e-12);
+    }
+
+    #[test]
+    fn test_get_shortest_path_from_vertex() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 10.0, 0.0],
+            vec![0, 1, 2]
+        );
+
+        let actual = input.get_shortest_path(&Point::new(0.0, 0.0, 0.0), &Point::new(10.0, 0.0, 0.0)).unwrap();
+
+        assert_eq!(actual[0], Point::new(0.0, 0.0, 0.0));
+        assert_eq!(actual[actual.len() - 1], Point::new(10.0, 0.0, 0.0));
+        assert!((get_path_length(&actual) - 10.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_shortest_path_disconnected() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+                 5.0, 0.0, 0.0, 6.0, 0.0, 0.0, 5.0, 1.0, 0.0],
+            vec![0, 1, 2, 3, 4, 5]
+        );
+
+        assert_eq!(input.get_shortest_path(&Point::new(0.1, 0.1, 0.0), &Point::new(5.1, 0.1, 0.0)), None);
+    }
+
+    #[test]
+    fn test_get_shortest_path_empty() {
+        let input = Mesh::new(vec![], vec![]);
+
+        assert_eq!(input.get_shortest_path(&Point::new(0.0, 0.0, 0.0), &Point::new(1.0, 0.0, 0.0)), None);
+    }
+}
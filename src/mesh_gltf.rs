@@ -0,0 +1,1112 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use serde_json::json;
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::mesh::Mesh;
+use crate::mesh_indices::get_indices_u32;
+use crate::point::Point;
+use crate::units::Units;
+use crate::uv_channel::UvChannel;
+use crate::vector::Vector;
+
+/// Column-major 4x4 matrix, same layout as glTF `matrix` property.
+type GltfMatrix = [f64; 16];
+
+const GLTF_IDENTITY: GltfMatrix = [
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 0.0, 0.0,
+    0.0, 0.0, 1.0, 0.0,
+    0.0, 0.0, 0.0, 1.0,
+];
+
+/// Relative tolerance of the check if axes of a node transform are perpendicular.
+const GLTF_ORTHOGONALITY_TOLERANCE: f64 = 1e-6;
+
+#[derive(Deserialize)]
+struct GltfDocument {
+    #[serde(default)]
+    scene: Option<usize>,
+    #[serde(default)]
+    scenes: Vec<GltfScene>,
+    #[serde(default)]
+    nodes: Vec<GltfNode>,
+    #[serde(default)]
+    meshes: Vec<GltfMesh>,
+    #[serde(default)]
+    accessors: Vec<GltfAccessor>,
+    #[serde(default, rename = "bufferViews")]
+    buffer_views: Vec<GltfBufferView>,
+    #[serde(default)]
+    buffers: Vec<GltfBuffer>,
+}
+
+#[derive(Deserialize)]
+struct GltfScene {
+    #[serde(default)]
+    nodes: Vec<usize>,
+}
+
+#[derive(Deserialize)]
+struct GltfNode {
+    #[serde(default)]
+    children: Vec<usize>,
+    #[serde(default)]
+    mesh: Option<usize>,
+    #[serde(default)]
+    matrix: Option<[f64; 16]>,
+    #[serde(default)]
+    translation: Option<[f64; 3]>,
+    #[serde(default)]
+    rotation: Option<[f64; 4]>,
+    #[serde(default)]
+    scale: Option<[f64; 3]>,
+}
+
+#[derive(Deserialize)]
+struct GltfMesh {
+    #[serde(default)]
+    primitives: Vec<GltfPrimitive>,
+    #[serde(default)]
+    extras: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct GltfPrimitive {
+    attributes: HashMap<String, usize>,
+    #[serde(default)]
+    indices: Option<usize>,
+    #[serde(default)]
+    mode: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct GltfAccessor {
+    #[serde(default, rename = "bufferView")]
+    buffer_view: Option<usize>,
+    #[serde(default, rename = "byteOffset")]
+    byte_offset: usize,
+    #[serde(rename = "componentType")]
+    component_type: u32,
+    count: usize,
+    #[serde(rename = "type")]
+    accessor_type: String,
+    #[serde(default)]
+    normalized: bool,
+}
+
+#[derive(Deserialize)]
+struct GltfBufferView {
+    buffer: usize,
+    #[serde(default, rename = "byteOffset")]
+    byte_offset: usize,
+    #[serde(rename = "byteLength")]
+    byte_length: usize,
+    #[serde(default, rename = "byteStride")]
+    byte_stride: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct GltfBuffer {
+    #[serde(default)]
+    uri: Option<String>,
+}
+
+impl Mesh {
+    /// The [Units] of the glTF geometry.
+    ///
+    /// The glTF specification defines all linear distances in meters, so all the [Mesh]es
+    /// imported from glTF (`.gltf` and `.glb`) are in meters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::units::Units;
+    ///
+    /// let mut actual = Mesh::from_gltf("models/input/Pyramids.gltf").unwrap();
+    /// actual[0].convert_units(Mesh::GLTF_UNITS, Units::Millimeters);
+    ///
+    /// assert_eq!(Mesh::GLTF_UNITS, Units::Meters);
+    /// assert!((actual[0].get_bounding_box().max_x - 18000.0).abs() < 0.001);
+    /// ```
+    pub const GLTF_UNITS: Units = Units::Meters;
+
+    /// Imports all the [Mesh]es from the glTF (`.gltf`) file.
+    ///
+    /// Every primitive of every mesh instanced by the nodes of the default scene is flattened
+    /// into a separate [Mesh]. Node transforms (including the whole parent hierarchy) are applied
+    /// to the coordinates, so all the returned Meshes are located in the global coordinate system.
+    ///
+    /// Buffers can be embedded as base64 data uris or stored in external files, which are
+    /// resolved relative to the `.gltf` file location.
+    ///
+    /// Imported coordinates are in meters, see [Mesh::GLTF_UNITS].
+    ///
+    /// The `extras` of the glTF mesh are restored on each of its primitives: `mesh_id` becomes
+    /// the `id`, entries of the `metadata` object become the `metadata`.
+    ///
+    /// Only triangle-based primitives (triangles, triangle strips and triangle fans) are imported,
+    /// other primitives (points, lines) are skipped.
+    ///
+    /// The order of the output follows the order of nodes in the scene (depth-first), then the
+    /// order of primitives.
+    ///
+    /// # Example
+    ///
+    /// Here is an example of importing the file with the same pyramid instanced twice: once
+    /// translated by 10 along X, and once inside a hierarchy of nodes.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    ///
+    /// let actual = Mesh::from_gltf("models/input/Pyramids.gltf").unwrap();
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert_eq!(actual[0].get_number_of_faces(), 6);
+    /// assert!(actual[0].get_bounding_box().eq_with_tolerance(&BoundingBox::new(8.0, 18.0, 1.0, 11.0, 0.0, 4.0), 0.0001));
+    /// ```
+    pub fn from_gltf(path: &str) -> Result<Vec<Mesh>, String> {
+        let placed = Self::read_gltf_file(path)?;
+
+        Ok(placed.into_iter().map(|(mesh, matrix)| Self::get_with_gltf_matrix_applied(&mesh, &matrix)).collect())
+    }
+
+    /// Imports all the [Mesh]es from the binary glTF (`.glb`) content.
+    ///
+    /// It works the same way as [Mesh::from_gltf], but reads the data from the GLB container,
+    /// where the geometry is stored in the binary chunk.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    ///
+    /// let bytes = fs::read("models/input/Pyramids.glb").unwrap();
+    /// let actual = Mesh::from_glb(&bytes).unwrap();
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert!(actual[0].get_bounding_box().eq_with_tolerance(&BoundingBox::new(8.0, 18.0, 1.0, 11.0, 0.0, 4.0), 0.0001));
+    /// ```
+    pub fn from_glb(bytes: &[u8]) -> Result<Vec<Mesh>, String> {
+        let placed = Self::read_glb_bytes(bytes)?;
+
+        Ok(placed.into_iter().map(|(mesh, matrix)| Self::get_with_gltf_matrix_applied(&mesh, &matrix)).collect())
+    }
+
+    /// Imports all the [Mesh]es from the glTF (`.gltf`) file, but instead of applying node
+    /// transforms to the coordinates, it returns them as [LocalCoordinateSystem]s.
+    ///
+    /// Each returned [Mesh] stays in its local coordinates, and the paired
+    /// [LocalCoordinateSystem] tells where it is located (use
+    /// [Mesh::get_in_local_coordinate_system] to place it). This way instanced meshes can be kept
+    /// deduplicated.
+    ///
+    /// [LocalCoordinateSystem] cannot describe scaling, so if a node is scaled, the scale is
+    /// applied to the local coordinates of the [Mesh] instead. If the transform also contains
+    /// shear (e.g. a non-uniformly scaled parent of a rotated node), the whole transform is applied
+    /// to the coordinates, same as in [Mesh::from_gltf], and the [LocalCoordinateSystem] is global.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let actual = Mesh::from_gltf_with_local_coordinate_systems("models/input/Pyramids.gltf").unwrap();
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert!(actual[0].1.origin.eq_with_tolerance(&Point::new(10.0, 0.0, 0.0), 0.0001));
+    /// assert!(actual[1].1.origin.eq_with_tolerance(&Point::new(0.0, 0.0, 5.0), 0.0001));
+    /// assert!(actual[1].1.x.eq_with_tolerance(&Vector::new(0.0, 1.0, 0.0), 0.0001));
+    /// ```
+    pub fn from_gltf_with_local_coordinate_systems(path: &str) -> Result<Vec<(Mesh, LocalCoordinateSystem)>, String> {
+        let placed = Self::read_gltf_file(path)?;
+
+        Ok(placed.into_iter().map(|(mesh, matrix)| Self::get_with_gltf_matrix_as_local_coordinate_system(&mesh, &matrix)).collect())
+    }
+
+    /// Imports all the [Mesh]es from the binary glTF (`.glb`) content, returning node transforms
+    /// as [LocalCoordinateSystem]s.
+    ///
+    /// It works the same way as [Mesh::from_gltf_with_local_coordinate_systems], but reads the
+    /// data from the GLB container.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let bytes = fs::read("models/input/Pyramids.glb").unwrap();
+    /// let actual = Mesh::from_glb_with_local_coordinate_systems(&bytes).unwrap();
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert!(actual[0].1.origin.eq_with_tolerance(&Point::new(10.0, 0.0, 0.0), 0.0001));
+    /// ```
+    pub fn from_glb_with_local_coordinate_systems(bytes: &[u8]) -> Result<Vec<(Mesh, LocalCoordinateSystem)>, String> {
+        let placed = Self::read_glb_bytes(bytes)?;
+
+        Ok(placed.into_iter().map(|(mesh, matrix)| Self::get_with_gltf_matrix_as_local_coordinate_system(&mesh, &matrix)).collect())
+    }
+
+    /// Creates the content of the glTF (`.gltf`) file with the [Mesh], with the binary buffer
+    /// embedded as a base64 data uri.
+    ///
+    /// `vertex_normals` are written as `NORMAL`, `uvs` as `TEXCOORD_0` and `vertex_colors` as
+    /// `COLOR_0`. glTF supports per
+    /// vertex attributes only, so if the [Mesh] has per corner `uvs`, each face corner is written
+    /// as a separate vertex. The `id` is written to the mesh `extras` as `mesh_id` and the
+    /// `metadata` as a nested `metadata` object, so they're restored by [Mesh::from_gltf].
+    ///
+    /// If the [Mesh] has `face_groups`, faces of each group are written as a separate primitive
+    /// (sharing the same vertices), with the group id in the primitive `extras` as `face_group`.
+    /// Groups without faces are skipped.
+    ///
+    /// glTF stores coordinates in meters as 32-bit floats, see [Mesh::GLTF_UNITS].
+    ///
+    /// It returns an error if the [Mesh] has no faces, as glTF doesn't allow empty accessors, or
+    /// if it has more vertices than 32-bit indices can address.
+    /// It panics if the number of `face_groups` is different than the number of faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::uv_channel::UvChannel;
+    ///
+    /// let mut input = Mesh::new_with_id(Some(3), vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+    ///
+    /// let actual = input.to_gltf_string().unwrap();
+    ///
+    /// assert!(actual.contains("\"TEXCOORD_0\":1"));
+    /// assert!(actual.contains("\"extras\":{\"mesh_id\":3}"));
+    /// ```
+    pub fn to_gltf_string(&self) -> Result<String, String> {
+        if self.get_number_of_faces() == 0 {
+            return Err("Cannot export the Mesh without faces to glTF".to_string());
+        }
+
+        let is_per_corner = matches!(self.uvs, Some(UvChannel::PerCorner(_)));
+        let vertex_ids: Vec<usize> = if is_per_corner { self.indices.clone() } else { (0..self.get_number_of_vertices()).collect() };
+        let indices: Vec<u32> = if is_per_corner { get_indices_u32(&(0..self.indices.len()).collect::<Vec<usize>>())? } else { self.get_indices_u32()? };
+
+        let mut buffer: Vec<u8> = Vec::new();
+        let mut buffer_views: Vec<serde_json::Value> = Vec::new();
+        let mut accessors: Vec<serde_json::Value> = Vec::new();
+        let mut attributes = serde_json::Map::new();
+
+        let mut minimum = [f64::MAX; 3];
+        let mut maximum = [f64::MIN; 3];
+        let mut positions: Vec<f64> = Vec::with_capacity(vertex_ids.len() * 3);
+        for vertex_id in &vertex_ids {
+            for i in 0..3 {
+                let value = self.coordinates[vertex_id * 3 + i] as f32 as f64;
+                minimum[i] = minimum[i].min(value);
+                maximum[i] = maximum[i].max(value);
+                positions.push(value);
+            }
+        }
+        attributes.insert("POSITION".to_string(), json!(accessors.len()));
+        push_gltf_floats(&mut buffer, &mut buffer_views, &mut accessors, &positions, "VEC3");
+        accessors.last_mut().unwrap()["min"] = json!(minimum);
+        accessors.last_mut().unwrap()["max"] = json!(maximum);
+
+        if let Some(normals) = &self.vertex_normals {
+            let normals: Vec<f64> = vertex_ids.iter().flat_map(|vertex_id| normals[vertex_id * 3..vertex_id * 3 + 3].to_vec()).collect();
+            attributes.insert("NORMAL".to_string(), json!(accessors.len()));
+            push_gltf_floats(&mut buffer, &mut buffer_views, &mut accessors, &normals, "VEC3");
+        }
+
+        let uvs = match &self.uvs {
+            Some(UvChannel::PerVertex(uvs)) => Some(uvs.clone()),
+            Some(UvChannel::PerCorner(uvs)) => Some(uvs.clone()),
+            None => None,
+        };
+        if let Some(uvs) = uvs {
+            attributes.insert("TEXCOORD_0".to_string(), json!(accessors.len()));
+            push_gltf_floats(&mut buffer, &mut buffer_views, &mut accessors, &uvs, "VEC2");
+        }
+
+        if let Some(colors) = self.get_vertex_colors_by_vertex_ids(vertex_ids.iter().copied()) {
+            let colors: Vec<f64> = colors.iter().map(|value| value.clamp(&0, &255)).map(|value| *value as f64 / 255.0).collect();
+            attributes.insert("COLOR_0".to_string(), json!(accessors.len()));
+            push_gltf_floats(&mut buffer, &mut buffer_views, &mut accessors, &colors, "VEC4");
+        }
+
+        let face_ids_per_group: Vec<(Option<usize>, Vec<usize>)> = match &self.face_groups {
+            Some(_) => self.get_face_ids_per_face_group().into_iter().enumerate()
+                .filter(|(_, face_ids)| !face_ids.is_empty())
+                .map(|(group_id, face_ids)| (Some(group_id), face_ids))
+                .collect(),
+            None => vec![(None, (0..self.get_number_of_faces()).collect())],
+        };
+
+        let mut primitives: Vec<serde_json::Value> = Vec::with_capacity(face_ids_per_group.len());
+        for (group_id, face_ids) in face_ids_per_group {
+            let indices_offset = buffer.len();
+            for face_id in &face_ids {
+                for index in &indices[face_id * 3..face_id * 3 + 3] {
+                    buffer.extend_from_slice(&index.to_le_bytes());
+                }
+            }
+            buffer_views.push(json!({"buffer": 0, "byteOffset": indices_offset, "byteLength": face_ids.len() * 12, "target": 34963}));
+            let mut primitive = json!({"attributes": attributes, "indices": accessors.len(), "mode": 4});
+            accessors.push(json!({"bufferView": buffer_views.len() - 1, "componentType": 5125, "count": face_ids.len() * 3, "type": "SCALAR"}));
+            if let Some(group_id) = group_id {
+                primitive["extras"] = json!({"face_group": group_id});
+            }
+            primitives.push(primitive);
+        }
+
+        let mut extras = serde_json::Map::new();
+        if let Some(id) = self.id {
+            extras.insert("mesh_id".to_string(), json!(id));
+        }
+        if !self.metadata.is_empty() {
+            let mut metadata: Vec<(&String, &String)> = self.metadata.iter().collect();
+            metadata.sort();
+            let metadata: serde_json::Map<String, serde_json::Value> = metadata.into_iter().map(|(key, value)| (key.clone(), json!(value))).collect();
+            extras.insert("metadata".to_string(), json!(metadata));
+        }
+
+        let mut mesh = json!({"primitives": primitives});
+        if !extras.is_empty() {
+            mesh["extras"] = json!(extras);
+        }
+
+        let document = json!({
+            "asset": {"version": "2.0", "generator": "meshmeshmesh"},
+            "scene": 0,
+            "scenes": [{"nodes": [0]}],
+            "nodes": [{"mesh": 0}],
+            "meshes": [mesh],
+            "accessors": accessors,
+            "bufferViews": buffer_views,
+            "buffers": [{"byteLength": buffer.len(), "uri": format!("data:application/octet-stream;base64,{}", encode_base64(&buffer))}],
+        });
+
+        Ok(document.to_string())
+    }
+
+    /// Writes the [Mesh] to the glTF (`.gltf`) file, see [Mesh::to_gltf_string].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// input.to_gltf("result.gltf").unwrap();
+    /// ```
+    pub fn to_gltf(&self, path: &str) -> Result<(), String> {
+        let content = self.to_gltf_string()?;
+        fs::write(path, content).map_err(|e| format!("Couldn't write the glTF file: {}", e))
+    }
+
+    fn read_gltf_file(path: &str) -> Result<Vec<(Mesh, GltfMatrix)>, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("Couldn't read the glTF file: {}", e))?;
+        let document: GltfDocument = serde_json::from_str(&content).map_err(|e| format!("Couldn't parse the glTF file: {}", e))?;
+        let base_directory = Path::new(path).parent();
+
+        let mut buffers = Vec::with_capacity(document.buffers.len());
+        for buffer in &document.buffers {
+            let uri = buffer.uri.as_ref().ok_or("The glTF buffer without uri is allowed only in the .glb files")?;
+            buffers.push(Self::read_gltf_buffer_uri(uri, base_directory)?);
+        }
+
+        Self::read_gltf_document(&document, &buffers)
+    }
+
+    fn read_glb_bytes(bytes: &[u8]) -> Result<Vec<(Mesh, GltfMatrix)>, String> {
+        if bytes.len() < 20 || &bytes[0..4] != b"glTF" {
+            return Err("The content is not a valid .glb file".to_string());
+        }
+
+        let mut json_chunk: Option<&[u8]> = None;
+        let mut binary_chunk: Option<&[u8]> = None;
+        let mut offset = 12;
+        while offset + 8 <= bytes.len() {
+            let chunk_length = u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]]) as usize;
+            let chunk_type = &bytes[offset + 4..offset + 8];
+            let chunk_start = offset + 8;
+            let chunk_end = chunk_start + chunk_length;
+            if chunk_end > bytes.len() {
+                return Err("The .glb chunk exceeds the file length".to_string());
+            }
+            if chunk_type == b"JSON" {
+                json_chunk = Some(&bytes[chunk_start..chunk_end]);
+            } else if chunk_type == b"BIN\0" {
+                binary_chunk = Some(&bytes[chunk_start..chunk_end]);
+            }
+            offset = chunk_end;
+        }
+
+        let json = json_chunk.ok_or("The .glb file has no JSON chunk")?;
+        let json_text = std::str::from_utf8(json).map_err(|e| format!("The .glb JSON chunk is not valid UTF-8: {}", e))?;
+        let document: GltfDocument = serde_json::from_str(json_text).map_err(|e| format!("Couldn't parse the .glb JSON chunk: {}", e))?;
+
+        let mut buffers = Vec::with_capacity(document.buffers.len());
+        for buffer in &document.buffers {
+            match &buffer.uri {
+                Some(uri) => buffers.push(Self::read_gltf_buffer_uri(uri, None)?),
+                None => buffers.push(binary_chunk.ok_or("The .glb file has no BIN chunk")?.to_vec()),
+            }
+        }
+
+        Self::read_gltf_document(&document, &buffers)
+    }
+
+    fn read_gltf_buffer_uri(uri: &str, base_directory: Option<&Path>) -> Result<Vec<u8>, String> {
+        if let Some(stripped) = uri.strip_prefix("data:") {
+            let separator = stripped.find(";base64,").ok_or("Only base64 data uris are supported for glTF buffers")?;
+            return Self::decode_base64(&stripped[separator + 8..]);
+        }
+
+        let path = match base_directory {
+            Some(directory) => directory.join(uri),
+            None => Path::new(uri).to_path_buf(),
+        };
+
+        fs::read(&path).map_err(|e| format!("Couldn't read the glTF buffer {}: {}", uri, e))
+    }
+
+    fn decode_base64(input: &str) -> Result<Vec<u8>, String> {
+        let mut output = Vec::with_capacity(input.len() * 3 / 4);
+        let mut accumulator: u32 = 0;
+        let mut number_of_bits = 0;
+        for character in input.bytes() {
+            let value = match character {
+                b'A'..=b'Z' => character - b'A',
+                b'a'..=b'z' => character - b'a' + 26,
+                b'0'..=b'9' => character - b'0' + 52,
+                b'+' | b'-' => 62,
+                b'/' | b'_' => 63,
+                b'=' => break,
+                b'\r' | b'\n' | b' ' => continue,
+                _ => return Err(format!("Invalid base64 character: {}", character as char)),
+            };
+            accumulator = (accumulator << 6) | value as u32;
+            number_of_bits += 6;
+            if number_of_bits >= 8 {
+                number_of_bits -= 8;
+                output.push((accumulator >> number_of_bits) as u8);
+                accumulator &= (1 << number_of_bits) - 1;
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn read_gltf_document(document: &GltfDocument, buffers: &[Vec<u8>]) -> Result<Vec<(Mesh, GltfMatrix)>, String> {
+        let root_nodes: Vec<usize> = if document.scenes.is_empty() {
+            let mut is_child = vec![false; document.nodes.len()]; // Without scenes, all the nodes which are not children are roots
+            for node in &document.nodes {
+                for child in &node.children {
+                    if *child < is_child.len() {
+                        is_child[*child] = true;
+                    }
+                }
+            }
+            (0..document.nodes.len()).filter(|i| !is_child[*i]).collect()
+        } else {
+            let scene_id = document.scene.unwrap_or(0);
+            document.scenes.get(scene_id).ok_or("The glTF default scene doesn't exist")?.nodes.clone()
+        };
+
+        let mut placed: Vec<(Mesh, GltfMatrix)> = Vec::new();
+        let mut stack: Vec<(usize, GltfMatrix, usize)> = root_nodes.iter().rev().map(|node_id| (*node_id, GLTF_IDENTITY, 0)).collect();
+        while let Some((node_id, parent_matrix, depth)) = stack.pop() {
+            if depth > document.nodes.len() {
+                return Err("The glTF node hierarchy contains a cycle".to_string());
+            }
+            let node = document.nodes.get(node_id).ok_or("The glTF node doesn't exist")?;
+            let world_matrix = Self::multiply_gltf_matrices(&parent_matrix, &Self::get_gltf_node_matrix(node));
+
+            if let Some(mesh_id) = node.mesh {
+                let gltf_mesh = document.meshes.get(mesh_id).ok_or("The glTF mesh doesn't exist")?;
+                for primitive in &gltf_mesh.primitives {
+                    if let Some(mut mesh) = Self::read_gltf_primitive(document, buffers, primitive)? {
+                        if let Some(extras) = &gltf_mesh.extras {
+                            mesh.set_gltf_extras(extras);
+                        }
+                        placed.push((mesh, world_matrix));
+                    }
+                }
+            }
+
+            for child in node.children.iter().rev() {
+                stack.push((*child, world_matrix, depth + 1));
+            }
+        }
+
+        Ok(placed)
+    }
+
+    fn read_gltf_primitive(document: &GltfDocument, buffers: &[Vec<u8>], primitive: &GltfPrimitive) -> Result<Option<Mesh>, String> {
+        let mode = primitive.mode.unwrap_or(4);
+        if mode < 4 { // Points and lines are not Meshes
+            return Ok(None);
+        }
+
+        let position_accessor_id = *primitive.attributes.get("POSITION").ok_or("The glTF primitive has no POSITION attribute")?;
+        let coordinates = Self::read_gltf_accessor(document, buffers, position_accessor_id)?;
+        let number_of_vertices = coordinates.len() / 3;
+
+        let raw_indices: Vec<usize> = match primitive.indices {
+            Some(indices_accessor_id) => Self::read_gltf_accessor(document, buffers, indices_accessor_id)?.iter().map(|value| *value as usize).collect(),
+            None => (0..number_of_vertices).collect(),
+        };
+
+        let mut indices: Vec<usize> = Vec::with_capacity(raw_indices.len());
+        match mode {
+            4 => { // Triangles
+                indices.extend_from_slice(&raw_indices[0..raw_indices.len() - raw_indices.len() % 3]);
+            },
+            5 => { // Triangle strip
+                for i in 2..raw_indices.len() {
+                    if i % 2 == 0 {
+                        indices.extend_from_slice(&[raw_indices[i - 2], raw_indices[i - 1], raw_indices[i]]);
+                    } else {
+                        indices.extend_from_slice(&[raw_indices[i - 1], raw_indices[i - 2], raw_indices[i]]);
+                    }
+                }
+            },
+            6 => { // Triangle fan
+                for i in 2..raw_indices.len() {
+                    indices.extend_from_slice(&[raw_indices[0], raw_indices[i - 1], raw_indices[i]]);
+                }
+            },
+            _ => return Err(format!("Unknown glTF primitive mode: {}", mode)),
+        }
+
+        if indices.iter().any(|index| *index >= number_of_vertices) {
+            return Err("The glTF primitive has indices out of range".to_string());
+        }
+
+        let mut mesh = Mesh::new(coordinates, indices);
+        if let Some(uvs_accessor_id) = primitive.attributes.get("TEXCOORD_0") {
+            let uvs = Self::read_gltf_accessor(document, buffers, *uvs_accessor_id)?;
+            if uvs.len() != number_of_vertices * 2 {
+                return Err("The glTF primitive has a wrong number of TEXCOORD_0 values".to_string());
+            }
+            mesh.uvs = Some(UvChannel::PerVertex(uvs));
+        }
+        if let Some(colors_accessor_id) = primitive.attributes.get("COLOR_0") {
+            mesh.vertex_colors = Some(Self::read_gltf_colors(document, buffers, *colors_accessor_id, number_of_vertices)?);
+        }
+
+        Ok(Some(mesh))
+    }
+
+    /// Reads the accessor values, `normalized` integers are mapped to 0-1 (or -1-1 if signed).
+    fn read_gltf_accessor(document: &GltfDocument, buffers: &[Vec<u8>], accessor_id: usize) -> Result<Vec<f64>, String> {
+        let accessor = document.accessors.get(accessor_id).ok_or("The glTF accessor doesn't exist")?;
+        let number_of_components = match accessor.accessor_type.as_str() {
+            "SCALAR" => 1,
+            "VEC2" => 2,
+            "VEC3" => 3,
+            "VEC4" => 4,
+            _ => return Err(format!("Unsupported glTF accessor type: {}", accessor.accessor_type)),
+        };
+        let component_size = match accessor.component_type {
+            5120 | 5121 => 1,
+            5122 | 5123 => 2,
+            5125 | 5126 => 4,
+            _ => return Err(format!("Unsupported glTF component type: {}", accessor.component_type)),
+        };
+
+        let buffer_view_id = accessor.buffer_view.ok_or("Sparse or empty glTF accessors are not supported")?;
+        let buffer_view = document.buffer_views.get(buffer_view_id).ok_or("The glTF buffer view doesn't exist")?;
+        let buffer = buffers.get(buffer_view.buffer).ok_or("The glTF buffer doesn't exist")?;
+        let stride = buffer_view.byte_stride.unwrap_or(number_of_components * component_size);
+        let start = buffer_view.byte_offset + accessor.byte_offset;
+        let view_end = buffer_view.byte_offset + buffer_view.byte_length;
+
+        // The count comes from the file, so it's checked against the buffer view before allocating
+        if accessor.count > 0 {
+            let end = (accessor.count - 1).checked_mul(stride)
+                .and_then(|last_offset| last_offset.checked_add(start + number_of_components * component_size));
+            match end {
+                Some(end) if end <= view_end && end <= buffer.len() => {},
+                _ => return Err("The glTF accessor exceeds its buffer view".to_string()),
+            }
+        }
+
+        let mut values = Vec::with_capacity(accessor.count * number_of_components);
+        for i in 0..accessor.count {
+            for j in 0..number_of_components {
+                let offset = start + i * stride + j * component_size;
+                let bytes = &buffer[offset..offset + component_size];
+                let value = match (accessor.component_type, accessor.normalized) {
+                    (5120, false) => bytes[0] as i8 as f64,
+                    (5120, true) => (bytes[0] as i8 as f64 / 127.0).max(-1.0),
+                    (5121, false) => bytes[0] as f64,
+                    (5121, true) => bytes[0] as f64 / 255.0,
+                    (5122, false) => i16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+                    (5122, true) => (i16::from_le_bytes([bytes[0], bytes[1]]) as f64 / 32767.0).max(-1.0),
+                    (5123, false) => u16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+                    (5123, true) => u16::from_le_bytes([bytes[0], bytes[1]]) as f64 / 65535.0,
+                    (5125, _) => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+                    _ => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+                };
+                values.push(value);
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Reads `COLOR_0` values (float or normalized integers, RGB or RGBA) as 0-255 RGBA.
+    fn read_gltf_colors(document: &GltfDocument, buffers: &[Vec<u8>], accessor_id: usize, number_of_vertices: usize) -> Result<Vec<i32>, String> {
+        let values = Self::read_gltf_accessor(document, buffers, accessor_id)?;
+        let accessor = &document.accessors[accessor_id];
+        let number_of_components = if accessor.accessor_type == "VEC3" { 3 } else { 4 };
+        if values.len() != number_of_vertices * number_of_components {
+            return Err("The glTF primitive has a wrong number of COLOR_0 values".to_string());
+        }
+        let scale = match accessor.component_type {
+            _ if accessor.normalized => 255.0,
+            5121 => 1.0,
+            5123 => 255.0 / 65535.0,
+            _ => 255.0,
+        };
+
+        let mut colors: Vec<i32> = Vec::with_capacity(number_of_vertices * 4);
+        for color in values.chunks(number_of_components) {
+            colors.extend(color.iter().map(|value| (value * scale).round() as i32));
+            if number_of_components == 3 {
+                colors.push(255);
+            }
+        }
+        Ok(colors)
+    }
+
+    fn get_gltf_node_matrix(node: &GltfNode) -> GltfMatrix {
+        if let Some(matrix) = node.matrix {
+            return matrix;
+        }
+
+        let [tx, ty, tz] = node.translation.unwrap_or([0.0, 0.0, 0.0]);
+        let [qx, qy, qz, qw] = node.rotation.unwrap_or([0.0, 0.0, 0.0, 1.0]);
+        let [sx, sy, sz] = node.scale.unwrap_or([1.0, 1.0, 1.0]);
+
+        [
+            (1.0 - 2.0 * (qy * qy + qz * qz)) * sx, (2.0 * (qx * qy + qz * qw)) * sx, (2.0 * (qx * qz - qy * qw)) * sx, 0.0,
+            (2.0 * (qx * qy - qz * qw)) * sy, (1.0 - 2.0 * (qx * qx + qz * qz)) * sy, (2.0 * (qy * qz + qx * qw)) * sy, 0.0,
+            (2.0 * (qx * qz + qy * qw)) * sz, (2.0 * (qy * qz - qx * qw)) * sz, (1.0 - 2.0 * (qx * qx + qy * qy)) * sz, 0.0,
+            tx, ty, tz, 1.0,
+        ]
+    }
+
+    fn multiply_gltf_matrices(a: &GltfMatrix, b: &GltfMatrix) -> GltfMatrix {
+        let mut result = [0.0; 16];
+        for column in 0..4 {
+            for row in 0..4 {
+                let mut sum = 0.0;
+                for k in 0..4 {
+                    sum += a[k * 4 + row] * b[column * 4 + k];
+                }
+                result[column * 4 + row] = sum;
+            }
+        }
+
+        result
+    }
+
+    /// Restores the `id` and `metadata` from the glTF mesh `extras`: `mesh_id` becomes the `id`,
+    /// entries of the `metadata` object become `metadata` (values which are not strings are kept
+    /// as JSON). Other entries are ignored.
+    fn set_gltf_extras(&mut self, extras: &serde_json::Value) {
+        self.id = extras.get("mesh_id").and_then(|id| id.as_u64()).map(|id| id as usize);
+        let entries = match extras.get("metadata").and_then(|metadata| metadata.as_object()) {
+            Some(entries) => entries,
+            None => return,
+        };
+        for (key, value) in entries {
+            let text = match value.as_str() {
+                Some(text) => text.to_string(),
+                None => value.to_string(),
+            };
+            self.metadata.insert(key.clone(), text);
+        }
+    }
+
+    fn get_with_gltf_matrix_applied(mesh: &Mesh, matrix: &GltfMatrix) -> Mesh {
+        let mut coordinates = Vec::with_capacity(mesh.coordinates.len());
+        for vertex in mesh.coordinates.chunks(3) {
+            for row in 0..3 {
+                coordinates.push(matrix[row] * vertex[0] + matrix[4 + row] * vertex[1] + matrix[8 + row] * vertex[2] + matrix[12 + row]);
+            }
+        }
+
+        let mut transformed = Mesh::new_with_id(mesh.id, coordinates, mesh.indices.clone());
+        if Self::get_gltf_matrix_determinant(matrix) < 0.0 { // Mirroring transforms reverse the winding
+            transformed = transformed.get_with_all_faces_flipped();
+        }
+        transformed.metadata = mesh.metadata.clone();
+        transformed.uvs = mesh.uvs.clone();
+        transformed.vertex_colors = mesh.vertex_colors.clone();
+        transformed
+    }
+
+    fn get_with_gltf_matrix_as_local_coordinate_system(mesh: &Mesh, matrix: &GltfMatrix) -> (Mesh, LocalCoordinateSystem) {
+        let x_column = Vector::new(matrix[0], matrix[1], matrix[2]);
+        let y_column = Vector::new(matrix[4], matrix[5], matrix[6]);
+        let z_column = Vector::new(matrix[8], matrix[9], matrix[10]);
+        let scale_x = x_column.get_length();
+        let scale_y = y_column.get_length();
+        let mut scale_z = z_column.get_length();
+        let is_orthogonal = |a: &Vector, b: &Vector, a_length: f64, b_length: f64| a.get_dot_product(b).abs() <= GLTF_ORTHOGONALITY_TOLERANCE * a_length * b_length;
+        if !is_orthogonal(&x_column, &y_column, scale_x, scale_y) || !is_orthogonal(&x_column, &z_column, scale_x, scale_z) || !is_orthogonal(&y_column, &z_column, scale_y, scale_z) {
+            // Sheared transforms can't be split into scaled local coordinates and a LocalCoordinateSystem
+            return (Self::get_with_gltf_matrix_applied(mesh, matrix), LocalCoordinateSystem::global());
+        }
+        let determinant = Self::get_gltf_matrix_determinant(matrix);
+        if determinant < 0.0 {
+            scale_z = -scale_z; // Mirroring is moved into the local coordinates, so the system stays right-handed
+        }
+
+        let mut coordinates = Vec::with_capacity(mesh.coordinates.len());
+        for vertex in mesh.coordinates.chunks(3) {
+            coordinates.push(vertex[0] * scale_x);
+            coordinates.push(vertex[1] * scale_y);
+            coordinates.push(vertex[2] * scale_z);
+        }
+        let mut local_mesh = Mesh::new_with_id(mesh.id, coordinates, mesh.indices.clone());
+        if determinant < 0.0 {
+            local_mesh = local_mesh.get_with_all_faces_flipped();
+        }
+        local_mesh.metadata = mesh.metadata.clone();
+        local_mesh.uvs = mesh.uvs.clone();
+        local_mesh.vertex_colors = mesh.vertex_colors.clone();
+
+        let origin = Point::new(matrix[12], matrix[13], matrix[14]);
+
+        (local_mesh, LocalCoordinateSystem::new(origin, x_column, y_column))
+    }
+
+    fn get_gltf_matrix_determinant(matrix: &GltfMatrix) -> f64 {
+        matrix[0] * (matrix[5] * matrix[10] - matrix[9] * matrix[6])
+            - matrix[4] * (matrix[1] * matrix[10] - matrix[9] * matrix[2])
+            + matrix[8] * (matrix[1] * matrix[6] - matrix[5] * matrix[2])
+    }
+}
+
+/// Appends the values as 32-bit floats to the buffer, with their buffer view and accessor.
+fn push_gltf_floats(buffer: &mut Vec<u8>, buffer_views: &mut Vec<serde_json::Value>, accessors: &mut Vec<serde_json::Value>, values: &[f64], accessor_type: &str) {
+    let number_of_components = match accessor_type {
+        "VEC2" => 2,
+        "VEC4" => 4,
+        _ => 3,
+    };
+    let offset = buffer.len();
+    for value in values {
+        buffer.extend_from_slice(&(*value as f32).to_le_bytes());
+    }
+    buffer_views.push(json!({"buffer": 0, "byteOffset": offset, "byteLength": values.len() * 4, "target": 34962}));
+    accessors.push(json!({"bufferView": buffer_views.len() - 1, "componentType": 5126, "count": values.len() / number_of_components, "type": accessor_type}));
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut output = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let value = (chunk[0] as u32) << 16 | (*chunk.get(1).unwrap_or(&0) as u32) << 8 | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(ALPHABET[(value >> (18 - i * 6) & 63) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::bounding_box::BoundingBox;
+    use super::*;
+
+    #[test]
+    fn test_from_gltf() {
+        let actual = Mesh::from_gltf("models/input/Pyramids.gltf").unwrap();
+
+        assert_eq!(actual.len(), 2);
+
+        let expected_translated = Mesh::new(
+            vec![
+                8.0,1.0,0.0,
+                18.0,1.0,0.0,
+                18.0,11.0,0.0,
+                8.0,11.0,0.0,
+                13.0,6.0,4.0
+            ],
+            vec![
+                0,1,2,
+                0,2,3,
+                0,1,4,
+                1,2,4,
+                2,3,4,
+                3,0,4
+            ]);
+        assert!(expected_translated.eq_with_tolerance(&actual[0], 0.0001));
+
+        let expected_rotated = Mesh::new(
+            vec![
+                -1.0,-2.0,5.0,
+                -1.0,8.0,5.0,
+                -11.0,8.0,5.0,
+                -11.0,-2.0,5.0,
+                -6.0,3.0,9.0
+            ],
+            vec![
+                0,1,2,
+                0,2,3,
+                0,1,4,
+                1,2,4,
+                2,3,4,
+                3,0,4
+            ]);
+        assert!(expected_rotated.eq_with_tolerance(&actual[1], 0.0001));
+    }
+
+    #[test]
+    fn test_from_glb_same_as_gltf() {
+        let bytes = fs::read("models/input/Pyramids.glb").unwrap();
+        let actual = Mesh::from_glb(&bytes).unwrap();
+        let expected = Mesh::from_gltf("models/input/Pyramids.gltf").unwrap();
+
+        assert_eq!(expected.len(), actual.len());
+        for i in 0..expected.len() {
+            assert!(expected[i].eq_with_tolerance(&actual[i], 0.0001));
+        }
+    }
+
+    #[test]
+    fn test_from_gltf_with_local_coordinate_systems() {
+        let actual = Mesh::from_gltf_with_local_coordinate_systems("models/input/Pyramids.gltf").unwrap();
+
+        assert_eq!(actual.len(), 2);
+        for (mesh, _) in &actual {
+            assert!(mesh.get_bounding_box().eq_with_tolerance(&BoundingBox::new(-2.0, 8.0, 1.0, 11.0, 0.0, 4.0), 0.0001));
+        }
+
+        let expected_first = LocalCoordinateSystem::new(Point::new(10.0, 0.0, 0.0), Vector::x_unit(), Vector::y_unit());
+        assert!(expected_first.eq_with_tolerance(&actual[0].1, 0.0001));
+
+        let expected_second = LocalCoordinateSystem::new(Point::new(0.0, 0.0, 5.0), Vector::y_unit(), Vector::new(-1.0, 0.0, 0.0));
+        assert!(expected_second.eq_with_tolerance(&actual[1].1, 0.0001));
+
+        let flattened = Mesh::from_gltf("models/input/Pyramids.gltf").unwrap();
+        for i in 0..actual.len() {
+            let placed = actual[i].0.get_in_local_coordinate_system(&actual[i].1);
+            assert!(placed.eq_with_tolerance(&flattened[i], 0.0001));
+        }
+    }
+
+    #[test]
+    fn test_from_glb_invalid() {
+        let actual = Mesh::from_glb(&[0, 1, 2, 3]);
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_decode_base64() {
+        let actual = Mesh::decode_base64("TWVzaG1lc2g=").unwrap();
+        assert_eq!(actual, b"Meshmesh".to_vec());
+    }
+
+    fn create_glb_with_mesh_extras(extras: &str) -> Vec<u8> {
+        let json = format!("{{\"asset\":{{\"version\":\"2.0\"}},\"scenes\":[{{\"nodes\":[0]}}],\"nodes\":[{{\"mesh\":0,\"translation\":[1,0,0]}}],\
+            \"meshes\":[{{\"primitives\":[{{\"attributes\":{{\"POSITION\":0}}}}],\"extras\":{}}}],\
+            \"accessors\":[{{\"bufferView\":0,\"componentType\":5126,\"count\":3,\"type\":\"VEC3\"}}],\
+            \"bufferViews\":[{{\"buffer\":0,\"byteLength\":36}}],\"buffers\":[{{\"byteLength\":36}}]}}", extras);
+        let mut binary: Vec<u8> = Vec::new();
+        for value in [0.0_f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0] {
+            binary.extend_from_slice(&value.to_le_bytes());
+        }
+
+        create_glb(&json, &binary)
+    }
+
+    fn create_glb(json: &str, binary: &[u8]) -> Vec<u8> {
+        let mut bytes: Vec<u8> = b"glTF".to_vec();
+        bytes.extend_from_slice(&2_u32.to_le_bytes());
+        bytes.extend_from_slice(&((12 + 8 + json.len() + 8 + binary.len()) as u32).to_le_bytes());
+        bytes.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(b"JSON");
+        bytes.extend_from_slice(json.as_bytes());
+        bytes.extend_from_slice(&(binary.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(b"BIN\0");
+        bytes.extend_from_slice(binary);
+        bytes
+    }
+
+    #[test]
+    fn test_from_glb_id_and_metadata_from_extras() {
+        let bytes = create_glb_with_mesh_extras("{\"mesh_id\":12,\"metadata\":{\"Name\":\"Slab\",\"Level\":2,\"mesh_id\":\"A-7\"},\"Author\":\"Someone\"}");
+
+        let actual = Mesh::from_glb(&bytes).unwrap();
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].id, Some(12));
+        assert_eq!(actual[0].metadata.len(), 3);
+        assert_eq!(actual[0].metadata.get("Name"), Some(&String::from("Slab")));
+        assert_eq!(actual[0].metadata.get("Level"), Some(&String::from("2")));
+        assert_eq!(actual[0].metadata.get("mesh_id"), Some(&String::from("A-7")));
+        assert_eq!(actual[0].coordinates, vec![1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_from_glb_with_local_coordinate_systems_extras_not_object() {
+        let bytes = create_glb_with_mesh_extras("\"just text\"");
+
+        let actual = Mesh::from_glb_with_local_coordinate_systems(&bytes).unwrap();
+
+        assert_eq!(actual[0].0.id, None);
+        assert!(actual[0].0.metadata.is_empty());
+    }
+
+    #[test]
+    fn test_from_glb_with_local_coordinate_systems_sheared() {
+        // Non-uniformly scaled parent of a node rotated by 45 degrees around z
+        let half_angle = std::f64::consts::FRAC_PI_8;
+        let half_sqrt2 = std::f64::consts::FRAC_1_SQRT_2;
+        let json = format!("{{\"asset\":{{\"version\":\"2.0\"}},\"scenes\":[{{\"nodes\":[0]}}],\
+            \"nodes\":[{{\"children\":[1],\"scale\":[2,1,1]}},{{\"mesh\":0,\"rotation\":[0,0,{},{}]}}],\
+            \"meshes\":[{{\"primitives\":[{{\"attributes\":{{\"POSITION\":0}}}}]}}],\
+            \"accessors\":[{{\"bufferView\":0,\"componentType\":5126,\"count\":3,\"type\":\"VEC3\"}}],\
+            \"bufferViews\":[{{\"buffer\":0,\"byteLength\":36}}],\"buffers\":[{{\"byteLength\":36}}]}}", half_angle.sin(), half_angle.cos());
+        let mut binary: Vec<u8> = Vec::new();
+        for value in [0.0_f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0] {
+            binary.extend_from_slice(&value.to_le_bytes());
+        }
+        let bytes = create_glb(&json, &binary);
+
+        let actual = Mesh::from_glb_with_local_coordinate_systems(&bytes).unwrap();
+        let flattened = Mesh::from_glb(&bytes).unwrap();
+
+        assert_eq!(actual.len(), 1);
+        assert!(actual[0].1.eq_with_tolerance(&LocalCoordinateSystem::global(), 0.0001));
+        assert!(actual[0].0.eq_with_tolerance(&flattened[0], 0.0001));
+        let expected = vec![0.0, 0.0, 0.0, 2.0 * half_sqrt2, half_sqrt2, 0.0, -2.0 * half_sqrt2, half_sqrt2, 0.0];
+        assert!(actual[0].0.coordinates.iter().zip(expected).all(|(a, b)| (a - b).abs() < 0.0001));
+    }
+
+    #[test]
+    fn test_from_glb_normalized_accessor() {
+        let json = "{\"asset\":{\"version\":\"2.0\"},\"scenes\":[{\"nodes\":[0]}],\"nodes\":[{\"mesh\":0}],\
+            \"meshes\":[{\"primitives\":[{\"attributes\":{\"POSITION\":0}}]}],\
+            \"accessors\":[{\"bufferView\":0,\"componentType\":5123,\"normalized\":true,\"count\":3,\"type\":\"VEC3\"}],\
+            \"bufferViews\":[{\"buffer\":0,\"byteLength\":18}],\"buffers\":[{\"byteLength\":18}]}";
+        let mut binary: Vec<u8> = Vec::new();
+        for value in [0_u16, 0, 0, 65535, 0, 0, 0, 65535, 0] {
+            binary.extend_from_slice(&value.to_le_bytes());
+        }
+
+        let actual = Mesh::from_glb(&create_glb(json, &binary)).unwrap();
+
+        assert_eq!(actual[0].coordinates, vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_from_glb_accessor_count_exceeds_buffer_view() {
+        let json = "{\"asset\":{\"version\":\"2.0\"},\"scenes\":[{\"nodes\":[0]}],\"nodes\":[{\"mesh\":0}],\
+            \"meshes\":[{\"primitives\":[{\"attributes\":{\"POSITION\":0}}]}],\
+            \"accessors\":[{\"bufferView\":0,\"componentType\":5126,\"count\":1152921504606846976,\"type\":\"VEC3\"}],\
+            \"bufferViews\":[{\"buffer\":0,\"byteLength\":36}],\"buffers\":[{\"byteLength\":36}]}";
+
+        let actual = Mesh::from_glb(&create_glb(json, &[0; 36]));
+
+        assert_eq!(actual.err(), Some("The glTF accessor exceeds its buffer view".to_string()));
+    }
+
+    #[test]
+    fn test_encode_base64() {
+        assert_eq!(encode_base64(b"Meshmesh"), "TWVzaG1lc2g=");
+        assert_eq!(encode_base64(b"Mesh"), "TWVzaA==");
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(Mesh::decode_base64(&encode_base64(&[0, 255, 128, 7, 9])).unwrap(), vec![0, 255, 128, 7, 9]);
+    }
+
+    #[test]
+    fn test_to_gltf_round_trip() {
+        let mut input = Mesh::new_with_id(Some(4), vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]));
+        input.metadata.insert(String::from("Name"), String::from("Floor"));
+        input.metadata.insert(String::from("mesh_id"), String::from("F-1"));
+        input.vertex_colors = Some(vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 128, 10, 20, 30, 0]);
+        input.compute_vertex_normals(crate::vertex_normal_weighting::VertexNormalWeighting::Angle, 0.5);
+        let path = std::env::temp_dir().join("meshmeshmesh_test_to_gltf_round_trip.gltf");
+
+        input.to_gltf(path.to_str().unwrap()).unwrap();
+        let actual = Mesh::from_gltf(path.to_str().unwrap()).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let mut expected = input.clone();
+        expected.vertex_normals = None;
+        assert_eq!(actual, vec![expected]);
+    }
+
+    #[test]
+    fn test_to_gltf_string_per_corner_uvs() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        input.uvs = Some(UvChannel::PerCorner(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.5, 0.5, 1.0, 1.0, 0.0, 1.0]));
+        let document: serde_json::Value = serde_json::from_str(&input.to_gltf_string().unwrap()).unwrap();
+        let buffer = Mesh::read_gltf_buffer_uri(document["buffers"][0]["uri"].as_str().unwrap(), None).unwrap();
+        let document: GltfDocument = serde_json::from_value(document).unwrap();
+
+        let actual = Mesh::read_gltf_document(&document, &[buffer]).unwrap();
+
+        assert_eq!(actual[0].0.get_number_of_vertices(), 6);
+        assert_eq!(actual[0].0.indices, vec![0, 1, 2, 3, 4, 5]);
+        for corner_id in 0..6 {
+            assert_eq!(actual[0].0.get_uv_for_corner(corner_id), input.get_uv_for_corner(corner_id));
+        }
+    }
+
+    #[test]
+    fn test_to_gltf_string_empty_error() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0], vec![]);
+
+        assert_eq!(input.to_gltf_string(), Err("Cannot export the Mesh without faces to glTF".to_string()));
+    }
+
+    #[test]
+    fn test_to_gltf_string_with_face_groups() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3, 0, 1, 3]);
+        input.face_groups = Some(vec![2, 0, 2]);
+
+        let actual = input.to_gltf_string().unwrap();
+
+        let document: serde_json::Value = serde_json::from_str(&actual).unwrap();
+        let primitives = document["meshes"][0]["primitives"].as_array().unwrap();
+        assert_eq!(primitives.len(), 2);
+        assert_eq!(primitives[0]["extras"]["face_group"], 0);
+        assert_eq!(primitives[1]["extras"]["face_group"], 2);
+        assert_eq!(primitives[0]["attributes"], primitives[1]["attributes"]);
+        assert_eq!(document["accessors"][primitives[0]["indices"].as_u64().unwrap() as usize]["count"], 3);
+        assert_eq!(document["accessors"][primitives[1]["indices"].as_u64().unwrap() as usize]["count"], 6);
+
+        let path = std::env::temp_dir().join("meshmeshmesh_face_groups.gltf");
+        fs::write(&path, actual).unwrap();
+        let imported = Mesh::from_gltf(path.to_str().unwrap()).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(imported.len(), 2);
+        assert_eq!(imported[0].indices, vec![0, 2, 3]);
+        assert_eq!(imported[1].indices, vec![0, 1, 2, 0, 1, 3]);
+    }
+}
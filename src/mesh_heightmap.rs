@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+use crate::mesh::Mesh;
+
+impl Mesh {
+    /// Creates a new grid [Mesh] from the heightmap.
+    ///
+    /// `values` are the heights (Z coordinates) organized in `rows` rows of `cols` values each.
+    /// The value with index `row * cols + col` becomes the vertex at
+    /// (`col * cell_size`, `row * cell_size`, value). Each grid cell is made of 2 triangles, with
+    /// normals pointing up.
+    ///
+    /// It panics if the grid has less than 2 rows or columns, or if the number of `values`
+    /// doesn't match it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let values = vec![
+    ///     0.0, 1.0, 0.0,
+    ///     0.0, 2.0, 0.0,
+    /// ];
+    ///
+    /// let actual = Mesh::from_heightmap(&values, 2, 3, 5.0);
+    ///
+    /// let expected = Mesh::new(
+    ///     vec![
+    ///         0.0, 0.0, 0.0,
+    ///         5.0, 0.0, 1.0,
+    ///         10.0, 0.0, 0.0,
+    ///         0.0, 5.0, 0.0,
+    ///         5.0, 5.0, 2.0,
+    ///         10.0, 5.0, 0.0,
+    ///     ],
+    ///     vec![
+    ///         0, 1, 4,
+    ///         0, 4, 3,
+    ///         1, 2, 5,
+    ///         1, 5, 4,
+    ///     ]);
+    ///
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn from_heightmap(values: &[f64], rows: usize, cols: usize, cell_size: f64) -> Mesh {
+        if rows < 2 || cols < 2 {
+            panic!("Heightmap should have at least 2 rows and 2 columns");
+        }
+        if values.len() != rows * cols {
+            panic!("Number of heightmap values should be equal to rows * cols");
+        }
+
+        let mut coordinates: Vec<f64> = Vec::with_capacity(values.len() * 3);
+        for row in 0..rows {
+            for col in 0..cols {
+                coordinates.extend_from_slice(&[col as f64 * cell_size, row as f64 * cell_size, values[row * cols + col]]);
+            }
+        }
+
+        let mut indices: Vec<usize> = Vec::with_capacity((rows - 1) * (cols - 1) * 6);
+        for row in 0..rows - 1 {
+            for col in 0..cols - 1 {
+                let a = row * cols + col;
+                indices.extend_from_slice(&[a, a + 1, a + cols + 1, a, a + cols + 1, a + cols]);
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+
+    /// Creates a new [Mesh] from the heightmap, see [Mesh::from_heightmap], with the optional
+    /// simplification and skirt.
+    ///
+    /// If `max_error` is given, the grid is decimated with [Mesh::get_decimated], so flat areas
+    /// of the terrain get much less faces. The outline of the grid is always kept.
+    ///
+    /// If `skirt_depth` is given, a vertical strip of faces (a skirt) is added along the
+    /// outline, going `skirt_depth` down from each boundary vertex. Skirts hide the cracks
+    /// between neighbouring terrain tiles with different levels of detail.
+    ///
+    /// It panics in the same cases as [Mesh::from_heightmap].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Flat 3x3 grid
+    /// let values = vec![0.0; 16];
+    ///
+    /// let actual = Mesh::from_heightmap_with_options(&values, 4, 4, 1.0, Some(0.5), Some(0.001));
+    ///
+    /// // 2 faces on top, 2 faces per each of 4 sides of the skirt
+    /// assert_eq!(actual.get_number_of_faces(), 10);
+    ///
+    /// let bounding_box = actual.get_bounding_box();
+    /// assert_eq!(bounding_box.min_z, -0.5);
+    /// assert_eq!(bounding_box.max_x, 3.0);
+    /// ```
+    pub fn from_heightmap_with_options(values: &[f64], rows: usize, cols: usize, cell_size: f64, skirt_depth: Option<f64>, max_error: Option<f64>) -> Mesh {
+        let mut mesh = Mesh::from_heightmap(values, rows, cols, cell_size);
+
+        if let Some(max_error) = max_error {
+            mesh = mesh.get_decimated(max_error);
+        }
+
+        if let Some(skirt_depth) = skirt_depth {
+            mesh.add_skirt(skirt_depth);
+        }
+
+        mesh
+    }
+
+    /// Adds vertical faces going `depth` down along all the boundary edges.
+    fn add_skirt(&mut self, depth: f64) {
+        let mut edges: HashSet<(usize, usize)> = HashSet::new();
+        for face in self.indices.chunks(3) {
+            for i in 0..3 {
+                edges.insert((face[i], face[(i + 1) % 3]));
+            }
+        }
+
+        // Boundary edges keep the orientation of their faces, so the skirt faces outside.
+        let mut boundary_edges: Vec<(usize, usize)> = edges.iter().copied().filter(|(a, b)| !edges.contains(&(*b, *a))).collect();
+        boundary_edges.sort();
+
+        let mut bottom_vertex_ids: HashMap<usize, usize> = HashMap::new();
+        for (a, b) in boundary_edges {
+            let [bottom_a, bottom_b] = [a, b].map(|vertex_id| {
+                *bottom_vertex_ids.entry(vertex_id).or_insert_with(|| {
+                    let (x, y, z) = (self.coordinates[vertex_id * 3], self.coordinates[vertex_id * 3 + 1], self.coordinates[vertex_id * 3 + 2]);
+                    self.coordinates.extend_from_slice(&[x, y, z - depth]);
+                    self.coordinates.len() / 3 - 1
+                })
+            });
+            self.indices.extend_from_slice(&[b, a, bottom_a, b, bottom_a, bottom_b]);
+        }
+        self.invalidate_cache();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_heightmap_is_welded_grid() {
+        let values: Vec<f64> = (0..20).map(|i| (i as f64 * 0.7).sin()).collect();
+
+        let actual = Mesh::from_heightmap(&values, 4, 5, 2.0);
+
+        assert_eq!(actual.get_number_of_vertices(), 20);
+        assert_eq!(actual.get_number_of_faces(), 24);
+        assert!(actual.is_connected());
+        assert_eq!(actual.get_edges_with_missing_neighbour().len(), 14);
+        assert_eq!(actual.coordinates[3 * 7..3 * 7 + 3], [4.0, 2.0, values[7]]);
+        for normal in actual.get_face_normal_vectors_unitized() {
+            assert!(normal.z > 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of heightmap values should be equal to rows * cols")]
+    fn test_from_heightmap_wrong_number_of_values_panic() {
+        Mesh::from_heightmap(&[0.0; 5], 2, 3, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Heightmap should have at least 2 rows and 2 columns")]
+    fn test_from_heightmap_single_row_panic() {
+        Mesh::from_heightmap(&[0.0; 3], 1, 3, 1.0);
+    }
+
+    #[test]
+    fn test_from_heightmap_with_options_skirt_is_closed_down_to_depth() {
+        let values: Vec<f64> = (0..12).map(|i| i as f64 * 0.1).collect();
+
+        let actual = Mesh::from_heightmap_with_options(&values, 3, 4, 1.0, Some(2.0), None);
+
+        // 12 grid faces + 2 faces per each of 10 boundary edges
+        assert_eq!(actual.get_number_of_faces(), 12 + 20);
+        assert_eq!(actual.get_number_of_vertices(), 12 + 10);
+        assert_eq!(actual.get_non_manifold_edges().len(), actual.get_edges_with_missing_neighbour().len());
+        assert_eq!(actual.get_edges_with_missing_neighbour().len(), 10);
+        for vertex_id in 12..22 {
+            let z = actual.coordinates[vertex_id * 3 + 2];
+            let top_z = (0..12).map(|top_id| actual.coordinates[top_id * 3 + 2])
+                .find(|top_z| (top_z - 2.0 - z).abs() < 1e-12);
+            assert!(top_z.is_some());
+        }
+    }
+
+    #[test]
+    fn test_from_heightmap_with_options_skirt_faces_outside() {
+        let actual = Mesh::from_heightmap_with_options(&[0.0; 4], 2, 2, 1.0, Some(1.0), None);
+
+        let normals = actual.get_face_normal_vectors_unitized();
+        let triangles = actual.to_triangles();
+        for (normal, triangle) in normals.iter().zip(triangles.iter()).skip(2) {
+            let centroid = triangle.get_centroid();
+            let outward = crate::vector::Vector::new(centroid.x - 0.5, centroid.y - 0.5, 0.0);
+            assert!(normal.get_dot_product(&outward) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_from_heightmap_with_options_simplification_within_error() {
+        let (rows, cols) = (9, 11);
+        let values: Vec<f64> = (0..rows * cols).map(|i| {
+            let (row, col) = ((i / cols) as f64, (i % cols) as f64);
+            if col < 5.0 { 0.0 } else { (row * 0.4).sin() }
+        }).collect();
+        let full = Mesh::from_heightmap(&values, rows, cols, 1.0);
+
+        let actual = Mesh::from_heightmap_with_options(&values, rows, cols, 1.0, None, Some(0.01));
+
+        assert!(actual.get_number_of_faces() < full.get_number_of_faces());
+        assert!(actual.get_bounding_box().eq_with_tolerance(&full.get_bounding_box(), 1e-12));
+        let triangles = actual.to_triangles();
+        for point in full.to_points() {
+            let distance = triangles.iter()
+                .map(|triangle| triangle.get_closest_point(&point).get_distance_to_point(&point))
+                .fold(f64::MAX, f64::min);
+            assert!(distance <= 0.01 + 1e-9);
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use std::collections::HashMap;
+use crate::filled_hole::FilledHole;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::uv_channel::UvChannel;
+use crate::vector::Vector;
+
+/// Boundary loops with all vertices closer to their plane than this part of the loop's length
+/// are treated as planar by [Mesh::fill_holes].
+const PLANARITY_TOLERANCE: f64 = 1e-6;
+
+impl Mesh {
+    /// Fills holes of the [Mesh] with new faces and returns which holes were filled.
+    ///
+    /// Holes are found as boundary loops (see [Mesh::get_boundary_loops]), only loops not
+    /// longer than `max_boundary_length` are filled, so the outer boundary of an open surface
+    /// can be kept open. Planar loops are triangulated with the polygon triangulation (see
+    /// [Polygon::triangulate_raw]), non-planar ones with the ear clipping, which clips the
+    /// smallest ear first. New faces get the winding opposite to the boundary edges, so it's
+    /// consistent with the faces around.
+    ///
+    /// No vertices are added. New faces get per face data (`face_groups` and face `attributes`)
+    /// of the face next to the first edge of their hole's boundary. Each corner of the new faces
+    /// gets per corner `uvs` of the same vertex's corner in a face next to the hole's boundary.
+    ///
+    /// The input [Mesh] should be welded, because boundary loops are read from shared vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Box without the top
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+    ///          0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.3, 0.0, 1.0, 1.0],
+    ///     vec![0, 2, 1, 0, 3, 2, 0, 1, 5, 0, 5, 4,
+    ///          1, 2, 6, 1, 6, 5, 2, 3, 7, 2, 7, 6, 3, 0, 4, 3, 4, 7]);
+    ///
+    /// let actual = input.fill_holes(10.0);
+    ///
+    /// assert_eq!(actual.len(), 1);
+    /// assert_eq!(actual[0].face_ids, vec![10, 11]);
+    /// assert!(!actual[0].is_planar);
+    /// assert!(input.is_watertight());
+    /// ```
+    pub fn fill_holes(&mut self, max_boundary_length: f64) -> Vec<FilledHole> {
+        self.fill_holes_matching(|boundary_length, _| boundary_length <= max_boundary_length)
+    }
+
+    /// Caps planar holes of the [Mesh] with new faces and returns which holes were capped.
+    ///
+    /// It works like [Mesh::fill_holes], but only planar boundary loops are closed (with the
+    /// polygon triangulation), whatever their length, and non-planar ones are left open. It's a
+    /// safer choice for BIM-like data, where missing faces are usually flat, e.g. ends of cut
+    /// elements, while non-planar loops are often intended openings.
+    ///
+    /// The input [Mesh] should be welded, because boundary loops are read from shared vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Box without the top and bottom, with the top edge bent
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+    ///          0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.3, 0.0, 1.0, 1.0],
+    ///     vec![0, 1, 5, 0, 5, 4, 1, 2, 6, 1, 6, 5, 2, 3, 7, 2, 7, 6, 3, 0, 4, 3, 4, 7]);
+    ///
+    /// let actual = input.cap_holes_planar();
+    ///
+    /// assert_eq!(actual.len(), 1);
+    /// assert!(actual[0].is_planar);
+    /// assert_eq!(actual[0].face_ids, vec![8, 9]);
+    /// assert_eq!(input.get_boundary_loops().len(), 1);
+    /// ```
+    pub fn cap_holes_planar(&mut self) -> Vec<FilledHole> {
+        self.fill_holes_matching(|_, is_planar| is_planar)
+    }
+
+    /// Fills holes for which `should_fill` (taking the boundary length and whether the
+    /// boundary is planar) returns `true`.
+    fn fill_holes_matching(&mut self, should_fill: impl Fn(f64, bool) -> bool) -> Vec<FilledHole> {
+        let points = self.to_points();
+        let mut edge_corner_ids: HashMap<(usize, usize), usize> = HashMap::new(); // Directed edge -> corner id of its start
+        for (corner_id, index) in self.indices.iter().enumerate() {
+            edge_corner_ids.entry((*index, self.indices[get_next_corner_id(corner_id)])).or_insert(corner_id);
+        }
+        let mut filled_holes = Vec::new();
+        let mut source_face_ids = Vec::new();
+        let mut new_corner_uvs: Vec<f64> = Vec::new();
+        for boundary_vertex_ids in self.get_boundary_loops_vertex_ids() {
+            let boundary_length: f64 = (0..boundary_vertex_ids.len())
+                .map(|i| points[boundary_vertex_ids[i]].get_distance_to_point(&points[boundary_vertex_ids[(i + 1) % boundary_vertex_ids.len()]]))
+                .sum();
+
+            // The new faces have to use boundary edges in the opposite direction
+            let mut fill_vertex_ids = boundary_vertex_ids.clone();
+            if self.has_directed_edge(fill_vertex_ids[0], fill_vertex_ids[1]) {
+                fill_vertex_ids.reverse();
+            }
+            let fill_points: Vec<Point> = fill_vertex_ids.iter().map(|vertex_id| points[*vertex_id]).collect();
+            let polygon = Polygon::new(fill_points.clone());
+            let is_planar = polygon.is_planar_with_tolerance(boundary_length * PLANARITY_TOLERANCE);
+            if !should_fill(boundary_length, is_planar) {
+                continue;
+            }
+            let triangles = if is_planar {
+                get_polygon_triangulation_triangles(&polygon)
+            } else {
+                get_min_area_ear_clipping_triangles(&fill_points)
+            };
+
+            // Corners of the faces around the hole, one for each boundary vertex
+            let mut boundary_corner_ids: HashMap<usize, usize> = HashMap::new();
+            for (i, start) in boundary_vertex_ids.iter().enumerate() {
+                let end = boundary_vertex_ids[(i + 1) % boundary_vertex_ids.len()];
+                let (corner_id, corner_vertex_ids) = match edge_corner_ids.get(&(*start, end)) {
+                    Some(corner_id) => (*corner_id, [*start, end]),
+                    None => (edge_corner_ids[&(end, *start)], [end, *start]),
+                };
+                boundary_corner_ids.entry(corner_vertex_ids[0]).or_insert(corner_id);
+                boundary_corner_ids.entry(corner_vertex_ids[1]).or_insert(get_next_corner_id(corner_id));
+            }
+            let boundary_face_id = boundary_corner_ids[&boundary_vertex_ids[0]] / 3;
+            source_face_ids.extend(std::iter::repeat_n(boundary_face_id, triangles.len()));
+
+            let first_face_id = self.get_number_of_faces();
+            for triangle in &triangles {
+                for position in triangle {
+                    let vertex_id = fill_vertex_ids[*position];
+                    self.indices.push(vertex_id);
+                    if let Some(UvChannel::PerCorner(uvs)) = &self.uvs {
+                        let corner_id = boundary_corner_ids[&vertex_id];
+                        new_corner_uvs.extend_from_slice(&uvs[corner_id * 2..corner_id * 2 + 2]);
+                    }
+                }
+            }
+            let face_ids = (first_face_id..self.get_number_of_faces()).collect();
+            filled_holes.push(FilledHole::new(boundary_vertex_ids, face_ids, is_planar));
+        }
+
+        if !filled_holes.is_empty() {
+            self.extend_face_data(&source_face_ids);
+            if let Some(UvChannel::PerCorner(uvs)) = &mut self.uvs {
+                uvs.extend(new_corner_uvs);
+            }
+            self.invalidate_cache();
+        }
+
+        filled_holes
+    }
+
+    pub(crate) fn has_directed_edge(&self, start: usize, end: usize) -> bool {
+        self.indices.chunks(3).any(|face| (0..3).any(|i| face[i] == start && face[(i + 1) % 3] == end))
+    }
+}
+
+/// Gets the id of the next corner of the same face.
+fn get_next_corner_id(corner_id: usize) -> usize {
+    corner_id - corner_id % 3 + (corner_id + 1) % 3
+}
+
+/// Gets triangles (as positions in the polygon) of the planar polygon triangulated with
+/// [Polygon::triangulate_raw], with the winding of the polygon.
+///
+/// If vertices of the triangulation don't match the polygon's vertices, the minimum area ear
+/// clipping is used instead.
+fn get_polygon_triangulation_triangles(polygon: &Polygon) -> Vec<[usize; 3]> {
+    let points = &polygon.vertices;
+    let normal = get_newell_normal(points);
+    let triangulated = polygon.triangulate_raw();
+    let tolerance = points.iter().zip(points.iter().skip(1))
+        .map(|(first, second)| first.get_distance_to_point(second))
+        .fold(f64::INFINITY, f64::min) * 1e-3;
+
+    let mut positions = Vec::with_capacity(triangulated.get_number_of_vertices());
+    for vertex in triangulated.to_points() {
+        match points.iter().position(|point| point.get_distance_to_point(&vertex) <= tolerance) {
+            Some(position) => positions.push(position),
+            None => return get_min_area_ear_clipping_triangles(points),
+        }
+    }
+
+    let triangles: Vec<[usize; 3]> = triangulated.indices.chunks(3)
+        .map(|face| {
+            let (a, b, c) = (positions[face[0]], positions[face[1]], positions[face[2]]);
+            let face_normal = Vector::from_2_points(&points[a], &points[b]).get_cross_product(&Vector::from_2_points(&points[a], &points[c]));
+            if face_normal.get_dot_product(&normal) < 0.0 { [a, c, b] } else { [a, b, c] }
+        })
+        .collect();
+    if triangles.len() != points.len() - 2 {
+        return get_min_area_ear_clipping_triangles(points);
+    }
+
+    triangles
+}
+
+/// Gets triangles (as positions in the loop) by clipping ears of the (possibly non-planar)
+/// loop, always the one with the smallest area.
+///
+/// Only ears convex in the direction of the loop's normal, which contain no other vertices of
+/// the loop (seen along the normal), are clipped, unless there are none.
+fn get_min_area_ear_clipping_triangles(points: &[Point]) -> Vec<[usize; 3]> {
+    let normal = get_newell_normal(points);
+
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut triangles: Vec<[usize; 3]> = Vec::with_capacity(points.len() - 2);
+    while remaining.len() > 3 {
+        let number_of_remaining = remaining.len();
+        let ears: Vec<(usize, Vector)> = (0..number_of_remaining).map(|i| {
+            let previous = &points[remaining[(i + number_of_remaining - 1) % number_of_remaining]];
+            let current = &points[remaining[i]];
+            let next = &points[remaining[(i + 1) % number_of_remaining]];
+            (i, Vector::from_2_points(previous, current).get_cross_product(&Vector::from_2_points(current, next)))
+        }).collect();
+        let smallest = |ears: &mut dyn Iterator<Item = &(usize, Vector)>| ears
+            .min_by(|(_, a), (_, b)| a.get_length().total_cmp(&b.get_length()))
+            .map(|(i, _)| *i);
+        let is_convex = |(_, cross): &&(usize, Vector)| cross.get_dot_product(&normal) > 0.0;
+        let is_empty = |(i, _): &&(usize, Vector)| {
+            let ear_ids = [remaining[(i + number_of_remaining - 1) % number_of_remaining], remaining[*i], remaining[(i + 1) % number_of_remaining]];
+            !remaining.iter()
+                .filter(|vertex_id| !ear_ids.contains(vertex_id))
+                .any(|vertex_id| is_inside_ear(&points[*vertex_id], &ear_ids.map(|ear_id| points[ear_id]), &normal))
+        };
+        let ear = smallest(&mut ears.iter().filter(is_convex).filter(is_empty))
+            .or_else(|| smallest(&mut ears.iter().filter(is_convex)))
+            .or_else(|| smallest(&mut ears.iter()))
+            .unwrap();
+
+        let previous = remaining[(ear + number_of_remaining - 1) % number_of_remaining];
+        let next = remaining[(ear + 1) % number_of_remaining];
+        triangles.push([previous, remaining[ear], next]);
+        remaining.remove(ear);
+    }
+    triangles.push([remaining[0], remaining[1], remaining[2]]);
+
+    triangles
+}
+
+/// Checks if the [Point] seen along the `normal` lies inside (or on the edge of) the ear made of
+/// the previous, current and next point of the loop.
+fn is_inside_ear(point: &Point, ear: &[Point; 3], normal: &Vector) -> bool {
+    (0..3).all(|i| {
+        let (start, end) = (&ear[i], &ear[(i + 1) % 3]);
+        Vector::from_2_points(start, end).get_cross_product(&Vector::from_2_points(start, point)).get_dot_product(normal) >= 0.0
+    })
+}
+
+/// Gets the (not unitized) normal of the loop with Newell's method, pointing to the side from
+/// which the loop is anticlockwise.
+pub(crate) fn get_newell_normal(points: &[Point]) -> Vector {
+    let mut normal = Vector::zero();
+    for (i, current) in points.iter().enumerate() {
+        let next = &points[(i + 1) % points.len()];
+        normal.x += (current.y - next.y) * (current.z + next.z);
+        normal.y += (current.z - next.z) * (current.x + next.x);
+        normal.z += (current.x - next.x) * (current.y + next.y);
+    }
+
+    normal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh_attributes::AttributeValues;
+    use crate::triangle::Triangle;
+
+    /// Gets a box without the top face, with the top boundary at `top_z`.
+    fn get_open_box(top_z: [f64; 4]) -> Mesh {
+        Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 2.0, 0.0,
+                 0.0, 0.0, top_z[0], 2.0, 0.0, top_z[1], 2.0, 2.0, top_z[2], 0.0, 2.0, top_z[3]],
+            vec![0, 2, 1, 0, 3, 2, // bottom
+                 0, 1, 5, 0, 5, 4, // front
+                 1, 2, 6, 1, 6, 5, // right
+                 2, 3, 7, 2, 7, 6, // back
+                 3, 0, 4, 3, 4, 7, // left
+                 ])
+    }
+
+    #[test]
+    fn test_fill_holes_planar() {
+        let mut input = get_open_box([2.0, 2.0, 2.0, 2.0]);
+
+        let actual = input.fill_holes(100.0);
+
+        assert_eq!(actual.len(), 1);
+        assert!(actual[0].is_planar);
+        assert_eq!(actual[0].face_ids, vec![10, 11]);
+        let mut boundary_vertex_ids = actual[0].boundary_vertex_ids.clone();
+        boundary_vertex_ids.sort();
+        assert_eq!(boundary_vertex_ids, vec![4, 5, 6, 7]);
+        assert!(input.is_watertight());
+        assert!((input.get_volume().unwrap() - 8.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fill_holes_non_planar() {
+        let mut input = get_open_box([2.0, 2.5, 2.0, 2.5]);
+
+        let actual = input.fill_holes(100.0);
+
+        assert_eq!(actual.len(), 1);
+        assert!(!actual[0].is_planar);
+        assert!(input.is_watertight());
+        assert!(input.get_volume().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_fill_holes_too_long() {
+        let mut input = get_open_box([2.0, 2.0, 2.0, 2.0]);
+
+        let actual = input.fill_holes(7.9);
+
+        assert!(actual.is_empty());
+        assert_eq!(input.get_number_of_faces(), 10);
+    }
+
+    #[test]
+    fn test_fill_holes_extends_face_data() {
+        let mut input = get_open_box([2.0, 2.5, 2.0, 2.5]);
+        input.face_groups = Some((0..10).collect());
+        input.attributes.face.insert(String::from("thickness"), AttributeValues::F64((0..10).map(|face_id| face_id as f64).collect()));
+        input.attributes.vertex.insert(String::from("temperature"), AttributeValues::F64(vec![20.0; 8]));
+
+        let filled = input.fill_holes(100.0);
+
+        let boundary = &filled[0].boundary_vertex_ids;
+        let boundary_face_id = input.indices.chunks(3).take(10).position(|face| face.contains(&boundary[0]) && face.contains(&boundary[1])).unwrap();
+        let mut expected: Vec<usize> = (0..10).collect();
+        expected.extend([boundary_face_id; 2]);
+        assert_eq!(input.face_groups, Some(expected.clone()));
+        assert_eq!(input.attributes.face["thickness"], AttributeValues::F64(expected.iter().map(|face_id| *face_id as f64).collect()));
+        assert_eq!(input.attributes.vertex["temperature"], AttributeValues::F64(vec![20.0; 8]));
+    }
+
+    #[test]
+    fn test_fill_holes_extends_per_corner_uvs() {
+        let mut input = get_open_box([2.0, 2.0, 2.0, 2.0]);
+        let corner_uvs: Vec<f64> = input.indices.iter().flat_map(|index| [*index as f64, 0.5]).collect();
+        input.uvs = Some(UvChannel::PerCorner(corner_uvs));
+
+        input.fill_holes(100.0);
+
+        let Some(UvChannel::PerCorner(uvs)) = &input.uvs else { panic!("Per corner UVs should be kept") };
+        assert_eq!(uvs.len(), input.indices.len() * 2);
+        for (corner_id, index) in input.indices.iter().enumerate() {
+            assert_eq!(uvs[corner_id * 2..corner_id * 2 + 2], [*index as f64, 0.5]);
+        }
+    }
+
+    #[test]
+    fn test_fill_holes_closed() {
+        let mut input = get_open_box([2.0, 2.0, 2.0, 2.0]);
+        input.fill_holes(100.0);
+        let expected = input.clone();
+
+        let actual = input.fill_holes(100.0);
+
+        assert!(actual.is_empty());
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn test_cap_holes_planar_skips_non_planar() {
+        let mut input = get_open_box([2.0, 2.5, 2.0, 2.5]);
+
+        let actual = input.cap_holes_planar();
+
+        assert!(actual.is_empty());
+        assert_eq!(input.get_number_of_faces(), 10);
+    }
+
+    #[test]
+    fn test_cap_holes_planar_concave_ends() {
+        // Open L-shaped prism, both ends are concave and planar
+        let outline = [(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)];
+        let mut coordinates = Vec::new();
+        for z in [0.0, 1.0] {
+            for (x, y) in outline {
+                coordinates.extend_from_slice(&[x, y, z]);
+            }
+        }
+        let mut indices = Vec::new();
+        for i in 0..6 {
+            let next = (i + 1) % 6;
+            indices.extend_from_slice(&[i, next, next + 6, i, next + 6, i + 6]);
+        }
+        let mut input = Mesh::new(coordinates, indices);
+
+        let actual = input.cap_holes_planar();
+
+        assert_eq!(actual.len(), 2);
+        assert!(actual.iter().all(|capped_hole| capped_hole.is_planar && capped_hole.face_ids.len() == 4));
+        assert!(input.is_watertight());
+        assert!((input.get_volume().unwrap() - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_min_area_ear_clipping_triangles_clips_smallest_ear_first() {
+        let points = vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(4.0, 0.0, 0.0),
+            Point::new(4.2, 2.0, 0.3),
+            Point::new(4.0, 4.0, 0.0),
+            Point::new(0.0, 4.0, 0.0),
+        ];
+
+        let actual = get_min_area_ear_clipping_triangles(&points);
+
+        assert_eq!(actual.len(), 3);
+        assert_eq!(actual[0], [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_get_min_area_ear_clipping_triangles_skips_ears_containing_vertices() {
+        // The smallest convex ear (5, 0, 1) contains the vertex 3
+        let points: Vec<Point> = [(3.0, 7.0), (3.0, 1.0), (4.0, 8.0), (6.0, 3.0), (5.0, 1.0), (1.0, 0.0)].iter()
+            .map(|(x, y)| Point::new(*x, *y, 0.0))
+            .collect();
+
+        let actual = get_min_area_ear_clipping_triangles(&points);
+
+        let area: f64 = actual.iter().map(|[a, b, c]| Triangle::new(points[*a], points[*b], points[*c]).get_area()).sum();
+        assert_eq!(actual.len(), 4);
+        assert!((area - 18.5).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::HashSet;
+use crate::mesh::Mesh;
+use crate::mesh_point_insertion::{get_point, insert_point, split_edge};
+use crate::mesh_refinement::Refinement;
+use crate::polyline::Polyline;
+use crate::vector::Vector;
+
+impl Mesh {
+    /// Splits faces of this [Mesh] along the [Polyline], so the curve becomes a chain of edges,
+    /// e.g. for `get_part_by_face_ids` selections following exact boundaries.
+    ///
+    /// Vertices of the [Polyline] are projected onto the closest points of the surface and
+    /// inserted like in [Mesh::get_with_points_inserted]. Each segment between them is imprinted
+    /// as the section of the surface with the plane containing the segment and the surface normal,
+    /// by splitting edges crossing that plane. Crossings within the `tolerance` from existing
+    /// vertices use these vertices.
+    ///
+    /// New vertices and faces get their data the same way as in
+    /// [Mesh::get_with_points_inserted]. The imprinting of a segment stops if the section leaves
+    /// the surface, e.g. at an open boundary.
+    ///
+    /// Returns vertex ids of the imprinted chain, in the order of the [Polyline].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 2.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    /// let polyline = Polyline::new(vec![Point::new(0.5, 0.0, 0.0), Point::new(0.5, 2.0, 0.0)]);
+    ///
+    /// let actual = input.imprint_polyline(&polyline, 0.001);
+    ///
+    /// assert_eq!(actual.len(), 3);
+    /// let points = input.to_points();
+    /// assert!(actual.iter().all(|vertex| (points[*vertex].x - 0.5).abs() < 1e-12));
+    /// ```
+    pub fn imprint_polyline(&mut self, polyline: &Polyline, tolerance: f64) -> Vec<usize> {
+        let mut refinement = Refinement::new(self);
+        let mut chain: Vec<usize> = Vec::new();
+        for point in &polyline.vertices {
+            let Some(vertex) = insert_point(&mut refinement, point, f64::INFINITY, tolerance) else { break };
+            if let Some(&previous) = chain.last() {
+                if previous == vertex {
+                    continue;
+                }
+                let section = imprint_segment(&mut refinement, previous, vertex, tolerance);
+                chain.extend(section);
+            }
+            chain.push(vertex);
+        }
+        *self = refinement.into_mesh(self);
+
+        chain
+    }
+}
+
+/// Splits edges along the section between the `start` and `end` vertices, returns ids of vertices
+/// between them.
+fn imprint_segment(refinement: &mut Refinement, start: usize, end: usize, tolerance: f64) -> Vec<usize> {
+    let end_point = get_point(refinement, end);
+    let direction = Vector::from_2_points(&get_point(refinement, start), &end_point);
+    let plane_normal = direction.get_cross_product(&get_normal_around(refinement, &[start, end]));
+    if plane_normal.get_length() == 0.0 {
+        return Vec::new();
+    }
+    let plane_normal = plane_normal.get_unitized();
+    let plane_origin = get_point(refinement, start);
+    let get_signed_distance = |refinement: &Refinement, vertex: usize| Vector::from_2_points(&plane_origin, &get_point(refinement, vertex)).get_dot_product(&plane_normal);
+
+    let mut section = Vec::new();
+    let mut visited = HashSet::from([start]);
+    let mut current = start;
+    // The plane crosses each of the original faces at most once
+    for _ in 0..=refinement.faces.len() {
+        let faces: Vec<[usize; 3]> = refinement.faces.iter().filter(|face| face.contains(&current)).copied().collect();
+        if faces.iter().any(|face| face.contains(&end)) {
+            return section;
+        }
+
+        // Candidates are vertices lying on the plane and edges crossing it, opposite to the current vertex
+        let current_point = get_point(refinement, current);
+        let mut best: Option<(f64, usize, usize, f64)> = None;
+        for face in &faces {
+            let corner = face.iter().position(|vertex| *vertex == current).unwrap();
+            let (a, b) = (face[(corner + 1) % 3], face[(corner + 2) % 3]);
+            let (distance_a, distance_b) = (get_signed_distance(refinement, a), get_signed_distance(refinement, b));
+            let mut candidates = Vec::new();
+            for (vertex, distance) in [(a, distance_a), (b, distance_b)] {
+                if distance.abs() <= tolerance && !visited.contains(&vertex) {
+                    candidates.push((vertex, vertex, 0.0));
+                }
+            }
+            if distance_a.abs() > tolerance && distance_b.abs() > tolerance && (distance_a > 0.0) != (distance_b > 0.0) {
+                candidates.push((a, b, distance_a / (distance_a - distance_b)));
+            }
+            for (a, b, parameter) in candidates {
+                let (point_a, point_b) = (get_point(refinement, a), get_point(refinement, b));
+                let step = Vector::from_2_points(&current_point, &(point_a + Vector::from_2_points(&point_a, &point_b) * parameter));
+                if step.get_length() == 0.0 {
+                    continue;
+                }
+                let progress = step.get_unitized().get_dot_product(&Vector::from_2_points(&current_point, &end_point));
+                if progress > 0.0 && best.is_none_or(|best| progress > best.0) {
+                    best = Some((progress, a, b, parameter));
+                }
+            }
+        }
+
+        let Some((_, a, b, parameter)) = best else { return section };
+        current = if a == b {
+            a
+        } else {
+            let (point_a, point_b) = (get_point(refinement, a), get_point(refinement, b));
+            split_edge(refinement, a, b, &(point_a + Vector::from_2_points(&point_a, &point_b) * parameter), tolerance)
+        };
+        if current == end || !visited.insert(current) {
+            return section;
+        }
+        section.push(current);
+    }
+
+    section
+}
+
+/// Gets the area weighted normal of faces around the given vertices.
+fn get_normal_around(refinement: &Refinement, vertices: &[usize]) -> Vector {
+    let mut normal = Vector::new(0.0, 0.0, 0.0);
+    for face in refinement.faces.iter().filter(|face| face.iter().any(|vertex| vertices.contains(vertex))) {
+        let first = Vector::from_2_points(&get_point(refinement, face[0]), &get_point(refinement, face[1]));
+        let second = Vector::from_2_points(&get_point(refinement, face[0]), &get_point(refinement, face[2]));
+        normal = normal + first.get_cross_product(&second);
+    }
+
+    normal
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point;
+    use super::*;
+
+    fn get_grid() -> Mesh {
+        // 2 x 2 squares, each split along its diagonal
+        let mut coordinates = Vec::new();
+        for y in 0..3 {
+            for x in 0..3 {
+                coordinates.extend([x as f64, y as f64, 0.0]);
+            }
+        }
+        let mut indices = Vec::new();
+        for y in 0..2 {
+            for x in 0..2 {
+                let first = y * 3 + x;
+                indices.extend([first, first + 1, first + 4, first, first + 4, first + 3]);
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+
+    #[test]
+    fn test_imprint_polyline_across_faces() {
+        let mut input = get_grid();
+        let polyline = Polyline::new(vec![Point::new(0.0, 0.3, 0.0), Point::new(2.0, 0.3, 0.0)]);
+
+        let actual = input.imprint_polyline(&polyline, 0.001);
+
+        let points = input.to_points();
+        assert_eq!(actual.len(), 5);
+        assert!(actual.iter().all(|vertex| (points[*vertex].y - 0.3).abs() < 1e-12));
+        assert!(actual.windows(2).all(|pair| points[pair[0]].x < points[pair[1]].x));
+        assert!((input.get_area() - 4.0).abs() < 1e-12);
+
+        let below: Vec<usize> = input.to_triangles().iter().enumerate()
+            .filter(|(_, triangle)| triangle.get_centroid().y < 0.3)
+            .map(|(face_id, _)| face_id)
+            .collect();
+        assert!((input.get_part_by_face_ids(&below).get_area() - 0.6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_imprint_polyline_projected_through_vertices() {
+        let mut input = get_grid();
+        input.face_groups = Some(vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        let polyline = Polyline::new(vec![
+            Point::new(0.5, 1.0, 1.0),
+            Point::new(1.5, 1.0, 1.0),
+            Point::new(1.5, 2.0, -1.0),
+        ]);
+
+        let actual = input.imprint_polyline(&polyline, 0.001);
+
+        let points = input.to_points();
+        assert_eq!(actual.len(), 5);
+        assert_eq!(actual[1], 4);
+        assert!(points[actual[0]].eq_with_tolerance(&Point::new(0.5, 1.0, 0.0), 1e-12));
+        assert!(points[actual[4]].eq_with_tolerance(&Point::new(1.5, 2.0, 0.0), 1e-12));
+        assert_eq!(input.face_groups.as_ref().unwrap().len(), input.get_number_of_faces());
+        assert!(input.get_non_manifold_vertices().is_empty());
+        assert!((input.get_area() - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_imprint_polyline_off_surface() {
+        let mut input = get_grid();
+        let polyline = Polyline::new(vec![Point::new(1.0, 1.0, 0.0), Point::new(1.0, 1.0, 0.0)]);
+
+        let actual = input.imprint_polyline(&polyline, 0.001);
+
+        assert_eq!(actual, vec![4]);
+        assert_eq!(input.get_number_of_faces(), 8);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::index_width::IndexWidth;
+use crate::mesh::Mesh;
+
+impl Mesh {
+    /// Gets the smallest [IndexWidth] which can store all the `indices` of the [Mesh].
+    ///
+    /// The [Mesh] without faces gets [IndexWidth::U16].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::index_width::IndexWidth;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// assert_eq!(input.get_index_width(), IndexWidth::U16);
+    /// ```
+    pub fn get_index_width(&self) -> IndexWidth {
+        IndexWidth::from_max_index(self.indices.iter().copied().max().unwrap_or(0))
+    }
+
+    /// Gets `indices` converted to `u32`.
+    ///
+    /// It returns an error if any index doesn't fit into `u32`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// assert_eq!(input.get_indices_u32(), Ok(vec![0_u32, 1, 2]));
+    /// ```
+    pub fn get_indices_u32(&self) -> Result<Vec<u32>, String> {
+        get_indices_u32(&self.indices)
+    }
+
+    /// Gets `indices` converted to `u16`.
+    ///
+    /// It returns an error if any index doesn't fit into `u16`, so it can be used only for
+    /// small Meshes (up to 65 536 vertices).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// assert_eq!(input.get_indices_u16(), Ok(vec![0_u16, 1, 2]));
+    ///
+    /// let too_big = Mesh::new(vec![], vec![0, 1, 70000]);
+    ///
+    /// assert!(too_big.get_indices_u16().is_err());
+    /// ```
+    pub fn get_indices_u16(&self) -> Result<Vec<u16>, String> {
+        self.indices.iter()
+            .map(|index| u16::try_from(*index).map_err(|_| format!("Index {} doesn't fit into u16", index)))
+            .collect()
+    }
+
+    /// Creates a new [Mesh] from `u32` indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let indices: Vec<u32> = vec![0, 1, 2];
+    ///
+    /// let actual = Mesh::from_indices_u32(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], &indices);
+    ///
+    /// assert_eq!(actual.indices, vec![0, 1, 2]);
+    /// ```
+    pub fn from_indices_u32(coordinates: Vec<f64>, indices: &[u32]) -> Mesh {
+        Mesh::new(coordinates, indices.iter().map(|index| *index as usize).collect())
+    }
+
+    /// Creates a new [Mesh] from `u16` indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let indices: Vec<u16> = vec![0, 1, 2];
+    ///
+    /// let actual = Mesh::from_indices_u16(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], &indices);
+    ///
+    /// assert_eq!(actual.indices, vec![0, 1, 2]);
+    /// ```
+    pub fn from_indices_u16(coordinates: Vec<f64>, indices: &[u16]) -> Mesh {
+        Mesh::new(coordinates, indices.iter().map(|index| *index as usize).collect())
+    }
+}
+
+/// Converts given indices to `u32`, returning an error if any of them doesn't fit.
+pub(crate) fn get_indices_u32(indices: &[usize]) -> Result<Vec<u32>, String> {
+    indices.iter()
+        .map(|index| u32::try_from(*index).map_err(|_| format!("Index {} doesn't fit into u32", index)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_index_width() {
+        assert_eq!(Mesh::new(vec![], vec![]).get_index_width(), IndexWidth::U16);
+        assert_eq!(Mesh::new(vec![], vec![0, 1, 65535]).get_index_width(), IndexWidth::U16);
+        assert_eq!(Mesh::new(vec![], vec![0, 65536, 1]).get_index_width(), IndexWidth::U32);
+    }
+
+    #[test]
+    fn test_get_indices_u16_error() {
+        let input = Mesh::new(vec![], vec![0, 65536, 1]);
+
+        assert_eq!(input.get_indices_u16(), Err("Index 65536 doesn't fit into u16".to_string()));
+        assert_eq!(input.get_indices_u32(), Ok(vec![0, 65536, 1]));
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_get_indices_u32_error() {
+        let input = Mesh::new(vec![], vec![0, 1, u32::MAX as usize + 1]);
+
+        assert_eq!(input.get_indices_u32(), Err("Index 4294967296 doesn't fit into u32".to_string()));
+        assert_eq!(input.get_index_width(), IndexWidth::U64);
+    }
+
+    #[test]
+    fn test_from_indices_round_trip() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2, 2, 1, 3]);
+
+        assert_eq!(Mesh::from_indices_u32(input.coordinates.clone(), &input.get_indices_u32().unwrap()), input);
+        assert_eq!(Mesh::from_indices_u16(input.coordinates.clone(), &input.get_indices_u16().unwrap()), input);
+    }
+}
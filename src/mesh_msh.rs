@@ -0,0 +1,171 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::fs;
+use crate::mesh::Mesh;
+
+/// gmsh element type of the 3-node triangle.
+const MSH_TRIANGLE: u8 = 2;
+
+impl Mesh {
+    /// Creates the content of the gmsh .msh file (version 2.2, ASCII) with the surface triangles
+    /// of the [Mesh], ready to be used by FEM pipelines.
+    ///
+    /// Every face set from `face_groups` becomes a physical surface group with the tag equal to
+    /// the group id + 1 (gmsh tags start at 1). The name of the group is taken from
+    /// `group_names` by the group id, or defaults to `group_<id>`. A [Mesh] without `face_groups`
+    /// is exported as a single group 0.
+    ///
+    /// It returns an error if the number of `face_groups` is different than the number of
+    /// faces, or if a name contains `"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// input.face_groups = Some(vec![0, 1]);
+    ///
+    /// let actual = input.to_msh_string(&["floor"]).unwrap();
+    ///
+    /// let expected = "$MeshFormat
+    /// 2.2 0 8
+    /// $EndMeshFormat
+    /// $PhysicalNames
+    /// 2
+    /// 2 1 \"floor\"
+    /// 2 2 \"group_1\"
+    /// $EndPhysicalNames
+    /// $Nodes
+    /// 4
+    /// 1 0 0 0
+    /// 2 1 0 0
+    /// 3 1 1 0
+    /// 4 0 1 0
+    /// $EndNodes
+    /// $Elements
+    /// 2
+    /// 1 2 2 1 1 1 2 3
+    /// 2 2 2 2 2 1 3 4
+    /// $EndElements
+    /// ";
+    ///
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn to_msh_string(&self, group_names: &[&str]) -> Result<String, String> {
+        let number_of_faces = self.get_number_of_faces();
+        let face_groups = match &self.face_groups {
+            Some(face_groups) if face_groups.len() != number_of_faces => {
+                return Err(format!("The mesh has {} face groups, but {} faces", face_groups.len(), number_of_faces));
+            },
+            Some(face_groups) => face_groups.clone(),
+            None => vec![0; number_of_faces],
+        };
+        if let Some(name) = group_names.iter().find(|name| name.contains('"')) {
+            return Err(format!("Invalid group name for the .msh file: '{}'", name));
+        }
+
+        let mut content = String::new();
+        content.push_str("$MeshFormat\n2.2 0 8\n$EndMeshFormat\n");
+
+        let group_ids: BTreeSet<usize> = face_groups.iter().copied().collect();
+        writeln!(content, "$PhysicalNames\n{}", group_ids.len()).unwrap();
+        for group_id in &group_ids {
+            match group_names.get(*group_id) {
+                Some(name) => writeln!(content, "2 {} \"{}\"", group_id + 1, name).unwrap(),
+                None => writeln!(content, "2 {} \"group_{}\"", group_id + 1, group_id).unwrap(),
+            }
+        }
+        content.push_str("$EndPhysicalNames\n");
+
+        writeln!(content, "$Nodes\n{}", self.get_number_of_vertices()).unwrap();
+        for (vertex_id, vertex) in self.coordinates.chunks(3).enumerate() {
+            writeln!(content, "{} {} {} {}", vertex_id + 1, vertex[0], vertex[1], vertex[2]).unwrap();
+        }
+        content.push_str("$EndNodes\n");
+
+        // Tags of each element: physical group and elementary entity, 1 entity per group.
+        writeln!(content, "$Elements\n{}", number_of_faces).unwrap();
+        for (face_id, face) in self.indices.chunks(3).enumerate() {
+            let tag = face_groups[face_id] + 1;
+            writeln!(content, "{} {} 2 {} {} {} {} {}", face_id + 1, MSH_TRIANGLE, tag, tag, face[0] + 1, face[1] + 1, face[2] + 1).unwrap();
+        }
+        content.push_str("$EndElements\n");
+
+        Ok(content)
+    }
+
+    /// Writes the [Mesh] with its physical groups to the gmsh .msh file, see
+    /// [Mesh::to_msh_string].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// input.to_msh("result.msh", &["wall"]).unwrap();
+    /// ```
+    pub fn to_msh(&self, path: &str, group_names: &[&str]) -> Result<(), String> {
+        let content = self.to_msh_string(group_names)?;
+        fs::write(path, content).map_err(|e| format!("Couldn't write the .msh file: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_msh_string_without_face_groups() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.5], vec![0, 1, 2]);
+
+        let actual = input.to_msh_string(&[]).unwrap();
+
+        let expected = "$MeshFormat\n2.2 0 8\n$EndMeshFormat\n\
+            $PhysicalNames\n1\n2 1 \"group_0\"\n$EndPhysicalNames\n\
+            $Nodes\n3\n1 0 0 0\n2 1 0 0\n3 0 1 0.5\n$EndNodes\n\
+            $Elements\n1\n1 2 2 1 1 1 2 3\n$EndElements\n";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_to_msh_string_skips_unused_groups() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        input.face_groups = Some(vec![2, 2]);
+
+        let actual = input.to_msh_string(&["a", "b", "c"]).unwrap();
+
+        assert!(actual.contains("$PhysicalNames\n1\n2 3 \"c\"\n$EndPhysicalNames\n"));
+        assert!(actual.contains("$Elements\n2\n1 2 2 3 3 1 2 3\n2 2 2 3 3 1 3 4\n$EndElements\n"));
+    }
+
+    #[test]
+    fn test_to_msh_string_wrong_number_of_face_groups() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.face_groups = Some(vec![0, 1]);
+
+        let actual = input.to_msh_string(&[]);
+
+        assert_eq!(actual, Err("The mesh has 2 face groups, but 1 faces".to_string()));
+    }
+
+    #[test]
+    fn test_to_msh_string_invalid_name() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        let actual = input.to_msh_string(&["a \"b\""]);
+
+        assert_eq!(actual, Err("Invalid group name for the .msh file: 'a \"b\"'".to_string()));
+    }
+
+    #[test]
+    fn test_to_msh_string_empty() {
+        let input = Mesh::new(vec![], vec![]);
+
+        let actual = input.to_msh_string(&[]).unwrap();
+
+        assert!(actual.contains("$PhysicalNames\n0\n$EndPhysicalNames\n$Nodes\n0\n$EndNodes\n$Elements\n0\n$EndElements\n"));
+    }
+}
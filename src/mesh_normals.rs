@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::uv_channel::UvChannel;
+use crate::vector::Vector;
+use crate::vertex_normal_weighting::VertexNormalWeighting;
+
+impl Mesh {
+    /// Computes `vertex_normals` by averaging normals of the faces around each vertex.
+    ///
+    /// `weighting` tells how much each face contributes, see [VertexNormalWeighting].
+    ///
+    /// `smoothing_angle` (in radians) controls hard edges: if the angle between normals of 2
+    /// faces sharing an edge is bigger than it, the edge is hard, and the vertices of that edge
+    /// are split, so each side gets its own normal. The split vertices are added at the end of
+    /// the `coordinates` and faces are updated, all the other vertices keep their ids. Per-vertex
+    /// `uvs`, `vertex_colors` and vertex `attributes` are copied to the split vertices. Use
+    /// [std::f64::consts::PI] to keep all the edges smooth.
+    ///
+    /// Corners of faces are joined through shared edges only, so vertices touching with corners
+    /// (non-manifold) are split as well. Degenerate faces have no normal, so they don't affect
+    /// the result and never create hard edges.
+    ///
+    /// # Example
+    ///
+    /// Here is an example with 2 faces folded by 90 degrees along the edge between vertices 0
+    /// and 1.
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vertex_normal_weighting::VertexNormalWeighting;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0], vec![0, 1, 2, 1, 0, 3]);
+    ///
+    /// let mut smooth = input.clone();
+    /// smooth.compute_vertex_normals(VertexNormalWeighting::Area, PI);
+    ///
+    /// let normals = smooth.vertex_normals.as_ref().unwrap();
+    /// let s = 0.5_f64.sqrt();
+    /// assert_eq!(smooth.get_number_of_vertices(), 4);
+    /// assert!((normals[0] - 0.0).abs() < 1e-12 && (normals[1] - s).abs() < 1e-12 && (normals[2] - s).abs() < 1e-12);
+    /// assert_eq!(&normals[6..9], &[0.0, 0.0, 1.0]);
+    ///
+    /// let mut hard = input.clone();
+    /// hard.compute_vertex_normals(VertexNormalWeighting::Area, PI / 4.0);
+    ///
+    /// assert_eq!(hard.get_number_of_vertices(), 6);
+    /// assert_eq!(hard.indices, vec![0, 1, 2, 4, 5, 3]);
+    /// assert_eq!(&hard.vertex_normals.unwrap()[9..12], &[0.0, 1.0, 0.0]);
+    /// ```
+    pub fn compute_vertex_normals(&mut self, weighting: VertexNormalWeighting, smoothing_angle: f64) {
+        let number_of_corners = self.indices.len() - self.indices.len() % 3;
+        let triangles = self.to_triangles();
+        let face_normals: Vec<Option<Vector>> = triangles.iter().map(|triangle| triangle.get_normal_vector_unitized_checked()).collect();
+
+        // Corner id is face_id * 3 + corner, corners sharing smooth edges are joined into 1 vertex.
+        let mut corner_parents: Vec<usize> = (0..number_of_corners).collect();
+        let mut edge_corners: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+        for corner in 0..number_of_corners {
+            let next = corner - corner % 3 + (corner + 1) % 3;
+            let (start, end) = (self.indices[corner], self.indices[next]);
+            if start < end {
+                edge_corners.entry((start, end)).or_default().push((corner, next));
+            } else {
+                edge_corners.entry((end, start)).or_default().push((next, corner));
+            }
+        }
+        for corners in edge_corners.values() {
+            for i in 0..corners.len() {
+                for j in i + 1..corners.len() {
+                    let is_smooth = match (face_normals[corners[i].0 / 3], face_normals[corners[j].0 / 3]) {
+                        (Some(first), Some(second)) => first.get_angle(&second) <= smoothing_angle,
+                        _ => true,
+                    };
+                    if is_smooth {
+                        join_corners(&mut corner_parents, corners[i].0, corners[j].0);
+                        join_corners(&mut corner_parents, corners[i].1, corners[j].1);
+                    }
+                }
+            }
+        }
+
+        let number_of_vertices = self.get_number_of_vertices();
+        let mut source_vertex_ids: Vec<usize> = (0..number_of_vertices).collect();
+        let mut is_vertex_used = vec![false; number_of_vertices];
+        let mut new_vertex_ids: HashMap<usize, usize> = HashMap::new();
+        let mut normals: Vec<Vector> = vec![Vector::zero(); self.get_number_of_vertices()];
+        for corner in 0..number_of_corners {
+            let vertex_id = self.indices[corner];
+            let root = find_corner_root(&mut corner_parents, corner);
+            let new_vertex_id = match new_vertex_ids.get(&root) {
+                Some(new_vertex_id) => *new_vertex_id,
+                None => {
+                    let new_vertex_id = if is_vertex_used[vertex_id] {
+                        self.coordinates.extend_from_within(vertex_id * 3..vertex_id * 3 + 3);
+                        normals.push(Vector::zero());
+                        source_vertex_ids.push(vertex_id);
+                        normals.len() - 1
+                    } else {
+                        is_vertex_used[vertex_id] = true;
+                        vertex_id
+                    };
+                    new_vertex_ids.insert(root, new_vertex_id);
+                    new_vertex_id
+                },
+            };
+            self.indices[corner] = new_vertex_id;
+
+            let face_id = corner / 3;
+            if let Some(face_normal) = face_normals[face_id] {
+                let triangle = &triangles[face_id];
+                let weight = match weighting {
+                    VertexNormalWeighting::Area => triangle.get_area(),
+                    VertexNormalWeighting::Angle => {
+                        let points = [triangle.first_point, triangle.second_point, triangle.third_point];
+                        let vertex = points[corner % 3];
+                        let previous = Vector::from_2_points(&vertex, &points[(corner + 2) % 3]);
+                        let next = Vector::from_2_points(&vertex, &points[(corner + 1) % 3]);
+                        previous.get_angle(&next)
+                    },
+                };
+                let normal = &mut normals[new_vertex_id];
+                normal.x += face_normal.x * weight;
+                normal.y += face_normal.y * weight;
+                normal.z += face_normal.z * weight;
+            }
+        }
+
+        let mut vertex_normals: Vec<f64> = Vec::with_capacity(normals.len() * 3);
+        for normal in normals {
+            if normal.is_absolute_zero_length() {
+                vertex_normals.extend_from_slice(&[0.0, 0.0, 0.0]);
+            } else {
+                let unitized = normal.get_unitized();
+                vertex_normals.extend_from_slice(&[unitized.x, unitized.y, unitized.z]);
+            }
+        }
+        self.vertex_normals = Some(vertex_normals);
+
+        if source_vertex_ids.len() > number_of_vertices {
+            if let Some(UvChannel::PerVertex(uvs)) = &self.uvs {
+                self.uvs = Some(UvChannel::PerVertex(source_vertex_ids.iter().flat_map(|vertex_id| uvs[vertex_id * 2..vertex_id * 2 + 2].to_vec()).collect()));
+            }
+            self.vertex_colors = self.get_vertex_colors_by_vertex_ids(source_vertex_ids.iter().copied());
+            let face_ids: Vec<usize> = (0..self.get_number_of_faces()).collect();
+            self.attributes = self.attributes.get_by_ids(&source_vertex_ids, number_of_vertices, &face_ids, face_ids.len());
+        }
+        self.invalidate_cache();
+    }
+}
+
+fn find_corner_root(corner_parents: &mut [usize], corner: usize) -> usize {
+    let mut current = corner;
+    while corner_parents[current] != current {
+        corner_parents[current] = corner_parents[corner_parents[current]];
+        current = corner_parents[current];
+    }
+    current
+}
+
+fn join_corners(corner_parents: &mut [usize], first: usize, second: usize) {
+    let first_root = find_corner_root(corner_parents, first);
+    let second_root = find_corner_root(corner_parents, second);
+    if first_root != second_root {
+        corner_parents[first_root.max(second_root)] = first_root.min(second_root);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use crate::mesh_attributes::AttributeValues;
+    use super::*;
+
+    fn get_normal(mesh: &Mesh, vertex_id: usize) -> Vector {
+        let normals = mesh.vertex_normals.as_ref().unwrap();
+        Vector::new(normals[vertex_id * 3], normals[vertex_id * 3 + 1], normals[vertex_id * 3 + 2])
+    }
+
+    #[test]
+    fn test_compute_vertex_normals_planar_keeps_topology() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        let expected_indices = input.indices.clone();
+
+        input.compute_vertex_normals(VertexNormalWeighting::Angle, 0.0);
+
+        assert_eq!(input.indices, expected_indices);
+        assert_eq!(input.vertex_normals, Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_compute_vertex_normals_area_vs_angle_weighting() {
+        // Vertex 0 is shared by a big face in XY plane and a small face in XZ plane
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 1.0],
+            vec![0, 1, 2, 1, 0, 3]);
+
+        let mut area = input.clone();
+        area.compute_vertex_normals(VertexNormalWeighting::Area, PI);
+        let mut angle = input.clone();
+        angle.compute_vertex_normals(VertexNormalWeighting::Angle, PI);
+
+        assert!(get_normal(&area, 0).eq_with_tolerance(&Vector::new(0.0, 5.0, 50.0).get_unitized(), 1e-12));
+        assert!(get_normal(&angle, 0).eq_with_tolerance(&Vector::new(0.0, 1.0, 1.0).get_unitized(), 1e-12));
+    }
+
+    #[test]
+    fn test_compute_vertex_normals_cube_hard_edges() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+                 0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0, 1.0, 1.0],
+            vec![0, 2, 1, 0, 3, 2, 4, 5, 6, 4, 6, 7, 0, 1, 5, 0, 5, 4,
+                 1, 2, 6, 1, 6, 5, 2, 3, 7, 2, 7, 6, 3, 0, 4, 3, 4, 7]);
+
+        input.compute_vertex_normals(VertexNormalWeighting::Angle, PI / 6.0);
+
+        assert_eq!(input.get_number_of_vertices(), 24);
+        let triangles = input.to_triangles();
+        for (face_id, triangle) in triangles.iter().enumerate() {
+            let face_normal = triangle.get_normal_vector_unitized();
+            for vertex_id in &input.indices[face_id * 3..face_id * 3 + 3] {
+                assert!(get_normal(&input, *vertex_id).eq_with_tolerance(&face_normal, 1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn test_compute_vertex_normals_degenerate_and_unused_vertices() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0, 5.0, 5.0, 5.0],
+            vec![0, 1, 2, 1, 3, 0]);
+
+        input.compute_vertex_normals(VertexNormalWeighting::Angle, 0.0);
+
+        assert_eq!(input.get_number_of_vertices(), 5);
+        assert!(get_normal(&input, 0).eq_with_tolerance(&Vector::z_unit(), 1e-12));
+        assert!(get_normal(&input, 3).eq_with_tolerance(&Vector::zero(), 0.0));
+        assert!(get_normal(&input, 4).eq_with_tolerance(&Vector::zero(), 0.0));
+    }
+
+    #[test]
+    fn test_compute_vertex_normals_empty() {
+        let mut input = Mesh::new(vec![], vec![]);
+
+        input.compute_vertex_normals(VertexNormalWeighting::Area, PI);
+
+        assert_eq!(input.vertex_normals, Some(vec![]));
+    }
+
+    #[test]
+    fn test_compute_vertex_normals_split_vertices_keep_per_vertex_data() {
+        let mut mesh = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+            vec![0, 1, 2, 0, 3, 1]);
+        mesh.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0]));
+        mesh.vertex_colors = Some(vec![0, 0, 0, 255, 1, 1, 1, 255, 2, 2, 2, 255, 3, 3, 3, 255]);
+        mesh.attributes.vertex.insert(String::from("temperature"), AttributeValues::U32(vec![10, 11, 12, 13]));
+
+        mesh.compute_vertex_normals(VertexNormalWeighting::Area, PI / 4.0);
+
+        assert_eq!(mesh.indices, vec![0, 1, 2, 4, 3, 5]);
+        assert_eq!(mesh.uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0])));
+        assert_eq!(mesh.vertex_colors, Some(vec![0, 0, 0, 255, 1, 1, 1, 255, 2, 2, 2, 255, 3, 3, 3, 255, 0, 0, 0, 255, 1, 1, 1, 255]));
+        assert_eq!(mesh.attributes.vertex["temperature"], AttributeValues::U32(vec![10, 11, 12, 13, 10, 11]));
+    }
+}
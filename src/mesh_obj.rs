@@ -0,0 +1,352 @@
+use std::fmt::Write;
+use std::fs;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::triangle::Triangle;
+use crate::uv_channel::UvChannel;
+
+impl Mesh {
+    /// Reads the .obj file, see [Mesh::from_obj_reader].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::from_obj("scan.obj").unwrap();
+    /// ```
+    pub fn from_obj(path: &str) -> Result<Mesh, String> {
+        let file = File::open(path).map_err(|e| format!("Couldn't read the .obj file: {}", e))?;
+        Self::from_obj_reader(file)
+    }
+
+    /// Reads the .obj content from the reader into a single [Mesh].
+    ///
+    /// The content is streamed line by line, so only the resulting [Mesh] is kept in memory.
+    /// Vertices (`v`) and faces (`f`) are read, other statements (texture coordinates, normals,
+    /// groups, materials) are skipped. Polygonal faces are triangulated as fans, so they should
+    /// be convex. The OBJ vertex indexing is kept, so the result is as welded as the file is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let content = "# square
+    /// v 0 0 0
+    /// v 1 0 0
+    /// v 1 1 0
+    /// v 0 1 0
+    /// vn 0 0 1
+    /// f 1//1 2//1 3//1 4//1
+    /// ";
+    ///
+    /// let actual = Mesh::from_obj_reader(content.as_bytes()).unwrap();
+    ///
+    /// let expected = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn from_obj_reader<R: Read>(reader: R) -> Result<Mesh, String> {
+        let mut coordinates: Vec<f64> = Vec::new();
+        let mut indices: Vec<usize> = Vec::new();
+        read_obj_statements(reader, |statement| match statement {
+            ObjStatement::Vertex(point) => coordinates.extend_from_slice(&[point.x, point.y, point.z]),
+            ObjStatement::Face(face) => indices.extend_from_slice(&face),
+        })?;
+        Ok(Mesh::new(coordinates, indices))
+    }
+
+    /// Streams triangles of the .obj content from the reader into the `sink`, and returns the
+    /// number of triangles.
+    ///
+    /// Vertex positions have to be kept to resolve face indices, but faces are never stored, so
+    /// the memory usage doesn't grow with the number of faces. Polygonal faces are triangulated
+    /// as fans.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let content = "v 0 0 0\nv 2 0 0\nv 2 2 0\nv 0 2 0\nf 1 2 3 4\n";
+    ///
+    /// let mut area = 0.0;
+    /// let actual = Mesh::read_obj(content.as_bytes(), |triangle| area += triangle.get_area()).unwrap();
+    ///
+    /// assert_eq!(actual, 2);
+    /// assert!((area - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn read_obj<R: Read>(reader: R, mut sink: impl FnMut(Triangle)) -> Result<usize, String> {
+        let mut points: Vec<Point> = Vec::new();
+        let mut number_of_triangles = 0;
+        read_obj_statements(reader, |statement| match statement {
+            ObjStatement::Vertex(point) => points.push(point),
+            ObjStatement::Face(face) => {
+                sink(Triangle::new(points[face[0]], points[face[1]], points[face[2]]));
+                number_of_triangles += 1;
+            },
+        })?;
+        Ok(number_of_triangles)
+    }
+}
+
+impl Mesh {
+    /// Creates the content of the .obj file with the [Mesh].
+    ///
+    /// `uvs` are written as texture coordinates (`vt`), per vertex or per face corner, and
+    /// `vertex_normals` as normals (`vn`). If the [Mesh] has `face_groups`, each group starts
+    /// with the group statement named `group_<id>`.
+    ///
+    /// Returns an error if the number of `face_groups` is different than the number of faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::uv_channel::UvChannel;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.5], vec![0, 1, 2]);
+    /// input.uvs = Some(UvChannel::PerCorner(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+    ///
+    /// let actual = input.to_obj_string().unwrap();
+    ///
+    /// let expected = "# meshmeshmesh
+    /// v 0 0 0
+    /// v 1 0 0
+    /// v 0 1 0.5
+    /// vt 0 0
+    /// vt 1 0
+    /// vt 0 1
+    /// f 1/1 2/2 3/3
+    /// ";
+    ///
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn to_obj_string(&self) -> Result<String, String> {
+        let mut content = String::from("# meshmeshmesh\n");
+
+        for vertex in self.coordinates.chunks(3) {
+            writeln!(content, "v {} {} {}", vertex[0], vertex[1], vertex[2]).unwrap();
+        }
+        let uvs = match &self.uvs {
+            Some(UvChannel::PerVertex(uvs)) | Some(UvChannel::PerCorner(uvs)) => uvs.as_slice(),
+            None => &[],
+        };
+        for uv in uvs.chunks(2) {
+            writeln!(content, "vt {} {}", uv[0], uv[1]).unwrap();
+        }
+        if let Some(normals) = &self.vertex_normals {
+            for normal in normals.chunks(3) {
+                writeln!(content, "vn {} {} {}", normal[0], normal[1], normal[2]).unwrap();
+            }
+        }
+
+        if let Some(face_groups) = &self.face_groups {
+            if face_groups.len() != self.get_number_of_faces() {
+                return Err("The number of face groups should be equal to the number of faces".to_string());
+            }
+        }
+
+        let mut current_group_id: Option<usize> = None;
+        for (face_id, face) in self.indices.chunks(3).enumerate() {
+            if let Some(face_groups) = &self.face_groups {
+                if current_group_id != Some(face_groups[face_id]) {
+                    current_group_id = Some(face_groups[face_id]);
+                    writeln!(content, "g group_{}", face_groups[face_id]).unwrap();
+                }
+            }
+            content.push('f');
+            for (i, vertex_id) in face.iter().enumerate() {
+                let uv_id = match &self.uvs {
+                    Some(UvChannel::PerVertex(_)) => Some(vertex_id + 1),
+                    Some(UvChannel::PerCorner(_)) => Some(face_id * 3 + i + 1),
+                    None => None,
+                };
+                match (uv_id, self.vertex_normals.is_some()) {
+                    (Some(uv_id), true) => write!(content, " {}/{}/{}", vertex_id + 1, uv_id, vertex_id + 1).unwrap(),
+                    (Some(uv_id), false) => write!(content, " {}/{}", vertex_id + 1, uv_id).unwrap(),
+                    (None, true) => write!(content, " {}//{}", vertex_id + 1, vertex_id + 1).unwrap(),
+                    (None, false) => write!(content, " {}", vertex_id + 1).unwrap(),
+                }
+            }
+            content.push('\n');
+        }
+
+        Ok(content)
+    }
+
+    /// Writes the [Mesh] to the .obj file, see [Mesh::to_obj_string].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// input.to_obj("result.obj").unwrap();
+    /// ```
+    pub fn to_obj(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_obj_string()?).map_err(|e| format!("Couldn't write the .obj file: {}", e))
+    }
+}
+
+enum ObjStatement {
+    Vertex(Point),
+    /// Triangle with already resolved, 0-based vertex ids.
+    Face([usize; 3]),
+}
+
+/// Reads vertices and triangulated faces of the .obj content, resolving relative indices.
+fn read_obj_statements<R: Read>(reader: R, mut sink: impl FnMut(ObjStatement)) -> Result<(), String> {
+    let reader = BufReader::new(reader);
+    let mut number_of_vertices = 0;
+    let mut face: Vec<usize> = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| format!("Couldn't read the .obj content: {}", e))?;
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("v") => {
+                let values = words
+                    .take(3)
+                    .map(|word| word.parse::<f64>().map_err(|e| format!("Invalid vertex at line {}: {}", i + 1, e)))
+                    .collect::<Result<Vec<f64>, String>>()?;
+                if values.len() != 3 {
+                    return Err(format!("Vertex at line {} should have 3 coordinates", i + 1));
+                }
+                sink(ObjStatement::Vertex(Point::new(values[0], values[1], values[2])));
+                number_of_vertices += 1;
+            },
+            Some("f") => {
+                face.clear();
+                for word in words {
+                    let index = word.split('/').next().unwrap_or("");
+                    let index = index.parse::<i64>().map_err(|e| format!("Invalid face index at line {}: {}", i + 1, e))?;
+                    let vertex_id = if index > 0 { index - 1 } else { number_of_vertices as i64 + index };
+                    if index == 0 || vertex_id < 0 || vertex_id >= number_of_vertices as i64 {
+                        return Err(format!("Face index {} at line {} is out of range", index, i + 1));
+                    }
+                    face.push(vertex_id as usize);
+                }
+                if face.len() < 3 {
+                    return Err(format!("Face at line {} should have at least 3 vertices", i + 1));
+                }
+                for j in 1..face.len() - 1 {
+                    sink(ObjStatement::Face([face[0], face[j], face[j + 1]]));
+                }
+            },
+            _ => {},
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_obj_reader_negative_indices_and_texture_coordinates() {
+        let content = "v 0 0 0\nv 1 0 0\nv 0 1 0\nvt 0 0\nvt 1 0\nvt 0 1\ng part\nusemtl red\nf -3/1 -2/2 -1/3\n";
+
+        let actual = Mesh::from_obj_reader(content.as_bytes()).unwrap();
+
+        assert_eq!(actual, Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn test_from_obj_reader_index_out_of_range() {
+        let content = "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 4\n";
+
+        let actual = Mesh::from_obj_reader(content.as_bytes());
+
+        assert_eq!(actual, Err("Face index 4 at line 4 is out of range".to_string()));
+    }
+
+    #[test]
+    fn test_from_obj_reader_face_with_2_vertices() {
+        let content = "v 0 0 0\nv 1 0 0\nf 1 2\n";
+
+        let actual = Mesh::from_obj_reader(content.as_bytes());
+
+        assert_eq!(actual, Err("Face at line 3 should have at least 3 vertices".to_string()));
+    }
+
+    #[test]
+    fn test_from_obj_reader_invalid_vertex() {
+        let actual = Mesh::from_obj_reader("v 0 a 0\n".as_bytes());
+
+        assert!(actual.unwrap_err().starts_with("Invalid vertex at line 1"));
+    }
+
+    #[test]
+    fn test_read_obj_same_triangles_as_from_obj_reader() {
+        let content = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nv 0.5 0.5 1\nf 1 2 5\nf 2 3 5\nf 3 4 5\nf 4 1 5\n";
+
+        let mut triangles: Vec<Triangle> = Vec::new();
+        Mesh::read_obj(content.as_bytes(), |triangle| triangles.push(triangle)).unwrap();
+
+        assert_eq!(triangles, Mesh::from_obj_reader(content.as_bytes()).unwrap().to_triangles());
+    }
+
+    #[test]
+    fn test_to_obj_string_per_vertex_uvs_and_normals() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+        input.vertex_normals = Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+
+        let actual = input.to_obj_string().unwrap();
+
+        assert!(actual.contains("vt 1 0\n"));
+        assert!(actual.contains("vn 0 0 1\n"));
+        assert!(actual.ends_with("f 1/1/1 2/2/2 3/3/3\n"));
+    }
+
+    #[test]
+    fn test_to_obj_string_read_back() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.5, 0.0, 0.0, 1.5, 1.0, 0.0, 0.0, 1.0, -0.25], vec![0, 1, 2, 0, 2, 3]);
+        let mut with_normals = input.clone();
+        with_normals.vertex_normals = Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+
+        assert_eq!(Mesh::from_obj_reader(input.to_obj_string().unwrap().as_bytes()).unwrap(), input);
+        assert_eq!(Mesh::from_obj_reader(with_normals.to_obj_string().unwrap().as_bytes()).unwrap(), input);
+    }
+
+    #[test]
+    fn test_to_obj_string_with_face_groups() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3, 0, 1, 3]);
+        input.face_groups = Some(vec![1, 1, 0]);
+
+        let actual = input.to_obj_string().unwrap();
+
+        let expected = "# meshmeshmesh
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+g group_1
+f 1 2 3
+f 1 3 4
+g group_0
+f 1 2 4
+";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_to_obj_wrong_number_of_face_groups_error() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.face_groups = Some(vec![0, 1]);
+
+        let expected = "The number of face groups should be equal to the number of faces".to_string();
+        assert_eq!(input.to_obj_string(), Err(expected.clone()));
+        assert_eq!(input.to_obj("never_written.obj"), Err(expected));
+        assert!(!std::path::Path::new("never_written.obj").exists());
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::vector::Vector;
+
+/// The 6 tetrahedra sharing the main diagonal (0-7) of a grid cube.
+///
+/// Cube corners are numbered with bits: x = 1, y = 2, z = 4.
+const CUBE_TETRAHEDRA: [[usize; 4]; 6] = [
+    [0, 1, 3, 7],
+    [0, 3, 2, 7],
+    [0, 2, 6, 7],
+    [0, 6, 4, 7],
+    [0, 4, 5, 7],
+    [0, 5, 1, 7],
+];
+
+impl Mesh {
+    /// Creates a new [Mesh] which is a rounded offset of the given one.
+    ///
+    /// It approximates the Minkowski sum of the solid with a sphere of radius `distance`, so
+    /// all the convex edges and corners get rounded. It's useful for e.g. clearance volumes.
+    ///
+    /// The result is computed from the signed distance field sampled on a regular grid, which
+    /// is then polygonized with marching tetrahedra. Thanks to that it's always watertight,
+    /// welded, and free of self-intersections, no matter how complex the input is.
+    ///
+    /// `segments` is the number of grid cells per `distance`. The bigger it is, the smoother
+    /// and more precise the result, but the cost grows with the cube of it.
+    ///
+    /// The input [Mesh] should be closed and oriented outside, as it's used to determine which
+    /// points are inside the solid.
+    ///
+    /// It panics if `distance` is not positive or `segments` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         2.0,0.0,0.0,
+    ///         0.0,2.0,0.0,
+    ///         0.0,0.0,2.0,
+    ///     ],
+    ///     vec![
+    ///         0,2,1,
+    ///         0,1,3,
+    ///         1,2,3,
+    ///         0,3,2,
+    ///     ]);
+    ///
+    /// let actual = input.get_rounded_offset(1.0, 2);
+    ///
+    /// assert!(actual.get_edges_with_missing_neighbour().is_empty());
+    /// assert!(actual.get_non_manifold_edges().is_empty());
+    ///
+    /// let bounding_box = actual.get_bounding_box();
+    /// assert!((bounding_box.min_x + 1.0).abs() < 0.1);
+    /// assert!((bounding_box.max_z - 3.0).abs() < 0.1);
+    /// ```
+    pub fn get_rounded_offset(&self, distance: f64, segments: usize) -> Mesh {
+        if distance <= 0.0 {
+            panic!("Rounded offset distance should be greater than 0.0");
+        }
+        if segments == 0 {
+            panic!("Rounded offset needs at least 1 segment");
+        }
+
+        let cell_size = distance / segments as f64;
+        let bounding_box = self.get_bounding_box();
+        let margin = distance + 2.0 * cell_size;
+        let origin = Point::new(bounding_box.min_x - margin, bounding_box.min_y - margin, bounding_box.min_z - margin);
+        let number_of_cells = [
+            ((bounding_box.max_x - bounding_box.min_x + 2.0 * margin) / cell_size).ceil() as usize,
+            ((bounding_box.max_y - bounding_box.min_y + 2.0 * margin) / cell_size).ceil() as usize,
+            ((bounding_box.max_z - bounding_box.min_z + 2.0 * margin) / cell_size).ceil() as usize,
+        ];
+        let number_of_nodes = [number_of_cells[0] + 1, number_of_cells[1] + 1, number_of_cells[2] + 1];
+        let get_node_point = |node_id: usize| {
+            let x = node_id % number_of_nodes[0];
+            let y = (node_id / number_of_nodes[0]) % number_of_nodes[1];
+            let z = node_id / (number_of_nodes[0] * number_of_nodes[1]);
+            Point::new(origin.x + x as f64 * cell_size, origin.y + y as f64 * cell_size, origin.z + z as f64 * cell_size)
+        };
+
+        // Values are shifted by the distance, so the offset surface is where they cross 0.0.
+        let values: Vec<f64> = (0..number_of_nodes[0] * number_of_nodes[1] * number_of_nodes[2])
+            .map(|node_id| self.get_signed_distance(&get_node_point(node_id)) - distance)
+            .collect();
+
+        get_isosurface(&origin, cell_size, number_of_cells, &values)
+    }
+}
+
+/// Polygonizes the surface where `values` sampled on grid nodes cross 0.0 with marching
+/// tetrahedra. Negative values are inside.
+///
+/// The grid starts at the `origin` and has `number_of_cells` cells along each axis, so
+/// `values` are given for each of the (cells + 1) nodes, with x changing the fastest.
+///
+/// The result is welded and oriented outside. If values on the boundary of the grid are all
+/// positive, it's also watertight and manifold.
+pub(crate) fn get_isosurface(origin: &Point, cell_size: f64, number_of_cells: [usize; 3], values: &[f64]) -> Mesh {
+    let number_of_nodes = [number_of_cells[0] + 1, number_of_cells[1] + 1, number_of_cells[2] + 1];
+    let get_node_id = |x: usize, y: usize, z: usize| (z * number_of_nodes[1] + y) * number_of_nodes[0] + x;
+    let get_node_point = |node_id: usize| {
+        let x = node_id % number_of_nodes[0];
+        let y = (node_id / number_of_nodes[0]) % number_of_nodes[1];
+        let z = node_id / (number_of_nodes[0] * number_of_nodes[1]);
+        Point::new(origin.x + x as f64 * cell_size, origin.y + y as f64 * cell_size, origin.z + z as f64 * cell_size)
+    };
+
+    // Exact zeros would create degenerate faces, so they're nudged outside.
+    let nudge = cell_size * 1e-6;
+    let values: Vec<f64> = values.iter().map(|value| if value.abs() < nudge { nudge } else { *value }).collect();
+
+    let mut coordinates: Vec<f64> = Vec::new();
+    let mut indices: Vec<usize> = Vec::new();
+    let mut edge_vertex_ids: HashMap<(usize, usize), usize> = HashMap::new();
+
+    for z in 0..number_of_cells[2] {
+        for y in 0..number_of_cells[1] {
+            for x in 0..number_of_cells[0] {
+                let corners: [usize; 8] = std::array::from_fn(|corner| get_node_id(x + (corner & 1), y + ((corner >> 1) & 1), z + ((corner >> 2) & 1)));
+                for tetrahedron in CUBE_TETRAHEDRA {
+                    let nodes = tetrahedron.map(|corner| corners[corner]);
+                    let inside: Vec<usize> = nodes.iter().copied().filter(|node_id| values[*node_id] < 0.0).collect();
+                    let outside: Vec<usize> = nodes.iter().copied().filter(|node_id| values[*node_id] >= 0.0).collect();
+
+                    let mut get_vertex_id = |a: usize, b: usize| {
+                        let key = if a < b { (a, b) } else { (b, a) };
+                        *edge_vertex_ids.entry(key).or_insert_with(|| {
+                            let (start, end) = (get_node_point(key.0), get_node_point(key.1));
+                            let t = values[key.0] / (values[key.0] - values[key.1]);
+                            let point = start + Vector::from_2_points(&start, &end) * t;
+                            coordinates.extend_from_slice(&[point.x, point.y, point.z]);
+                            coordinates.len() / 3 - 1
+                        })
+                    };
+
+                    let faces: Vec<[usize; 3]> = match inside.len() {
+                        1 => vec![[get_vertex_id(inside[0], outside[0]), get_vertex_id(inside[0], outside[1]), get_vertex_id(inside[0], outside[2])]],
+                        3 => vec![[get_vertex_id(inside[0], outside[0]), get_vertex_id(inside[1], outside[0]), get_vertex_id(inside[2], outside[0])]],
+                        2 => {
+                            let a = get_vertex_id(inside[0], outside[0]);
+                            let b = get_vertex_id(inside[0], outside[1]);
+                            let c = get_vertex_id(inside[1], outside[1]);
+                            let d = get_vertex_id(inside[1], outside[0]);
+                            vec![[a, b, c], [a, c, d]]
+                        },
+                        _ => Vec::new(),
+                    };
+
+                    let inside_centroid = get_centroid(&inside.iter().map(|node_id| get_node_point(*node_id)).collect::<Vec<Point>>());
+                    let outside_centroid = get_centroid(&outside.iter().map(|node_id| get_node_point(*node_id)).collect::<Vec<Point>>());
+                    let outward = Vector::from_2_points(&inside_centroid, &outside_centroid);
+                    for face in faces {
+                        let [a, b, c] = face.map(|vertex_id| Point::new(coordinates[vertex_id * 3], coordinates[vertex_id * 3 + 1], coordinates[vertex_id * 3 + 2]));
+                        let normal = Vector::from_2_points(&a, &b).get_cross_product(&Vector::from_2_points(&a, &c));
+                        if normal.get_dot_product(&outward) >= 0.0 {
+                            indices.extend_from_slice(&face);
+                        } else {
+                            indices.extend_from_slice(&[face[0], face[2], face[1]]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Mesh::new(coordinates, indices)
+}
+
+fn get_centroid(points: &[Point]) -> Point {
+    let mut sum = Vector::zero();
+    for point in points {
+        sum = sum + point.to_vector();
+    }
+    (sum * (1.0 / points.len() as f64)).to_point()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use crate::test_utils::get_cube;
+    use super::*;
+
+    fn get_volume(mesh: &Mesh) -> f64 {
+        mesh.to_triangles().iter().map(|triangle| {
+            triangle.first_point.to_vector().get_dot_product(&triangle.second_point.to_vector().get_cross_product(&triangle.third_point.to_vector())) / 6.0
+        }).sum()
+    }
+
+    #[test]
+    fn test_get_rounded_offset_box_is_watertight() {
+        let input = get_cube(0.0, 4.0);
+
+        let actual = input.get_rounded_offset(1.0, 3);
+
+        assert!(actual.get_edges_with_missing_neighbour().is_empty());
+        assert!(actual.get_non_manifold_edges().is_empty());
+        assert!(actual.is_connected());
+    }
+
+    #[test]
+    fn test_get_rounded_offset_box_volume() {
+        let input = get_cube(0.0, 4.0);
+
+        let actual = input.get_rounded_offset(1.0, 4);
+
+        // Box + 6 slabs + 12 quarter cylinders + 8 sphere octants
+        let expected = 64.0 + 6.0 * 16.0 + 12.0 * 4.0 * PI / 4.0 + 4.0 / 3.0 * PI;
+        let volume = get_volume(&actual);
+        assert!(volume > 0.0);
+        assert!((volume - expected).abs() / expected < 0.02);
+    }
+
+    #[test]
+    fn test_get_rounded_offset_vertices_at_distance() {
+        let input = get_cube(0.0, 4.0);
+
+        let actual = input.get_rounded_offset(1.0, 4);
+
+        for point in actual.to_points() {
+            let distance = input.get_signed_distance(&point);
+            assert!((distance - 1.0).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_get_signed_distance() {
+        let input = get_cube(0.0, 4.0);
+
+        assert!((input.get_signed_distance(&Point::new(2.0, 2.0, 1.0)) + 1.0).abs() < 1e-9);
+        assert!((input.get_signed_distance(&Point::new(2.0, 2.0, 6.0)) - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rounded offset distance should be greater than 0.0")]
+    fn test_get_rounded_offset_negative_distance_panic() {
+        get_cube(0.0, 4.0).get_rounded_offset(-1.0, 2);
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::VecDeque;
+use crate::mesh::Mesh;
+use crate::uv_channel::UvChannel;
+
+/// The size of the simulated vertex cache used by [Mesh::get_with_optimized_vertex_cache].
+const VERTEX_CACHE_SIZE: usize = 32;
+const CACHE_DECAY_POWER: f64 = 1.5;
+const LAST_TRIANGLE_SCORE: f64 = 0.75;
+const VALENCE_BOOST_SCALE: f64 = 2.0;
+const VALENCE_BOOST_POWER: f64 = 0.5;
+
+impl Mesh {
+    /// Creates a new [Mesh] with faces reordered to improve the GPU post-transform vertex cache
+    /// utilization.
+    ///
+    /// It uses Tom Forsyth's linear-speed vertex cache optimization: faces using vertices
+    /// recently put into the (simulated) cache are emitted first, so fewer vertices have to be
+    /// transformed again during rendering.
+    ///
+    /// Only the order of faces is changed. Vertices and the order of vertices inside each face
+    /// (so also face orientation) stay untouched. Face data (`face_groups`, per corner `uvs`
+    /// and face attributes) is reordered together with the faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Grid of 16x16 quads with faces stored in a cache-unfriendly order
+    /// let size = 16;
+    /// let mut coordinates = Vec::new();
+    /// for y in 0..=size {
+    ///     for x in 0..=size {
+    ///         coordinates.extend_from_slice(&[x as f64, y as f64, 0.0]);
+    ///     }
+    /// }
+    /// let mut indices = Vec::new();
+    /// for x in 0..size {
+    ///     for y in 0..size {
+    ///         let a = y * (size + 1) + x;
+    ///         indices.extend_from_slice(&[a, a + 1, a + size + 2, a, a + size + 2, a + size + 1]);
+    ///     }
+    /// }
+    /// let input = Mesh::new(coordinates, indices);
+    ///
+    /// let actual = input.get_with_optimized_vertex_cache();
+    ///
+    /// assert_eq!(actual.coordinates, input.coordinates);
+    /// assert_eq!(actual.get_number_of_faces(), input.get_number_of_faces());
+    /// assert!(actual.get_average_cache_miss_ratio(16) < input.get_average_cache_miss_ratio(16));
+    /// ```
+    pub fn get_with_optimized_vertex_cache(&self) -> Mesh {
+        let number_of_vertices = self.get_number_of_vertices();
+        let number_of_faces = self.get_number_of_faces();
+
+        let mut vertex_faces: Vec<Vec<usize>> = vec![Vec::new(); number_of_vertices];
+        for face_id in 0..number_of_faces {
+            for vertex_id in self.get_unique_face_vertex_ids(face_id) {
+                vertex_faces[vertex_id].push(face_id);
+            }
+        }
+
+        let mut remaining_valences: Vec<usize> = vertex_faces.iter().map(|faces| faces.len()).collect();
+        let mut cache_positions: Vec<Option<usize>> = vec![None; number_of_vertices];
+        let mut vertex_scores: Vec<f64> = (0..number_of_vertices).map(|vertex_id| get_vertex_cache_score(None, remaining_valences[vertex_id])).collect();
+        let mut face_scores: Vec<f64> = (0..number_of_faces).map(|face_id| {
+            self.get_unique_face_vertex_ids(face_id).iter().map(|vertex_id| vertex_scores[*vertex_id]).sum()
+        }).collect();
+        let mut is_face_added = vec![false; number_of_faces];
+
+        let mut cache: Vec<usize> = Vec::with_capacity(VERTEX_CACHE_SIZE + 3);
+        let mut indices: Vec<usize> = Vec::with_capacity(self.indices.len());
+        let mut face_order: Vec<usize> = Vec::with_capacity(number_of_faces);
+        let mut best_face: Option<usize> = None;
+        let mut next_unadded_face = 0;
+
+        for _ in 0..number_of_faces {
+            let face_id = match best_face {
+                Some(face_id) => face_id,
+                None => {
+                    // Nothing useful in the cache, so we start again from the first face left.
+                    while is_face_added[next_unadded_face] {
+                        next_unadded_face += 1;
+                    }
+                    next_unadded_face
+                }
+            };
+
+            is_face_added[face_id] = true;
+            face_order.push(face_id);
+            indices.extend_from_slice(&self.indices[face_id * 3..face_id * 3 + 3]);
+
+            let face_vertex_ids = self.get_unique_face_vertex_ids(face_id);
+            for vertex_id in &face_vertex_ids {
+                remaining_valences[*vertex_id] -= 1;
+            }
+
+            let mut new_cache: Vec<usize> = face_vertex_ids.clone();
+            new_cache.extend(cache.iter().filter(|vertex_id| !face_vertex_ids.contains(vertex_id)));
+            let evicted: Vec<usize> = if new_cache.len() > VERTEX_CACHE_SIZE { new_cache.split_off(VERTEX_CACHE_SIZE) } else { Vec::new() };
+
+            for vertex_id in &evicted {
+                cache_positions[*vertex_id] = None;
+                vertex_scores[*vertex_id] = get_vertex_cache_score(None, remaining_valences[*vertex_id]);
+            }
+            for (position, vertex_id) in new_cache.iter().enumerate() {
+                cache_positions[*vertex_id] = Some(position);
+                vertex_scores[*vertex_id] = get_vertex_cache_score(Some(position), remaining_valences[*vertex_id]);
+            }
+
+            for vertex_id in evicted.iter().chain(new_cache.iter()) {
+                for neighbour_face_id in &vertex_faces[*vertex_id] {
+                    if !is_face_added[*neighbour_face_id] {
+                        face_scores[*neighbour_face_id] = self.get_unique_face_vertex_ids(*neighbour_face_id).iter().map(|id| vertex_scores[*id]).sum();
+                    }
+                }
+            }
+
+            best_face = None;
+            let mut best_score = f64::MIN;
+            for vertex_id in &new_cache {
+                for neighbour_face_id in &vertex_faces[*vertex_id] {
+                    if !is_face_added[*neighbour_face_id] && face_scores[*neighbour_face_id] > best_score {
+                        best_score = face_scores[*neighbour_face_id];
+                        best_face = Some(*neighbour_face_id);
+                    }
+                }
+            }
+
+            cache = new_cache;
+        }
+
+        let mut optimized = self.clone();
+        optimized.indices = indices;
+        optimized.face_groups = self.get_face_groups_by_face_ids(&face_order);
+        if let Some(UvChannel::PerCorner(_)) = &self.uvs {
+            optimized.uvs = self.get_corner_uvs_by_face_ids(&face_order).map(UvChannel::PerCorner);
+        }
+        optimized.attributes = self.get_attributes_by_ids(&(0..number_of_vertices).collect::<Vec<usize>>(), &face_order);
+
+        optimized
+    }
+
+    /// Creates a new [Mesh] with vertices reordered to improve the GPU vertex fetch efficiency.
+    ///
+    /// Vertices are sorted by their first use in the faces, so consecutive faces read vertex
+    /// data which lies close in the memory. It's best to call it after
+    /// [Mesh::get_with_optimized_vertex_cache], as it depends on the order of faces.
+    ///
+    /// It's a pure permutation of vertices: unused vertices are kept and moved to the end, and
+    /// indices are remapped accordingly, so the geometry stays exactly the same. Vertex data
+    /// (per vertex `uvs`, `vertex_colors`, `vertex_normals` and vertex attributes) is reordered
+    /// together with the vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0, // 0 unused
+    ///         10.0,10.0,0.0, // 1
+    ///         0.0,10.0,0.0, // 2
+    ///         10.0,0.0,0.0, // 3
+    ///     ],
+    ///     vec![
+    ///         3,1,2,
+    ///     ]);
+    ///
+    /// let actual = input.get_with_optimized_vertex_fetch();
+    ///
+    /// let expected = Mesh::new(
+    ///     vec![
+    ///         10.0,0.0,0.0,
+    ///         10.0,10.0,0.0,
+    ///         0.0,10.0,0.0,
+    ///         0.0,0.0,0.0,
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///     ]);
+    ///
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn get_with_optimized_vertex_fetch(&self) -> Mesh {
+        let number_of_vertices = self.get_number_of_vertices();
+
+        let mut new_vertex_ids: Vec<Option<usize>> = vec![None; number_of_vertices];
+        let mut old_vertex_ids: Vec<usize> = Vec::with_capacity(number_of_vertices);
+        for index in &self.indices {
+            if new_vertex_ids[*index].is_none() {
+                new_vertex_ids[*index] = Some(old_vertex_ids.len());
+                old_vertex_ids.push(*index);
+            }
+        }
+        for (vertex_id, new_vertex_id) in new_vertex_ids.iter_mut().enumerate() {
+            if new_vertex_id.is_none() {
+                *new_vertex_id = Some(old_vertex_ids.len());
+                old_vertex_ids.push(vertex_id);
+            }
+        }
+
+        let mut coordinates: Vec<f64> = Vec::with_capacity(self.coordinates.len());
+        for old_vertex_id in &old_vertex_ids {
+            coordinates.extend_from_slice(&self.coordinates[old_vertex_id * 3..old_vertex_id * 3 + 3]);
+        }
+        let indices: Vec<usize> = self.indices.iter().map(|index| new_vertex_ids[*index].unwrap()).collect();
+
+        let mut optimized = self.clone();
+        optimized.coordinates = coordinates;
+        optimized.indices = indices;
+        if let Some(UvChannel::PerVertex(uvs)) = &self.uvs {
+            optimized.uvs = Some(UvChannel::PerVertex(old_vertex_ids.iter().flat_map(|vertex_id| [uvs[vertex_id * 2], uvs[vertex_id * 2 + 1]]).collect()));
+        }
+        optimized.vertex_colors = self.get_vertex_colors_by_vertex_ids(old_vertex_ids.iter().copied());
+        optimized.vertex_normals = self.vertex_normals.as_ref().map(|normals| old_vertex_ids.iter().flat_map(|vertex_id| normals[vertex_id * 3..vertex_id * 3 + 3].to_vec()).collect());
+        optimized.attributes = self.get_attributes_by_ids(&old_vertex_ids, &(0..self.get_number_of_faces()).collect::<Vec<usize>>());
+
+        optimized
+    }
+
+    /// Calculates the average cache miss ratio (ACMR) of the [Mesh] for a simulated FIFO vertex
+    /// cache of given size.
+    ///
+    /// It's the number of vertex cache misses divided by the number of faces. The lower, the
+    /// better: 3.0 is the worst case, while well optimized regular meshes are close to 0.5-0.7.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         10.0,0.0,0.0,
+    ///         10.0,10.0,0.0,
+    ///         0.0,10.0,0.0,
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///         0,2,3,
+    ///     ]);
+    ///
+    /// assert_eq!(input.get_average_cache_miss_ratio(16), 2.0);
+    /// assert_eq!(input.get_average_cache_miss_ratio(1), 3.0);
+    /// ```
+    pub fn get_average_cache_miss_ratio(&self, cache_size: usize) -> f64 {
+        let number_of_faces = self.get_number_of_faces();
+        if number_of_faces == 0 {
+            return 0.0;
+        }
+
+        let mut cache: VecDeque<usize> = VecDeque::with_capacity(cache_size + 1);
+        let mut misses = 0;
+        for index in &self.indices {
+            if !cache.contains(index) {
+                misses += 1;
+                cache.push_back(*index);
+                if cache.len() > cache_size {
+                    cache.pop_front();
+                }
+            }
+        }
+
+        misses as f64 / number_of_faces as f64
+    }
+
+    /// Gets ids of the vertices of given face, skipping the repeated ones (of degenerate faces).
+    fn get_unique_face_vertex_ids(&self, face_id: usize) -> Vec<usize> {
+        let mut vertex_ids = Vec::with_capacity(3);
+        for index in &self.indices[face_id * 3..face_id * 3 + 3] {
+            if !vertex_ids.contains(index) {
+                vertex_ids.push(*index);
+            }
+        }
+
+        vertex_ids
+    }
+}
+
+/// Calculates the score of the vertex for the Forsyth's vertex cache optimization.
+fn get_vertex_cache_score(cache_position: Option<usize>, remaining_valence: usize) -> f64 {
+    if remaining_valence == 0 {
+        return -1.0;
+    }
+
+    let cache_score = match cache_position {
+        None => 0.0,
+        Some(position) if position < 3 => LAST_TRIANGLE_SCORE,
+        Some(position) => {
+            let scaler = 1.0 / (VERTEX_CACHE_SIZE - 3) as f64;
+            (1.0 - (position - 3) as f64 * scaler).powf(CACHE_DECAY_POWER)
+        }
+    };
+
+    cache_score + VALENCE_BOOST_SCALE * (remaining_valence as f64).powf(-VALENCE_BOOST_POWER)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use super::*;
+
+    fn create_grid_in_column_order(size: usize) -> Mesh {
+        let mut coordinates = Vec::new();
+        for y in 0..=size {
+            for x in 0..=size {
+                coordinates.extend_from_slice(&[x as f64, y as f64, 0.0]);
+            }
+        }
+        let mut indices = Vec::new();
+        for x in 0..size {
+            for y in 0..size {
+                let a = y * (size + 1) + x;
+                indices.extend_from_slice(&[a, a + 1, a + size + 2, a, a + size + 2, a + size + 1]);
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+
+    fn get_face_set(mesh: &Mesh) -> HashSet<[usize; 3]> {
+        mesh.indices.chunks(3).map(|face| [face[0], face[1], face[2]]).collect()
+    }
+
+    #[test]
+    fn test_get_with_optimized_vertex_cache_keeps_faces() {
+        let input = create_grid_in_column_order(40);
+
+        let actual = input.get_with_optimized_vertex_cache();
+
+        assert_eq!(get_face_set(&input), get_face_set(&actual));
+        assert!(actual.get_average_cache_miss_ratio(32) < 0.8);
+        assert!(input.get_average_cache_miss_ratio(32) > 0.9);
+    }
+
+    #[test]
+    fn test_get_with_optimized_vertex_cache_degenerate_and_disconnected() {
+        let input = Mesh::new_with_id(Some(4),
+            vec![
+                0.0,0.0,0.0,
+                1.0,0.0,0.0,
+                1.0,1.0,0.0,
+                5.0,0.0,0.0,
+                6.0,0.0,0.0,
+                6.0,1.0,0.0,
+            ],
+            vec![
+                0,1,2,
+                3,4,5,
+                0,0,1,
+            ]);
+
+        let actual = input.get_with_optimized_vertex_cache();
+
+        assert_eq!(actual.id, Some(4));
+        assert_eq!(get_face_set(&input), get_face_set(&actual));
+    }
+
+    #[test]
+    fn test_get_with_optimized_vertex_fetch_keeps_geometry() {
+        let input = create_grid_in_column_order(10).get_with_optimized_vertex_cache();
+
+        let actual = input.get_with_optimized_vertex_fetch();
+
+        assert_eq!(input.to_triangles(), actual.to_triangles());
+        assert_eq!(actual.indices[0..3], [0, 1, 2]);
+        let mut sorted_coordinates = actual.coordinates.clone();
+        let mut expected_coordinates = input.coordinates.clone();
+        sorted_coordinates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        expected_coordinates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(sorted_coordinates, expected_coordinates);
+    }
+
+    #[test]
+    fn test_get_average_cache_miss_ratio_empty() {
+        let input = Mesh::new(Vec::new(), Vec::new());
+        assert_eq!(input.get_average_cache_miss_ratio(16), 0.0);
+    }
+
+    #[test]
+    fn test_get_with_optimized_vertex_cache_keeps_face_data() {
+        let mut input = create_grid_in_column_order(4);
+        let number_of_faces = input.get_number_of_faces();
+        input.face_groups = Some((0..number_of_faces).collect());
+        input.uvs = Some(UvChannel::PerCorner((0..number_of_faces * 6).map(|value| value as f64).collect()));
+
+        let actual = input.get_with_optimized_vertex_cache();
+
+        let face_groups = actual.face_groups.as_ref().unwrap();
+        let Some(UvChannel::PerCorner(uvs)) = &actual.uvs else { panic!("Per corner UVs should be kept") };
+        for (face_id, face_group) in face_groups.iter().enumerate() {
+            assert_eq!(actual.indices[face_id * 3..face_id * 3 + 3], input.indices[face_group * 3..face_group * 3 + 3]);
+            assert_eq!(uvs[face_id * 6], (face_group * 6) as f64);
+        }
+    }
+
+    #[test]
+    fn test_get_with_optimized_vertex_fetch_keeps_vertex_data() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0, 10.0, 0.0, 0.0], vec![3, 1, 2]);
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 0.0]));
+        input.vertex_colors = Some(vec![0, 0, 0, 255, 1, 1, 1, 255, 2, 2, 2, 255, 3, 3, 3, 255]);
+        input.vertex_normals = Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0]);
+
+        let actual = input.get_with_optimized_vertex_fetch();
+
+        assert_eq!(actual.indices, vec![0, 1, 2]);
+        assert_eq!(actual.uvs, Some(UvChannel::PerVertex(vec![1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 0.0])));
+        assert_eq!(actual.vertex_colors, Some(vec![3, 3, 3, 255, 1, 1, 1, 255, 2, 2, 2, 255, 0, 0, 0, 255]));
+        assert_eq!(actual.vertex_normals, Some(vec![0.0, 0.0, -1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+    }
+}
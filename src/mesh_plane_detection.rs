@@ -0,0 +1,322 @@
+use crate::detected_plane::DetectedPlane;
+use crate::edge_adjacency::EdgeAdjacency;
+use crate::mesh::Mesh;
+use crate::plane::Plane;
+use crate::point::Point;
+use crate::random::Random;
+use crate::triangle::Triangle;
+
+/// Number of random candidate [Plane]s tested for each detected region.
+const RANSAC_ITERATIONS: usize = 200;
+
+/// Maximum number of refits of the best candidate region.
+const REFINEMENT_ITERATIONS: usize = 10;
+
+impl Mesh {
+    /// Segments the [Mesh] into near-planar regions, e.g. walls, floors and roofs of a scanned
+    /// building, which are never perfectly planar.
+    ///
+    /// Regions are found one after another, largest first. For each region, [Plane]s of up to
+    /// 200 randomly chosen faces are tested, and the one whose connected region
+    /// of faces (with all vertices within `tolerance` of the Plane) is the largest wins. The
+    /// winning region is then refined: the Plane is fitted to all its vertices and the region is
+    /// grown again, until it stops growing. Detection stops when the largest region has fewer
+    /// than `min_faces` faces. Faces which don't belong to any region are not returned.
+    ///
+    /// Faces are connected through shared edges, so the [Mesh] should be welded. Sampling is
+    /// deterministic: the same [Mesh] always gives the same result.
+    ///
+    /// Use [Mesh::get_with_projected_to_detected_planes] to flatten the regions before
+    /// [Mesh::get_planar_simplify].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0, 0.0, 0.0,
+    ///         10.0, 0.0, 0.01,
+    ///         10.0, 10.0, -0.01,
+    ///         0.0, 10.0, 0.0,
+    ///         0.0, 0.0, 10.0,
+    ///         0.0, 10.0, 10.0,
+    ///     ],
+    ///     vec![
+    ///         0, 1, 2,
+    ///         0, 2, 3,
+    ///         0, 3, 5,
+    ///         0, 5, 4,
+    ///     ]);
+    ///
+    /// let actual = input.detect_planes(0.05, 2);
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert_eq!(actual[0].face_ids.len(), 2);
+    /// assert_eq!(actual[1].face_ids.len(), 2);
+    /// ```
+    pub fn detect_planes(&self, tolerance: f64, min_faces: usize) -> Vec<DetectedPlane> {
+        let number_of_faces = self.get_number_of_faces();
+        let min_faces = min_faces.max(1);
+        let points = self.to_points();
+        let adjacency = EdgeAdjacency::from_mesh(self);
+        let mut random = Random::new(number_of_faces as u64);
+        let mut is_assigned = vec![false; number_of_faces];
+        let mut detected_planes = Vec::new();
+
+        loop {
+            let unassigned: Vec<usize> = (0..number_of_faces).filter(|face_id| !is_assigned[*face_id]).collect();
+            if unassigned.len() < min_faces {
+                break;
+            }
+
+            let mut best: Option<(Plane, usize, Vec<usize>)> = None;
+            for _ in 0..RANSAC_ITERATIONS.min(unassigned.len()) {
+                let seed = unassigned[random.next_index(unassigned.len())];
+                let triangle = Triangle::new(points[self.indices[seed * 3]], points[self.indices[seed * 3 + 1]], points[self.indices[seed * 3 + 2]]);
+                let normal = match triangle.get_normal_vector_unitized_checked() {
+                    Some(normal) => normal,
+                    None => continue,
+                };
+                let plane = Plane::new(triangle.first_point, normal);
+                let region = self.get_plane_region(&plane, seed, tolerance, &points, &adjacency, &is_assigned);
+                if region.is_empty() {
+                    continue;
+                }
+                let is_better = match &best {
+                    Some((_, _, best_region)) => region.len() > best_region.len(),
+                    None => true,
+                };
+                if is_better {
+                    best = Some((plane, seed, region));
+                }
+            }
+
+            let (mut plane, seed, mut region) = match best {
+                Some(best) => best,
+                None => break,
+            };
+            for _ in 0..REFINEMENT_ITERATIONS {
+                let refitted = Plane::new_best_fit(&self.get_region_points(&region, &points));
+                let grown = self.get_plane_region(&refitted, seed, tolerance, &points, &adjacency, &is_assigned);
+                if grown.len() < region.len() {
+                    break;
+                }
+                let has_grown = grown.len() > region.len();
+                plane = refitted;
+                region = grown;
+                if !has_grown {
+                    break;
+                }
+            }
+
+            if region.len() < min_faces {
+                break;
+            }
+            for face_id in &region {
+                is_assigned[*face_id] = true;
+            }
+            region.sort();
+            detected_planes.push(DetectedPlane::new(plane, region));
+        }
+
+        detected_planes
+    }
+
+    /// Creates a new [Mesh] with the vertices of each [DetectedPlane] (see [Mesh::detect_planes])
+    /// moved onto its [Plane].
+    ///
+    /// Vertices shared by several regions are moved to the [Point] closest to all their Planes,
+    /// so edges between regions stay sharp. Vertices outside of all regions are not moved.
+    /// Other data is copied, without [Mesh::vertex_normals].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0, 0.0, 0.0,
+    ///         10.0, 0.0, 0.01,
+    ///         10.0, 10.0, -0.01,
+    ///         0.0, 10.0, 0.0,
+    ///     ],
+    ///     vec![
+    ///         0, 1, 2,
+    ///         0, 2, 3,
+    ///     ]);
+    ///
+    /// let detected_planes = input.detect_planes(0.05, 2);
+    /// let actual = input.get_with_projected_to_detected_planes(&detected_planes);
+    ///
+    /// let plane = detected_planes[0].plane;
+    /// for point in actual.to_points() {
+    ///     assert!(plane.get_signed_distance_to_point(&point).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn get_with_projected_to_detected_planes(&self, detected_planes: &[DetectedPlane]) -> Mesh {
+        let mut vertex_planes: Vec<Vec<Plane>> = vec![Vec::new(); self.get_number_of_vertices()];
+        for detected_plane in detected_planes {
+            let mut vertex_ids: Vec<usize> = detected_plane.face_ids.iter().flat_map(|face_id| self.indices[face_id * 3..face_id * 3 + 3].to_vec()).collect();
+            vertex_ids.sort();
+            vertex_ids.dedup();
+            for vertex_id in vertex_ids {
+                vertex_planes[vertex_id].push(detected_plane.plane);
+            }
+        }
+
+        self.get_with_moved_vertices(|vertex_id, point| {
+            let planes = &vertex_planes[vertex_id];
+            match planes.len() {
+                0 => point,
+                1 => planes[0].get_closest_point(&point),
+                _ => Self::get_closest_point_to_planes(&point, planes),
+            }
+        })
+    }
+
+    /// Gets the connected region of unassigned faces, starting from the `seed` face, with all the
+    /// vertices within `tolerance` of the [Plane].
+    ///
+    /// Returns an empty region if the `seed` face itself is not within `tolerance`.
+    fn get_plane_region(&self, plane: &Plane, seed: usize, tolerance: f64, points: &[Point], adjacency: &EdgeAdjacency, is_assigned: &[bool]) -> Vec<usize> {
+        let is_inlier = |face_id: usize| {
+            self.indices[face_id * 3..face_id * 3 + 3].iter().all(|vertex_id| plane.get_signed_distance_to_point(&points[*vertex_id]).abs() <= tolerance)
+        };
+
+        let mut region = Vec::new();
+        if !is_inlier(seed) {
+            return region;
+        }
+        let mut is_visited = vec![false; is_assigned.len()];
+        is_visited[seed] = true;
+        let mut stack = vec![seed];
+        while let Some(face_id) = stack.pop() {
+            region.push(face_id);
+            for neighbour in adjacency.get_neighbour_faces_of_face(face_id) {
+                if !is_visited[neighbour] && !is_assigned[neighbour] && is_inlier(neighbour) {
+                    is_visited[neighbour] = true;
+                    stack.push(neighbour);
+                }
+            }
+        }
+
+        region
+    }
+
+    fn get_region_points(&self, region: &[usize], points: &[Point]) -> Vec<Point> {
+        let mut vertex_ids: Vec<usize> = region.iter().flat_map(|face_id| self.indices[face_id * 3..face_id * 3 + 3].to_vec()).collect();
+        vertex_ids.sort();
+        vertex_ids.dedup();
+        vertex_ids.iter().map(|vertex_id| points[*vertex_id]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a welded 10x10 grid, flat for x in [0, 5] and sloped by 45 degrees for x in
+    /// [5, 10], with random noise added to the heights.
+    fn get_noisy_roof(noise: f64) -> Mesh {
+        let mut random = Random::new(1);
+        let mut coordinates = Vec::new();
+        for j in 0..11 {
+            for i in 0..11 {
+                let x = i as f64;
+                let z = if i > 5 { x - 5.0 } else { 0.0 };
+                coordinates.extend_from_slice(&[x, j as f64, z + noise * random.next()]);
+            }
+        }
+        let mut indices = Vec::new();
+        for j in 0..10 {
+            for i in 0..10 {
+                let first = j * 11 + i;
+                indices.extend_from_slice(&[first, first + 1, first + 12, first, first + 12, first + 11]);
+            }
+        }
+        Mesh::new(coordinates, indices)
+    }
+
+    fn get_roof_faces(is_flat: bool) -> Vec<usize> {
+        (0..200).filter(|face_id| ((face_id / 2) % 10 < 5) == is_flat).collect()
+    }
+
+    #[test]
+    fn test_detect_planes_noisy_roof() {
+        let input = get_noisy_roof(0.005);
+
+        let mut actual = input.detect_planes(0.02, 10);
+        actual.sort_by_key(|detected_plane| detected_plane.face_ids[0]);
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0].face_ids, get_roof_faces(true));
+        assert_eq!(actual[1].face_ids, get_roof_faces(false));
+        assert!(actual[0].plane.normal.z.abs() > 0.9999);
+        assert!((actual[1].plane.normal.x.abs() - 0.5_f64.sqrt()).abs() < 0.005);
+    }
+
+    #[test]
+    fn test_detect_planes_min_faces() {
+        let input = get_noisy_roof(0.005);
+
+        assert!(input.detect_planes(0.02, 101).is_empty());
+    }
+
+    #[test]
+    fn test_detect_planes_too_small_tolerance() {
+        let input = get_noisy_roof(0.005);
+
+        let actual = input.detect_planes(0.0001, 10);
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_detect_planes_empty() {
+        let input = Mesh::new(vec![], vec![]);
+
+        assert!(input.detect_planes(0.1, 1).is_empty());
+    }
+
+    #[test]
+    fn test_get_with_projected_to_detected_planes_noisy_roof() {
+        let input = get_noisy_roof(0.005);
+        let detected_planes = input.detect_planes(0.02, 10);
+
+        let actual = input.get_with_projected_to_detected_planes(&detected_planes);
+
+        for detected_plane in &detected_planes {
+            for face_id in &detected_plane.face_ids {
+                for vertex_id in &actual.indices[face_id * 3..face_id * 3 + 3] {
+                    let point = Point::new(actual.coordinates[vertex_id * 3], actual.coordinates[vertex_id * 3 + 1], actual.coordinates[vertex_id * 3 + 2]);
+                    assert!(detected_plane.plane.get_signed_distance_to_point(&point).abs() < 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_with_projected_to_detected_planes_nothing_detected() {
+        let input = get_noisy_roof(0.005);
+
+        let actual = input.get_with_projected_to_detected_planes(&[]);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_get_with_projected_to_detected_planes_keeps_other_data() {
+        let mut input = get_noisy_roof(0.005);
+        input.face_groups = Some(vec![2; input.get_number_of_faces()]);
+        input.metadata.insert("name".to_string(), "roof".to_string());
+        let detected_planes = input.detect_planes(0.05, 10);
+
+        let actual = input.get_with_projected_to_detected_planes(&detected_planes);
+
+        assert_eq!(actual.face_groups, input.face_groups);
+        assert_eq!(actual.metadata, input.metadata);
+    }
+}
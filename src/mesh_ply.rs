@@ -0,0 +1,118 @@
+use std::fmt::Write;
+use std::fs;
+use crate::mesh::Mesh;
+
+impl Mesh {
+    /// Creates the content of the .ply file (ASCII) with the [Mesh].
+    ///
+    /// `vertex_normals` are written as `nx`, `ny`, `nz` and `vertex_colors` as `red`, `green`,
+    /// `blue`, `alpha` vertex properties. Color values are clamped to 0-255.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.5], vec![0, 1, 2]);
+    /// input.vertex_colors = Some(vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 128]);
+    ///
+    /// let actual = input.to_ply_string();
+    ///
+    /// let expected = "ply
+    /// format ascii 1.0
+    /// comment meshmeshmesh
+    /// element vertex 3
+    /// property double x
+    /// property double y
+    /// property double z
+    /// property uchar red
+    /// property uchar green
+    /// property uchar blue
+    /// property uchar alpha
+    /// element face 1
+    /// property list uchar uint vertex_indices
+    /// end_header
+    /// 0 0 0 255 0 0 255
+    /// 1 0 0 0 255 0 255
+    /// 0 1 0.5 0 0 255 128
+    /// 3 0 1 2
+    /// ";
+    ///
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn to_ply_string(&self) -> String {
+        let mut content = String::from("ply\nformat ascii 1.0\ncomment meshmeshmesh\n");
+
+        writeln!(content, "element vertex {}", self.get_number_of_vertices()).unwrap();
+        content.push_str("property double x\nproperty double y\nproperty double z\n");
+        if self.vertex_normals.is_some() {
+            content.push_str("property double nx\nproperty double ny\nproperty double nz\n");
+        }
+        if self.vertex_colors.is_some() {
+            content.push_str("property uchar red\nproperty uchar green\nproperty uchar blue\nproperty uchar alpha\n");
+        }
+        writeln!(content, "element face {}", self.get_number_of_faces()).unwrap();
+        content.push_str("property list uchar uint vertex_indices\nend_header\n");
+
+        for (vertex_id, vertex) in self.coordinates.chunks(3).enumerate() {
+            write!(content, "{} {} {}", vertex[0], vertex[1], vertex[2]).unwrap();
+            if let Some(normals) = &self.vertex_normals {
+                write!(content, " {} {} {}", normals[vertex_id * 3], normals[vertex_id * 3 + 1], normals[vertex_id * 3 + 2]).unwrap();
+            }
+            if let Some(colors) = &self.vertex_colors {
+                for value in &colors[vertex_id * 4..vertex_id * 4 + 4] {
+                    write!(content, " {}", value.clamp(&0, &255)).unwrap();
+                }
+            }
+            content.push('\n');
+        }
+        for face in self.indices.chunks(3) {
+            writeln!(content, "3 {} {} {}", face[0], face[1], face[2]).unwrap();
+        }
+
+        content
+    }
+
+    /// Writes the [Mesh] to the .ply file (ASCII), see [Mesh::to_ply_string].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// input.to_ply("result.ply").unwrap();
+    /// ```
+    pub fn to_ply(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_ply_string()).map_err(|e| format!("Couldn't write the .ply file: {}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ply_string_normals_and_clamped_colors() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.vertex_normals = Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+        input.vertex_colors = Some(vec![300, -5, 0, 255, 0, 0, 0, 0, 1, 2, 3, 4]);
+
+        let actual = input.to_ply_string();
+
+        assert!(actual.contains("property double nz\nproperty uchar red\n"));
+        assert!(actual.contains("\n0 0 0 0 0 1 255 0 0 255\n"));
+        assert!(actual.contains("\n0 1 0 0 0 1 1 2 3 4\n3 0 1 2\n"));
+    }
+
+    #[test]
+    fn test_to_ply_string_without_attributes() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 5.0, 5.0, 5.0], vec![0, 1, 2]);
+
+        let actual = input.to_ply_string();
+
+        assert!(actual.contains("element vertex 4\nproperty double x\nproperty double y\nproperty double z\nelement face 1\n"));
+        assert!(actual.ends_with("end_header\n0 0 0\n1 0 0\n0 1 0\n5 5 5\n3 0 1 2\n"));
+    }
+}
@@ -0,0 +1,228 @@
+use crate::mesh::Mesh;
+use crate::mesh_refinement::Refinement;
+use crate::point::Point;
+use crate::triangle::Triangle;
+use crate::vector::Vector;
+
+impl Mesh {
+    /// Creates a new [Mesh] with the given [Point]s inserted as vertices, e.g. for imprinting
+    /// survey points or connection nodes onto a surface.
+    ///
+    /// Each [Point] is moved to the closest point of the surface. If it's within the `tolerance`
+    /// from an existing vertex, that vertex is used. If it's within the `tolerance` from an edge,
+    /// the edge is split, together with all the faces sharing it. Otherwise the face containing it
+    /// is split into 3. Points farther than the `tolerance` from the surface are skipped.
+    ///
+    /// New vertices get per vertex `uvs`, `vertex_colors` and `vertex_normals` (unitized)
+    /// interpolated, and vertex `attributes` of the closest corner of the split face or edge. If
+    /// the split face is degenerate (see [Triangle::is_degenerate]), all the data is taken from
+    /// its closest corner. New faces keep `face_groups` and face `attributes` of the face they
+    /// come from. Per corner `uvs` are removed.
+    ///
+    /// Returns the new [Mesh] and the vertex id for each of the given [Point]s, or `None` for
+    /// the skipped ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let (actual, vertex_ids) = input.get_with_points_inserted(&[
+    ///     Point::new(1.0, 1.0, 0.001), // inside the face
+    ///     Point::new(1.5, 0.0, 0.0), // on the edge
+    ///     Point::new(0.0, 0.0, 0.0), // existing vertex
+    ///     Point::new(1.0, 1.0, 5.0), // far from the surface
+    /// ], 0.01);
+    ///
+    /// assert_eq!(vertex_ids, vec![Some(3), Some(4), Some(0), None]);
+    /// assert_eq!(actual.get_number_of_faces(), 4);
+    /// assert_eq!(actual.to_points()[3], Point::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn get_with_points_inserted(&self, points: &[Point], tolerance: f64) -> (Mesh, Vec<Option<usize>>) {
+        let mut refinement = Refinement::new(self);
+        let vertex_ids = points.iter().map(|point| insert_point(&mut refinement, point, tolerance, tolerance)).collect();
+
+        (refinement.into_mesh(self), vertex_ids)
+    }
+}
+
+/// Inserts a single [Point] not farther than the `max_distance` from the surface, snapping it to
+/// vertices and edges within the `tolerance`, returns the id of its vertex.
+pub(crate) fn insert_point(refinement: &mut Refinement, point: &Point, max_distance: f64, tolerance: f64) -> Option<usize> {
+    let mut closest: Option<(usize, Point)> = None;
+    let mut closest_distance = max_distance;
+    for (face_id, face) in refinement.faces.iter().enumerate() {
+        let triangle = Triangle::new(get_point(refinement, face[0]), get_point(refinement, face[1]), get_point(refinement, face[2]));
+        let closest_point = triangle.get_closest_point(point);
+        let distance = closest_point.get_distance_to_point(point);
+        if distance <= closest_distance {
+            closest = Some((face_id, closest_point));
+            closest_distance = distance;
+        }
+    }
+    let (face_id, closest_point) = closest?;
+
+    let face = refinement.faces[face_id];
+    let corners = face.map(|vertex| get_point(refinement, vertex));
+
+    let (corner, corner_distance) = (0..3)
+        .map(|corner| (corner, corners[corner].get_distance_to_point(&closest_point)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+    if corner_distance <= tolerance {
+        return Some(face[corner]);
+    }
+
+    let (edge, edge_point, edge_distance) = (0..3)
+        .map(|corner| {
+            let edge_point = closest_point.get_closest_point_on_segment(&corners[corner], &corners[(corner + 1) % 3]);
+            (corner, edge_point, edge_point.get_distance_to_point(&closest_point))
+        })
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+        .unwrap();
+    if edge_distance <= tolerance {
+        let (start, end) = (face[edge], face[(edge + 1) % 3]);
+
+        return Some(split_edge(refinement, start, end, &edge_point, tolerance));
+    }
+
+    let mut weights: Vec<(usize, f64)> = match get_barycentric_weights(&closest_point, &corners) {
+        Some(barycentric_weights) => face.into_iter().zip(barycentric_weights).collect(),
+        None => vec![(face[corner], 1.0)], // Degenerate face, so the data of the closest corner is used
+    };
+    weights.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let vertex = refinement.add_vertex([closest_point.x, closest_point.y, closest_point.z], &weights);
+    let [a, b, c] = face;
+    refinement.faces[face_id] = [a, b, vertex];
+    refinement.faces.extend([[b, c, vertex], [c, a, vertex]]);
+    refinement.face_sources.extend([refinement.face_sources[face_id]; 2]);
+
+    Some(vertex)
+}
+
+/// Splits all the faces sharing the edge between `start` and `end` at the given [Point] lying on
+/// it, returns the id of the new vertex, or of the end closer than the `tolerance`.
+pub(crate) fn split_edge(refinement: &mut Refinement, start: usize, end: usize, point: &Point, tolerance: f64) -> usize {
+    let (start_point, end_point) = (get_point(refinement, start), get_point(refinement, end));
+    let (start_distance, end_distance) = (start_point.get_distance_to_point(point), end_point.get_distance_to_point(point));
+    if start_distance <= tolerance && start_distance <= end_distance {
+        return start;
+    }
+    if end_distance <= tolerance {
+        return end;
+    }
+    let parameter = start_distance / start_point.get_distance_to_point(&end_point);
+    let weights = if parameter <= 0.5 { [(start, 1.0 - parameter), (end, parameter)] } else { [(end, parameter), (start, 1.0 - parameter)] };
+    let vertex = refinement.add_vertex([point.x, point.y, point.z], &weights);
+
+    for face_id in 0..refinement.faces.len() {
+        let face = refinement.faces[face_id];
+        let corner = (0..3).find(|corner| {
+            let (a, b) = (face[*corner], face[(corner + 1) % 3]);
+            (a == start && b == end) || (a == end && b == start)
+        });
+        if let Some(corner) = corner {
+            let [a, b, c] = [face[corner], face[(corner + 1) % 3], face[(corner + 2) % 3]];
+            refinement.faces[face_id] = [a, vertex, c];
+            refinement.faces.push([vertex, b, c]);
+            refinement.face_sources.push(refinement.face_sources[face_id]);
+        }
+    }
+
+    vertex
+}
+
+/// Gets the [Point] of the vertex with the given id.
+pub(crate) fn get_point(refinement: &Refinement, vertex: usize) -> Point {
+    let [x, y, z] = refinement.positions[vertex];
+
+    Point::new(x, y, z)
+}
+
+/// Gets barycentric weights of the [Point] lying on the triangle with the given corners, or
+/// `None` if the triangle is degenerate (see [Triangle::is_degenerate]).
+fn get_barycentric_weights(point: &Point, corners: &[Point; 3]) -> Option<[f64; 3]> {
+    if Triangle::new(corners[0], corners[1], corners[2]).is_degenerate() {
+        return None;
+    }
+    let first = Vector::from_2_points(&corners[0], &corners[1]);
+    let second = Vector::from_2_points(&corners[0], &corners[2]);
+    let to_point = Vector::from_2_points(&corners[0], point);
+    let (d00, d01, d11) = (first.get_dot_product(&first), first.get_dot_product(&second), second.get_dot_product(&second));
+    let (d20, d21) = (to_point.get_dot_product(&first), to_point.get_dot_product(&second));
+    let denominator = d00 * d11 - d01 * d01;
+    let v = (d11 * d20 - d01 * d21) / denominator;
+    let w = (d00 * d21 - d01 * d20) / denominator;
+
+    Some([1.0 - v - w, v, w])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::uv_channel::UvChannel;
+    use super::*;
+
+    fn get_square() -> Mesh {
+        Mesh::new(vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 2.0, 0.0], vec![0, 1, 2, 0, 2, 3])
+    }
+
+    #[test]
+    fn test_get_with_points_inserted_inside_face() {
+        let mut input = get_square();
+        input.face_groups = Some(vec![1, 2]);
+
+        let (actual, vertex_ids) = input.get_with_points_inserted(&[Point::new(1.5, 0.5, 0.0)], 0.001);
+
+        assert_eq!(vertex_ids, vec![Some(4)]);
+        assert_eq!(actual.indices, vec![0, 1, 4, 0, 2, 3, 1, 2, 4, 2, 0, 4]);
+        assert_eq!(actual.face_groups, Some(vec![1, 2, 1, 1]));
+        assert!((actual.get_area() - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_with_points_inserted_on_shared_edge() {
+        let input = get_square();
+
+        let (actual, vertex_ids) = input.get_with_points_inserted(&[Point::new(0.999, 1.0, 0.0)], 0.01);
+
+        assert_eq!(vertex_ids, vec![Some(4)]);
+        assert_eq!(actual.get_number_of_faces(), 4);
+        assert!(actual.to_points()[4].eq_with_tolerance(&Point::new(0.9995, 0.9995, 0.0), 1e-12));
+        assert_eq!(actual.get_boundary_loops_vertex_ids()[0].len(), 4);
+        assert!(actual.get_non_manifold_vertices().is_empty());
+    }
+
+    #[test]
+    fn test_get_with_points_inserted_snapped_to_vertex() {
+        let input = get_square();
+
+        let (actual, vertex_ids) = input.get_with_points_inserted(&[Point::new(2.005, 2.0, 0.0), Point::new(-1.0, 1.0, 0.0)], 0.01);
+
+        assert_eq!(vertex_ids, vec![Some(2), None]);
+        assert_eq!(actual.coordinates, input.coordinates);
+        assert_eq!(actual.indices, input.indices);
+    }
+
+    #[test]
+    fn test_get_with_points_inserted_interpolates_uvs() {
+        let mut input = get_square();
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]));
+
+        let (actual, _) = input.get_with_points_inserted(&[Point::new(1.5, 0.5, 0.0), Point::new(1.0, 0.0, 0.0)], 0.001);
+
+        let Some(UvChannel::PerVertex(uvs)) = actual.uvs else { panic!("uvs should be per vertex") };
+        assert!((uvs[8] - 0.75).abs() < 1e-12 && (uvs[9] - 0.25).abs() < 1e-12);
+        assert!((uvs[10] - 0.5).abs() < 1e-12 && uvs[11].abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_barycentric_weights_degenerate() {
+        let corners = [Point::new(0.1, 0.3, 0.7), Point::new(1.1, 1.6, 2.4), Point::new(0.6, 0.95, 1.55)];
+
+        assert_eq!(get_barycentric_weights(&Point::new(0.41, 0.703, 1.227), &corners), None);
+        assert_eq!(get_barycentric_weights(&Point::new(0.5, 0.5, 0.0), &[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)]), Some([0.0, 0.5, 0.5]));
+    }
+}
@@ -1,13 +1,23 @@
 use crate::mesh::Mesh;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use crate::bounding_box::BoundingBox;
 use crate::face_neighbours::FaceNeighbours;
 use crate::face_neighbours_angle::FaceNeighboursAngle;
 use crate::graph::Graph;
 use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::mesh_delta::MeshDelta;
+use crate::octree::Octree;
+use crate::plane::Plane;
 use crate::point::Point;
 use crate::polygon2d::Polygon2D;
-use crate::polygon::Polygon;
+use crate::weld_report::WeldReport;
+use crate::uv_channel::UvChannel;
+use crate::vector::Vector;
+use crate::vertex_welder::VertexWelder;
+
+/// Part of the shortest edge used as the offset of the inside test in
+/// [Mesh::get_with_consistent_winding], so it stays inside thin parts.
+const CONSISTENT_WINDING_OFFSET_FACTOR: f64 = 0.01;
 
 impl Mesh {
 
@@ -76,26 +86,9 @@ impl Mesh {
     /// assert_eq!(expected.eq(&actual), true);
     /// ```
     pub fn get_with_faces_flipped(&self, indices_of_faces_to_remove:HashSet<usize>) -> Mesh {
-        let mut new_indices: Vec<usize> = Vec::<usize>::new();
-        let start_indices = self.get_start_indices();
-        let middle_indices = self.get_middle_indices();
-        let end_indices = self.get_end_indices();
-        let number_of_faces = self.get_number_of_faces();
-
-        for i in 0..number_of_faces {
-            if indices_of_faces_to_remove.contains(&i) {
-                new_indices.push(end_indices[i]);
-                new_indices.push(middle_indices[i]);
-                new_indices.push(start_indices[i]);
-            }
-            else {
-                new_indices.push(start_indices[i]);
-                new_indices.push(middle_indices[i]);
-                new_indices.push(end_indices[i]);
-            }
-        }
-
-        Mesh::new_with_id(self.id, self.coordinates.clone(), new_indices)
+        let mut flipped = self.clone();
+        flipped.flip_faces(&indices_of_faces_to_remove);
+        flipped
     }
 
     /// Creates a new [Mesh], but with all faces flipped
@@ -160,25 +153,79 @@ impl Mesh {
     /// assert_eq!(expected.eq(&actual), true);
     /// ```
     pub fn get_with_all_faces_flipped(&self) -> Mesh {
-        let mut new_indices: Vec<usize> = Vec::<usize>::new();
-        let start_indices = self.get_start_indices();
-        let middle_indices = self.get_middle_indices();
-        let end_indices = self.get_end_indices();
-        let number_of_faces = self.get_number_of_faces();
+        let mut flipped = self.clone();
+        flipped.flip_all_faces();
+        flipped
+    }
 
-        for i in 0..number_of_faces {
-            new_indices.push(end_indices[i]);
-            new_indices.push(middle_indices[i]);
-            new_indices.push(start_indices[i]);
+    /// Flips the faces with given ids, see [Mesh::get_with_faces_flipped].
+    ///
+    /// It mutates the Mesh, so no copy of `coordinates` is created. Per corner `uvs` are
+    /// flipped together with the faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// input.flip_faces(&HashSet::from([1]));
+    ///
+    /// assert_eq!(input.indices, vec![0, 1, 2, 3, 2, 0]);
+    /// ```
+    pub fn flip_faces(&mut self, face_ids: &HashSet<usize>) {
+        for &face_id in face_ids {
+            self.flip_face(face_id);
         }
+        self.invalidate_cache();
+    }
 
-        Mesh::new_with_id(self.id, self.coordinates.clone(), new_indices)
+    /// Flips all the faces, see [Mesh::get_with_all_faces_flipped].
+    ///
+    /// It mutates the Mesh, so no copy of `coordinates` is created. Per corner `uvs` are
+    /// flipped together with the faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// input.flip_all_faces();
+    ///
+    /// assert_eq!(input.indices, vec![2, 1, 0, 3, 2, 0]);
+    /// ```
+    pub fn flip_all_faces(&mut self) {
+        for face_id in 0..self.get_number_of_faces() {
+            self.flip_face(face_id);
+        }
+        self.invalidate_cache();
+    }
+
+    fn flip_face(&mut self, face_id: usize) {
+        self.indices.swap(face_id * 3, face_id * 3 + 2);
+        if let Some(UvChannel::PerCorner(uvs)) = &mut self.uvs {
+            uvs.swap(face_id * 6, face_id * 6 + 4);
+            uvs.swap(face_id * 6 + 1, face_id * 6 + 5);
+        }
     }
     
     /// Tries to flip the faces of the [Mesh] using offset. 
     /// 
     /// It offsets origin of normal for every face and checks if it's inside the Mesh.
     /// If yes -> then it flips.
+    ///
+    /// As every face is tested separately, it fails for open Meshes, or if the `offset` is
+    /// bigger than the thickness. See [Mesh::get_with_consistent_winding] for these cases.
     /// 
     /// # Example
     ///
@@ -239,6 +286,120 @@ impl Mesh {
         self.get_with_faces_flipped(faces_to_flip)
     }
 
+    /// Creates a new [Mesh] with faces flipped, so the winding is consistent across
+    /// neighbouring faces and faces point outside.
+    ///
+    /// Faces are oriented by walking over [FaceNeighbours] of each connected part: a neighbour is
+    /// flipped if it uses the shared edge in the same direction as the current face. Only then
+    /// the global orientation of each part is set:
+    /// * closed parts are flipped if most of their faces point inside, using the same offset test
+    ///   as [Mesh::get_with_faces_flipped_outside_using_offset], with the offset set to a small
+    ///   part of the part's shortest edge,
+    /// * open parts keep the winding of the majority of their faces, as inside is not defined
+    ///   for them.
+    ///
+    /// Unlike [Mesh::get_with_faces_flipped_outside_using_offset] it works for open and thin
+    /// Meshes, as the offset test is not used for every face separately.
+    ///
+    /// The input [Mesh] should be welded and can't have edges shared by more than 2 faces.
+    /// Non-orientable parts (e.g. a Möbius strip) can't be fully fixed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    /// vec![
+    ///     // Base
+    ///     -2.0,1.0,0.0,
+    ///     8.0,1.0,0.0,
+    ///     8.0,11.0,0.0,
+    ///     -2.0,11.0,0.0,
+    ///
+    ///     // Top
+    ///     3.0,6.0,4.0
+    /// ],
+    /// vec![
+    ///     // Base faces
+    ///     0,1,2, // flipped
+    ///     3,2,0,
+    ///
+    ///     // Side faces
+    ///     0,1,4,
+    ///     4,2,1, // flipped
+    ///     2,3,4,
+    ///     4,0,3 // flipped
+    /// ]);
+    ///
+    /// let actual = input.get_with_consistent_winding();
+    ///
+    /// assert_eq!(actual.indices, vec![2,1,0, 3,2,0, 0,1,4, 1,2,4, 2,3,4, 3,0,4]);
+    /// ```
+    pub fn get_with_consistent_winding(&self) -> Mesh {
+        let face_neighbours = FaceNeighbours::from_mesh(self);
+        let triangles = self.to_triangles();
+        let number_of_faces = self.get_number_of_faces();
+
+        // Whether the face should be flipped relative to the first face of its part
+        let mut is_flipped: Vec<Option<bool>> = vec![None; number_of_faces];
+        let mut faces_to_flip: HashSet<usize> = HashSet::new();
+        for first_face_id in 0..number_of_faces {
+            if is_flipped[first_face_id].is_some() {
+                continue;
+            }
+            is_flipped[first_face_id] = Some(false);
+            let mut part_face_ids = vec![first_face_id];
+            let mut is_closed = true;
+            let mut queue = VecDeque::from([first_face_id]);
+            while let Some(face_id) = queue.pop_front() {
+                let neighbours = face_neighbours[face_id];
+                for (i, neighbour) in [neighbours.first, neighbours.second, neighbours.third].into_iter().enumerate() {
+                    let Some(neighbour_face_id) = neighbour else {
+                        is_closed = false;
+                        continue;
+                    };
+                    if is_flipped[neighbour_face_id].is_some() {
+                        continue;
+                    }
+                    // Consistent neighbours use the shared edge in opposite directions
+                    let start = self.indices[face_id * 3 + i];
+                    let end = self.indices[face_id * 3 + (i + 1) % 3];
+                    let neighbour_face = &self.indices[neighbour_face_id * 3..neighbour_face_id * 3 + 3];
+                    let is_same_direction = (0..3).any(|j| neighbour_face[j] == start && neighbour_face[(j + 1) % 3] == end);
+                    is_flipped[neighbour_face_id] = Some(is_flipped[face_id].unwrap() != is_same_direction);
+                    part_face_ids.push(neighbour_face_id);
+                    queue.push_back(neighbour_face_id);
+                }
+            }
+
+            let should_flip_part = if is_closed {
+                let shortest_edge_length = part_face_ids.iter()
+                    .flat_map(|face_id| {
+                        let triangle = &triangles[*face_id];
+                        [triangle.first_point.get_distance_to_point(&triangle.second_point), triangle.second_point.get_distance_to_point(&triangle.third_point), triangle.third_point.get_distance_to_point(&triangle.first_point)]
+                    })
+                    .fold(f64::INFINITY, f64::min);
+                let offset = shortest_edge_length * CONSISTENT_WINDING_OFFSET_FACTOR;
+                let number_of_faces_pointing_inside = part_face_ids.iter()
+                    .filter(|face_id| {
+                        let face_offset = if is_flipped[**face_id].unwrap() { -offset } else { offset };
+                        triangles[**face_id].get_normal_ray().get_point_at(face_offset).is_inside_mesh_using_xyz(self)
+                    })
+                    .count();
+                number_of_faces_pointing_inside * 2 > part_face_ids.len()
+            } else {
+                let number_of_flipped = part_face_ids.iter().filter(|face_id| is_flipped[**face_id].unwrap()).count();
+                number_of_flipped * 2 > part_face_ids.len()
+            };
+            faces_to_flip.extend(part_face_ids.into_iter().filter(|face_id| is_flipped[*face_id].unwrap() != should_flip_part));
+        }
+
+        let mut consistent = self.clone();
+        consistent.flip_faces(&faces_to_flip);
+        consistent
+    }
+
     /// Removes specified vertices of a [Mesh], but it doesn't update its indices.
     ///
     /// It means that it will only affect coordinates list. In other words: faces will be specified
@@ -329,6 +490,73 @@ impl Mesh {
         Mesh::new_with_id(self.id, new_coordinates, self.indices.clone())
     }
 
+    /// Creates a new [Mesh] without vertices which are not used by any face, with indices
+    /// updated to the new vertices.
+    ///
+    /// Unlike [Mesh::get_with_removed_vertices_without_indices_update] it keeps the faces
+    /// pointing to the same points. Kept vertices stay in their original order. `uvs`,
+    /// `vertex_colors`, `vertex_normals`, `face_groups` and `attributes` are kept, together with
+    /// `id` and `metadata`.
+    ///
+    /// It also returns the mapping from old to new vertex ids, with `None` for removed vertices,
+    /// so any other per vertex data can be remapped the same way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    /// vec![
+    ///     0.0, 0.0, 0.0,
+    ///     5.0, 5.0, 5.0, // unused
+    ///     1.0, 0.0, 0.0,
+    ///     1.0, 1.0, 0.0,
+    ///     9.0, 9.0, 9.0, // unused
+    ///     0.0, 1.0, 0.0,
+    /// ],
+    /// vec![
+    ///     0, 2, 3,
+    ///     0, 3, 5,
+    /// ]);
+    ///
+    /// let (actual, mapping) = input.get_compacted();
+    ///
+    /// let expected = Mesh::new(
+    /// vec![
+    ///     0.0, 0.0, 0.0,
+    ///     1.0, 0.0, 0.0,
+    ///     1.0, 1.0, 0.0,
+    ///     0.0, 1.0, 0.0,
+    /// ],
+    /// vec![
+    ///     0, 1, 2,
+    ///     0, 2, 3,
+    /// ]);
+    ///
+    /// assert_eq!(actual, expected);
+    /// assert_eq!(mapping, vec![Some(0), None, Some(1), Some(2), None, Some(3)]);
+    /// ```
+    pub fn get_compacted(&self) -> (Mesh, Vec<Option<usize>>) {
+        let face_ids: Vec<usize> = (0..self.get_number_of_faces()).collect();
+        self.get_compacted_part_by_face_ids(&face_ids)
+    }
+
+    /// Creates a new [Mesh] only with the faces of given ids, see [Mesh::get_compacted].
+    pub(crate) fn get_compacted_part_by_face_ids(&self, face_ids: &[usize]) -> (Mesh, Vec<Option<usize>>) {
+        let (mut compacted, new_vertex_ids) = self.get_welded_part_by_face_ids_with_new_vertex_ids(face_ids);
+        compacted.id = self.id;
+        compacted.metadata = self.metadata.clone();
+        compacted.vertex_normals = self.vertex_normals.as_ref().map(|normals| {
+            new_vertex_ids.iter().enumerate()
+                .filter(|(_, new_vertex_id)| new_vertex_id.is_some())
+                .flat_map(|(vertex_id, _)| normals[vertex_id * 3..vertex_id * 3 + 3].to_vec())
+                .collect()
+        });
+
+        (compacted, new_vertex_ids)
+    }
+
     /// Allows to replace specific indices with new ones
     ///
     /// Creates the new [Mesh], but with replaced indices
@@ -411,6 +639,34 @@ impl Mesh {
         Mesh::new_with_id(self.id, self.coordinates.clone(), new_indices)
     }
 
+    /// Replaces indices, see [Mesh::get_with_replaced_indices].
+    ///
+    /// It mutates the Mesh, so no copy of `coordinates` is created.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// input.replace_indices(&HashMap::from([(3, 1)]));
+    ///
+    /// assert_eq!(input.indices, vec![0, 1, 2, 0, 2, 1]);
+    /// ```
+    pub fn replace_indices(&mut self, replacement_instruction: &HashMap<usize, usize>) {
+        for index in self.indices.iter_mut() {
+            if let Some(replacement) = replacement_instruction.get(index) {
+                *index = *replacement;
+            }
+        }
+        self.invalidate_cache();
+    }
+
     /// Welds vertices of the given [Mesh].
     ///
     /// In other words, it searches for duplicate (with tolerance) vertices and removes these duplicates.
@@ -491,9 +747,206 @@ impl Mesh {
     /// assert_eq!(expected.eq(&actual), true);
     /// ```
     pub fn get_with_welded_vertices(&self, tolerance: f64) -> Mesh {
+        self.get_with_welded_vertices_with_report(tolerance, usize::MAX).0
+    }
+
+    /// Welds vertices, see [Mesh::get_with_welded_vertices].
+    ///
+    /// It mutates the Mesh: `coordinates` are compacted and `indices` are remapped in place.
+    /// Per vertex `uvs`, `vertex_colors` and vertex attributes are compacted together with
+    /// `coordinates`, so they are the same as in [Mesh::get_with_welded_vertices]. If any vertex
+    /// is welded, `vertex_normals` are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0,
+    ///          0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    ///
+    /// input.weld_vertices(0.001);
+    ///
+    /// assert_eq!(input.coordinates, vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0]);
+    /// assert_eq!(input.indices, vec![0, 1, 2, 0, 2, 3]);
+    /// ```
+    pub fn weld_vertices(&mut self, tolerance: f64) {
+        let mut duplicate_vertices_info = Point::scan_for_duplicates_with_tolerance_info(&self.to_points(), tolerance);
+        if let Some(UvChannel::PerVertex(uvs)) = &self.uvs {
+            split_duplicates_by_uvs(&mut duplicate_vertices_info, uvs, tolerance);
+        }
+
+        let mut new_vertex_ids: Vec<usize> = Vec::with_capacity(duplicate_vertices_info.len());
+        let mut kept_vertex_ids: Vec<usize> = Vec::new();
+        for (i, (first_occurrence, is_duplicate)) in duplicate_vertices_info.iter().enumerate() {
+            if *is_duplicate {
+                new_vertex_ids.push(new_vertex_ids[*first_occurrence]);
+            }
+            else {
+                new_vertex_ids.push(kept_vertex_ids.len());
+                kept_vertex_ids.push(i);
+            }
+        }
+
+        if kept_vertex_ids.len() == duplicate_vertices_info.len() { // No duplicates - no welding
+            return;
+        }
+
+        self.vertex_colors = self.get_vertex_colors_by_vertex_ids(kept_vertex_ids.iter().copied());
+        self.attributes = self.get_attributes_by_ids(&kept_vertex_ids, &(0..self.get_number_of_faces()).collect::<Vec<usize>>());
+        for (new_vertex_id, old_vertex_id) in kept_vertex_ids.iter().enumerate() {
+            self.coordinates.copy_within(old_vertex_id * 3..old_vertex_id * 3 + 3, new_vertex_id * 3);
+            if let Some(UvChannel::PerVertex(uvs)) = &mut self.uvs {
+                uvs.copy_within(old_vertex_id * 2..old_vertex_id * 2 + 2, new_vertex_id * 2);
+            }
+        }
+        self.coordinates.truncate(kept_vertex_ids.len() * 3);
+        if let Some(UvChannel::PerVertex(uvs)) = &mut self.uvs {
+            uvs.truncate(kept_vertex_ids.len() * 2);
+        }
+        for index in self.indices.iter_mut() {
+            *index = new_vertex_ids[*index];
+        }
+        self.vertex_normals = None;
+        self.invalidate_cache();
+    }
+
+    /// Creates a new [Mesh] with welded vertices, see [Mesh::get_with_welded_vertices], together
+    /// with the [WeldReport] describing what was merged.
+    ///
+    /// Clusters of merged vertices with more than `large_cluster_size` vertices are listed in
+    /// the report, as it usually means that the `tolerance` was set too high.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    /// vec![
+    ///     0.0, 0.0, 0.0,
+    ///     10.0, 0.0, 0.0,
+    ///     10.0, 10.0, 0.0,
+    ///
+    ///     0.0, 0.0, 0.0, // duplicate of 0
+    ///     10.0, 10.0, 0.0005, // duplicate of 2
+    ///     0.0, 10.0, 0.0,
+    /// ],
+    /// vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    ///
+    /// let (actual, report) = input.get_with_welded_vertices_with_report(0.001, 1);
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 4);
+    /// assert_eq!(report.number_of_merged_vertices, 2);
+    /// assert_eq!(report.max_merge_distance, 0.0005);
+    /// assert_eq!(report.large_clusters, vec![vec![0, 3], vec![2, 4]]);
+    /// ```
+    pub fn get_with_welded_vertices_with_report(&self, tolerance: f64, large_cluster_size: usize) -> (Mesh, WeldReport) {
+        self.get_with_welded_vertices_with_uv_tolerance_and_report(tolerance, tolerance, large_cluster_size)
+    }
+
+    /// Creates a new [Mesh] with welded vertices, see [Mesh::get_with_welded_vertices], using a
+    /// separate tolerance for UVs.
+    ///
+    /// If the [Mesh] has per vertex `uvs`, vertices are welded only if both their positions are
+    /// the same within `tolerance`, and their UVs are the same within `uv_tolerance`, so UV seams
+    /// are kept. Per corner `uvs` don't depend on vertices, so they're kept as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::uv_channel::UvChannel;
+    ///
+    /// let mut input = Mesh::new(
+    /// vec![
+    ///     0.0, 0.0, 0.0,
+    ///     10.0, 0.0, 0.0,
+    ///     10.0, 10.0, 0.0,
+    ///
+    ///     0.0, 0.0, 0.0, // same position and UV as 0
+    ///     10.0, 10.0, 0.0, // same position as 2, but different UV (seam)
+    ///     0.0, 10.0, 0.0,
+    /// ],
+    /// vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.5, 0.5, 0.0, 1.0]));
+    ///
+    /// let actual = input.get_with_welded_vertices_with_uv_tolerance(0.001, 0.0001);
+    ///
+    /// assert_eq!(actual.indices, vec![0, 1, 2, 0, 3, 4]);
+    /// assert_eq!(actual.uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.5, 0.5, 0.0, 1.0])));
+    /// ```
+    pub fn get_with_welded_vertices_with_uv_tolerance(&self, tolerance: f64, uv_tolerance: f64) -> Mesh {
+        self.get_with_welded_vertices_with_uv_tolerance_and_report(tolerance, uv_tolerance, usize::MAX).0
+    }
+
+    /// Creates a new [Mesh] with welded vertices, like [Mesh::get_with_welded_vertices], but
+    /// finds duplicates using a hash grid with the cell size equal to the `tolerance`, so it
+    /// takes O(n) time on average.
+    ///
+    /// Every vertex is welded into the latest of the earlier kept vertices within the `tolerance`
+    /// (checked the same way as in [Point::eq_with_tolerance]), so the result is the same as of
+    /// [Mesh::get_with_welded_vertices], also for chains of vertices closer to each other than
+    /// the `tolerance`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0,
+    ///          0.0, 0.0, 0.0, 1.0, 1.0, 0.0005, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    ///
+    /// let actual = input.get_with_welded_vertices_fast(0.001);
+    ///
+    /// assert_eq!(actual.coordinates, vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0]);
+    /// assert_eq!(actual.indices, vec![0, 1, 2, 0, 2, 3]);
+    /// assert_eq!(actual, input.get_with_welded_vertices(0.001));
+    /// ```
+    pub fn get_with_welded_vertices_fast(&self, tolerance: f64) -> Mesh {
+        let vertices = self.to_points();
+        let mut welder = VertexWelder::new(tolerance);
+        let mut first_occurrences: Vec<usize> = Vec::new();
+        let duplicate_vertices_info = vertices.iter().enumerate().map(|(i, vertex)| {
+            let welded_vertex_id = welder.add_vertex(vertex);
+            if welded_vertex_id == first_occurrences.len() {
+                first_occurrences.push(i);
+                (i, false)
+            }
+            else {
+                (first_occurrences[welded_vertex_id], true)
+            }
+        }).collect();
+
+        self.get_with_welded_duplicates(&vertices, duplicate_vertices_info, tolerance, usize::MAX).0
+    }
+
+    fn get_with_welded_vertices_with_uv_tolerance_and_report(&self, tolerance: f64, uv_tolerance: f64, large_cluster_size: usize) -> (Mesh, WeldReport) {
         let vertices = self.to_points();
         let duplicate_vertices_info = Point::scan_for_duplicates_with_tolerance_info(&vertices, tolerance);
+        self.get_with_welded_duplicates(&vertices, duplicate_vertices_info, uv_tolerance, large_cluster_size)
+    }
+
+    /// Welds vertices marked as duplicates in `duplicate_vertices_info` (in the format of
+    /// [Point::scan_for_duplicates_with_tolerance_info]), keeping per vertex UV seams.
+    pub(crate) fn get_with_welded_duplicates(&self, vertices: &[Point], mut duplicate_vertices_info: Vec<(usize, bool)>, uv_tolerance: f64, large_cluster_size: usize) -> (Mesh, WeldReport) {
+        if let Some(UvChannel::PerVertex(uvs)) = &self.uvs {
+            split_duplicates_by_uvs(&mut duplicate_vertices_info, uvs, uv_tolerance);
+        }
         let info_length = duplicate_vertices_info.len();
+        let report = get_weld_report(vertices, &duplicate_vertices_info, large_cluster_size);
 
         let mut duplicates_above_count: Vec<usize> = Vec::<usize>::new(); // First step is to create a Vec of duplicates above these vertices. It is necessary to apply proper offset later.
         let mut current_duplicates_count = 0;
@@ -522,12 +975,25 @@ impl Mesh {
             }
 
             let mesh_with_replaced_indices = self.get_with_replaced_indices(indices_replacement_instructions);
-            let mesh_with_replaced_indices_and_removed_vertices = mesh_with_replaced_indices.get_with_removed_vertices_without_indices_update(vertices_replacement_instructions);
-
-            mesh_with_replaced_indices_and_removed_vertices
+            let mut mesh_with_replaced_indices_and_removed_vertices = mesh_with_replaced_indices.get_with_removed_vertices_without_indices_update(vertices_replacement_instructions);
+            let kept_vertex_ids: Vec<usize> = (0..info_length).filter(|i| !duplicate_vertices_info[*i].1).collect();
+            mesh_with_replaced_indices_and_removed_vertices.uvs = match &self.uvs {
+                Some(UvChannel::PerVertex(uvs)) => Some(UvChannel::PerVertex(kept_vertex_ids.iter().flat_map(|i| [uvs[i * 2], uvs[i * 2 + 1]]).collect())),
+                other => other.clone(),
+            };
+            mesh_with_replaced_indices_and_removed_vertices.vertex_colors = self.get_vertex_colors_by_vertex_ids(kept_vertex_ids.iter().copied());
+            mesh_with_replaced_indices_and_removed_vertices.face_groups = self.face_groups.clone();
+            mesh_with_replaced_indices_and_removed_vertices.attributes = self.get_attributes_by_ids(&kept_vertex_ids, &(0..self.get_number_of_faces()).collect::<Vec<usize>>());
+
+            (mesh_with_replaced_indices_and_removed_vertices, report)
         }
         else { // No duplicates - no welding
-            Mesh::new_with_id(self.id, self.coordinates.clone(), self.indices.clone())
+            let mut mesh = Mesh::new_with_id(self.id, self.coordinates.clone(), self.indices.clone());
+            mesh.uvs = self.uvs.clone();
+            mesh.vertex_colors = self.vertex_colors.clone();
+            mesh.face_groups = self.face_groups.clone();
+            mesh.attributes = self.attributes.clone();
+            (mesh, report)
         }
     }
 
@@ -611,7 +1077,12 @@ impl Mesh {
     /// assert_eq!(expected.eq(&actual), true);
     /// ```
     pub fn get_with_unwelded_vertices(&self) -> Mesh {
-        Mesh::from_triangles(self.to_triangles())
+        let mut unwelded = Mesh::from_triangles(self.to_triangles());
+        unwelded.uvs = self.get_corner_uvs().map(UvChannel::PerVertex);
+        unwelded.vertex_colors = self.get_vertex_colors_by_vertex_ids(self.indices.iter().copied());
+        unwelded.face_groups = self.face_groups.clone();
+        unwelded.attributes = self.get_attributes_by_ids(&self.indices, &(0..self.get_number_of_faces()).collect::<Vec<usize>>());
+        unwelded
     }
 
     /// Creates a new [Mesh], but for all indices it offsets them by given number.
@@ -677,6 +1148,26 @@ impl Mesh {
         Mesh::new_with_id(self.id, self.coordinates.clone(), new_indices)
     }
 
+    /// Adds `index_offset` to all the indices, see [Mesh::get_with_index_offset].
+    ///
+    /// It mutates the Mesh, so no copy of `coordinates` is created.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// input.offset_indices(10);
+    ///
+    /// assert_eq!(input.indices, vec![10, 11, 12]);
+    /// ```
+    pub fn offset_indices(&mut self, index_offset: usize) {
+        self.indices.iter_mut().for_each(|x| *x += index_offset);
+        self.invalidate_cache();
+    }
+
     /// Creates a new [Mesh] which is a result of joining it with another one.
     ///
     /// # Example
@@ -714,7 +1205,12 @@ impl Mesh {
         let mut indices: Vec<usize> = self.indices.clone();
         indices.extend(another_mesh_with_indices_offset.indices.clone());
 
-        Mesh::new(coordinates, indices)
+        let mut joined = Mesh::new(coordinates, indices);
+        joined.uvs = Mesh::get_joined_uvs(&[self, another_mesh]);
+        joined.vertex_colors = Mesh::get_joined_vertex_colors(&[self, another_mesh]);
+        joined.face_groups = Mesh::get_joined_face_groups(&[self, another_mesh]);
+        joined.attributes = Mesh::get_joined_attributes(&[self, another_mesh]);
+        joined
     }
 
     /// Creates a new [Mesh] which is a result of joining it with another one.
@@ -759,6 +1255,10 @@ impl Mesh {
     /// ```
     pub fn join(meshes: &Vec<Mesh>) -> Mesh {
         let mut joined = Mesh::new(meshes[0].coordinates.clone(), meshes[0].indices.clone());
+        joined.uvs = meshes[0].uvs.clone();
+        joined.vertex_colors = meshes[0].vertex_colors.clone();
+        joined.face_groups = meshes[0].face_groups.clone();
+        joined.attributes = meshes[0].attributes.clone();
         let number_of_meshes = meshes.len();
         for i in 1..number_of_meshes {
             joined = joined.get_by_joining_with(&meshes[i]);
@@ -767,27 +1267,115 @@ impl Mesh {
         joined
     }
 
-    /// Gets only specific part of the [Mesh] using specified face ids.
+    /// Creates a new [Mesh] which is a result of joining given Meshes, remembering which face
+    /// came from which Mesh.
     ///
-    /// The result Mesh is unwelded.
-    /// 
-    /// The new result Mesh has no `id` (`None`).
+    /// The index of the source [Mesh] is stored for every face in `face_groups`, so the joined
+    /// Mesh can be split back later with [Mesh::split_by_face_groups], or colored per source.
+    ///
+    /// Vertices are not welded. Joined [Mesh] has an `id`: `None`.
     ///
     /// # Example
     ///
     /// ```
     /// use meshmeshmesh::mesh::Mesh;
     ///
-    /// let input = Mesh::new(
-    /// vec![
-    ///     // Base
-    ///     -2.0,1.0,0.0,
-    ///     8.0,1.0,0.0,
-    ///     8.0,11.0,0.0,
-    ///     -2.0,11.0,0.0,
+    /// let a = Mesh::new(vec![0.0, 0.0, 0.0,
+    ///                        10.0, 0.0, 0.0,
+    ///                        10.0, -15.0, 0.0],
+    /// vec![0, 1, 2]);
     ///
-    ///     // Top
-    ///     3.0,6.0,4.0
+    /// let b = Mesh::new(vec![0.0, 0.0, 5.0,
+    ///                        10.0, 0.0, 5.0,
+    ///                        10.0, 5.0, 5.0,
+    ///                        10.0, -15.0, 5.0],
+    /// vec![0, 1, 2, 1, 2, 3]);
+    ///
+    /// let actual = Mesh::join_with_groups(&[a, b]);
+    ///
+    /// assert_eq!(actual.indices, vec![0, 1, 2, 3, 4, 5, 4, 5, 6]);
+    /// assert_eq!(actual.face_groups, Some(vec![0, 1, 1]));
+    /// ```
+    pub fn join_with_groups(meshes: &[Mesh]) -> Mesh {
+        let mut coordinates: Vec<f64> = Vec::new();
+        let mut indices: Vec<usize> = Vec::new();
+        let mut face_groups: Vec<usize> = Vec::new();
+
+        for (group_id, mesh) in meshes.iter().enumerate() {
+            let offset = coordinates.len() / 3;
+            coordinates.extend_from_slice(&mesh.coordinates);
+            indices.extend(mesh.indices.iter().map(|index| index + offset));
+            face_groups.extend(std::iter::repeat_n(group_id, mesh.get_number_of_faces()));
+        }
+
+        let mut joined = Mesh::new(coordinates, indices);
+        joined.face_groups = Some(face_groups);
+        let meshes: Vec<&Mesh> = meshes.iter().collect();
+        joined.uvs = Mesh::get_joined_uvs(&meshes);
+        joined.vertex_colors = Mesh::get_joined_vertex_colors(&meshes);
+        joined.attributes = Mesh::get_joined_attributes(&meshes);
+        joined
+    }
+
+    /// Splits given [Mesh] into separate Meshes using its `face_groups`.
+    ///
+    /// The result contains 1 [Mesh] per group id: from 0 up to the highest group id. Groups
+    /// without any faces produce empty Meshes, so the position of the result Mesh is always
+    /// equal to its group id. It's the inverse of [Mesh::join_with_groups].
+    ///
+    /// Only the vertices used by each group are kept, their sharing (welding) is preserved.
+    /// Result Meshes have no `face_groups`.
+    ///
+    /// It panics if the [Mesh] has no `face_groups`, or if their number is different than the
+    /// number of faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let a = Mesh::new(vec![0.0, 0.0, 0.0,
+    ///                        10.0, 0.0, 0.0,
+    ///                        10.0, -15.0, 0.0],
+    /// vec![0, 1, 2]);
+    ///
+    /// let b = Mesh::new(vec![0.0, 0.0, 5.0,
+    ///                        10.0, 0.0, 5.0,
+    ///                        10.0, 5.0, 5.0,
+    ///                        10.0, -15.0, 5.0],
+    /// vec![0, 1, 2, 1, 2, 3]);
+    ///
+    /// let joined = Mesh::join_with_groups(&[a.clone(), b.clone()]);
+    ///
+    /// let actual = joined.split_by_face_groups();
+    ///
+    /// assert_eq!(actual, vec![a, b]);
+    /// ```
+    pub fn split_by_face_groups(&self) -> Vec<Mesh> {
+        self.split_by_face_groups_with_provenance().into_iter().map(|(part, _)| part).collect()
+    }
+
+    /// Gets only specific part of the [Mesh] using specified face ids.
+    ///
+    /// The result Mesh is unwelded.
+    /// 
+    /// The new result Mesh has no `id` (`None`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    /// vec![
+    ///     // Base
+    ///     -2.0,1.0,0.0,
+    ///     8.0,1.0,0.0,
+    ///     8.0,11.0,0.0,
+    ///     -2.0,11.0,0.0,
+    ///
+    ///     // Top
+    ///     3.0,6.0,4.0
     /// ],
     /// vec![
     ///     // Base faces
@@ -844,7 +1432,218 @@ impl Mesh {
             current_max += 3;
         }
 
-        Mesh::new(coordinates, indices)
+        let mut part = Mesh::new(coordinates, indices);
+        part.uvs = self.get_corner_uvs_by_face_ids(face_ids).map(UvChannel::PerVertex);
+        let vertex_ids: Vec<usize> = face_ids.iter().flat_map(|face_id| self.indices[face_id * 3..face_id * 3 + 3].to_vec()).collect();
+        part.vertex_colors = self.get_vertex_colors_by_vertex_ids(vertex_ids.iter().copied());
+        part.face_groups = self.get_face_groups_by_face_ids(face_ids);
+        part.attributes = self.get_attributes_by_ids(&vertex_ids, face_ids);
+        part
+    }
+
+    /// Gets only specific part of the [Mesh] using specified face ids, keeping the vertices welded.
+    ///
+    /// Only the vertices used by given faces are kept, in their original order.
+    ///
+    /// The new result Mesh has no `id` (`None`).
+    pub(crate) fn get_welded_part_by_face_ids(&self, face_ids: &[usize]) -> Mesh {
+        self.get_welded_part_by_face_ids_with_new_vertex_ids(face_ids).0
+    }
+
+    /// Gets the welded part of the [Mesh], see [Mesh::get_welded_part_by_face_ids], together
+    /// with the new id of every original vertex (`None` if the vertex is not used by given faces).
+    fn get_welded_part_by_face_ids_with_new_vertex_ids(&self, face_ids: &[usize]) -> (Mesh, Vec<Option<usize>>) {
+        let mut new_vertex_ids: Vec<Option<usize>> = vec![None; self.get_number_of_vertices()];
+        for face_id in face_ids {
+            for i in 0..3 {
+                new_vertex_ids[self.indices[face_id * 3 + i]] = Some(0);
+            }
+        }
+
+        let mut coordinates: Vec<f64> = Vec::new();
+        let mut vertex_uvs: Vec<f64> = Vec::new();
+        let mut kept_vertex_ids: Vec<usize> = Vec::new();
+        let mut current_vertex_id = 0;
+        for (vertex_id, new_vertex_id) in new_vertex_ids.iter_mut().enumerate() {
+            if new_vertex_id.is_some() {
+                *new_vertex_id = Some(current_vertex_id);
+                kept_vertex_ids.push(vertex_id);
+                coordinates.extend_from_slice(&self.coordinates[vertex_id * 3..vertex_id * 3 + 3]);
+                if let Some(UvChannel::PerVertex(uvs)) = &self.uvs {
+                    vertex_uvs.extend_from_slice(&uvs[vertex_id * 2..vertex_id * 2 + 2]);
+                }
+                current_vertex_id += 1;
+            }
+        }
+
+        let mut indices: Vec<usize> = Vec::with_capacity(face_ids.len() * 3);
+        for face_id in face_ids {
+            for i in 0..3 {
+                indices.push(new_vertex_ids[self.indices[face_id * 3 + i]].unwrap());
+            }
+        }
+
+        let mut part = Mesh::new(coordinates, indices);
+        part.uvs = match &self.uvs {
+            Some(UvChannel::PerVertex(_)) => Some(UvChannel::PerVertex(vertex_uvs)),
+            Some(UvChannel::PerCorner(_)) => self.get_corner_uvs_by_face_ids(face_ids).map(UvChannel::PerCorner),
+            None => None,
+        };
+        part.vertex_colors = self.get_vertex_colors_by_vertex_ids(kept_vertex_ids.iter().copied());
+        part.face_groups = self.get_face_groups_by_face_ids(face_ids);
+        part.attributes = self.get_attributes_by_ids(&kept_vertex_ids, face_ids);
+        (part, new_vertex_ids)
+    }
+
+    /// Creates a new [Mesh] where vertices of nearly-coplanar regions are projected exactly
+    /// onto their best-fit [Plane]s.
+    ///
+    /// Regions are groups of connected faces grown from seed faces, where the angle between
+    /// the normal of each face and the normal of the region's seed face is not higher than
+    /// `angle_tolerance` (in radians). Unlike in [Mesh::split_by_face_angle], small angles
+    /// don't add up, so curved surfaces are not flattened: finely tessellated curves are moved
+    /// by no more than the sagitta of the `angle_tolerance` arc. Each region with more than 1
+    /// face gets its least-squares best-fit [Plane]. Vertices shared by multiple regions are
+    /// moved to the point closest to all of their [Plane]s (e.g. to the intersection line of
+    /// 2 [Plane]s), or to the average of their projections if these [Plane]s are nearly
+    /// parallel.
+    ///
+    /// Topology is not changed, other data is copied without [Mesh::vertex_normals]. It
+    /// improves the success rate of [Mesh::get_planar_simplify] for noisy, nearly planar
+    /// Meshes.
+    ///
+    /// The input [Mesh] should be welded.
+    ///
+    /// # Example
+    ///
+    /// Here is an example of the quad made of 2 triangles, where one of the vertices is
+    /// slightly off the plane.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         10.0,0.0,0.0,
+    ///         10.0,10.0,0.04,
+    ///         0.0,10.0,0.0,
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///         0,2,3
+    ///     ]);
+    ///
+    /// let actual = input.get_with_planarized_regions(0.01);
+    ///
+    /// let expected = Mesh::new(
+    ///     vec![
+    ///         0.00002,0.00002,-0.01,
+    ///         9.99998,-0.00002,0.01,
+    ///         10.00002,10.00002,0.03,
+    ///         -0.00002,9.99998,0.01,
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///         0,2,3
+    ///     ]);
+    ///
+    /// assert!(expected.eq_with_tolerance(&actual, 0.00001));
+    /// ```
+    pub fn get_with_planarized_regions(&self, angle_tolerance: f64) -> Mesh {
+        let face_neighbours = FaceNeighbours::from_mesh(self);
+        let triangles = self.to_triangles();
+        let mut is_assigned = vec![false; triangles.len()];
+        let mut regions: Vec<Vec<usize>> = Vec::new();
+        for seed in 0..triangles.len() {
+            if is_assigned[seed] {
+                continue;
+            }
+            is_assigned[seed] = true;
+            let mut region = vec![seed];
+            let mut stack = vec![seed];
+            while let Some(face_id) = stack.pop() {
+                let neighbours = &face_neighbours[face_id];
+                for neighbour in [neighbours.first, neighbours.second, neighbours.third].into_iter().flatten() {
+                    if !is_assigned[neighbour] && triangles[seed].get_normals_angle_checked(&triangles[neighbour]).is_some_and(|angle| angle <= angle_tolerance) {
+                        is_assigned[neighbour] = true;
+                        region.push(neighbour);
+                        stack.push(neighbour);
+                    }
+                }
+            }
+            regions.push(region);
+        }
+
+        let points = self.to_points();
+        let mut vertex_planes: Vec<Vec<Plane>> = vec![Vec::new(); points.len()];
+        for region in regions {
+            if region.len() < 2 { // Single triangle is always planar
+                continue;
+            }
+            let mut vertex_ids: Vec<usize> = region.iter().flat_map(|face_id| self.indices[face_id * 3..face_id * 3 + 3].to_vec()).collect();
+            vertex_ids.sort();
+            vertex_ids.dedup();
+            let region_points: Vec<Point> = vertex_ids.iter().map(|vertex_id| points[*vertex_id]).collect();
+            let plane = Plane::new_best_fit(&region_points);
+            for vertex_id in vertex_ids {
+                vertex_planes[vertex_id].push(plane);
+            }
+        }
+
+        self.get_with_moved_vertices(|vertex_id, point| {
+            let planes = &vertex_planes[vertex_id];
+            let are_planes_nearly_parallel = planes.iter().all(|plane| plane.normal.get_angle(&planes[0].normal) <= 2.0 * angle_tolerance);
+            match planes.len() {
+                0 => point,
+                1 => planes[0].get_closest_point(&point),
+                _ if are_planes_nearly_parallel => { // Intersection of nearly parallel Planes would slide the vertex along the surface
+                    let sum = planes.iter().fold(Vector::zero(), |sum, plane| sum + plane.get_closest_point(&point).to_vector());
+                    (sum * (1.0 / planes.len() as f64)).to_point()
+                },
+                _ => Self::get_closest_point_to_planes(&point, planes),
+            }
+        })
+    }
+
+    /// Gets the [Point] closest (in the least squares sense) to all given [Plane]s.
+    ///
+    /// Directions not constrained by [Plane]s (e.g. along the intersection line of 2 [Plane]s)
+    /// are kept as close as possible to the original [Point].
+    pub(crate) fn get_closest_point_to_planes(point: &Point, planes: &[Plane]) -> Point {
+        let regularization = 1e-9;
+        let mut matrix = [[0.0; 3]; 3];
+        let mut right_side = [regularization * point.x, regularization * point.y, regularization * point.z];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = regularization;
+        }
+        for plane in planes {
+            let normal = [plane.normal.x, plane.normal.y, plane.normal.z];
+            let offset = plane.normal.get_dot_product(&plane.origin.to_vector());
+            for row in 0..3 {
+                for column in 0..3 {
+                    matrix[row][column] += normal[row] * normal[column];
+                }
+                right_side[row] += normal[row] * offset;
+            }
+        }
+
+        let determinant = |m: &[[f64; 3]; 3]| {
+            m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+        };
+        let main_determinant = determinant(&matrix);
+        let mut solution = [0.0; 3];
+        for (column, value) in solution.iter_mut().enumerate() { // Cramer's rule
+            let mut replaced = matrix;
+            for row in 0..3 {
+                replaced[row][column] = right_side[row];
+            }
+            *value = determinant(&replaced) / main_determinant;
+        }
+
+        Point::new(solution[0], solution[1], solution[2])
     }
 
     /// Tries to simplify the planar parts of the [Mesh].
@@ -1357,8 +2156,8 @@ impl Mesh {
     ///
     /// ```
     pub fn get_planar_simplify(&self, tolerance: f64, angle_tolerance: f64) -> Result<Mesh, String> {
-        let original_aabb = self.get_bounding_box();
-        let original_area = self.get_area();
+        let original_aabb = self.get_cached_bounding_box();
+        let original_area: f64 = self.get_cached_face_areas().iter().sum();
 
         let welded_original = self.get_with_welded_vertices(tolerance);
 
@@ -1466,30 +2265,14 @@ impl Mesh {
     ///
     /// ```
     pub fn split_by_face_disconnected(&self, weld_vertices_tolerance: Option<f64>) -> Vec<Mesh> {
-
-        let face_neighbours = FaceNeighbours::from_mesh(self);
-        let graph = Graph::from_face_neighbours(&face_neighbours);
-
-        let isolated_groups = graph.split_disconnected_vertices();
-        let mut isolated_meshes:Vec<Mesh> = Vec::new();
-        for isolated_group in isolated_groups {
-            isolated_meshes.push(self.get_part_by_face_ids(&isolated_group))
-        }
-
-        if weld_vertices_tolerance.is_some() {
-            let tolerance = weld_vertices_tolerance.unwrap();
-            for i in 0..isolated_meshes.len() {
-                isolated_meshes[i] = isolated_meshes[i].get_with_welded_vertices(tolerance);
-            }
-        }
-
-        isolated_meshes
+        self.split_by_face_disconnected_with_provenance(weld_vertices_tolerance).into_iter().map(|(part, _)| part).collect()
     }
 
     /// Splits given [Mesh] where the value of angle between faces' normals is higher than given one
     /// in the `max_angle` parameter. This angle should be given in radians.
     ///
-    /// Optionally you can use `weld_vertices_tolerance` to weld resulting [Mesh]es.
+    /// Optionally you can use `weld_vertices_tolerance` to weld resulting [Mesh]es
+    /// (see [Mesh::get_with_welded_vertices_fast]).
     ///
     /// # Example
     ///
@@ -1529,27 +2312,198 @@ impl Mesh {
     ///
     /// ```
     pub fn split_by_face_angle(&self, max_angle: f64, weld_vertices_tolerance: Option<f64>) -> Vec<Mesh> {
+        self.split_by_face_angle_with_provenance(max_angle, weld_vertices_tolerance).into_iter().map(|(part, _)| part).collect()
+    }
+
+    /// Gets face ids of parts which are not connected with each other, see
+    /// [Mesh::split_by_face_disconnected].
+    pub(crate) fn get_face_ids_split_by_face_disconnected(&self) -> Vec<Vec<usize>> {
+        let face_neighbours = FaceNeighbours::from_mesh(self);
+        let graph = Graph::from_face_neighbours(&face_neighbours);
 
+        graph.split_disconnected_vertices()
+    }
+
+    /// Gets face ids of parts split where the angle between faces' normals is higher than the
+    /// `max_angle`, see [Mesh::split_by_face_angle].
+    pub(crate) fn get_face_ids_split_by_face_angle(&self, max_angle: f64) -> Vec<Vec<usize>> {
         let face_neighbours = FaceNeighbours::from_mesh(self);
         let triangles = self.to_triangles();
         let face_neighbours_angles = FaceNeighboursAngle::from_face_neighbours_and_triangles(&face_neighbours, &triangles);
 
         let graph = Graph::from_face_neighbours_with_max_angle(&face_neighbours, &face_neighbours_angles, max_angle);
 
-        let isolated_groups = graph.split_disconnected_vertices();
-        let mut isolated_meshes:Vec<Mesh> = Vec::new();
-        for isolated_group in isolated_groups {
-            isolated_meshes.push(self.get_part_by_face_ids(&isolated_group))
+        graph.split_disconnected_vertices()
+    }
+
+    /// Splits given [Mesh] into patches, where each of them is a topological disk.
+    ///
+    /// Topological disk means the patch is connected, has exactly 1 boundary loop and has no
+    /// handles (tunnels). Closed parts (e.g. spheres) and parts with handles (e.g. tori) are cut
+    /// into multiple patches. It's a prerequisite for operations such as UV unwrapping or
+    /// unfolding.
+    ///
+    /// Patches are grown greedily face by face (breadth-first), adding only faces that keep
+    /// the patch a disk.
+    ///
+    /// The input [Mesh] should be welded, because the topology is read from shared vertices.
+    /// Resulting Meshes stay welded.
+    ///
+    /// # Example
+    ///
+    /// Here is an example with the closed pyramid, which has to be cut into 2 patches.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         -2.0,1.0,0.0,
+    ///         8.0,1.0,0.0,
+    ///         8.0,11.0,0.0,
+    ///         -2.0,11.0,0.0,
+    ///         3.0,6.0,4.0
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///         0,2,3,
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// let actual = input.split_into_disk_patches();
+    ///
+    /// let expected = vec![
+    ///     Mesh::new(
+    ///         vec![
+    ///             -2.0,1.0,0.0,
+    ///             8.0,1.0,0.0,
+    ///             8.0,11.0,0.0,
+    ///             -2.0,11.0,0.0,
+    ///             3.0,6.0,4.0
+    ///         ],
+    ///         vec![
+    ///             0,1,2,
+    ///             0,2,3,
+    ///             0,1,4,
+    ///             1,2,4,
+    ///             3,0,4
+    ///         ]),
+    ///     Mesh::new(
+    ///         vec![
+    ///             8.0,11.0,0.0,
+    ///             -2.0,11.0,0.0,
+    ///             3.0,6.0,4.0
+    ///         ],
+    ///         vec![
+    ///             0,1,2
+    ///         ]),
+    /// ];
+    ///
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn split_into_disk_patches(&self) -> Vec<Mesh> {
+        let patches = self.get_disk_patches_face_ids();
+
+        patches.iter().map(|face_ids| self.get_welded_part_by_face_ids(face_ids)).collect()
+    }
+
+    /// Gets face ids of patches, where each of them is a topological disk.
+    ///
+    /// See [Mesh::split_into_disk_patches] for details.
+    pub(crate) fn get_disk_patches_face_ids(&self) -> Vec<Vec<usize>> {
+        self.get_disk_patches_face_ids_within_groups(&vec![0; self.get_number_of_faces()])
+    }
+
+    /// Gets face ids of patches, where each of them is a topological disk, without crossing
+    /// the borders of given face groups.
+    ///
+    /// `face_groups` assigns a group id to each face, so each patch contains faces of 1 group only.
+    pub(crate) fn get_disk_patches_face_ids_within_groups(&self, face_groups: &[usize]) -> Vec<Vec<usize>> {
+        let number_of_faces = self.get_number_of_faces();
+        let mut edge_faces: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+        for face_id in 0..number_of_faces {
+            for edge in Self::get_face_edge_keys(&self.indices, face_id) {
+                edge_faces.entry(edge).or_default().push(face_id);
+            }
+        }
+
+        let mut face_patch: Vec<Option<usize>> = vec![None; number_of_faces];
+        let mut vertex_patch: Vec<Option<usize>> = vec![None; self.get_number_of_vertices()];
+        let mut patches: Vec<Vec<usize>> = Vec::new();
+
+        for seed in 0..number_of_faces {
+            if face_patch[seed].is_some() {
+                continue;
+            }
+
+            let patch_id = patches.len();
+            let mut patch: Vec<usize> = Vec::new();
+            let mut queue: VecDeque<usize> = VecDeque::new();
+            queue.push_back(seed);
+            while let Some(face_id) = queue.pop_front() {
+                if face_patch[face_id].is_some() {
+                    continue;
+                }
+                if face_id != seed && !self.can_extend_disk_patch(face_id, patch_id, &edge_faces, &face_patch, &vertex_patch) {
+                    continue; // It could be reconsidered after one of its neighbours is added
+                }
+
+                face_patch[face_id] = Some(patch_id);
+                patch.push(face_id);
+                for i in 0..3 {
+                    vertex_patch[self.indices[face_id * 3 + i]] = Some(patch_id);
+                }
+                for edge in Self::get_face_edge_keys(&self.indices, face_id) {
+                    for neighbour in &edge_faces[&edge] {
+                        if face_patch[*neighbour].is_none() && face_groups[*neighbour] == face_groups[face_id] {
+                            queue.push_back(*neighbour);
+                        }
+                    }
+                }
+            }
+
+            patch.sort(); // Keeping the original order of faces
+            patches.push(patch);
         }
 
-        if weld_vertices_tolerance.is_some() {
-            let tolerance = weld_vertices_tolerance.unwrap();
-            for i in 0..isolated_meshes.len() {
-                isolated_meshes[i] = isolated_meshes[i].get_with_welded_vertices(tolerance);
+        patches
+    }
+
+    /// Checks if adding given face to the disk patch keeps it a topological disk.
+    fn can_extend_disk_patch(&self, face_id: usize, patch_id: usize, edge_faces: &HashMap<(usize, usize), Vec<usize>>, face_patch: &[Option<usize>], vertex_patch: &[Option<usize>]) -> bool {
+        let edges = Self::get_face_edge_keys(&self.indices, face_id);
+        let mut shared_edges = Vec::new();
+        for edge in edges {
+            let number_of_patch_faces = edge_faces[&edge].iter().filter(|f| face_patch[**f] == Some(patch_id)).count();
+            if number_of_patch_faces > 1 {
+                return false; // Edge is already inside the patch
+            }
+            if number_of_patch_faces == 1 {
+                shared_edges.push(edge);
             }
         }
 
-        isolated_meshes
+        match shared_edges.len() {
+            1 => { // Growing outside: the opposite vertex has to be new, otherwise the boundary would get pinched
+                let (a, b) = shared_edges[0];
+                let opposite = (0..3).map(|i| self.indices[face_id * 3 + i]).find(|v| *v != a && *v != b).unwrap();
+                vertex_patch[opposite] != Some(patch_id)
+            },
+            2 => true, // Filling the notch between 2 consecutive boundary edges
+            _ => false, // Not connected or closing the hole
+        }
+    }
+
+    /// Gets edges of the face as sorted vertex id pairs.
+    pub(crate) fn get_face_edge_keys(indices: &[usize], face_id: usize) -> [(usize, usize); 3] {
+        let a = indices[face_id * 3];
+        let b = indices[face_id * 3 + 1];
+        let c = indices[face_id * 3 + 2];
+
+        [(a.min(b), a.max(b)), (b.min(c), b.max(c)), (c.min(a), c.max(a))]
     }
 
     /// Remeshes in the simplified way the [Mesh] which is already a planar one.
@@ -1703,12 +2657,12 @@ impl Mesh {
     /// ```
     pub fn get_planar_simplify_for_planar_mesh(&self, tolerance: f64, angle_tolerance: f64) -> Result<Mesh, String> {
         let original_normal = self.get_face_normal_vectors_unitized()[0];
-        let original_aabb = self.get_bounding_box();
-        let original_area = self.get_area();
+        let original_aabb = self.get_cached_bounding_box();
+        let original_area: f64 = self.get_cached_face_areas().iter().sum();
 
         let welded_original = self.get_with_welded_vertices(tolerance);
 
-        let polygons = welded_original.get_polygons_for_planar_mesh();
+        let polygons = welded_original.get_boundary_loops();
         
         let local_coordinate_system_of_polygons = self.get_local_coordinate_system_for_first_face(); // Converting Polygons to 2D Polygons
         let mut polygon2ds = Vec::with_capacity(polygons.len());
@@ -1731,7 +2685,8 @@ impl Mesh {
             let mut biggest_bounding_area = 0.0; // Trying to find out which Polygon2D is the main one and should be triangulated
             let mut biggest_bounding_area_polygon_id= 0;
             for i in 0..cleaned_polygon2ds.len() {
-                let current_bounding_area = cleaned_polygon2ds[i].get_bounding_area().get_area();
+                let rectangle = cleaned_polygon2ds[i].get_minimum_area_bounding_rectangle().vertices; // Unlike axis aligned one, it doesn't depend on rotation
+                let current_bounding_area = rectangle[0].get_distance_to_point(&rectangle[1]) * rectangle[1].get_distance_to_point(&rectangle[2]);
                 if current_bounding_area > biggest_bounding_area {
                     biggest_bounding_area = current_bounding_area;
                     biggest_bounding_area_polygon_id = i;
@@ -1934,6 +2889,7 @@ impl Mesh {
     pub fn deduplicate(meshes: Vec<Mesh>, tolerance: f64) -> Vec<(Mesh, Vec<LocalCoordinateSystem>)> {
         let mut uniques: Vec<(Mesh, Vec<LocalCoordinateSystem>)> = Vec::new();
         let mut aabbs_of_uniques: Vec<BoundingBox> = Vec::new();
+        let mut aabb_corners_of_uniques = Octree::new();
 
         for current_mesh in meshes {
             let mut found_duplicate = false;
@@ -1942,7 +2898,7 @@ impl Mesh {
             let current_mesh_in_global = current_mesh.get_in_global_coordinate_system(&current_mesh_local_coordinate_system);
             let current_aabb = current_mesh_in_global.get_bounding_box();
 
-            for i in 0..aabbs_of_uniques.len() {
+            for i in aabb_corners_of_uniques.get_point_ids_in_box(&Mesh::get_deduplication_search_box(&current_aabb, tolerance)) {
                 let existing_aabb = &aabbs_of_uniques[i];
                 if current_aabb.eq_with_tolerance(existing_aabb, tolerance) {
                     let existing_candidate_unique_mesh = &uniques[i].0;
@@ -1956,6 +2912,7 @@ impl Mesh {
 
             if !found_duplicate {
                 uniques.push((current_mesh_in_global, vec![current_mesh_local_coordinate_system]));
+                aabb_corners_of_uniques.insert_point(Point::new(current_aabb.min_x, current_aabb.min_y, current_aabb.min_z));
                 aabbs_of_uniques.push(current_aabb);
             }
         }
@@ -1963,6 +2920,19 @@ impl Mesh {
         uniques
     }
 
+    /// Gets the box around the minimum corner of the Bounding Box, in which minimum corners of
+    /// its duplicates can be. It's twice bigger than needed to be safe from rounding errors,
+    /// as candidates are checked with [BoundingBox::eq_with_tolerance] anyway.
+    fn get_deduplication_search_box(aabb: &BoundingBox, tolerance: f64) -> BoundingBox {
+        let padding = 2.0 * tolerance;
+
+        BoundingBox {
+            min_x: aabb.min_x - padding, max_x: aabb.min_x + padding,
+            min_y: aabb.min_y - padding, max_y: aabb.min_y + padding,
+            min_z: aabb.min_z - padding, max_z: aabb.min_z + padding,
+        }
+    }
+
     /// Creates a `vec` of tuples with unique [Mesh] located in Global Coordinate System,
     /// and a [HashMap] with original Mesh `id` as the key and [LocalCoordinateSystem]s where these 
     /// instances are located as value. This way it's easier to track which Meshes were deduplicated 
@@ -2142,6 +3112,7 @@ impl Mesh {
         
         let mut uniques: Vec<(Mesh, HashMap<usize, LocalCoordinateSystem>)> = Vec::new();
         let mut aabbs_of_uniques: Vec<BoundingBox> = Vec::new();
+        let mut aabb_corners_of_uniques = Octree::new();
 
         for current_mesh in meshes {
             let mut found_duplicate = false;
@@ -2150,7 +3121,7 @@ impl Mesh {
             let current_mesh_in_global = current_mesh.get_in_global_coordinate_system(&current_mesh_local_coordinate_system);
             let current_aabb = current_mesh_in_global.get_bounding_box();
 
-            for i in 0..aabbs_of_uniques.len() {
+            for i in aabb_corners_of_uniques.get_point_ids_in_box(&Mesh::get_deduplication_search_box(&current_aabb, tolerance)) {
                 let existing_aabb = &aabbs_of_uniques[i];
                 if current_aabb.eq_with_tolerance(existing_aabb, tolerance) {
                     let existing_candidate_unique_mesh = &uniques[i].0;
@@ -2166,6 +3137,7 @@ impl Mesh {
                 let mut new_map: HashMap<usize, LocalCoordinateSystem> = HashMap::new();
                 new_map.insert(current_mesh.id.unwrap(), current_mesh_local_coordinate_system);
                 uniques.push((current_mesh_in_global, new_map));
+                aabb_corners_of_uniques.insert_point(Point::new(current_aabb.min_x, current_aabb.min_y, current_aabb.min_z));
                 aabbs_of_uniques.push(current_aabb);
             }
         }
@@ -2173,45 +3145,370 @@ impl Mesh {
         uniques
     }
 
+    /// Creates instances of the `unique` [Mesh] placed in each of the [LocalCoordinateSystem]s,
+    /// which is the inverse of [Mesh::deduplicate] for a single unique [Mesh].
+    ///
+    /// Instances keep the id of the `unique` [Mesh], see [Mesh::instantiate_with_id_info] to
+    /// get the original ids back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let triangle = Mesh::new(vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let input = vec![triangle.clone(), triangle.clone() + Vector::new(5.0, 0.0, 0.0)];
+    ///
+    /// let deduplicated = Mesh::deduplicate(input.clone(), 0.001);
+    /// let actual = Mesh::instantiate(&deduplicated[0].0, &deduplicated[0].1);
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert!(actual[0].eq_with_tolerance(&input[0], 1e-9));
+    /// assert!(actual[1].eq_with_tolerance(&input[1], 1e-9));
+    /// ```
+    pub fn instantiate(unique: &Mesh, local_coordinate_systems: &[LocalCoordinateSystem]) -> Vec<Mesh> {
+        unique.get_instances(local_coordinate_systems)
+    }
+
+    /// Creates all the [Mesh]es back from the result of [Mesh::deduplicate_with_id_info], with
+    /// their original ids.
+    ///
+    /// The result is sorted by ids.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let triangle = Mesh::new_with_id(Some(7), vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let mut moved = triangle.clone() + Vector::new(0.0, 0.0, 3.0);
+    /// moved.id = Some(2);
+    ///
+    /// let deduplicated = Mesh::deduplicate_with_id_info(vec![triangle.clone(), moved.clone()], 0.001);
+    /// let actual = Mesh::instantiate_with_id_info(&deduplicated);
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert!(actual[0].eq_with_tolerance(&moved, 1e-9));
+    /// assert!(actual[1].eq_with_tolerance(&triangle, 1e-9));
+    /// ```
+    pub fn instantiate_with_id_info(uniques: &[(Mesh, HashMap<usize, LocalCoordinateSystem>)]) -> Vec<Mesh> {
+        let mut meshes: Vec<Mesh> = Vec::new();
+        for (unique, local_coordinate_systems) in uniques {
+            for (id, local_coordinate_system) in local_coordinate_systems {
+                let mut mesh = Mesh::instantiate(unique, &[*local_coordinate_system]).remove(0);
+                mesh.id = Some(*id);
+                meshes.push(mesh);
+            }
+        }
+        meshes.sort_by_key(|mesh| mesh.id);
+
+        meshes
+    }
+
+    /// Computes the [MeshDelta] which transforms this [Mesh] into the `other` one.
+    ///
+    /// Vertices are compared by their ids: vertices with the same id, but different coordinates
+    /// are stored as moved, surplus vertices are appended or removed at the end.
+    ///
+    /// Faces are compared using a greedy linear diff: faces of `other` are matched in order with
+    /// the next identical face of this [Mesh]. Unmatched faces of this [Mesh] are removed, unmatched
+    /// faces of `other` are added.
+    ///
+    /// Applying the result with [Mesh::apply_delta] on this [Mesh] gives exactly the `other` one
+    /// (with the id of this [Mesh]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         -2.0,1.0,0.0,
+    ///         8.0,1.0,0.0,
+    ///         8.0,11.0,0.0,
+    ///         -2.0,11.0,0.0,
+    ///         3.0,6.0,4.0
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///         0,2,3,
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// let other = Mesh::new(
+    ///     vec![
+    ///         -2.0,1.0,0.0,
+    ///         8.0,1.0,0.0,
+    ///         8.0,11.0,0.0,
+    ///         -2.0,11.0,0.0,
+    ///         3.0,6.0,10.0 // Top of the pyramid moved up
+    ///     ],
+    ///     vec![
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4 // Bottom faces removed
+    ///     ]);
+    ///
+    /// let actual = input.compute_delta(&other);
+    ///
+    /// assert_eq!(actual.moved_vertex_ids, vec![4]);
+    /// assert_eq!(actual.moved_coordinates, vec![3.0, 6.0, 10.0]);
+    /// assert_eq!(actual.removed_face_ids, vec![0, 1]);
+    /// assert!(actual.added_face_ids.is_empty());
+    ///
+    /// assert_eq!(input.apply_delta(&actual).unwrap(), other);
+    /// ```
+    pub fn compute_delta(&self, other: &Mesh) -> MeshDelta {
+        let source_number_of_vertices = self.get_number_of_vertices();
+        let source_number_of_faces = self.get_number_of_faces();
+        let number_of_vertices = other.get_number_of_vertices();
+        let mut delta = MeshDelta::new_empty(source_number_of_vertices, source_number_of_faces);
+        delta.number_of_vertices = number_of_vertices;
+
+        let number_of_common_vertices = source_number_of_vertices.min(number_of_vertices);
+        for i in 0..number_of_common_vertices {
+            let source_vertex = &self.coordinates[i*3..i*3+3];
+            let other_vertex = &other.coordinates[i*3..i*3+3];
+            if source_vertex != other_vertex {
+                delta.moved_vertex_ids.push(i);
+                delta.moved_coordinates.extend_from_slice(other_vertex);
+            }
+        }
+        if number_of_vertices > source_number_of_vertices {
+            delta.added_coordinates.extend_from_slice(&other.coordinates[source_number_of_vertices*3..]);
+        }
+
+        let mut source_positions: HashMap<[usize; 3], Vec<usize>> = HashMap::new(); // Face -> its ids in the source, descending, so the next one is popped from the end
+        for face_id in (0..source_number_of_faces).rev() {
+            let face = [self.indices[face_id*3], self.indices[face_id*3+1], self.indices[face_id*3+2]];
+            source_positions.entry(face).or_default().push(face_id);
+        }
 
-    /// Gets Polygons for planar [Mesh].
-    fn get_polygons_for_planar_mesh(&self) -> Vec<Polygon> {
-        let edges = self.get_edges_with_missing_neighbour(); // Getting Graph and calculating grouped edges
-        let graph = Graph::new(self.get_number_of_vertices(), edges);
-        let disconnected_parts = graph.split_disconnected_loops();
+        let mut cursor = 0;
+        for other_face_id in 0..other.get_number_of_faces() {
+            let face = [other.indices[other_face_id*3], other.indices[other_face_id*3+1], other.indices[other_face_id*3+2]];
+            let mut matched_face_id = None;
+            if let Some(positions) = source_positions.get_mut(&face) {
+                while let Some(position) = positions.last() { // Skipping occurrences already passed by the cursor
+                    if *position < cursor {
+                        positions.pop();
+                    } else {
+                        matched_face_id = positions.pop();
+                        break;
+                    }
+                }
+            }
 
-        let mut polygons = Vec::with_capacity(disconnected_parts.len()); // Converting grouped edges to Polygons
-        for disconnected_part in disconnected_parts {
-            let mut polygon_vertices = Vec::new();
-            for i in disconnected_part {
-                let vertex = Point::new(self.coordinates[i*3], self.coordinates[i*3+1], self.coordinates[i*3+2]);
-                polygon_vertices.push(vertex)
+            match matched_face_id {
+                Some(source_face_id) => {
+                    delta.removed_face_ids.extend(cursor..source_face_id);
+                    cursor = source_face_id + 1;
+                },
+                None => {
+                    delta.added_face_ids.push(other_face_id);
+                    delta.added_indices.extend_from_slice(&face);
+                }
             }
-            polygons.push(Polygon::new(polygon_vertices))
         }
+        delta.removed_face_ids.extend(cursor..source_number_of_faces);
 
-        polygons
+        delta
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::vector::Vector;
-    use super::*;
-    
-    #[test]
-    fn test_get_with_faces_flipped() {
-        let input = Mesh::new(
-        vec![
-            // Base
-            -2.0,1.0,0.0,
-            8.0,1.0,0.0,
-            8.0,11.0,0.0,
-            -2.0,11.0,0.0,
-        
-            // Top
-            3.0,6.0,4.0
-        ],
+    /// Creates a new [Mesh] by applying given [MeshDelta].
+    ///
+    /// Returns an error if the [MeshDelta] was created for a [Mesh] with a different number of
+    /// vertices or faces, or if it's internally inconsistent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::mesh_delta::MeshDelta;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         -2.0,1.0,0.0,
+    ///         8.0,1.0,0.0,
+    ///         8.0,11.0,0.0,
+    ///         -2.0,11.0,0.0,
+    ///         3.0,6.0,4.0
+    ///     ],
+    ///     vec![
+    ///         0,1,2,
+    ///         0,2,3,
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// let mut delta = MeshDelta::new_empty(5, 6);
+    /// delta.number_of_vertices = 6;
+    /// delta.added_coordinates = vec![3.0, 6.0, -4.0]; // New bottom vertex
+    /// delta.removed_face_ids = vec![0, 1];
+    /// delta.added_face_ids = vec![0, 1, 2, 3];
+    /// delta.added_indices = vec![
+    ///     1,0,5,
+    ///     2,1,5,
+    ///     3,2,5,
+    ///     0,3,5
+    /// ];
+    ///
+    /// let actual = input.apply_delta(&delta).unwrap();
+    ///
+    /// let expected = Mesh::new(
+    ///     vec![
+    ///         -2.0,1.0,0.0,
+    ///         8.0,1.0,0.0,
+    ///         8.0,11.0,0.0,
+    ///         -2.0,11.0,0.0,
+    ///         3.0,6.0,4.0,
+    ///         3.0,6.0,-4.0
+    ///     ],
+    ///     vec![
+    ///         1,0,5,
+    ///         2,1,5,
+    ///         3,2,5,
+    ///         0,3,5,
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn apply_delta(&self, delta: &MeshDelta) -> Result<Mesh, String> {
+        let source_number_of_vertices = self.get_number_of_vertices();
+        let source_number_of_faces = self.get_number_of_faces();
+        if delta.source_number_of_vertices != source_number_of_vertices || delta.source_number_of_faces != source_number_of_faces {
+            return Err("The MeshDelta was created for a Mesh with a different number of vertices or faces".to_string());
+        }
+        if delta.moved_vertex_ids.len() * 3 != delta.moved_coordinates.len() || delta.added_face_ids.len() * 3 != delta.added_indices.len() {
+            return Err("The MeshDelta has inconsistent lengths of its lists".to_string());
+        }
+        let number_of_kept_vertices = source_number_of_vertices.min(delta.number_of_vertices);
+        if number_of_kept_vertices * 3 + delta.added_coordinates.len() != delta.number_of_vertices * 3 {
+            return Err("The MeshDelta added coordinates don't match its number of vertices".to_string());
+        }
+
+        let mut coordinates = self.coordinates[0..number_of_kept_vertices*3].to_vec();
+        for (i, vertex_id) in delta.moved_vertex_ids.iter().enumerate() {
+            if *vertex_id >= number_of_kept_vertices {
+                return Err("The MeshDelta moves a vertex which doesn't exist".to_string());
+            }
+            coordinates[vertex_id*3..vertex_id*3+3].copy_from_slice(&delta.moved_coordinates[i*3..i*3+3]);
+        }
+        coordinates.extend_from_slice(&delta.added_coordinates);
+
+        let mut is_removed = vec![false; source_number_of_faces];
+        for face_id in &delta.removed_face_ids {
+            if *face_id >= source_number_of_faces {
+                return Err("The MeshDelta removes a face which doesn't exist".to_string());
+            }
+            is_removed[*face_id] = true;
+        }
+        let mut kept_faces = (0..source_number_of_faces).filter(|face_id| !is_removed[*face_id]);
+        let number_of_faces = is_removed.iter().filter(|removed| !**removed).count() + delta.added_face_ids.len();
+
+        let mut indices = Vec::with_capacity(number_of_faces * 3);
+        let mut next_added = 0;
+        for face_id in 0..number_of_faces {
+            if next_added < delta.added_face_ids.len() && delta.added_face_ids[next_added] == face_id {
+                indices.extend_from_slice(&delta.added_indices[next_added*3..next_added*3+3]);
+                next_added += 1;
+            } else {
+                let source_face_id = kept_faces.next().ok_or("The MeshDelta added face ids are not sorted or out of range")?;
+                indices.extend_from_slice(&self.indices[source_face_id*3..source_face_id*3+3]);
+            }
+        }
+        if next_added != delta.added_face_ids.len() {
+            return Err("The MeshDelta added face ids are not sorted or out of range".to_string());
+        }
+        if indices.iter().any(|index| *index >= delta.number_of_vertices) {
+            return Err("The MeshDelta leaves faces pointing to vertices which don't exist".to_string());
+        }
+
+        Ok(Mesh::new_with_id(self.id, coordinates, indices))
+    }
+}
+
+/// Keeps position duplicates as duplicates only if their UVs are the same within the tolerance.
+///
+/// Otherwise each of them is welded with another vertex of the same position and UV, or it becomes
+/// a new, not duplicated vertex.
+fn split_duplicates_by_uvs(duplicate_vertices_info: &mut [(usize, bool)], uvs: &[f64], uv_tolerance: f64) {
+    let mut same_uv_vertices: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..duplicate_vertices_info.len() {
+        let (first_occurrence, is_duplicate) = duplicate_vertices_info[i];
+        if !is_duplicate {
+            continue;
+        }
+
+        let candidates = same_uv_vertices.entry(first_occurrence).or_insert_with(|| vec![first_occurrence]);
+        let same_uv = candidates.iter().find(|candidate| (uvs[*candidate * 2] - uvs[i * 2]).abs() <= uv_tolerance
+            && (uvs[*candidate * 2 + 1] - uvs[i * 2 + 1]).abs() <= uv_tolerance);
+        match same_uv {
+            Some(candidate) => duplicate_vertices_info[i] = (*candidate, true),
+            None => {
+                duplicate_vertices_info[i] = (i, false);
+                candidates.push(i);
+            },
+        }
+    }
+}
+
+fn get_weld_report(vertices: &[Point], duplicate_vertices_info: &[(usize, bool)], large_cluster_size: usize) -> WeldReport {
+    let mut number_of_merged_vertices = 0;
+    let mut max_merge_distance = 0.0;
+    let mut clusters: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    for (i, (first_occurrence, is_duplicate)) in duplicate_vertices_info.iter().enumerate() {
+        if *is_duplicate {
+            number_of_merged_vertices += 1;
+            let distance = vertices[i].get_distance_to_point(&vertices[*first_occurrence]);
+            if distance > max_merge_distance {
+                max_merge_distance = distance;
+            }
+        }
+        clusters.entry(*first_occurrence).or_default().push(i);
+    }
+
+    let mut large_clusters: Vec<Vec<usize>> = clusters.into_values().filter(|cluster| cluster.len() > large_cluster_size).collect();
+    large_clusters.sort();
+
+    WeldReport { number_of_merged_vertices, max_merge_distance, large_clusters }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mesh_attributes::AttributeValues;
+    use crate::polygon::Polygon;
+    use crate::test_utils::get_box;
+    use super::*;
+    
+    #[test]
+    fn test_get_with_faces_flipped() {
+        let input = Mesh::new(
+        vec![
+            // Base
+            -2.0,1.0,0.0,
+            8.0,1.0,0.0,
+            8.0,11.0,0.0,
+            -2.0,11.0,0.0,
+        
+            // Top
+            3.0,6.0,4.0
+        ],
         vec![
             // Base faces
             0,1,2, // to be flipped (0)
@@ -2250,6 +3547,140 @@ mod tests {
         assert_eq!(expected.eq(&actual), true);
     }
     
+    #[test]
+    fn test_flip_faces_same_as_get_with_faces_flipped() {
+        let mut input = Mesh::new(
+            vec![-2.0,1.0,0.0, 8.0,1.0,0.0, 8.0,11.0,0.0, -2.0,11.0,0.0, 3.0,6.0,4.0],
+            vec![0,1,2, 0,2,3, 0,1,4, 1,2,4, 2,3,4, 3,0,4]
+        );
+        input.face_groups = Some(vec![0, 0, 1, 1, 1, 1]);
+        let expected = input.get_with_faces_flipped(HashSet::from([0, 3, 5]));
+
+        input.flip_faces(&HashSet::from([0, 3, 5]));
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn test_flip_all_faces_per_corner_uvs() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.uvs = Some(UvChannel::PerCorner(vec![0.0, 0.1, 1.0, 0.2, 0.0, 0.3]));
+        assert_eq!(input.get_cached_face_normals(), &[Some(Vector::new(0.0, 0.0, 1.0))]);
+
+        input.flip_all_faces();
+
+        assert_eq!(input.indices, vec![2, 1, 0]);
+        assert_eq!(input.uvs, Some(UvChannel::PerCorner(vec![0.0, 0.3, 1.0, 0.2, 0.0, 0.1])));
+        assert_eq!(input.get_cached_face_normals(), &[Some(Vector::new(0.0, 0.0, -1.0))]);
+    }
+
+    #[test]
+    fn test_replace_indices() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 1, 3]);
+
+        input.replace_indices(&HashMap::from([(3, 2), (0, 1)]));
+
+        assert_eq!(input.indices, vec![1, 1, 2, 1, 1, 2]);
+    }
+
+    #[test]
+    fn test_offset_indices_same_as_get_with_index_offset() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        let expected = input.get_with_index_offset(3);
+
+        input.offset_indices(3);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn test_weld_vertices_keeps_id_and_metadata() {
+        let mut input = Mesh::new_with_id(Some(7),
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        input.metadata.insert("name".to_string(), "slab".to_string());
+        let expected_geometry = input.get_with_welded_vertices(0.001);
+
+        input.weld_vertices(0.001);
+
+        assert_eq!(input.id, Some(7));
+        assert_eq!(input.metadata.get("name"), Some(&"slab".to_string()));
+        assert_eq!(input.coordinates, expected_geometry.coordinates);
+        assert_eq!(input.indices, expected_geometry.indices);
+    }
+
+    #[test]
+    fn test_weld_vertices_same_as_get_with_welded_vertices() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.5, 0.5, 0.0, 1.0]));
+        input.vertex_colors = Some(vec![1, 1, 1, 255, 2, 2, 2, 255, 3, 3, 3, 255, 1, 1, 1, 255, 4, 4, 4, 255, 5, 5, 5, 255]);
+        input.face_groups = Some(vec![0, 1]);
+        let expected = input.get_with_welded_vertices(0.001);
+
+        input.weld_vertices(0.001);
+
+        assert_eq!(input, expected);
+        assert_eq!(input.indices, vec![0, 1, 2, 0, 3, 4]);
+    }
+
+    #[test]
+    fn test_get_compacted_keeps_vertex_data() {
+        let mut input = Mesh::new_with_id(Some(3),
+            vec![9.0, 9.0, 9.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            vec![1, 2, 3]
+        );
+        input.metadata.insert("name".to_string(), "panel".to_string());
+        input.uvs = Some(UvChannel::PerVertex(vec![0.5, 0.5, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+        input.vertex_colors = Some(vec![1, 1, 1, 1, 255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255]);
+        input.vertex_normals = Some(vec![1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+        input.face_groups = Some(vec![4]);
+
+        let (actual, mapping) = input.get_compacted();
+
+        assert_eq!(mapping, vec![None, Some(0), Some(1), Some(2)]);
+        assert_eq!(actual.id, Some(3));
+        assert_eq!(actual.metadata, input.metadata);
+        assert_eq!(actual.coordinates, vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(actual.indices, vec![0, 1, 2]);
+        assert_eq!(actual.uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0])));
+        assert_eq!(actual.vertex_colors, Some(vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255]));
+        assert_eq!(actual.vertex_normals, Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(actual.face_groups, Some(vec![4]));
+    }
+
+    #[test]
+    fn test_get_compacted_nothing_to_remove() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![2, 0, 1]);
+
+        let (actual, mapping) = input.get_compacted();
+
+        assert_eq!(actual, input);
+        assert_eq!(mapping, vec![Some(0), Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn test_get_with_faces_flipped_keeps_other_data() {
+        let mut input = Mesh::new_with_id(Some(2), vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        input.uvs = Some(UvChannel::PerCorner(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0]));
+        input.vertex_colors = Some(vec![255; 16]);
+        input.metadata.insert("name".to_string(), "slab".to_string());
+
+        let actual = input.get_with_faces_flipped(HashSet::from([1]));
+        let actual_all = input.get_with_all_faces_flipped();
+
+        assert_eq!(actual.indices, vec![0, 1, 2, 3, 2, 0]);
+        assert_eq!(actual.uvs, Some(UvChannel::PerCorner(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0])));
+        assert_eq!(actual.id, Some(2));
+        assert_eq!(actual.vertex_colors, input.vertex_colors);
+        assert_eq!(actual.metadata, input.metadata);
+        assert_eq!(actual_all.uvs, Some(UvChannel::PerCorner(vec![1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0])));
+        assert_eq!(actual_all.metadata, input.metadata);
+    }
+
     #[test]
     fn test_get_with_all_faces_flipped() {
         let input = Mesh::new(
@@ -2352,6 +3783,62 @@ mod tests {
         assert_eq!(expected.eq(&actual), true);
     }
 
+    /// Gets an open, non-planar grid of 3 x 3 vertices.
+    fn get_grid_for_winding() -> Mesh {
+        Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.3, 2.0, 0.0, 0.0,
+                 0.0, 1.0, 0.2, 1.0, 1.0, 0.5, 2.0, 1.0, 0.1,
+                 0.0, 2.0, 0.0, 1.0, 2.0, 0.4, 2.0, 2.0, 0.0],
+            vec![0, 1, 4, 0, 4, 3, 1, 2, 5, 1, 5, 4,
+                 3, 4, 7, 3, 7, 6, 4, 5, 8, 4, 8, 7])
+    }
+
+    #[test]
+    fn test_get_with_consistent_winding_thin_closed() {
+        let expected = get_box(100.0, 80.0, 0.1);
+        let input = expected.get_with_faces_flipped(HashSet::from([0, 3, 5, 8]));
+
+        let actual = input.get_with_consistent_winding();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_get_with_consistent_winding_all_inside() {
+        let expected = get_box(3.0, 2.0, 1.0);
+        let input = expected.get_with_all_faces_flipped();
+
+        let actual = input.get_with_consistent_winding();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_get_with_consistent_winding_open() {
+        let mut expected = get_grid_for_winding();
+        expected.face_groups = Some(vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        let mut input = expected.clone();
+        input.flip_faces(&HashSet::from([1, 2, 7]));
+
+        let actual = input.get_with_consistent_winding();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_get_with_consistent_winding_2_parts() {
+        let mut grid = get_grid_for_winding();
+        for x in grid.coordinates.iter_mut().step_by(3) {
+            *x += 10.0;
+        }
+        let expected = get_box(3.0, 2.0, 1.0).get_by_joining_with(&grid);
+        let input = expected.get_with_faces_flipped(HashSet::from([2, 3, 4, 13, 19]));
+
+        let actual = input.get_with_consistent_winding();
+
+        assert_eq!(actual.indices, expected.indices);
+    }
+
     #[test]
     fn test_get_with_removed_vertices_without_indices_update_first_removed() {
         let input = Mesh::new(
@@ -9711,7 +11198,7 @@ mod tests {
     }
 
     #[test]
-    fn test_get_polygons_for_planar_mesh_star() {
+    fn test_get_boundary_loops_star() {
         let mesh = Mesh::new(
             vec![
                 53.727875, 19.499361, -0.982379,
@@ -9919,7 +11406,7 @@ mod tests {
             ]
         );
 
-        let actual = mesh.get_polygons_for_planar_mesh();
+        let actual = mesh.get_boundary_loops();
 
         for act in &actual {
             println!("New Polygon");
@@ -10020,7 +11507,7 @@ mod tests {
     }
 
     #[test]
-    fn test_get_polygons_for_planar_mesh_triangle() {
+    fn test_get_boundary_loops_triangle() {
         let mesh = Mesh::new(
             vec![
                 -17.906073, 18.384705, 0.0,
@@ -10143,7 +11630,7 @@ mod tests {
             ]
         );
 
-        let actual = mesh.get_polygons_for_planar_mesh();
+        let actual = mesh.get_boundary_loops();
 
         for act in &actual {
             println!("New Polygon");
@@ -10197,7 +11684,7 @@ mod tests {
     }
 
     #[test]
-    fn test_get_polygons_for_planar_mesh_rectangle_2_holes() {
+    fn test_get_boundary_loops_rectangle_2_holes() {
         let mesh = Mesh::new(
             vec![
                 -39.321667, 43.177273, 21.852428,
@@ -10557,7 +12044,7 @@ mod tests {
             ]
         );
 
-        let actual = mesh.get_polygons_for_planar_mesh();
+        let actual = mesh.get_boundary_loops();
 
         for act in &actual {
             println!("New Polygon");
@@ -11597,7 +13084,6 @@ mod tests {
             ]),
         ];
 
-
         for act in &actual {
             println!("Mesh: {:?}", act.0);
             println!("Local:");
@@ -11739,7 +13225,6 @@ mod tests {
             ]),
         ];
 
-
         for act in &actual {
             println!("Mesh: {:?}", act.0);
             println!("Local:");
@@ -12080,7 +13565,6 @@ mod tests {
             ]),
         ];
 
-
         for act in &actual {
             println!("Mesh: {:?}", act.0);
             println!("Local:");
@@ -12800,4 +14284,645 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_compute_delta_and_apply_delta() {
+        let input = Mesh::new_with_id(Some(3),
+            vec![
+                -2.0,1.0,0.0,
+                8.0,1.0,0.0,
+                8.0,11.0,0.0,
+                -2.0,11.0,0.0,
+                3.0,6.0,4.0
+            ],
+            vec![
+                0,1,2,
+                0,2,3,
+                0,1,4,
+                1,2,4,
+                2,3,4,
+                3,0,4
+            ]);
+
+        let other = Mesh::new_with_id(Some(3),
+            vec![
+                -2.0,1.0,0.0,
+                8.0,1.0,0.0,
+                8.0,11.0,0.0,
+                -2.0,11.0,0.0,
+                3.0,6.0,4.0,
+                3.0,6.0,-4.0
+            ],
+            vec![
+                1,0,5,
+                0,1,4,
+                2,1,5,
+                1,2,4,
+                2,3,4,
+                3,0,4,
+                3,2,5,
+                0,3,5
+            ]);
+
+        let actual_delta = input.compute_delta(&other);
+
+        assert!(actual_delta.moved_vertex_ids.is_empty());
+        assert_eq!(actual_delta.added_coordinates, vec![3.0, 6.0, -4.0]);
+        assert_eq!(actual_delta.removed_face_ids, vec![0, 1]);
+        assert_eq!(actual_delta.added_face_ids, vec![0, 2, 6, 7]);
+        assert_eq!(actual_delta.added_indices, vec![1,0,5, 2,1,5, 3,2,5, 0,3,5]);
+
+        let actual = input.apply_delta(&actual_delta).unwrap();
+
+        assert_eq!(other, actual);
+    }
+
+    #[test]
+    fn test_compute_delta_removed_vertices() {
+        let input = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                1.0,0.0,0.0,
+                1.0,1.0,0.0,
+                0.0,1.0,0.0,
+            ],
+            vec![
+                0,1,2,
+                0,2,3
+            ]);
+
+        let other = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                1.0,0.0,0.0,
+                1.0,2.0,0.0,
+            ],
+            vec![
+                0,1,2
+            ]);
+
+        let actual_delta = input.compute_delta(&other);
+
+        assert_eq!(actual_delta.number_of_vertices, 3);
+        assert_eq!(actual_delta.moved_vertex_ids, vec![2]);
+        assert_eq!(actual_delta.removed_face_ids, vec![1]);
+
+        let actual = input.apply_delta(&actual_delta).unwrap();
+
+        assert_eq!(other, actual);
+    }
+
+    #[test]
+    fn test_compute_delta_same_mesh_empty() {
+        let input = Mesh::new(vec![0.0,0.0,0.0, 1.0,0.0,0.0, 1.0,1.0,0.0], vec![0,1,2]);
+
+        let actual = input.compute_delta(&input.clone());
+
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn test_apply_delta_wrong_source_error() {
+        let input = Mesh::new(vec![0.0,0.0,0.0, 1.0,0.0,0.0, 1.0,1.0,0.0], vec![0,1,2]);
+        let delta = MeshDelta::new_empty(4, 1);
+
+        let actual = input.apply_delta(&delta);
+
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn test_apply_delta_removed_vertex_still_used_error() {
+        let input = Mesh::new(vec![0.0,0.0,0.0, 1.0,0.0,0.0, 1.0,1.0,0.0], vec![0,1,2]);
+        let mut delta = MeshDelta::new_empty(3, 1);
+        delta.number_of_vertices = 2;
+
+        let actual = input.apply_delta(&delta);
+
+        assert!(actual.is_err());
+    }
+
+    fn is_topological_disk(mesh: &Mesh) -> bool {
+        let mut edge_counts: HashMap<(usize, usize), usize> = HashMap::new();
+        for face_id in 0..mesh.get_number_of_faces() {
+            for edge in Mesh::get_face_edge_keys(&mesh.indices, face_id) {
+                *edge_counts.entry(edge).or_insert(0) += 1;
+            }
+        }
+        if edge_counts.values().any(|count| *count > 2) {
+            return false;
+        }
+
+        let mut boundary_degree: HashMap<usize, usize> = HashMap::new();
+        for (edge, count) in &edge_counts {
+            if *count == 1 {
+                *boundary_degree.entry(edge.0).or_insert(0) += 1;
+                *boundary_degree.entry(edge.1).or_insert(0) += 1;
+            }
+        }
+        if boundary_degree.is_empty() || boundary_degree.values().any(|degree| *degree != 2) {
+            return false;
+        }
+
+        let euler_characteristic = mesh.get_number_of_vertices() as i64 - edge_counts.len() as i64 + mesh.get_number_of_faces() as i64;
+        euler_characteristic == 1
+    }
+
+    fn create_torus(number_of_segments_around: usize, number_of_segments_tube: usize) -> Mesh {
+        let mut coordinates = Vec::new();
+        for i in 0..number_of_segments_around {
+            let u = i as f64 / number_of_segments_around as f64 * std::f64::consts::TAU;
+            for j in 0..number_of_segments_tube {
+                let v = j as f64 / number_of_segments_tube as f64 * std::f64::consts::TAU;
+                coordinates.push((10.0 + 3.0 * v.cos()) * u.cos());
+                coordinates.push((10.0 + 3.0 * v.cos()) * u.sin());
+                coordinates.push(3.0 * v.sin());
+            }
+        }
+        let mut indices = Vec::new();
+        for i in 0..number_of_segments_around {
+            for j in 0..number_of_segments_tube {
+                let a = i * number_of_segments_tube + j;
+                let b = ((i + 1) % number_of_segments_around) * number_of_segments_tube + j;
+                let c = ((i + 1) % number_of_segments_around) * number_of_segments_tube + (j + 1) % number_of_segments_tube;
+                let d = i * number_of_segments_tube + (j + 1) % number_of_segments_tube;
+                indices.extend_from_slice(&[a, b, c, a, c, d]);
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+
+    #[test]
+    fn test_split_into_disk_patches_torus() {
+        let input = create_torus(12, 8);
+
+        let actual = input.split_into_disk_patches();
+
+        assert!(actual.len() > 1);
+        let mut number_of_faces = 0;
+        for patch in &actual {
+            assert!(is_topological_disk(patch));
+            number_of_faces += patch.get_number_of_faces();
+        }
+        assert_eq!(number_of_faces, input.get_number_of_faces());
+    }
+
+    #[test]
+    fn test_split_into_disk_patches_open_disk_single_patch() {
+        let input = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                1.0,0.0,0.0,
+                2.0,0.0,0.0,
+                0.0,1.0,0.0,
+                1.0,1.0,0.0,
+                2.0,1.0,0.0,
+            ],
+            vec![
+                0,1,4,
+                0,4,3,
+                1,2,5,
+                1,5,4
+            ]);
+
+        let actual = input.split_into_disk_patches();
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0], input);
+    }
+
+    #[test]
+    fn test_split_into_disk_patches_bow_tie() {
+        let input = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                1.0,0.0,0.0,
+                0.0,1.0,0.0,
+                -1.0,0.0,0.0,
+                0.0,-1.0,0.0,
+            ],
+            vec![
+                0,1,2,
+                0,3,4
+            ]);
+
+        let actual = input.split_into_disk_patches();
+
+        assert_eq!(actual.len(), 2);
+        for patch in &actual {
+            assert!(is_topological_disk(patch));
+        }
+    }
+
+    #[test]
+    fn test_get_with_planarized_regions_box() {
+        let input = Mesh::new_with_id(Some(2),
+            vec![
+                0.0,0.0,0.0,
+                10.0,0.0,0.01,
+                10.0,10.0,0.0,
+                0.0,10.0,-0.01,
+                0.0,0.0,10.02,
+                10.0,0.0,10.0,
+                10.0,10.0,9.98,
+                0.0,10.0,10.0,
+            ],
+            vec![
+                0,2,1, 0,3,2, // bottom
+                4,5,6, 4,6,7, // top
+                0,1,5, 0,5,4, // front
+                1,2,6, 1,6,5, // right
+                2,3,7, 2,7,6, // back
+                3,0,4, 3,4,7, // left
+            ]);
+
+        let actual = input.get_with_planarized_regions(0.01);
+
+        assert_eq!(actual.id, Some(2));
+        assert_eq!(actual.indices, input.indices);
+        let points = actual.to_points();
+        for face_pair in actual.indices.chunks(6) {
+            let mut vertex_ids = face_pair.to_vec();
+            vertex_ids.sort();
+            vertex_ids.dedup();
+            assert_eq!(vertex_ids.len(), 4);
+            let region_points: Vec<Point> = vertex_ids.iter().map(|vertex_id| points[*vertex_id]).collect();
+            let plane = Plane::new_best_fit(&region_points);
+            for point in &region_points {
+                assert!(plane.get_signed_distance_to_point(point).abs() < 1e-6);
+            }
+        }
+        for (input_point, actual_point) in input.to_points().iter().zip(points.iter()) {
+            assert!(input_point.get_distance_to_point(actual_point) < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_get_with_planarized_regions_sharp_edge_kept() {
+        let input = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                10.0,0.0,0.0,
+                10.0,10.0,0.0,
+                0.0,10.0,0.0,
+                0.0,20.0,10.0,
+            ],
+            vec![
+                0,1,2,
+                0,2,3,
+                3,2,4
+            ]);
+
+        let actual = input.get_with_planarized_regions(0.01);
+
+        assert!(input.eq_with_tolerance(&actual, 0.000001));
+    }
+
+    #[test]
+    fn test_get_with_planarized_regions_finely_tessellated_cylinder() {
+        // Angle between neighbouring faces (~0.0063) is below the tolerance, but the whole
+        // cylinder is far from planar.
+        let segments = 1000;
+        let radius = 10.0;
+        let mut coordinates = Vec::new();
+        let mut indices = Vec::new();
+        for i in 0..segments {
+            let angle = i as f64 / segments as f64 * 2.0 * std::f64::consts::PI;
+            coordinates.extend_from_slice(&[radius * angle.cos(), radius * angle.sin(), 0.0]);
+            coordinates.extend_from_slice(&[radius * angle.cos(), radius * angle.sin(), 5.0]);
+            let next = (i + 1) % segments;
+            indices.extend_from_slice(&[i * 2, next * 2, next * 2 + 1, i * 2, next * 2 + 1, i * 2 + 1]);
+        }
+        let mut input = Mesh::new(coordinates, indices);
+        input.face_groups = Some(vec![7; segments * 2]);
+        input.metadata.insert("name".to_string(), "column".to_string());
+
+        let actual = input.get_with_planarized_regions(0.01);
+
+        let sagitta = radius * (1.0 - 0.01_f64.cos());
+        assert!(input.eq_with_tolerance(&actual, sagitta));
+        assert_eq!(actual.face_groups, input.face_groups);
+        assert_eq!(actual.metadata, input.metadata);
+    }
+
+    #[test]
+    fn test_join_with_groups_empty() {
+        let actual = Mesh::join_with_groups(&[]);
+
+        assert_eq!(actual.get_number_of_faces(), 0);
+        assert_eq!(actual.face_groups, Some(vec![]));
+        assert!(actual.split_by_face_groups().is_empty());
+    }
+
+    #[test]
+    fn test_join_with_groups_with_unused_vertex_and_empty_mesh() {
+        let a = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0, 99.0, 99.0, 99.0], vec![0, 1, 2]);
+        let b = Mesh::new(vec![], vec![]);
+        let c = Mesh::new(vec![20.0, 20.0, 20.0, 30.0, 20.0, 20.0, 30.0, 5.0, 20.0], vec![2, 1, 0]);
+
+        let actual = Mesh::join_with_groups(&[a, b, c.clone()]);
+
+        assert_eq!(actual.indices, vec![0, 1, 2, 6, 5, 4]);
+        assert_eq!(actual.face_groups, Some(vec![0, 2]));
+
+        let split = actual.split_by_face_groups();
+        assert_eq!(split.len(), 3);
+        assert_eq!(split[0], Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]));
+        assert_eq!(split[1], Mesh::new(vec![], vec![]));
+        assert_eq!(split[2], c);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot split the Mesh by face groups, because it has no face groups")]
+    fn test_split_by_face_groups_without_groups_panic() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+        input.split_by_face_groups();
+    }
+
+    #[test]
+    fn test_split_by_face_angle_with_degenerate_face() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0,
+                 10.0, 0.0, 0.0,
+                 10.0, 10.0, 0.0,
+                 10.0, -5.0, 0.0,
+                 10.0, 0.0, 10.0],
+            vec![0, 1, 2, // base
+                 2, 1, 3, // collinear sliver on the base edge
+                 0, 4, 1, // wall
+            ]);
+
+        let actual = input.split_by_face_angle(0.1, None);
+
+        assert_eq!(input.get_degenerate_face_ids(), vec![1]);
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0].get_number_of_faces() + actual[1].get_number_of_faces(), 3);
+    }
+
+    #[test]
+    fn test_get_with_welded_vertices_fast_same_as_get_with_welded_vertices() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0, 5.0, 5.0, 5.0,
+                 0.0, 0.0, 0.0, 10.0, 0.0004, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0, 5.0, 5.0, 4.9996,
+                 -0.0004, 0.0, 0.0],
+            vec![0, 1, 4, 6, 2, 9, 2, 8, 4, 3, 10, 9, 0, 2, 1, 5, 7, 8]
+        );
+
+        let actual = input.get_with_welded_vertices_fast(0.001);
+
+        assert_eq!(actual.get_number_of_vertices(), 5);
+        assert_eq!(actual, input.get_with_welded_vertices(0.001));
+    }
+
+    #[test]
+    fn test_get_with_welded_vertices_fast_chain_same_as_get_with_welded_vertices() {
+        // The 3rd vertex is within the tolerance from both of the first 2, which are not
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 0.15, 0.0, 0.0, 0.08, 0.0, 0.0, -0.05, 0.0, 0.0, 0.0, 5.0, 0.0],
+            vec![0, 1, 4, 2, 3, 4]
+        );
+
+        let actual = input.get_with_welded_vertices_fast(0.1);
+
+        assert_eq!(actual.indices, vec![0, 1, 2, 1, 0, 2]);
+        assert_eq!(actual, input.get_with_welded_vertices(0.1));
+    }
+
+    #[test]
+    fn test_get_with_welded_vertices_fast_keeps_uv_seams() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0,
+                 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.5, 0.5, 0.0, 1.0]));
+
+        let actual = input.get_with_welded_vertices_fast(0.001);
+
+        assert_eq!(actual.indices, vec![0, 1, 2, 0, 3, 4]);
+        assert_eq!(actual, input.get_with_welded_vertices(0.001));
+    }
+
+    #[test]
+    fn test_get_with_welded_vertices_with_report_no_duplicates() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0], vec![0, 1, 2]);
+
+        let (actual, report) = input.get_with_welded_vertices_with_report(0.001, 0);
+
+        assert_eq!(actual, input);
+        assert_eq!(report.number_of_merged_vertices, 0);
+        assert_eq!(report.max_merge_distance, 0.0);
+        assert_eq!(report.large_clusters, vec![vec![0], vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn test_get_with_welded_vertices_with_report_high_tolerance() {
+        // Small triangle, which collapses completely with too high tolerance
+        let input = Mesh::new(
+            vec![
+                0.0, 0.0, 0.0,
+                0.1, 0.0, 0.0,
+                0.1, 0.1, 0.0,
+                5.0, 0.0, 0.0,
+            ],
+            vec![0, 1, 2, 0, 3, 1]);
+
+        let (actual, report) = input.get_with_welded_vertices_with_report(0.5, 2);
+
+        assert_eq!(actual.get_number_of_vertices(), 2);
+        assert_eq!(report.number_of_merged_vertices, 2);
+        assert!((report.max_merge_distance - 0.02_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(report.large_clusters, vec![vec![0, 1, 2]]);
+        assert!(report.has_large_clusters());
+    }
+
+    #[test]
+    fn test_get_with_welded_vertices_with_report_same_mesh_as_without() {
+        let input = Mesh::new(
+            vec![
+                0.0, 0.0, 0.0,
+                10.0, 0.0, 0.0,
+                10.0, 10.0, 0.0,
+                0.0, 0.0, 0.0,
+                10.0, 10.0, 0.0,
+                0.0, 10.0, 0.0,
+            ],
+            vec![0, 1, 2, 3, 4, 5]);
+
+        let (actual, report) = input.get_with_welded_vertices_with_report(0.001, 5);
+
+        assert_eq!(actual, input.get_with_welded_vertices(0.001));
+        assert_eq!(report.number_of_merged_vertices, 2);
+        assert!(!report.has_large_clusters());
+    }
+
+    fn create_quad_with_uvs() -> Mesh {
+        let mut quad = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        quad.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]));
+        quad
+    }
+
+    #[test]
+    fn test_get_with_unwelded_vertices_and_welded_back_keeps_uvs() {
+        let input = create_quad_with_uvs();
+
+        let unwelded = input.get_with_unwelded_vertices();
+        let actual = unwelded.get_with_welded_vertices(0.001);
+
+        assert_eq!(unwelded.uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0])));
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_get_with_welded_vertices_per_corner_uvs_kept() {
+        let mut input = create_quad_with_uvs().get_with_unwelded_vertices();
+        input.uvs = Some(UvChannel::PerCorner(vec![0.0; 12]));
+
+        let actual = input.get_with_welded_vertices(0.001);
+
+        assert_eq!(actual.get_number_of_vertices(), 4);
+        assert_eq!(actual.uvs, input.uvs);
+    }
+
+    #[test]
+    fn test_get_with_welded_vertices_with_uv_tolerance_seam_kept() {
+        let mut input = create_quad_with_uvs().get_with_unwelded_vertices();
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.00005, 0.5, 1.0]));
+
+        let actual = input.get_with_welded_vertices_with_uv_tolerance(0.001, 0.0001);
+        let strict = input.get_with_welded_vertices_with_uv_tolerance(0.001, 0.00001);
+
+        assert_eq!(actual.indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(actual.uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.5, 1.0])));
+        assert_eq!(strict.get_number_of_vertices(), 5);
+    }
+
+    #[test]
+    fn test_join_and_split_by_face_groups_keeps_uvs() {
+        let a = create_quad_with_uvs();
+        let mut b = create_quad_with_uvs();
+        b.uvs = Some(UvChannel::PerCorner(vec![0.5; 12]));
+
+        let joined = Mesh::join_with_groups(&[a.clone(), b.clone()]);
+        let actual = joined.split_by_face_groups();
+
+        assert_eq!(Mesh::join(&vec![a.clone(), a.clone()]).uvs, Some(UvChannel::PerVertex([0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0].repeat(2))));
+        assert_eq!(actual[0].uvs, Some(UvChannel::PerCorner(a.get_corner_uvs().unwrap())));
+        assert_eq!(actual[1].uvs, b.uvs);
+    }
+
+    #[test]
+    fn test_get_part_by_face_ids_keeps_uvs() {
+        let input = create_quad_with_uvs();
+
+        let actual = input.get_part_by_face_ids(&vec![1]);
+
+        assert_eq!(actual.uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 1.0, 0.0, 1.0])));
+        assert_eq!(input.get_welded_part_by_face_ids(&[1]).uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 1.0, 0.0, 1.0])));
+    }
+
+    #[test]
+    fn test_vertex_colors_kept_by_welding_parts_and_joining() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 3, 4, 5]);
+        input.vertex_colors = Some(vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 9, 9, 9, 9, 8, 8, 8, 8, 7, 7, 7, 255]);
+
+        let welded = input.get_with_welded_vertices(0.001);
+        assert_eq!(welded.vertex_colors, Some(vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 7, 7, 7, 255]));
+
+        let unwelded = welded.get_with_unwelded_vertices();
+        assert_eq!(unwelded.vertex_colors, Some(vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 255, 0, 0, 255, 0, 0, 255, 255, 7, 7, 7, 255]));
+
+        assert_eq!(welded.get_part_by_face_ids(&vec![1]).vertex_colors, Some(vec![255, 0, 0, 255, 0, 0, 255, 255, 7, 7, 7, 255]));
+        assert_eq!(welded.get_welded_part_by_face_ids(&[1]).vertex_colors, Some(vec![255, 0, 0, 255, 0, 0, 255, 255, 7, 7, 7, 255]));
+
+        let joined = Mesh::join_with_groups(&[welded.clone(), welded.clone()]);
+        assert_eq!(joined.vertex_colors.as_ref().unwrap().len(), 32);
+        assert_eq!(joined.split_by_face_groups(), vec![welded.clone(), welded.clone()]);
+        assert_eq!(Mesh::join(&vec![welded.clone(), Mesh::new(vec![], vec![])]).vertex_colors, None);
+
+        let split = welded.split_by_face_disconnected(Some(0.001));
+        assert_eq!(split.len(), 1);
+        assert_eq!(split[0].get_vertex_color(3), welded.get_vertex_color(3));
+    }
+
+    #[test]
+    fn test_attributes_remapped_by_topology_changes() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 3, 4, 5]);
+        input.attributes.vertex.insert(String::from("temperature"), AttributeValues::F64(vec![10.0, 11.0, 12.0, 13.0, 14.0, 15.0]));
+        input.attributes.face.insert(String::from("thickness"), AttributeValues::F32(vec![0.2, 0.3]));
+
+        let welded = input.get_with_welded_vertices(0.001);
+        assert_eq!(welded.attributes.vertex["temperature"], AttributeValues::F64(vec![10.0, 11.0, 12.0, 15.0]));
+        assert_eq!(welded.attributes.face["thickness"], AttributeValues::F32(vec![0.2, 0.3]));
+
+        let unwelded = welded.get_with_unwelded_vertices();
+        assert_eq!(unwelded.attributes.vertex["temperature"], AttributeValues::F64(vec![10.0, 11.0, 12.0, 10.0, 12.0, 15.0]));
+
+        let part = welded.get_welded_part_by_face_ids(&[1]);
+        assert_eq!(part.attributes.vertex["temperature"], AttributeValues::F64(vec![10.0, 12.0, 15.0]));
+        assert_eq!(part.attributes.face["thickness"], AttributeValues::F32(vec![0.3]));
+
+        let split = Mesh::join_with_groups(&[welded.clone(), part.clone()]).split_by_face_groups();
+        assert_eq!(split, vec![welded.clone(), part.clone()]);
+
+        let mut without_thickness = part.clone();
+        without_thickness.attributes.face.clear();
+        let joined = Mesh::join(&vec![welded.clone(), without_thickness]);
+        assert_eq!(joined.attributes.vertex["temperature"], AttributeValues::F64(vec![10.0, 11.0, 12.0, 15.0, 10.0, 12.0, 15.0]));
+        assert!(joined.attributes.face.is_empty());
+    }
+
+    #[test]
+    fn test_face_groups_kept_through_flip_weld_part_and_join() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 3, 4, 5]);
+        input.face_groups = Some(vec![2, 5]);
+
+        assert_eq!(input.get_with_all_faces_flipped().face_groups, Some(vec![2, 5]));
+        assert_eq!(input.get_with_faces_flipped(HashSet::from([1])).face_groups, Some(vec![2, 5]));
+
+        let welded = input.get_with_welded_vertices(0.001);
+        assert_eq!(welded.face_groups, Some(vec![2, 5]));
+        assert_eq!(welded.get_with_welded_vertices(0.001).face_groups, Some(vec![2, 5]));
+        assert_eq!(welded.get_with_unwelded_vertices().face_groups, Some(vec![2, 5]));
+
+        assert_eq!(welded.get_part_by_face_ids(&vec![1]).face_groups, Some(vec![5]));
+        assert_eq!(welded.get_welded_part_by_face_ids(&[1, 0]).face_groups, Some(vec![5, 2]));
+
+        let without_groups = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        assert_eq!(welded.get_by_joining_with(&without_groups).face_groups, Some(vec![2, 5, 0]));
+        assert_eq!(Mesh::join(&vec![without_groups.clone(), welded.clone()]).face_groups, Some(vec![0, 2, 5]));
+        assert_eq!(Mesh::join(&vec![without_groups.clone(), without_groups]).face_groups, None);
+    }
+
+    #[test]
+    fn test_instantiate_with_id_info_round_trip() {
+        let tetrahedron = Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 3.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]);
+        let wing = Mesh::new_with_id(Some(1), vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 1.0, 2.0, 0.5], vec![0, 1, 2]);
+        let axis = crate::ray::Ray::new(Point::new(-5.0, 1.0, 0.0), Vector::new(1.0, 0.0, 1.0));
+        let placements = LocalCoordinateSystem::global().get_polar_array(&axis, 3, 2.0 * std::f64::consts::PI);
+        let mut input = vec![wing];
+        for (i, mut instance) in tetrahedron.get_instances(&placements).into_iter().enumerate() {
+            instance.id = Some([3, 0, 2][i]);
+            input.push(instance);
+        }
+
+        let deduplicated = Mesh::deduplicate_with_id_info(input.clone(), 0.0001);
+        let actual = Mesh::instantiate_with_id_info(&deduplicated);
+
+        assert_eq!(deduplicated.len(), 2);
+        assert_eq!(actual.iter().map(|mesh| mesh.id.unwrap()).collect::<Vec<usize>>(), vec![0, 1, 2, 3]);
+        for mesh in &actual {
+            let original = input.iter().find(|original| original.id == mesh.id).unwrap();
+            assert!(mesh.eq_with_tolerance(original, 1e-9));
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::projection_method::ProjectionMethod;
+use crate::ray::Ray;
+
+impl Mesh {
+    /// Returns a new [Mesh] with vertices projected onto the surface of the `target` [Mesh]
+    /// (shrinkwrap), using given [ProjectionMethod].
+    ///
+    /// Vertices which would move further than the `max_distance`, or which don't hit the
+    /// `target` at all, stay where they are. Use `f64::INFINITY` for no limit.
+    ///
+    /// Faces are kept, and [Mesh::vertex_normals] are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::projection_method::ProjectionMethod;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// // Slope going up along x
+    /// let terrain = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 5.0, 10.0, 10.0, 5.0, 0.0, 10.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let road = Mesh::new(vec![2.0, 4.0, 10.0, 8.0, 4.0, 10.0, 8.0, 6.0, 10.0, 20.0, 6.0, 10.0], vec![0, 1, 2, 1, 3, 2]);
+    ///
+    /// let actual = road.get_projected_onto(&terrain, ProjectionMethod::Direction(Vector::new(0.0, 0.0, -1.0)), f64::INFINITY);
+    ///
+    /// assert_eq!(actual.coordinates, vec![2.0, 4.0, 1.0, 8.0, 4.0, 4.0, 8.0, 6.0, 4.0, 20.0, 6.0, 10.0]);
+    /// ```
+    pub fn get_projected_onto(&self, target: &Mesh, method: ProjectionMethod, max_distance: f64) -> Mesh {
+        let bvh = target.get_cached_bvh();
+        self.get_with_moved_vertices(|_, vertex| {
+            let projected_vertex = match method {
+                ProjectionMethod::ClosestPoint => bvh.get_closest_point(&vertex).map(|(_, point)| point),
+                ProjectionMethod::Direction(direction) => [direction, direction.get_reversed()].iter()
+                    .filter_map(|direction| bvh.get_ray_hits(&Ray::new(vertex, *direction)).first().map(|hit| hit.point))
+                    .min_by(|a, b| vertex.get_distance_to_point(a).total_cmp(&vertex.get_distance_to_point(b))),
+            };
+
+            projected_vertex.filter(|point: &Point| vertex.get_distance_to_point(point) <= max_distance).unwrap_or(vertex)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use crate::polyline::Polyline;
+    use crate::vector::Vector;
+    use super::*;
+
+    fn get_sphere(radius: f64) -> Mesh {
+        let profile = Polyline::new((0..=16).map(|i| {
+            let angle = PI * i as f64 / 16.0;
+            Point::new(radius * angle.sin(), 0.0, -radius * angle.cos())
+        }).collect());
+        let axis = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        Mesh::revolve(&profile, &axis, 2.0 * PI, 32)
+    }
+
+    #[test]
+    fn test_get_projected_onto_closest_point_shrinks_to_sphere() {
+        let target = get_sphere(1.0);
+        let input = get_sphere(2.0);
+
+        let actual = input.get_projected_onto(&target, ProjectionMethod::ClosestPoint, f64::INFINITY);
+
+        for vertex in actual.to_points() {
+            let radius = vertex.to_vector().get_length();
+            assert!(radius <= 1.0 + 1e-12 && radius > 0.98);
+        }
+        assert_eq!(actual.indices, input.indices);
+    }
+
+    #[test]
+    fn test_get_projected_onto_max_distance() {
+        let target = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 10.0, 0.0], vec![0, 1, 2]);
+        let mut input = Mesh::new(vec![1.0, 1.0, 0.5, 2.0, 1.0, -3.0, 1.0, 2.0, 0.5], vec![0, 1, 2]);
+        input.vertex_normals = Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+
+        let actual = input.get_projected_onto(&target, ProjectionMethod::Direction(Vector::new(0.0, 0.0, -2.0)), 1.0);
+
+        assert_eq!(actual.coordinates, vec![1.0, 1.0, 0.0, 2.0, 1.0, -3.0, 1.0, 2.0, 0.0]);
+        assert_eq!(actual.vertex_normals, None);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::face_source::FaceSource;
+use crate::mesh::Mesh;
+
+impl Mesh {
+    /// Creates a new [Mesh] by joining given Meshes, same as [Mesh::join_with_groups] but
+    /// keeping the original `face_groups`, together with the [FaceSource] of every face of the
+    /// result.
+    ///
+    /// Vertices are not welded. `uvs`, `vertex_colors` and `attributes` are kept too. Joined
+    /// [Mesh] has an `id`: `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::face_source::FaceSource;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let a = Mesh::new_with_id(Some(10), vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    /// let b = Mesh::new_with_id(Some(20),
+    ///     vec![0.0, 0.0, 5.0, 10.0, 0.0, 5.0, 10.0, 5.0, 5.0, 10.0, -15.0, 5.0],
+    ///     vec![0, 1, 2, 1, 2, 3]
+    /// );
+    ///
+    /// let (actual, provenance) = Mesh::join_with_provenance(&[a, b]);
+    ///
+    /// assert_eq!(actual.indices, vec![0, 1, 2, 3, 4, 5, 4, 5, 6]);
+    /// assert_eq!(provenance, vec![
+    ///     FaceSource::new(0, Some(10), 0),
+    ///     FaceSource::new(1, Some(20), 0),
+    ///     FaceSource::new(1, Some(20), 1),
+    /// ]);
+    /// ```
+    pub fn join_with_provenance(meshes: &[Mesh]) -> (Mesh, Vec<FaceSource>) {
+        let mut joined = Mesh::join_with_groups(meshes);
+        joined.face_groups = Mesh::get_joined_face_groups(&meshes.iter().collect::<Vec<&Mesh>>());
+
+        let provenance = meshes.iter().enumerate()
+            .flat_map(|(mesh_index, mesh)| (0..mesh.get_number_of_faces()).map(move |face_id| FaceSource::new(mesh_index, mesh.id, face_id)))
+            .collect();
+
+        (joined, provenance)
+    }
+
+    /// Splits given [Mesh] using its `face_groups`, see [Mesh::split_by_face_groups], together
+    /// with the [FaceSource]s of every face of every part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    /// input.face_groups = Some(vec![1, 0]);
+    ///
+    /// let actual = input.split_by_face_groups_with_provenance();
+    ///
+    /// assert_eq!(actual[0].1[0].face_id, 1);
+    /// assert_eq!(actual[1].1[0].face_id, 0);
+    /// ```
+    pub fn split_by_face_groups_with_provenance(&self) -> Vec<(Mesh, Vec<FaceSource>)> {
+        self.get_face_ids_per_face_group().into_iter().map(|face_ids| {
+            let mut part = self.get_welded_part_by_face_ids(&face_ids);
+            part.face_groups = None;
+            (part, self.get_face_sources(&face_ids))
+        }).collect()
+    }
+
+    /// Splits given [Mesh] into parts which are not connected, see
+    /// [Mesh::split_by_face_disconnected], together with the [FaceSource]s of every face of
+    /// every part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new_with_id(Some(4),
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+    ///          5.0, 0.0, 0.0, 6.0, 0.0, 0.0, 5.0, 1.0, 0.0],
+    ///     vec![3, 4, 5, 0, 1, 2]
+    /// );
+    ///
+    /// let actual = input.split_by_face_disconnected_with_provenance(None);
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// for (part, provenance) in &actual {
+    ///     assert_eq!(part.get_number_of_faces(), provenance.len());
+    ///     assert_eq!(provenance[0].mesh_id, Some(4));
+    /// }
+    /// ```
+    pub fn split_by_face_disconnected_with_provenance(&self, weld_vertices_tolerance: Option<f64>) -> Vec<(Mesh, Vec<FaceSource>)> {
+        self.get_parts_with_provenance(self.get_face_ids_split_by_face_disconnected(), weld_vertices_tolerance)
+    }
+
+    /// Splits given [Mesh] where the angle between faces' normals is higher than `max_angle`,
+    /// see [Mesh::split_by_face_angle], together with the [FaceSource]s of every face of every
+    /// part.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0],
+    ///     vec![0, 1, 2, 0, 3, 1]
+    /// );
+    ///
+    /// let actual = input.split_by_face_angle_with_provenance(0.1, Some(0.001));
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert_eq!(actual[0].1.len() + actual[1].1.len(), 2);
+    /// ```
+    pub fn split_by_face_angle_with_provenance(&self, max_angle: f64, weld_vertices_tolerance: Option<f64>) -> Vec<(Mesh, Vec<FaceSource>)> {
+        self.get_parts_with_provenance(self.get_face_ids_split_by_face_angle(max_angle), weld_vertices_tolerance)
+    }
+
+    /// Gets unwelded parts of given face ids, optionally welded afterwards, with their
+    /// [FaceSource]s.
+    fn get_parts_with_provenance(&self, face_ids_per_part: Vec<Vec<usize>>, weld_vertices_tolerance: Option<f64>) -> Vec<(Mesh, Vec<FaceSource>)> {
+        face_ids_per_part.into_iter().map(|face_ids| {
+            let mut part = self.get_part_by_face_ids(&face_ids);
+            if let Some(tolerance) = weld_vertices_tolerance {
+                part = part.get_with_welded_vertices_fast(tolerance);
+            }
+            (part, self.get_face_sources(&face_ids))
+        }).collect()
+    }
+
+    /// Gets [FaceSource]s pointing to given faces of this [Mesh].
+    fn get_face_sources(&self, face_ids: &[usize]) -> Vec<FaceSource> {
+        face_ids.iter().map(|face_id| FaceSource::new(0, self.id, *face_id)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_with_provenance_same_as_join() {
+        let a = Mesh::new_with_id(Some(1), vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+        let mut b = Mesh::new(vec![0.0, 0.0, 5.0, 10.0, 0.0, 5.0, 10.0, 5.0, 5.0, 10.0, -15.0, 5.0], vec![0, 1, 2, 1, 2, 3]);
+        b.face_groups = Some(vec![3, 2]);
+        let meshes = vec![a, b];
+
+        let (actual, provenance) = Mesh::join_with_provenance(&meshes);
+
+        assert_eq!(actual, Mesh::join(&meshes));
+        assert_eq!(provenance, vec![FaceSource::new(0, Some(1), 0), FaceSource::new(1, None, 0), FaceSource::new(1, None, 1)]);
+    }
+
+    #[test]
+    fn test_split_by_face_disconnected_with_provenance_same_as_split() {
+        let input = Mesh::new_with_id(Some(9),
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0,
+                 5.0, 0.0, 0.0, 6.0, 0.0, 0.0, 5.0, 1.0, 0.0],
+            vec![0, 1, 2, 4, 5, 6, 1, 3, 2]
+        );
+
+        let actual = input.split_by_face_disconnected_with_provenance(Some(0.001));
+        let expected = input.split_by_face_disconnected(Some(0.001));
+
+        assert_eq!(actual.len(), expected.len());
+        for ((part, provenance), expected_part) in actual.iter().zip(expected.iter()) {
+            assert_eq!(part, expected_part);
+            for (face_id, source) in provenance.iter().enumerate() {
+                assert_eq!(source.mesh_id, Some(9));
+                let source_triangle = input.to_triangles()[source.face_id];
+                assert_eq!(part.to_triangles()[face_id], source_triangle);
+            }
+        }
+    }
+
+    #[test]
+    fn test_join_and_split_round_trip() {
+        let a = Mesh::new_with_id(Some(5), vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        let b = Mesh::new_with_id(Some(6), vec![0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0], vec![0, 2, 1]);
+        let (joined, join_provenance) = Mesh::join_with_provenance(&[a, b]);
+
+        let parts = joined.split_by_face_disconnected_with_provenance(None);
+
+        let mut original_mesh_ids: Vec<Option<usize>> = parts.iter()
+            .map(|(_, provenance)| join_provenance[provenance[0].face_id].mesh_id)
+            .collect();
+        original_mesh_ids.sort();
+
+        assert_eq!(original_mesh_ids, vec![Some(5), Some(6)]);
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use crate::bvh::Bvh;
+use crate::mesh::Mesh;
+use crate::ray::Ray;
+use crate::ray_hit::RayHit;
+
+impl Mesh {
+    /// Casts many [Ray]s against the [Mesh], returning all the [RayHit]s of every Ray (sorted
+    /// by distance, see [Ray::get_all_hits_with_mesh]), in the same order as given Rays.
+    ///
+    /// The [Bvh](crate::bvh::Bvh) is built once (see [Mesh::get_cached_bvh]) and shared by all
+    /// the Rays. With the `rayon` feature enabled Rays are cast in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::ray::Ray;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0], vec![0, 1, 2]);
+    /// let rays = vec![
+    ///     Ray::new(Point::new(1.0, 1.0, 2.0), Vector::new(0.0, 0.0, -1.0)),
+    ///     Ray::new(Point::new(5.0, 5.0, 2.0), Vector::new(0.0, 0.0, -1.0)),
+    ///     Ray::new(Point::new(1.0, 2.0, -3.0), Vector::new(0.0, 0.0, 1.0)),
+    /// ];
+    ///
+    /// let actual = mesh.cast_rays(&rays);
+    ///
+    /// assert_eq!(actual.len(), 3);
+    /// assert_eq!(actual[0][0].distance, 2.0);
+    /// assert!(actual[1].is_empty());
+    /// assert_eq!(actual[2][0].point, Point::new(1.0, 2.0, 0.0));
+    /// ```
+    pub fn cast_rays(&self, rays: &[Ray]) -> Vec<Vec<RayHit>> {
+        Mesh::get_ray_hits_of_all_rays(self.get_cached_bvh(), rays)
+    }
+
+    #[cfg(feature = "rayon")]
+    fn get_ray_hits_of_all_rays(bvh: &Bvh, rays: &[Ray]) -> Vec<Vec<RayHit>> {
+        rays.par_iter().map(|ray| bvh.get_ray_hits(ray)).collect()
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    fn get_ray_hits_of_all_rays(bvh: &Bvh, rays: &[Ray]) -> Vec<Vec<RayHit>> {
+        rays.iter().map(|ray| bvh.get_ray_hits(ray)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+    use crate::vector::Vector;
+
+    #[test]
+    fn test_cast_rays_same_as_single_rays() {
+        let mesh = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0,
+                 0.0, 0.0, 3.0, 10.0, 0.0, 3.0, 10.0, 10.0, 3.0, 0.0, 10.0, 3.0],
+            vec![0, 1, 2, 0, 2, 3, 4, 6, 5, 4, 7, 6]
+        );
+        let rays: Vec<Ray> = (0..50).map(|i| Ray::new(Point::new((i % 12) as f64, (i / 4) as f64 * 0.9, 5.0), Vector::new(0.05, 0.0, -1.0))).collect();
+
+        let actual = mesh.cast_rays(&rays);
+
+        assert_eq!(actual.len(), rays.len());
+        for (ray, hits) in rays.iter().zip(actual.iter()) {
+            assert_eq!(*hits, ray.get_all_hits_with_mesh(&mesh));
+        }
+        assert!(actual.iter().any(|hits| hits.len() == 2));
+        assert!(actual.iter().any(|hits| hits.is_empty()));
+    }
+
+    #[test]
+    fn test_cast_rays_empty() {
+        let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        assert!(mesh.cast_rays(&[]).is_empty());
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::uv_channel::UvChannel;
+
+impl Mesh {
+    /// Creates a new [Mesh] with faces split until none of the edges is longer than the
+    /// `max_edge_length`, e.g. before vertex based analyses (ambient occlusion, thickness) or
+    /// displacement of coarse meshes.
+    ///
+    /// In each pass all the too long edges are split in the middle, and each face is split
+    /// depending on how many of its edges are split: into 2 faces (1 edge), 3 faces (2 edges,
+    /// the remaining quad gets the shorter diagonal), or 4 faces (3 edges). Neighbouring faces
+    /// share new vertices, so the result has no cracks, and faces keep their winding. Faces
+    /// with only short edges stay as they are.
+    ///
+    /// New vertices get per vertex `uvs`, `vertex_colors` and `vertex_normals` (unitized)
+    /// interpolated from ends of the split edge, and vertex `attributes` of the end with the
+    /// smaller id. New faces keep `face_groups` and face `attributes` of the face they come
+    /// from. Per corner `uvs` are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let actual = input.get_refined(1.5);
+    ///
+    /// let points = actual.to_points();
+    /// assert!(actual.to_edges().iter().all(|edge| points[edge.start].get_distance_to_point(&points[edge.end]) <= 1.5));
+    /// assert!((actual.get_area() - input.get_area()).abs() < 1e-12);
+    /// ```
+    pub fn get_refined(&self, max_edge_length: f64) -> Mesh {
+        if max_edge_length <= 0.0 {
+            panic!("Maximum edge length should be greater than 0.0");
+        }
+
+        let mut refinement = Refinement::new(self);
+        while refinement.split_long_edges(max_edge_length) {}
+
+        refinement.into_mesh(self)
+    }
+}
+
+/// State of a [Mesh] being split into smaller faces: current vertices and faces, together with
+/// interpolated per vertex data and sources of faces and vertices in the original [Mesh].
+pub(crate) struct Refinement {
+    pub(crate) positions: Vec<[f64; 3]>,
+    pub(crate) faces: Vec<[usize; 3]>,
+    pub(crate) face_sources: Vec<usize>,
+    vertex_sources: Vec<usize>,
+    uvs: Option<Vec<[f64; 2]>>,
+    colors: Option<Vec<[f64; 4]>>,
+    normals: Option<Vec<[f64; 3]>>,
+}
+
+impl Refinement {
+    pub(crate) fn new(mesh: &Mesh) -> Refinement {
+        let number_of_vertices = mesh.get_number_of_vertices();
+        Refinement {
+            positions: mesh.coordinates.chunks(3).map(|vertex| [vertex[0], vertex[1], vertex[2]]).collect(),
+            faces: mesh.indices.chunks_exact(3).map(|face| [face[0], face[1], face[2]]).collect(),
+            face_sources: (0..mesh.get_number_of_faces()).collect(),
+            vertex_sources: (0..number_of_vertices).collect(),
+            uvs: match &mesh.uvs {
+                Some(UvChannel::PerVertex(uvs)) => Some(uvs.chunks(2).map(|uv| [uv[0], uv[1]]).collect()),
+                _ => None,
+            },
+            colors: mesh.vertex_colors.as_ref().map(|colors| colors.chunks(4).map(|color| [color[0] as f64, color[1] as f64, color[2] as f64, color[3] as f64]).collect()),
+            normals: mesh.vertex_normals.as_ref().map(|normals| normals.chunks(3).map(|normal| [normal[0], normal[1], normal[2]]).collect()),
+        }
+    }
+
+    /// Splits all the edges longer than the `max_edge_length` once, returns `false` if there
+    /// were none.
+    fn split_long_edges(&mut self, max_edge_length: f64) -> bool {
+        let mut midpoints: HashMap<(usize, usize), usize> = HashMap::new();
+        for face_id in 0..self.faces.len() {
+            for corner in 0..3 {
+                let (start, end) = (self.faces[face_id][corner], self.faces[face_id][(corner + 1) % 3]);
+                let key = (start.min(end), start.max(end));
+                if !midpoints.contains_key(&key) && self.get_distance(start, end) > max_edge_length {
+                    let midpoint = self.add_midpoint(key.0, key.1);
+                    midpoints.insert(key, midpoint);
+                }
+            }
+        }
+        if midpoints.is_empty() {
+            return false;
+        }
+
+        let get_midpoint = |start: usize, end: usize| midpoints.get(&(start.min(end), start.max(end))).copied();
+        let mut faces = Vec::with_capacity(self.faces.len() * 2);
+        let mut face_sources = Vec::with_capacity(self.faces.len() * 2);
+        for (face, source) in self.faces.iter().zip(&self.face_sources) {
+            let split = [0, 1, 2].map(|corner| get_midpoint(face[corner], face[(corner + 1) % 3]));
+            let number_of_split = split.iter().flatten().count();
+            // Rotating the face, so the first edge is split, and for 2 split edges also the second one
+            let rotation = (0..3).find(|corner| split[*corner].is_some() && (number_of_split != 2 || split[(corner + 1) % 3].is_some())).unwrap_or(0);
+            let [a, b, c] = [face[rotation], face[(rotation + 1) % 3], face[(rotation + 2) % 3]];
+            let [ab, bc, ca] = [split[rotation], split[(rotation + 1) % 3], split[(rotation + 2) % 3]];
+
+            let new_faces = match (ab, bc, ca) {
+                (Some(ab), Some(bc), Some(ca)) => vec![[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]],
+                (Some(ab), Some(bc), None) => {
+                    if self.get_distance(a, bc) <= self.get_distance(ab, c) {
+                        vec![[ab, b, bc], [a, ab, bc], [a, bc, c]]
+                    } else {
+                        vec![[ab, b, bc], [a, ab, c], [ab, bc, c]]
+                    }
+                },
+                (Some(ab), None, None) => vec![[a, ab, c], [ab, b, c]],
+                _ => vec![*face],
+            };
+            face_sources.extend(std::iter::repeat_n(*source, new_faces.len()));
+            faces.extend(new_faces);
+        }
+        self.faces = faces;
+        self.face_sources = face_sources;
+
+        true
+    }
+
+    fn get_distance(&self, start: usize, end: usize) -> f64 {
+        let (start, end) = (self.positions[start], self.positions[end]);
+
+        ((end[0] - start[0]).powi(2) + (end[1] - start[1]).powi(2) + (end[2] - start[2]).powi(2)).sqrt()
+    }
+
+    fn add_midpoint(&mut self, start: usize, end: usize) -> usize {
+        let position = get_weighted_sum(&[(self.positions[start], 0.5), (self.positions[end], 0.5)]);
+
+        self.add_vertex(position, &[(start, 0.5), (end, 0.5)])
+    }
+
+    /// Adds a new vertex with per vertex data interpolated with the given weights of existing
+    /// vertices, and vertex `attributes` of the first one.
+    pub(crate) fn add_vertex(&mut self, position: [f64; 3], weights: &[(usize, f64)]) -> usize {
+        self.positions.push(position);
+        self.vertex_sources.push(self.vertex_sources[weights[0].0]);
+        if let Some(uvs) = &mut self.uvs {
+            let uv = get_weighted_sum(&weights.iter().map(|(vertex, weight)| (uvs[*vertex], *weight)).collect::<Vec<_>>());
+            uvs.push(uv);
+        }
+        if let Some(colors) = &mut self.colors {
+            let color = get_weighted_sum(&weights.iter().map(|(vertex, weight)| (colors[*vertex], *weight)).collect::<Vec<_>>());
+            colors.push(color);
+        }
+        if let Some(normals) = &mut self.normals {
+            let normal = get_weighted_sum(&weights.iter().map(|(vertex, weight)| (normals[*vertex], *weight)).collect::<Vec<_>>());
+            let length = (normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]).sqrt();
+            normals.push(if length == 0.0 { normal } else { normal.map(|value| value / length) });
+        }
+
+        self.positions.len() - 1
+    }
+
+    pub(crate) fn into_mesh(self, source: &Mesh) -> Mesh {
+        let mut refined = source.clone();
+        refined.coordinates = self.positions.into_iter().flatten().collect();
+        refined.indices = self.faces.into_iter().flatten().collect();
+        refined.uvs = self.uvs.map(|uvs| UvChannel::PerVertex(uvs.into_iter().flatten().collect()));
+        refined.vertex_colors = self.colors.map(|colors| colors.into_iter().flatten().map(|value| value.round() as i32).collect());
+        refined.vertex_normals = self.normals.map(|normals| normals.into_iter().flatten().collect());
+        refined.face_groups = source.get_face_groups_by_face_ids(&self.face_sources);
+        refined.attributes = source.get_attributes_by_ids(&self.vertex_sources, &self.face_sources);
+
+        refined
+    }
+}
+
+fn get_weighted_sum<const N: usize>(values: &[([f64; N], f64)]) -> [f64; N] {
+    let mut sum = [0.0; N];
+    for (value, weight) in values {
+        for (i, summed) in sum.iter_mut().enumerate() {
+            *summed += value[i] * weight;
+        }
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mesh_attributes::AttributeValues;
+    use super::*;
+
+    #[test]
+    fn test_get_refined_shared_edge_without_cracks() {
+        // 2 faces sharing the long diagonal, only the diagonal is too long
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 3.0, 3.0, 0.0, 0.0, 3.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+
+        let actual = input.get_refined(3.5);
+
+        assert_eq!(actual.get_number_of_vertices(), 5);
+        assert_eq!(actual.indices, vec![2, 4, 1, 4, 0, 1, 0, 4, 3, 4, 2, 3]);
+        assert_eq!(actual.get_boundary_loops_vertex_ids().len(), 1);
+        assert_eq!(actual.get_boundary_loops_vertex_ids()[0].len(), 4);
+    }
+
+    #[test]
+    fn test_get_refined_closed_box_stays_closed() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 5.0, 0.0, 0.0, 0.0, 3.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]);
+
+        let actual = input.get_refined(0.7);
+
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - input.get_volume().unwrap()).abs() < 1e-12);
+        let points = actual.to_points();
+        assert!(actual.to_edges().iter().all(|edge| points[edge.start].get_distance_to_point(&points[edge.end]) <= 0.7));
+    }
+
+    #[test]
+    fn test_get_refined_interpolates_data() {
+        // Only the first edge is too long
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 0.5]));
+        input.vertex_colors = Some(vec![0, 0, 0, 255, 255, 100, 0, 255, 0, 0, 0, 255]);
+        input.face_groups = Some(vec![3]);
+        input.attributes.vertex.insert(String::from("temperature"), AttributeValues::F64(vec![10.0, 20.0, 30.0]));
+
+        let actual = input.get_refined(1.5);
+
+        assert_eq!(actual.coordinates[9..], [1.0, 0.0, 0.0]);
+        assert_eq!(actual.uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 0.5, 0.5, 0.0])));
+        assert_eq!(actual.vertex_colors.unwrap()[12..], [128, 50, 0, 255]);
+        assert_eq!(actual.face_groups, Some(vec![3, 3]));
+        assert_eq!(actual.attributes.vertex["temperature"], AttributeValues::F64(vec![10.0, 20.0, 30.0, 10.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Maximum edge length should be greater than 0.0")]
+    fn test_get_refined_zero_length_panic() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        input.get_refined(0.0);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::icp_options::IcpOptions;
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::vector::Vector;
+
+impl Mesh {
+    /// Registers this [Mesh] (e.g. a scan) to the `target` one (e.g. a model) using
+    /// point-to-plane ICP (Iterative Closest Point).
+    ///
+    /// Returns the [LocalCoordinateSystem] in which this Mesh should be placed to match the
+    /// `target`, so [Mesh::get_in_local_coordinate_system] with the result gives the aligned
+    /// Mesh, the same way as with the results of [Mesh::deduplicate].
+    ///
+    /// Points are sampled from vertices and face centroids of this Mesh (evenly thinned out to
+    /// `number_of_samples`, see [IcpOptions]). In each iteration every sampled point is paired
+    /// with its closest point on the `target` (using [Mesh::get_cached_bvh]), and the rotation
+    /// and translation minimizing the squared distances to the planes of the paired faces are
+    /// applied.
+    ///
+    /// ICP finds the nearest fit only, so the Meshes should be roughly aligned first. If there
+    /// are not enough paired points to define the motion (e.g. a plane can slide over a plane),
+    /// iterations stop early.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::icp_options::IcpOptions;
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let target = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 2.0, 0.0,
+    ///          0.0, 0.0, 3.0, 1.0, 0.0, 3.0, 1.0, 2.0, 3.0, 0.0, 2.0, 3.0],
+    ///     vec![0, 2, 1, 0, 3, 2, 4, 5, 6, 4, 6, 7, 0, 1, 5, 0, 5, 4,
+    ///          1, 2, 6, 1, 6, 5, 2, 3, 7, 2, 7, 6, 3, 0, 4, 3, 4, 7]);
+    /// let scan = target.get_in_local_coordinate_system(&LocalCoordinateSystem::new(
+    ///     Point::new(0.1, -0.05, 0.08),
+    ///     Vector::new(1.0, 0.05, 0.0),
+    ///     Vector::new(-0.05, 1.0, 0.02),
+    /// ));
+    ///
+    /// let actual = scan.align_to(&target, IcpOptions::new(50, 1000, 1.0, 1e-12));
+    ///
+    /// assert!(scan.get_in_local_coordinate_system(&actual).eq_with_tolerance(&target, 1e-6));
+    /// ```
+    pub fn align_to(&self, target: &Mesh, options: IcpOptions) -> LocalCoordinateSystem {
+        let mut origin = Point::new(0.0, 0.0, 0.0);
+        let mut x = Vector::x_unit();
+        let mut y = Vector::y_unit();
+
+        let samples = self.get_registration_samples(options.number_of_samples);
+        if samples.is_empty() || target.get_number_of_faces() == 0 {
+            return LocalCoordinateSystem::new(origin, x, y);
+        }
+        let bvh = target.get_cached_bvh();
+        let target_normals = target.get_face_normal_vectors_unitized_checked();
+
+        for _ in 0..options.max_iterations {
+            let z = x.get_cross_product(&y);
+            let mut pairs: Vec<(Point, Point, Vector)> = Vec::with_capacity(samples.len());
+            for sample in &samples {
+                let point = origin + x * sample.x + y * sample.y + z * sample.z;
+                let (face_id, closest) = match bvh.get_closest_point(&point) {
+                    Some(closest) => closest,
+                    None => continue,
+                };
+                if let Some(normal) = target_normals[face_id] {
+                    if point.get_distance_to_point(&closest) <= options.max_correspondence_distance {
+                        pairs.push((point, closest, normal));
+                    }
+                }
+            }
+            if pairs.len() < 6 {
+                break;
+            }
+
+            // Rotation is linearized around the centroid of the paired points, so it's
+            // independent of the distance from the global origin.
+            let center = Point::new(
+                pairs.iter().map(|(point, _, _)| point.x).sum::<f64>() / pairs.len() as f64,
+                pairs.iter().map(|(point, _, _)| point.y).sum::<f64>() / pairs.len() as f64,
+                pairs.iter().map(|(point, _, _)| point.z).sum::<f64>() / pairs.len() as f64,
+            );
+            let mut matrix = [[0.0; 6]; 6];
+            let mut right_side = [0.0; 6];
+            for (point, closest, normal) in &pairs {
+                let arm = Vector::from_2_points(&center, point).get_cross_product(normal);
+                let row = [arm.x, arm.y, arm.z, normal.x, normal.y, normal.z];
+                let residual = Vector::from_2_points(closest, point).get_dot_product(normal);
+                for i in 0..6 {
+                    for j in 0..6 {
+                        matrix[i][j] += row[i] * row[j];
+                    }
+                    right_side[i] -= row[i] * residual;
+                }
+            }
+            let solution = match solve_linear_system(matrix, right_side) {
+                Some(solution) => solution,
+                None => break,
+            };
+
+            let rotation = Vector::new(solution[0], solution[1], solution[2]);
+            let translation = Vector::new(solution[3], solution[4], solution[5]);
+            let angle = rotation.get_length();
+            if angle > 0.0 {
+                let arm = Vector::from_2_points(&center, &origin).get_rotated(&rotation, angle);
+                origin = center + arm;
+                x = x.get_rotated(&rotation, angle);
+                y = y.get_rotated(&rotation, angle);
+            }
+            origin = origin + translation;
+
+            if angle < options.convergence_tolerance && translation.get_length() < options.convergence_tolerance {
+                break;
+            }
+        }
+
+        LocalCoordinateSystem::new(origin, x, y)
+    }
+
+    /// Gets vertices and face centroids, evenly thinned out to at most `number_of_samples`.
+    fn get_registration_samples(&self, number_of_samples: usize) -> Vec<Point> {
+        let mut candidates = self.to_points();
+        candidates.extend(self.triangles().map(|triangle| triangle.get_centroid()));
+        if number_of_samples == 0 {
+            return Vec::new();
+        }
+
+        let step = candidates.len().div_ceil(number_of_samples).max(1);
+        candidates.into_iter().step_by(step).collect()
+    }
+}
+
+/// Solves the linear system using Gaussian elimination with partial pivoting, returns `None` if
+/// it's singular.
+fn solve_linear_system(mut matrix: [[f64; 6]; 6], mut right_side: [f64; 6]) -> Option<[f64; 6]> {
+    let scale = matrix.iter().flatten().fold(0.0_f64, |max, value| max.max(value.abs()));
+    if scale == 0.0 {
+        return None;
+    }
+
+    for column in 0..6 {
+        let pivot_row = (column..6).max_by(|a, b| matrix[*a][column].abs().total_cmp(&matrix[*b][column].abs())).unwrap();
+        if matrix[pivot_row][column].abs() <= scale * 1e-12 {
+            return None;
+        }
+        matrix.swap(column, pivot_row);
+        right_side.swap(column, pivot_row);
+        for row in column + 1..6 {
+            let factor = matrix[row][column] / matrix[column][column];
+            let pivot = matrix[column];
+            for (value, pivot_value) in matrix[row].iter_mut().zip(pivot.iter()).skip(column) {
+                *value -= factor * pivot_value;
+            }
+            right_side[row] -= factor * right_side[column];
+        }
+    }
+
+    let mut solution = [0.0; 6];
+    for row in (0..6).rev() {
+        let known: f64 = (row + 1..6).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (right_side[row] - known) / matrix[row][row];
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::get_box;
+    use super::*;
+
+    #[test]
+    fn test_align_to_moved_box() {
+        let target = get_box(1.0, 2.0, 3.0) + Vector::new(100.0, -50.0, 20.0);
+        let offset = LocalCoordinateSystem::new(
+            Point::new(0.0, 0.0, 0.0),
+            Vector::new(1.0, 0.0, 0.0).get_rotated(&Vector::new(1.0, 2.0, 3.0), 0.08),
+            Vector::new(0.0, 1.0, 0.0).get_rotated(&Vector::new(1.0, 2.0, 3.0), 0.08),
+        );
+        let scan = get_box(1.0, 2.0, 3.0).get_in_local_coordinate_system(&offset) + Vector::new(99.9, -49.85, 20.05);
+
+        let actual = scan.align_to(&target, IcpOptions::new(100, 1000, 1.0, 1e-12));
+
+        assert!(scan.get_in_local_coordinate_system(&actual).eq_with_tolerance(&target, 1e-6));
+    }
+
+    #[test]
+    fn test_align_to_itself() {
+        let input = get_box(1.0, 2.0, 3.0);
+
+        let actual = input.align_to(&input, IcpOptions::new(10, 1000, 1.0, 1e-12));
+
+        assert!(actual.eq_with_tolerance(&LocalCoordinateSystem::global(), 1e-9));
+    }
+
+    #[test]
+    fn test_align_to_with_outliers_ignored() {
+        let target = get_box(1.0, 2.0, 3.0);
+        let outlier = Mesh::new(vec![10.0, 10.0, 10.0, 11.0, 10.0, 10.0, 10.0, 11.0, 10.0], vec![0, 1, 2]);
+        let scan = Mesh::join(&vec![get_box(1.0, 2.0, 3.0) + Vector::new(0.05, -0.03, 0.02), outlier]);
+
+        let actual = scan.align_to(&target, IcpOptions::new(100, 1000, 1.0, 1e-12));
+
+        assert!(actual.origin.eq_with_tolerance(&Point::new(-0.05, 0.03, -0.02), 1e-6));
+    }
+
+    #[test]
+    fn test_align_to_planes_sliding_stops() {
+        let target = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        let scan = target.clone() + Vector::new(0.0, 0.0, 0.5);
+
+        let actual = scan.align_to(&target, IcpOptions::new(10, 1000, 1.0, 1e-12));
+
+        assert!(actual.eq_with_tolerance(&LocalCoordinateSystem::global(), 1e-9));
+    }
+
+    #[test]
+    fn test_align_to_empty() {
+        let target = get_box(1.0, 2.0, 3.0);
+        let scan = Mesh::new(vec![], vec![]);
+
+        let actual = scan.align_to(&target, IcpOptions::new(10, 1000, 1.0, 1e-12));
+
+        assert_eq!(actual, LocalCoordinateSystem::global());
+    }
+}
@@ -0,0 +1,208 @@
+use crate::bvh::Bvh;
+use crate::mesh::Mesh;
+use crate::mesh_offset::get_isosurface;
+use crate::point::Point;
+use crate::vector::Vector;
+use crate::voxel_fill_mode::VoxelFillMode;
+
+/// The number of cells checked away from the closest face to find out on which side of the
+/// surface a cell lies.
+const SIDE_PROBE_STEPS: usize = 2;
+
+impl Mesh {
+    /// Creates a new watertight and manifold [Mesh] approximating the given one, rebuilt from
+    /// its signed distance field.
+    ///
+    /// It's the last resort for broken inputs (e.g. scans) that can't be fixed directly: the
+    /// [Mesh] is voxelized with the `cell_size`, the outside is flood filled from the border of
+    /// the grid, and distances to the closest faces get signs from that. The surface is then
+    /// extracted with marching tetrahedra, so the result is always welded, closed, manifold
+    /// and oriented outside.
+    ///
+    /// Holes and gaps smaller than the `cell_size` get sealed, while details smaller than it
+    /// get lost. Bigger holes let the outside in, so such parts get wrapped in a thin shell.
+    ///
+    /// It panics if the `cell_size` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Tetrahedron with one face missing
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         2.0,0.0,0.0,
+    ///         0.0,2.0,0.0,
+    ///         0.0,0.0,2.0,
+    ///     ],
+    ///     vec![
+    ///         0,2,1,
+    ///         0,1,3,
+    ///         0,3,2,
+    ///     ]);
+    ///
+    /// let actual = input.get_repaired_via_sdf(0.25);
+    ///
+    /// assert!(actual.get_edges_with_missing_neighbour().is_empty());
+    /// assert!(actual.get_non_manifold_edges().is_empty());
+    /// assert!(actual.get_volume().unwrap() > 0.0);
+    /// ```
+    pub fn get_repaired_via_sdf(&self, cell_size: f64) -> Mesh {
+        if cell_size <= 0.0 {
+            panic!("Cell size should be greater than 0.0");
+        }
+
+        let (origin, number_of_cells, values) = get_repair_signed_distances(self, &Bvh::from_mesh(self), cell_size);
+
+        get_isosurface(&origin, cell_size, number_of_cells, &values)
+    }
+}
+
+/// Samples signed distances to the (possibly broken) [Mesh] on a regular grid, as described in
+/// [Mesh::get_repaired_via_sdf].
+///
+/// Returns the origin of the grid, its number of cells along each axis and values in its nodes,
+/// with x changing the fastest. The border of the grid is always outside.
+pub(crate) fn get_repair_signed_distances(mesh: &Mesh, bvh: &Bvh, cell_size: f64) -> (Point, [usize; 3], Vec<f64>) {
+    let voxel_grid = mesh.voxelize(cell_size, VoxelFillMode::Surface);
+
+    // Values are sampled in centers of cells, with 1 more empty cell on each side, so the
+    // border of the sampled grid is always outside.
+    let number_of_nodes = [voxel_grid.size_x + 2, voxel_grid.size_y + 2, voxel_grid.size_z + 2];
+    let number_of_all_nodes = number_of_nodes[0] * number_of_nodes[1] * number_of_nodes[2];
+    let get_node_id = |node: [usize; 3]| (node[2] * number_of_nodes[1] + node[1]) * number_of_nodes[0] + node[0];
+    let is_surface = |node: [usize; 3]| {
+        node.iter().all(|coordinate| *coordinate > 0) && voxel_grid.is_filled(node[0] - 1, node[1] - 1, node[2] - 1)
+    };
+
+    let mut is_outside = vec![false; number_of_all_nodes];
+    is_outside[0] = true;
+    let mut stack: Vec<[usize; 3]> = vec![[0, 0, 0]];
+    while let Some(node) = stack.pop() {
+        for axis in 0..3 {
+            for is_positive in [false, true] {
+                let mut neighbour = node;
+                if is_positive && node[axis] + 1 < number_of_nodes[axis] {
+                    neighbour[axis] += 1;
+                } else if !is_positive && node[axis] > 0 {
+                    neighbour[axis] -= 1;
+                } else {
+                    continue;
+                }
+                let neighbour_id = get_node_id(neighbour);
+                if !is_outside[neighbour_id] && !is_surface(neighbour) {
+                    is_outside[neighbour_id] = true;
+                    stack.push(neighbour);
+                }
+            }
+        }
+    }
+
+    let origin = Point::new(voxel_grid.origin.x - cell_size / 2.0, voxel_grid.origin.y - cell_size / 2.0, voxel_grid.origin.z - cell_size / 2.0);
+    let get_node = |point: &Point| -> Option<[usize; 3]> {
+        let node = [
+            ((point.x - origin.x) / cell_size).round(),
+            ((point.y - origin.y) / cell_size).round(),
+            ((point.z - origin.z) / cell_size).round(),
+        ];
+        if (0..3).all(|axis| node[axis] >= 0.0 && node[axis] < number_of_nodes[axis] as f64) {
+            Some(node.map(|coordinate| coordinate as usize))
+        } else {
+            None
+        }
+    };
+    let values: Vec<f64> = (0..number_of_all_nodes).map(|node_id| {
+        let node = [node_id % number_of_nodes[0], (node_id / number_of_nodes[0]) % number_of_nodes[1], node_id / (number_of_nodes[0] * number_of_nodes[1])];
+        let point = Point::new(origin.x + node[0] as f64 * cell_size, origin.y + node[1] as f64 * cell_size, origin.z + node[2] as f64 * cell_size);
+        let (_, closest_point) = match bvh.get_closest_point(&point) {
+            Some(closest) => closest,
+            None => return cell_size,
+        };
+        let distance = point.get_distance_to_point(&closest_point);
+        if distance == 0.0 {
+            return 0.0;
+        }
+        let is_inside = if is_outside[node_id] {
+            false
+        } else if is_surface(node) {
+            // The surface passes through this cell, so the side is taken from cells further
+            // away from the closest face, and from the winding number (see Mesh::contains_point)
+            // only if they're all on the surface
+            let away = Vector::from_2_points(&closest_point, &point).get_unitized();
+            let probed_node = (1..=SIDE_PROBE_STEPS)
+                .filter_map(|step| get_node(&(point + away * (step as f64 * cell_size))))
+                .find(|probed_node| !is_surface(*probed_node));
+            match probed_node {
+                Some(probed_node) => !is_outside[get_node_id(probed_node)],
+                None => mesh.contains_point(&point),
+            }
+        } else {
+            true
+        };
+        if is_inside { -distance } else { distance }
+    }).collect();
+
+    (origin, [number_of_nodes[0] - 1, number_of_nodes[1] - 1, number_of_nodes[2] - 1], values)
+}
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::get_cube;
+    use super::*;
+
+    fn assert_watertight_and_manifold(mesh: &Mesh) {
+        assert!(mesh.get_edges_with_missing_neighbour().is_empty());
+        assert!(mesh.get_non_manifold_edges().is_empty());
+        assert!(mesh.get_non_manifold_vertices().is_empty());
+    }
+
+    #[test]
+    fn test_get_repaired_via_sdf_closed_box() {
+        let input = get_cube(0.0, 4.0);
+
+        let actual = input.get_repaired_via_sdf(0.5);
+
+        assert_watertight_and_manifold(&actual);
+        assert!(actual.is_connected());
+        let volume = actual.get_volume().unwrap();
+        assert!((volume - 64.0).abs() / 64.0 < 0.05);
+        for point in actual.to_points() {
+            assert!(input.get_signed_distance(&point).abs() < 0.5);
+        }
+    }
+
+    #[test]
+    fn test_get_repaired_via_sdf_box_with_gap_and_flipped_face() {
+        // The top side is lifted a bit, leaving a gap all around, and one face is flipped
+        let Mesh { mut coordinates, mut indices, .. } = get_cube(0.0, 4.0);
+        for vertex_id in 4..8 {
+            coordinates[vertex_id * 3 + 2] = 4.1;
+        }
+        coordinates.extend_from_slice(&[0.0, 0.0, 4.0, 4.0, 0.0, 4.0, 4.0, 4.0, 4.0, 0.0, 4.0, 4.0]);
+        for side in 2..6 {
+            for index in &mut indices[side * 6..side * 6 + 6] {
+                if *index >= 4 {
+                    *index += 4;
+                }
+            }
+        }
+        indices.swap(0, 1);
+        let input = Mesh::new(coordinates, indices);
+        assert!(!input.get_edges_with_missing_neighbour().is_empty());
+
+        let actual = input.get_repaired_via_sdf(0.5);
+
+        assert_watertight_and_manifold(&actual);
+        assert!(actual.is_connected());
+        // Edges of the box get slightly cut, as the grid doesn't capture them exactly
+        let volume = actual.get_volume().unwrap();
+        assert!(volume > 60.0 && volume < 4.0 * 4.0 * 4.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cell size should be greater than 0.0")]
+    fn test_get_repaired_via_sdf_zero_cell_size_panic() {
+        get_cube(0.0, 4.0).get_repaired_via_sdf(0.0);
+    }
+}
@@ -0,0 +1,303 @@
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::plane::Plane;
+use crate::point::Point;
+use crate::polygon::Polygon;
+
+/// Section of a [Mesh] with a Plane, described by nodes, which are edges crossing the Plane.
+pub(crate) struct SectionNodes {
+    /// Vertex ids of edges of nodes, the smaller one first.
+    pub(crate) edges: Vec<(usize, usize)>,
+    /// Points where edges of nodes cross the Plane.
+    pub(crate) points: Vec<Point>,
+    /// Closed section curves as node ids, without repeating the first node at the end.
+    pub(crate) closed_chains: Vec<Vec<usize>>,
+    /// Open section curves as node ids.
+    pub(crate) open_chains: Vec<Vec<usize>>,
+}
+
+impl Mesh {
+    /// Sections the [Mesh] with the [Plane], returning closed section curves as [Polygon]s.
+    ///
+    /// Every edge crossing the Plane gives a vertex of the section, so for faces crossing
+    /// the Plane, vertices are not simplified. Vertices lying exactly on the Plane are treated
+    /// as being below it, so faces lying on the Plane give no section. For closed Meshes with
+    /// faces oriented outside, Polygons go counter-clockwise looking against the Plane's normal
+    /// (e.g. from above for the Plane with the normal pointing up), so outer contours and holes
+    /// have opposite orientations.
+    ///
+    /// Open curves (e.g. where the Mesh has holes) are skipped, use [Mesh::get_section_curves]
+    /// to get them too.
+    ///
+    /// The input [Mesh] should be welded, otherwise curves are not connected between faces
+    /// with different vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polygon::Polygon;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    /// let plane = Plane::new(Point::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// let actual = tetrahedron.section_with_plane(&plane);
+    ///
+    /// let expected = vec![Polygon::new(vec![Point::new(0.0, 0.0, 1.0), Point::new(2.0, 0.0, 1.0), Point::new(0.0, 2.0, 1.0)])];
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn section_with_plane(&self, plane: &Plane) -> Vec<Polygon> {
+        self.get_section_curves(plane).0
+    }
+
+    /// Sections the [Mesh] with the [Plane], returning both closed section curves as [Polygon]s,
+    /// and open ones as polylines (`vec`s of [Point]s).
+    ///
+    /// Open curves start and end at the boundary of the Mesh, see [Mesh::section_with_plane] for
+    /// other rules. They follow the same orientation as closed curves.
+    ///
+    /// # Example
+    ///
+    /// Here is an example of the vertical square, which gives one open curve.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let square = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 4.0, 0.0, 4.0, 0.0, 0.0, 4.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    /// let plane = Plane::new(Point::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// let (closed, open) = square.get_section_curves(&plane);
+    ///
+    /// assert!(closed.is_empty());
+    /// assert_eq!(open, vec![vec![Point::new(0.0, 0.0, 1.0), Point::new(1.0, 0.0, 1.0), Point::new(4.0, 0.0, 1.0)]]);
+    /// ```
+    pub fn get_section_curves(&self, plane: &Plane) -> (Vec<Polygon>, Vec<Vec<Point>>) {
+        let distances: Vec<f64> = self.to_points().iter().map(|point| plane.get_signed_distance_to_point(point)).collect();
+        let section = self.get_section_nodes(&distances);
+
+        let polygons = section.closed_chains.iter()
+            .map(|chain| Polygon::new(chain.iter().map(|node| section.points[*node]).collect()))
+            .collect();
+        let polylines = section.open_chains.iter()
+            .map(|chain| chain.iter().map(|node| section.points[*node]).collect())
+            .collect();
+
+        (polygons, polylines)
+    }
+
+    /// Sections the [Mesh] with the Plane, knowing signed distances of all vertices to it, see
+    /// [Mesh::get_section_curves] for rules.
+    pub(crate) fn get_section_nodes(&self, distances: &[f64]) -> SectionNodes {
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        let mut points: Vec<Point> = Vec::new();
+        let mut edge_nodes: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut segments: Vec<(usize, usize)> = Vec::new();
+        for face in self.indices.chunks_exact(3) {
+            let mut start = None;
+            let mut end = None;
+            for (first, second) in [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])] {
+                let is_first_above = distances[first] > 0.0;
+                if is_first_above != (distances[second] > 0.0) {
+                    let key = (first.min(second), first.max(second));
+                    let node = *edge_nodes.entry(key).or_insert_with(|| {
+                        edges.push(key);
+                        points.push(self.get_plane_crossing_point(key.0, key.1, distances));
+                        points.len() - 1
+                    });
+                    if is_first_above { // Going from above to below the Plane along the winding
+                        start = Some(node);
+                    }
+                    else {
+                        end = Some(node);
+                    }
+                }
+            }
+            if let (Some(start), Some(end)) = (start, end) {
+                if start != end {
+                    segments.push((start, end));
+                }
+            }
+        }
+
+        let mut outgoing_segments: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut has_incoming_segment = vec![false; points.len()];
+        for (segment_id, (start, end)) in segments.iter().enumerate() {
+            outgoing_segments.entry(*start).or_default().push(segment_id);
+            has_incoming_segment[*end] = true;
+        }
+
+        let mut is_used = vec![false; segments.len()];
+        let mut chains = Vec::new();
+        for segment_id in 0..segments.len() { // Open chains first, so they're not traced from the middle
+            if !is_used[segment_id] && !has_incoming_segment[segments[segment_id].0] {
+                chains.push(Mesh::get_section_chain(&segments, &outgoing_segments, &mut is_used, segment_id));
+            }
+        }
+        for segment_id in 0..segments.len() {
+            if !is_used[segment_id] {
+                chains.push(Mesh::get_section_chain(&segments, &outgoing_segments, &mut is_used, segment_id));
+            }
+        }
+
+        let mut closed_chains = Vec::new();
+        let mut open_chains = Vec::new();
+        for chain in chains {
+            let is_closed = chain.len() > 2 && chain[0] == chain[chain.len() - 1];
+            let mut nodes: Vec<usize> = Vec::with_capacity(chain.len());
+            for node in chain {
+                if nodes.last().is_some_and(|last| points[*last] == points[node]) { // Skipping zero length segments, e.g. at vertices on the Plane
+                    continue;
+                }
+                nodes.push(node);
+            }
+            if is_closed {
+                while nodes.len() > 1 && points[nodes[0]] == points[nodes[nodes.len() - 1]] { // The last node is the first one
+                    nodes.pop();
+                }
+                if nodes.len() > 2 {
+                    closed_chains.push(nodes);
+                }
+            }
+            else if nodes.len() > 1 {
+                open_chains.push(nodes);
+            }
+        }
+
+        SectionNodes { edges, points, closed_chains, open_chains }
+    }
+
+    /// Gets the [Point] where the edge between given vertices crosses the Plane, knowing signed
+    /// distances of all vertices to the Plane. Vertices should be on different sides of it.
+    pub(crate) fn get_plane_crossing_point(&self, first: usize, second: usize, distances: &[f64]) -> Point {
+        let (first, second) = if distances[second] == 0.0 { (second, first) } else { (first, second) }; // So the vertex on the Plane is given exactly
+        let t = distances[first] / (distances[first] - distances[second]);
+        let x = self.coordinates[first * 3];
+        let y = self.coordinates[first * 3 + 1];
+        let z = self.coordinates[first * 3 + 2];
+
+        Point::new(
+            x + (self.coordinates[second * 3] - x) * t,
+            y + (self.coordinates[second * 3 + 1] - y) * t,
+            z + (self.coordinates[second * 3 + 2] - z) * t,
+        )
+    }
+
+    /// Follows unused segments starting from the given one, until it gets back to the start or
+    /// there is no way further. Returns visited nodes.
+    fn get_section_chain(segments: &[(usize, usize)], outgoing_segments: &HashMap<usize, Vec<usize>>, is_used: &mut [bool], first_segment_id: usize) -> Vec<usize> {
+        let (start, end) = segments[first_segment_id];
+        is_used[first_segment_id] = true;
+        let mut nodes = vec![start, end];
+        let mut current = end;
+        while current != start {
+            let next_segment_id = outgoing_segments.get(&current)
+                .and_then(|segment_ids| segment_ids.iter().find(|segment_id| !is_used[**segment_id]).copied());
+            match next_segment_id {
+                Some(segment_id) => {
+                    is_used[segment_id] = true;
+                    current = segments[segment_id].1;
+                    nodes.push(current);
+                },
+                None => break,
+            }
+        }
+
+        nodes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_cube;
+    use crate::vector::Vector;
+
+    fn get_signed_area_xy(polygon: &Polygon) -> f64 {
+        let vertices = &polygon.vertices;
+        (0..vertices.len()).map(|i| {
+            let next = &vertices[(i + 1) % vertices.len()];
+            vertices[i].x * next.y - next.x * vertices[i].y
+        }).sum::<f64>() / 2.0
+    }
+
+    #[test]
+    fn test_section_with_plane_cube_counter_clockwise() {
+        let input = get_cube(0.0, 2.0);
+        let plane = Plane::new(Point::new(0.0, 0.0, 0.5), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = input.section_with_plane(&plane);
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].vertices.len(), 8);
+        assert!(actual[0].vertices.iter().all(|vertex| vertex.z == 0.5));
+        assert!((get_signed_area_xy(&actual[0]) - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_section_with_plane_flipped_cube_clockwise() {
+        let input = get_cube(0.0, 2.0).get_with_all_faces_flipped();
+        let plane = Plane::new(Point::new(0.0, 0.0, 0.5), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = input.section_with_plane(&plane);
+
+        assert_eq!(actual.len(), 1);
+        assert!((get_signed_area_xy(&actual[0]) + 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_section_with_plane_2_cubes() {
+        let input = Mesh::join(&vec![get_cube(0.0, 2.0), get_cube(5.0, 6.0)]);
+        let plane = Plane::new(Point::new(5.5, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0));
+
+        let actual = input.section_with_plane(&plane);
+
+        assert_eq!(actual.len(), 1);
+        assert!(actual[0].vertices.iter().all(|vertex| vertex.x == 5.5));
+    }
+
+    #[test]
+    fn test_section_with_plane_through_vertices() {
+        let input = get_cube(0.0, 2.0);
+        let plane = Plane::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = input.section_with_plane(&plane);
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].vertices, vec![Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(2.0, 2.0, 0.0), Point::new(0.0, 2.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_get_section_curves_open_cube() {
+        let cube = get_cube(0.0, 2.0);
+        let input = Mesh::new(cube.coordinates.clone(), cube.indices[18..].to_vec()); // Bottom, top and front removed
+        let plane = Plane::new(Point::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 1.0));
+
+        let (closed, open) = input.get_section_curves(&plane);
+
+        assert!(closed.is_empty());
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0][0], Point::new(2.0, 0.0, 1.0));
+        assert_eq!(open[0][open[0].len() - 1], Point::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_section_with_plane_no_intersection() {
+        let input = get_cube(0.0, 2.0);
+        let plane = Plane::new(Point::new(0.0, 0.0, 3.0), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(input.section_with_plane(&plane).is_empty());
+        assert_eq!(input.get_section_curves(&plane).1, Vec::<Vec<Point>>::new());
+    }
+}
@@ -0,0 +1,160 @@
+use std::f64::consts::PI;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::vector::Vector;
+
+impl Mesh {
+    /// Gets the generalized winding number of the [Point] with respect to the [Mesh].
+    ///
+    /// It's the sum of solid angles of all faces seen from the Point, divided by `4 * PI`. For
+    /// the closed Mesh with faces oriented outside it's `1.0` inside and `0.0` outside, for
+    /// the Mesh oriented inside it's `-1.0` inside. For Meshes with small cracks or holes it
+    /// changes smoothly, so it's still close to these values far from the holes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    ///
+    /// assert!((tetrahedron.get_winding_number(&Point::new(0.5, 0.5, 0.5)) - 1.0).abs() < 1e-12);
+    /// assert!(tetrahedron.get_winding_number(&Point::new(5.0, 5.0, 5.0)).abs() < 1e-12);
+    /// ```
+    pub fn get_winding_number(&self, point: &Point) -> f64 {
+        let mut solid_angles_sum = 0.0;
+        for triangle in self.triangles() {
+            let a = Vector::from_2_points(point, &triangle.first_point);
+            let b = Vector::from_2_points(point, &triangle.second_point);
+            let c = Vector::from_2_points(point, &triangle.third_point);
+            let a_length = a.get_length();
+            let b_length = b.get_length();
+            let c_length = c.get_length();
+
+            let numerator = a.get_dot_product(&b.get_cross_product(&c)); // Van Oosterom and Strackee formula
+            let denominator = a_length * b_length * c_length + a.get_dot_product(&b) * c_length + b.get_dot_product(&c) * a_length + c.get_dot_product(&a) * b_length;
+            solid_angles_sum += 2.0 * f64::atan2(numerator, denominator);
+        }
+
+        solid_angles_sum / (4.0 * PI)
+    }
+
+    /// Checks if the [Point] is inside the [Mesh] using the generalized winding number
+    /// (see [Mesh::get_winding_number]).
+    ///
+    /// Point is inside if the absolute value of its winding number is at least `0.5`, so the
+    /// orientation of the whole Mesh doesn't matter, but its faces should have consistent winding.
+    /// Unlike [Point::is_inside_mesh_using_xyz], which shoots rays, it works also for Meshes with
+    /// small cracks or holes.
+    ///
+    /// # Example
+    ///
+    /// Here is an example of the tetrahedron with one face missing.
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let open_tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 0, 3, 2]
+    /// );
+    ///
+    /// assert!(open_tetrahedron.contains_point(&Point::new(0.2, 0.2, 0.2)));
+    /// assert!(!open_tetrahedron.contains_point(&Point::new(-1.0, 0.5, 0.5)));
+    /// ```
+    pub fn contains_point(&self, point: &Point) -> bool {
+        self.get_winding_number(point).abs() >= 0.5
+    }
+
+    /// Gets the signed distance from the [Point] to the surface of the [Mesh].
+    ///
+    /// The absolute value is the distance to the closest point on any face, the sign is negative
+    /// if the Point is inside the Mesh (see [Mesh::contains_point]) and positive otherwise.
+    ///
+    /// `f64::INFINITY` is returned for the Mesh without faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let tetrahedron = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 3.0],
+    ///     vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]
+    /// );
+    ///
+    /// assert!((tetrahedron.get_signed_distance(&Point::new(0.5, 0.5, 0.5)) + 0.5).abs() < 1e-12);
+    /// assert!((tetrahedron.get_signed_distance(&Point::new(0.5, 1.0, -2.0)) - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn get_signed_distance(&self, point: &Point) -> f64 {
+        let distance = self.triangles()
+            .map(|triangle| triangle.get_closest_point(point).get_distance_to_point(point))
+            .fold(f64::INFINITY, f64::min);
+
+        if self.contains_point(point) {
+            -distance
+        }
+        else {
+            distance
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::get_cube;
+    use super::*;
+
+    #[test]
+    fn test_get_winding_number_cube() {
+        let input = get_cube(0.0, 2.0);
+
+        assert!((input.get_winding_number(&Point::new(1.0, 1.0, 1.0)) - 1.0).abs() < 1e-12);
+        assert!((input.get_winding_number(&Point::new(0.1, 1.9, 0.3)) - 1.0).abs() < 1e-12);
+        assert!(input.get_winding_number(&Point::new(3.0, 1.0, 1.0)).abs() < 1e-12);
+        assert!((input.get_with_all_faces_flipped().get_winding_number(&Point::new(1.0, 1.0, 1.0)) + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_contains_point_cube_with_crack() {
+        let cube = get_cube(0.0, 2.0);
+        let input = Mesh::new(cube.coordinates.clone(), cube.indices[6..].to_vec()); // Bottom removed
+
+        let center = Point::new(1.0, 1.0, 1.0);
+        assert!((input.get_winding_number(&center) - 5.0 / 6.0).abs() < 1e-12);
+        assert!(input.contains_point(&center));
+        assert!(!center.is_inside_mesh_using_xyz(&input));
+        assert!(!input.contains_point(&Point::new(1.0, 1.0, 3.0)));
+    }
+
+    #[test]
+    fn test_contains_point_flipped_cube() {
+        let input = get_cube(0.0, 2.0).get_with_all_faces_flipped();
+
+        assert!(input.contains_point(&Point::new(1.0, 1.0, 1.0)));
+        assert!(!input.contains_point(&Point::new(-1.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_get_signed_distance_cube() {
+        let input = get_cube(0.0, 2.0);
+
+        assert!((input.get_signed_distance(&Point::new(1.0, 1.0, 1.0)) + 1.0).abs() < 1e-12);
+        assert!((input.get_signed_distance(&Point::new(1.5, 1.0, 1.0)) + 0.5).abs() < 1e-12);
+        assert!((input.get_signed_distance(&Point::new(5.0, 1.0, 1.0)) - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_signed_distance_empty() {
+        let input = Mesh::new(vec![], vec![]);
+
+        assert_eq!(input.get_signed_distance(&Point::new(1.0, 1.0, 1.0)), f64::INFINITY);
+        assert!(!input.contains_point(&Point::new(1.0, 1.0, 1.0)));
+    }
+}
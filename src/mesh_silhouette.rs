@@ -0,0 +1,172 @@
+use i_overlay::core::fill_rule::FillRule;
+use i_overlay::float::simplify::SimplifyShape;
+use crate::mesh::Mesh;
+use crate::point2d::Point2D;
+use crate::polygon2d::Polygon2D;
+use crate::vector::Vector;
+
+/// Merging faces can leave vertices on straight outline segments, these are removed.
+const STRAIGHT_ANGLE_TOLERANCE: f64 = 1e-6;
+
+impl Mesh {
+    /// Gets the area of the [Mesh] projected along the `direction`, e.g. the area of its shadow
+    /// or the area exposed to the wind.
+    ///
+    /// Overlapping parts are counted once, as it is the area of
+    /// [Mesh::get_silhouette_outline].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 2.0, 0.0,
+    ///          1.0, 1.0, 5.0, 3.0, 1.0, 5.0, 3.0, 3.0, 5.0, 1.0, 3.0, 5.0],
+    ///     vec![0, 1, 2, 0, 2, 3,
+    ///          4, 5, 6, 4, 6, 7]);
+    ///
+    /// let actual = input.get_projected_area(&Vector::new(0.0, 0.0, -1.0));
+    ///
+    /// assert!((actual - 7.0).abs() < 1e-6);
+    /// ```
+    pub fn get_projected_area(&self, direction: &Vector) -> f64 {
+        self.get_silhouette_outline(direction).iter().map(get_signed_area).sum()
+    }
+
+    /// Gets the outlines of the [Mesh] projected along the `direction`, with all the faces
+    /// merged together.
+    ///
+    /// Points are projected on the plane perpendicular to the `direction`, with 2D axes x and
+    /// y, such that x × y gives the `direction`. X axis is
+    /// [Vector::get_any_perpendicular] of the `direction`.
+    ///
+    /// Outer outlines are anticlockwise, and each is followed by its holes, which are
+    /// clockwise. Vertices on straight segments are removed. Faces seen edge-on are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 2.0, 0.0,
+    ///          1.0, 1.0, 5.0, 3.0, 1.0, 5.0, 3.0, 3.0, 5.0, 1.0, 3.0, 5.0],
+    ///     vec![0, 1, 2, 0, 2, 3,
+    ///          4, 5, 6, 4, 6, 7]);
+    ///
+    /// let actual = input.get_silhouette_outline(&Vector::new(0.0, 0.0, -1.0));
+    ///
+    /// assert_eq!(actual.len(), 1);
+    /// assert_eq!(actual[0].vertices.len(), 8);
+    /// assert!(!actual[0].is_clockwise());
+    /// ```
+    pub fn get_silhouette_outline(&self, direction: &Vector) -> Vec<Polygon2D> {
+        let direction = direction.get_unitized();
+        let x = direction.get_any_perpendicular().get_unitized();
+        let y = direction.get_cross_product(&x);
+
+        let projected: Vec<[f64; 2]> = self.to_points().iter()
+            .map(|point| {
+                let vector = point.to_vector();
+                [vector.get_dot_product(&x), vector.get_dot_product(&y)]
+            })
+            .collect();
+
+        let mut triangles: Vec<Vec<[f64; 2]>> = Vec::with_capacity(self.get_number_of_faces());
+        for face in self.indices.chunks_exact(3) {
+            let (first, second, third) = (projected[face[0]], projected[face[1]], projected[face[2]]);
+            let doubled_area = (second[0] - first[0]) * (third[1] - first[1]) - (third[0] - first[0]) * (second[1] - first[1]);
+            if doubled_area > 0.0 {
+                triangles.push(vec![first, second, third]);
+            } else if doubled_area < 0.0 {
+                triangles.push(vec![first, third, second]);
+            }
+        }
+
+        // All triangles are anticlockwise, so the non-zero fill rule gives their union.
+        triangles.simplify_shape(FillRule::NonZero).into_iter()
+            .flatten()
+            .map(|contour| Polygon2D::new(contour.into_iter().map(|point| Point2D::new(point[0], point[1])).collect())
+                .get_with_removed_neighbour_parallel_segments_with_tolerance(STRAIGHT_ANGLE_TOLERANCE))
+            .collect()
+    }
+}
+
+/// Gets the area of the [Polygon2D], positive for the anticlockwise ones and negative for the
+/// clockwise ones.
+fn get_signed_area(polygon: &Polygon2D) -> f64 {
+    let vertices = &polygon.vertices;
+    let mut doubled_area = 0.0;
+    for (i, current) in vertices.iter().enumerate() {
+        let next = &vertices[(i + 1) % vertices.len()];
+        doubled_area += current.x * next.y - next.x * current.y;
+    }
+
+    doubled_area / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::get_cube;
+    use super::*;
+
+    #[test]
+    fn test_get_projected_area_cube_along_axes() {
+        let input = get_cube(0.0, 2.0);
+
+        for direction in [Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, -1.0, 0.0), Vector::new(0.0, 0.0, 3.0)] {
+            assert!((input.get_projected_area(&direction) - 4.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_get_projected_area_cube_along_diagonal() {
+        let input = get_cube(0.0, 1.0);
+
+        let actual = input.get_projected_area(&Vector::new(1.0, 1.0, 1.0));
+
+        // Regular hexagon with the side sqrt(2/3).
+        assert!((actual - 3.0_f64.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_get_silhouette_outline_with_hole() {
+        let frame = Mesh::join(&vec![
+            get_cube(0.0, 1.0),
+            get_cube(0.0, 1.0) + Vector::new(1.0, 0.0, 0.0),
+            get_cube(0.0, 1.0) + Vector::new(2.0, 0.0, 0.0),
+            get_cube(0.0, 1.0) + Vector::new(0.0, 1.0, 0.0),
+            get_cube(0.0, 1.0) + Vector::new(2.0, 1.0, 0.0),
+            get_cube(0.0, 1.0) + Vector::new(0.0, 2.0, 0.0),
+            get_cube(0.0, 1.0) + Vector::new(1.0, 2.0, 0.0),
+            get_cube(0.0, 1.0) + Vector::new(2.0, 2.0, 0.0),
+        ]);
+
+        let actual = frame.get_silhouette_outline(&Vector::new(0.0, 0.0, 1.0));
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0].vertices.len(), 4);
+        assert!(!actual[0].is_clockwise());
+        assert_eq!(actual[1].vertices.len(), 4);
+        assert!(actual[1].is_clockwise());
+        assert!((frame.get_projected_area(&Vector::new(0.0, 0.0, 1.0)) - 8.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_get_silhouette_outline_face_seen_edge_on() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        assert!(input.get_silhouette_outline(&Vector::new(1.0, 0.0, 0.0)).is_empty());
+        assert_eq!(input.get_projected_area(&Vector::new(1.0, 0.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_get_silhouette_outline_empty() {
+        let input = Mesh::new(vec![], vec![]);
+
+        assert!(input.get_silhouette_outline(&Vector::new(0.0, 0.0, 1.0)).is_empty());
+    }
+}
@@ -0,0 +1,505 @@
+use std::collections::{HashMap, HashSet};
+use crate::face_neighbours::FaceNeighbours;
+use crate::face_neighbours_angle::FaceNeighboursAngle;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::vector::Vector;
+
+/// Number of vertex position updates following the filtered face normals in every iteration of
+/// [Mesh::get_with_bilateral_smoothing].
+const VERTEX_UPDATES_PER_ITERATION: usize = 10;
+
+impl Mesh {
+    /// Creates a new [Mesh] smoothed with the Laplacian smoothing.
+    ///
+    /// In every iteration each vertex is moved towards the average of its neighbouring vertices
+    /// (vertices connected with it by an edge). `factor` tells how far it's moved: 0.0 means
+    /// no movement, 1.0 means the vertex is moved exactly to the average.
+    ///
+    /// Topology is not changed, other data is copied without [Mesh::vertex_normals], as they no
+    /// longer match the moved vertices. The input [Mesh] should be welded, otherwise vertices of
+    /// different faces are smoothed separately.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         10.0,0.0,0.0,
+    ///         10.0,10.0,0.0,
+    ///         0.0,10.0,0.0,
+    ///         5.0,5.0,4.0
+    ///     ],
+    ///     vec![
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// let actual = input.get_with_laplacian_smoothing(1, 0.5);
+    ///
+    /// let expected = Mesh::new(
+    ///     vec![
+    ///         2.5,2.5,0.6666666666666666,
+    ///         7.5,2.5,0.6666666666666666,
+    ///         7.5,7.5,0.6666666666666666,
+    ///         2.5,7.5,0.6666666666666666,
+    ///         5.0,5.0,2.0
+    ///     ],
+    ///     vec![
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// assert!(expected.eq_with_tolerance(&actual, 0.0001));
+    /// ```
+    pub fn get_with_laplacian_smoothing(&self, iterations: usize, factor: f64) -> Mesh {
+        self.get_with_laplacian_smoothing_with_weights(iterations, factor, &HashMap::new())
+    }
+
+    /// Creates a new [Mesh] smoothed with the Laplacian smoothing, but keeping locked vertices
+    /// in place.
+    ///
+    /// Locked vertices keep their exact coordinates, so it's useful for preserving e.g. feature
+    /// points, boundary vertices or survey points. Other vertices are smoothed the same way
+    /// as in [Mesh::get_with_laplacian_smoothing].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         10.0,0.0,0.0,
+    ///         10.0,10.0,0.0,
+    ///         0.0,10.0,0.0,
+    ///         5.0,5.0,4.0
+    ///     ],
+    ///     vec![
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// let locked = HashSet::from([0, 1, 2, 3]);
+    ///
+    /// let actual = input.get_with_laplacian_smoothing_with_locked_vertices(1, 0.5, &locked);
+    ///
+    /// let expected = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         10.0,0.0,0.0,
+    ///         10.0,10.0,0.0,
+    ///         0.0,10.0,0.0,
+    ///         5.0,5.0,2.0
+    ///     ],
+    ///     vec![
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// assert!(expected.eq_with_tolerance(&actual, 0.0001));
+    /// ```
+    pub fn get_with_laplacian_smoothing_with_locked_vertices(&self, iterations: usize, factor: f64, locked_vertex_ids: &HashSet<usize>) -> Mesh {
+        let weights: HashMap<usize, f64> = locked_vertex_ids.iter().map(|vertex_id| (*vertex_id, 0.0)).collect();
+
+        self.get_with_laplacian_smoothing_with_weights(iterations, factor, &weights)
+    }
+
+    /// Creates a new [Mesh] smoothed with the Laplacian smoothing, using a weight map for
+    /// vertices.
+    ///
+    /// The weight of a vertex multiplies the `factor` used for it. Weight 0.0 means the vertex
+    /// is locked, 1.0 means it's smoothed normally. Weights are clamped to the 0.0..=1.0 range.
+    /// Vertices which are not in the `weights` map get 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         10.0,0.0,0.0,
+    ///         10.0,10.0,0.0,
+    ///         0.0,10.0,0.0,
+    ///         5.0,5.0,4.0
+    ///     ],
+    ///     vec![
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// let weights = HashMap::from([(0, 0.0), (1, 0.0), (2, 0.0), (3, 0.0), (4, 0.5)]);
+    ///
+    /// let actual = input.get_with_laplacian_smoothing_with_weights(1, 1.0, &weights);
+    ///
+    /// let expected = Mesh::new(
+    ///     vec![
+    ///         0.0,0.0,0.0,
+    ///         10.0,0.0,0.0,
+    ///         10.0,10.0,0.0,
+    ///         0.0,10.0,0.0,
+    ///         5.0,5.0,2.0
+    ///     ],
+    ///     vec![
+    ///         0,1,4,
+    ///         1,2,4,
+    ///         2,3,4,
+    ///         3,0,4
+    ///     ]);
+    ///
+    /// assert!(expected.eq_with_tolerance(&actual, 0.0001));
+    /// ```
+    pub fn get_with_laplacian_smoothing_with_weights(&self, iterations: usize, factor: f64, weights: &HashMap<usize, f64>) -> Mesh {
+        let number_of_vertices = self.get_number_of_vertices();
+        let neighbours = self.get_vertex_neighbours();
+        let vertex_factors: Vec<f64> = (0..number_of_vertices)
+            .map(|vertex_id| factor * weights.get(&vertex_id).copied().unwrap_or(1.0).clamp(0.0, 1.0))
+            .collect();
+
+        let mut coordinates = self.coordinates.clone();
+        for _ in 0..iterations {
+            let previous = coordinates.clone();
+            for vertex_id in 0..number_of_vertices {
+                let vertex_factor = vertex_factors[vertex_id];
+                let vertex_neighbours = &neighbours[vertex_id];
+                if vertex_factor == 0.0 || vertex_neighbours.is_empty() {
+                    continue;
+                }
+                for axis in 0..3 {
+                    let sum: f64 = vertex_neighbours.iter().map(|neighbour| previous[neighbour * 3 + axis]).sum();
+                    let average = sum / vertex_neighbours.len() as f64;
+                    let current = previous[vertex_id * 3 + axis];
+                    coordinates[vertex_id * 3 + axis] = current + (average - current) * vertex_factor;
+                }
+            }
+        }
+
+        let mut smoothed = self.clone();
+        smoothed.coordinates = coordinates;
+        smoothed.vertex_normals = None;
+
+        smoothed
+    }
+
+    /// Creates a new [Mesh] smoothed with the bilateral normal filtering, which removes noise
+    /// but keeps sharp creases (e.g. panel edges), unlike [Mesh::get_with_laplacian_smoothing].
+    ///
+    /// Creases are edges where the angle between normals of the neighbouring faces (see
+    /// [FaceNeighboursAngle]) is bigger than `crease_angle` (in radians). In every iteration
+    /// each face normal is replaced with the weighted average of normals of the face and its
+    /// neighbours, but never across the crease. Neighbours with closer centroids and with more
+    /// similar normals get bigger weights. Then vertices are moved, so faces follow the
+    /// filtered normals.
+    ///
+    /// Topology is not changed, other data is copied without [Mesh::vertex_normals], same as in
+    /// [Mesh::get_with_laplacian_smoothing]. The input [Mesh] should be welded, otherwise faces
+    /// are not connected with their neighbours.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Two perpendicular walls with a bump on the first one
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0,
+    ///          0.0, 1.0, 0.0, 1.0, 1.0, 0.1, 2.0, 1.0, 0.0,
+    ///          0.0, 2.0, 0.0, 1.0, 2.0, 0.0, 2.0, 2.0, 0.0,
+    ///          0.0, 2.0, 1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0],
+    ///     vec![0, 1, 4, 0, 4, 3, 1, 2, 5, 1, 5, 4,
+    ///          3, 4, 7, 3, 7, 6, 4, 5, 8, 4, 8, 7,
+    ///          6, 7, 10, 6, 10, 9, 7, 8, 11, 7, 11, 10]);
+    ///
+    /// let actual = input.get_with_bilateral_smoothing(5, 1.0);
+    ///
+    /// let triangles = actual.to_triangles();
+    /// assert!(actual.coordinates[14].abs() < 0.05); // the bump is flattened
+    /// assert!((triangles[0].get_normals_angle(&triangles[11]) - FRAC_PI_2).abs() < 0.05); // the crease stays
+    /// ```
+    pub fn get_with_bilateral_smoothing(&self, iterations: usize, crease_angle: f64) -> Mesh {
+        let number_of_faces = self.get_number_of_faces();
+        let face_neighbours = FaceNeighbours::from_mesh(self);
+        let face_neighbours_angles = FaceNeighboursAngle::from_face_neighbours_and_triangles(&face_neighbours, &self.to_triangles());
+        let smooth_neighbours: Vec<Vec<usize>> = face_neighbours.iter().zip(face_neighbours_angles.iter())
+            .map(|(neighbours, angles)| {
+                [(neighbours.first, angles.first), (neighbours.second, angles.second), (neighbours.third, angles.third)].iter()
+                    .filter_map(|(neighbour, angle)| match (neighbour, angle) {
+                        (Some(neighbour), Some(angle)) if *angle <= crease_angle => Some(*neighbour),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut vertex_faces: Vec<Vec<usize>> = vec![Vec::new(); self.get_number_of_vertices()];
+        for (face_id, face) in self.indices.chunks(3).enumerate() {
+            for vertex_id in face {
+                vertex_faces[*vertex_id].push(face_id);
+            }
+        }
+        let normal_sigma = 2.0 * (crease_angle / 2.0).sin();
+
+        let mut smoothed = Mesh::new_with_id(self.id, self.coordinates.clone(), self.indices.clone());
+        for _ in 0..iterations {
+            let triangles = smoothed.to_triangles();
+            let normals: Vec<Vector> = triangles.iter()
+                .map(|triangle| triangle.get_normal_vector_unitized_checked().unwrap_or(Vector::zero()))
+                .collect();
+            let centroids: Vec<Point> = triangles.iter().map(|triangle| triangle.get_centroid()).collect();
+            let areas: Vec<f64> = triangles.iter().map(|triangle| triangle.get_area()).collect();
+
+            let neighbour_distances: Vec<f64> = (0..number_of_faces)
+                .flat_map(|face_id| smooth_neighbours[face_id].iter().map(move |neighbour| (face_id, *neighbour)))
+                .map(|(face_id, neighbour)| centroids[face_id].get_distance_to_point(&centroids[neighbour]))
+                .collect();
+            if neighbour_distances.is_empty() {
+                break;
+            }
+            let distance_sigma = neighbour_distances.iter().sum::<f64>() / neighbour_distances.len() as f64;
+
+            let filtered_normals: Vec<Vector> = (0..number_of_faces).map(|face_id| {
+                let mut sum = normals[face_id] * areas[face_id];
+                for neighbour in &smooth_neighbours[face_id] {
+                    let distance = centroids[face_id].get_distance_to_point(&centroids[*neighbour]);
+                    let normal_difference = (normals[face_id] - normals[*neighbour]).get_length();
+                    let weight = areas[*neighbour] *
+                        (-0.5 * (distance / distance_sigma).powi(2)).exp() *
+                        (-0.5 * (normal_difference / normal_sigma).powi(2)).exp();
+                    sum = sum + normals[*neighbour] * weight;
+                }
+                if sum.get_length() > 0.0 {
+                    sum.get_unitized()
+                } else {
+                    normals[face_id]
+                }
+            }).collect();
+
+            for _ in 0..VERTEX_UPDATES_PER_ITERATION {
+                let centroids: Vec<Point> = smoothed.triangles().map(|triangle| triangle.get_centroid()).collect();
+                let mut points = smoothed.to_points();
+                for (vertex_id, point) in points.iter_mut().enumerate() {
+                    let faces = &vertex_faces[vertex_id];
+                    if faces.is_empty() {
+                        continue;
+                    }
+                    let mut movement = Vector::zero();
+                    for face_id in faces {
+                        let normal = filtered_normals[*face_id];
+                        movement = movement + normal * normal.get_dot_product(&Vector::from_2_points(point, &centroids[*face_id]));
+                    }
+                    *point = *point + movement * (1.0 / faces.len() as f64);
+                }
+                smoothed.coordinates = points.iter().flat_map(|point| [point.x, point.y, point.z]).collect();
+            }
+        }
+
+        let mut result = self.clone();
+        result.coordinates = smoothed.coordinates;
+        result.vertex_normals = None;
+
+        result
+    }
+
+    /// Gets ids of neighbouring vertices (connected by an edge) for each vertex.
+    fn get_vertex_neighbours(&self) -> Vec<Vec<usize>> {
+        let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); self.get_number_of_vertices()];
+        for face in self.indices.chunks(3) {
+            for i in 0..3 {
+                let start = face[i];
+                let end = face[(i + 1) % 3];
+                if !neighbours[start].contains(&end) {
+                    neighbours[start].push(end);
+                }
+                if !neighbours[end].contains(&start) {
+                    neighbours[end].push(start);
+                }
+            }
+        }
+
+        neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_noisy_grid() -> Mesh {
+        let mut coordinates = Vec::new();
+        for i in 0..5 {
+            for j in 0..5 {
+                let noise = if (i + j) % 2 == 0 { 0.5 } else { -0.5 };
+                coordinates.extend_from_slice(&[i as f64, j as f64, noise]);
+            }
+        }
+        let mut indices = Vec::new();
+        for i in 0..4 {
+            for j in 0..4 {
+                let a = i * 5 + j;
+                indices.extend_from_slice(&[a, a + 5, a + 6, a, a + 6, a + 1]);
+            }
+        }
+
+        Mesh::new_with_id(Some(7), coordinates, indices)
+    }
+
+    fn create_noisy_folded_grid() -> Mesh {
+        let mut coordinates = Vec::new();
+        for i in 0..9 {
+            for j in 0..5 {
+                let noise = if i == 4 { 0.0 } else { ((i * 7 + j * 3) % 5) as f64 * 0.02 - 0.04 };
+                if i <= 4 {
+                    coordinates.extend_from_slice(&[i as f64, j as f64, noise]);
+                } else {
+                    coordinates.extend_from_slice(&[4.0 + noise, j as f64, (i - 4) as f64]);
+                }
+            }
+        }
+        let mut indices = Vec::new();
+        for i in 0..8 {
+            for j in 0..4 {
+                let a = i * 5 + j;
+                indices.extend_from_slice(&[a, a + 5, a + 6, a, a + 6, a + 1]);
+            }
+        }
+
+        Mesh::new_with_id(Some(3), coordinates, indices)
+    }
+
+    /// Gets RMS distances of vertices from the floor and from the wall, and the biggest distance
+    /// from the crease line of the folded grid.
+    fn get_folded_grid_deviations(mesh: &Mesh) -> (f64, f64, f64) {
+        let points = mesh.to_points();
+        let floor = ((0..20).map(|vertex_id| points[vertex_id].z.powi(2)).sum::<f64>() / 20.0).sqrt();
+        let wall = ((25..45).map(|vertex_id| (points[vertex_id].x - 4.0).powi(2)).sum::<f64>() / 20.0).sqrt();
+        let crease = (20..25).map(|vertex_id| points[vertex_id].z.abs().max((points[vertex_id].x - 4.0).abs())).fold(0.0, f64::max);
+
+        (floor, wall, crease)
+    }
+
+    #[test]
+    fn test_get_with_bilateral_smoothing_keeps_crease() {
+        let input = create_noisy_folded_grid();
+        let (input_floor, input_wall, _) = get_folded_grid_deviations(&input);
+
+        let actual = input.get_with_bilateral_smoothing(10, 0.5);
+
+        assert_eq!(actual.id, Some(3));
+        assert_eq!(actual.indices, input.indices);
+        let (floor, wall, crease) = get_folded_grid_deviations(&actual);
+        assert!(floor < input_floor * 0.5);
+        assert!(wall < input_wall * 0.5);
+        assert!(crease < 0.02);
+    }
+
+    #[test]
+    fn test_get_with_laplacian_smoothing_rounds_crease() {
+        let input = create_noisy_folded_grid();
+
+        let actual = input.get_with_laplacian_smoothing(10, 0.5);
+
+        let (_, _, crease) = get_folded_grid_deviations(&actual);
+        assert!(crease > 0.1);
+    }
+
+    #[test]
+    fn test_get_with_bilateral_smoothing_zero_iterations() {
+        let input = create_noisy_folded_grid();
+
+        let actual = input.get_with_bilateral_smoothing(0, 0.5);
+
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_get_with_laplacian_smoothing_reduces_noise() {
+        let input = create_noisy_grid();
+
+        let actual = input.get_with_laplacian_smoothing(10, 0.5);
+
+        assert_eq!(actual.id, Some(7));
+        assert_eq!(actual.indices, input.indices);
+        let input_max_z = input.coordinates.chunks(3).map(|v| v[2].abs()).fold(0.0, f64::max);
+        let actual_max_z = actual.coordinates.chunks(3).map(|v| v[2].abs()).fold(0.0, f64::max);
+        assert!(actual_max_z < input_max_z * 0.5);
+    }
+
+    #[test]
+    fn test_get_with_laplacian_smoothing_zero_iterations() {
+        let input = create_noisy_grid();
+
+        let actual = input.get_with_laplacian_smoothing(0, 0.5);
+
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_get_with_laplacian_smoothing_with_locked_vertices_keeps_exact_coordinates() {
+        let input = create_noisy_grid();
+        let locked = HashSet::from([0, 4, 12, 20, 24]);
+
+        let actual = input.get_with_laplacian_smoothing_with_locked_vertices(20, 0.7, &locked);
+
+        for vertex_id in 0..input.get_number_of_vertices() {
+            let input_vertex = &input.coordinates[vertex_id * 3..vertex_id * 3 + 3];
+            let actual_vertex = &actual.coordinates[vertex_id * 3..vertex_id * 3 + 3];
+            if locked.contains(&vertex_id) {
+                assert_eq!(input_vertex, actual_vertex);
+            } else {
+                assert_ne!(input_vertex, actual_vertex);
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_with_laplacian_smoothing_with_weights_partial() {
+        let input = create_noisy_grid();
+        let weights = HashMap::from([(12, 0.5)]);
+
+        let full = input.get_with_laplacian_smoothing(1, 0.8);
+        let actual = input.get_with_laplacian_smoothing_with_weights(1, 0.8, &weights);
+
+        let input_z = input.coordinates[12 * 3 + 2];
+        let full_z = full.coordinates[12 * 3 + 2];
+        let actual_z = actual.coordinates[12 * 3 + 2];
+        assert!((actual_z - (input_z + (full_z - input_z) * 0.5)).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_get_with_laplacian_smoothing_keeps_other_data() {
+        let mut input = create_noisy_grid();
+        input.id = Some(8);
+        input.face_groups = Some(vec![1; input.get_number_of_faces()]);
+        input.vertex_normals = Some(vec![0.0; input.coordinates.len()]);
+        input.metadata.insert("name".to_string(), "terrain".to_string());
+
+        let actual = input.get_with_laplacian_smoothing(2, 0.5);
+        let actual_bilateral = input.get_with_bilateral_smoothing(2, 1.0);
+
+        assert_eq!(actual.id, Some(8));
+        assert_eq!(actual.face_groups, input.face_groups);
+        assert_eq!(actual.metadata, input.metadata);
+        assert_eq!(actual.vertex_normals, None);
+        assert_eq!(actual_bilateral.face_groups, input.face_groups);
+        assert_eq!(actual_bilateral.metadata, input.metadata);
+        assert_eq!(actual_bilateral.vertex_normals, None);
+    }
+}
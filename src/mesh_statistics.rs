@@ -0,0 +1,70 @@
+use crate::bounding_box::BoundingBox;
+use serde::{Deserialize, Serialize};
+
+/// Summarizes basic properties of a [Mesh](crate::mesh::Mesh) in one place.
+///
+/// It's created by [Mesh::get_statistics](crate::mesh::Mesh::get_statistics), so reports don't
+/// need separate calls for every number. It can be serialized e.g. to json.
+///
+/// Edge lengths are measured for undirected edges, so an edge shared by 2 faces is counted once.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::mesh::Mesh;
+///
+/// let input = Mesh::new(vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 4.0, 0.0], vec![0, 1, 2]);
+///
+/// let actual = input.get_statistics();
+///
+/// assert_eq!(actual.number_of_vertices, 3);
+/// assert_eq!(actual.number_of_faces, 1);
+/// assert_eq!(actual.number_of_edges, 3);
+/// assert_eq!(actual.area, 6.0);
+/// assert_eq!(actual.min_edge_length, 3.0);
+/// assert_eq!(actual.mean_edge_length, 4.0);
+/// assert_eq!(actual.max_edge_length, 5.0);
+/// assert_eq!(actual.number_of_degenerate_faces, 0);
+/// assert_eq!(actual.number_of_boundary_edges, 3);
+///
+/// let json = serde_json::to_string(&actual).unwrap();
+/// assert_eq!(serde_json::from_str::<meshmeshmesh::mesh_statistics::MeshStatistics>(&json).unwrap(), actual);
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeshStatistics {
+    /// Number of vertices.
+    pub number_of_vertices: usize,
+    /// Number of faces.
+    pub number_of_faces: usize,
+    /// Number of unique undirected edges.
+    pub number_of_edges: usize,
+    /// Total area of all the faces.
+    pub area: f64,
+    /// Length of the shortest edge, `0.0` if there are no edges.
+    pub min_edge_length: f64,
+    /// Mean length of edges, `0.0` if there are no edges.
+    pub mean_edge_length: f64,
+    /// Length of the longest edge, `0.0` if there are no edges.
+    pub max_edge_length: f64,
+    /// Number of faces with (nearly) zero area.
+    pub number_of_degenerate_faces: usize,
+    /// Number of edges used by only 1 face.
+    pub number_of_boundary_edges: usize,
+    /// Bounding box of all the vertices, `None` if there are no vertices.
+    pub bounding_box: Option<BoundingBox>,
+}
+
+impl PartialEq for MeshStatistics {
+    fn eq(&self, other: &Self) -> bool {
+        self.number_of_vertices == other.number_of_vertices &&
+            self.number_of_faces == other.number_of_faces &&
+            self.number_of_edges == other.number_of_edges &&
+            self.area == other.area &&
+            self.min_edge_length == other.min_edge_length &&
+            self.mean_edge_length == other.mean_edge_length &&
+            self.max_edge_length == other.max_edge_length &&
+            self.number_of_degenerate_faces == other.number_of_degenerate_faces &&
+            self.number_of_boundary_edges == other.number_of_boundary_edges &&
+            self.bounding_box == other.bounding_box
+    }
+}
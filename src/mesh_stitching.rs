@@ -0,0 +1,246 @@
+use std::collections::HashSet;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::uv_channel::UvChannel;
+use crate::weld_report::WeldReport;
+
+impl Mesh {
+    /// Stitches open boundaries of the [Mesh], e.g. of separate parts joined together, by
+    /// welding boundary vertices closer to each other than the `tolerance`, so coincident
+    /// open edges become shared ones.
+    ///
+    /// Unlike [Mesh::weld_vertices] only vertices of edges with a missing neighbour face are
+    /// welded, so close vertices inside the surface (e.g. of thin walls) are never merged.
+    /// Other fields are set the same way as by [Mesh::weld_vertices].
+    ///
+    /// Returns the [WeldReport] describing what was merged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let first = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let second = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 1.0, 0.0005, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let mut input = Mesh::join(&vec![first, second]);
+    ///
+    /// let report = input.stitch_boundaries(0.001);
+    ///
+    /// assert_eq!(report.number_of_merged_vertices, 2);
+    /// assert_eq!(input.indices, vec![0, 1, 2, 0, 2, 3]);
+    /// assert_eq!(input.get_boundary_loops_vertex_ids().len(), 1);
+    /// ```
+    pub fn stitch_boundaries(&mut self, tolerance: f64) -> WeldReport {
+        let vertices = self.to_points();
+        let mut boundary_vertex_ids: Vec<usize> = self.get_edges_with_missing_neighbour().iter()
+            .flat_map(|edge| [edge.start, edge.end])
+            .collect::<HashSet<usize>>()
+            .into_iter()
+            .collect();
+        boundary_vertex_ids.sort();
+
+        let boundary_vertices: Vec<Point> = boundary_vertex_ids.iter().map(|vertex_id| vertices[*vertex_id]).collect();
+        let mut duplicate_vertices_info: Vec<(usize, bool)> = (0..vertices.len()).map(|vertex_id| (vertex_id, false)).collect();
+        for (i, (first_occurrence, is_duplicate)) in Point::scan_for_duplicates_with_tolerance_info(&boundary_vertices, tolerance).into_iter().enumerate() {
+            duplicate_vertices_info[boundary_vertex_ids[i]] = (boundary_vertex_ids[first_occurrence], is_duplicate);
+        }
+
+        let (stitched, report) = self.get_with_welded_duplicates(&vertices, duplicate_vertices_info, tolerance, usize::MAX);
+        let id = self.id;
+        let metadata = std::mem::take(&mut self.metadata);
+        *self = stitched;
+        self.id = id;
+        self.metadata = metadata;
+
+        report
+    }
+
+    /// Connects 2 loops of vertices, e.g. boundary loops (see
+    /// [Mesh::get_boundary_loops_vertex_ids]), with a band of new faces, and returns ids of
+    /// these faces.
+    ///
+    /// Loops are given as ids of their vertices. If a loop goes against the direction of its
+    /// boundary edges, it's reversed, so the band's winding is consistent with the faces
+    /// around. Loops can have different numbers of vertices: the band starts at the vertex of
+    /// `loop_b` closest to the first vertex of `loop_a`, and each next triangle takes the
+    /// shorter diagonal.
+    ///
+    /// No vertices are added. New faces get per face data (`face_groups` and face `attributes`)
+    /// of the face next to the boundary edge of `loop_a` they start from. Per corner `uvs` are
+    /// removed, as they have no values for the new faces.
+    ///
+    /// Returns an error if any loop has less than 3 vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // 2 squares facing each other
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0,
+    ///          0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0, 1.0, 1.0],
+    ///     vec![0, 2, 1, 0, 3, 2, 4, 5, 6, 4, 6, 7]);
+    ///
+    /// let actual = input.bridge_loops(&[0, 1, 2, 3], &[4, 5, 6, 7]);
+    ///
+    /// assert_eq!(actual, Ok((4..12).collect::<Vec<usize>>()));
+    /// assert!(input.is_watertight());
+    /// assert!((input.get_volume().unwrap() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn bridge_loops(&mut self, loop_a: &[usize], loop_b: &[usize]) -> Result<Vec<usize>, String> {
+        if loop_a.len() < 3 || loop_b.len() < 3 {
+            return Err("Each loop should have at least 3 vertices".to_string());
+        }
+
+        let points = self.to_points();
+        let loop_a = self.get_loop_along_boundary_edges(loop_a);
+        // Going backwards along the second loop, so both loops go the same way around the band
+        let mut loop_b: Vec<usize> = self.get_loop_along_boundary_edges(loop_b).into_iter().rev().collect();
+        let closest = (0..loop_b.len())
+            .min_by(|a, b| points[loop_a[0]].get_distance_to_point(&points[loop_b[*a]]).total_cmp(&points[loop_a[0]].get_distance_to_point(&points[loop_b[*b]])))
+            .unwrap();
+        loop_b.rotate_left(closest);
+
+        let first_face_id = self.get_number_of_faces();
+        let (length_a, length_b) = (loop_a.len(), loop_b.len());
+        let (mut i, mut j) = (0, 0);
+        let mut source_face_ids = Vec::with_capacity(length_a + length_b);
+        while i < length_a || j < length_b {
+            let (a, next_a) = (loop_a[i % length_a], loop_a[(i + 1) % length_a]);
+            source_face_ids.push(self.get_face_id_with_edge(a, next_a, first_face_id));
+            let (b, next_b) = (loop_b[j % length_b], loop_b[(j + 1) % length_b]);
+            let advance_a = j == length_b || (i < length_a &&
+                points[next_a].get_distance_to_point(&points[b]) <= points[a].get_distance_to_point(&points[next_b]));
+            if advance_a {
+                self.indices.extend_from_slice(&[next_a, a, b]);
+                i += 1;
+            } else {
+                self.indices.extend_from_slice(&[b, next_b, a]);
+                j += 1;
+            }
+        }
+
+        // Faces next to the loop, or any face if the loop doesn't follow boundary edges
+        let fallback_face_id = source_face_ids.iter().flatten().next().copied().or((first_face_id > 0).then_some(0));
+        match fallback_face_id {
+            Some(fallback_face_id) => self.extend_face_data(&source_face_ids.iter().map(|face_id| face_id.unwrap_or(fallback_face_id)).collect::<Vec<_>>()),
+            None => {
+                self.face_groups = None;
+                self.attributes.face.clear();
+            },
+        }
+        if let Some(UvChannel::PerCorner(_)) = self.uvs {
+            self.uvs = None;
+        }
+        self.invalidate_cache();
+
+        Ok((first_face_id..self.get_number_of_faces()).collect())
+    }
+
+    /// Gets the id of the face (among the first `number_of_faces` ones) having the edge between
+    /// the given vertices, in any direction.
+    fn get_face_id_with_edge(&self, start: usize, end: usize, number_of_faces: usize) -> Option<usize> {
+        self.indices[..number_of_faces * 3].chunks(3)
+            .position(|face| (0..3).any(|i| (face[i] == start && face[(i + 1) % 3] == end) || (face[i] == end && face[(i + 1) % 3] == start)))
+    }
+
+    fn get_loop_along_boundary_edges(&self, vertex_loop: &[usize]) -> Vec<usize> {
+        if self.has_directed_edge(vertex_loop[1], vertex_loop[0]) {
+            vertex_loop.iter().rev().copied().collect()
+        } else {
+            vertex_loop.to_vec()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use crate::mesh_attributes::AttributeValues;
+    use crate::point::Point;
+    use crate::polyline::Polyline;
+    use crate::ray::Ray;
+    use crate::vector::Vector;
+    use super::*;
+
+    fn get_tube(bottom: f64, top: f64, segments: usize) -> Mesh {
+        let profile = Polyline::new(vec![Point::new(1.0, 0.0, bottom), Point::new(1.0, 0.0, top)]);
+        let axis = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+
+        Mesh::revolve(&profile, &axis, 2.0 * PI, segments)
+    }
+
+    #[test]
+    fn test_stitch_boundaries_keeps_thin_walls() {
+        // Closed flat box 0.001 thick, so it has no boundary
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.001],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]);
+        let mut actual = input.clone();
+
+        let report = actual.stitch_boundaries(0.01);
+
+        assert_eq!(report.number_of_merged_vertices, 0);
+        assert_eq!(actual.coordinates, input.coordinates);
+        assert_eq!(input.get_with_welded_vertices(0.01).get_number_of_vertices(), 3);
+    }
+
+    #[test]
+    fn test_stitch_boundaries_joined_tubes() {
+        let mut input = Mesh::join(&vec![get_tube(0.0, 1.0, 12), get_tube(1.0, 2.0, 12)]);
+
+        input.stitch_boundaries(0.0001);
+
+        assert_eq!(input.get_number_of_vertices(), 36);
+        assert_eq!(input.get_boundary_loops_vertex_ids().len(), 2);
+        assert!(input.get_non_manifold_vertices().is_empty());
+    }
+
+    #[test]
+    fn test_bridge_loops_different_number_of_vertices() {
+        let mut input = Mesh::join(&vec![get_tube(0.0, 1.0, 12), get_tube(2.0, 3.0, 8)]);
+        let loops = input.get_boundary_loops_vertex_ids();
+        let is_at_height = |vertex_loop: &Vec<usize>, z: f64| input.coordinates[vertex_loop[0] * 3 + 2] == z;
+        let loop_a = loops.iter().find(|vertex_loop| is_at_height(vertex_loop, 1.0)).unwrap().clone();
+        let loop_b = loops.iter().find(|vertex_loop| is_at_height(vertex_loop, 2.0)).unwrap().clone();
+
+        let actual = input.bridge_loops(&loop_a, &loop_b).unwrap();
+
+        assert_eq!(actual.len(), 20);
+        assert_eq!(input.get_boundary_loops_vertex_ids().len(), 2);
+        assert!(input.get_non_manifold_vertices().is_empty());
+        // Winding is consistent, so no edge is used twice in the same direction
+        let directed_edges: HashSet<(usize, usize)> = input.indices.chunks(3).flat_map(|face| [(face[0], face[1]), (face[1], face[2]), (face[2], face[0])]).collect();
+        assert_eq!(directed_edges.len(), input.indices.len());
+    }
+
+    #[test]
+    fn test_bridge_loops_extends_face_data() {
+        let mut input = Mesh::join_with_groups(&[get_tube(0.0, 1.0, 6), get_tube(2.0, 3.0, 6)]);
+        let number_of_faces = input.get_number_of_faces();
+        input.attributes.face.insert(String::from("part"), AttributeValues::U32(input.face_groups.clone().unwrap().iter().map(|group| *group as u32).collect()));
+        let loops = input.get_boundary_loops_vertex_ids();
+        let is_at_height = |vertex_loop: &Vec<usize>, z: f64| input.coordinates[vertex_loop[0] * 3 + 2] == z;
+        let loop_a = loops.iter().find(|vertex_loop| is_at_height(vertex_loop, 1.0)).unwrap().clone();
+        let loop_b = loops.iter().find(|vertex_loop| is_at_height(vertex_loop, 2.0)).unwrap().clone();
+        let expected_old_groups = input.face_groups.clone().unwrap();
+
+        let actual = input.bridge_loops(&loop_a, &loop_b).unwrap();
+
+        let face_groups = input.face_groups.unwrap();
+        assert_eq!(face_groups[..number_of_faces], expected_old_groups);
+        assert!(actual.iter().all(|face_id| face_groups[*face_id] == 0));
+        assert_eq!(input.attributes.face["part"].len(), input.indices.len() / 3);
+    }
+
+    #[test]
+    fn test_bridge_loops_too_short_loop_error() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        let actual = input.bridge_loops(&[0, 1, 2], &[0, 1]);
+
+        assert_eq!(actual, Err("Each loop should have at least 3 vertices".to_string()));
+        assert_eq!(input.get_number_of_faces(), 1);
+    }
+}
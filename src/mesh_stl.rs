@@ -0,0 +1,252 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::triangle::Triangle;
+use crate::vertex_welder::VertexWelder;
+
+/// Size of the binary STL header, followed by the number of triangles.
+const STL_HEADER_SIZE: usize = 80;
+/// Size of a single binary STL triangle record: normal, 3 vertices and the attribute.
+const STL_TRIANGLE_SIZE: usize = 50;
+/// How many bytes are checked to tell ASCII files from binary ones.
+const STL_DETECTION_SIZE: usize = 512;
+
+impl Mesh {
+    /// Reads the .stl file, binary or ASCII, welding vertices on the fly.
+    ///
+    /// See [Mesh::from_stl_reader].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::from_stl("scan.stl", 0.0001).unwrap();
+    /// ```
+    pub fn from_stl(path: &str, weld_tolerance: f64) -> Result<Mesh, String> {
+        let file = File::open(path).map_err(|e| format!("Couldn't read the .stl file: {}", e))?;
+        Self::from_stl_reader(file, weld_tolerance)
+    }
+
+    /// Reads the .stl content, binary or ASCII, from the reader, welding vertices on the fly.
+    ///
+    /// The content is streamed: triangles are welded as they're read, so the memory usage depends
+    /// on the size of the welded [Mesh] only, not on the size of the file. Vertices are merged
+    /// with the same rule as [Mesh::get_with_welded_vertices], `weld_tolerance` 0.0 merges only
+    /// identical vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let content = "solid square
+    ///   facet normal 0 0 1
+    ///     outer loop
+    ///       vertex 0 0 0
+    ///       vertex 1 0 0
+    ///       vertex 1 1 0
+    ///     endloop
+    ///   endfacet
+    ///   facet normal 0 0 1
+    ///     outer loop
+    ///       vertex 0 0 0
+    ///       vertex 1 1 0
+    ///       vertex 0 1 0
+    ///     endloop
+    ///   endfacet
+    /// endsolid square
+    /// ";
+    ///
+    /// let actual = Mesh::from_stl_reader(content.as_bytes(), 0.0).unwrap();
+    ///
+    /// let expected = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn from_stl_reader<R: Read>(reader: R, weld_tolerance: f64) -> Result<Mesh, String> {
+        let mut welder = VertexWelder::new(weld_tolerance);
+        Self::read_stl(reader, |triangle| welder.add_face(&triangle.first_point, &triangle.second_point, &triangle.third_point))?;
+        Ok(welder.into_mesh())
+    }
+
+    /// Streams triangles of the .stl content, binary or ASCII, from the reader into the `sink`,
+    /// and returns the number of triangles read.
+    ///
+    /// Only a small, fixed-size buffer is kept in memory, so it can process files of any size,
+    /// e.g. to compute statistics or to feed a custom welding/decimation pipeline.
+    ///
+    /// Binary files are recognized by their content, even if their header starts with `solid`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// // Binary STL with 1 triangle
+    /// let mut content = vec![0_u8; 80];
+    /// content.extend_from_slice(&1_u32.to_le_bytes());
+    /// for value in [0.0_f32, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0, 0.0] {
+    ///     content.extend_from_slice(&value.to_le_bytes());
+    /// }
+    /// content.extend_from_slice(&[0, 0]);
+    ///
+    /// let mut area = 0.0;
+    /// let actual = Mesh::read_stl(content.as_slice(), |triangle| area += triangle.get_area()).unwrap();
+    ///
+    /// assert_eq!(actual, 1);
+    /// assert!((area - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn read_stl<R: Read>(reader: R, mut sink: impl FnMut(Triangle)) -> Result<usize, String> {
+        let mut reader = BufReader::new(reader);
+        let start = reader.fill_buf().map_err(|e| format!("Couldn't read the .stl content: {}", e))?;
+        let start = &start[..start.len().min(STL_DETECTION_SIZE)];
+
+        let is_ascii = start.starts_with(b"solid")
+            && start.iter().all(|byte| byte.is_ascii() && *byte != 0)
+            && (start.windows(5).any(|window| window == b"facet") || start.windows(8).any(|window| window == b"endsolid"));
+
+        if is_ascii {
+            read_ascii_stl(reader, &mut sink)
+        } else {
+            read_binary_stl(reader, &mut sink)
+        }
+    }
+}
+
+fn read_binary_stl<R: BufRead>(mut reader: R, sink: &mut impl FnMut(Triangle)) -> Result<usize, String> {
+    let mut header = [0_u8; STL_HEADER_SIZE + 4];
+    reader.read_exact(&mut header).map_err(|_| "The .stl content is too short for the binary header".to_string())?;
+    let number_of_triangles = u32::from_le_bytes([header[80], header[81], header[82], header[83]]) as usize;
+
+    let mut record = [0_u8; STL_TRIANGLE_SIZE];
+    for triangle_id in 0..number_of_triangles {
+        reader.read_exact(&mut record).map_err(|_| format!("The binary .stl content ends after {} of {} triangles", triangle_id, number_of_triangles))?;
+        let value = |i: usize| {
+            let offset = 12 + i * 4;
+            f32::from_le_bytes([record[offset], record[offset + 1], record[offset + 2], record[offset + 3]]) as f64
+        };
+        sink(Triangle::new(
+            Point::new(value(0), value(1), value(2)),
+            Point::new(value(3), value(4), value(5)),
+            Point::new(value(6), value(7), value(8)),
+        ));
+    }
+
+    Ok(number_of_triangles)
+}
+
+fn read_ascii_stl<R: BufRead>(reader: R, sink: &mut impl FnMut(Triangle)) -> Result<usize, String> {
+    let mut number_of_triangles = 0;
+    let mut vertices: Vec<Point> = Vec::with_capacity(3);
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| format!("Couldn't read the .stl content: {}", e))?;
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("vertex") => {
+                let values = words
+                    .map(|word| word.parse::<f64>().map_err(|e| format!("Invalid vertex at line {}: {}", i + 1, e)))
+                    .collect::<Result<Vec<f64>, String>>()?;
+                if values.len() != 3 {
+                    return Err(format!("Vertex at line {} should have 3 coordinates", i + 1));
+                }
+                if vertices.len() == 3 {
+                    return Err(format!("Facet at line {} has more than 3 vertices", i + 1));
+                }
+                vertices.push(Point::new(values[0], values[1], values[2]));
+            },
+            Some("endloop") => {
+                if vertices.len() != 3 {
+                    return Err(format!("Facet ending at line {} should have 3 vertices", i + 1));
+                }
+                sink(Triangle::new(vertices[0], vertices[1], vertices[2]));
+                vertices.clear();
+                number_of_triangles += 1;
+            },
+            _ => {},
+        }
+    }
+
+    Ok(number_of_triangles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_binary_stl(header: &[u8], triangles: &[[f32; 9]]) -> Vec<u8> {
+        let mut content = vec![0_u8; 80];
+        content[..header.len()].copy_from_slice(header);
+        content.extend_from_slice(&(triangles.len() as u32).to_le_bytes());
+        for triangle in triangles {
+            content.extend_from_slice(&[0_u8; 12]);
+            for value in triangle {
+                content.extend_from_slice(&value.to_le_bytes());
+            }
+            content.extend_from_slice(&[0, 0]);
+        }
+        content
+    }
+
+    #[test]
+    fn test_from_stl_reader_binary_with_solid_header_welded() {
+        let content = create_binary_stl(b"solid but binary", &[
+            [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+        ]);
+
+        let actual = Mesh::from_stl_reader(content.as_slice(), 0.0).unwrap();
+
+        let expected = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_read_stl_binary_truncated() {
+        let mut content = create_binary_stl(b"", &[[0.0; 9], [0.0; 9]]);
+        content.truncate(content.len() - 10);
+
+        let actual = Mesh::read_stl(content.as_slice(), |_| {});
+
+        assert_eq!(actual, Err("The binary .stl content ends after 1 of 2 triangles".to_string()));
+    }
+
+    #[test]
+    fn test_read_stl_empty() {
+        let actual = Mesh::read_stl(&[][..], |_| {});
+
+        assert_eq!(actual, Err("The .stl content is too short for the binary header".to_string()));
+    }
+
+    #[test]
+    fn test_read_stl_ascii_invalid_vertex() {
+        let content = "solid a\nfacet normal 0 0 1\nouter loop\nvertex 0 0 x\n";
+
+        let actual = Mesh::read_stl(content.as_bytes(), |_| {});
+
+        assert!(actual.unwrap_err().starts_with("Invalid vertex at line 4"));
+    }
+
+    #[test]
+    fn test_read_stl_ascii_too_few_vertices() {
+        let content = "solid a\nfacet normal 0 0 1\nouter loop\nvertex 0 0 0\nvertex 1 0 0\nendloop\n";
+
+        let actual = Mesh::read_stl(content.as_bytes(), |_| {});
+
+        assert_eq!(actual, Err("Facet ending at line 6 should have 3 vertices".to_string()));
+    }
+
+    #[test]
+    fn test_read_stl_streams_many_triangles_in_order() {
+        let triangles: Vec<[f32; 9]> = (0..2000).map(|i| [i as f32, 0.0, 0.0, i as f32 + 1.0, 0.0, 0.0, i as f32, 1.0, 0.0]).collect();
+        let content = create_binary_stl(b"", &triangles);
+
+        let mut first_x: Vec<f64> = Vec::new();
+        let actual = Mesh::read_stl(content.as_slice(), |triangle| first_x.push(triangle.first_point.x)).unwrap();
+
+        assert_eq!(actual, 2000);
+        assert_eq!(first_x, (0..2000).map(|i| i as f64).collect::<Vec<f64>>());
+        assert_eq!(Mesh::from_stl_reader(content.as_slice(), 0.0).unwrap().get_number_of_vertices(), 2001 + 2000);
+    }
+}
@@ -1,8 +1,11 @@
 use std::ops;
 use crate::local_coordinate_system::LocalCoordinateSystem;
 use crate::mesh::Mesh;
+use crate::plane::Plane;
 use crate::point::Point;
 use crate::quaternion::Quaternion;
+use crate::transform::Transform;
+use crate::units::Units;
 use crate::vector::Vector;
 
 impl ops::Add<Vector> for Mesh {
@@ -69,18 +72,15 @@ impl ops::Add<Vector> for Mesh {
     /// assert_eq!(result.eq(&expected), true);
     /// ```
     fn add(self, vector: Vector) -> Mesh {
-        let number_of_coordinates = self.coordinates.len();
-        let mut moved_coordinates = Vec::with_capacity(number_of_coordinates);
-
-        let mut counter: usize = 0;
-        while counter < number_of_coordinates {
-            moved_coordinates.push(self.coordinates[counter] + vector.x);
-            moved_coordinates.push(self.coordinates[counter+1] + vector.y);
-            moved_coordinates.push(self.coordinates[counter+2] + vector.z);
-            counter += 3;
+        let mut moved = self;
+        for vertex in moved.coordinates.chunks_mut(3) {
+            vertex[0] += vector.x;
+            vertex[1] += vector.y;
+            vertex[2] += vector.z;
         }
-        
-        Mesh::new_with_id(self.id, moved_coordinates, self.indices.clone())
+        moved.invalidate_cache();
+
+        moved
     }
 }
 
@@ -148,18 +148,15 @@ impl ops::Sub<Vector> for Mesh {
     /// assert_eq!(result.eq(&expected), true);
     /// ```
     fn sub(self, vector: Vector) -> Mesh {
-        let number_of_coordinates = self.coordinates.len();
-        let mut moved_coordinates = Vec::with_capacity(number_of_coordinates);
-
-        let mut counter: usize = 0;
-        while counter < number_of_coordinates {
-            moved_coordinates.push(self.coordinates[counter] - vector.x);
-            moved_coordinates.push(self.coordinates[counter+1] - vector.y);
-            moved_coordinates.push(self.coordinates[counter+2] - vector.z);
-            counter += 3;
+        let mut moved = self;
+        for vertex in moved.coordinates.chunks_mut(3) {
+            vertex[0] -= vector.x;
+            vertex[1] -= vector.y;
+            vertex[2] -= vector.z;
         }
+        moved.invalidate_cache();
 
-        Mesh::new_with_id(self.id, moved_coordinates, self.indices.clone())
+        moved
     }
 }
 
@@ -201,16 +198,7 @@ impl Mesh {
     /// 
     /// ```
     pub fn get_in_local_coordinate_system(&self, local_coordinate_system: &LocalCoordinateSystem) -> Mesh {
-        let points = self.to_points();
-        let transformed_points = Point::get_all_in_local_coordinate_system(&points, local_coordinate_system);
-        let mut transformed_coordinates = Vec::with_capacity(self.coordinates.len());
-        for point in transformed_points {
-            transformed_coordinates.push(point.x);
-            transformed_coordinates.push(point.y);
-            transformed_coordinates.push(point.z);
-        }
-        
-        Mesh::new_with_id(self.id, transformed_coordinates, self.indices.clone())
+        self.get_rigidly_transformed(|point| point.get_in_local_coordinate_system(local_coordinate_system))
     }
 
     /// Creates a new [Mesh], but with coordinates in the Global Coordinate System.
@@ -253,16 +241,7 @@ impl Mesh {
     ///
     /// ```
     pub fn get_in_global_coordinate_system(&self, local_coordinate_system: &LocalCoordinateSystem) -> Mesh {
-        let points = self.to_points();
-        let transformed_points = Point::get_all_in_global_coordinate_system(&points, local_coordinate_system);
-        let mut transformed_coordinates = Vec::with_capacity(self.coordinates.len());
-        for point in transformed_points {
-            transformed_coordinates.push(point.x);
-            transformed_coordinates.push(point.y);
-            transformed_coordinates.push(point.z);
-        }
-
-        Mesh::new_with_id(self.id, transformed_coordinates, self.indices.clone())
+        self.get_rigidly_transformed(|point| point.get_in_global_coordinate_system(local_coordinate_system))
     }
 
     /// Returns the rotated [Mesh] using given [Quaternion].
@@ -290,22 +269,253 @@ impl Mesh {
     /// assert!(expected.eq_with_tolerance(&actual, 0.001));
     /// ```
     pub fn get_rotated_by_quaternion(&self, quaternion: Quaternion) -> Mesh {
-        let mut rotated_coordinates = Vec::with_capacity(self.coordinates.len());
-        let vertices = self.to_points();
-
-        for vertex in vertices {
-            let rotated_vertex = vertex.get_rotated_by_quaternion(quaternion);
-            rotated_coordinates.push(rotated_vertex.x);
-            rotated_coordinates.push(rotated_vertex.y);
-            rotated_coordinates.push(rotated_vertex.z);
+        self.get_rigidly_transformed(|point| point.get_rotated_by_quaternion(quaternion))
+    }
+
+    /// Creates a new [Mesh] with each vertex moved by the rigid `transform_point`, and with
+    /// [Mesh::vertex_normals] rotated the same way. Other data is copied.
+    fn get_rigidly_transformed(&self, transform_point: impl Fn(&Point) -> Point) -> Mesh {
+        let mut transformed = self.clone();
+        for vertex in transformed.coordinates.chunks_mut(3) {
+            let transformed_vertex = transform_point(&Point::new(vertex[0], vertex[1], vertex[2]));
+            vertex.copy_from_slice(&[transformed_vertex.x, transformed_vertex.y, transformed_vertex.z]);
         }
-        
-        Mesh::new_with_id(self.id, rotated_coordinates, self.indices.clone())
+        if let Some(vertex_normals) = &mut transformed.vertex_normals {
+            let transformed_origin = transform_point(&Point::new(0.0, 0.0, 0.0));
+            for vertex_normal in vertex_normals.chunks_mut(3) {
+                let transformed_normal = Vector::from_2_points(&transformed_origin, &transform_point(&Point::new(vertex_normal[0], vertex_normal[1], vertex_normal[2])));
+                vertex_normal.copy_from_slice(&[transformed_normal.x, transformed_normal.y, transformed_normal.z]);
+            }
+        }
+
+        transformed
+    }
+
+    /// Re-bases all given [Mesh]es, so their coordinates become relative to the `new_origin`.
+    ///
+    /// All the geometry is translated by the [Vector] from `new_origin` to the global origin
+    /// (0.0, 0.0, 0.0). It's useful for georeferenced models with huge coordinates, which would
+    /// lose precision when converted to `f32` (e.g. during export for rendering).
+    ///
+    /// Returned offset is the [Vector] from the global origin to the `new_origin`, so the
+    /// original location can be restored by adding it back.
+    ///
+    /// It mutates given Meshes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mut meshes = vec![
+    ///     Mesh::new(vec![500000.0, 6000000.0, 100.0, 500010.0, 6000000.0, 100.0, 500010.0, 6000010.0, 100.0], vec![0, 1, 2]),
+    ///     Mesh::new(vec![500020.0, 6000000.0, 100.0, 500030.0, 6000000.0, 100.0, 500030.0, 6000010.0, 105.0], vec![0, 1, 2]),
+    /// ];
+    ///
+    /// let actual = Mesh::rebase(&mut meshes, &Point::new(500000.0, 6000000.0, 100.0));
+    ///
+    /// assert_eq!(actual, Vector::new(500000.0, 6000000.0, 100.0));
+    /// assert_eq!(meshes[0].coordinates, vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0]);
+    /// assert_eq!(meshes[1].coordinates, vec![20.0, 0.0, 0.0, 30.0, 0.0, 0.0, 30.0, 10.0, 5.0]);
+    /// ```
+    pub fn rebase(meshes: &mut [Mesh], new_origin: &Point) -> Vector {
+        for mesh in meshes.iter_mut() {
+            for vertex in mesh.coordinates.chunks_mut(3) {
+                vertex[0] -= new_origin.x;
+                vertex[1] -= new_origin.y;
+                vertex[2] -= new_origin.z;
+            }
+            mesh.invalidate_cache();
+        }
+
+        new_origin.to_vector()
+    }
+
+    /// Converts the [Mesh] coordinates from `from` [Units] to `to` [Units].
+    ///
+    /// All the coordinates are scaled around the global origin (0.0, 0.0, 0.0).
+    ///
+    /// It mutates the Mesh.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::units::Units;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.5, 0.0, 0.0, 1.5, -2.0, 0.25], vec![0, 1, 2]);
+    ///
+    /// input.convert_units(Units::Meters, Units::Millimeters);
+    ///
+    /// assert_eq!(input.coordinates, vec![0.0, 0.0, 0.0, 1500.0, 0.0, 0.0, 1500.0, -2000.0, 250.0]);
+    /// ```
+    pub fn convert_units(&mut self, from: Units, to: Units) {
+        let factor = from.get_conversion_factor(to);
+        if factor == 1.0 {
+            return;
+        }
+
+        for coordinate in self.coordinates.iter_mut() {
+            *coordinate *= factor;
+        }
+        self.invalidate_cache();
+    }
+
+    /// Returns a new [Mesh] with coordinates converted from `from` [Units] to `to` [Units].
+    ///
+    /// All the coordinates are scaled around the global origin (0.0, 0.0, 0.0).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::units::Units;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 12.0, 0.0, 0.0, 12.0, 24.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let actual = input.get_with_converted_units(Units::Inches, Units::Feet);
+    ///
+    /// assert_eq!(actual.coordinates, vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 2.0, 0.0]);
+    /// ```
+    pub fn get_with_converted_units(&self, from: Units, to: Units) -> Mesh {
+        let mut converted = self.clone();
+        converted.convert_units(from, to);
+        converted
+    }
+
+    /// Returns a new [Mesh] mirrored across the [Plane].
+    ///
+    /// Reflection turns the winding of faces inside out, so faces are flipped back (together
+    /// with per corner `uvs`), and normals of the result still point outside. `vertex_normals`
+    /// are mirrored as well, other data is copied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Mesh::new(vec![1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let plane = Plane::new(Point::new(0.0, 0.0, 0.0), Vector::new(2.0, 0.0, 0.0));
+    ///
+    /// let actual = input.get_mirrored(&plane);
+    ///
+    /// assert_eq!(actual.coordinates, vec![-1.0, 0.0, 0.0, -2.0, 0.0, 0.0, -1.0, 1.0, 0.0]);
+    /// assert_eq!(actual.indices, vec![2, 1, 0]);
+    /// assert_eq!(actual.get_face_normal_vectors_unitized(), vec![Vector::new(0.0, 0.0, 1.0)]);
+    /// ```
+    pub fn get_mirrored(&self, plane: &Plane) -> Mesh {
+        let normal = plane.normal.get_unitized();
+        let mut mirrored = self.clone();
+        for (i, vertex) in self.to_points().iter().enumerate() {
+            let reflected = *vertex + normal * (-2.0 * normal.get_dot_product(&Vector::from_2_points(&plane.origin, vertex)));
+            mirrored.coordinates[i * 3] = reflected.x;
+            mirrored.coordinates[i * 3 + 1] = reflected.y;
+            mirrored.coordinates[i * 3 + 2] = reflected.z;
+        }
+        if let Some(vertex_normals) = &mut mirrored.vertex_normals {
+            for vertex_normal in vertex_normals.chunks_mut(3) {
+                let projection = 2.0 * (vertex_normal[0] * normal.x + vertex_normal[1] * normal.y + vertex_normal[2] * normal.z);
+                vertex_normal[0] -= projection * normal.x;
+                vertex_normal[1] -= projection * normal.y;
+                vertex_normal[2] -= projection * normal.z;
+            }
+        }
+        mirrored.flip_all_faces();
+
+        mirrored
+    }
+
+    /// Returns a new [Mesh] transformed by the given [Transform], e.g. non-uniformly scaled,
+    /// sheared, or moved between coordinate systems with
+    /// [Transform::new_in_local_coordinate_system].
+    ///
+    /// `vertex_normals` are transformed by the inverse transpose, so they stay perpendicular
+    /// to the surface, and unitized. If the [Transform] mirrors the space (negative
+    /// determinant), faces are flipped back (together with per corner `uvs`), so normals of
+    /// the result still point outside. Other data is copied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::transform::Transform;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let transform = Transform::new_translation(&Vector::new(0.0, 0.0, 1.0)) * Transform::new_scaling(3.0, 2.0, 1.0);
+    ///
+    /// let actual = input.get_transformed(&transform);
+    ///
+    /// assert_eq!(actual.coordinates, vec![0.0, 0.0, 1.0, 3.0, 0.0, 1.0, 0.0, 2.0, 1.0]);
+    /// assert_eq!(actual.indices, vec![0, 1, 2]);
+    /// ```
+    pub fn get_transformed(&self, transform: &Transform) -> Mesh {
+        let mut transformed = self.clone();
+        for (i, vertex) in self.to_points().iter().enumerate() {
+            let transformed_vertex = vertex.get_transformed(transform);
+            transformed.coordinates[i * 3] = transformed_vertex.x;
+            transformed.coordinates[i * 3 + 1] = transformed_vertex.y;
+            transformed.coordinates[i * 3 + 2] = transformed_vertex.z;
+        }
+        if let (Some(vertex_normals), Some(normal_matrix)) = (&mut transformed.vertex_normals, transform.get_normal_matrix()) {
+            for vertex_normal in vertex_normals.chunks_mut(3) {
+                let mut normal = [0.0; 3];
+                for (row, value) in normal.iter_mut().enumerate() {
+                    *value = normal_matrix[row][0] * vertex_normal[0] + normal_matrix[row][1] * vertex_normal[1] + normal_matrix[row][2] * vertex_normal[2];
+                }
+                let length = (normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]).sqrt();
+                if length != 0.0 {
+                    vertex_normal.copy_from_slice(&normal.map(|value| value / length));
+                }
+            }
+        }
+        if transform.get_determinant() < 0.0 {
+            transformed.flip_all_faces();
+        }
+
+        transformed
+    }
+
+    /// Returns copies of the [Mesh] placed in each of the [LocalCoordinateSystem]s, treating
+    /// its coordinates as local ones, e.g. for arrays created with
+    /// [LocalCoordinateSystem::get_linear_array], [LocalCoordinateSystem::get_rectangular_array]
+    /// or [LocalCoordinateSystem::get_polar_array].
+    ///
+    /// It's the opposite of [Mesh::deduplicate]. All the data (normals, uvs) is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let placements = LocalCoordinateSystem::global().get_linear_array(&Vector::new(0.0, 0.0, 3.0), 2);
+    ///
+    /// let actual = input.get_instances(&placements);
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert_eq!(actual[0].coordinates, input.coordinates);
+    /// assert_eq!(actual[1].coordinates, vec![0.0, 0.0, 3.0, 1.0, 0.0, 3.0, 0.0, 1.0, 3.0]);
+    /// ```
+    pub fn get_instances(&self, local_coordinate_systems: &[LocalCoordinateSystem]) -> Vec<Mesh> {
+        local_coordinate_systems.iter()
+            .map(|local_coordinate_system| self.get_transformed(&Transform::new_in_local_coordinate_system(local_coordinate_system)))
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::f64::consts::PI;
+    use crate::ray::Ray;
+    use crate::uv_channel::UvChannel;
     use crate::vector::Vector;
     use super::*;
     
@@ -487,4 +697,208 @@ mod tests {
         
         assert!(expected.eq_with_tolerance(&actual, 0.001));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_rebase_restores_with_offset() {
+        let input = vec![
+            Mesh::new_with_id(Some(1), vec![712345.678, 5432109.876, 45.5, 712355.678, 5432109.876, 45.5, 712355.678, 5432119.876, 47.25], vec![0, 1, 2]),
+            Mesh::new_with_id(Some(2), vec![712300.0, 5432100.0, 40.0, 712310.0, 5432100.0, 40.0, 712310.0, 5432110.0, 40.0], vec![0, 2, 1]),
+        ];
+        let mut actual = input.clone();
+
+        let offset = Mesh::rebase(&mut actual, &Point::new(712300.0, 5432100.0, 40.0));
+
+        assert_eq!(actual[0].id, Some(1));
+        assert_eq!(actual[1].indices, vec![0, 2, 1]);
+        assert!(actual[0].get_bounding_box().max_x < 100.0);
+        for i in 0..input.len() {
+            let restored = actual[i].clone() + offset;
+            assert!(input[i].eq_with_tolerance(&restored, 0.000001));
+        }
+    }
+
+    #[test]
+    fn test_rebase_empty() {
+        let mut meshes: Vec<Mesh> = Vec::new();
+
+        let actual = Mesh::rebase(&mut meshes, &Point::new(1.0, 2.0, 3.0));
+
+        assert_eq!(actual, Vector::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_convert_units_roundtrip() {
+        let input = Mesh::new_with_id(Some(2), vec![-2.0, 1.0, 0.0, 8.0, 1.0, 0.0, 8.0, 11.0, 0.0, 3.0, 6.0, 4.0], vec![0, 1, 2, 0, 2, 3]);
+
+        let mut actual = input.clone();
+        actual.convert_units(Units::Feet, Units::Centimeters);
+        assert!(actual.coordinates.iter().zip(input.coordinates.iter()).all(|(a, b)| (a - b * 30.48).abs() < 1e-9));
+        actual.convert_units(Units::Centimeters, Units::Feet);
+
+        assert_eq!(actual.id, Some(2));
+        assert_eq!(actual.indices, input.indices);
+        assert!(actual.eq_with_tolerance(&input, 1e-9));
+    }
+
+    #[test]
+    fn test_get_with_converted_units_same_units() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+
+        let actual = input.get_with_converted_units(Units::Millimeters, Units::Millimeters);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn test_get_mirrored_tetrahedron_stays_outward() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]);
+        input.vertex_normals = Some(vec![0.0, 0.0, -1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        input.uvs = Some(UvChannel::PerCorner((0..24).map(|i| i as f64).collect()));
+        let plane = Plane::new(Point::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 1.0));
+
+        let actual = input.get_mirrored(&plane);
+
+        assert_eq!(actual.to_points()[3], Point::new(0.0, 0.0, 0.0));
+        assert_eq!(actual.to_points()[0], Point::new(0.0, 0.0, 2.0));
+        assert!((actual.get_volume().unwrap() - input.get_volume().unwrap()).abs() < 1e-12);
+        assert_eq!(actual.vertex_normals, Some(vec![0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, -1.0]));
+        for corner_id in 0..12 {
+            let source_corner_id = corner_id / 3 * 3 + 2 - corner_id % 3;
+            assert_eq!(actual.indices[corner_id], input.indices[source_corner_id]);
+            assert_eq!(actual.get_uv_for_corner(corner_id), input.get_uv_for_corner(source_corner_id));
+        }
+    }
+
+    #[test]
+    fn test_get_mirrored_twice_is_identity() {
+        let input = Mesh::new(vec![0.3, 1.2, -0.7, 2.0, 0.5, 0.1, -1.0, 2.0, 3.0], vec![0, 1, 2]);
+        let plane = Plane::new(Point::new(1.0, -2.0, 0.5), Vector::new(1.0, 2.0, -2.0));
+
+        let actual = input.get_mirrored(&plane).get_mirrored(&plane);
+
+        assert!(actual.eq_with_tolerance(&input, 1e-12));
+    }
+
+    #[test]
+    fn test_get_transformed_non_uniform_scaling_keeps_normals_perpendicular() {
+        // Slanted face x + y = 1 with its normal
+        let mut input = Mesh::new(vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0], vec![0, 1, 2]);
+        let normal = 0.5_f64.sqrt();
+        input.vertex_normals = Some(vec![normal, normal, 0.0, normal, normal, 0.0, normal, normal, 0.0]);
+
+        let actual = input.get_transformed(&Transform::new_scaling(2.0, 1.0, 1.0));
+
+        let face_normal = actual.get_face_normal_vectors_unitized()[0];
+        let vertex_normals = actual.vertex_normals.unwrap();
+        let vertex_normal = Vector::new(vertex_normals[0], vertex_normals[1], vertex_normals[2]);
+        assert!(face_normal.eq_with_tolerance(&vertex_normal, 1e-12));
+        assert!((vertex_normal.get_length() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_transformed_mirroring_same_as_get_mirrored() {
+        let mut input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]);
+        input.vertex_normals = Some(vec![0.0, 0.0, -1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+        input.uvs = Some(UvChannel::PerCorner((0..24).map(|i| i as f64).collect()));
+        let plane = Plane::new(Point::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, 1.0));
+        let transform = Transform::new_translation(&Vector::new(0.0, 0.0, 2.0)) * Transform::new_scaling(1.0, 1.0, -1.0);
+
+        let actual = input.get_transformed(&transform);
+        let expected = input.get_mirrored(&plane);
+
+        assert_eq!(actual.coordinates, expected.coordinates);
+        assert_eq!(actual.indices, expected.indices);
+        assert_eq!(actual.uvs, expected.uvs);
+        assert_eq!(actual.vertex_normals, expected.vertex_normals);
+    }
+
+    #[test]
+    fn test_get_transformed_in_local_coordinate_system() {
+        let input = Mesh::new(vec![28.305465, 44.53041, 14.292343, 37.662834, -14.359948, -15.339571, -30.621039, 23.839233, 0.0], vec![0, 1, 2]);
+        let local_coordinate_system = LocalCoordinateSystem::new(
+            Point::new(-43.836955, -22.211852, 10.0),
+            Vector::new(0.721276,0.692648,0.0),
+            Vector::new(-0.290878,0.3029,0.907547)
+        );
+
+        let actual = input.get_transformed(&Transform::new_in_local_coordinate_system(&local_coordinate_system));
+        let expected = input.get_in_local_coordinate_system(&local_coordinate_system);
+
+        assert!(actual.eq_with_tolerance(&expected, 1e-9));
+    }
+
+    #[test]
+    fn test_get_instances_deduplicated_back() {
+        let input = Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 3.0],
+            vec![0, 2, 1, 0, 1, 3, 1, 2, 3, 0, 3, 2]);
+        let axis = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        let placements = LocalCoordinateSystem::global().get_polar_array(&axis, 3, PI);
+
+        let actual = input.get_instances(&placements);
+
+        assert_eq!(actual.len(), 3);
+        for instance in &actual {
+            assert!((instance.get_volume().unwrap() - input.get_volume().unwrap()).abs() < 1e-12);
+        }
+        let deduplicated = Mesh::deduplicate(actual, 1e-9);
+        assert_eq!(deduplicated.len(), 1);
+        assert_eq!(deduplicated[0].1.len(), 3);
+    }
+
+    #[test]
+    fn test_get_rotated_by_quaternion_keeps_other_data() {
+        let mut input = Mesh::new_with_id(Some(5), vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+        input.vertex_colors = Some(vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255]);
+        input.vertex_normals = Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+        input.face_groups = Some(vec![3]);
+        input.metadata.insert("name".to_string(), "panel".to_string());
+        let quaternion = Quaternion::new((PI / 4.0).sin(), 0.0, 0.0, (PI / 4.0).cos()); // 90 degrees around X
+
+        let actual = input.get_rotated_by_quaternion(quaternion);
+
+        assert_eq!(actual.id, Some(5));
+        assert_eq!(actual.uvs, input.uvs);
+        assert_eq!(actual.vertex_colors, input.vertex_colors);
+        assert_eq!(actual.face_groups, input.face_groups);
+        assert_eq!(actual.metadata, input.metadata);
+        let vertex_normals = actual.vertex_normals.unwrap();
+        assert!(Vector::new(vertex_normals[0], vertex_normals[1], vertex_normals[2]).eq_with_tolerance(&Vector::new(0.0, -1.0, 0.0), 1e-12));
+    }
+
+    #[test]
+    fn test_get_in_local_coordinate_system_round_trip_keeps_vertex_normals() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.vertex_normals = Some(vec![0.0, 0.0, 1.0, 0.0, 0.6, 0.8, 1.0, 0.0, 0.0]);
+        let local_coordinate_system = LocalCoordinateSystem::new(
+            Point::new(-43.836955, -22.211852, 10.0),
+            Vector::new(0.721276,0.692648,0.0).get_unitized(),
+            Vector::new(-0.290878,0.3029,0.907547).get_unitized()
+        );
+
+        let actual = input.get_in_local_coordinate_system(&local_coordinate_system).get_in_global_coordinate_system(&local_coordinate_system);
+
+        let actual_normals = actual.vertex_normals.unwrap();
+        let expected_normals = input.vertex_normals.unwrap();
+        assert!(actual_normals.iter().zip(expected_normals.iter()).all(|(a, b)| (a - b).abs() < 1e-4));
+    }
+
+    #[test]
+    fn test_add_vector_keeps_other_data() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        input.uvs = Some(UvChannel::PerCorner(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+        input.vertex_normals = Some(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(input.get_cached_bounding_box().max_x, 1.0);
+
+        let actual = input.clone() + Vector::new(2.0, 0.0, 0.0);
+
+        assert_eq!(actual.uvs, input.uvs);
+        assert_eq!(actual.vertex_normals, input.vertex_normals);
+        assert_eq!(actual.get_cached_bounding_box().max_x, 3.0);
+    }
+}
@@ -0,0 +1,221 @@
+use std::f64::consts::PI;
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::uv_channel::UvChannel;
+use crate::vector::Vector;
+
+impl Mesh {
+    /// Sets `uvs` (per vertex) to the planar projection onto the xy plane of the
+    /// [LocalCoordinateSystem], so u and v are local x and y coordinates divided by the `size`
+    /// (the length of a single texture tile).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::uv_channel::UvChannel;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 0.0, 2.0], vec![0, 1, 2]);
+    /// let wall = LocalCoordinateSystem::new(Point::new(0.0, 0.0, 0.0), Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// input.project_uv_planar(&wall, 2.0);
+    ///
+    /// assert_eq!(input.uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 2.0, 0.0, 0.0, 1.0])));
+    /// ```
+    pub fn project_uv_planar(&mut self, local_coordinate_system: &LocalCoordinateSystem, size: f64) {
+        let uvs = self.get_local_points(local_coordinate_system).iter().flat_map(|point| [point.x / size, point.y / size]).collect();
+
+        self.uvs = Some(UvChannel::PerVertex(uvs));
+    }
+
+    /// Sets `uvs` (per corner) to the box (triplanar) projection: each face is projected onto
+    /// the side of the box, aligned with the [LocalCoordinateSystem], which its normal faces
+    /// the most. Coordinates are divided by the `size` (the length of a single texture tile).
+    ///
+    /// Textures aren't mirrored on opposite sides, and on the vertical ones v goes up along
+    /// the local z axis, which suits walls of buildings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point2d::Point2D;
+    ///
+    /// // Face of the wall facing -y
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 3.0, 0.0, 3.0], vec![0, 1, 2]);
+    ///
+    /// input.project_uv_box(&LocalCoordinateSystem::global(), 3.0);
+    ///
+    /// assert_eq!(input.get_uv_for_corner(1), Some(Point2D::new(1.0, 0.0)));
+    /// assert_eq!(input.get_uv_for_corner(2), Some(Point2D::new(1.0, 1.0)));
+    /// ```
+    pub fn project_uv_box(&mut self, local_coordinate_system: &LocalCoordinateSystem, size: f64) {
+        let points = self.get_local_points(local_coordinate_system);
+        let mut uvs: Vec<f64> = Vec::with_capacity(self.indices.len() * 2);
+        for face in self.indices.chunks(3) {
+            let [a, b, c] = [points[face[0]], points[face[1]], points[face[2]]];
+            let normal = Vector::from_2_points(&a, &b).get_cross_product(&Vector::from_2_points(&a, &c));
+            let (x, y, z) = (normal.x.abs(), normal.y.abs(), normal.z.abs());
+            for vertex_id in face {
+                let point = points[*vertex_id];
+                let uv = if z >= x && z >= y {
+                    [point.x * normal.z.signum(), point.y]
+                } else if x >= y {
+                    [point.y * normal.x.signum(), point.z]
+                } else {
+                    [-point.x * normal.y.signum(), point.z]
+                };
+                uvs.extend_from_slice(&[uv[0] / size, uv[1] / size]);
+            }
+        }
+
+        self.uvs = Some(UvChannel::PerCorner(uvs));
+    }
+
+    /// Sets `uvs` (per corner) to the cylindrical projection around the z axis of the
+    /// [LocalCoordinateSystem]: u goes from 0.0 to 1.0 around the axis (starting from the
+    /// local x axis), and v is the local z coordinate divided by the `height`.
+    ///
+    /// Faces crossing the seam get u above 1.0 instead of going back through the whole texture.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point2d::Point2D;
+    ///
+    /// let mut input = Mesh::new(vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 2.0], vec![0, 1, 2]);
+    ///
+    /// input.project_uv_cylindrical(&LocalCoordinateSystem::global(), 2.0);
+    ///
+    /// assert_eq!(input.get_uv_for_corner(0), Some(Point2D::new(0.0, 0.0)));
+    /// assert_eq!(input.get_uv_for_corner(2), Some(Point2D::new(0.25, 1.0)));
+    /// ```
+    pub fn project_uv_cylindrical(&mut self, local_coordinate_system: &LocalCoordinateSystem, height: f64) {
+        let uvs = self.get_local_points(local_coordinate_system).iter().map(|point| [get_turns(point), point.z / height]).collect();
+
+        self.uvs = Some(UvChannel::PerCorner(self.get_corner_uvs_across_seam(uvs)));
+    }
+
+    /// Sets `uvs` (per corner) to the spherical projection around the origin of the
+    /// [LocalCoordinateSystem]: u goes from 0.0 to 1.0 around its z axis (starting from the
+    /// local x axis), and v from 0.0 at the bottom pole to 1.0 at the top one.
+    ///
+    /// Faces crossing the seam get u above 1.0 instead of going back through the whole texture.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point2d::Point2D;
+    ///
+    /// let mut input = Mesh::new(vec![1.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 1.0], vec![0, 1, 2]);
+    ///
+    /// input.project_uv_spherical(&LocalCoordinateSystem::global());
+    ///
+    /// assert_eq!(input.get_uv_for_corner(0), Some(Point2D::new(1.0, 0.5)));
+    /// assert_eq!(input.get_uv_for_corner(1), Some(Point2D::new(0.75, 0.5)));
+    /// assert_eq!(input.get_uv_for_corner(2).unwrap().y, 1.0);
+    /// ```
+    pub fn project_uv_spherical(&mut self, local_coordinate_system: &LocalCoordinateSystem) {
+        let uvs = self.get_local_points(local_coordinate_system).iter().map(|point| {
+            let radius = point.to_vector().get_length();
+            let v = if radius == 0.0 { 0.5 } else { 1.0 - (point.z / radius).clamp(-1.0, 1.0).acos() / PI };
+            [get_turns(point), v]
+        }).collect();
+
+        self.uvs = Some(UvChannel::PerCorner(self.get_corner_uvs_across_seam(uvs)));
+    }
+
+    fn get_local_points(&self, local_coordinate_system: &LocalCoordinateSystem) -> Vec<Point> {
+        self.to_points().iter().map(|point| point.get_in_global_coordinate_system(local_coordinate_system)).collect()
+    }
+
+    /// Converts UVs of vertices with u in turns (0.0 - 1.0) into UVs of corners, moving u of
+    /// corners of faces crossing the seam by a full turn, so they don't span the whole texture.
+    fn get_corner_uvs_across_seam(&self, vertex_uvs: Vec<[f64; 2]>) -> Vec<f64> {
+        let mut uvs: Vec<f64> = Vec::with_capacity(self.indices.len() * 2);
+        for face in self.indices.chunks(3) {
+            let face_uvs = [vertex_uvs[face[0]], vertex_uvs[face[1]], vertex_uvs[face[2]]];
+            let max_u = face_uvs.iter().map(|uv| uv[0]).fold(f64::NEG_INFINITY, f64::max);
+            for uv in face_uvs {
+                let u = if max_u - uv[0] > 0.5 { uv[0] + 1.0 } else { uv[0] };
+                uvs.extend_from_slice(&[u, uv[1]]);
+            }
+        }
+
+        uvs
+    }
+}
+
+/// Gets the angle around the z axis as a fraction of the full turn (0.0 - 1.0), starting from
+/// the x axis.
+fn get_turns(point: &Point) -> f64 {
+    let angle = point.y.atan2(point.x);
+
+    if angle < 0.0 { angle / (2.0 * PI) + 1.0 } else { angle / (2.0 * PI) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point2d::Point2D;
+    use super::*;
+
+    #[test]
+    fn test_project_uv_box_sides_not_mirrored() {
+        // Faces facing +x and -x, both anticlockwise from outside
+        let mut input = Mesh::new(
+            vec![
+                1.0,0.0,0.0, 1.0,1.0,0.0, 1.0,0.0,1.0,
+                0.0,1.0,0.0, 0.0,0.0,0.0, 0.0,1.0,1.0,
+            ],
+            vec![0, 1, 2, 3, 4, 5]);
+
+        input.project_uv_box(&LocalCoordinateSystem::global(), 1.0);
+
+        for face_id in 0..2 {
+            let [a, b, c] = [0, 1, 2].map(|corner| input.get_uv_for_corner(face_id * 3 + corner).unwrap());
+            let signed_area = (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y);
+            assert!(signed_area > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_project_uv_box_top() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 5.0, 2.0, 0.0, 5.0, 0.0, 2.0, 5.0], vec![0, 1, 2]);
+
+        input.project_uv_box(&LocalCoordinateSystem::global(), 2.0);
+
+        assert_eq!(input.uvs, Some(UvChannel::PerCorner(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0])));
+    }
+
+    #[test]
+    fn test_project_uv_cylindrical_across_seam() {
+        let mut input = Mesh::new(vec![1.0, -0.1, 0.0, 1.0, 0.1, 0.0, 1.0, 0.0, 1.0], vec![0, 1, 2]);
+
+        input.project_uv_cylindrical(&LocalCoordinateSystem::global(), 1.0);
+
+        let first = input.get_uv_for_corner(0).unwrap();
+        let second = input.get_uv_for_corner(1).unwrap();
+        assert!((first.x - (1.0 - 0.1_f64.atan() / (2.0 * PI))).abs() < 1e-12);
+        assert!((second.x - (1.0 + 0.1_f64.atan() / (2.0 * PI))).abs() < 1e-12);
+        assert_eq!(input.get_uv_for_corner(2), Some(Point2D::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_project_uv_planar_in_rotated_coordinate_system() {
+        let mut input = Mesh::new(vec![1.0, 1.0, 0.0, 1.0, 3.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        let local_coordinate_system = LocalCoordinateSystem::new(Point::new(1.0, 1.0, 0.0), Vector::new(0.0, 1.0, 0.0), Vector::new(-1.0, 0.0, 0.0));
+
+        input.project_uv_planar(&local_coordinate_system, 1.0);
+
+        assert_eq!(input.uvs, Some(UvChannel::PerVertex(vec![0.0, 0.0, 2.0, 0.0, 0.0, 1.0])));
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::point2d::Point2D;
+use crate::uv_channel::UvChannel;
+use crate::vector::Vector;
+
+/// The gap between charts in the atlas, as a fraction of its size.
+const ATLAS_PADDING: f64 = 0.01;
+
+/// The relative residual at which the conjugate gradient solver stops.
+const SOLVER_TOLERANCE: f64 = 1e-12;
+
+impl Mesh {
+    /// Unwraps the [Mesh] into UVs, which are stored per corner in `uvs`.
+    ///
+    /// The [Mesh] is first split into charts where the angle between faces' normals is higher
+    /// than the `angle_threshold` (in radians), like in [Mesh::split_by_face_angle]. Each chart
+    /// is flattened with least squares conformal maps (LSCM), which keep angles as well as
+    /// possible. Charts are then packed next to each other into the atlas fitting the 0.0-1.0
+    /// square, all with the same scale.
+    ///
+    /// Charts should be disc-like (e.g. not closed), otherwise their UVs can overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::uv_channel::UvChannel;
+    ///
+    /// // 2 squares folded by 90 degrees
+    /// let mut input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0],
+    ///     vec![0, 1, 2, 0, 2, 3, 1, 4, 5, 1, 5, 2],
+    /// );
+    ///
+    /// input.unwrap_uv(0.5);
+    ///
+    /// match input.uvs {
+    ///     Some(UvChannel::PerCorner(uvs)) => {
+    ///         assert_eq!(uvs.len(), 4 * 3 * 2);
+    ///         assert!(uvs.iter().all(|uv| *uv >= 0.0 && *uv <= 1.0));
+    ///     },
+    ///     _ => panic!("UVs should be stored per corner"),
+    /// }
+    /// ```
+    pub fn unwrap_uv(&mut self, angle_threshold: f64) {
+        let charts = self.get_face_ids_split_by_face_angle(angle_threshold);
+        let chart_uvs: Vec<Vec<Point2D>> = charts.iter().map(|face_ids| self.get_chart_uvs(face_ids)).collect();
+        let (offsets, scale) = get_atlas_placement(&chart_uvs);
+
+        let mut uvs = vec![0.0; self.indices.len() * 2];
+        for ((face_ids, chart_uvs), offset) in charts.iter().zip(&chart_uvs).zip(&offsets) {
+            for (i, face_id) in face_ids.iter().enumerate() {
+                for corner in 0..3 {
+                    let uv = chart_uvs[i * 3 + corner];
+                    let corner_id = face_id * 3 + corner;
+                    uvs[corner_id * 2] = (uv.x + offset.x) * scale;
+                    uvs[corner_id * 2 + 1] = (uv.y + offset.y) * scale;
+                }
+            }
+        }
+
+        self.uvs = Some(UvChannel::PerCorner(uvs));
+    }
+
+    /// Flattens the chart with LSCM, returns UVs of corners of its faces (in the order of
+    /// `face_ids`), moved so their minimum is at (0.0, 0.0).
+    fn get_chart_uvs(&self, face_ids: &[usize]) -> Vec<Point2D> {
+        let mut local_vertex_ids: HashMap<usize, usize> = HashMap::new();
+        for face_id in face_ids {
+            for corner in 0..3 {
+                let number_of_local_vertices = local_vertex_ids.len();
+                local_vertex_ids.entry(self.indices[face_id * 3 + corner]).or_insert(number_of_local_vertices);
+            }
+        }
+        let mut vertex_ids = vec![0; local_vertex_ids.len()];
+        for (vertex_id, local_vertex_id) in &local_vertex_ids {
+            vertex_ids[*local_vertex_id] = *vertex_id;
+        }
+        let points: Vec<Point> = vertex_ids.iter().map(|vertex_id| Point::new(self.coordinates[vertex_id * 3], self.coordinates[vertex_id * 3 + 1], self.coordinates[vertex_id * 3 + 2])).collect();
+
+        // Pins are the 2 vertices furthest apart along the longest side of the bounding box
+        let extents: Vec<(usize, usize, f64)> = (0..3).map(|axis| {
+            let get_coordinate = |i: usize| [points[i].x, points[i].y, points[i].z][axis];
+            let min = (0..points.len()).min_by(|a, b| get_coordinate(*a).total_cmp(&get_coordinate(*b))).unwrap();
+            let max = (0..points.len()).max_by(|a, b| get_coordinate(*a).total_cmp(&get_coordinate(*b))).unwrap();
+            (min, max, get_coordinate(max) - get_coordinate(min))
+        }).collect();
+        let (first_pin, second_pin, _) = *extents.iter().max_by(|a, b| a.2.total_cmp(&b.2)).unwrap();
+        let pins = [(first_pin, Point2D::new(0.0, 0.0)), (second_pin, Point2D::new(points[first_pin].get_distance_to_point(&points[second_pin]), 0.0))];
+
+        // Unknowns are u and v of each vertex, 2 rows (real and imaginary part) per triangle
+        let mut rows: Vec<Vec<(usize, f64)>> = Vec::new();
+        for face_id in face_ids {
+            let ids: [usize; 3] = std::array::from_fn(|corner| local_vertex_ids[&self.indices[face_id * 3 + corner]]);
+            let first_side = Vector::from_2_points(&points[ids[0]], &points[ids[1]]);
+            let second_side = Vector::from_2_points(&points[ids[0]], &points[ids[2]]);
+            let double_area = first_side.get_cross_product(&second_side).get_length();
+            if double_area == 0.0 {
+                continue;
+            }
+            let x_axis = first_side.get_unitized();
+            let local = [
+                Point2D::new(0.0, 0.0),
+                Point2D::new(first_side.get_length(), 0.0),
+                Point2D::new(second_side.get_dot_product(&x_axis), double_area / first_side.get_length()),
+            ];
+            let weight = 1.0 / double_area.sqrt();
+            let mut real_row = Vec::new();
+            let mut imaginary_row = Vec::new();
+            for corner in 0..3 {
+                let (next, previous) = (local[(corner + 1) % 3], local[(corner + 2) % 3]);
+                let (real, imaginary) = ((previous.x - next.x) * weight, (previous.y - next.y) * weight);
+                real_row.extend_from_slice(&[(ids[corner] * 2, real), (ids[corner] * 2 + 1, -imaginary)]);
+                imaginary_row.extend_from_slice(&[(ids[corner] * 2, imaginary), (ids[corner] * 2 + 1, real)]);
+            }
+            rows.push(real_row);
+            rows.push(imaginary_row);
+        }
+
+        let mut is_pinned = vec![false; points.len() * 2];
+        let mut solution = vec![0.0; points.len() * 2];
+        for (vertex_id, uv) in pins {
+            is_pinned[vertex_id * 2] = true;
+            is_pinned[vertex_id * 2 + 1] = true;
+            solution[vertex_id * 2] = uv.x;
+            solution[vertex_id * 2 + 1] = uv.y;
+        }
+        solve_least_squares(&rows, &is_pinned, &mut solution);
+
+        let mut uvs: Vec<Point2D> = (0..points.len()).map(|i| Point2D::new(solution[i * 2], solution[i * 2 + 1])).collect();
+
+        // The conformal map can come out mirrored, then it's flipped back
+        let signed_area: f64 = face_ids.iter().map(|face_id| {
+            let [a, b, c] = std::array::from_fn(|corner| uvs[local_vertex_ids[&self.indices[face_id * 3 + corner]]]);
+            (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)
+        }).sum();
+        if signed_area < 0.0 {
+            for uv in &mut uvs {
+                uv.y = -uv.y;
+            }
+        }
+
+        let min_u = uvs.iter().map(|uv| uv.x).fold(f64::INFINITY, f64::min);
+        let min_v = uvs.iter().map(|uv| uv.y).fold(f64::INFINITY, f64::min);
+        face_ids.iter()
+            .flat_map(|face_id| (0..3).map(move |corner| self.indices[face_id * 3 + corner]))
+            .map(|vertex_id| {
+                let uv = uvs[local_vertex_ids[&vertex_id]];
+                Point2D::new(uv.x - min_u, uv.y - min_v)
+            })
+            .collect()
+    }
+}
+
+/// Minimizes the sum of squares of `rows` (sparse, as pairs of the unknown's id and its
+/// factor) with the conjugate gradient method on normal equations. Pinned unknowns keep their
+/// values from the `solution`.
+fn solve_least_squares(rows: &[Vec<(usize, f64)>], is_pinned: &[bool], solution: &mut [f64]) {
+    let number_of_unknowns = solution.len();
+    let multiply = |x: &[f64]| -> Vec<f64> {
+        // A^T A x, only for free unknowns
+        let mut result = vec![0.0; number_of_unknowns];
+        for row in rows {
+            let value: f64 = row.iter().filter(|(id, _)| !is_pinned[*id]).map(|(id, factor)| factor * x[*id]).sum();
+            for (id, factor) in row {
+                if !is_pinned[*id] {
+                    result[*id] += factor * value;
+                }
+            }
+        }
+        result
+    };
+
+    // Right side: -A^T (A x) of pinned unknowns
+    let mut residual = vec![0.0; number_of_unknowns];
+    for row in rows {
+        let value: f64 = row.iter().filter(|(id, _)| is_pinned[*id]).map(|(id, factor)| factor * solution[*id]).sum();
+        for (id, factor) in row {
+            if !is_pinned[*id] {
+                residual[*id] -= factor * value;
+            }
+        }
+    }
+
+    let get_dot_product = |a: &[f64], b: &[f64]| -> f64 { a.iter().zip(b).map(|(a, b)| a * b).sum() };
+    let mut x = vec![0.0; number_of_unknowns];
+    let mut direction = residual.clone();
+    let mut residual_squared = get_dot_product(&residual, &residual);
+    let stop = residual_squared * SOLVER_TOLERANCE * SOLVER_TOLERANCE;
+    for _ in 0..number_of_unknowns * 2 {
+        if residual_squared <= stop || residual_squared == 0.0 {
+            break;
+        }
+        let product = multiply(&direction);
+        let step = residual_squared / get_dot_product(&direction, &product);
+        for i in 0..number_of_unknowns {
+            x[i] += step * direction[i];
+            residual[i] -= step * product[i];
+        }
+        let new_residual_squared = get_dot_product(&residual, &residual);
+        for i in 0..number_of_unknowns {
+            direction[i] = residual[i] + direction[i] * (new_residual_squared / residual_squared);
+        }
+        residual_squared = new_residual_squared;
+    }
+
+    for i in 0..number_of_unknowns {
+        if !is_pinned[i] {
+            solution[i] = x[i];
+        }
+    }
+}
+
+/// Packs charts (their UVs starting at (0.0, 0.0)) into rows of the atlas, from the tallest.
+///
+/// Returns offsets of charts and the scale fitting the whole atlas into the 0.0-1.0 square.
+fn get_atlas_placement(chart_uvs: &[Vec<Point2D>]) -> (Vec<Point2D>, f64) {
+    let sizes: Vec<Point2D> = chart_uvs.iter().map(|uvs| Point2D::new(
+        uvs.iter().map(|uv| uv.x).fold(0.0, f64::max),
+        uvs.iter().map(|uv| uv.y).fold(0.0, f64::max),
+    )).collect();
+    let total_area: f64 = sizes.iter().map(|size| size.x * size.y).sum();
+    let widest = sizes.iter().map(|size| size.x).fold(0.0, f64::max);
+    let padding = total_area.sqrt() * ATLAS_PADDING;
+    let atlas_width = total_area.sqrt().max(widest);
+
+    let mut order: Vec<usize> = (0..sizes.len()).collect();
+    order.sort_by(|a, b| sizes[*b].y.total_cmp(&sizes[*a].y));
+    let mut offsets = vec![Point2D::new(0.0, 0.0); sizes.len()];
+    let (mut x, mut y, mut row_height, mut used_width) = (0.0, 0.0, 0.0_f64, 0.0_f64);
+    for chart_id in order {
+        let size = sizes[chart_id];
+        if x > 0.0 && x + size.x > atlas_width {
+            x = 0.0;
+            y += row_height + padding;
+            row_height = 0.0;
+        }
+        offsets[chart_id] = Point2D::new(x, y);
+        x += size.x + padding;
+        used_width = used_width.max(x - padding);
+        row_height = row_height.max(size.y);
+    }
+
+    let atlas_size = used_width.max(y + row_height);
+    let scale = if atlas_size > 0.0 { 1.0 / atlas_size } else { 1.0 };
+
+    (offsets, scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use super::*;
+
+    fn get_uv_triangles(mesh: &Mesh) -> Vec<[Point2D; 3]> {
+        (0..mesh.get_number_of_faces()).map(|face_id| std::array::from_fn(|corner| mesh.get_uv_for_corner(face_id * 3 + corner).unwrap())).collect()
+    }
+
+    fn get_signed_area(triangle: &[Point2D; 3]) -> f64 {
+        let [a, b, c] = triangle;
+        ((b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)) / 2.0
+    }
+
+    #[test]
+    fn test_unwrap_uv_box() {
+        let mut input = Mesh::new(
+            vec![
+                0.0,0.0,0.0,
+                2.0,0.0,0.0,
+                2.0,1.0,0.0,
+                0.0,1.0,0.0,
+                0.0,0.0,3.0,
+                2.0,0.0,3.0,
+                2.0,1.0,3.0,
+                0.0,1.0,3.0,
+            ],
+            vec![
+                0,2,1, 0,3,2,
+                4,5,6, 4,6,7,
+                0,1,5, 0,5,4,
+                1,2,6, 1,6,5,
+                2,3,7, 2,7,6,
+                3,0,4, 3,4,7,
+            ]);
+
+        input.unwrap_uv(PI / 4.0);
+
+        let areas: Vec<f64> = input.to_triangles().iter().map(|triangle| triangle.get_area()).collect();
+        let uv_triangles = get_uv_triangles(&input);
+        let scale = get_signed_area(&uv_triangles[0]) / areas[0];
+        assert!(scale > 0.0);
+        for (uv_triangle, area) in uv_triangles.iter().zip(areas) {
+            assert!((get_signed_area(uv_triangle) / area - scale).abs() < 1e-9);
+            assert!(uv_triangle.iter().all(|uv| uv.x >= 0.0 && uv.x <= 1.0 && uv.y >= 0.0 && uv.y <= 1.0));
+        }
+    }
+
+    #[test]
+    fn test_unwrap_uv_cylinder_patch_is_isometric() {
+        // Quarter of a cylinder, which can be flattened without any distortion
+        let mut coordinates = Vec::new();
+        let mut indices = Vec::new();
+        for i in 0..=8 {
+            let angle = PI / 2.0 * i as f64 / 8.0;
+            for z in 0..=4 {
+                coordinates.extend_from_slice(&[2.0 * angle.cos(), 2.0 * angle.sin(), z as f64]);
+            }
+        }
+        for i in 0..8 {
+            for z in 0..4 {
+                let a = i * 5 + z;
+                indices.extend_from_slice(&[a, a + 5, a + 6, a, a + 6, a + 1]);
+            }
+        }
+        let mut input = Mesh::new(coordinates, indices);
+
+        input.unwrap_uv(PI / 4.0);
+
+        let areas: Vec<f64> = input.to_triangles().iter().map(|triangle| triangle.get_area()).collect();
+        let uv_triangles = get_uv_triangles(&input);
+        let scale = get_signed_area(&uv_triangles[0]) / areas[0];
+        for (uv_triangle, area) in uv_triangles.iter().zip(areas) {
+            assert!((get_signed_area(uv_triangle) / area - scale).abs() < 1e-6 * scale);
+        }
+    }
+
+    #[test]
+    fn test_get_atlas_placement_no_overlaps() {
+        let chart_uvs = vec![
+            vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 2.0)],
+            vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 1.0)],
+            vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)],
+        ];
+
+        let (offsets, scale) = get_atlas_placement(&chart_uvs);
+
+        let boxes: Vec<(Point2D, Point2D)> = offsets.iter().zip(&chart_uvs)
+            .map(|(offset, uvs)| (*offset, Point2D::new(offset.x + uvs[1].x, offset.y + uvs[1].y)))
+            .collect();
+        for (i, a) in boxes.iter().enumerate() {
+            assert!(a.1.x * scale <= 1.0 + 1e-12 && a.1.y * scale <= 1.0 + 1e-12);
+            for b in &boxes[i + 1..] {
+                assert!(a.1.x <= b.0.x || b.1.x <= a.0.x || a.1.y <= b.0.y || b.1.y <= a.0.y);
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::mesh::Mesh;
+use crate::point2d::Point2D;
+use crate::uv_channel::UvChannel;
+
+impl Mesh {
+    /// Gets the UV of the face corner, where the corner id is the position in `indices`.
+    ///
+    /// It returns `None` if the [Mesh] has no `uvs`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::uv_channel::UvChannel;
+    ///
+    /// let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// assert_eq!(input.get_uv_for_corner(0), None);
+    ///
+    /// input.uvs = Some(UvChannel::PerCorner(vec![0.0, 0.0, 0.5, 0.0, 0.0, 0.5]));
+    /// assert_eq!(input.get_uv_for_corner(2), Some(Point2D::new(0.0, 0.5)));
+    /// ```
+    pub fn get_uv_for_corner(&self, corner_id: usize) -> Option<Point2D> {
+        match &self.uvs {
+            Some(UvChannel::PerVertex(uvs)) => {
+                let vertex_id = self.indices[corner_id];
+                Some(Point2D::new(uvs[vertex_id * 2], uvs[vertex_id * 2 + 1]))
+            },
+            Some(UvChannel::PerCorner(uvs)) => Some(Point2D::new(uvs[corner_id * 2], uvs[corner_id * 2 + 1])),
+            None => None,
+        }
+    }
+
+    /// Gets UVs of all the face corners as a flat list, no matter how they're stored.
+    pub(crate) fn get_corner_uvs(&self) -> Option<Vec<f64>> {
+        match &self.uvs {
+            Some(UvChannel::PerVertex(uvs)) => Some(self.indices.iter().flat_map(|index| [uvs[index * 2], uvs[index * 2 + 1]]).collect()),
+            Some(UvChannel::PerCorner(uvs)) => Some(uvs.clone()),
+            None => None,
+        }
+    }
+
+    /// Gets UVs of the given faces' corners as a flat list.
+    pub(crate) fn get_corner_uvs_by_face_ids(&self, face_ids: &[usize]) -> Option<Vec<f64>> {
+        self.uvs.as_ref()?;
+        let mut uvs: Vec<f64> = Vec::with_capacity(face_ids.len() * 6);
+        for face_id in face_ids {
+            for corner_id in face_id * 3..face_id * 3 + 3 {
+                let uv = self.get_uv_for_corner(corner_id).unwrap();
+                uvs.extend_from_slice(&[uv.x, uv.y]);
+            }
+        }
+        Some(uvs)
+    }
+
+    /// Gets UVs for the Meshes joined in the given order, without any vertex welding.
+    ///
+    /// If all the Meshes have per vertex UVs, they're kept per vertex. If all the Meshes have
+    /// UVs, but some of them per corner, they're all converted to per corner. If any of the
+    /// Meshes has no UVs, there are no UVs.
+    pub(crate) fn get_joined_uvs(meshes: &[&Mesh]) -> Option<UvChannel> {
+        if meshes.iter().all(|mesh| matches!(mesh.uvs, Some(UvChannel::PerVertex(_)))) {
+            let mut uvs: Vec<f64> = Vec::new();
+            for mesh in meshes {
+                if let Some(UvChannel::PerVertex(mesh_uvs)) = &mesh.uvs {
+                    uvs.extend_from_slice(mesh_uvs);
+                }
+            }
+            return Some(UvChannel::PerVertex(uvs));
+        }
+
+        let mut uvs: Vec<f64> = Vec::new();
+        for mesh in meshes {
+            uvs.extend(mesh.get_corner_uvs()?);
+        }
+        Some(UvChannel::PerCorner(uvs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_corner_uvs_per_vertex() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        input.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]));
+
+        let actual = input.get_corner_uvs();
+
+        assert_eq!(actual, Some(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0]));
+        assert_eq!(input.get_corner_uvs_by_face_ids(&[1]), Some(vec![0.0, 0.0, 1.0, 1.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn test_get_joined_uvs() {
+        let mut a = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+        a.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+        let mut b = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![2, 1, 0]);
+        b.uvs = Some(UvChannel::PerVertex(vec![0.5, 0.5, 0.6, 0.5, 0.5, 0.6]));
+        let c = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        assert_eq!(Mesh::get_joined_uvs(&[&a, &b]), Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.5, 0.5, 0.6, 0.5, 0.5, 0.6])));
+        assert_eq!(Mesh::get_joined_uvs(&[&a, &b, &c]), None);
+
+        b.uvs = Some(UvChannel::PerCorner(vec![0.5, 0.6, 0.6, 0.5, 0.5, 0.5]));
+        assert_eq!(Mesh::get_joined_uvs(&[&a, &b]), Some(UvChannel::PerCorner(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.5, 0.6, 0.6, 0.5, 0.5, 0.5])));
+    }
+}
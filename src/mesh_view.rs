@@ -0,0 +1,110 @@
+use crate::mesh::Mesh;
+
+/// Represents a selection of faces of a [Mesh], borrowing its buffers without copying them.
+///
+/// It can be used to inspect selections (e.g. area, bounding box, normals) without creating a
+/// new [Mesh] for each of them, like [Mesh::get_part_by_face_ids] does. The view can still be
+/// converted into a [Mesh] with [MeshView::to_mesh] when needed.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::mesh::Mesh;
+///
+/// let mesh = Mesh::new(
+///     vec![0.0, 0.0, 0.0,
+///          1.0, 0.0, 0.0,
+///          1.0, 1.0, 0.0,
+///          0.0, 1.0, 0.0,
+///          0.0, 0.0, 2.0,
+///          ],
+///     vec![0, 1, 2, // first face
+///          0, 2, 3, // second face
+///          0, 1, 4, // third face
+///          ]
+/// );
+/// let face_ids = vec![0, 1];
+///
+/// let view = mesh.get_view_by_face_ids(&face_ids);
+///
+/// assert!((view.get_area() - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MeshView<'a> {
+    pub(crate) mesh: &'a Mesh,
+    pub(crate) face_ids: &'a [usize],
+}
+
+impl<'a> MeshView<'a> {
+    /// Creates a new [MeshView] of the given faces of the [Mesh].
+    ///
+    /// It panics if any of the face ids is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::mesh_view::MeshView;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let face_ids = vec![1];
+    ///
+    /// let view = MeshView::new(&mesh, &face_ids);
+    ///
+    /// assert_eq!(view.get_face_ids(), &[1]);
+    /// assert_eq!(view.get_number_of_faces(), 1);
+    /// ```
+    pub fn new(mesh: &'a Mesh, face_ids: &'a [usize]) -> MeshView<'a> {
+        let number_of_faces = mesh.get_number_of_faces();
+        if let Some(face_id) = face_ids.iter().find(|&&face_id| face_id >= number_of_faces) {
+            panic!("Face id {} is out of range, the Mesh has {} faces.", face_id, number_of_faces);
+        }
+
+        MeshView { mesh, face_ids }
+    }
+}
+
+impl Mesh {
+    /// Creates a [MeshView] of the given faces, see [MeshView::new].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let face_ids = vec![0];
+    ///
+    /// let view = mesh.get_view_by_face_ids(&face_ids);
+    ///
+    /// assert!((view.get_area() - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn get_view_by_face_ids<'a>(&'a self, face_ids: &'a [usize]) -> MeshView<'a> {
+        MeshView::new(self, face_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+        let face_ids = vec![1, 0];
+
+        let actual = MeshView::new(&mesh, &face_ids);
+
+        assert!(std::ptr::eq(actual.mesh, &mesh));
+        assert_eq!(actual.face_ids, &[1, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Face id 1 is out of range, the Mesh has 1 faces.")]
+    fn test_new_out_of_range() {
+        let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2]);
+        let face_ids = vec![0, 1];
+
+        MeshView::new(&mesh, &face_ids);
+    }
+}
@@ -0,0 +1,291 @@
+use crate::bounding_box::BoundingBox;
+use crate::mesh::Mesh;
+use crate::mesh_view::MeshView;
+use crate::point::Point;
+use crate::triangle::Triangle;
+use crate::vector::Vector;
+
+impl<'a> MeshView<'a> {
+    /// Gets the [Mesh] this view borrows from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let face_ids = vec![0];
+    ///
+    /// let view = mesh.get_view_by_face_ids(&face_ids);
+    ///
+    /// assert!(std::ptr::eq(view.get_mesh(), &mesh));
+    /// ```
+    pub fn get_mesh(&self) -> &'a Mesh {
+        self.mesh
+    }
+
+    /// Gets ids of the faces of the [Mesh] selected by this view.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let face_ids = vec![1];
+    ///
+    /// let view = mesh.get_view_by_face_ids(&face_ids);
+    ///
+    /// assert_eq!(view.get_face_ids(), &[1]);
+    /// ```
+    pub fn get_face_ids(&self) -> &'a [usize] {
+        self.face_ids
+    }
+
+    /// Gets the number of faces selected by this view.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let face_ids = vec![0, 1];
+    ///
+    /// let view = mesh.get_view_by_face_ids(&face_ids);
+    ///
+    /// assert_eq!(view.get_number_of_faces(), 2);
+    /// ```
+    pub fn get_number_of_faces(&self) -> usize {
+        self.face_ids.len()
+    }
+
+    /// Iterates over selected faces as tuples of 3 vertex indices of the [Mesh].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let face_ids = vec![1];
+    ///
+    /// let actual: Vec<(usize, usize, usize)> = mesh.get_view_by_face_ids(&face_ids).faces().collect();
+    ///
+    /// assert_eq!(actual, vec![(0, 2, 3)]);
+    /// ```
+    pub fn faces(&self) -> impl Iterator<Item = (usize, usize, usize)> + 'a {
+        let indices: &'a [usize] = &self.mesh.indices;
+        let face_ids: &'a [usize] = self.face_ids;
+        face_ids.iter().map(move |&face_id| (indices[face_id * 3], indices[face_id * 3 + 1], indices[face_id * 3 + 2]))
+    }
+
+    /// Iterates over selected faces as [Triangle]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let face_ids = vec![1];
+    ///
+    /// let actual: Vec<_> = mesh.get_view_by_face_ids(&face_ids).triangles().collect();
+    ///
+    /// assert_eq!(actual[0].third_point, Point::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn triangles(&self) -> impl Iterator<Item = Triangle> + 'a {
+        let coordinates: &'a [f64] = &self.mesh.coordinates;
+        let get_point = move |vertex_id: usize| Point::new(coordinates[vertex_id * 3], coordinates[vertex_id * 3 + 1], coordinates[vertex_id * 3 + 2]);
+        self.faces().map(move |(first, second, third)| Triangle::new(get_point(first), get_point(second), get_point(third)))
+    }
+
+    /// Gets the area of selected faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 4.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let face_ids = vec![1];
+    ///
+    /// let actual = mesh.get_view_by_face_ids(&face_ids).get_area();
+    ///
+    /// assert!((actual - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn get_area(&self) -> f64 {
+        self.triangles().map(|triangle| triangle.get_area()).sum()
+    }
+
+    /// Gets unitized normals of selected faces, in the order of the view's face ids.
+    ///
+    /// Degenerate faces have `None`, same as [Mesh::get_face_normal_vectors_unitized_checked].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 3, 2]);
+    /// let face_ids = vec![1];
+    ///
+    /// let actual = mesh.get_view_by_face_ids(&face_ids).get_face_normal_vectors_unitized_checked();
+    ///
+    /// assert_eq!(actual, vec![Some(Vector::new(0.0, 0.0, -1.0))]);
+    /// ```
+    pub fn get_face_normal_vectors_unitized_checked(&self) -> Vec<Option<Vector>> {
+        self.triangles().map(|triangle| triangle.get_normal_vector_unitized_checked()).collect()
+    }
+
+    /// Gets the Bounding Box (AABB) of vertices used by selected faces.
+    ///
+    /// It returns `None` if there are no faces selected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 5.0],
+    ///     vec![0, 1, 2, 0, 1, 3]
+    /// );
+    /// let face_ids = vec![0];
+    ///
+    /// let actual = mesh.get_view_by_face_ids(&face_ids).get_bounding_box();
+    ///
+    /// assert_eq!(actual, Some(BoundingBox::new(0.0, 1.0, 0.0, 1.0, 0.0, 0.0)));
+    /// ```
+    pub fn get_bounding_box(&self) -> Option<BoundingBox> {
+        let coordinates = &self.mesh.coordinates;
+        let mut vertex_ids = self.faces().flat_map(|(first, second, third)| [first, second, third]);
+        let first_vertex_id = vertex_ids.next()?;
+
+        let mut min = [coordinates[first_vertex_id * 3], coordinates[first_vertex_id * 3 + 1], coordinates[first_vertex_id * 3 + 2]];
+        let mut max = min;
+        for vertex_id in vertex_ids {
+            let vertex = &coordinates[vertex_id * 3..vertex_id * 3 + 3];
+            for ((min, max), coordinate) in min.iter_mut().zip(max.iter_mut()).zip(vertex) {
+                *min = min.min(*coordinate);
+                *max = max.max(*coordinate);
+            }
+        }
+
+        Some(BoundingBox::new(min[0], max[0], min[1], max[1], min[2], max[2]))
+    }
+
+    /// Gets ids of vertices used by selected faces, sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let face_ids = vec![1];
+    ///
+    /// let actual = mesh.get_view_by_face_ids(&face_ids).get_vertex_ids();
+    ///
+    /// assert_eq!(actual, vec![0, 2, 3]);
+    /// ```
+    pub fn get_vertex_ids(&self) -> Vec<usize> {
+        let mut vertex_ids: Vec<usize> = self.faces().flat_map(|(first, second, third)| [first, second, third]).collect();
+        vertex_ids.sort();
+        vertex_ids.dedup();
+
+        vertex_ids
+    }
+
+    /// Creates a new [Mesh] from selected faces.
+    ///
+    /// Only the vertices used by selected faces are kept, in their original order, so the
+    /// result stays welded. `uvs`, `vertex_colors`, `face_groups` and `attributes` are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3]);
+    /// let face_ids = vec![1];
+    ///
+    /// let actual = mesh.get_view_by_face_ids(&face_ids).to_mesh();
+    ///
+    /// assert_eq!(actual, Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]));
+    /// ```
+    pub fn to_mesh(&self) -> Mesh {
+        self.mesh.get_welded_part_by_face_ids(self.face_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_pyramid() -> Mesh {
+        Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0, 5.0, 5.0, 4.0],
+            vec![0, 1, 2, 0, 2, 3, 0, 1, 4, 1, 2, 4, 2, 3, 4, 3, 0, 4]
+        )
+    }
+
+    #[test]
+    fn test_get_area_same_as_part() {
+        let mesh = get_pyramid();
+        let face_ids = vec![2, 3, 5];
+
+        let actual = mesh.get_view_by_face_ids(&face_ids).get_area();
+        let expected = mesh.get_part_by_face_ids(&face_ids).get_area();
+
+        assert!((actual - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_bounding_box_same_as_part() {
+        let mesh = get_pyramid();
+        let face_ids = vec![3, 4];
+
+        let actual = mesh.get_view_by_face_ids(&face_ids).get_bounding_box();
+        let expected = mesh.get_part_by_face_ids(&face_ids).get_bounding_box();
+
+        assert_eq!(actual, Some(expected));
+    }
+
+    #[test]
+    fn test_get_bounding_box_empty() {
+        let mesh = get_pyramid();
+        let face_ids: Vec<usize> = vec![];
+
+        assert_eq!(mesh.get_view_by_face_ids(&face_ids).get_bounding_box(), None);
+    }
+
+    #[test]
+    fn test_get_face_normal_vectors_unitized_checked_same_as_mesh() {
+        let mesh = get_pyramid();
+        let face_ids = vec![4, 0];
+
+        let actual = mesh.get_view_by_face_ids(&face_ids).get_face_normal_vectors_unitized_checked();
+        let normals = mesh.get_face_normal_vectors_unitized_checked();
+
+        assert_eq!(actual, vec![normals[4], normals[0]]);
+    }
+
+    #[test]
+    fn test_to_mesh() {
+        let mesh = get_pyramid();
+        let face_ids = vec![2, 3];
+
+        let actual = mesh.get_view_by_face_ids(&face_ids).to_mesh();
+
+        let expected = Mesh::new(
+            vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 5.0, 5.0, 4.0],
+            vec![0, 1, 3, 1, 2, 3]
+        );
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::voxel_fill_mode::VoxelFillMode;
+use crate::voxel_grid::VoxelGrid;
+
+impl Mesh {
+    /// Converts the [Mesh] into the [VoxelGrid] of cubic cells with the edge of `cell_size`.
+    ///
+    /// The grid starts at the minimum corner of the [Mesh]'s bounding box and covers it whole.
+    /// With [VoxelFillMode::Surface] cells overlapping (or touching) any face are filled, using
+    /// the separating axis test of the triangle and the box. With [VoxelFillMode::Solid] also
+    /// cells with centers inside the [Mesh] are filled: rays along the X axis are cast through
+    /// centers of cells and cells between odd and even crossings of faces are inside. Cells
+    /// overlapping faces stick out, so the volume of the [VoxelGrid] is a bit bigger than the
+    /// volume of the [Mesh].
+    ///
+    /// For [VoxelFillMode::Solid] the [Mesh] should be closed. The `cell_size` should be positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::voxel_fill_mode::VoxelFillMode;
+    ///
+    /// // Box 2 x 2 x 2
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 2.0, 2.0, 0.0, 0.0, 2.0, 0.0,
+    ///          0.0, 0.0, 2.0, 2.0, 0.0, 2.0, 2.0, 2.0, 2.0, 0.0, 2.0, 2.0],
+    ///     vec![0, 2, 1, 0, 3, 2, 4, 5, 6, 4, 6, 7, 0, 1, 5, 0, 5, 4,
+    ///          1, 2, 6, 1, 6, 5, 2, 3, 7, 2, 7, 6, 3, 0, 4, 3, 4, 7]);
+    ///
+    /// let surface = input.voxelize(0.5, VoxelFillMode::Surface);
+    /// let solid = input.voxelize(0.5, VoxelFillMode::Solid);
+    ///
+    /// assert_eq!((surface.size_x, surface.size_y, surface.size_z), (4, 4, 4));
+    /// assert_eq!(surface.get_number_of_filled_cells(), 64 - 8);
+    /// assert_eq!(solid.get_volume(), 8.0);
+    /// ```
+    pub fn voxelize(&self, cell_size: f64, fill_mode: VoxelFillMode) -> VoxelGrid {
+        let bounding_box = self.get_bounding_box();
+        let origin = Point::new(bounding_box.min_x, bounding_box.min_y, bounding_box.min_z);
+        let get_size = |extent: f64| ((extent / cell_size).ceil() as usize).max(1);
+        let sizes = [
+            get_size(bounding_box.max_x - bounding_box.min_x),
+            get_size(bounding_box.max_y - bounding_box.min_y),
+            get_size(bounding_box.max_z - bounding_box.min_z),
+        ];
+        let mut grid = VoxelGrid::new(origin, cell_size, sizes[0], sizes[1], sizes[2], vec![false; sizes[0] * sizes[1] * sizes[2]]);
+        let origin = [origin.x, origin.y, origin.z];
+        let half_size = cell_size / 2.0;
+        let get_cell_range = |min: f64, max: f64, axis: usize| -> (usize, usize) {
+            let first = ((min - origin[axis]) / cell_size).floor().max(0.0) as usize;
+            let last = ((max - origin[axis]) / cell_size).floor().max(0.0) as usize;
+            (first.min(sizes[axis] - 1), last.min(sizes[axis] - 1))
+        };
+
+        let triangles: Vec<[[f64; 3]; 3]> = self.to_triangles().iter()
+            .map(|triangle| [triangle.first_point, triangle.second_point, triangle.third_point].map(|point| [point.x, point.y, point.z]))
+            .collect();
+        for triangle in &triangles {
+            let ranges: Vec<(usize, usize)> = (0..3)
+                .map(|axis| {
+                    let coordinates = triangle.map(|point| point[axis]);
+                    get_cell_range(coordinates.iter().copied().fold(f64::INFINITY, f64::min), coordinates.iter().copied().fold(f64::NEG_INFINITY, f64::max), axis)
+                })
+                .collect();
+            for z in ranges[2].0..=ranges[2].1 {
+                for y in ranges[1].0..=ranges[1].1 {
+                    for x in ranges[0].0..=ranges[0].1 {
+                        let index = grid.get_index(x, y, z);
+                        if grid.cells[index] {
+                            continue;
+                        }
+                        let center = grid.get_cell_center(x, y, z);
+                        if is_triangle_overlapping_box(triangle, [center.x, center.y, center.z], half_size) {
+                            grid.cells[index] = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        if fill_mode == VoxelFillMode::Solid {
+            // X coordinates of crossings of rays going through centers of cells in each row (y, z)
+            let mut crossings: Vec<Vec<f64>> = vec![Vec::new(); sizes[1] * sizes[2]];
+            for triangle in &triangles {
+                let (first_y, last_y) = get_cell_range(triangle.iter().map(|point| point[1]).fold(f64::INFINITY, f64::min), triangle.iter().map(|point| point[1]).fold(f64::NEG_INFINITY, f64::max), 1);
+                let (first_z, last_z) = get_cell_range(triangle.iter().map(|point| point[2]).fold(f64::INFINITY, f64::min), triangle.iter().map(|point| point[2]).fold(f64::NEG_INFINITY, f64::max), 2);
+                for z in first_z..=last_z {
+                    for y in first_y..=last_y {
+                        let (center_y, center_z) = (origin[1] + (y as f64 + 0.5) * cell_size, origin[2] + (z as f64 + 0.5) * cell_size);
+                        if let Some(crossing) = get_x_crossing(triangle, center_y, center_z) {
+                            crossings[y + sizes[1] * z].push(crossing);
+                        }
+                    }
+                }
+            }
+            for z in 0..sizes[2] {
+                for y in 0..sizes[1] {
+                    let row_crossings = &mut crossings[y + sizes[1] * z];
+                    row_crossings.sort_by(|a, b| a.total_cmp(b));
+                    for pair in row_crossings.chunks_exact(2) {
+                        for x in 0..sizes[0] {
+                            let center_x = origin[0] + (x as f64 + 0.5) * cell_size;
+                            if center_x >= pair[0] && center_x <= pair[1] {
+                                let index = grid.get_index(x, y, z);
+                                grid.cells[index] = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        grid
+    }
+}
+
+/// Checks if the triangle overlaps the cube with the given `center` and `half_size`, using the
+/// separating axis test (box axes, the triangle's normal, and crossings of their edges).
+fn is_triangle_overlapping_box(triangle: &[[f64; 3]; 3], center: [f64; 3], half_size: f64) -> bool {
+    let points = triangle.map(|point| [point[0] - center[0], point[1] - center[1], point[2] - center[2]]);
+    let edges = [0, 1, 2].map(|i| {
+        let (start, end) = (points[i], points[(i + 1) % 3]);
+        [end[0] - start[0], end[1] - start[1], end[2] - start[2]]
+    });
+    let get_cross_product = |a: [f64; 3], b: [f64; 3]| [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]];
+
+    let mut axes: Vec<[f64; 3]> = vec![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], get_cross_product(edges[0], edges[1])];
+    for edge in edges {
+        for box_axis in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]] {
+            axes.push(get_cross_product(edge, box_axis));
+        }
+    }
+
+    axes.iter().all(|axis| {
+        let projections = points.map(|point| point[0] * axis[0] + point[1] * axis[1] + point[2] * axis[2]);
+        let radius = half_size * (axis[0].abs() + axis[1].abs() + axis[2].abs());
+        let min = projections.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = projections.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        // Zero axes (parallel edges, degenerate triangles) don't separate anything
+        !(min > radius || max < -radius)
+    })
+}
+
+/// Gets the x coordinate where the line parallel to the X axis going through (y, z) crosses
+/// the triangle.
+///
+/// Points on edges follow the half-open rule, so the line going through the shared edge
+/// crosses only one of its faces.
+fn get_x_crossing(triangle: &[[f64; 3]; 3], y: f64, z: f64) -> Option<f64> {
+    let [a, b, c] = triangle.map(|point| [point[1] - y, point[2] - z]);
+    let get_edge_function = |start: [f64; 2], end: [f64; 2]| -> f64 { start[0] * end[1] - start[1] * end[0] };
+    let weights = [get_edge_function(b, c), get_edge_function(c, a), get_edge_function(a, b)];
+    let area = weights[0] + weights[1] + weights[2];
+    if area == 0.0 {
+        return None;
+    }
+    // The half-open rule: zero weights count only for edges on one side, chosen by their direction
+    let edges = [(b, c), (c, a), (a, b)];
+    let is_inside = weights.iter().zip(edges).all(|(weight, (start, end))| {
+        let signed_weight = weight * area.signum();
+        let is_top_left = (end[1] - start[1]) * area.signum() > 0.0 || ((end[1] - start[1]) == 0.0 && (end[0] - start[0]) * area.signum() < 0.0);
+        signed_weight > 0.0 || (signed_weight == 0.0 && is_top_left)
+    });
+    if !is_inside {
+        return None;
+    }
+
+    Some((weights[0] * triangle[0][0] + weights[1] * triangle[1][0] + weights[2] * triangle[2][0]) / area)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::get_cube;
+    use super::*;
+
+    /// Gets an octahedron with vertices at the distance of `radius` from the `center`.
+    fn get_octahedron(center: Point, radius: f64) -> Mesh {
+        let mut coordinates = Vec::new();
+        for offset in [[1.0, 0.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, -1.0]] {
+            coordinates.extend_from_slice(&[center.x + offset[0] * radius, center.y + offset[1] * radius, center.z + offset[2] * radius]);
+        }
+
+        Mesh::new(coordinates, vec![0, 2, 4, 2, 1, 4, 1, 3, 4, 3, 0, 4, 2, 0, 5, 1, 2, 5, 3, 1, 5, 0, 3, 5])
+    }
+
+    #[test]
+    fn test_voxelize_solid_octahedron() {
+        let input = get_octahedron(Point::new(0.3, -0.2, 0.1), 3.0);
+
+        let actual = input.voxelize(0.1, VoxelFillMode::Solid);
+
+        let expected_volume = 4.0 / 3.0 * 27.0;
+        // Cells overlapping faces stick out
+        let shell_volume = input.get_area() * 0.1 * 3.0_f64.sqrt();
+        assert!(actual.get_volume() > expected_volume && actual.get_volume() < expected_volume + shell_volume);
+        let center = actual.get_cell_center(30, 30, 30);
+        assert!(input.contains_point(&center));
+        assert!(actual.is_filled(30, 30, 30));
+        assert!(!actual.is_filled(0, 0, 0));
+    }
+
+    #[test]
+    fn test_voxelize_surface_is_hollow() {
+        let input = get_octahedron(Point::new(0.0, 0.0, 0.0), 1.0);
+
+        let surface = input.voxelize(0.1, VoxelFillMode::Surface);
+        let solid = input.voxelize(0.1, VoxelFillMode::Solid);
+
+        assert!(!surface.is_filled(10, 10, 10));
+        assert!(solid.is_filled(10, 10, 10));
+        assert!(surface.cells.iter().zip(&solid.cells).all(|(is_surface, is_solid)| !is_surface || *is_solid));
+    }
+
+    #[test]
+    fn test_voxelize_open_mesh() {
+        // Single face on the diagonal of the square 1 x 1
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![0, 1, 2]);
+
+        let actual = input.voxelize(0.25, VoxelFillMode::Surface);
+
+        assert_eq!((actual.size_x, actual.size_y, actual.size_z), (4, 4, 1));
+        // Cells below the diagonal and touching it
+        assert_eq!(actual.get_number_of_filled_cells(), 4 + 3 + 3 + 3);
+        assert!(actual.is_filled(3, 0, 0));
+        assert!(!actual.is_filled(0, 3, 0));
+    }
+
+    #[test]
+    fn test_voxelize_to_mesh() {
+        let input = get_cube(0.0, 1.0);
+
+        let actual = input.voxelize(0.25, VoxelFillMode::Solid).to_mesh();
+
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt::Write;
+use std::fs;
+use crate::mesh::Mesh;
+
+/// VTK cell type of the triangle.
+const VTK_TRIANGLE: u8 = 5;
+
+impl Mesh {
+    /// Creates the content of the legacy .vtk file (ASCII, `POLYDATA`) with the [Mesh] and its
+    /// scalar fields, e.g. curvature or quality metrics, which can be inspected in ParaView.
+    ///
+    /// `vertex_fields` are pairs of a name and values (one per vertex), `face_fields` are pairs
+    /// of a name and values (one per face).
+    ///
+    /// It returns an error if the number of values doesn't match, or a name is empty or
+    /// contains whitespace (not allowed by the legacy format).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.5], vec![0, 1, 2]);
+    ///
+    /// let actual = input.to_vtk_string(&[("height", &[0.0, 0.0, 0.5])], &[("quality", &[0.8])]).unwrap();
+    ///
+    /// let expected = "# vtk DataFile Version 3.0
+    /// meshmeshmesh
+    /// ASCII
+    /// DATASET POLYDATA
+    /// POINTS 3 double
+    /// 0 0 0
+    /// 1 0 0
+    /// 0 1 0.5
+    /// POLYGONS 1 4
+    /// 3 0 1 2
+    /// POINT_DATA 3
+    /// SCALARS height double 1
+    /// LOOKUP_TABLE default
+    /// 0
+    /// 0
+    /// 0.5
+    /// CELL_DATA 1
+    /// SCALARS quality double 1
+    /// LOOKUP_TABLE default
+    /// 0.8
+    /// ";
+    ///
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn to_vtk_string(&self, vertex_fields: &[(&str, &[f64])], face_fields: &[(&str, &[f64])]) -> Result<String, String> {
+        self.check_vtk_fields(vertex_fields, face_fields)?;
+        if let Some((name, _)) = vertex_fields.iter().chain(face_fields).find(|(name, _)| name.is_empty() || name.contains(char::is_whitespace)) {
+            return Err(format!("Invalid field name for the .vtk file: '{}'", name));
+        }
+
+        let number_of_vertices = self.get_number_of_vertices();
+        let number_of_faces = self.get_number_of_faces();
+        let mut content = String::new();
+
+        content.push_str("# vtk DataFile Version 3.0\nmeshmeshmesh\nASCII\nDATASET POLYDATA\n");
+        writeln!(content, "POINTS {} double", number_of_vertices).unwrap();
+        for vertex in self.coordinates.chunks(3) {
+            writeln!(content, "{} {} {}", vertex[0], vertex[1], vertex[2]).unwrap();
+        }
+        writeln!(content, "POLYGONS {} {}", number_of_faces, number_of_faces * 4).unwrap();
+        for face in self.indices.chunks(3) {
+            writeln!(content, "3 {} {} {}", face[0], face[1], face[2]).unwrap();
+        }
+
+        for (header, count, fields) in [("POINT_DATA", number_of_vertices, vertex_fields), ("CELL_DATA", number_of_faces, face_fields)] {
+            if fields.is_empty() {
+                continue;
+            }
+            writeln!(content, "{} {}", header, count).unwrap();
+            for (name, values) in fields {
+                writeln!(content, "SCALARS {} double 1\nLOOKUP_TABLE default", name).unwrap();
+                for value in values.iter() {
+                    writeln!(content, "{}", value).unwrap();
+                }
+            }
+        }
+
+        Ok(content)
+    }
+
+    /// Writes the [Mesh] with its scalar fields to the legacy .vtk file, see
+    /// [Mesh::to_vtk_string].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.5], vec![0, 1, 2]);
+    ///
+    /// input.to_vtk("result.vtk", &[("height", &[0.0, 0.0, 0.5])], &[]).unwrap();
+    /// ```
+    pub fn to_vtk(&self, path: &str, vertex_fields: &[(&str, &[f64])], face_fields: &[(&str, &[f64])]) -> Result<(), String> {
+        let content = self.to_vtk_string(vertex_fields, face_fields)?;
+        fs::write(path, content).map_err(|e| format!("Couldn't write the .vtk file: {}", e))
+    }
+
+    /// Creates the content of the .vtu file (VTK XML `UnstructuredGrid`, ASCII) with the [Mesh]
+    /// and its scalar fields, which can be inspected in ParaView.
+    ///
+    /// `vertex_fields` are pairs of a name and values (one per vertex), `face_fields` are pairs
+    /// of a name and values (one per face).
+    ///
+    /// It returns an error if the number of values doesn't match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.5], vec![0, 1, 2]);
+    ///
+    /// let actual = input.to_vtu_string(&[("mean curvature", &[0.0, 0.1, 0.2])], &[]).unwrap();
+    ///
+    /// assert!(actual.contains("<Piece NumberOfPoints=\"3\" NumberOfCells=\"1\">"));
+    /// assert!(actual.contains("<DataArray type=\"Float64\" Name=\"mean curvature\" format=\"ascii\">0 0.1 0.2</DataArray>"));
+    /// assert!(actual.contains("<DataArray type=\"Int64\" Name=\"connectivity\" format=\"ascii\">0 1 2</DataArray>"));
+    /// ```
+    pub fn to_vtu_string(&self, vertex_fields: &[(&str, &[f64])], face_fields: &[(&str, &[f64])]) -> Result<String, String> {
+        self.check_vtk_fields(vertex_fields, face_fields)?;
+
+        let join = |values: &mut dyn Iterator<Item = String>| values.collect::<Vec<String>>().join(" ");
+        let mut content = String::new();
+
+        content.push_str("<?xml version=\"1.0\"?>\n");
+        content.push_str("<VTKFile type=\"UnstructuredGrid\" version=\"0.1\" byte_order=\"LittleEndian\">\n");
+        content.push_str("  <UnstructuredGrid>\n");
+        writeln!(content, "    <Piece NumberOfPoints=\"{}\" NumberOfCells=\"{}\">", self.get_number_of_vertices(), self.get_number_of_faces()).unwrap();
+
+        for (tag, fields) in [("PointData", vertex_fields), ("CellData", face_fields)] {
+            if fields.is_empty() {
+                continue;
+            }
+            writeln!(content, "      <{} Scalars=\"{}\">", tag, escape_xml(fields[0].0)).unwrap();
+            for (name, values) in fields {
+                writeln!(content, "        <DataArray type=\"Float64\" Name=\"{}\" format=\"ascii\">{}</DataArray>",
+                         escape_xml(name), join(&mut values.iter().map(|value| value.to_string()))).unwrap();
+            }
+            writeln!(content, "      </{}>", tag).unwrap();
+        }
+
+        content.push_str("      <Points>\n");
+        writeln!(content, "        <DataArray type=\"Float64\" NumberOfComponents=\"3\" format=\"ascii\">{}</DataArray>",
+                 join(&mut self.coordinates.iter().map(|value| value.to_string()))).unwrap();
+        content.push_str("      </Points>\n");
+
+        content.push_str("      <Cells>\n");
+        writeln!(content, "        <DataArray type=\"Int64\" Name=\"connectivity\" format=\"ascii\">{}</DataArray>",
+                 join(&mut self.indices.iter().map(|index| index.to_string()))).unwrap();
+        writeln!(content, "        <DataArray type=\"Int64\" Name=\"offsets\" format=\"ascii\">{}</DataArray>",
+                 join(&mut (1..=self.get_number_of_faces()).map(|face| (face * 3).to_string()))).unwrap();
+        writeln!(content, "        <DataArray type=\"UInt8\" Name=\"types\" format=\"ascii\">{}</DataArray>",
+                 join(&mut (0..self.get_number_of_faces()).map(|_| VTK_TRIANGLE.to_string()))).unwrap();
+        content.push_str("      </Cells>\n");
+
+        content.push_str("    </Piece>\n  </UnstructuredGrid>\n</VTKFile>\n");
+
+        Ok(content)
+    }
+
+    /// Writes the [Mesh] with its scalar fields to the .vtu file, see [Mesh::to_vtu_string].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.5], vec![0, 1, 2]);
+    ///
+    /// input.to_vtu("result.vtu", &[], &[("quality", &[0.8])]).unwrap();
+    /// ```
+    pub fn to_vtu(&self, path: &str, vertex_fields: &[(&str, &[f64])], face_fields: &[(&str, &[f64])]) -> Result<(), String> {
+        let content = self.to_vtu_string(vertex_fields, face_fields)?;
+        fs::write(path, content).map_err(|e| format!("Couldn't write the .vtu file: {}", e))
+    }
+
+    fn check_vtk_fields(&self, vertex_fields: &[(&str, &[f64])], face_fields: &[(&str, &[f64])]) -> Result<(), String> {
+        let number_of_vertices = self.get_number_of_vertices();
+        if let Some((name, values)) = vertex_fields.iter().find(|(_, values)| values.len() != number_of_vertices) {
+            return Err(format!("Vertex field '{}' has {} values, but the mesh has {} vertices", name, values.len(), number_of_vertices));
+        }
+
+        let number_of_faces = self.get_number_of_faces();
+        if let Some((name, values)) = face_fields.iter().find(|(_, values)| values.len() != number_of_faces) {
+            return Err(format!("Face field '{}' has {} values, but the mesh has {} faces", name, values.len(), number_of_faces));
+        }
+
+        Ok(())
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_square() -> Mesh {
+        Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2, 0, 2, 3])
+    }
+
+    #[test]
+    fn test_to_vtk_string_without_fields() {
+        let actual = create_square().to_vtk_string(&[], &[]).unwrap();
+
+        assert!(actual.ends_with("POLYGONS 2 8\n3 0 1 2\n3 0 2 3\n"));
+        assert!(!actual.contains("POINT_DATA"));
+    }
+
+    #[test]
+    fn test_to_vtk_string_wrong_number_of_values() {
+        let actual = create_square().to_vtk_string(&[], &[("quality", &[1.0])]);
+
+        assert_eq!(actual, Err("Face field 'quality' has 1 values, but the mesh has 2 faces".to_string()));
+    }
+
+    #[test]
+    fn test_to_vtk_string_name_with_space() {
+        let actual = create_square().to_vtk_string(&[("mean curvature", &[0.0; 4])], &[]);
+
+        assert_eq!(actual, Err("Invalid field name for the .vtk file: 'mean curvature'".to_string()));
+    }
+
+    #[test]
+    fn test_to_vtu_string_structure() {
+        let actual = create_square().to_vtu_string(&[("a<b", &[1.0, 2.0, 3.0, 4.0])], &[("q", &[0.5, 1.5]), ("r", &[2.0, 3.0])]).unwrap();
+
+        assert!(actual.contains("<PointData Scalars=\"a&lt;b\">"));
+        assert!(actual.contains("<DataArray type=\"Float64\" Name=\"r\" format=\"ascii\">2 3</DataArray>"));
+        assert!(actual.contains("Name=\"offsets\" format=\"ascii\">3 6</DataArray>"));
+        assert!(actual.contains("Name=\"types\" format=\"ascii\">5 5</DataArray>"));
+        assert!(actual.contains("NumberOfComponents=\"3\" format=\"ascii\">0 0 0 1 0 0 1 1 0 0 1 0</DataArray>"));
+        assert!(actual.find("<CellData").unwrap() < actual.find("<Points>").unwrap());
+    }
+
+    #[test]
+    fn test_to_vtu_string_wrong_number_of_values() {
+        let actual = create_square().to_vtu_string(&[("height", &[0.0])], &[]);
+
+        assert_eq!(actual, Err("Vertex field 'height' has 1 values, but the mesh has 4 vertices".to_string()));
+    }
+}
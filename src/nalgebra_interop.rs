@@ -0,0 +1,212 @@
+use nalgebra::{Isometry3, Matrix3, Matrix4, Point3, Rotation3, Translation3, UnitQuaternion, Vector3};
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::vector::Vector;
+
+impl From<Vector> for Vector3<f64> {
+    fn from(vector: Vector) -> Self {
+        Vector3::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Vector3<f64>> for Vector {
+    fn from(vector: Vector3<f64>) -> Self {
+        Vector::new(vector.x, vector.y, vector.z)
+    }
+}
+
+impl From<Point> for Point3<f64> {
+    fn from(point: Point) -> Self {
+        Point3::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<Point3<f64>> for Point {
+    fn from(point: Point3<f64>) -> Self {
+        Point::new(point.x, point.y, point.z)
+    }
+}
+
+impl From<LocalCoordinateSystem> for Isometry3<f64> {
+    fn from(local_coordinate_system: LocalCoordinateSystem) -> Self {
+        let z = local_coordinate_system.get_z();
+        let rotation_matrix = Matrix3::from_columns(&[
+            local_coordinate_system.x.into(),
+            local_coordinate_system.y.into(),
+            z.into(),
+        ]);
+        let rotation = UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(rotation_matrix));
+        let origin = local_coordinate_system.origin;
+
+        Isometry3::from_parts(Translation3::new(origin.x, origin.y, origin.z), rotation)
+    }
+}
+
+impl From<Isometry3<f64>> for LocalCoordinateSystem {
+    fn from(isometry: Isometry3<f64>) -> Self {
+        let origin = Point::new(isometry.translation.x, isometry.translation.y, isometry.translation.z);
+        let x = isometry.rotation * Vector3::x();
+        let y = isometry.rotation * Vector3::y();
+
+        LocalCoordinateSystem::new(origin, x.into(), y.into())
+    }
+}
+
+impl Mesh {
+    /// Transforms the [Mesh] using given 4x4 transformation matrix.
+    ///
+    /// Coordinates are treated as homogeneous points (with w = 1.0). `vertex_normals` are
+    /// transformed by the inverse transpose of the 3x3 part and unitized, same as in
+    /// [Mesh::get_transformed]. If the matrix mirrors the geometry (negative determinant of its
+    /// 3x3 part), faces are flipped (together with per corner `uvs`), so they keep their
+    /// outward orientation.
+    ///
+    /// It mutates given [Mesh].
+    ///
+    /// It's available with the `nalgebra` feature only.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nalgebra::{Matrix4, Vector3};
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let mut mesh = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    /// let matrix = Matrix4::new_translation(&Vector3::new(1.0, 2.0, 3.0)) * Matrix4::new_scaling(2.0);
+    ///
+    /// mesh.transform_by_matrix(&matrix);
+    ///
+    /// let expected = Mesh::new(vec![1.0, 2.0, 3.0, 21.0, 2.0, 3.0, 21.0, -28.0, 3.0], vec![0, 1, 2]);
+    ///
+    /// assert!(expected.eq_with_tolerance(&mesh, 0.0001));
+    /// ```
+    pub fn transform_by_matrix(&mut self, matrix: &Matrix4<f64>) {
+        for vertex in self.coordinates.chunks_mut(3) {
+            let transformed = matrix.transform_point(&Point3::new(vertex[0], vertex[1], vertex[2]));
+            vertex[0] = transformed.x;
+            vertex[1] = transformed.y;
+            vertex[2] = transformed.z;
+        }
+
+        let linear_part = matrix.fixed_view::<3, 3>(0, 0).into_owned();
+        if let (Some(vertex_normals), Some(inverse)) = (&mut self.vertex_normals, linear_part.try_inverse()) {
+            let normal_matrix = inverse.transpose();
+            for vertex_normal in vertex_normals.chunks_mut(3) {
+                let normal = normal_matrix * Vector3::new(vertex_normal[0], vertex_normal[1], vertex_normal[2]);
+                if let Some(normal) = normal.try_normalize(0.0) {
+                    vertex_normal.copy_from_slice(normal.as_slice());
+                }
+            }
+        }
+
+        if linear_part.determinant() < 0.0 {
+            self.flip_all_faces();
+        }
+
+        self.invalidate_cache();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::uv_channel::UvChannel;
+    use super::*;
+
+    #[test]
+    fn test_vector_round_trip() {
+        let input = Vector::new(5.231, -0.341, 11.034);
+
+        let converted: Vector3<f64> = input.into();
+        assert_eq!(converted, Vector3::new(5.231, -0.341, 11.034));
+
+        let actual: Vector = converted.into();
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_point_round_trip() {
+        let input = Point::new(5.231, -0.341, 11.034);
+
+        let converted: Point3<f64> = input.into();
+        assert_eq!(converted, Point3::new(5.231, -0.341, 11.034));
+
+        let actual: Point = converted.into();
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_local_coordinate_system_to_isometry() {
+        let input = LocalCoordinateSystem::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0), Vector::new(-1.0, 0.0, 0.0));
+
+        let actual: Isometry3<f64> = input.into();
+
+        let local_point = Point3::new(1.0, 0.0, 5.0);
+        let expected: Point3<f64> = Point::new(local_point.x, local_point.y, local_point.z).get_in_local_coordinate_system(&input).into();
+        assert!((actual * local_point - expected).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_local_coordinate_system_isometry_round_trip() {
+        let input = LocalCoordinateSystem::new(
+            Point::new(-43.836955, -22.211852, 10.0),
+            Vector::new(0.721276,0.692648,0.0),
+            Vector::new(-0.290878,0.3029,0.907547)
+        );
+
+        let isometry: Isometry3<f64> = input.into();
+        let actual: LocalCoordinateSystem = isometry.into();
+
+        assert!(input.eq_with_tolerance(&actual, 0.0001));
+    }
+
+    #[test]
+    fn test_transform_by_matrix_mirror_flips_faces() {
+        let mut mesh = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 15.0, 0.0], vec![0, 1, 2]);
+        let matrix = Matrix4::new_nonuniform_scaling(&Vector3::new(-1.0, 1.0, 1.0));
+
+        mesh.transform_by_matrix(&matrix);
+
+        let expected = Mesh::new(vec![0.0, 0.0, 0.0, -10.0, 0.0, 0.0, -10.0, 15.0, 0.0], vec![2, 1, 0]);
+        assert!(expected.eq_with_tolerance(&mesh, 0.0001));
+    }
+
+    #[test]
+    fn test_transform_by_matrix_mirror_flips_per_corner_uvs() {
+        let mut mesh = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 15.0, 0.0], vec![0, 1, 2]);
+        mesh.uvs = Some(UvChannel::PerCorner(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0]));
+
+        mesh.transform_by_matrix(&Matrix4::new_nonuniform_scaling(&Vector3::new(-1.0, 1.0, 1.0)));
+
+        assert_eq!(mesh.indices, vec![2, 1, 0]);
+        assert_eq!(mesh.uvs, Some(UvChannel::PerCorner(vec![1.0, 1.0, 1.0, 0.0, 0.0, 0.0])));
+    }
+
+    #[test]
+    fn test_transform_by_matrix_non_uniform_scaling_keeps_normals_perpendicular() {
+        // Slanted face x + y = 1 with its normal
+        let mut mesh = Mesh::new(vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0], vec![0, 1, 2]);
+        let normal = 0.5_f64.sqrt();
+        mesh.vertex_normals = Some(vec![normal, normal, 0.0, normal, normal, 0.0, normal, normal, 0.0]);
+
+        mesh.transform_by_matrix(&Matrix4::new_nonuniform_scaling(&Vector3::new(2.0, 1.0, 1.0)));
+
+        let face_normal = mesh.get_face_normal_vectors_unitized()[0];
+        let vertex_normals = mesh.vertex_normals.unwrap();
+        let vertex_normal = Vector::new(vertex_normals[0], vertex_normals[1], vertex_normals[2]);
+        assert!(face_normal.eq_with_tolerance(&vertex_normal, 1e-12));
+        assert!((vertex_normal.get_length() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_transform_by_matrix_invalidates_cache() {
+        let mut input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 15.0, 0.0], vec![0, 1, 2]);
+        assert_eq!(input.get_cached_bounding_box().max_x, 10.0);
+        assert_eq!(input.get_cached_face_normals()[0], Some(Vector::new(0.0, 0.0, 1.0)));
+
+        input.transform_by_matrix(&Matrix4::new_nonuniform_scaling(&Vector3::new(2.0, 1.0, -1.0)));
+
+        assert_eq!(input.get_cached_bounding_box().max_x, 20.0);
+        assert_eq!(input.get_cached_face_normals()[0], Some(Vector::new(0.0, 0.0, -1.0)));
+    }
+}
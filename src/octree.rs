@@ -0,0 +1,413 @@
+use crate::bounding_box::BoundingBox;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::triangle::Triangle;
+
+/// The number of items a node can keep before it gets split into 8 children.
+const MAX_ITEMS_IN_NODE: usize = 8;
+
+/// Represents an octree of [Point]s and [Triangle]s.
+///
+/// Space is recursively divided into 8 equal cubes, and every item is kept in the smallest cube
+/// fully containing it, so range and nearest neighbour queries can skip whole branches instead of
+/// checking every item. Unlike [crate::bvh::Bvh] it can be filled incrementally: the root grows
+/// automatically when items are inserted outside of it.
+///
+/// Items get ids in the order of insertion, separately for points and triangles.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::bounding_box::BoundingBox;
+/// use meshmeshmesh::octree::Octree;
+/// use meshmeshmesh::point::Point;
+///
+/// let mut octree = Octree::new();
+/// octree.insert_point(Point::new(0.0, 0.0, 0.0));
+/// octree.insert_point(Point::new(5.0, 5.0, 5.0));
+/// octree.insert_point(Point::new(-3.0, 2.0, 1.0));
+///
+/// assert_eq!(octree.get_nearest_point(&Point::new(4.0, 4.0, 4.0)), Some(1));
+/// assert_eq!(octree.get_point_ids_in_box(&BoundingBox::new(-5.0, 1.0, -5.0, 5.0, -5.0, 5.0)), vec![0, 2]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Octree {
+    /// Nodes of the tree, the root is not always the first one, as it's replaced when growing.
+    pub(crate) nodes: Vec<OctreeNode>,
+    /// Id of the root node.
+    pub(crate) root: usize,
+    /// Inserted points, in the order of insertion.
+    pub(crate) points: Vec<Point>,
+    /// Inserted triangles, in the order of insertion.
+    pub(crate) triangles: Vec<Triangle>,
+}
+
+/// Represents a single cube of the [Octree].
+#[derive(Debug, Clone)]
+pub(crate) struct OctreeNode {
+    pub(crate) bounding_box: BoundingBox,
+    /// Ids of the child nodes. The first bit of the child's index means it's in the upper half
+    /// along x, the second along y and the third along z.
+    pub(crate) children: Option<[usize; 8]>,
+    /// Items which are fully inside this node, but don't fit into any of its children.
+    pub(crate) items: Vec<OctreeItem>,
+}
+
+/// Represents an item kept in the [OctreeNode].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum OctreeItem {
+    /// Id of the point.
+    Point(usize),
+    /// Id of the triangle.
+    Triangle(usize),
+}
+
+impl Octree {
+    /// Creates an empty [Octree].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::octree::Octree;
+    ///
+    /// let actual = Octree::new();
+    ///
+    /// assert_eq!(actual.get_number_of_points(), 0);
+    /// assert_eq!(actual.get_number_of_triangles(), 0);
+    /// ```
+    pub fn new() -> Octree {
+        Octree { nodes: Vec::new(), root: 0, points: Vec::new(), triangles: Vec::new() }
+    }
+
+    /// Creates an [Octree] with given [Point]s. Their ids are their indices in the given slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::octree::Octree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let actual = Octree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 3.0)]);
+    ///
+    /// assert_eq!(actual.get_number_of_points(), 2);
+    /// assert_eq!(actual.get_nearest_point(&Point::new(1.0, 1.0, 3.0)), Some(1));
+    /// ```
+    pub fn from_points(points: &[Point]) -> Octree {
+        let mut octree = Octree::new();
+        for point in points {
+            octree.insert_point(*point);
+        }
+
+        octree
+    }
+
+    /// Creates an [Octree] with all the faces of the [Mesh] as [Triangle]s. Their ids are
+    /// the face ids.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::octree::Octree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3]
+    /// );
+    ///
+    /// let actual = Octree::from_mesh(&mesh);
+    ///
+    /// assert_eq!(actual.get_number_of_triangles(), 2);
+    /// assert_eq!(actual.get_nearest_triangle(&Point::new(12.0, 5.0, 0.0)), Some((0, Point::new(10.0, 5.0, 0.0))));
+    /// ```
+    pub fn from_mesh(mesh: &Mesh) -> Octree {
+        let mut octree = Octree::new();
+        for triangle in mesh.triangles() {
+            octree.insert_triangle(triangle);
+        }
+
+        octree
+    }
+
+    /// Inserts the [Point] and returns its id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::octree::Octree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let mut octree = Octree::new();
+    ///
+    /// assert_eq!(octree.insert_point(Point::new(0.0, 0.0, 0.0)), 0);
+    /// assert_eq!(octree.insert_point(Point::new(100.0, 0.0, 0.0)), 1);
+    /// assert_eq!(octree.get_number_of_points(), 2);
+    /// ```
+    pub fn insert_point(&mut self, point: Point) -> usize {
+        let id = self.points.len();
+        self.points.push(point);
+        self.insert_item(OctreeItem::Point(id));
+
+        id
+    }
+
+    /// Inserts the [Triangle] and returns its id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::octree::Octree;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let mut octree = Octree::new();
+    ///
+    /// let actual = octree.insert_triangle(Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0)));
+    ///
+    /// assert_eq!(actual, 0);
+    /// assert_eq!(octree.get_number_of_triangles(), 1);
+    /// ```
+    pub fn insert_triangle(&mut self, triangle: Triangle) -> usize {
+        let id = self.triangles.len();
+        self.triangles.push(triangle);
+        self.insert_item(OctreeItem::Triangle(id));
+
+        id
+    }
+
+    /// Puts the item into the smallest node fully containing it, growing the root first if
+    /// needed. Items with not finite coordinates are kept in the root.
+    fn insert_item(&mut self, item: OctreeItem) {
+        let item_box = self.get_item_bounding_box(item);
+        let is_finite = [item_box.min_x, item_box.max_x, item_box.min_y, item_box.max_y, item_box.min_z, item_box.max_z].iter().all(|coordinate| coordinate.is_finite());
+
+        if self.nodes.is_empty() {
+            let center = if is_finite { Octree::get_center(&item_box) } else { Point::new(0.0, 0.0, 0.0) };
+            let half_size = if is_finite { (item_box.max_x - item_box.min_x).max(item_box.max_y - item_box.min_y).max(item_box.max_z - item_box.min_z).max(1.0) / 2.0 } else { 0.5 };
+            self.nodes.push(OctreeNode {
+                bounding_box: BoundingBox { min_x: center.x - half_size, max_x: center.x + half_size, min_y: center.y - half_size, max_y: center.y + half_size, min_z: center.z - half_size, max_z: center.z + half_size },
+                children: None,
+                items: Vec::new(),
+            });
+            self.root = 0;
+        }
+
+        if !is_finite {
+            self.nodes[self.root].items.push(item);
+            return;
+        }
+
+        while !Octree::does_contain(&self.nodes[self.root].bounding_box, &item_box) {
+            self.grow_root(&item_box);
+        }
+
+        let mut node_id = self.root;
+        while let Some(child_id) = self.get_child_containing(node_id, &item_box) {
+            node_id = child_id;
+        }
+        self.nodes[node_id].items.push(item);
+        self.split_if_needed(node_id);
+    }
+
+    /// Replaces the root with the twice bigger one, growing towards the given Bounding Box.
+    /// Items with not finite coordinates are moved to the new root.
+    fn grow_root(&mut self, towards: &BoundingBox) {
+        let old_root = self.root;
+        let old_box = self.nodes[old_root].bounding_box;
+        let (kept_items, moved_items): (Vec<OctreeItem>, Vec<OctreeItem>) = std::mem::take(&mut self.nodes[old_root].items)
+            .into_iter()
+            .partition(|item| Octree::does_contain(&old_box, &self.get_item_bounding_box(*item)));
+        self.nodes[old_root].items = kept_items;
+        let size = old_box.max_x - old_box.min_x;
+
+        let grow_down_x = towards.min_x < old_box.min_x;
+        let grow_down_y = towards.min_y < old_box.min_y;
+        let grow_down_z = towards.min_z < old_box.min_z;
+        let new_box = BoundingBox {
+            min_x: if grow_down_x { old_box.min_x - size } else { old_box.min_x },
+            max_x: if grow_down_x { old_box.max_x } else { old_box.max_x + size },
+            min_y: if grow_down_y { old_box.min_y - size } else { old_box.min_y },
+            max_y: if grow_down_y { old_box.max_y } else { old_box.max_y + size },
+            min_z: if grow_down_z { old_box.min_z - size } else { old_box.min_z },
+            max_z: if grow_down_z { old_box.max_z } else { old_box.max_z + size },
+        };
+        let old_root_octant = (grow_down_x as usize) | (grow_down_y as usize) << 1 | (grow_down_z as usize) << 2;
+
+        let mut children = [0; 8];
+        for (octant, child) in children.iter_mut().enumerate() {
+            if octant == old_root_octant {
+                *child = old_root;
+            }
+            else {
+                *child = self.nodes.len();
+                self.nodes.push(OctreeNode { bounding_box: Octree::get_octant_bounding_box(&new_box, octant), children: None, items: Vec::new() });
+            }
+        }
+
+        self.root = self.nodes.len();
+        self.nodes.push(OctreeNode { bounding_box: new_box, children: Some(children), items: moved_items });
+    }
+
+    /// Splits the leaf node into 8 children if it has too many items, and moves there
+    /// the items which fit into them. Children are split recursively if needed.
+    fn split_if_needed(&mut self, node_id: usize) {
+        let node_box = self.nodes[node_id].bounding_box;
+        let size = node_box.max_x - node_box.min_x;
+        let largest_coordinate = [node_box.min_x, node_box.max_x, node_box.min_y, node_box.max_y, node_box.min_z, node_box.max_z].iter().fold(1.0, |a: f64, b| a.max(b.abs()));
+        if self.nodes[node_id].children.is_some() || self.nodes[node_id].items.len() <= MAX_ITEMS_IN_NODE || size <= largest_coordinate * 1e-9 {
+            return;
+        }
+
+        let mut children = [0; 8];
+        for (octant, child) in children.iter_mut().enumerate() {
+            *child = self.nodes.len();
+            self.nodes.push(OctreeNode { bounding_box: Octree::get_octant_bounding_box(&node_box, octant), children: None, items: Vec::new() });
+        }
+        self.nodes[node_id].children = Some(children);
+
+        let items = std::mem::take(&mut self.nodes[node_id].items);
+        for item in items {
+            let item_box = self.get_item_bounding_box(item);
+            match self.get_child_containing(node_id, &item_box) {
+                Some(child_id) => self.nodes[child_id].items.push(item),
+                None => self.nodes[node_id].items.push(item),
+            }
+        }
+
+        for child_id in children {
+            self.split_if_needed(child_id);
+        }
+    }
+
+    /// Gets the id of the child of the node which fully contains the Bounding Box. `None` is
+    /// returned if the node has no children, or the Bounding Box doesn't fit into any of them.
+    fn get_child_containing(&self, node_id: usize, bounding_box: &BoundingBox) -> Option<usize> {
+        let children = self.nodes[node_id].children?;
+        let center = Octree::get_center(&self.nodes[node_id].bounding_box);
+        let octant = (bounding_box.min_x >= center.x) as usize | ((bounding_box.min_y >= center.y) as usize) << 1 | ((bounding_box.min_z >= center.z) as usize) << 2;
+        let child_id = children[octant];
+
+        if Octree::does_contain(&self.nodes[child_id].bounding_box, bounding_box) {
+            Some(child_id)
+        }
+        else {
+            None
+        }
+    }
+
+    pub(crate) fn get_item_bounding_box(&self, item: OctreeItem) -> BoundingBox {
+        match item {
+            OctreeItem::Point(id) => {
+                let point = self.points[id];
+                BoundingBox { min_x: point.x, max_x: point.x, min_y: point.y, max_y: point.y, min_z: point.z, max_z: point.z }
+            },
+            OctreeItem::Triangle(id) => {
+                let triangle = &self.triangles[id];
+                let points = [triangle.first_point, triangle.second_point, triangle.third_point];
+                BoundingBox {
+                    min_x: points.iter().map(|point| point.x).fold(f64::INFINITY, f64::min),
+                    max_x: points.iter().map(|point| point.x).fold(f64::NEG_INFINITY, f64::max),
+                    min_y: points.iter().map(|point| point.y).fold(f64::INFINITY, f64::min),
+                    max_y: points.iter().map(|point| point.y).fold(f64::NEG_INFINITY, f64::max),
+                    min_z: points.iter().map(|point| point.z).fold(f64::INFINITY, f64::min),
+                    max_z: points.iter().map(|point| point.z).fold(f64::NEG_INFINITY, f64::max),
+                }
+            },
+        }
+    }
+
+    fn get_octant_bounding_box(bounding_box: &BoundingBox, octant: usize) -> BoundingBox {
+        let center = Octree::get_center(bounding_box);
+
+        BoundingBox {
+            min_x: if octant & 1 != 0 { center.x } else { bounding_box.min_x },
+            max_x: if octant & 1 != 0 { bounding_box.max_x } else { center.x },
+            min_y: if octant & 2 != 0 { center.y } else { bounding_box.min_y },
+            max_y: if octant & 2 != 0 { bounding_box.max_y } else { center.y },
+            min_z: if octant & 4 != 0 { center.z } else { bounding_box.min_z },
+            max_z: if octant & 4 != 0 { bounding_box.max_z } else { center.z },
+        }
+    }
+
+    fn get_center(bounding_box: &BoundingBox) -> Point {
+        Point::new(
+            (bounding_box.min_x + bounding_box.max_x) / 2.0,
+            (bounding_box.min_y + bounding_box.max_y) / 2.0,
+            (bounding_box.min_z + bounding_box.max_z) / 2.0,
+        )
+    }
+
+    fn does_contain(outer: &BoundingBox, inner: &BoundingBox) -> bool {
+        outer.min_x <= inner.min_x && inner.max_x <= outer.max_x &&
+            outer.min_y <= inner.min_y && inner.max_y <= outer.max_y &&
+            outer.min_z <= inner.min_z && inner.max_z <= outer.max_z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_point_every_item_inside_its_node() {
+        let mut octree = Octree::new();
+        for i in 0..200 {
+            let x = ((i * 37) % 101) as f64 - 50.0;
+            let y = ((i * 53) % 89) as f64 * 0.5;
+            let z = ((i * 11) % 7) as f64 * -3.0;
+            octree.insert_point(Point::new(x, y, z));
+        }
+
+        let mut ids_in_nodes = Vec::new();
+        for node in &octree.nodes {
+            if node.children.is_none() {
+                assert!(node.items.len() <= MAX_ITEMS_IN_NODE);
+            }
+            for item in &node.items {
+                assert!(Octree::does_contain(&node.bounding_box, &octree.get_item_bounding_box(*item)));
+                if let OctreeItem::Point(id) = item {
+                    ids_in_nodes.push(*id);
+                }
+            }
+        }
+        ids_in_nodes.sort();
+        assert_eq!(ids_in_nodes, (0..200).collect::<Vec<usize>>());
+        assert!(Octree::does_contain(&octree.nodes[octree.root].bounding_box, &BoundingBox::new(-50.0, 50.0, 0.0, 44.0, -18.0, 0.0)));
+    }
+
+    #[test]
+    fn test_insert_point_many_duplicates() {
+        let mut octree = Octree::new();
+        for _ in 0..100 {
+            octree.insert_point(Point::new(1.0, 2.0, 3.0));
+        }
+
+        assert_eq!(octree.get_number_of_points(), 100);
+        let number_of_items: usize = octree.nodes.iter().map(|node| node.items.len()).sum();
+        assert_eq!(number_of_items, 100);
+    }
+
+    #[test]
+    fn test_insert_point_not_finite() {
+        let mut octree = Octree::new();
+        octree.insert_point(Point::new(f64::NAN, 0.0, 0.0));
+        octree.insert_point(Point::new(1.0, 1.0, 1.0));
+
+        assert_eq!(octree.nodes[octree.root].items, vec![OctreeItem::Point(0)]);
+        assert_eq!(octree.get_point_ids_in_box(&BoundingBox::new(0.0, 2.0, 0.0, 2.0, 0.0, 2.0)), vec![1]);
+    }
+
+    #[test]
+    fn test_grow_root_keeps_old_root_as_child() {
+        let mut octree = Octree::new();
+        octree.insert_point(Point::new(0.0, 0.0, 0.0));
+        let old_root = octree.root;
+        octree.insert_point(Point::new(-0.7, 0.2, 0.8));
+
+        assert_ne!(octree.root, old_root);
+        let root = &octree.nodes[octree.root];
+        assert_eq!(root.bounding_box, BoundingBox::new(-1.5, 0.5, -0.5, 1.5, -0.5, 1.5));
+        assert_eq!(root.children.unwrap()[1], old_root);
+    }
+}
@@ -0,0 +1,388 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use crate::bounding_box::BoundingBox;
+use crate::octree::{Octree, OctreeItem};
+use crate::point::Point;
+
+/// Candidate node of the nearest neighbour search with the distance to its Bounding Box.
+struct NodeVisit {
+    distance: f64,
+    node: usize,
+}
+
+impl PartialEq for NodeVisit {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+impl Eq for NodeVisit {}
+
+impl PartialOrd for NodeVisit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for NodeVisit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, so the BinaryHeap pops the closest node first.
+        other.distance.total_cmp(&self.distance)
+    }
+}
+
+impl Octree {
+    /// Returns the number of inserted [Point]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::octree::Octree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = Octree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)]);
+    ///
+    /// assert_eq!(input.get_number_of_points(), 2);
+    /// ```
+    pub fn get_number_of_points(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Returns the number of inserted [crate::triangle::Triangle]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::octree::Octree;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let input = Octree::from_mesh(&mesh);
+    ///
+    /// assert_eq!(input.get_number_of_triangles(), 1);
+    /// ```
+    pub fn get_number_of_triangles(&self) -> usize {
+        self.triangles.len()
+    }
+
+    /// Gets ids of [Point]s inside the [BoundingBox] (including its sides), in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::octree::Octree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = Octree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0)]);
+    ///
+    /// let actual = input.get_point_ids_in_box(&BoundingBox::new(0.5, 2.0, -1.0, 1.0, -1.0, 1.0));
+    ///
+    /// assert_eq!(actual, vec![1, 2]);
+    /// ```
+    pub fn get_point_ids_in_box(&self, bounding_box: &BoundingBox) -> Vec<usize> {
+        let mut point_ids = Vec::new();
+        self.visit_items(|node_box| Octree::do_boxes_overlap(node_box, bounding_box), |item| {
+            if let OctreeItem::Point(id) = item {
+                let point = &self.points[id];
+                if bounding_box.min_x <= point.x && point.x <= bounding_box.max_x &&
+                    bounding_box.min_y <= point.y && point.y <= bounding_box.max_y &&
+                    bounding_box.min_z <= point.z && point.z <= bounding_box.max_z {
+                    point_ids.push(id);
+                }
+            }
+        });
+        point_ids.sort();
+
+        point_ids
+    }
+
+    /// Gets ids of [Point]s within the `distance` from the `center` (including the ones exactly
+    /// at the `distance`), in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::octree::Octree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = Octree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 1.0, 1.0), Point::new(0.0, 0.0, 3.0)]);
+    ///
+    /// let actual = input.get_point_ids_within_distance(&Point::new(0.0, 0.0, 1.0), 2.0);
+    ///
+    /// assert_eq!(actual, vec![0, 1, 2]);
+    /// assert_eq!(input.get_point_ids_within_distance(&Point::new(0.0, 0.0, 0.0), 1.5), vec![0]);
+    /// ```
+    pub fn get_point_ids_within_distance(&self, center: &Point, distance: f64) -> Vec<usize> {
+        let mut point_ids = Vec::new();
+        self.visit_items(|node_box| Octree::get_distance_to_box(center, node_box) <= distance, |item| {
+            if let OctreeItem::Point(id) = item {
+                if self.points[id].get_distance_to_point(center) <= distance {
+                    point_ids.push(id);
+                }
+            }
+        });
+        point_ids.sort();
+
+        point_ids
+    }
+
+    /// Gets ids of [crate::triangle::Triangle]s which Bounding Boxes overlap with the given
+    /// [BoundingBox], in ascending order.
+    ///
+    /// It's a broad phase check: Triangle itself doesn't have to intersect the Bounding Box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::octree::Octree;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+    ///          5.0, 0.0, 0.0, 6.0, 0.0, 0.0, 5.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    /// let input = Octree::from_mesh(&mesh);
+    ///
+    /// let actual = input.get_triangle_ids_in_box(&BoundingBox::new(4.0, 5.5, -1.0, 1.0, -1.0, 1.0));
+    ///
+    /// assert_eq!(actual, vec![1]);
+    /// ```
+    pub fn get_triangle_ids_in_box(&self, bounding_box: &BoundingBox) -> Vec<usize> {
+        let mut triangle_ids = Vec::new();
+        self.visit_items(|node_box| Octree::do_boxes_overlap(node_box, bounding_box), |item| {
+            if let OctreeItem::Triangle(id) = item {
+                if Octree::do_boxes_overlap(&self.get_item_bounding_box(item), bounding_box) {
+                    triangle_ids.push(id);
+                }
+            }
+        });
+        triangle_ids.sort();
+
+        triangle_ids
+    }
+
+    /// Gets the id of the [Point] closest to the given one. If there are several equally close
+    /// Points, the one with the smallest id is returned.
+    ///
+    /// `None` is returned if there are no Points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::octree::Octree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let input = Octree::from_points(&[Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0)]);
+    ///
+    /// assert_eq!(input.get_nearest_point(&Point::new(6.0, 1.0, 0.0)), Some(1));
+    /// assert_eq!(Octree::new().get_nearest_point(&Point::new(6.0, 1.0, 0.0)), None);
+    /// ```
+    pub fn get_nearest_point(&self, point: &Point) -> Option<usize> {
+        let mut nearest: Option<(usize, f64)> = None;
+        self.visit_nearest_items(point, |item| {
+            if let OctreeItem::Point(id) = item {
+                let distance = self.points[id].get_distance_to_point(point);
+                if Octree::is_closer(id, distance, nearest) {
+                    nearest = Some((id, distance));
+                }
+            }
+            nearest.map_or(f64::INFINITY, |(_, distance)| distance)
+        });
+
+        nearest.map(|(id, _)| id)
+    }
+
+    /// Gets the id of the [crate::triangle::Triangle] closest to the given [Point], and the
+    /// closest Point on it. If there are several equally close Triangles, the one with
+    /// the smallest id is returned.
+    ///
+    /// `None` is returned if there are no Triangles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::octree::Octree;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+    ///          5.0, 0.0, 0.0, 6.0, 0.0, 0.0, 5.0, 1.0, 0.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    /// let input = Octree::from_mesh(&mesh);
+    ///
+    /// let (actual_id, actual_point) = input.get_nearest_triangle(&Point::new(4.0, 0.5, 2.0)).unwrap();
+    ///
+    /// assert_eq!(actual_id, 1);
+    /// assert!(actual_point.get_distance_to_point(&Point::new(5.0, 0.5, 0.0)) < 1e-12);
+    /// ```
+    pub fn get_nearest_triangle(&self, point: &Point) -> Option<(usize, Point)> {
+        let mut nearest: Option<(usize, f64)> = None;
+        let mut nearest_point = *point;
+        self.visit_nearest_items(point, |item| {
+            if let OctreeItem::Triangle(id) = item {
+                let closest_point = self.triangles[id].get_closest_point(point);
+                let distance = closest_point.get_distance_to_point(point);
+                if Octree::is_closer(id, distance, nearest) {
+                    nearest = Some((id, distance));
+                    nearest_point = closest_point;
+                }
+            }
+            nearest.map_or(f64::INFINITY, |(_, distance)| distance)
+        });
+
+        nearest.map(|(id, _)| (id, nearest_point))
+    }
+
+    /// Calls `on_item` for all the items in nodes accepted by `accept_box`. The root is always
+    /// visited, as it also keeps the items with not finite coordinates.
+    fn visit_items(&self, accept_box: impl Fn(&BoundingBox) -> bool, mut on_item: impl FnMut(OctreeItem)) {
+        if self.nodes.is_empty() {
+            return;
+        }
+
+        let mut stack = vec![self.root];
+        while let Some(node_id) = stack.pop() {
+            let node = &self.nodes[node_id];
+            if node_id != self.root && !accept_box(&node.bounding_box) {
+                continue;
+            }
+            for item in &node.items {
+                on_item(*item);
+            }
+            if let Some(children) = node.children {
+                stack.extend_from_slice(&children);
+            }
+        }
+    }
+
+    /// Calls `on_item` for items in nodes in order of their distance from the `point`, until
+    /// the next node is further than the distance returned by `on_item`.
+    fn visit_nearest_items(&self, point: &Point, mut on_item: impl FnMut(OctreeItem) -> f64) {
+        if self.nodes.is_empty() {
+            return;
+        }
+
+        let mut best_distance = f64::INFINITY;
+        let mut heap = BinaryHeap::new();
+        heap.push(NodeVisit { distance: 0.0, node: self.root });
+        while let Some(NodeVisit { distance, node }) = heap.pop() {
+            if distance > best_distance {
+                break;
+            }
+            for item in &self.nodes[node].items {
+                best_distance = on_item(*item);
+            }
+            if let Some(children) = self.nodes[node].children {
+                for child in children {
+                    heap.push(NodeVisit { distance: Octree::get_distance_to_box(point, &self.nodes[child].bounding_box), node: child });
+                }
+            }
+        }
+    }
+
+    /// Checks if the item with given `id` and `distance` is closer than the current nearest
+    /// one, preferring smaller ids for equal distances. Items with NaN distance are skipped.
+    fn is_closer(id: usize, distance: f64, nearest: Option<(usize, f64)>) -> bool {
+        if distance.is_nan() {
+            return false;
+        }
+        match nearest {
+            None => true,
+            Some((nearest_id, nearest_distance)) => distance < nearest_distance || (distance == nearest_distance && id < nearest_id),
+        }
+    }
+
+    fn get_distance_to_box(point: &Point, bounding_box: &BoundingBox) -> f64 {
+        let dx = (bounding_box.min_x - point.x).max(point.x - bounding_box.max_x).max(0.0);
+        let dy = (bounding_box.min_y - point.y).max(point.y - bounding_box.max_y).max(0.0);
+        let dz = (bounding_box.min_z - point.z).max(point.z - bounding_box.max_z).max(0.0);
+
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    fn do_boxes_overlap(a: &BoundingBox, b: &BoundingBox) -> bool {
+        a.min_x <= b.max_x && b.min_x <= a.max_x &&
+            a.min_y <= b.max_y && b.min_y <= a.max_y &&
+            a.min_z <= b.max_z && b.min_z <= a.max_z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::Mesh;
+
+    fn get_scattered_points(number_of_points: usize) -> Vec<Point> {
+        (0..number_of_points).map(|i| Point::new(
+            ((i * 37) % 101) as f64 * 0.3 - 15.0,
+            ((i * 53) % 89) as f64 * 0.5,
+            ((i * 11) % 17) as f64 * -1.1,
+        )).collect()
+    }
+
+    #[test]
+    fn test_point_queries_same_as_brute_force() {
+        let points = get_scattered_points(500);
+        let input = Octree::from_points(&points);
+
+        for query in get_scattered_points(60).iter().map(|point| Point::new(point.x * 1.3 + 0.17, point.y - 3.1, point.z + 0.4)) {
+            let expected_nearest = (0..points.len()).fold(0, |best, i| if points[i].get_distance_to_point(&query) < points[best].get_distance_to_point(&query) { i } else { best });
+            assert_eq!(input.get_nearest_point(&query), Some(expected_nearest));
+
+            let expected_within: Vec<usize> = (0..points.len()).filter(|i| points[*i].get_distance_to_point(&query) <= 4.0).collect();
+            assert_eq!(input.get_point_ids_within_distance(&query, 4.0), expected_within);
+
+            let bounding_box = BoundingBox::new(query.x - 2.0, query.x + 3.0, query.y - 5.0, query.y + 1.0, query.z - 2.0, query.z + 2.0);
+            let expected_in_box: Vec<usize> = (0..points.len()).filter(|i| {
+                let point = &points[*i];
+                point.x >= bounding_box.min_x && point.x <= bounding_box.max_x && point.y >= bounding_box.min_y && point.y <= bounding_box.max_y && point.z >= bounding_box.min_z && point.z <= bounding_box.max_z
+            }).collect();
+            assert_eq!(input.get_point_ids_in_box(&bounding_box), expected_in_box);
+        }
+    }
+
+    #[test]
+    fn test_get_nearest_triangle_same_as_brute_force() {
+        let mut coordinates = Vec::new();
+        let mut indices = Vec::new();
+        for (i, point) in get_scattered_points(150).iter().enumerate() {
+            coordinates.extend_from_slice(&[point.x, point.y, point.z, point.x + 0.8, point.y, point.z + 0.3, point.x, point.y + 1.2, point.z - 0.5]);
+            indices.extend_from_slice(&[i * 3, i * 3 + 1, i * 3 + 2]);
+        }
+        let mesh = Mesh::new(coordinates, indices);
+        let triangles = mesh.to_triangles();
+        let input = Octree::from_mesh(&mesh);
+
+        for query in get_scattered_points(40).iter().map(|point| Point::new(point.x + 0.31, point.y * 0.9, point.z + 1.7)) {
+            let distances: Vec<f64> = triangles.iter().map(|triangle| triangle.get_closest_point(&query).get_distance_to_point(&query)).collect();
+            let expected_id = (0..distances.len()).fold(0, |best, i| if distances[i] < distances[best] { i } else { best });
+
+            let (actual_id, actual_point) = input.get_nearest_triangle(&query).unwrap();
+
+            assert_eq!(actual_id, expected_id);
+            assert_eq!(actual_point, triangles[expected_id].get_closest_point(&query));
+        }
+    }
+
+    #[test]
+    fn test_queries_empty() {
+        let input = Octree::new();
+
+        assert_eq!(input.get_nearest_point(&Point::new(0.0, 0.0, 0.0)), None);
+        assert_eq!(input.get_nearest_triangle(&Point::new(0.0, 0.0, 0.0)), None);
+        assert!(input.get_point_ids_in_box(&BoundingBox::new(-1.0, 1.0, -1.0, 1.0, -1.0, 1.0)).is_empty());
+        assert!(input.get_point_ids_within_distance(&Point::new(0.0, 0.0, 0.0), 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_get_nearest_point_equal_distances_smallest_id() {
+        let input = Octree::from_points(&[Point::new(2.0, 0.0, 0.0), Point::new(-2.0, 0.0, 0.0), Point::new(0.0, 2.0, 0.0)]);
+
+        assert_eq!(input.get_nearest_point(&Point::new(0.0, 0.0, 0.0)), Some(0));
+    }
+}
@@ -0,0 +1,68 @@
+/// Represents the result of the validation of an offset [Mesh](crate::mesh::Mesh).
+///
+/// It's created by [Mesh::validate_offset](crate::mesh::Mesh::validate_offset).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::offset_validation_report::OffsetValidationReport;
+///
+/// let report = OffsetValidationReport {
+///     minimum_distance: 0.99,
+///     maximum_distance: 1.01,
+///     vertex_ids_out_of_bounds: vec![],
+///     face_ids_out_of_bounds: vec![],
+///     self_intersecting_face_pairs: vec![],
+/// };
+///
+/// assert!(report.is_valid());
+/// ```
+#[derive(Debug, Clone)]
+pub struct OffsetValidationReport {
+    /// The smallest measured distance from the offset Mesh to the original one.
+    pub minimum_distance: f64,
+    /// The biggest measured distance from the offset Mesh to the original one.
+    pub maximum_distance: f64,
+    /// Ids of vertices of the offset Mesh, which are too close or too far from the original one.
+    pub vertex_ids_out_of_bounds: Vec<usize>,
+    /// Ids of faces of the offset Mesh, which centroids are too close or too far from the original one.
+    pub face_ids_out_of_bounds: Vec<usize>,
+    /// Pairs of ids of faces of the offset Mesh, which intersect each other.
+    pub self_intersecting_face_pairs: Vec<(usize, usize)>,
+}
+
+impl PartialEq for OffsetValidationReport {
+    fn eq(&self, other: &Self) -> bool {
+        self.minimum_distance == other.minimum_distance &&
+            self.maximum_distance == other.maximum_distance &&
+            self.vertex_ids_out_of_bounds == other.vertex_ids_out_of_bounds &&
+            self.face_ids_out_of_bounds == other.face_ids_out_of_bounds &&
+            self.self_intersecting_face_pairs == other.self_intersecting_face_pairs
+    }
+}
+
+impl OffsetValidationReport {
+    /// Checks if the offset passed the validation: it stays within the distance bounds and
+    /// has no self-intersections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::offset_validation_report::OffsetValidationReport;
+    ///
+    /// let report = OffsetValidationReport {
+    ///     minimum_distance: 0.2,
+    ///     maximum_distance: 1.01,
+    ///     vertex_ids_out_of_bounds: vec![3],
+    ///     face_ids_out_of_bounds: vec![],
+    ///     self_intersecting_face_pairs: vec![(0, 5)],
+    /// };
+    ///
+    /// assert!(!report.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.vertex_ids_out_of_bounds.is_empty() &&
+            self.face_ids_out_of_bounds.is_empty() &&
+            self.self_intersecting_face_pairs.is_empty()
+    }
+}
@@ -0,0 +1,94 @@
+use crate::random::Random;
+
+/// Improved Perlin (gradient) noise in three dimensions, with the permutation shuffled by the
+/// seed, so the same seed always gives the same noise.
+pub(crate) struct PerlinNoise {
+    permutation: [usize; 512],
+}
+
+impl PerlinNoise {
+    pub(crate) fn new(seed: u64) -> PerlinNoise {
+        let mut shuffled: Vec<usize> = (0..256).collect();
+        let mut random = Random::new(seed);
+        for i in (1..256).rev() {
+            shuffled.swap(i, random.next_index(i + 1));
+        }
+        let mut permutation = [0; 512];
+        for (i, value) in permutation.iter_mut().enumerate() {
+            *value = shuffled[i % 256];
+        }
+
+        PerlinNoise { permutation }
+    }
+
+    /// Returns the noise value (in the range [-1.0, 1.0]) at the given position. Values at
+    /// integer positions are 0.0, and the noise changes smoothly between them.
+    pub(crate) fn get_value(&self, x: f64, y: f64, z: f64) -> f64 {
+        let (cell_x, cell_y, cell_z) = (x.floor(), y.floor(), z.floor());
+        let (x, y, z) = (x - cell_x, y - cell_y, z - cell_z);
+        let (i, j, k) = ((cell_x as i64 & 255) as usize, (cell_y as i64 & 255) as usize, (cell_z as i64 & 255) as usize);
+        let (u, v, w) = (fade(x), fade(y), fade(z));
+        let p = &self.permutation;
+
+        let a = p[i] + j;
+        let (aa, ab) = (p[a] + k, p[a + 1] + k);
+        let b = p[i + 1] + j;
+        let (ba, bb) = (p[b] + k, p[b + 1] + k);
+
+        let value = lerp(w,
+            lerp(v,
+                lerp(u, gradient(p[aa], x, y, z), gradient(p[ba], x - 1.0, y, z)),
+                lerp(u, gradient(p[ab], x, y - 1.0, z), gradient(p[bb], x - 1.0, y - 1.0, z))),
+            lerp(v,
+                lerp(u, gradient(p[aa + 1], x, y, z - 1.0), gradient(p[ba + 1], x - 1.0, y, z - 1.0)),
+                lerp(u, gradient(p[ab + 1], x, y - 1.0, z - 1.0), gradient(p[bb + 1], x - 1.0, y - 1.0, z - 1.0))));
+
+        // Corners of the range are reachable only in theory, but may be slightly exceeded.
+        value.clamp(-1.0, 1.0)
+    }
+}
+
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    a + t * (b - a)
+}
+
+/// Dot product of the offset with one of 12 gradients pointing at edges of a cube, picked by
+/// the hash.
+fn gradient(hash: usize, x: f64, y: f64, z: f64) -> f64 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 { y } else if h == 12 || h == 14 { x } else { z };
+
+    (if h & 1 == 0 { u } else { -u }) + (if h & 2 == 0 { v } else { -v })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_value_zero_at_integer_positions() {
+        let noise = PerlinNoise::new(3);
+
+        assert_eq!(noise.get_value(2.0, -5.0, 7.0), 0.0);
+    }
+
+    #[test]
+    fn test_get_value_in_range_and_deterministic() {
+        let noise = PerlinNoise::new(11);
+        let other = PerlinNoise::new(11);
+        let mut any_different_from_zero = false;
+        for i in 0..500 {
+            let (x, y, z) = (i as f64 * 0.137, i as f64 * -0.071, i as f64 * 0.029);
+            let value = noise.get_value(x, y, z);
+            assert!((-1.0..=1.0).contains(&value));
+            assert_eq!(value, other.get_value(x, y, z));
+            any_different_from_zero |= value.abs() > 0.1;
+        }
+        assert!(any_different_from_zero);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::point::Point;
+use crate::vector::Vector;
+
+/// Represents an infinite Plane in three-dimensional space.
+///
+/// It is described by the origin [Point] lying on the [Plane] and the normal [Vector].
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::plane::Plane;
+/// use meshmeshmesh::point::Point;
+/// use meshmeshmesh::vector::Vector;
+///
+/// let result = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(0.0, 0.0, 5.0));
+///
+/// assert_eq!(result.origin, Point::new(0.0, 1.0, -2.5));
+/// assert_eq!(result.normal, Vector::new(0.0, 0.0, 1.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Plane {
+    /// The origin [Point] lying on the [Plane].
+    pub origin: Point,
+
+    /// The normal [Vector] of the [Plane]. Should be an unitized Vector.
+    pub normal: Vector,
+}
+
+impl PartialEq for Plane {
+    fn eq(&self, other: &Self) -> bool {
+
+        if self.origin != other.origin {
+            return false;
+        }
+
+        if self.normal != other.normal {
+            return false;
+        }
+
+        true
+    }
+}
+
+impl Plane {
+    /// Creates a new [Plane].
+    ///
+    /// New created [Plane] will have a `normal` unitized automatically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let result = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(3.0, 0.0, 0.0));
+    ///
+    /// assert_eq!(result.origin.eq(&Point::new(0.0, 1.0, -2.5)), true);
+    /// assert_eq!(result.normal.eq(&Vector::new(1.0, 0.0, 0.0)), true);
+    /// ```
+    pub fn new(origin: Point, normal: Vector) -> Plane {
+        Plane {origin, normal: normal.get_unitized()}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let result = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(0.0, -2.0, 0.0));
+
+        assert!(result.origin.eq(&Point::new(0.0, 1.0, -2.5)));
+        assert!(result.normal.eq(&Vector::new(0.0, -1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_partialeq_true() {
+        let a = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(1.0, 0.0, 0.0));
+        let b = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(1.0, 0.0, 0.0));
+        assert!(a.eq(&b));
+        assert!(b.eq(&a));
+    }
+
+    #[test]
+    fn test_partialeq_different_origin_false() {
+        let a = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(1.0, 0.0, 0.0));
+        let b = Plane::new(Point::new(0.0, 1.1, -2.5), Vector::new(1.0, 0.0, 0.0));
+        assert!(!a.eq(&b));
+        assert!(!b.eq(&a));
+    }
+
+    #[test]
+    fn test_partialeq_different_normal_false() {
+        let a = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(1.0, 0.0, 0.0));
+        let b = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(0.0, 1.0, 0.0));
+        assert!(!a.eq(&b));
+        assert!(!b.eq(&a));
+    }
+}
@@ -0,0 +1,246 @@
+use crate::plane::Plane;
+use crate::point::Point;
+use crate::vector::Vector;
+
+impl Plane {
+    /// Compares given [Plane] to other one, but with a `f64` tolerance.
+    ///
+    /// If any value absolute difference is > tolerance, then it should return `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let tolerance: f64 = 0.001;
+    /// let a = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(0.0, 0.0, 1.0));
+    /// let b = Plane::new(Point::new(0.0, 1.0 + 0.001, -2.5), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(a.eq_with_tolerance(&b, tolerance), true);
+    /// ```
+    pub fn eq_with_tolerance(&self, other: &Plane, tolerance: f64) -> bool {
+        if !self.origin.eq_with_tolerance(&other.origin, tolerance) {
+            return false;
+        }
+
+        if !self.normal.eq_with_tolerance(&other.normal, tolerance) {
+            return false;
+        }
+
+        true
+    }
+
+    /// Calculates the signed distance from the [Plane] to given [Point].
+    ///
+    /// The distance is positive if the [Point] is on the side pointed by the normal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let plane = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(plane.get_signed_distance_to_point(&Point::new(5.0, 3.0, 1.5)), 4.0);
+    /// assert_eq!(plane.get_signed_distance_to_point(&Point::new(5.0, 3.0, -3.5)), -1.0);
+    /// ```
+    pub fn get_signed_distance_to_point(&self, point: &Point) -> f64 {
+        self.normal.get_dot_product(&Vector::from_2_points(&self.origin, point))
+    }
+
+    /// Calculates the closest [Point] on the [Plane] to given [Point] (its projection).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let plane = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// let actual = plane.get_closest_point(&Point::new(5.0, 3.0, 1.5));
+    ///
+    /// assert_eq!(actual, Point::new(5.0, 3.0, -2.5));
+    /// ```
+    pub fn get_closest_point(&self, point: &Point) -> Point {
+        *point - self.normal * self.get_signed_distance_to_point(point)
+    }
+
+    /// Creates the best-fit [Plane] for given [Point]s using the least squares method.
+    ///
+    /// The origin of the result is the centroid of given [Point]s, the normal is the direction
+    /// of the smallest spread of [Point]s. The orientation of the normal is arbitrary.
+    ///
+    /// It panics if there are less than 3 [Point]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::plane::Plane;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = vec![
+    ///     Point::new(0.0, 0.0, 0.1),
+    ///     Point::new(10.0, 0.0, -0.1),
+    ///     Point::new(10.0, 10.0, 0.1),
+    ///     Point::new(0.0, 10.0, -0.1),
+    /// ];
+    ///
+    /// let actual = Plane::new_best_fit(&input);
+    ///
+    /// assert!(actual.origin.eq_with_tolerance(&Point::new(5.0, 5.0, 0.0), 0.0001));
+    /// assert!(actual.normal.get_cross_product(&Vector::new(0.0, 0.0, 1.0)).get_length() < 0.0001);
+    /// ```
+    pub fn new_best_fit(points: &[Point]) -> Plane {
+        if points.len() < 3 {
+            panic!("Cannot fit the Plane to less than 3 Points");
+        }
+
+        let number_of_points = points.len() as f64;
+        let mut centroid = Vector::zero();
+        for point in points {
+            centroid = centroid + point.to_vector();
+        }
+        centroid = centroid * (1.0 / number_of_points);
+        let origin = centroid.to_point();
+
+        let mut covariance = [[0.0; 3]; 3];
+        for point in points {
+            let d = [point.x - origin.x, point.y - origin.y, point.z - origin.z];
+            for (row, covariance_row) in covariance.iter_mut().enumerate() {
+                for (column, value) in covariance_row.iter_mut().enumerate() {
+                    *value += d[row] * d[column];
+                }
+            }
+        }
+
+        Plane::new(origin, Self::get_smallest_eigenvector(covariance))
+    }
+
+    /// Gets the eigenvector of the symmetric 3x3 matrix with the smallest eigenvalue.
+    fn get_smallest_eigenvector(matrix: [[f64; 3]; 3]) -> Vector {
+        let (eigenvalues, v) = get_symmetric_eigen_decomposition(matrix);
+
+        let mut smallest = 0;
+        for i in 1..3 {
+            if eigenvalues[i] < eigenvalues[smallest] {
+                smallest = i;
+            }
+        }
+
+        Vector::new(v[0][smallest], v[1][smallest], v[2][smallest])
+    }
+}
+
+/// Gets eigenvalues and eigenvectors (as columns) of the symmetric 3x3 matrix using the Jacobi
+/// eigenvalue algorithm.
+pub(crate) fn get_symmetric_eigen_decomposition(matrix: [[f64; 3]; 3]) -> ([f64; 3], [[f64; 3]; 3]) {
+    let mut a = matrix;
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+    for _ in 0..50 {
+        let off_diagonal = a[0][1].abs() + a[0][2].abs() + a[1][2].abs();
+        if off_diagonal < 1e-15 {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q].abs() < 1e-300 {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+
+            for row in a.iter_mut() { // A = A * J
+                let akp = row[p];
+                let akq = row[q];
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let row_p = a[p]; // A = J^T * A
+            let row_q = a[q];
+            a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+            for row in v.iter_mut() { // V = V * J
+                let vkp = row[p];
+                let vkq = row[q];
+                row[p] = c * vkp - s * vkq;
+                row[q] = s * vkp + c * vkq;
+            }
+        }
+    }
+
+    ([a[0][0], a[1][1], a[2][2]], v)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq_with_tolerance_false() {
+        let a = Plane::new(Point::new(0.0, 1.0, -2.5), Vector::new(0.0, 0.0, 1.0));
+        let b = Plane::new(Point::new(0.0, 1.0 + 0.0011, -2.5), Vector::new(0.0, 0.0, 1.0));
+
+        assert!(!a.eq_with_tolerance(&b, 0.001));
+    }
+
+    #[test]
+    fn test_get_closest_point_tilted() {
+        let plane = Plane::new(Point::new(1.0, 2.0, 3.0), Vector::new(1.0, 1.0, 1.0));
+        let input = Point::new(5.0, -3.0, 8.0);
+
+        let actual = plane.get_closest_point(&input);
+
+        assert!(plane.get_signed_distance_to_point(&actual).abs() < 1e-12);
+        let offset = Vector::from_2_points(&actual, &input);
+        assert!(offset.get_cross_product(&plane.normal).get_length() < 1e-12);
+    }
+
+    #[test]
+    fn test_new_best_fit_exact_tilted_plane() {
+        let expected_normal = Vector::new(0.3, -0.5, 0.8).get_unitized();
+        let x = expected_normal.get_any_perpendicular().get_unitized();
+        let y = expected_normal.get_cross_product(&x);
+        let origin = Point::new(12.0, -4.0, 7.0);
+        let mut points = Vec::new();
+        for i in 0..5 {
+            for j in 0..3 {
+                points.push(origin + x * (i as f64 * 2.5) + y * (j as f64 * 1.5 - i as f64 * 0.3));
+            }
+        }
+
+        let actual = Plane::new_best_fit(&points);
+
+        assert!(actual.normal.get_cross_product(&expected_normal).get_length() < 1e-9);
+        for point in &points {
+            assert!(actual.get_signed_distance_to_point(point).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_new_best_fit_axis_aligned() {
+        let points = vec![
+            Point::new(0.0, 3.0, 0.0),
+            Point::new(10.0, 3.0, 0.0),
+            Point::new(0.0, 3.0, 10.0),
+        ];
+
+        let actual = Plane::new_best_fit(&points);
+
+        assert!(actual.normal.get_cross_product(&Vector::new(0.0, 1.0, 0.0)).get_length() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot fit the Plane to less than 3 Points")]
+    fn test_new_best_fit_too_few_points_panic() {
+        Plane::new_best_fit(&[Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)]);
+    }
+}
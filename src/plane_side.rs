@@ -0,0 +1,19 @@
+/// Describes a side of a [Plane](crate::plane::Plane), see
+/// [Mesh::clip_by_plane](crate::mesh::Mesh::clip_by_plane).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::plane_side::PlaneSide;
+///
+/// let side = PlaneSide::Above;
+///
+/// assert_ne!(side, PlaneSide::Below);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaneSide {
+    /// The side the Plane's normal points to.
+    Above,
+    /// The opposite side, together with the Plane itself.
+    Below,
+}
@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use crate::point::Point;
+use crate::vector32::Vector32;
+
+/// Represents a Point object in three-dimensional space using `f32` values.
+///
+/// It's the single precision version of the [Point], used by the [Mesh32](crate::mesh32::Mesh32).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::point32::Point32;
+///
+/// let result = Point32::new(1.5, -2.5, 3.0);
+/// assert_eq!(result.x, 1.5);
+/// assert_eq!(result.y, -2.5);
+/// assert_eq!(result.z, 3.0);
+/// ```
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct Point32 {
+    /// The x-coordinate of the point.
+    pub x: f32,
+    /// The y-coordinate of the point.
+    pub y: f32,
+    /// The z-coordinate of the point.
+    pub z: f32
+}
+
+impl PartialEq for Point32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+
+impl From<Point> for Point32 {
+    fn from(point: Point) -> Self {
+        Point32::new(point.x as f32, point.y as f32, point.z as f32)
+    }
+}
+
+impl From<Point32> for Point {
+    fn from(point: Point32) -> Self {
+        Point::new(point.x as f64, point.y as f64, point.z as f64)
+    }
+}
+
+impl Point32 {
+    /// Creates a new [Point32]
+    ///
+    /// # Example
+    /// ```
+    /// use meshmeshmesh::point32::Point32;
+    ///
+    /// let result = Point32::new(1.5, -2.5, 3.0);
+    /// assert_eq!(result.x, 1.5);
+    /// assert_eq!(result.y, -2.5);
+    /// assert_eq!(result.z, 3.0);
+    /// ```
+    pub fn new(x: f32, y: f32, z: f32) -> Point32 { Point32 { x, y, z } }
+
+    /// Calculates the distance to the other [Point32].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point32::Point32;
+    ///
+    /// let a = Point32::new(1.0, 2.0, 3.0);
+    /// let b = Point32::new(4.0, 2.0, 7.0);
+    ///
+    /// assert_eq!(a.get_distance_to_point(&b), 5.0);
+    /// ```
+    pub fn get_distance_to_point(&self, other: &Point32) -> f32 {
+        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2) + (self.z - other.z).powi(2)).sqrt()
+    }
+
+    /// Gets the [Vector32] from this [Point32] to the other one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point32::Point32;
+    /// use meshmeshmesh::vector32::Vector32;
+    ///
+    /// let a = Point32::new(1.0, 2.0, 3.0);
+    /// let b = Point32::new(4.0, 2.0, 7.0);
+    ///
+    /// assert_eq!(a.get_vector_to_point(&b), Vector32::new(3.0, 0.0, 4.0));
+    /// ```
+    pub fn get_vector_to_point(&self, other: &Point32) -> Vector32 {
+        Vector32::new(other.x - self.x, other.y - self.y, other.z - self.z)
+    }
+
+    /// Gets the [Point32] moved by the [Vector32].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point32::Point32;
+    /// use meshmeshmesh::vector32::Vector32;
+    ///
+    /// let input = Point32::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(input.get_translated(&Vector32::new(0.5, -2.0, 1.0)), Point32::new(1.5, 0.0, 4.0));
+    /// ```
+    pub fn get_translated(&self, vector: &Vector32) -> Point32 {
+        Point32::new(self.x + vector.x, self.y + vector.y, self.z + vector.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_point_and_back() {
+        let input = Point::new(1.5, -2.25, 3.125);
+
+        let converted = Point32::from(input);
+        let actual = Point::from(converted);
+
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_get_distance_to_point_symmetric() {
+        let a = Point32::new(-1.0, 0.5, 2.0);
+        let b = Point32::new(2.0, 4.5, 2.0);
+
+        assert_eq!(a.get_distance_to_point(&b), 5.0);
+        assert_eq!(b.get_distance_to_point(&a), 5.0);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::bounding_box::BoundingBox;
+use crate::kd_tree::KdTree;
 use crate::mesh::Mesh;
 use crate::point::Point;
 use crate::ray::Ray;
@@ -208,6 +210,33 @@ impl Point {
         Point::new((self.x + other.x) / 2.0, (self.y + other.y) / 2.0, (self.z + other.z) / 2.0)
     }
 
+    /// Gets the [Point] of the segment from `start` to `end` closest to this one.
+    ///
+    /// For a zero length segment the `start` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let start = Point::new(0.0, 0.0, 0.0);
+    /// let end = Point::new(10.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(Point::new(4.0, 3.0, 1.0).get_closest_point_on_segment(&start, &end), Point::new(4.0, 0.0, 0.0));
+    /// assert_eq!(Point::new(-2.0, 3.0, 0.0).get_closest_point_on_segment(&start, &end), start);
+    /// assert_eq!(Point::new(4.0, 3.0, 1.0).get_closest_point_on_segment(&start, &start), start);
+    /// ```
+    pub fn get_closest_point_on_segment(&self, start: &Point, end: &Point) -> Point {
+        let segment = Vector::from_2_points(start, end);
+        let squared_length = segment.get_dot_product(&segment);
+        if squared_length == 0.0 {
+            return *start;
+        }
+        let parameter = Vector::from_2_points(start, self).get_dot_product(&segment) / squared_length;
+
+        *start + segment * parameter.clamp(0.0, 1.0)
+    }
+
     /// Scans given `vec` of [Point]s and creates a `vec` of tuples with information about duplicates.
     ///
     /// Duplicates are checked with given tolerance.
@@ -223,6 +252,9 @@ impl Point {
     ///
     /// This way it is clear which Points are the duplicates and of which Point.
     ///
+    /// Candidates are found with a [KdTree], so it's fast also for big `vec`s, e.g. when
+    /// welding large unwelded Meshes.
+    ///
     /// # Examples
     ///
     /// ```
@@ -239,20 +271,19 @@ impl Point {
     /// assert_eq!(actual, expected);
     /// ```
     pub fn scan_for_duplicates_with_tolerance_info(points: &Vec<Point>, tolerance: f64) -> Vec<(usize, bool)>{
-        let mut info: Vec<(usize, bool)> = Vec::new();
+        let mut info: Vec<(usize, bool)> = (0..points.len()).map(|i| (i, false)).collect();
+        let kd_tree = KdTree::from_points(points);
+        let padding = 2.0 * tolerance.max(0.0); // Bigger than needed to be safe from rounding errors, candidates are checked below anyway
 
-        let points_length = points.len();
-
-        for i in 0..points_length {
-            info.push((i, false));
-        }
-
-        for i in 0..points_length {
+        for (i, current_point) in points.iter().enumerate() {
             if !info[i].1 { // Checks if it's not already a duplicate, cause if it is, then doesn't make sense to check again with Points below
-                let current_point = &points[i];
-                for j in (i+1)..points_length {
-                    let next_point_for_comparison = &points[j];
-                    if current_point.eq_with_tolerance(next_point_for_comparison, tolerance) {
+                let search_box = BoundingBox {
+                    min_x: current_point.x - padding, max_x: current_point.x + padding,
+                    min_y: current_point.y - padding, max_y: current_point.y + padding,
+                    min_z: current_point.z - padding, max_z: current_point.z + padding,
+                };
+                for j in kd_tree.get_point_ids_in_box(&search_box) {
+                    if j > i && current_point.eq_with_tolerance(&points[j], tolerance) {
                         info[j] = (i, true);
                     }
                 }
@@ -474,5 +505,26 @@ mod tests {
         let actual = Point::scan_for_duplicates_with_tolerance_info(&points, 0.001);
         assert_eq!(actual, expected);
     }
-}
 
+    #[test]
+    fn test_scan_for_duplicates_with_tolerance_info_same_as_brute_force(){
+        let points: Vec<Point> = (0..300).map(|i| Point::new(((i * 7) % 5) as f64 * 0.5 + (i % 7) as f64 * 0.0001, ((i * 5) % 3) as f64 * 0.5, (i % 4) as f64 * 0.5)).collect();
+        let tolerance = 0.001;
+
+        let mut expected: Vec<(usize, bool)> = (0..points.len()).map(|i| (i, false)).collect();
+        for i in 0..points.len() {
+            if !expected[i].1 {
+                for j in (i + 1)..points.len() {
+                    if points[i].eq_with_tolerance(&points[j], tolerance) {
+                        expected[j] = (i, true);
+                    }
+                }
+            }
+        }
+
+        let actual = Point::scan_for_duplicates_with_tolerance_info(&points, tolerance);
+
+        assert_eq!(actual, expected);
+        assert!(actual.iter().any(|(_, is_duplicate)| *is_duplicate));
+    }
+}
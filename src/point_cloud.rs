@@ -0,0 +1,103 @@
+use crate::color::Color;
+use crate::point::Point;
+
+/// Represents a point cloud: a set of [Point]s with optional per-point attributes.
+///
+/// Attributes, if present, have exactly one value per [Point].
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::point::Point;
+/// use meshmeshmesh::point_cloud::PointCloud;
+///
+/// let result = PointCloud::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(1.5, -2.0, 3.0)]);
+///
+/// assert_eq!(result.points.len(), 2);
+/// assert_eq!(result.colors, None);
+/// assert_eq!(result.intensities, None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PointCloud {
+    /// The points.
+    pub points: Vec<Point>,
+    /// Optional colors, one per point.
+    pub colors: Option<Vec<Color>>,
+    /// Optional intensities (e.g. laser return strength), one per point.
+    pub intensities: Option<Vec<f64>>,
+}
+
+impl PartialEq for PointCloud {
+    fn eq(&self, other: &Self) -> bool {
+        self.points == other.points && self.colors == other.colors && self.intensities == other.intensities
+    }
+}
+
+impl PointCloud {
+    /// Returns a new [PointCloud] without any attributes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::point_cloud::PointCloud;
+    ///
+    /// let result = PointCloud::new(vec![Point::new(0.0, 0.0, 0.0)]);
+    ///
+    /// assert_eq!(result.points, vec![Point::new(0.0, 0.0, 0.0)]);
+    /// ```
+    pub fn new(points: Vec<Point>) -> PointCloud { PointCloud { points, colors: None, intensities: None } }
+
+    /// Returns a new [PointCloud] with attributes.
+    ///
+    /// It panics if any of the attributes has a different length than `points`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::color::Color;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::point_cloud::PointCloud;
+    ///
+    /// let result = PointCloud::new_with_attributes(
+    ///     vec![Point::new(0.0, 0.0, 0.0)],
+    ///     Some(vec![Color::new(255, 0, 0, 255)]),
+    ///     None);
+    ///
+    /// assert_eq!(result.colors, Some(vec![Color::new(255, 0, 0, 255)]));
+    /// ```
+    pub fn new_with_attributes(points: Vec<Point>, colors: Option<Vec<Color>>, intensities: Option<Vec<f64>>) -> PointCloud {
+        if colors.as_ref().is_some_and(|colors| colors.len() != points.len()) {
+            panic!("Number of colors should be equal to the number of points");
+        }
+        if intensities.as_ref().is_some_and(|intensities| intensities.len() != points.len()) {
+            panic!("Number of intensities should be equal to the number of points");
+        }
+        PointCloud { points, colors, intensities }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partialeq_true() {
+        let a = PointCloud::new_with_attributes(vec![Point::new(1.0, 2.0, 3.0)], None, Some(vec![0.5]));
+        let b = PointCloud::new_with_attributes(vec![Point::new(1.0, 2.0, 3.0)], None, Some(vec![0.5]));
+        assert_eq!(a.eq(&b), true);
+    }
+
+    #[test]
+    fn test_partialeq_different_intensities_false() {
+        let a = PointCloud::new_with_attributes(vec![Point::new(1.0, 2.0, 3.0)], None, Some(vec![0.5]));
+        let b = PointCloud::new(vec![Point::new(1.0, 2.0, 3.0)]);
+        assert_eq!(a.eq(&b), false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of colors should be equal to the number of points")]
+    fn test_new_with_attributes_wrong_colors_panic() {
+        PointCloud::new_with_attributes(vec![Point::new(1.0, 2.0, 3.0)], Some(vec![]), None);
+    }
+}
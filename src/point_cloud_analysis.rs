@@ -0,0 +1,107 @@
+use crate::bounding_box::BoundingBox;
+use crate::mesh::Mesh;
+use crate::octree::Octree;
+use crate::point_cloud::PointCloud;
+
+impl PointCloud {
+    /// Returns the number of points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::point_cloud::PointCloud;
+    ///
+    /// let input = PointCloud::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(1.5, -2.0, 3.0)]);
+    ///
+    /// assert_eq!(input.get_number_of_points(), 2);
+    /// ```
+    pub fn get_number_of_points(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Gets the [BoundingBox] of the [PointCloud].
+    ///
+    /// It panics if the [PointCloud] is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::point_cloud::PointCloud;
+    ///
+    /// let input = PointCloud::new(vec![Point::new(0.0, 4.0, 0.0), Point::new(1.5, -2.0, 3.0)]);
+    ///
+    /// assert!(input.get_bounding_box().eq(&BoundingBox::new(0.0, 1.5, -2.0, 4.0, 0.0, 3.0)));
+    /// ```
+    pub fn get_bounding_box(&self) -> BoundingBox {
+        if self.points.is_empty() {
+            panic!("Cannot get the bounding box of an empty point cloud");
+        }
+
+        let min_x = self.points.iter().map(|point| point.x).reduce(f64::min).unwrap();
+        let max_x = self.points.iter().map(|point| point.x).reduce(f64::max).unwrap();
+
+        let min_y = self.points.iter().map(|point| point.y).reduce(f64::min).unwrap();
+        let max_y = self.points.iter().map(|point| point.y).reduce(f64::max).unwrap();
+
+        let min_z = self.points.iter().map(|point| point.z).reduce(f64::min).unwrap();
+        let max_z = self.points.iter().map(|point| point.z).reduce(f64::max).unwrap();
+
+        BoundingBox::new(min_x, max_x, min_y, max_y, min_z, max_z)
+    }
+
+    /// Calculates the distance from each point to the closest point on the [Mesh].
+    ///
+    /// It's useful e.g. to check how well a reconstructed or fitted [Mesh] matches the scan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::point_cloud::PointCloud;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 10.0, 0.0], vec![0, 1, 2]);
+    /// let input = PointCloud::new(vec![Point::new(1.0, 1.0, 2.0), Point::new(-3.0, 0.0, 4.0)]);
+    ///
+    /// let actual = input.get_distances_to_mesh(&mesh);
+    ///
+    /// assert_eq!(actual, vec![2.0, 5.0]);
+    /// ```
+    pub fn get_distances_to_mesh(&self, mesh: &Mesh) -> Vec<f64> {
+        let octree = Octree::from_mesh(mesh);
+        self.points.iter().map(|point| {
+            octree.get_nearest_triangle(point)
+                .map_or(f64::MAX, |(_, closest_point)| closest_point.get_distance_to_point(point))
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+
+    #[test]
+    #[should_panic(expected = "Cannot get the bounding box of an empty point cloud")]
+    fn test_get_bounding_box_empty_panic() {
+        PointCloud::new(vec![]).get_bounding_box();
+    }
+
+    #[test]
+    fn test_get_distances_to_mesh_closest_face() {
+        let mesh = Mesh::new(
+            vec![
+                0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 10.0, 0.0,
+                0.0, 0.0, 5.0, 10.0, 0.0, 5.0, 0.0, 10.0, 5.0,
+            ],
+            vec![0, 1, 2, 3, 4, 5]);
+        let input = PointCloud::new(vec![Point::new(1.0, 1.0, 1.0), Point::new(1.0, 1.0, 4.5), Point::new(1.0, 1.0, 5.0)]);
+
+        let actual = input.get_distances_to_mesh(&mesh);
+
+        assert_eq!(actual, vec![1.0, 0.5, 0.0]);
+    }
+}
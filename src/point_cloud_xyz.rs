@@ -0,0 +1,250 @@
+use std::fs;
+use crate::color::Color;
+use crate::point::Point;
+use crate::point_cloud::PointCloud;
+
+impl PointCloud {
+    /// Reads the [PointCloud] from the .xyz file.
+    ///
+    /// See [PointCloud::from_xyz_str] for the supported content.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::point_cloud::PointCloud;
+    ///
+    /// let point_cloud = PointCloud::from_xyz("scan.xyz").unwrap();
+    /// ```
+    pub fn from_xyz(path: &str) -> Result<PointCloud, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("Couldn't read the .xyz file: {}", e))?;
+        Self::from_xyz_str(&content)
+    }
+
+    /// Reads the [PointCloud] from the content of the .xyz file.
+    ///
+    /// Each line contains the coordinates of one point: `x y z`, optionally followed by the
+    /// color `r g b` (0-255). Values can be separated with spaces, tabs, commas or semicolons.
+    /// Empty lines and comments (starting with `#` or `//`) are skipped.
+    ///
+    /// Colors are read only if every point has them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::color::Color;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::point_cloud::PointCloud;
+    ///
+    /// let content = "# x y z r g b
+    /// 0.0 0.0 0.0 255 0 0
+    /// 1.5,-2.0,3.0,0,128,0
+    /// ";
+    ///
+    /// let actual = PointCloud::from_xyz_str(content).unwrap();
+    ///
+    /// assert_eq!(actual.points, vec![Point::new(0.0, 0.0, 0.0), Point::new(1.5, -2.0, 3.0)]);
+    /// assert_eq!(actual.colors, Some(vec![Color::new(255, 0, 0, 255), Color::new(0, 128, 0, 255)]));
+    /// assert_eq!(actual.intensities, None);
+    /// ```
+    pub fn from_xyz_str(content: &str) -> Result<PointCloud, String> {
+        let rows = parse_rows(content)?;
+
+        let mut points: Vec<Point> = Vec::with_capacity(rows.len());
+        for (line_number, values) in &rows {
+            if values.len() < 3 {
+                return Err(format!("Line {} should have at least 3 values", line_number));
+            }
+            points.push(Point::new(values[0], values[1], values[2]));
+        }
+
+        let colors = get_colors(&rows, 3)?;
+
+        Ok(PointCloud { points, colors, intensities: None })
+    }
+
+    /// Reads the [PointCloud] from the .pts file.
+    ///
+    /// See [PointCloud::from_pts_str] for the supported content.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use meshmeshmesh::point_cloud::PointCloud;
+    ///
+    /// let point_cloud = PointCloud::from_pts("scan.pts").unwrap();
+    /// ```
+    pub fn from_pts(path: &str) -> Result<PointCloud, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("Couldn't read the .pts file: {}", e))?;
+        Self::from_pts_str(&content)
+    }
+
+    /// Reads the [PointCloud] from the content of the .pts file.
+    ///
+    /// Each scan starts with a line with the number of its points. Then each line contains
+    /// `x y z`, optionally followed by the intensity and the color `r g b` (0-255).
+    ///
+    /// Intensities and colors are read only if every point has them. Scans are merged into one
+    /// [PointCloud].
+    ///
+    /// It returns an error if the number of points doesn't match the header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::color::Color;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::point_cloud::PointCloud;
+    ///
+    /// let content = "2
+    /// 0.0 0.0 0.0 -120 255 0 0
+    /// 1.5 -2.0 3.0 35 0 128 0
+    /// ";
+    ///
+    /// let actual = PointCloud::from_pts_str(content).unwrap();
+    ///
+    /// assert_eq!(actual.points, vec![Point::new(0.0, 0.0, 0.0), Point::new(1.5, -2.0, 3.0)]);
+    /// assert_eq!(actual.intensities, Some(vec![-120.0, 35.0]));
+    /// assert_eq!(actual.colors, Some(vec![Color::new(255, 0, 0, 255), Color::new(0, 128, 0, 255)]));
+    /// ```
+    pub fn from_pts_str(content: &str) -> Result<PointCloud, String> {
+        let rows = parse_rows(content)?;
+
+        let mut point_rows: Vec<(usize, Vec<f64>)> = Vec::with_capacity(rows.len());
+        let mut remaining_in_scan = 0;
+        for (line_number, values) in rows {
+            if remaining_in_scan == 0 {
+                if values.len() != 1 || values[0] < 0.0 || values[0].fract() != 0.0 {
+                    return Err(format!("Line {} should contain the number of points", line_number));
+                }
+                remaining_in_scan = values[0] as usize;
+                continue;
+            }
+            if values.len() < 3 {
+                return Err(format!("Line {} should have at least 3 values", line_number));
+            }
+            point_rows.push((line_number, values));
+            remaining_in_scan -= 1;
+        }
+        if remaining_in_scan > 0 {
+            return Err(format!("Missing {} points declared in the header", remaining_in_scan));
+        }
+
+        let points: Vec<Point> = point_rows.iter().map(|(_, values)| Point::new(values[0], values[1], values[2])).collect();
+        let intensities = if !point_rows.is_empty() && point_rows.iter().all(|(_, values)| values.len() >= 4) {
+            Some(point_rows.iter().map(|(_, values)| values[3]).collect())
+        } else {
+            None
+        };
+        let colors = get_colors(&point_rows, 4)?;
+
+        Ok(PointCloud { points, colors, intensities })
+    }
+}
+
+/// Parses non-empty, non-comment lines into numbers, keeping their (1-based) line numbers.
+fn parse_rows(content: &str) -> Result<Vec<(usize, Vec<f64>)>, String> {
+    let mut rows = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+            continue;
+        }
+        let values = line
+            .split(|character: char| character.is_whitespace() || character == ',' || character == ';')
+            .filter(|value| !value.is_empty())
+            .map(|value| value.parse::<f64>().map_err(|e| format!("Invalid value '{}' at line {}: {}", value, i + 1, e)))
+            .collect::<Result<Vec<f64>, String>>()?;
+        rows.push((i + 1, values));
+    }
+    Ok(rows)
+}
+
+/// Reads colors starting at the given column, if every row has them.
+fn get_colors(rows: &[(usize, Vec<f64>)], first_column: usize) -> Result<Option<Vec<Color>>, String> {
+    if rows.is_empty() || rows.iter().any(|(_, values)| values.len() < first_column + 3) {
+        return Ok(None);
+    }
+
+    let mut colors = Vec::with_capacity(rows.len());
+    for (line_number, values) in rows {
+        let channels = &values[first_column..first_column + 3];
+        if channels.iter().any(|channel| !(0.0..=255.0).contains(channel)) {
+            return Err(format!("Color at line {} should be in the range 0-255", line_number));
+        }
+        colors.push(Color::new(channels[0] as i32, channels[1] as i32, channels[2] as i32, 255));
+    }
+    Ok(Some(colors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_xyz_str_without_colors() {
+        let content = "1 2 3\n\n// comment\n4\t5\t6\n7;8;9 255 255\n";
+
+        let actual = PointCloud::from_xyz_str(content).unwrap();
+
+        assert_eq!(actual, PointCloud::new(vec![Point::new(1.0, 2.0, 3.0), Point::new(4.0, 5.0, 6.0), Point::new(7.0, 8.0, 9.0)]));
+    }
+
+    #[test]
+    fn test_from_xyz_str_invalid_value() {
+        let actual = PointCloud::from_xyz_str("1 2 3\n1 x 3\n");
+
+        assert!(actual.unwrap_err().contains("line 2"));
+    }
+
+    #[test]
+    fn test_from_xyz_str_too_few_values() {
+        let actual = PointCloud::from_xyz_str("1 2\n");
+
+        assert_eq!(actual, Err("Line 1 should have at least 3 values".to_string()));
+    }
+
+    #[test]
+    fn test_from_xyz_str_color_out_of_range() {
+        let actual = PointCloud::from_xyz_str("1 2 3 0 300 0\n");
+
+        assert_eq!(actual, Err("Color at line 1 should be in the range 0-255".to_string()));
+    }
+
+    #[test]
+    fn test_from_pts_str_multiple_scans_without_attributes() {
+        let content = "1\n1 2 3\n2\n4 5 6\n7 8 9\n";
+
+        let actual = PointCloud::from_pts_str(content).unwrap();
+
+        assert_eq!(actual, PointCloud::new(vec![Point::new(1.0, 2.0, 3.0), Point::new(4.0, 5.0, 6.0), Point::new(7.0, 8.0, 9.0)]));
+    }
+
+    #[test]
+    fn test_from_pts_str_intensity_only() {
+        let actual = PointCloud::from_pts_str("1\n1 2 3 0.25\n").unwrap();
+
+        assert_eq!(actual.intensities, Some(vec![0.25]));
+        assert_eq!(actual.colors, None);
+    }
+
+    #[test]
+    fn test_from_pts_str_missing_points() {
+        let actual = PointCloud::from_pts_str("3\n1 2 3\n4 5 6\n");
+
+        assert_eq!(actual, Err("Missing 1 points declared in the header".to_string()));
+    }
+
+    #[test]
+    fn test_from_pts_str_missing_header() {
+        let actual = PointCloud::from_pts_str("1 2 3\n");
+
+        assert_eq!(actual, Err("Line 1 should contain the number of points".to_string()));
+    }
+
+    #[test]
+    fn test_from_xyz_missing_file() {
+        let actual = PointCloud::from_xyz("not_existing_file.xyz");
+
+        assert!(actual.unwrap_err().starts_with("Couldn't read the .xyz file"));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::bounding_box::BoundingBox;
+use crate::point::Point;
+
+impl Point {
+    /// Encodes the [Point] as 3 `u16` values relative to given [BoundingBox].
+    ///
+    /// Each coordinate is mapped linearly from the [BoundingBox] range to 0..=65535, so the
+    /// error is at most 1/131070 of the [BoundingBox] size in given direction.
+    /// It's useful for storing positions in compact binary formats and GPU buffers.
+    ///
+    /// Coordinates outside the [BoundingBox] are clamped. If the [BoundingBox] is flat in some
+    /// direction, that coordinate is encoded as 0.
+    ///
+    /// Use [Point::from_quantized_u16] to decode it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let bounding_box = BoundingBox::new(-2.0, 8.0, 1.0, 11.0, 0.0, 4.0);
+    /// let input = Point::new(8.0, 1.0, 2.0);
+    ///
+    /// let actual = input.to_quantized_u16(&bounding_box);
+    ///
+    /// assert_eq!(actual, [65535, 0, 32768]);
+    /// ```
+    pub fn to_quantized_u16(&self, bounding_box: &BoundingBox) -> [u16; 3] {
+        [
+            Self::quantize_coordinate(self.x, bounding_box.min_x, bounding_box.max_x),
+            Self::quantize_coordinate(self.y, bounding_box.min_y, bounding_box.max_y),
+            Self::quantize_coordinate(self.z, bounding_box.min_z, bounding_box.max_z),
+        ]
+    }
+
+    /// Decodes the [Point] from 3 `u16` values encoded with [Point::to_quantized_u16] using the
+    /// same [BoundingBox].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::point::Point;
+    ///
+    /// let bounding_box = BoundingBox::new(-2.0, 8.0, 1.0, 11.0, 0.0, 4.0);
+    /// let input = Point::new(3.0, 6.0, 4.0);
+    ///
+    /// let actual = Point::from_quantized_u16(input.to_quantized_u16(&bounding_box), &bounding_box);
+    ///
+    /// assert!(input.eq_with_tolerance(&actual, 0.0001));
+    /// ```
+    pub fn from_quantized_u16(quantized: [u16; 3], bounding_box: &BoundingBox) -> Point {
+        Point::new(
+            Self::dequantize_coordinate(quantized[0], bounding_box.min_x, bounding_box.max_x),
+            Self::dequantize_coordinate(quantized[1], bounding_box.min_y, bounding_box.max_y),
+            Self::dequantize_coordinate(quantized[2], bounding_box.min_z, bounding_box.max_z),
+        )
+    }
+
+    fn quantize_coordinate(value: f64, min: f64, max: f64) -> u16 {
+        let range = max - min;
+        if range <= 0.0 {
+            return 0;
+        }
+
+        (((value - min) / range).clamp(0.0, 1.0) * u16::MAX as f64).round() as u16
+    }
+
+    fn dequantize_coordinate(value: u16, min: f64, max: f64) -> f64 {
+        min + (value as f64 / u16::MAX as f64) * (max - min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_quantized_u16_corners() {
+        let bounding_box = BoundingBox::new(-2.0, 8.0, 1.0, 11.0, 0.0, 4.0);
+
+        assert_eq!(Point::new(-2.0, 1.0, 0.0).to_quantized_u16(&bounding_box), [0, 0, 0]);
+        assert_eq!(Point::new(8.0, 11.0, 4.0).to_quantized_u16(&bounding_box), [65535, 65535, 65535]);
+    }
+
+    #[test]
+    fn test_to_quantized_u16_outside_clamped() {
+        let bounding_box = BoundingBox::new(-2.0, 8.0, 1.0, 11.0, 0.0, 4.0);
+
+        assert_eq!(Point::new(-20.0, 100.0, 2.0).to_quantized_u16(&bounding_box), [0, 65535, 32768]);
+    }
+
+    #[test]
+    fn test_quantized_u16_flat_bounding_box() {
+        let bounding_box = BoundingBox::new(-2.0, 8.0, 1.0, 11.0, 3.0, 3.0);
+        let input = Point::new(1.5, 2.5, 3.0);
+
+        let encoded = input.to_quantized_u16(&bounding_box);
+        assert_eq!(encoded[2], 0);
+
+        let actual = Point::from_quantized_u16(encoded, &bounding_box);
+        assert!(input.eq_with_tolerance(&actual, 0.0002));
+    }
+
+    #[test]
+    fn test_quantized_u16_round_trip_error() {
+        let bounding_box = BoundingBox::new(-1000.0, 1000.0, -1000.0, 1000.0, -1000.0, 1000.0);
+        let input = Point::new(123.456789, -987.654321, 0.000123);
+
+        let actual = Point::from_quantized_u16(input.to_quantized_u16(&bounding_box), &bounding_box);
+
+        assert!(input.eq_with_tolerance(&actual, 2000.0 / 131070.0 + 1e-9));
+    }
+}
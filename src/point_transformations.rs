@@ -2,6 +2,7 @@ use std::ops;
 use crate::local_coordinate_system::LocalCoordinateSystem;
 use crate::point::Point;
 use crate::quaternion::Quaternion;
+use crate::transform::Transform;
 use crate::vector::Vector;
 
 impl ops::Add<Vector> for Point {
@@ -245,6 +246,32 @@ impl Point {
 
         translated
     }
+
+    /// Returns the [Point] transformed by the given [Transform].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::transform::Transform;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Point::new(1.0, -2.0, 3.0);
+    /// let transform = Transform::new_translation(&Vector::new(0.5, 0.5, 0.5)) * Transform::new_scaling(2.0, 1.0, -1.0);
+    ///
+    /// let actual = input.get_transformed(&transform);
+    ///
+    /// assert_eq!(actual, Point::new(2.5, -1.5, -2.5));
+    /// ```
+    pub fn get_transformed(&self, transform: &Transform) -> Point {
+        let m = &transform.matrix;
+
+        Point::new(
+            m[0][0] * self.x + m[0][1] * self.y + m[0][2] * self.z + m[0][3],
+            m[1][0] * self.x + m[1][1] * self.y + m[1][2] * self.z + m[1][3],
+            m[2][0] * self.x + m[2][1] * self.y + m[2][2] * self.z + m[2][3],
+        )
+    }
 }
 
 #[cfg(test)]
@@ -58,6 +58,66 @@ impl Polygon2D {
     ///
     /// ```
     pub fn new(vertices: Vec<Point2D>) -> Polygon2D { Polygon2D { vertices } }
+
+    /// Creates a convex hull of given [Point2D]s as a counter-clockwise [Polygon2D].
+    ///
+    /// Duplicated Points and Points lying on straight sides of the hull are skipped. If all the
+    /// Points are collinear, the result has just 2 vertices: both ends of them (or 1 vertex, if
+    /// all the Points are the same).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::polygon2d::Polygon2D;
+    ///
+    /// let input = vec![
+    ///     Point2D::new(0.0, 0.0),
+    ///     Point2D::new(1.0, 1.0),
+    ///     Point2D::new(4.0, 0.0),
+    ///     Point2D::new(2.0, 0.0),
+    ///     Point2D::new(4.0, 3.0),
+    ///     Point2D::new(0.0, 3.0),
+    /// ];
+    ///
+    /// let actual = Polygon2D::convex_hull(&input);
+    ///
+    /// assert_eq!(actual, Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 3.0), Point2D::new(0.0, 3.0)]));
+    /// ```
+    pub fn convex_hull(points: &[Point2D]) -> Polygon2D {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        sorted.dedup();
+        if sorted.len() < 3 {
+            return Polygon2D::new(sorted);
+        }
+
+        let lower = Polygon2D::get_hull_chain(sorted.iter());
+        let upper = Polygon2D::get_hull_chain(sorted.iter().rev());
+        let mut vertices = Vec::with_capacity(lower.len() + upper.len());
+        vertices.extend_from_slice(&lower[..lower.len() - 1]); // Last Points are the first ones of the other chain
+        vertices.extend_from_slice(&upper[..upper.len() - 1]);
+
+        Polygon2D::new(vertices)
+    }
+
+    /// Gets a chain of the convex hull going through sorted [Point2D]s, always turning left.
+    fn get_hull_chain<'a>(sorted_points: impl Iterator<Item = &'a Point2D>) -> Vec<Point2D> {
+        let mut chain: Vec<Point2D> = Vec::new();
+        for point in sorted_points {
+            while chain.len() > 1 {
+                let a = chain[chain.len() - 2];
+                let b = chain[chain.len() - 1];
+                if (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x) > 0.0 {
+                    break;
+                }
+                chain.pop();
+            }
+            chain.push(*point);
+        }
+
+        chain
+    }
 }
 
 #[cfg(test)]
@@ -71,6 +131,38 @@ mod tests {
         assert_eq!(result.vertices, vec![Point2D::new(0.0, 0.0), Point2D::new(5.0, 10.0), Point2D::new(10.0, 0.0)]);
     }
 
+    #[test]
+    fn test_convex_hull_square_with_inner_points() {
+        let mut input = Vec::new();
+        for i in 0..5 {
+            for j in 0..5 {
+                input.push(Point2D::new(i as f64, j as f64));
+            }
+        }
+
+        let actual = Polygon2D::convex_hull(&input);
+
+        assert_eq!(actual, Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 4.0), Point2D::new(0.0, 4.0)]));
+        assert!(!actual.is_clockwise());
+    }
+
+    #[test]
+    fn test_convex_hull_collinear() {
+        let input = vec![Point2D::new(2.0, 2.0), Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(3.0, 3.0)];
+
+        let actual = Polygon2D::convex_hull(&input);
+
+        assert_eq!(actual, Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 3.0)]));
+    }
+
+    #[test]
+    fn test_convex_hull_duplicates_and_empty() {
+        let input = vec![Point2D::new(1.0, 2.0), Point2D::new(1.0, 2.0), Point2D::new(1.0, 2.0)];
+
+        assert_eq!(Polygon2D::convex_hull(&input), Polygon2D::new(vec![Point2D::new(1.0, 2.0)]));
+        assert_eq!(Polygon2D::convex_hull(&[]), Polygon2D::new(vec![]));
+    }
+
     #[test]
     fn test_partialeq_true() {
         let a = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(5.0, 10.0), Point2D::new(10.0, 0.0)]);
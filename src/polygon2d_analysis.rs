@@ -1,5 +1,7 @@
 use crate::bounding_area::BoundingArea;
+use crate::point2d::Point2D;
 use crate::polygon2d::Polygon2D;
+use crate::section_properties::SectionProperties;
 use crate::vector2d::Vector2D;
 
 impl Polygon2D {
@@ -175,13 +177,179 @@ impl Polygon2D {
         
         BoundingArea::new(min_x, max_x, min_y, max_y)
     }
+
+    /// Gets the rectangle with the smallest area containing the [Polygon2D], as a
+    /// counter-clockwise Polygon2D with 4 corners.
+    ///
+    /// Unlike [Polygon2D::get_bounding_area] it can be rotated, so it fits tightly no matter how
+    /// the Polygon2D is rotated. One of its sides always lies on a side of the convex hull
+    /// (see [Polygon2D::convex_hull]). For collinear vertices it has zero width, and for the
+    /// Polygon2D without vertices it has no corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::polygon2d::Polygon2D;
+    ///
+    /// let input = Polygon2D::new(vec![Point2D::new(1.0, 0.0), Point2D::new(2.0, 1.0), Point2D::new(1.5, 1.2), Point2D::new(1.0, 2.0), Point2D::new(0.0, 1.0)]);
+    ///
+    /// let actual = input.get_minimum_area_bounding_rectangle();
+    ///
+    /// assert_eq!(actual.vertices.len(), 4);
+    /// for corner in [Point2D::new(1.0, 0.0), Point2D::new(2.0, 1.0), Point2D::new(1.0, 2.0), Point2D::new(0.0, 1.0)] {
+    ///     assert!(actual.vertices.iter().any(|vertex| vertex.eq_with_tolerance(&corner, 1e-12)));
+    /// }
+    /// ```
+    pub fn get_minimum_area_bounding_rectangle(&self) -> Polygon2D {
+        let hull = Polygon2D::convex_hull(&self.vertices).vertices;
+        if hull.len() < 2 {
+            return Polygon2D::new(hull.iter().flat_map(|vertex| [*vertex; 4]).collect());
+        }
+
+        let mut smallest_area = f64::INFINITY;
+        let mut corners = Vec::new();
+        for (i, start) in hull.iter().enumerate() {
+            let direction = Vector2D::from_2_points(start, &hull[(i + 1) % hull.len()]);
+            let length = direction.get_length();
+            let u = Vector2D::new(direction.x / length, direction.y / length); // Along the side
+            let v = Vector2D::new(-u.y, u.x);
+
+            let mut min = [f64::INFINITY; 2];
+            let mut max = [f64::NEG_INFINITY; 2];
+            for vertex in &hull {
+                let relative = Vector2D::from_2_points(start, vertex);
+                for (axis, coordinate) in [relative.get_dot_product(&u), relative.get_dot_product(&v)].into_iter().enumerate() {
+                    min[axis] = min[axis].min(coordinate);
+                    max[axis] = max[axis].max(coordinate);
+                }
+            }
+
+            let area = (max[0] - min[0]) * (max[1] - min[1]);
+            if area < smallest_area {
+                smallest_area = area;
+                corners = [(min[0], min[1]), (max[0], min[1]), (max[0], max[1]), (min[0], max[1])].into_iter()
+                    .map(|(along_u, along_v)| Point2D::new(start.x + u.x * along_u + v.x * along_v, start.y + u.y * along_u + v.y * along_v))
+                    .collect();
+            }
+        }
+
+        Polygon2D::new(corners)
+    }
+
+    /// Calculates [SectionProperties] of the planar section defined by this [Polygon2D] as an
+    /// outer boundary and given holes.
+    ///
+    /// Both the outer boundary and holes can be clockwise or counter-clockwise. Holes should be
+    /// inside the outer boundary and shouldn't overlap each other.
+    ///
+    /// Section moduli are calculated using extreme fibres of the outer boundary.
+    ///
+    /// # Example
+    ///
+    /// Here below is an example of a hollow square section 100x100 with 10 thick walls.
+    ///
+    /// ```
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::polygon2d::Polygon2D;
+    ///
+    /// let outer = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(100.0, 0.0), Point2D::new(100.0, 100.0), Point2D::new(0.0, 100.0)]);
+    /// let hole = Polygon2D::new(vec![Point2D::new(10.0, 10.0), Point2D::new(90.0, 10.0), Point2D::new(90.0, 90.0), Point2D::new(10.0, 90.0)]);
+    ///
+    /// let result = outer.get_section_properties(&vec![hole]);
+    ///
+    /// let expected_i = (100.0_f64.powi(4) - 80.0_f64.powi(4)) / 12.0;
+    /// assert_eq!(result.area, 3600.0);
+    /// assert!(result.centroid.eq_with_tolerance(&Point2D::new(50.0, 50.0), 1e-9));
+    /// assert!((result.i_xx - expected_i).abs() < 1e-6);
+    /// assert!((result.i_yy - expected_i).abs() < 1e-6);
+    /// assert!(result.i_xy.abs() < 1e-6);
+    /// assert!((result.section_modulus_x_bottom - expected_i / 50.0).abs() < 1e-6);
+    /// ```
+    pub fn get_section_properties(&self, holes: &Vec<Polygon2D>) -> SectionProperties {
+        let mut integrals = self.get_area_integrals(if self.is_clockwise() { -1.0 } else { 1.0 });
+        for hole in holes {
+            let hole_integrals = hole.get_area_integrals(if hole.is_clockwise() { 1.0 } else { -1.0 });
+            for (sum, value) in integrals.iter_mut().zip(hole_integrals) {
+                *sum += value;
+            }
+        }
+        let [area, first_moment_x, first_moment_y, i_xx_origin, i_yy_origin, i_xy_origin] = integrals;
+
+        let centroid = Point2D::new(first_moment_x / area, first_moment_y / area);
+        let i_xx = i_xx_origin - area * centroid.y * centroid.y;
+        let i_yy = i_yy_origin - area * centroid.x * centroid.x;
+        let i_xy = i_xy_origin - area * centroid.x * centroid.y;
+
+        let bounding_area = self.get_bounding_area();
+
+        SectionProperties {
+            area,
+            centroid,
+            i_xx,
+            i_yy,
+            i_xy,
+            section_modulus_x_top: i_xx / (bounding_area.max_y - centroid.y),
+            section_modulus_x_bottom: i_xx / (centroid.y - bounding_area.min_y),
+            section_modulus_y_left: i_yy / (centroid.x - bounding_area.min_x),
+            section_modulus_y_right: i_yy / (bounding_area.max_x - centroid.x),
+        }
+    }
+
+    /// Calculates area integrals using the Green's theorem, multiplied by the `sign`.
+    ///
+    /// Returned values: area, integral of x, integral of y, integral of y^2, integral of x^2,
+    /// integral of xy. Values are positive for counter-clockwise polygon and `sign` = 1.0.
+    fn get_area_integrals(&self, sign: f64) -> [f64; 6] {
+        let mut integrals = [0.0; 6];
+        let number_of_vertices = self.vertices.len();
+        for i in 0..number_of_vertices {
+            let current = self.vertices[i];
+            let next = self.vertices[(i + 1) % number_of_vertices];
+            let cross = current.x * next.y - next.x * current.y;
+            integrals[0] += cross / 2.0;
+            integrals[1] += (current.x + next.x) * cross / 6.0;
+            integrals[2] += (current.y + next.y) * cross / 6.0;
+            integrals[3] += (current.y * current.y + current.y * next.y + next.y * next.y) * cross / 12.0;
+            integrals[4] += (current.x * current.x + current.x * next.x + next.x * next.x) * cross / 12.0;
+            integrals[5] += (current.x * next.y + 2.0 * current.x * current.y + 2.0 * next.x * next.y + next.x * current.y) * cross / 24.0;
+        }
+
+        integrals.map(|value| value * sign)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::point2d::Point2D;
     use super::*;
 
+    #[test]
+    fn test_get_minimum_area_bounding_rectangle_rotated_rectangle() {
+        let angle: f64 = 0.3;
+        let (sin, cos) = angle.sin_cos();
+        let rotate = |x: f64, y: f64| Point2D::new(x * cos - y * sin + 5.0, x * sin + y * cos - 2.0);
+        let input = Polygon2D::new(vec![rotate(0.0, 0.0), rotate(3.0, 0.0), rotate(3.0, 1.0), rotate(1.5, 0.5), rotate(0.0, 1.0)]);
+
+        let actual = input.get_minimum_area_bounding_rectangle();
+
+        assert_eq!(actual.vertices.len(), 4);
+        assert!(!actual.is_clockwise());
+        let sides = [actual.vertices[0].get_distance_to_point(&actual.vertices[1]), actual.vertices[1].get_distance_to_point(&actual.vertices[2])];
+        assert!((sides[0] * sides[1] - 3.0).abs() < 1e-9);
+        assert!(input.get_bounding_area().get_area() > 3.5);
+    }
+
+    #[test]
+    fn test_get_minimum_area_bounding_rectangle_collinear_and_empty() {
+        let collinear = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(1.0, 1.0)]);
+
+        let actual = collinear.get_minimum_area_bounding_rectangle();
+
+        assert_eq!(actual.vertices.len(), 4);
+        assert!(actual.vertices.iter().all(|vertex| vertex.eq_with_tolerance(&Point2D::new(0.0, 0.0), 1e-12) || vertex.eq_with_tolerance(&Point2D::new(2.0, 2.0), 1e-12)));
+        assert!(Polygon2D::new(vec![]).get_minimum_area_bounding_rectangle().vertices.is_empty());
+    }
+
     #[test]
     fn test_is_clockwise_true() {
         let input = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(5.0, 10.0), Point2D::new(10.0, 0.0), Point2D::new(5.0, -10.0)]);
@@ -245,4 +413,53 @@ mod tests {
         
         assert_eq!(expected, actual);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_get_section_properties_clockwise_same_as_counter_clockwise() {
+        let counter_clockwise = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 1.0), Point2D::new(1.0, 1.0), Point2D::new(1.0, 3.0), Point2D::new(0.0, 3.0)]);
+        let clockwise = Polygon2D::new(counter_clockwise.vertices.iter().rev().copied().collect());
+
+        let expected = counter_clockwise.get_section_properties(&vec![]);
+        let actual = clockwise.get_section_properties(&vec![]);
+
+        assert!((expected.area - actual.area).abs() < 1e-12);
+        assert!((expected.i_xy - actual.i_xy).abs() < 1e-12);
+        assert!((expected.i_xx - actual.i_xx).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_section_properties_l_section() {
+        // L-section made of 4x1 horizontal leg and 1x2 vertical leg on top of it
+        let input = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 1.0), Point2D::new(1.0, 1.0), Point2D::new(1.0, 3.0), Point2D::new(0.0, 3.0)]);
+
+        let actual = input.get_section_properties(&vec![]);
+
+        // Parts: A1 = 4 at (2.0, 0.5), A2 = 2 at (0.5, 2.0)
+        let centroid = Point2D::new((4.0 * 2.0 + 2.0 * 0.5) / 6.0, (4.0 * 0.5 + 2.0 * 2.0) / 6.0);
+        let i_xx = 4.0 / 12.0 + 4.0 * (0.5 - centroid.y).powi(2) + 8.0 / 12.0 + 2.0 * (2.0 - centroid.y).powi(2);
+        let i_yy = 64.0 / 12.0 + 4.0 * (2.0 - centroid.x).powi(2) + 2.0 / 12.0 + 2.0 * (0.5 - centroid.x).powi(2);
+        let i_xy = 4.0 * (2.0 - centroid.x) * (0.5 - centroid.y) + 2.0 * (0.5 - centroid.x) * (2.0 - centroid.y);
+
+        assert_eq!(actual.area, 6.0);
+        assert!((actual.centroid.x - centroid.x).abs() < 1e-12);
+        assert!((actual.centroid.y - centroid.y).abs() < 1e-12);
+        assert!((actual.i_xx - i_xx).abs() < 1e-12);
+        assert!((actual.i_yy - i_yy).abs() < 1e-12);
+        assert!((actual.i_xy - i_xy).abs() < 1e-12);
+        assert!((actual.section_modulus_x_top - i_xx / (3.0 - centroid.y)).abs() < 1e-12);
+        assert!((actual.section_modulus_y_left - i_yy / centroid.x).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_section_properties_off_center_hole() {
+        let outer = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(10.0, 0.0), Point2D::new(10.0, 10.0), Point2D::new(0.0, 10.0)]);
+        let hole = Polygon2D::new(vec![Point2D::new(6.0, 6.0), Point2D::new(6.0, 8.0), Point2D::new(8.0, 8.0), Point2D::new(8.0, 6.0)]);
+
+        let actual = outer.get_section_properties(&vec![hole]);
+
+        assert_eq!(actual.area, 96.0);
+        assert!((actual.centroid.x - (100.0 * 5.0 - 4.0 * 7.0) / 96.0).abs() < 1e-12);
+        assert!((actual.centroid.y - (100.0 * 5.0 - 4.0 * 7.0) / 96.0).abs() < 1e-12);
+        assert!(actual.i_xy < 0.0);
+    }
+}
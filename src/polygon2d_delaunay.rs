@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::point2d::Point2D;
+use crate::polygon2d::Polygon2D;
+
+/// The size of the triangle enclosing all the points used by [Polygon2D::delaunay], relative to
+/// the size of their bounding area.
+const SUPER_TRIANGLE_SIZE_FACTOR: f64 = 100.0;
+
+/// The triangle of [Polygon2D::delaunay] with its circumcircle.
+struct DelaunayTriangle {
+    vertex_ids: [usize; 3],
+    center: Point2D,
+    radius: f64,
+}
+
+impl Polygon2D {
+    /// Creates the Delaunay triangulation of the given `points`, so no point lies inside the
+    /// circumcircle of any face. It's useful e.g. for TINs (triangulated irregular networks) made
+    /// of scattered data.
+    ///
+    /// It uses the Bowyer-Watson algorithm. Unlike [Polygon2D::triangulate_raw] there is no
+    /// boundary, so faces cover the convex hull of the `points`.
+    ///
+    /// Vertices of the [Mesh] are the `points` in the same order (with z = 0.0), so data
+    /// assigned to the points can be used directly for its vertices. Duplicated points are
+    /// used only once, the other copies stay as unused vertices. Faces point up (+z).
+    ///
+    /// If there are less than 3 unique points, or all of them lie on a single line, the [Mesh]
+    /// has no faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::polygon2d::Polygon2D;
+    ///
+    /// let input = vec![
+    ///     Point2D::new(0.0, 0.0),
+    ///     Point2D::new(4.0, 0.0),
+    ///     Point2D::new(4.0, 3.0),
+    ///     Point2D::new(0.0, 3.0),
+    ///     Point2D::new(1.0, 1.0),
+    /// ];
+    ///
+    /// let actual = Polygon2D::delaunay(&input);
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 5);
+    /// assert_eq!(actual.get_number_of_faces(), 4);
+    /// assert!((actual.get_area() - 12.0).abs() < 1e-12);
+    /// ```
+    pub fn delaunay(points: &Vec<Point2D>) -> Mesh {
+        let number_of_points = points.len();
+        let coordinates: Vec<f64> = points.iter().flat_map(|point| [point.x, point.y, 0.0]).collect();
+        if number_of_points < 3 {
+            return Mesh::new(coordinates, Vec::new());
+        }
+
+        // Points sorted along x, so triangles which can't change anymore are put aside early
+        let mut sorted_ids: Vec<usize> = (0..number_of_points).collect();
+        sorted_ids.sort_by(|a, b| points[*a].x.total_cmp(&points[*b].x).then(points[*a].y.total_cmp(&points[*b].y)));
+
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for point in points {
+            (min_x, min_y, max_x, max_y) = (min_x.min(point.x), min_y.min(point.y), max_x.max(point.x), max_y.max(point.y));
+        }
+        let size = (max_x - min_x).max(max_y - min_y).max(f64::EPSILON) * SUPER_TRIANGLE_SIZE_FACTOR;
+        let (middle_x, middle_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+        let mut all_points = points.clone();
+        all_points.push(Point2D::new(middle_x - size, middle_y - size));
+        all_points.push(Point2D::new(middle_x + size, middle_y - size));
+        all_points.push(Point2D::new(middle_x, middle_y + size));
+
+        let mut triangles = vec![DelaunayTriangle::new(&all_points, [number_of_points, number_of_points + 1, number_of_points + 2])];
+        let mut completed: Vec<DelaunayTriangle> = Vec::new();
+        let mut previous_id: Option<usize> = None;
+        for id in sorted_ids {
+            let point = &all_points[id];
+            if previous_id.is_some_and(|previous_id| all_points[previous_id] == *point) {
+                continue;
+            }
+            previous_id = Some(id);
+
+            // Edges of removed triangles, the ones used twice are inside the cavity
+            let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+            let mut kept: Vec<DelaunayTriangle> = Vec::with_capacity(triangles.len());
+            for triangle in triangles {
+                if triangle.center.x + triangle.radius < point.x {
+                    completed.push(triangle);
+                } else if triangle.is_in_circumcircle(&all_points, point) {
+                    for i in 0..3 {
+                        let (start, end) = (triangle.vertex_ids[i], triangle.vertex_ids[(i + 1) % 3]);
+                        *edges.entry((start.min(end), start.max(end))).or_default() += 1;
+                    }
+                } else {
+                    kept.push(triangle);
+                }
+            }
+            triangles = kept;
+            for ((start, end), count) in edges {
+                if count == 1 {
+                    triangles.push(DelaunayTriangle::new(&all_points, [start, end, id]));
+                }
+            }
+        }
+
+        let mut indices: Vec<usize> = Vec::new();
+        for triangle in completed.iter().chain(triangles.iter()) {
+            let [first, second, third] = triangle.vertex_ids;
+            if first >= number_of_points || second >= number_of_points || third >= number_of_points {
+                continue;
+            }
+            match get_orientation(&all_points[first], &all_points[second], &all_points[third]) {
+                orientation if orientation > 0.0 => indices.extend_from_slice(&[first, second, third]),
+                orientation if orientation < 0.0 => indices.extend_from_slice(&[first, third, second]),
+                _ => {},
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+}
+
+impl DelaunayTriangle {
+    fn new(points: &[Point2D], vertex_ids: [usize; 3]) -> DelaunayTriangle {
+        let [a, b, c] = vertex_ids.map(|id| points[id]);
+        let (bx, by, cx, cy) = (b.x - a.x, b.y - a.y, c.x - a.x, c.y - a.y);
+        let d = 2.0 * (bx * cy - by * cx);
+        let (b_squared, c_squared) = (bx * bx + by * by, cx * cx + cy * cy);
+        let (ux, uy) = ((cy * b_squared - by * c_squared) / d, (bx * c_squared - cx * b_squared) / d);
+        let radius = if d == 0.0 { f64::INFINITY } else { (ux * ux + uy * uy).sqrt() };
+
+        DelaunayTriangle { vertex_ids, center: Point2D::new(a.x + ux, a.y + uy), radius }
+    }
+
+    /// Checks if the `point` is strictly inside the circumcircle, using the in-circle
+    /// determinant, so points exactly on the circle (e.g. corners of a grid) are outside.
+    fn is_in_circumcircle(&self, points: &[Point2D], point: &Point2D) -> bool {
+        let [a, b, c] = self.vertex_ids.map(|id| points[id]);
+        let (ax, ay, bx, by, cx, cy) = (a.x - point.x, a.y - point.y, b.x - point.x, b.y - point.y, c.x - point.x, c.y - point.y);
+        let determinant = (ax * ax + ay * ay) * (bx * cy - cx * by)
+            - (bx * bx + by * by) * (ax * cy - cx * ay)
+            + (cx * cx + cy * cy) * (ax * by - bx * ay);
+
+        let orientation = get_orientation(&a, &b, &c);
+
+        orientation != 0.0 && determinant * orientation.signum() > 0.0
+    }
+}
+
+/// Gets twice the signed area of the triangle, positive if it's anticlockwise.
+fn get_orientation(a: &Point2D, b: &Point2D, c: &Point2D) -> f64 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    fn is_delaunay(mesh: &Mesh, points: &[Point2D]) -> bool {
+        mesh.indices.chunks(3).all(|face| {
+            let triangle = DelaunayTriangle::new(points, [face[0], face[1], face[2]]);
+            points.iter().all(|point| !triangle.is_in_circumcircle(points, point))
+        })
+    }
+
+    #[test]
+    fn test_delaunay_random_points() {
+        let mut random = Random::new(3);
+        let points: Vec<Point2D> = (0..300).map(|_| Point2D::new(random.next() * 50.0, random.next() * 20.0)).collect();
+
+        let actual = Polygon2D::delaunay(&points);
+
+        assert_eq!(actual.get_number_of_vertices(), 300);
+        assert!(is_delaunay(&actual, &points));
+        assert!(actual.to_triangles().iter().all(|triangle| triangle.get_normal_vector_unitized().z > 0.0));
+        assert!(actual.get_non_manifold_vertices().is_empty());
+        // Euler's formula for the triangulation of a disk: faces = 2 * vertices - 2 - boundary vertices
+        let number_of_boundary_vertices = actual.get_boundary_loops()[0].vertices.len();
+        assert_eq!(actual.get_boundary_loops().len(), 1);
+        assert_eq!(actual.get_number_of_faces(), 2 * 300 - 2 - number_of_boundary_vertices);
+    }
+
+    #[test]
+    fn test_delaunay_grid() {
+        let points: Vec<Point2D> = (0..36).map(|i| Point2D::new((i % 6) as f64, (i / 6) as f64)).collect();
+
+        let actual = Polygon2D::delaunay(&points);
+
+        assert_eq!(actual.get_number_of_faces(), 2 * 5 * 5);
+        assert!((actual.get_area() - 25.0).abs() < 1e-12);
+        assert!(actual.get_non_manifold_vertices().is_empty());
+    }
+
+    #[test]
+    fn test_delaunay_duplicated_points() {
+        let points = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 0.0),
+            Point2D::new(0.0, 1.0),
+            Point2D::new(1.0, 0.0),
+        ];
+
+        let actual = Polygon2D::delaunay(&points);
+
+        assert_eq!(actual.get_number_of_vertices(), 4);
+        assert_eq!(actual.get_number_of_faces(), 1);
+    }
+
+    #[test]
+    fn test_delaunay_degenerate() {
+        let collinear = vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0), Point2D::new(2.0, 2.0)];
+        assert_eq!(Polygon2D::delaunay(&collinear).get_number_of_faces(), 0);
+
+        let too_few = vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)];
+        assert_eq!(Polygon2D::delaunay(&too_few), Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![]));
+    }
+}
@@ -0,0 +1,483 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+use crate::edge::Edge;
+use crate::point2d::Point2D;
+use crate::polygon2d::Polygon2D;
+use crate::straight_skeleton::StraightSkeleton;
+
+/// Distances smaller than this part of the size of the region are treated as zero by
+/// [Polygon2D::get_straight_skeleton].
+const RELATIVE_TOLERANCE: f64 = 1e-9;
+
+/// The edge of the boundary, with the region on its left side.
+struct ContourEdge {
+    start: [f64; 2],
+    direction: [f64; 2],
+    normal: [f64; 2],
+}
+
+/// The vertex of the shrinking boundary, moving with the constant velocity between its edges.
+struct WavefrontVertex {
+    /// Position at the offset distance (time) 0.0, so the position at the time t is
+    /// `base + velocity * t`.
+    base: [f64; 2],
+    velocity: [f64; 2],
+    /// False for vertices between opposite edges, which meet there, so the vertex doesn't move.
+    is_moving: bool,
+    node_id: usize,
+    left_edge: usize,
+    right_edge: usize,
+    previous: usize,
+    next: usize,
+    is_active: bool,
+}
+
+enum EventKind {
+    /// The wavefront edge between the vertex and the next one shrinks to zero.
+    Edge(usize, usize),
+    /// The reflex vertex hits the wavefront edge of the contour edge, splitting it.
+    Split(usize, usize),
+}
+
+struct Event {
+    time: f64,
+    kind: EventKind,
+}
+
+impl Event {
+    fn is_edge_event(&self) -> bool {
+        matches!(self.kind, EventKind::Edge(_, _))
+    }
+}
+
+impl PartialEq for Event {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Event {}
+
+impl PartialOrd for Event {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Event {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, so the BinaryHeap pops the earliest event first, edge events before splits.
+        other.time.total_cmp(&self.time).then(self.is_edge_event().cmp(&other.is_edge_event()))
+    }
+}
+
+impl Polygon2D {
+    /// Gets the [StraightSkeleton] of the region bounded by the [Polygon2D] with `holes`.
+    ///
+    /// The boundary of the region is shrunk with the constant speed (every edge moves parallel
+    /// to itself), the skeleton is made of paths of its vertices. Vertices meet when an edge
+    /// shrinks to zero, or split the boundary when they hit another edge. Heights of nodes
+    /// (offset distances at which they're reached) make it e.g. ridges and hips of a roof with
+    /// the constant slope, see [StraightSkeleton::to_points].
+    ///
+    /// Nodes start with vertices of the [Polygon2D], then vertices of `holes` in order. The
+    /// orientation of the [Polygon2D] and `holes` doesn't matter.
+    ///
+    /// The [Polygon2D] and `holes` shouldn't have duplicated vertices or self-intersections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::polygon2d::Polygon2D;
+    ///
+    /// // Rectangle gets the ridge along its longer side
+    /// let input = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(4.0, 0.0), Point2D::new(4.0, 2.0), Point2D::new(0.0, 2.0)]);
+    ///
+    /// let actual = input.get_straight_skeleton(&[]);
+    ///
+    /// assert_eq!(actual.nodes.len(), 6);
+    /// assert_eq!(actual.arcs.len(), 5);
+    /// assert!(actual.nodes[4..].contains(&Point2D::new(1.0, 1.0)));
+    /// assert!(actual.nodes[4..].contains(&Point2D::new(3.0, 1.0)));
+    /// assert_eq!(actual.heights[4..], [1.0, 1.0]);
+    /// ```
+    pub fn get_straight_skeleton(&self, holes: &[Polygon2D]) -> StraightSkeleton {
+        let mut builder = SkeletonBuilder {
+            nodes: Vec::new(),
+            heights: Vec::new(),
+            arcs: Vec::new(),
+            edges: Vec::new(),
+            vertices: Vec::new(),
+            events: BinaryHeap::new(),
+            tolerance: 0.0,
+        };
+
+        let mut loops: Vec<Vec<usize>> = Vec::with_capacity(holes.len() + 1);
+        for (loop_id, polygon) in std::iter::once(self).chain(holes.iter()).enumerate() {
+            let first_node_id = builder.nodes.len();
+            builder.nodes.extend(polygon.vertices.iter().copied());
+            builder.heights.extend(std::iter::repeat_n(0.0, polygon.vertices.len()));
+            let mut node_ids: Vec<usize> = (first_node_id..builder.nodes.len()).collect();
+            // The outer boundary goes anticlockwise and holes go clockwise, so the region is on
+            // the left side of all edges
+            if polygon.is_clockwise() == (loop_id == 0) {
+                node_ids.reverse();
+            }
+            loops.push(node_ids);
+        }
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for node in &builder.nodes {
+            (min_x, min_y, max_x, max_y) = (min_x.min(node.x), min_y.min(node.y), max_x.max(node.x), max_y.max(node.y));
+        }
+        builder.tolerance = (max_x - min_x).max(max_y - min_y) * RELATIVE_TOLERANCE;
+
+        for node_ids in &loops {
+            let number_of_loop_vertices = node_ids.len();
+            let first_edge_id = builder.edges.len();
+            for i in 0..number_of_loop_vertices {
+                let (start, end) = (builder.nodes[node_ids[i]], builder.nodes[node_ids[(i + 1) % number_of_loop_vertices]]);
+                let length = start.get_distance_to_point(&end);
+                let direction = [(end.x - start.x) / length, (end.y - start.y) / length];
+                builder.edges.push(ContourEdge { start: [start.x, start.y], direction, normal: [-direction[1], direction[0]] });
+            }
+            let first_vertex_id = builder.vertices.len();
+            for (i, node_id) in node_ids.iter().enumerate() {
+                let node = builder.nodes[*node_id];
+                let left_edge = first_edge_id + (i + number_of_loop_vertices - 1) % number_of_loop_vertices;
+                let vertex_id = builder.add_vertex([node.x, node.y], 0.0, *node_id, left_edge, first_edge_id + i);
+                builder.vertices[vertex_id].previous = first_vertex_id + (i + number_of_loop_vertices - 1) % number_of_loop_vertices;
+                builder.vertices[vertex_id].next = first_vertex_id + (i + 1) % number_of_loop_vertices;
+            }
+        }
+        for vertex_id in 0..builder.vertices.len() {
+            builder.push_events(vertex_id, 0.0);
+        }
+
+        while let Some(event) = builder.events.pop() {
+            match event.kind {
+                EventKind::Edge(first, second) => builder.process_edge_event(first, second, event.time),
+                EventKind::Split(vertex_id, edge_id) => builder.process_split_event(vertex_id, edge_id, event.time),
+            }
+        }
+
+        let mut unique_arcs: HashSet<Edge> = HashSet::new();
+        let arcs = builder.arcs.into_iter().filter(|arc| unique_arcs.insert(*arc)).collect();
+
+        StraightSkeleton::new(builder.nodes, builder.heights, arcs)
+    }
+}
+
+/// The state of [Polygon2D::get_straight_skeleton] calculation.
+struct SkeletonBuilder {
+    nodes: Vec<Point2D>,
+    heights: Vec<f64>,
+    arcs: Vec<Edge>,
+    edges: Vec<ContourEdge>,
+    vertices: Vec<WavefrontVertex>,
+    events: BinaryHeap<Event>,
+    tolerance: f64,
+}
+
+impl SkeletonBuilder {
+    fn get_position(&self, vertex_id: usize, time: f64) -> [f64; 2] {
+        let vertex = &self.vertices[vertex_id];
+        [vertex.base[0] + vertex.velocity[0] * time, vertex.base[1] + vertex.velocity[1] * time]
+    }
+
+    /// Adds the vertex, not linked with other ones yet.
+    fn add_vertex(&mut self, position: [f64; 2], time: f64, node_id: usize, left_edge: usize, right_edge: usize) -> usize {
+        let (left_normal, right_normal) = (self.edges[left_edge].normal, self.edges[right_edge].normal);
+        // The vertex stays on both edges moved by the time, so velocity · normal = 1 for both
+        let determinant = get_cross_product(left_normal, right_normal);
+        let (velocity, is_moving) = if determinant.abs() > RELATIVE_TOLERANCE {
+            ([(right_normal[1] - left_normal[1]) / determinant, (left_normal[0] - right_normal[0]) / determinant], true)
+        } else if get_dot_product(left_normal, right_normal) > 0.0 {
+            (left_normal, true)
+        } else {
+            ([0.0, 0.0], false)
+        };
+
+        self.vertices.push(WavefrontVertex {
+            base: [position[0] - velocity[0] * time, position[1] - velocity[1] * time],
+            velocity,
+            is_moving,
+            node_id,
+            left_edge,
+            right_edge,
+            previous: 0,
+            next: 0,
+            is_active: true,
+        });
+
+        self.vertices.len() - 1
+    }
+
+    fn push_events(&mut self, vertex_id: usize, time: f64) {
+        let (previous, next) = (self.vertices[vertex_id].previous, self.vertices[vertex_id].next);
+        self.push_edge_event(previous, vertex_id, time);
+        self.push_edge_event(vertex_id, next, time);
+
+        let vertex = &self.vertices[vertex_id];
+        let is_reflex = get_cross_product(self.edges[vertex.left_edge].direction, self.edges[vertex.right_edge].direction) < 0.0;
+        if !vertex.is_moving || !is_reflex {
+            return;
+        }
+        for (edge_id, edge) in self.edges.iter().enumerate() {
+            if edge_id == vertex.left_edge || edge_id == vertex.right_edge {
+                continue;
+            }
+            let denominator = 1.0 - get_dot_product(vertex.velocity, edge.normal);
+            if denominator <= RELATIVE_TOLERANCE {
+                continue;
+            }
+            let split_time = get_dot_product([vertex.base[0] - edge.start[0], vertex.base[1] - edge.start[1]], edge.normal) / denominator;
+            if split_time >= time {
+                self.events.push(Event { time: split_time, kind: EventKind::Split(vertex_id, edge_id) });
+            }
+        }
+    }
+
+    fn push_edge_event(&mut self, first: usize, second: usize, time: f64) {
+        let (first_vertex, second_vertex) = (&self.vertices[first], &self.vertices[second]);
+        if !first_vertex.is_moving || !second_vertex.is_moving {
+            return;
+        }
+        let direction = self.edges[first_vertex.right_edge].direction;
+        let rate = get_dot_product([second_vertex.velocity[0] - first_vertex.velocity[0], second_vertex.velocity[1] - first_vertex.velocity[1]], direction);
+        if rate >= -RELATIVE_TOLERANCE {
+            return;
+        }
+        let collapse_time = -get_dot_product([second_vertex.base[0] - first_vertex.base[0], second_vertex.base[1] - first_vertex.base[1]], direction) / rate;
+        self.events.push(Event { time: collapse_time.max(time), kind: EventKind::Edge(first, second) });
+    }
+
+    /// Gets the node of one of the `vertex_ids` if it's at the `position` at the `time`,
+    /// otherwise adds a new one.
+    fn get_node_id(&mut self, position: [f64; 2], time: f64, vertex_ids: &[usize]) -> usize {
+        let point = Point2D::new(position[0], position[1]);
+        for vertex_id in vertex_ids {
+            let node_id = self.vertices[*vertex_id].node_id;
+            if (self.heights[node_id] - time).abs() <= self.tolerance && self.nodes[node_id].get_distance_to_point(&point) <= self.tolerance {
+                return node_id;
+            }
+        }
+        self.nodes.push(point);
+        self.heights.push(time);
+
+        self.nodes.len() - 1
+    }
+
+    fn add_arc(&mut self, vertex_id: usize, node_id: usize) {
+        let start = self.vertices[vertex_id].node_id;
+        if start != node_id {
+            self.arcs.push(Edge::new(start, node_id));
+        }
+    }
+
+    fn process_edge_event(&mut self, first: usize, second: usize, time: f64) {
+        if !self.vertices[first].is_active || !self.vertices[second].is_active || self.vertices[first].next != second {
+            return;
+        }
+        let position = self.get_position(first, time);
+        let (previous, next) = (self.vertices[first].previous, self.vertices[second].next);
+        let node_id = self.get_node_id(position, time, &[first, second, previous]);
+        self.add_arc(first, node_id);
+        self.add_arc(second, node_id);
+        self.vertices[first].is_active = false;
+        self.vertices[second].is_active = false;
+        if previous == second {
+            return;
+        }
+        if previous == next {
+            // The last triangle of this part of the boundary collapses into a single node
+            self.add_arc(previous, node_id);
+            self.vertices[previous].is_active = false;
+            return;
+        }
+
+        let vertex_id = self.add_vertex(position, time, node_id, self.vertices[first].left_edge, self.vertices[second].right_edge);
+        self.link(previous, vertex_id);
+        self.link(vertex_id, next);
+        self.finish_vertex(vertex_id, time);
+    }
+
+    fn process_split_event(&mut self, vertex_id: usize, edge_id: usize, time: f64) {
+        if !self.vertices[vertex_id].is_active {
+            return;
+        }
+        let position = self.get_position(vertex_id, time);
+        let direction = self.edges[edge_id].direction;
+        // The contour edge can be split into many parts already, the one being hit is needed
+        let Some(start) = (0..self.vertices.len()).find(|&start| {
+            let vertex = &self.vertices[start];
+            if !vertex.is_active || vertex.right_edge != edge_id || start == vertex_id || vertex.next == vertex_id {
+                return false;
+            }
+            let (start_position, end_position) = (self.get_position(start, time), self.get_position(vertex.next, time));
+            get_dot_product([position[0] - start_position[0], position[1] - start_position[1]], direction) >= -self.tolerance &&
+                get_dot_product([end_position[0] - position[0], end_position[1] - position[1]], direction) >= -self.tolerance
+        }) else {
+            return;
+        };
+
+        let node_id = self.get_node_id(position, time, &[vertex_id]);
+        self.add_arc(vertex_id, node_id);
+        self.vertices[vertex_id].is_active = false;
+        let (previous, next, end) = (self.vertices[vertex_id].previous, self.vertices[vertex_id].next, self.vertices[start].next);
+        let (left_edge, right_edge) = (self.vertices[vertex_id].left_edge, self.vertices[vertex_id].right_edge);
+
+        let first = self.add_vertex(position, time, node_id, left_edge, edge_id);
+        self.link(previous, first);
+        self.link(first, end);
+        let second = self.add_vertex(position, time, node_id, edge_id, right_edge);
+        self.link(start, second);
+        self.link(second, next);
+        self.finish_vertex(first, time);
+        self.finish_vertex(second, time);
+    }
+
+    fn link(&mut self, first: usize, second: usize) {
+        self.vertices[first].next = second;
+        self.vertices[second].previous = first;
+    }
+
+    /// Closes the part of the boundary if only 2 vertices are left, otherwise adds events of
+    /// the new vertex.
+    fn finish_vertex(&mut self, vertex_id: usize, time: f64) {
+        if !self.vertices[vertex_id].is_active {
+            return;
+        }
+        let (previous, next) = (self.vertices[vertex_id].previous, self.vertices[vertex_id].next);
+        if previous == next {
+            let node_id = self.vertices[vertex_id].node_id;
+            self.add_arc(next, node_id);
+            self.vertices[vertex_id].is_active = false;
+            self.vertices[next].is_active = false;
+            return;
+        }
+        self.push_events(vertex_id, time);
+    }
+}
+
+fn get_dot_product(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn get_cross_product(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Random;
+
+    /// Gets degrees of nodes, checking that the skeleton is connected.
+    fn get_degrees_of_connected(skeleton: &StraightSkeleton) -> Vec<usize> {
+        let number_of_nodes = skeleton.nodes.len();
+        let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); number_of_nodes];
+        for arc in &skeleton.arcs {
+            neighbours[arc.start].push(arc.end);
+            neighbours[arc.end].push(arc.start);
+        }
+        let mut is_visited = vec![false; number_of_nodes];
+        let mut stack = vec![0];
+        is_visited[0] = true;
+        while let Some(node_id) = stack.pop() {
+            for neighbour in &neighbours[node_id] {
+                if !is_visited[*neighbour] {
+                    is_visited[*neighbour] = true;
+                    stack.push(*neighbour);
+                }
+            }
+        }
+        assert!(is_visited.iter().all(|is_visited| *is_visited));
+
+        neighbours.iter().map(|neighbours| neighbours.len()).collect()
+    }
+
+    fn get_random_star(random: &mut Random, number_of_vertices: usize, center: Point2D, radius: f64) -> Polygon2D {
+        Polygon2D::new((0..number_of_vertices).map(|i| {
+            let angle = 2.0 * std::f64::consts::PI * (i as f64 + 0.4 * random.next()) / number_of_vertices as f64;
+            let distance = radius * (0.75 + 0.25 * random.next());
+            Point2D::new(center.x + distance * angle.cos(), center.y + distance * angle.sin())
+        }).collect())
+    }
+
+    #[test]
+    fn test_get_straight_skeleton_clockwise_square() {
+        let input = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(0.0, 2.0), Point2D::new(2.0, 2.0), Point2D::new(2.0, 0.0)]);
+
+        let actual = input.get_straight_skeleton(&[]);
+
+        assert_eq!(actual.nodes.len(), 5);
+        assert_eq!(actual.nodes[4], Point2D::new(1.0, 1.0));
+        assert_eq!(actual.heights[4], 1.0);
+        assert_eq!(get_degrees_of_connected(&actual), vec![1, 1, 1, 1, 4]);
+    }
+
+    #[test]
+    fn test_get_straight_skeleton_l_shape() {
+        let input = Polygon2D::new(vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(6.0, 0.0),
+            Point2D::new(6.0, 2.0),
+            Point2D::new(2.0, 2.0),
+            Point2D::new(2.0, 5.0),
+            Point2D::new(0.0, 5.0),
+        ]);
+
+        let actual = input.get_straight_skeleton(&[]);
+
+        // Both arms meet at the node reached by the reflex corner
+        assert_eq!(actual.nodes.len(), 9);
+        assert_eq!(actual.arcs.len(), 8);
+        assert!(actual.heights[6..].iter().all(|height| *height == 1.0));
+        let corner = actual.nodes.iter().position(|node| *node == Point2D::new(1.0, 1.0)).unwrap();
+        let right_end = actual.nodes.iter().position(|node| *node == Point2D::new(5.0, 1.0)).unwrap();
+        let top_end = actual.nodes.iter().position(|node| *node == Point2D::new(1.0, 4.0)).unwrap();
+        for arc in [Edge::new(0, corner), Edge::new(3, corner), Edge::new(right_end, corner), Edge::new(top_end, corner)] {
+            assert!(actual.arcs.contains(&arc));
+        }
+        let degrees = get_degrees_of_connected(&actual);
+        assert!(degrees[..6].iter().all(|degree| *degree == 1));
+    }
+
+    #[test]
+    fn test_get_straight_skeleton_random_polygons() {
+        let mut random = Random::new(11);
+        for _ in 0..20 {
+            let input = get_random_star(&mut random, 15, Point2D::new(0.0, 0.0), 10.0);
+
+            let actual = input.get_straight_skeleton(&[]);
+
+            // Tree with leaves at polygon's vertices and all other nodes of the degree 3
+            let degrees = get_degrees_of_connected(&actual);
+            assert_eq!(actual.arcs.len(), 2 * 15 - 3);
+            assert!(degrees[..15].iter().all(|degree| *degree == 1));
+            assert!(degrees[15..].iter().all(|degree| *degree == 3));
+            assert!(actual.heights[15..].iter().all(|height| *height > 0.0));
+        }
+    }
+
+    #[test]
+    fn test_get_straight_skeleton_with_holes() {
+        let mut random = Random::new(5);
+        for _ in 0..10 {
+            let input = get_random_star(&mut random, 12, Point2D::new(0.0, 0.0), 20.0);
+            let mut hole = get_random_star(&mut random, 5, Point2D::new(4.0, 1.0), 3.0);
+            hole.vertices.reverse();
+            let other_hole = get_random_star(&mut random, 4, Point2D::new(-6.0, -2.0), 2.0);
+
+            let actual = input.get_straight_skeleton(&[hole, other_hole]);
+
+            // Each hole adds a cycle
+            let degrees = get_degrees_of_connected(&actual);
+            assert_eq!(actual.arcs.len(), 2 * 21 - 3 + 3 * 2);
+            assert!(degrees[..21].iter().all(|degree| *degree == 1));
+            assert!(degrees[21..].iter().all(|degree| *degree == 3));
+        }
+    }
+}
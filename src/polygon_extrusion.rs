@@ -0,0 +1,184 @@
+use crate::mesh::Mesh;
+use crate::mesh_hole_filling::get_newell_normal;
+use crate::point::Point;
+use crate::polygon::Polygon;
+use crate::vector::Vector;
+
+impl Polygon {
+    /// Extrudes the [Polygon] along the given [Vector] into a closed, capped solid [Mesh].
+    ///
+    /// Caps are triangulated with [Polygon::triangulate_raw]. The bottom cap stays at the
+    /// [Polygon], the top one is moved by the `vector`. Faces point outside regardless of the
+    /// orientation of the [Polygon].
+    ///
+    /// Vertices are welded: first go vertices of the bottom cap (in the order of the
+    /// [Polygon]'s vertices), then the same vertices of the top cap.
+    ///
+    /// The [Polygon] should be planar and the `vector` shouldn't be parallel to its plane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polygon::Polygon;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Polygon::new(vec![
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(4.0, 0.0, 0.0),
+    ///     Point::new(4.0, 3.0, 0.0),
+    ///     Point::new(0.0, 3.0, 0.0),
+    /// ]);
+    ///
+    /// let actual = input.extrude(&Vector::new(0.0, 0.0, 2.0));
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 8);
+    /// assert_eq!(actual.get_number_of_faces(), 12);
+    /// assert!(actual.is_watertight());
+    /// assert!((actual.get_volume().unwrap() - 24.0).abs() < 1e-9);
+    /// ```
+    pub fn extrude(&self, vector: &Vector) -> Mesh {
+        self.extrude_with_holes(&Vec::new(), vector)
+    }
+
+    /// Extrudes the [Polygon] with holes along the given [Vector] into a closed, capped solid
+    /// [Mesh], see [Polygon::extrude].
+    ///
+    /// Caps are triangulated with [Polygon::triangulate_raw_with_holes], each hole gets its own
+    /// side walls. Vertices of holes go after vertices of the [Polygon], in the order of `holes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polygon::Polygon;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Polygon::new(vec![
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(4.0, 0.0, 0.0),
+    ///     Point::new(4.0, 4.0, 0.0),
+    ///     Point::new(0.0, 4.0, 0.0),
+    /// ]);
+    /// let hole = Polygon::new(vec![
+    ///     Point::new(1.0, 1.0, 0.0),
+    ///     Point::new(3.0, 1.0, 0.0),
+    ///     Point::new(3.0, 3.0, 0.0),
+    ///     Point::new(1.0, 3.0, 0.0),
+    /// ]);
+    ///
+    /// let actual = input.extrude_with_holes(&vec![hole], &Vector::new(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 16);
+    /// assert!(actual.is_watertight());
+    /// assert!((actual.get_volume().unwrap() - 12.0).abs() < 1e-9);
+    /// ```
+    pub fn extrude_with_holes(&self, holes: &Vec<Polygon>, vector: &Vector) -> Mesh {
+        let loops: Vec<&Vec<Point>> = std::iter::once(&self.vertices).chain(holes.iter().map(|hole| &hole.vertices)).collect();
+        let bottom_points: Vec<Point> = loops.iter().flat_map(|vertices| vertices.iter().copied()).collect();
+        let number_of_cap_vertices = bottom_points.len();
+
+        let mut coordinates: Vec<f64> = Vec::with_capacity(number_of_cap_vertices * 6);
+        for point in &bottom_points {
+            coordinates.extend_from_slice(&[point.x, point.y, point.z]);
+        }
+        for point in &bottom_points {
+            let top_point = *point + *vector;
+            coordinates.extend_from_slice(&[top_point.x, top_point.y, top_point.z]);
+        }
+
+        let mut indices: Vec<usize> = Vec::new();
+        for [first, second, third] in self.get_triangulation_vertex_ids(holes) {
+            let normal = Vector::from_2_points(&bottom_points[first], &bottom_points[second])
+                .get_cross_product(&Vector::from_2_points(&bottom_points[first], &bottom_points[third]));
+            // Bottom cap points against the vector, top cap along it
+            if normal.get_dot_product(vector) > 0.0 {
+                indices.extend_from_slice(&[first, third, second]);
+                indices.extend_from_slice(&[first + number_of_cap_vertices, second + number_of_cap_vertices, third + number_of_cap_vertices]);
+            } else {
+                indices.extend_from_slice(&[first, second, third]);
+                indices.extend_from_slice(&[first + number_of_cap_vertices, third + number_of_cap_vertices, second + number_of_cap_vertices]);
+            }
+        }
+
+        let mut first_vertex_id = 0;
+        for (loop_id, vertices) in loops.iter().enumerate() {
+            let number_of_loop_vertices = vertices.len();
+            // Walls point outside if the outer loop goes anticlockwise and holes go clockwise,
+            // looking against the vector
+            let is_anticlockwise = get_newell_normal(vertices).get_dot_product(vector) > 0.0;
+            let is_reversed = is_anticlockwise == (loop_id != 0);
+            for i in 0..number_of_loop_vertices {
+                let (mut start, mut end) = (first_vertex_id + i, first_vertex_id + (i + 1) % number_of_loop_vertices);
+                if is_reversed {
+                    (start, end) = (end, start);
+                }
+                let (top_start, top_end) = (start + number_of_cap_vertices, end + number_of_cap_vertices);
+                indices.extend_from_slice(&[start, end, top_end, start, top_end, top_start]);
+            }
+            first_vertex_id += number_of_loop_vertices;
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_square(size: f64, z: f64) -> Polygon {
+        Polygon::new(vec![
+            Point::new(0.0, 0.0, z),
+            Point::new(size, 0.0, z),
+            Point::new(size, size, z),
+            Point::new(0.0, size, z),
+        ])
+    }
+
+    #[test]
+    fn test_extrude() {
+        let input = get_square(2.0, 1.0);
+
+        let actual = input.extrude(&Vector::new(0.0, 0.0, 3.0));
+
+        assert_eq!(actual.get_number_of_vertices(), 8);
+        assert_eq!(actual.get_number_of_faces(), 12);
+        assert_eq!(actual.to_points()[4], Point::new(0.0, 0.0, 4.0));
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_extrude_reversed_polygon_and_vector() {
+        let mut input = get_square(2.0, 0.0);
+        input.vertices.reverse();
+
+        let actual = input.extrude(&Vector::new(1.0, 0.0, -3.0));
+
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_extrude_with_holes() {
+        let input = get_square(6.0, 0.0);
+        let hole_a = Polygon::new(vec![
+            Point::new(1.0, 1.0, 0.0),
+            Point::new(1.0, 2.0, 0.0),
+            Point::new(2.0, 2.0, 0.0),
+            Point::new(2.0, 1.0, 0.0),
+        ]);
+        let hole_b = Polygon::new(vec![
+            Point::new(3.0, 3.0, 0.0),
+            Point::new(5.0, 3.0, 0.0),
+            Point::new(4.0, 5.0, 0.0),
+        ]);
+
+        let actual = input.extrude_with_holes(&vec![hole_a, hole_b], &Vector::new(0.0, 0.0, 2.0));
+
+        assert_eq!(actual.get_number_of_vertices(), 22);
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - (36.0 - 1.0 - 2.0) * 2.0).abs() < 1e-9);
+    }
+}
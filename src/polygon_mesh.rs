@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents a Mesh object in three-dimensional space with polygonal faces (triangles, quads
+/// and ngons).
+///
+/// BIM and CAD sources are usually made of quads and ngons, so keeping them allows to postpone
+/// the triangulation, without losing the structure of the faces. It can be converted to the
+/// triangle [Mesh](crate::mesh::Mesh) with [PolygonMesh::to_mesh], and back with
+/// [Mesh::to_polygon_mesh](crate::mesh::Mesh::to_polygon_mesh).
+///
+/// *Coordinates* is a flat list of `f64` describing the vertices (points) like this:
+///
+/// [x0, y0, z0, x1, y1, z1, ... , xN, yN, zN]
+///
+/// *Faces* is a list of faces, each of them is a list of at least 3 vertex ids. The orientation
+/// of a face is described by a right hand thumb.
+///
+/// # Example
+///
+/// Here is an example with a square made of 1 quad face
+///
+/// ```
+/// use meshmeshmesh::polygon_mesh::PolygonMesh;
+///
+/// let result = PolygonMesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![vec![0, 1, 2, 3]]);
+///
+/// assert_eq!(result.get_number_of_vertices(), 4);
+/// assert_eq!(result.get_number_of_faces(), 1);
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PolygonMesh {
+    /// Optional identifier
+    #[serde(rename(serialize = "mesh_id", deserialize = "mesh_id"))]
+    pub id: Option<usize>,
+    /// The list of coordinates for the mesh vertices.
+    pub coordinates: Vec<f64>,
+    /// The list of faces, each of them is a list of vertex ids.
+    pub faces: Vec<Vec<usize>>,
+}
+
+impl PolygonMesh {
+    /// Returns a new [PolygonMesh] without `id`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::polygon_mesh::PolygonMesh;
+    ///
+    /// let result = PolygonMesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![vec![0, 1, 2]]);
+    ///
+    /// assert_eq!(result.id, None);
+    /// assert_eq!(result.faces, vec![vec![0, 1, 2]]);
+    /// ```
+    pub fn new(coordinates: Vec<f64>, faces: Vec<Vec<usize>>) -> PolygonMesh { PolygonMesh { id: None, coordinates, faces } }
+
+    /// Returns a new [PolygonMesh] with given `id`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::polygon_mesh::PolygonMesh;
+    ///
+    /// let result = PolygonMesh::new_with_id(Some(5), vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![vec![0, 1, 2]]);
+    ///
+    /// assert_eq!(result.id, Some(5));
+    /// ```
+    pub fn new_with_id(id: Option<usize>, coordinates: Vec<f64>, faces: Vec<Vec<usize>>) -> PolygonMesh { PolygonMesh { id, coordinates, faces } }
+
+    /// Returns the number of vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::polygon_mesh::PolygonMesh;
+    ///
+    /// let input = PolygonMesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![vec![0, 1, 2]]);
+    ///
+    /// assert_eq!(input.get_number_of_vertices(), 3);
+    /// ```
+    pub fn get_number_of_vertices(&self) -> usize { self.coordinates.len() / 3 }
+
+    /// Returns the number of faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::polygon_mesh::PolygonMesh;
+    ///
+    /// let input = PolygonMesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![vec![0, 1, 2], vec![0, 2, 3]]);
+    ///
+    /// assert_eq!(input.get_number_of_faces(), 2);
+    /// ```
+    pub fn get_number_of_faces(&self) -> usize { self.faces.len() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let actual = PolygonMesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![vec![0, 1, 2, 3]]);
+
+        assert_eq!(actual.id, None);
+        assert_eq!(actual.coordinates, vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(actual.faces, vec![vec![0, 1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_json() {
+        let input = PolygonMesh::new_with_id(Some(2), vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], vec![vec![0, 1, 2, 3]]);
+
+        let json = serde_json::to_string(&input).unwrap();
+        let actual: PolygonMesh = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains("\"mesh_id\":2"));
+        assert_eq!(actual, input);
+    }
+}
@@ -0,0 +1,364 @@
+use std::collections::HashMap;
+use crate::face_triangulation::FaceTriangulation;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::polygon_mesh::PolygonMesh;
+use crate::vector::Vector;
+
+impl PolygonMesh {
+    /// Converts the [PolygonMesh] into the triangle [Mesh], using given [FaceTriangulation].
+    ///
+    /// Vertices are kept as they are, triangles are added face by face, in the order of faces,
+    /// with the orientation of their faces. The `id` is kept.
+    ///
+    /// It panics if any of the faces has less than 3 vertices.
+    ///
+    /// # Example
+    ///
+    /// Here is an example with the concave L-shaped face, which can't be triangulated as a fan
+    /// from its first vertex.
+    ///
+    /// ```
+    /// use meshmeshmesh::face_triangulation::FaceTriangulation;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::polygon_mesh::PolygonMesh;
+    ///
+    /// let input = PolygonMesh::new(
+    ///     vec![
+    ///         2.0, 0.0, 0.0,
+    ///         2.0, 1.0, 0.0,
+    ///         1.0, 1.0, 0.0,
+    ///         1.0, 2.0, 0.0,
+    ///         0.0, 2.0, 0.0,
+    ///         0.0, 0.0, 0.0,
+    ///     ],
+    ///     vec![vec![0, 1, 2, 3, 4, 5]]);
+    ///
+    /// let actual = input.to_mesh(FaceTriangulation::EarClipping);
+    ///
+    /// assert_eq!(actual.get_number_of_faces(), 4);
+    /// assert!((actual.get_area() - 3.0).abs() < 1e-12);
+    /// assert!(input.to_mesh(FaceTriangulation::Fan).get_area() > 3.0);
+    /// ```
+    pub fn to_mesh(&self, triangulation: FaceTriangulation) -> Mesh {
+        let mut indices: Vec<usize> = Vec::new();
+        for (face_id, face) in self.faces.iter().enumerate() {
+            if face.len() < 3 {
+                panic!("The face {} has less than 3 vertices", face_id);
+            }
+            let triangles = match triangulation {
+                FaceTriangulation::Fan => get_fan_triangles(face.len()),
+                FaceTriangulation::EarClipping => {
+                    let points: Vec<Point> = face.iter().map(|vertex_id| self.get_point(*vertex_id)).collect();
+                    get_ear_clipping_triangles(&points)
+                },
+            };
+            for triangle in triangles {
+                indices.extend(triangle.iter().map(|i| face[*i]));
+            }
+        }
+
+        Mesh::new_with_id(self.id, self.coordinates.clone(), indices)
+    }
+
+    fn get_point(&self, vertex_id: usize) -> Point {
+        Point::new(self.coordinates[vertex_id * 3], self.coordinates[vertex_id * 3 + 1], self.coordinates[vertex_id * 3 + 2])
+    }
+}
+
+impl Mesh {
+    /// Converts the triangle [Mesh] into the [PolygonMesh], merging pairs of triangles into quads.
+    ///
+    /// 2 triangles are merged, if they share an edge (with consistent orientation) used by these
+    /// 2 faces only, the angle between their normals is not bigger than `angle_tolerance` (in
+    /// radians), and the resulting quad is convex. Pairs where the shared edge is the longest
+    /// edge of both triangles (e.g. the diagonal of a triangulated rectangle) are merged first,
+    /// then pairs with smaller angles. If the [Mesh] has `face_groups`, only triangles from the
+    /// same group are merged.
+    ///
+    /// The [Mesh] should be welded, because neighbours are found using shared vertices.
+    /// Vertices are kept as they are, faces keep their order (quads take the place of their first
+    /// triangle). The `id` is kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    ///
+    /// let input = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0],
+    ///     vec![0, 1, 2, 0, 2, 3, 1, 4, 2]);
+    ///
+    /// let actual = input.to_polygon_mesh(0.01);
+    ///
+    /// assert_eq!(actual.faces, vec![vec![0, 1, 2, 3], vec![1, 4, 2]]);
+    /// ```
+    pub fn to_polygon_mesh(&self, angle_tolerance: f64) -> PolygonMesh {
+        let number_of_faces = self.get_number_of_faces();
+        let face_normals = self.get_cached_face_normals();
+
+        let mut edge_faces: HashMap<(usize, usize), Vec<(usize, usize)>> = HashMap::new();
+        for face_id in 0..number_of_faces {
+            for k in 0..3 {
+                let start = self.indices[face_id * 3 + k];
+                let end = self.indices[face_id * 3 + (k + 1) % 3];
+                edge_faces.entry((start.min(end), start.max(end))).or_default().push((face_id, k));
+            }
+        }
+
+        let mut candidates: Vec<(bool, f64, usize, usize, [usize; 4])> = Vec::new();
+        for face_id in 0..number_of_faces {
+            for k in 0..3 {
+                let a = self.indices[face_id * 3 + (k + 2) % 3];
+                let b = self.indices[face_id * 3 + k];
+                let c = self.indices[face_id * 3 + (k + 1) % 3];
+                let faces = &edge_faces[&(b.min(c), b.max(c))];
+                if faces.len() != 2 {
+                    continue;
+                }
+                let (other_face_id, other_k) = if faces[0].0 == face_id { faces[1] } else { faces[0] };
+                if other_face_id <= face_id
+                    || self.indices[other_face_id * 3 + other_k] != c
+                    || self.indices[other_face_id * 3 + (other_k + 1) % 3] != b {
+                    continue;
+                }
+                if let Some(face_groups) = &self.face_groups {
+                    if face_groups[face_id] != face_groups[other_face_id] {
+                        continue;
+                    }
+                }
+                let (normal, other_normal) = match (face_normals[face_id], face_normals[other_face_id]) {
+                    (Some(normal), Some(other_normal)) => (normal, other_normal),
+                    _ => continue,
+                };
+                let angle = normal.get_angle(&other_normal);
+                if angle > angle_tolerance {
+                    continue;
+                }
+
+                let d = self.indices[other_face_id * 3 + (other_k + 2) % 3];
+                let quad = [a, b, d, c];
+                let quad_normal = Vector::new(normal.x + other_normal.x, normal.y + other_normal.y, normal.z + other_normal.z);
+                if !self.is_quad_convex(&quad, &quad_normal) {
+                    continue;
+                }
+
+                let is_longest_edge = self.is_longest_edge_of_face(face_id, k) && self.is_longest_edge_of_face(other_face_id, other_k);
+                let first_vertex = self.indices[face_id * 3];
+                let first_position = quad.iter().position(|vertex_id| *vertex_id == first_vertex).unwrap();
+                let quad = [quad[first_position], quad[(first_position + 1) % 4], quad[(first_position + 2) % 4], quad[(first_position + 3) % 4]];
+                candidates.push((!is_longest_edge, angle, face_id, other_face_id, quad));
+            }
+        }
+        candidates.sort_by(|first, second| first.0.cmp(&second.0).then(first.1.total_cmp(&second.1)));
+
+        let mut quads: Vec<Option<[usize; 4]>> = vec![None; number_of_faces];
+        let mut is_merged = vec![false; number_of_faces];
+        for (_, _, face_id, other_face_id, quad) in candidates {
+            if is_merged[face_id] || is_merged[other_face_id] {
+                continue;
+            }
+            is_merged[face_id] = true;
+            is_merged[other_face_id] = true;
+            quads[face_id] = Some(quad);
+        }
+
+        let mut faces: Vec<Vec<usize>> = Vec::new();
+        for face_id in 0..number_of_faces {
+            match quads[face_id] {
+                Some(quad) => faces.push(quad.to_vec()),
+                None if !is_merged[face_id] => faces.push(self.indices[face_id * 3..face_id * 3 + 3].to_vec()),
+                None => {},
+            }
+        }
+
+        PolygonMesh::new_with_id(self.id, self.coordinates.clone(), faces)
+    }
+
+    fn is_quad_convex(&self, quad: &[usize; 4], normal: &Vector) -> bool {
+        let points: Vec<Point> = quad.iter().map(|vertex_id| Point::new(self.coordinates[vertex_id * 3], self.coordinates[vertex_id * 3 + 1], self.coordinates[vertex_id * 3 + 2])).collect();
+        (0..4).all(|i| {
+            let previous = Vector::from_2_points(&points[i], &points[(i + 1) % 4]);
+            let next = Vector::from_2_points(&points[(i + 1) % 4], &points[(i + 2) % 4]);
+            previous.get_cross_product(&next).get_dot_product(normal) > 0.0
+        })
+    }
+
+    fn is_longest_edge_of_face(&self, face_id: usize, k: usize) -> bool {
+        let lengths: Vec<f64> = (0..3).map(|i| {
+            let start = self.indices[face_id * 3 + i];
+            let end = self.indices[face_id * 3 + (i + 1) % 3];
+            Point::new(self.coordinates[start * 3], self.coordinates[start * 3 + 1], self.coordinates[start * 3 + 2])
+                .get_distance_to_point(&Point::new(self.coordinates[end * 3], self.coordinates[end * 3 + 1], self.coordinates[end * 3 + 2]))
+        }).collect();
+        lengths.iter().all(|length| lengths[k] >= length * (1.0 - 1e-9))
+    }
+}
+
+/// Gets triangles (as positions in the face) of the fan from the first vertex of the face.
+fn get_fan_triangles(number_of_vertices: usize) -> Vec<[usize; 3]> {
+    (1..number_of_vertices - 1).map(|i| [0, i, i + 1]).collect()
+}
+
+/// Gets triangles (as positions in the face) by clipping ears of the face projected onto the
+/// plane perpendicular to its dominant normal axis.
+///
+/// If no ear can be found (e.g. for self-intersecting or degenerate faces), the next vertex is
+/// clipped anyway, so it always returns `number_of_vertices - 2` triangles.
+fn get_ear_clipping_triangles(points: &[Point]) -> Vec<[usize; 3]> {
+    let mut normal = Vector::zero();
+    for i in 0..points.len() {
+        let current = &points[i];
+        let next = &points[(i + 1) % points.len()];
+        normal.x += (current.y - next.y) * (current.z + next.z);
+        normal.y += (current.z - next.z) * (current.x + next.x);
+        normal.z += (current.x - next.x) * (current.y + next.y);
+    }
+    let (points_2d, sign): (Vec<(f64, f64)>, f64) = if normal.z.abs() >= normal.x.abs() && normal.z.abs() >= normal.y.abs() {
+        (points.iter().map(|point| (point.x, point.y)).collect(), normal.z.signum())
+    } else if normal.x.abs() >= normal.y.abs() {
+        (points.iter().map(|point| (point.y, point.z)).collect(), normal.x.signum())
+    } else {
+        (points.iter().map(|point| (point.z, point.x)).collect(), normal.y.signum())
+    };
+    let cross = |a: usize, b: usize, c: usize| {
+        let (a, b, c) = (points_2d[a], points_2d[b], points_2d[c]);
+        ((b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)) * sign
+    };
+
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut triangles: Vec<[usize; 3]> = Vec::with_capacity(points.len() - 2);
+    while remaining.len() > 3 {
+        let number_of_remaining = remaining.len();
+        let ear = (0..number_of_remaining).find(|i| {
+            let previous = remaining[(i + number_of_remaining - 1) % number_of_remaining];
+            let current = remaining[*i];
+            let next = remaining[(i + 1) % number_of_remaining];
+            if cross(previous, current, next) <= 0.0 {
+                return false;
+            }
+            !remaining.iter().any(|other| {
+                if *other == previous || *other == current || *other == next
+                    || points_2d[*other] == points_2d[previous] || points_2d[*other] == points_2d[current] || points_2d[*other] == points_2d[next] {
+                    return false;
+                }
+                cross(previous, current, *other) >= 0.0 && cross(current, next, *other) >= 0.0 && cross(next, previous, *other) >= 0.0
+            })
+        }).unwrap_or(0);
+
+        let previous = remaining[(ear + number_of_remaining - 1) % number_of_remaining];
+        let next = remaining[(ear + 1) % number_of_remaining];
+        triangles.push([previous, remaining[ear], next]);
+        remaining.remove(ear);
+    }
+    triangles.push([remaining[0], remaining[1], remaining[2]]);
+
+    triangles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_l_shape() -> PolygonMesh {
+        PolygonMesh::new(
+            vec![2.0, 0.0, 0.0, 2.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0],
+            vec![vec![0, 1, 2, 3, 4, 5]])
+    }
+
+    #[test]
+    fn test_to_mesh_fan() {
+        let input = PolygonMesh::new_with_id(
+            Some(4),
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0],
+            vec![vec![0, 1, 2, 3], vec![1, 4, 2]]);
+
+        let actual = input.to_mesh(FaceTriangulation::Fan);
+
+        let expected = Mesh::new_with_id(
+            Some(4),
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 2.0, 0.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3, 1, 4, 2]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_to_mesh_ear_clipping_concave_keeps_area_and_orientation() {
+        let input = get_l_shape();
+
+        let actual = input.to_mesh(FaceTriangulation::EarClipping);
+
+        assert_eq!(actual.get_number_of_faces(), 4);
+        assert!((actual.get_area() - 3.0).abs() < 1e-12);
+        for normal in actual.get_face_normal_vectors_unitized() {
+            assert!(normal.eq_with_tolerance(&Vector::new(0.0, 0.0, 1.0), 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_to_mesh_ear_clipping_vertical_reversed_face() {
+        let input = PolygonMesh::new(
+            vec![0.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0, 2.0, 2.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0],
+            vec![vec![5, 4, 3, 2, 1, 0]]);
+
+        let actual = input.to_mesh(FaceTriangulation::EarClipping);
+
+        assert_eq!(actual.get_number_of_faces(), 4);
+        assert!((actual.get_area() - 3.5).abs() < 1e-12);
+        for normal in actual.get_face_normal_vectors_unitized() {
+            assert!(normal.eq_with_tolerance(&Vector::new(1.0, 0.0, 0.0), 1e-12));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The face 1 has less than 3 vertices")]
+    fn test_to_mesh_too_small_face_panic() {
+        let input = PolygonMesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0], vec![vec![0, 1, 2], vec![0, 1]]);
+        input.to_mesh(FaceTriangulation::Fan);
+    }
+
+    #[test]
+    fn test_to_polygon_mesh_grid_prefers_diagonals() {
+        let input = Mesh::new_with_id(
+            Some(1),
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 2.0, 1.0, 0.0],
+            vec![0, 1, 4, 1, 2, 5, 0, 4, 3, 1, 5, 4]);
+
+        let actual = input.to_polygon_mesh(0.01);
+
+        let expected = PolygonMesh::new_with_id(
+            Some(1),
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 2.0, 1.0, 0.0],
+            vec![vec![0, 1, 4, 3], vec![1, 2, 5, 4]]);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_to_polygon_mesh_keeps_folded_concave_and_grouped_triangles() {
+        let folded = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0],
+            vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(folded.to_polygon_mesh(0.1).faces, vec![vec![0, 1, 2], vec![0, 2, 3]]);
+        assert_eq!(folded.to_polygon_mesh(1.0).faces, vec![vec![0, 1, 2, 3]]);
+
+        let concave = Mesh::new(
+            vec![0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.5, 0.5, 0.0, 0.0, 2.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(concave.to_polygon_mesh(0.1).faces, vec![vec![0, 1, 2], vec![0, 2, 3]]);
+
+        let mut grouped = Mesh::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0, 1, 2, 0, 2, 3]);
+        grouped.face_groups = Some(vec![0, 1]);
+        assert_eq!(grouped.to_polygon_mesh(0.1).faces, vec![vec![0, 1, 2], vec![0, 2, 3]]);
+    }
+
+    #[test]
+    fn test_to_polygon_mesh_and_back_keeps_area() {
+        let input = get_l_shape().to_mesh(FaceTriangulation::EarClipping);
+
+        let actual = input.to_polygon_mesh(0.01).to_mesh(FaceTriangulation::Fan);
+
+        assert!((actual.get_area() - 3.0).abs() < 1e-12);
+    }
+}
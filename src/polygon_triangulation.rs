@@ -1,4 +1,5 @@
 use crate::mesh::Mesh;
+use crate::point::Point;
 use crate::polygon::Polygon;
 
 impl Polygon {
@@ -92,6 +93,26 @@ impl Polygon {
         let mesh_2d = polygon_2d.triangulate_raw_with_holes(&holes_2d);
         mesh_2d.get_in_local_coordinate_system(&local_coordinate_system)
     }
+
+    /// Triangulates the [Polygon] with holes (see [Polygon::triangulate_raw_with_holes]) and
+    /// gets triangles as ids of vertices: first the Polygon's vertices, then vertices of holes
+    /// in the order of `holes`.
+    ///
+    /// Triangulation reorders vertices and moves them slightly, so they're matched back with
+    /// the closest input vertices.
+    pub(crate) fn get_triangulation_vertex_ids(&self, holes: &Vec<Polygon>) -> Vec<[usize; 3]> {
+        let vertices: Vec<&Point> = self.vertices.iter().chain(holes.iter().flat_map(|hole| hole.vertices.iter())).collect();
+        let triangulated = if holes.is_empty() { self.triangulate_raw() } else { self.triangulate_raw_with_holes(holes) };
+        let vertex_ids: Vec<usize> = triangulated.to_points().iter()
+            .map(|triangulated_point| (0..vertices.len())
+                .min_by(|a, b| vertices[*a].get_distance_to_point(triangulated_point).total_cmp(&vertices[*b].get_distance_to_point(triangulated_point)))
+                .unwrap())
+            .collect();
+
+        triangulated.indices.chunks(3)
+            .map(|face| [vertex_ids[face[0]], vertex_ids[face[1]], vertex_ids[face[2]]])
+            .collect()
+    }
 }
 
 #[cfg(test)]
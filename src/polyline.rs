@@ -0,0 +1,42 @@
+use crate::point::Point;
+
+/// Represents a three-dimensional open polyline.
+///
+/// Polyline should contain at least 2 vertices. Unlike [Polygon](crate::polygon::Polygon) it's
+/// not closed: the last vertex is not connected with the first one.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::point::Point;
+/// use meshmeshmesh::polyline::Polyline;
+///
+/// let input = vec![Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 5.0), Point::new(5.0, 0.0, 10.0)];
+///
+/// let result = Polyline::new(input);
+///
+/// assert_eq!(result.vertices, vec![Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 5.0), Point::new(5.0, 0.0, 10.0)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    /// Vertices which define the open [Polyline], in order.
+    pub vertices: Vec<Point>,
+}
+
+impl Polyline {
+    /// Returns a new [Polyline].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let input = vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 3.0)];
+    ///
+    /// let result = Polyline::new(input);
+    ///
+    /// assert_eq!(result.vertices, vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 3.0)]);
+    /// ```
+    pub fn new(vertices: Vec<Point>) -> Polyline { Polyline { vertices } }
+}
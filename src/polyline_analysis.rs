@@ -0,0 +1,79 @@
+use crate::point::Point;
+use crate::polyline::Polyline;
+use crate::vector::Vector;
+
+impl Polyline {
+    /// Gets the length of the [Polyline], so the sum of lengths of its segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let input = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(3.0, 4.0, 0.0), Point::new(3.0, 4.0, 2.0)]);
+    ///
+    /// assert_eq!(input.get_length(), 7.0);
+    /// ```
+    pub fn get_length(&self) -> f64 {
+        self.vertices.windows(2).map(|pair| pair[0].get_distance_to_point(&pair[1])).sum()
+    }
+
+    /// Gets the [Point] lying on the [Polyline] at the given `length` measured along it from
+    /// its first vertex.
+    ///
+    /// The `length` is clamped, so for the negative one it returns the first vertex and for the
+    /// one exceeding [Polyline::get_length] it returns the last vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let input = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(2.0, 2.0, 0.0)]);
+    ///
+    /// assert_eq!(input.get_point_at_length(3.0), Point::new(2.0, 1.0, 0.0));
+    /// assert_eq!(input.get_point_at_length(10.0), Point::new(2.0, 2.0, 0.0));
+    /// ```
+    pub fn get_point_at_length(&self, length: f64) -> Point {
+        let mut remaining = length.max(0.0);
+        for pair in self.vertices.windows(2) {
+            let segment_length = pair[0].get_distance_to_point(&pair[1]);
+            if remaining <= segment_length && segment_length > 0.0 {
+                return pair[0] + Vector::from_2_points(&pair[0], &pair[1]) * (remaining / segment_length);
+            }
+            remaining -= segment_length;
+        }
+
+        self.vertices[self.vertices.len() - 1]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_length_single_vertex() {
+        let input = Polyline::new(vec![Point::new(1.0, 2.0, 3.0)]);
+
+        assert_eq!(input.get_length(), 0.0);
+    }
+
+    #[test]
+    fn test_get_point_at_length() {
+        let input = Polyline::new(vec![
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(0.0, 0.0, 4.0),
+            Point::new(0.0, 3.0, 4.0),
+        ]);
+
+        assert_eq!(input.get_point_at_length(-1.0), Point::new(0.0, 0.0, 0.0));
+        assert_eq!(input.get_point_at_length(0.0), Point::new(0.0, 0.0, 0.0));
+        assert_eq!(input.get_point_at_length(4.0), Point::new(0.0, 0.0, 4.0));
+        assert_eq!(input.get_point_at_length(5.5), Point::new(0.0, 1.5, 4.0));
+        assert_eq!(input.get_point_at_length(7.0), Point::new(0.0, 3.0, 4.0));
+    }
+}
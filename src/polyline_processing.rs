@@ -0,0 +1,219 @@
+use crate::point::Point;
+use crate::polyline::Polyline;
+use crate::vector::Vector;
+
+impl Polyline {
+    /// Creates a new [Polyline] with `count` vertices evenly spaced along the original one.
+    ///
+    /// The first and the last vertices stay the same, corners between them can be cut off.
+    /// The `count` lower than 2 is treated as 2.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let input = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0)]);
+    ///
+    /// let actual = input.get_resampled_by_count(5);
+    ///
+    /// let expected = Polyline::new(vec![
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(1.0, 0.0, 0.0),
+    ///     Point::new(2.0, 0.0, 0.0),
+    ///     Point::new(3.0, 0.0, 0.0),
+    ///     Point::new(4.0, 0.0, 0.0),
+    /// ]);
+    ///
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn get_resampled_by_count(&self, count: usize) -> Polyline {
+        let number_of_segments = count.max(2) - 1;
+        let length = self.get_length();
+        let mut vertices: Vec<Point> = (0..number_of_segments)
+            .map(|i| self.get_point_at_length(length * i as f64 / number_of_segments as f64))
+            .collect();
+        vertices.push(self.vertices[self.vertices.len() - 1]);
+
+        Polyline::new(vertices)
+    }
+
+    /// Creates a new [Polyline] with vertices evenly spaced along the original one, with the
+    /// spacing as close to the `distance` as possible, but not longer.
+    ///
+    /// The first and the last vertices stay the same, see [Polyline::get_resampled_by_count].
+    /// The `distance` should be positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let input = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 10.0)]);
+    ///
+    /// let actual = input.get_resampled_by_distance(3.0);
+    ///
+    /// // 4 segments 2.5 long
+    /// assert_eq!(actual.vertices.len(), 5);
+    /// assert_eq!(actual.vertices[1], Point::new(0.0, 0.0, 2.5));
+    /// ```
+    pub fn get_resampled_by_distance(&self, distance: f64) -> Polyline {
+        let number_of_segments = (self.get_length() / distance).ceil() as usize;
+
+        self.get_resampled_by_count(number_of_segments + 1)
+    }
+
+    /// Creates a new simplified [Polyline] using the Douglas-Peucker algorithm.
+    ///
+    /// Vertices are removed as long as the removed part of the [Polyline] is not further than
+    /// the `tolerance` from the segment replacing it. The first and the last vertices are
+    /// always kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let input = Polyline::new(vec![
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(1.0, 0.01, 0.0),
+    ///     Point::new(2.0, 0.0, 0.0),
+    ///     Point::new(2.0, 5.0, 0.0),
+    /// ]);
+    ///
+    /// let actual = input.get_simplified(0.1);
+    ///
+    /// let expected = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(2.0, 5.0, 0.0)]);
+    ///
+    /// assert_eq!(actual, expected);
+    /// ```
+    pub fn get_simplified(&self, tolerance: f64) -> Polyline {
+        let number_of_vertices = self.vertices.len();
+        if number_of_vertices < 3 {
+            return self.clone();
+        }
+
+        let mut is_kept = vec![false; number_of_vertices];
+        is_kept[0] = true;
+        is_kept[number_of_vertices - 1] = true;
+        let mut ranges: Vec<(usize, usize)> = vec![(0, number_of_vertices - 1)];
+        while let Some((start, end)) = ranges.pop() {
+            let farthest = (start + 1..end)
+                .map(|i| (i, self.vertices[i].get_distance_to_point(&self.vertices[i].get_closest_point_on_segment(&self.vertices[start], &self.vertices[end]))))
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            if let Some((i, distance)) = farthest {
+                if distance > tolerance {
+                    is_kept[i] = true;
+                    ranges.push((start, i));
+                    ranges.push((i, end));
+                }
+            }
+        }
+
+        Polyline::new(self.vertices.iter().zip(is_kept).filter(|(_, is_kept)| *is_kept).map(|(vertex, _)| *vertex).collect())
+    }
+
+    /// Creates a new [Polyline] smoothed with the Laplacian smoothing, like
+    /// [Mesh::get_with_laplacian_smoothing](crate::mesh::Mesh::get_with_laplacian_smoothing).
+    ///
+    /// In each of the `iterations` every vertex is moved towards the middle of its neighbours by
+    /// the `factor` (0.0 - no move, 1.0 - move to the middle). The first and the last vertices
+    /// stay in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::polyline::Polyline;
+    ///
+    /// let input = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.0), Point::new(2.0, 0.0, 0.0)]);
+    ///
+    /// let actual = input.get_smoothed(1, 0.5);
+    ///
+    /// assert_eq!(actual.vertices[1], Point::new(1.0, 0.5, 0.0));
+    /// ```
+    pub fn get_smoothed(&self, iterations: usize, factor: f64) -> Polyline {
+        let number_of_vertices = self.vertices.len();
+        let mut vertices = self.vertices.clone();
+        for _ in 0..iterations {
+            let previous = vertices.clone();
+            for i in 1..number_of_vertices.saturating_sub(1) {
+                let middle = previous[i - 1] + Vector::from_2_points(&previous[i - 1], &previous[i + 1]) * 0.5;
+                vertices[i] = previous[i] + Vector::from_2_points(&previous[i], &middle) * factor;
+            }
+        }
+
+        Polyline::new(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_zigzag() -> Polyline {
+        Polyline::new((0..11).map(|i| Point::new(i as f64, if i % 2 == 0 { 0.0 } else { 1.0 }, 0.0)).collect())
+    }
+
+    #[test]
+    fn test_get_resampled_by_count_keeps_ends() {
+        let input = get_zigzag();
+
+        let actual = input.get_resampled_by_count(7);
+
+        assert_eq!(actual.vertices.len(), 7);
+        assert_eq!(actual.vertices[0], input.vertices[0]);
+        assert_eq!(actual.vertices[6], input.vertices[10]);
+        let step = input.get_length() / 6.0;
+        for i in 0..7 {
+            assert!(actual.vertices[i].get_distance_to_point(&input.get_point_at_length(step * i as f64)) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_get_resampled_by_count_too_low() {
+        let input = get_zigzag();
+
+        let actual = input.get_resampled_by_count(0);
+
+        assert_eq!(actual, Polyline::new(vec![input.vertices[0], input.vertices[10]]));
+    }
+
+    #[test]
+    fn test_get_resampled_by_distance() {
+        let input = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(0.0, 6.0, 0.0)]);
+
+        assert_eq!(input.get_resampled_by_distance(2.0).vertices.len(), 4);
+        assert_eq!(input.get_resampled_by_distance(10.0).vertices.len(), 2);
+    }
+
+    #[test]
+    fn test_get_simplified() {
+        let input = get_zigzag();
+
+        assert_eq!(input.get_simplified(0.5), input);
+        assert_eq!(input.get_simplified(1.0), Polyline::new(vec![input.vertices[0], input.vertices[10]]));
+    }
+
+    #[test]
+    fn test_get_simplified_short() {
+        let input = Polyline::new(vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0)]);
+
+        assert_eq!(input.get_simplified(1.0), input);
+    }
+
+    #[test]
+    fn test_get_smoothed() {
+        let input = get_zigzag();
+
+        let actual = input.get_smoothed(20, 0.5);
+
+        assert_eq!(actual.vertices[0], input.vertices[0]);
+        assert_eq!(actual.vertices[10], input.vertices[10]);
+        assert!(actual.vertices.iter().all(|vertex| vertex.y.abs() < 0.5));
+        assert!(actual.get_length() < input.get_length());
+    }
+}
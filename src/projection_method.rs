@@ -0,0 +1,24 @@
+use crate::vector::Vector;
+
+/// Describes how vertices are moved onto the target by
+/// [Mesh::get_projected_onto](crate::mesh::Mesh::get_projected_onto).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::projection_method::ProjectionMethod;
+/// use meshmeshmesh::vector::Vector;
+///
+/// let method = ProjectionMethod::Direction(Vector::new(0.0, 0.0, -1.0));
+///
+/// assert_ne!(method, ProjectionMethod::ClosestPoint);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectionMethod {
+    /// Each vertex is moved to the closest point of the target's surface, which wraps it
+    /// tightly, e.g. for retopology.
+    ClosestPoint,
+    /// Each vertex is moved along the line of the direction (both ways) to the closest hit
+    /// with the target, e.g. `(0.0, 0.0, -1.0)` drapes meshes onto terrains.
+    Direction(Vector),
+}
@@ -0,0 +1,93 @@
+/// Upper bounds of bins (but the last one) of histograms of aspect ratios and edge length ratios
+/// in [QualityReport]. The last bin has everything not smaller than the last bound.
+pub const RATIO_HISTOGRAM_BOUNDS: [f64; 5] = [1.5, 2.0, 3.0, 5.0, 10.0];
+
+/// Represents the statistics of shapes of faces (triangles) of a [Mesh](crate::mesh::Mesh).
+///
+/// It's created by [Mesh::get_quality_report](crate::mesh::Mesh::get_quality_report).
+///
+/// It can be used to find badly shaped (e.g. long and thin) faces before the simulation or after
+/// the planar simplification. The per-face metrics are described in
+/// [Triangle](crate::triangle::Triangle)'s `get_min_angle`, `get_skewness`, `get_aspect_ratio`
+/// and `get_edge_length_ratio`.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::quality_report::QualityReport;
+///
+/// let report = QualityReport {
+///     min_angle_histogram: [0, 0, 0, 0, 1, 1],
+///     skewness_histogram: [1, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+///     aspect_ratio_histogram: [2, 0, 0, 0, 0, 0],
+///     edge_length_ratio_histogram: [2, 0, 0, 0, 0, 0],
+///     worst_min_angle_face_ids: vec![1],
+///     worst_skewness_face_ids: vec![1],
+///     worst_aspect_ratio_face_ids: vec![1],
+///     worst_edge_length_ratio_face_ids: vec![1],
+/// };
+///
+/// assert_eq!(report.get_number_of_faces(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QualityReport {
+    /// Numbers of faces with the smallest angle in ranges of 10 degrees: `[0°, 10°)`, `[10°, 20°)`
+    /// and so on up to `[50°, 60°]`.
+    pub min_angle_histogram: [usize; 6],
+    /// Numbers of faces with the skewness in ranges of 0.1: `[0.0, 0.1)`, `[0.1, 0.2)` and so on
+    /// up to `[0.9, 1.0]`.
+    pub skewness_histogram: [usize; 10],
+    /// Numbers of faces with the aspect ratio in ranges split by [RATIO_HISTOGRAM_BOUNDS]:
+    /// `[1.0, 1.5)`, `[1.5, 2.0)` and so on up to `[10.0, ∞]`.
+    pub aspect_ratio_histogram: [usize; 6],
+    /// Numbers of faces with the edge length ratio in ranges split by [RATIO_HISTOGRAM_BOUNDS],
+    /// same as for the `aspect_ratio_histogram`.
+    pub edge_length_ratio_histogram: [usize; 6],
+    /// Ids of faces with the smallest min angles, the worst one first.
+    pub worst_min_angle_face_ids: Vec<usize>,
+    /// Ids of faces with the biggest skewness, the worst one first.
+    pub worst_skewness_face_ids: Vec<usize>,
+    /// Ids of faces with the biggest aspect ratios, the worst one first.
+    pub worst_aspect_ratio_face_ids: Vec<usize>,
+    /// Ids of faces with the biggest edge length ratios, the worst one first.
+    pub worst_edge_length_ratio_face_ids: Vec<usize>,
+}
+
+impl PartialEq for QualityReport {
+    fn eq(&self, other: &Self) -> bool {
+        self.min_angle_histogram == other.min_angle_histogram &&
+            self.skewness_histogram == other.skewness_histogram &&
+            self.aspect_ratio_histogram == other.aspect_ratio_histogram &&
+            self.edge_length_ratio_histogram == other.edge_length_ratio_histogram &&
+            self.worst_min_angle_face_ids == other.worst_min_angle_face_ids &&
+            self.worst_skewness_face_ids == other.worst_skewness_face_ids &&
+            self.worst_aspect_ratio_face_ids == other.worst_aspect_ratio_face_ids &&
+            self.worst_edge_length_ratio_face_ids == other.worst_edge_length_ratio_face_ids
+    }
+}
+
+impl QualityReport {
+    /// Gets the number of faces described by the report.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::quality_report::QualityReport;
+    ///
+    /// let report = QualityReport {
+    ///     min_angle_histogram: [1, 0, 0, 0, 0, 2],
+    ///     skewness_histogram: [2, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    ///     aspect_ratio_histogram: [2, 0, 0, 0, 0, 1],
+    ///     edge_length_ratio_histogram: [2, 0, 0, 0, 0, 1],
+    ///     worst_min_angle_face_ids: vec![0],
+    ///     worst_skewness_face_ids: vec![0],
+    ///     worst_aspect_ratio_face_ids: vec![0],
+    ///     worst_edge_length_ratio_face_ids: vec![0],
+    /// };
+    ///
+    /// assert_eq!(report.get_number_of_faces(), 3);
+    /// ```
+    pub fn get_number_of_faces(&self) -> usize {
+        self.min_angle_histogram.iter().sum()
+    }
+}
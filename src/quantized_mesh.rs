@@ -0,0 +1,449 @@
+use serde::{Deserialize, Serialize};
+use crate::bounding_box::BoundingBox;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::vector::Vector;
+
+/// Represents the precision used for storing coordinates of the [QuantizedMesh].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum QuantizationPrecision {
+    /// Each coordinate is stored as `u16` relative to the bounding box (2 bytes per coordinate).
+    ///
+    /// The maximum error is 1/131070 of the bounding box size in given direction.
+    U16,
+    /// Each coordinate is stored as `f32` relative to the minimum of the bounding box
+    /// (4 bytes per coordinate).
+    F32,
+    /// Each coordinate is stored as an integer with the given number of bits (1-32) relative to
+    /// the bounding box, kept in a `u32`.
+    ///
+    /// The maximum error is 1/(2 * (2^bits - 1)) of the bounding box size in given direction. Use
+    /// [QuantizedMesh::from_mesh_with_tolerance] to get the smallest number of bits for the
+    /// given maximum error.
+    Bits(u8),
+}
+
+/// Represents the compact version of the [Mesh], with coordinates quantized relative to its
+/// bounding box.
+///
+/// It's useful for transmission or storage of big scenes, where `f64` coordinates are
+/// 2-4 times bigger than needed.
+///
+/// Use [QuantizedMesh::from_mesh] to create it and [QuantizedMesh::to_mesh] to reconstruct
+/// the [Mesh]. Indices and the id are kept as they are, coordinates are quantized, and
+/// `vertex_normals` are stored with octahedral encoding (see [Vector::to_octahedral_u16]).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::mesh::Mesh;
+/// use meshmeshmesh::quantized_mesh::{QuantizationPrecision, QuantizedMesh};
+///
+/// let input = Mesh::new(
+///     vec![
+///         -2.0,1.0,0.0,
+///         8.0,1.0,0.0,
+///         8.0,11.0,0.0,
+///         -2.0,11.0,0.0,
+///         3.0,6.0,4.0
+///     ],
+///     vec![
+///         0,1,2,
+///         0,2,3,
+///         0,1,4,
+///         1,2,4,
+///         2,3,4,
+///         3,0,4
+///     ]);
+///
+/// let quantized = QuantizedMesh::from_mesh(&input, QuantizationPrecision::U16);
+/// let actual = quantized.to_mesh();
+///
+/// assert!(input.eq_with_tolerance(&actual, quantized.get_maximum_error()));
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QuantizedMesh {
+    /// Optional identifier
+    #[serde(rename(serialize = "mesh_id", deserialize = "mesh_id"))]
+    pub id: Option<usize>,
+    /// The bounding box minimum: [min_x, min_y, min_z].
+    pub origin: [f64; 3],
+    /// The bounding box size: [size_x, size_y, size_z].
+    pub size: [f64; 3],
+    /// The precision used for coordinates.
+    pub precision: QuantizationPrecision,
+    /// Quantized coordinates, used when the precision is [QuantizationPrecision::U16].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coordinates_u16: Vec<u16>,
+    /// Quantized coordinates, used when the precision is [QuantizationPrecision::F32].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coordinates_f32: Vec<f32>,
+    /// Quantized coordinates, used when the precision is [QuantizationPrecision::Bits].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coordinates_u32: Vec<u32>,
+    /// The list of indices for the mesh triangles.
+    pub indices: Vec<u32>,
+    /// Octahedral encoded `vertex_normals` (2 values per vertex), empty if the [Mesh] has none.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub normals_octahedral: Vec<u16>,
+}
+
+impl PartialEq for QuantizedMesh {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id &&
+            self.origin == other.origin &&
+            self.size == other.size &&
+            self.precision == other.precision &&
+            self.coordinates_u16 == other.coordinates_u16 &&
+            self.coordinates_f32 == other.coordinates_f32 &&
+            self.coordinates_u32 == other.coordinates_u32 &&
+            self.indices == other.indices &&
+            self.normals_octahedral == other.normals_octahedral
+    }
+}
+
+impl QuantizedMesh {
+    /// Creates a new [QuantizedMesh] from given [Mesh] using given precision.
+    ///
+    /// An empty [Mesh] gets zero `origin` and `size`. `vertex_normals` with zero length can't be
+    /// encoded, so they're stored as (0, 0, 1).
+    ///
+    /// It panics if the [Mesh] has more vertices than `u32` can index, or if the number of
+    /// [QuantizationPrecision::Bits] is not between 1 and 32.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::quantized_mesh::{QuantizationPrecision, QuantizedMesh};
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let actual = QuantizedMesh::from_mesh(&input, QuantizationPrecision::U16);
+    ///
+    /// assert_eq!(actual.origin, [0.0, -15.0, 0.0]);
+    /// assert_eq!(actual.size, [10.0, 15.0, 0.0]);
+    /// assert_eq!(actual.coordinates_u16, vec![0, 65535, 0, 65535, 65535, 0, 65535, 0, 0]);
+    /// assert_eq!(actual.indices, vec![0, 1, 2]);
+    /// ```
+    pub fn from_mesh(mesh: &Mesh, precision: QuantizationPrecision) -> QuantizedMesh {
+        if mesh.get_number_of_vertices() > u32::MAX as usize {
+            panic!("Cannot quantize the Mesh with more vertices than u32::MAX");
+        }
+        if let QuantizationPrecision::Bits(bits) = precision {
+            if !(1..=32).contains(&bits) {
+                panic!("Number of bits should be between 1 and 32");
+            }
+        }
+
+        let bounding_box = get_mesh_bounding_box(mesh);
+        let origin = [bounding_box.min_x, bounding_box.min_y, bounding_box.min_z];
+        let size = [
+            bounding_box.max_x - bounding_box.min_x,
+            bounding_box.max_y - bounding_box.min_y,
+            bounding_box.max_z - bounding_box.min_z,
+        ];
+
+        let mut coordinates_u16 = Vec::new();
+        let mut coordinates_f32 = Vec::new();
+        let mut coordinates_u32 = Vec::new();
+        match precision {
+            QuantizationPrecision::U16 => {
+                coordinates_u16.reserve(mesh.coordinates.len());
+                for point in mesh.to_points() {
+                    coordinates_u16.extend_from_slice(&point.to_quantized_u16(&bounding_box));
+                }
+            },
+            QuantizationPrecision::F32 => {
+                coordinates_f32 = mesh.coordinates.iter().enumerate()
+                    .map(|(i, coordinate)| (coordinate - origin[i % 3]) as f32)
+                    .collect();
+            },
+            QuantizationPrecision::Bits(bits) => {
+                let maximum = get_maximum_quantized_value(bits);
+                coordinates_u32 = mesh.coordinates.iter().enumerate()
+                    .map(|(i, coordinate)| {
+                        let range = size[i % 3];
+                        if range <= 0.0 {
+                            return 0;
+                        }
+                        (((coordinate - origin[i % 3]) / range).clamp(0.0, 1.0) * maximum).round() as u32
+                    })
+                    .collect();
+            },
+        }
+
+        QuantizedMesh {
+            id: mesh.id,
+            origin,
+            size,
+            precision,
+            coordinates_u16,
+            coordinates_f32,
+            coordinates_u32,
+            indices: mesh.indices.iter().map(|index| *index as u32).collect(),
+            normals_octahedral: match &mesh.vertex_normals {
+                Some(normals) => normals.chunks(3)
+                    .flat_map(|normal| {
+                        let vector = Vector::new(normal[0], normal[1], normal[2]);
+                        if vector.is_absolute_zero_length() { Vector::z_unit() } else { vector }.to_octahedral_u16()
+                    })
+                    .collect(),
+                None => Vec::new(),
+            },
+        }
+    }
+
+    /// Reconstructs the [Mesh] from the [QuantizedMesh].
+    ///
+    /// The difference between the original and the reconstructed coordinates is not bigger than
+    /// [QuantizedMesh::get_maximum_error]. Decoded `vertex_normals` are unitized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::quantized_mesh::{QuantizationPrecision, QuantizedMesh};
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let actual = QuantizedMesh::from_mesh(&input, QuantizationPrecision::F32).to_mesh();
+    ///
+    /// assert_eq!(input, actual);
+    /// ```
+    pub fn to_mesh(&self) -> Mesh {
+        let coordinates = match self.precision {
+            QuantizationPrecision::U16 => {
+                let bounding_box = self.get_bounding_box();
+                let mut coordinates = Vec::with_capacity(self.coordinates_u16.len());
+                for quantized in self.coordinates_u16.chunks(3) {
+                    let point = Point::from_quantized_u16([quantized[0], quantized[1], quantized[2]], &bounding_box);
+                    coordinates.extend_from_slice(&[point.x, point.y, point.z]);
+                }
+                coordinates
+            },
+            QuantizationPrecision::F32 => {
+                self.coordinates_f32.iter().enumerate()
+                    .map(|(i, coordinate)| self.origin[i % 3] + *coordinate as f64)
+                    .collect()
+            },
+            QuantizationPrecision::Bits(bits) => {
+                let maximum = get_maximum_quantized_value(bits);
+                self.coordinates_u32.iter().enumerate()
+                    .map(|(i, coordinate)| self.origin[i % 3] + (*coordinate as f64 / maximum) * self.size[i % 3])
+                    .collect()
+            },
+        };
+
+        let mut mesh = Mesh::new_with_id(self.id, coordinates, self.indices.iter().map(|index| *index as usize).collect());
+        if !self.normals_octahedral.is_empty() {
+            mesh.vertex_normals = Some(self.normals_octahedral.chunks(2)
+                .flat_map(|encoded| {
+                    let normal = Vector::from_octahedral_u16([encoded[0], encoded[1]]);
+                    [normal.x, normal.y, normal.z]
+                })
+                .collect());
+        }
+
+        mesh
+    }
+
+    /// Gets the maximum absolute error of coordinates introduced by the quantization.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::quantized_mesh::{QuantizationPrecision, QuantizedMesh};
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 131070.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let actual = QuantizedMesh::from_mesh(&input, QuantizationPrecision::U16);
+    ///
+    /// assert_eq!(actual.get_maximum_error(), 1.0);
+    /// ```
+    pub fn get_maximum_error(&self) -> f64 {
+        let biggest_size = self.size.iter().fold(0.0, |a: f64, b| a.max(*b));
+        match self.precision {
+            QuantizationPrecision::U16 => biggest_size / (2.0 * u16::MAX as f64),
+            QuantizationPrecision::F32 => biggest_size * f32::EPSILON as f64, // Rounding of the relative value
+            QuantizationPrecision::Bits(bits) => biggest_size / (2.0 * get_maximum_quantized_value(bits)),
+        }
+    }
+
+    /// Creates a new [QuantizedMesh] from given [Mesh] using the smallest number of
+    /// [QuantizationPrecision::Bits] keeping coordinates within the `tolerance`.
+    ///
+    /// It panics if the `tolerance` is not greater than 0.0, or if it needs more than 32 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::quantized_mesh::{QuantizationPrecision, QuantizedMesh};
+    ///
+    /// let input = Mesh::new(vec![0.0, 0.0, 0.0, 100.0, 0.0, 0.0, 100.0, -15.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let actual = QuantizedMesh::from_mesh_with_tolerance(&input, 0.001);
+    ///
+    /// assert_eq!(actual.precision, QuantizationPrecision::Bits(16));
+    /// assert!(input.eq_with_tolerance(&actual.to_mesh(), 0.001));
+    /// ```
+    pub fn from_mesh_with_tolerance(mesh: &Mesh, tolerance: f64) -> QuantizedMesh {
+        if tolerance <= 0.0 {
+            panic!("Tolerance should be greater than 0.0");
+        }
+
+        let bounding_box = get_mesh_bounding_box(mesh);
+        let biggest_size = (bounding_box.max_x - bounding_box.min_x)
+            .max(bounding_box.max_y - bounding_box.min_y)
+            .max(bounding_box.max_z - bounding_box.min_z);
+        let bits = (1..=32_u8)
+            .find(|bits| biggest_size / (2.0 * get_maximum_quantized_value(*bits)) <= tolerance)
+            .expect("Tolerance should be achievable with 32 bits");
+
+        Self::from_mesh(mesh, QuantizationPrecision::Bits(bits))
+    }
+
+    fn get_bounding_box(&self) -> BoundingBox {
+        BoundingBox::new(
+            self.origin[0], self.origin[0] + self.size[0],
+            self.origin[1], self.origin[1] + self.size[1],
+            self.origin[2], self.origin[2] + self.size[2],
+        )
+    }
+}
+
+/// Gets the [BoundingBox] of the [Mesh], or the zero one at the origin if it's empty.
+fn get_mesh_bounding_box(mesh: &Mesh) -> BoundingBox {
+    if mesh.coordinates.is_empty() {
+        return BoundingBox::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    }
+
+    mesh.get_bounding_box()
+}
+
+/// Gets the biggest integer stored with the given number of bits.
+fn get_maximum_quantized_value(bits: u8) -> f64 {
+    ((1_u64 << bits) - 1) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+    use super::*;
+
+    fn create_big_mesh() -> Mesh {
+        Mesh::new_with_id(Some(4),
+            vec![
+                100000.123456, -250000.654321, 12.5,
+                100321.987654, -249500.111111, 13.75,
+                100111.555555, -249999.999999, 250.125,
+            ],
+            vec![0, 1, 2])
+    }
+
+    #[test]
+    fn test_u16_round_trip_within_error() {
+        let input = create_big_mesh();
+
+        let quantized = QuantizedMesh::from_mesh(&input, QuantizationPrecision::U16);
+        let actual = quantized.to_mesh();
+
+        assert_eq!(actual.id, Some(4));
+        assert_eq!(actual.indices, input.indices);
+        assert!(input.eq_with_tolerance(&actual, quantized.get_maximum_error()));
+        assert!(quantized.coordinates_f32.is_empty());
+    }
+
+    #[test]
+    fn test_f32_round_trip_within_error() {
+        let input = create_big_mesh();
+
+        let quantized = QuantizedMesh::from_mesh(&input, QuantizationPrecision::F32);
+        let actual = quantized.to_mesh();
+
+        assert!(input.eq_with_tolerance(&actual, quantized.get_maximum_error()));
+        assert!(quantized.get_maximum_error() < 0.0001);
+        assert!(quantized.coordinates_u16.is_empty());
+    }
+
+    #[test]
+    fn test_quantized_round_trip_is_stable() {
+        let input = create_big_mesh();
+
+        let quantized = QuantizedMesh::from_mesh(&input, QuantizationPrecision::U16);
+        let requantized = QuantizedMesh::from_mesh(&quantized.to_mesh(), QuantizationPrecision::U16);
+
+        assert_eq!(quantized.coordinates_u16, requantized.coordinates_u16);
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let input = QuantizedMesh::from_mesh(&create_big_mesh(), QuantizationPrecision::U16);
+
+        let json = to_string(&input).unwrap();
+        let actual: QuantizedMesh = from_str(&json).unwrap();
+
+        assert!(!json.contains("coordinates_f32"));
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_bits_round_trip_within_error() {
+        let input = create_big_mesh();
+
+        let quantized = QuantizedMesh::from_mesh(&input, QuantizationPrecision::Bits(24));
+        let actual = quantized.to_mesh();
+
+        assert!(input.eq_with_tolerance(&actual, quantized.get_maximum_error()));
+        assert!(quantized.get_maximum_error() < 0.0001);
+        assert!(quantized.coordinates_u16.is_empty() && quantized.coordinates_f32.is_empty());
+        assert_eq!(quantized.coordinates_u32.len(), 9);
+    }
+
+    #[test]
+    fn test_from_mesh_with_tolerance() {
+        let input = create_big_mesh();
+
+        let actual = QuantizedMesh::from_mesh_with_tolerance(&input, 0.01);
+
+        assert_eq!(actual.precision, QuantizationPrecision::Bits(15));
+        assert!(actual.get_maximum_error() <= 0.01);
+        assert!(input.eq_with_tolerance(&actual.to_mesh(), 0.01));
+    }
+
+    #[test]
+    fn test_from_mesh_empty() {
+        let input = Mesh::new(Vec::new(), Vec::new());
+
+        let actual = QuantizedMesh::from_mesh(&input, QuantizationPrecision::U16);
+
+        assert_eq!(actual.origin, [0.0, 0.0, 0.0]);
+        assert_eq!(actual.size, [0.0, 0.0, 0.0]);
+        assert_eq!(actual.get_maximum_error(), 0.0);
+        assert_eq!(actual.to_mesh(), input);
+        assert_eq!(QuantizedMesh::from_mesh_with_tolerance(&input, 0.01).precision, QuantizationPrecision::Bits(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of bits should be between 1 and 32")]
+    fn test_from_mesh_too_many_bits_panic() {
+        QuantizedMesh::from_mesh(&create_big_mesh(), QuantizationPrecision::Bits(33));
+    }
+
+    #[test]
+    fn test_vertex_normals_round_trip() {
+        let mut input = create_big_mesh();
+        input.vertex_normals = Some(vec![0.0, 0.0, 2.0, 0.3, -0.2, -0.9, 0.0, 0.0, 0.0]);
+
+        let quantized = QuantizedMesh::from_mesh(&input, QuantizationPrecision::U16);
+        let actual = quantized.to_mesh().vertex_normals.unwrap();
+
+        assert_eq!(quantized.normals_octahedral.len(), 6);
+        let expected = [Vector::new(0.0, 0.0, 1.0), Vector::new(0.3, -0.2, -0.9).get_unitized(), Vector::new(0.0, 0.0, 1.0)];
+        for (normal, expected) in actual.chunks(3).zip(expected) {
+            assert!(Vector::new(normal[0], normal[1], normal[2]).get_angle(&expected) < 0.0002);
+        }
+    }
+}
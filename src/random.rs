@@ -0,0 +1,39 @@
+/// Simple xorshift64* generator, so no external crate is needed for the noise or sampling.
+pub(crate) struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub(crate) fn new(seed: u64) -> Random {
+        // Zero state would give only zeros.
+        Random { state: (seed ^ 0x9E37_79B9_7F4A_7C15) | 1 }
+    }
+
+    /// Returns the next value in the range [-1.0, 1.0).
+    pub(crate) fn next(&mut self) -> f64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        let value = self.state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        (value >> 11) as f64 / (1_u64 << 52) as f64 - 1.0
+    }
+
+    /// Returns the next index in the range [0, `length`).
+    pub(crate) fn next_index(&mut self, length: usize) -> usize {
+        (((self.next() + 1.0) / 2.0 * length as f64) as usize).min(length - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_index_in_range() {
+        let mut random = Random::new(7);
+        for _ in 0..1000 {
+            assert!(random.next_index(5) < 5);
+        }
+        assert_eq!(random.next_index(1), 0);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::mesh::Mesh;
 use crate::point::Point;
 use crate::ray::Ray;
+use crate::ray_hit::RayHit;
 use crate::triangle::Triangle;
 use crate::vector::Vector;
 
@@ -55,7 +56,10 @@ impl Ray {
     /// 
     /// If it hits: then `true` is returned, if not: `false`.
     /// 
-    /// Orientations of [Triangle]s are not taken into an account.
+    /// Orientations of [Triangle]s are not taken into an account. All the [Triangle]s are
+    /// checked, so for repeated queries against the same [Mesh] use
+    /// [Bvh::does_intersect_with_ray](crate::bvh::Bvh::does_intersect_with_ray) with the
+    /// [Mesh::get_cached_bvh] instead.
     /// 
     /// # Examples
     /// 
@@ -253,6 +257,14 @@ impl Ray {
     /// assert_eq!(actual_option.is_none(), true);
     /// ```
     pub fn get_intersection_with_triangle(&self, triangle:&Triangle) -> Option<Point> {
+        self.get_intersection_parameters_with_triangle(triangle)
+            .map(|(distance_to_intersection, _, _)| self.get_point_at(distance_to_intersection))
+    }
+
+    /// Gets the distance to the intersection with the [Triangle], and the `u` and `v`
+    /// parameters of the Möller–Trumbore algorithm, which are the weights of the second
+    /// and the third Triangle's point.
+    pub(crate) fn get_intersection_parameters_with_triangle(&self, triangle:&Triangle) -> Option<(f64, f64, f64)> {
         let ab = triangle.get_first_side_as_vector();
         let ac = triangle.get_third_side_as_vector().get_reversed();
 
@@ -280,7 +292,7 @@ impl Ray {
         let distance_to_intersection = ac.get_dot_product(&vector_q) * inverted_det;
 
         if distance_to_intersection > 0.0 {
-            return Some(self.get_point_at(distance_to_intersection))
+            return Some((distance_to_intersection, u, v))
         }
 
         None
@@ -288,7 +300,10 @@ impl Ray {
 
     /// Calculates [Ray]'s intersections with the [Mesh].
     ///
-    /// It iterates all the [Triangle]s and for each it tries to get an intersection.
+    /// It iterates all the [Triangle]s and for each it tries to get an intersection. For repeated
+    /// queries against the same [Mesh] use
+    /// [Bvh::get_ray_intersections](crate::bvh::Bvh::get_ray_intersections) with the
+    /// [Mesh::get_cached_bvh] instead.
     ///
     /// # Examples
     ///
@@ -377,14 +392,75 @@ impl Ray {
         let mut intersection_points: Vec<Point> = Vec::new();
 
         for triangle in triangles_to_check {
-            let result = self.get_intersection_with_triangle(&triangle);
-            if result.is_some() {
-                intersection_points.push(result.unwrap())
+            if let Some(point) = self.get_intersection_with_triangle(&triangle) {
+                intersection_points.push(point)
             }
         }
 
         intersection_points
     }
+
+    /// Gets all the [RayHit]s of the [Ray] with faces of the [Mesh], sorted by distance from
+    /// the Ray's origin (and by face ids for equal distances).
+    ///
+    /// Unlike [Ray::get_intersections_with_mesh] every hit tells which face was hit, the
+    /// barycentric coordinates of the hit on it and its normal, so it can be used for picking
+    /// or sectioning. It uses the [Mesh]'s cached [Bvh](crate::bvh::Bvh), so call
+    /// [Mesh::invalidate_cache] after editing `coordinates` or `indices` directly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::barycentric_coordinates::BarycentricCoordinates;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::ray::Ray;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new(
+    ///     vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0,
+    ///          0.0, 0.0, 2.0, 0.0, 4.0, 2.0, 4.0, 0.0, 2.0],
+    ///     vec![0, 1, 2, 3, 4, 5]
+    /// );
+    /// let ray = Ray::new(Point::new(1.0, 2.0, 5.0), Vector::new(0.0, 0.0, -1.0));
+    ///
+    /// let actual = ray.get_all_hits_with_mesh(&mesh);
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// assert_eq!(actual[0].face_id, 1);
+    /// assert_eq!(actual[0].distance, 3.0);
+    /// assert_eq!(actual[0].normal, Vector::new(0.0, 0.0, -1.0));
+    /// assert_eq!(actual[1].face_id, 0);
+    /// assert_eq!(actual[1].point, Point::new(1.0, 2.0, 0.0));
+    /// assert_eq!(actual[1].barycentric, BarycentricCoordinates::new(0.25, 0.25, 0.5));
+    /// ```
+    pub fn get_all_hits_with_mesh(&self, mesh:&Mesh) -> Vec<RayHit> {
+        mesh.get_cached_bvh().get_ray_hits(self)
+    }
+
+    /// Gets the [RayHit] closest to the [Ray]'s origin, see [Ray::get_all_hits_with_mesh].
+    ///
+    /// `None` is returned if the Ray doesn't hit the [Mesh].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::ray::Ray;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0], vec![0, 1, 2]);
+    ///
+    /// let hit = Ray::new(Point::new(1.0, 1.0, 2.0), Vector::new(0.0, 0.0, -1.0)).get_first_hit_with_mesh(&mesh);
+    /// let miss = Ray::new(Point::new(1.0, 1.0, 2.0), Vector::new(0.0, 0.0, 1.0)).get_first_hit_with_mesh(&mesh);
+    ///
+    /// assert_eq!(hit.unwrap().distance, 2.0);
+    /// assert_eq!(miss, None);
+    /// ```
+    pub fn get_first_hit_with_mesh(&self, mesh:&Mesh) -> Option<RayHit> {
+        self.get_all_hits_with_mesh(mesh).into_iter().next()
+    }
 }
 
 #[cfg(test)]
@@ -631,4 +707,17 @@ mod tests {
 
         assert_eq!(actual.is_empty(), true);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_does_intersect_with_mesh_after_direct_edit() {
+        let mut mesh = Mesh::new(vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0], vec![0, 1, 2]);
+        let ray = Ray::new(Point::new(1.0, 1.0, 2.0), Vector::new(0.0, 0.0, -1.0));
+        mesh.get_cached_bvh();
+        assert!(ray.does_intersect_with_mesh(&mesh));
+
+        mesh.coordinates = vec![10.0, 0.0, 0.0, 14.0, 0.0, 0.0, 10.0, 4.0, 0.0];
+
+        assert!(!ray.does_intersect_with_mesh(&mesh));
+        assert!(ray.get_intersections_with_mesh(&mesh).is_empty());
+    }
+}
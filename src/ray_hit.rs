@@ -0,0 +1,52 @@
+use crate::barycentric_coordinates::BarycentricCoordinates;
+use crate::point::Point;
+use crate::vector::Vector;
+
+/// Represents a single hit of a [Ray](crate::ray::Ray) with a face of a [Mesh](crate::mesh::Mesh).
+///
+/// It's created by [Ray::get_all_hits_with_mesh](crate::ray::Ray::get_all_hits_with_mesh) and
+/// [Ray::get_first_hit_with_mesh](crate::ray::Ray::get_first_hit_with_mesh), and gives everything
+/// needed for picking or sectioning: which face was hit, where exactly, and how it's oriented.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::mesh::Mesh;
+/// use meshmeshmesh::point::Point;
+/// use meshmeshmesh::ray::Ray;
+/// use meshmeshmesh::vector::Vector;
+///
+/// let mesh = Mesh::new(vec![0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 4.0, 0.0], vec![0, 1, 2]);
+/// let ray = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 0.0, -1.0));
+///
+/// let actual = ray.get_first_hit_with_mesh(&mesh).unwrap();
+///
+/// assert_eq!(actual.face_id, 0);
+/// assert_eq!(actual.distance, 3.0);
+/// assert_eq!(actual.point, Point::new(1.0, 2.0, 0.0));
+/// assert_eq!(actual.normal, Vector::new(0.0, 0.0, 1.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RayHit {
+    /// The distance from the Ray's origin to the hit [Point].
+    pub distance: f64,
+    /// The hit [Point].
+    pub point: Point,
+    /// The id of the hit face.
+    pub face_id: usize,
+    /// Position of the hit [Point] on the face. `u`, `v` and `w` are weights of the face's first,
+    /// second and third vertex.
+    pub barycentric: BarycentricCoordinates,
+    /// The unitized normal of the hit face, following its winding.
+    pub normal: Vector,
+}
+
+impl PartialEq for RayHit {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance &&
+            self.point == other.point &&
+            self.face_id == other.face_id &&
+            self.barycentric == other.barycentric &&
+            self.normal == other.normal
+    }
+}
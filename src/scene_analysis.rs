@@ -2,6 +2,10 @@ use std::collections::HashSet;
 use crate::element::Element;
 use crate::mesh::Mesh;
 use crate::scene::Scene;
+use crate::units::Units;
+
+/// The key of the [Scene] `info` under which the [Units] are stored.
+pub (crate) const SCENE_UNITS_KEY: &str = "Units";
 
 impl Scene {
     /// Compares given [Scene] to other one, but with a `f64` tolerance for fields
@@ -58,6 +62,72 @@ impl Scene {
         
         set
     }
+
+    /// Gets the [Units] of the [Scene] stored in its `info` under the `Units` key.
+    ///
+    /// The dotbim format doesn't define the units, so this is just a convention. It returns
+    /// `Ok(None)` if the units are not set, and an error if the stored value is not recognized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use meshmeshmesh::scene::Scene;
+    /// use meshmeshmesh::units::Units;
+    ///
+    /// let mut info = HashMap::new();
+    /// info.insert(String::from("Units"), String::from("mm"));
+    /// let scene = Scene::new(String::from("1.0.0"), Vec::new(), Vec::new(), info);
+    ///
+    /// assert_eq!(scene.get_units(), Ok(Some(Units::Millimeters)));
+    /// ```
+    pub fn get_units(&self) -> Result<Option<Units>, String> {
+        match self.info.get(SCENE_UNITS_KEY) {
+            Some(value) => Units::from_abbreviation(value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Checks if every [Element] of the [Scene] has a unique `guid`.
+    ///
+    /// It returns an error listing the duplicated `guid`s, see [Element::get_duplicate_guids].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use meshmeshmesh::color::Color;
+    /// use meshmeshmesh::element::Element;
+    /// use meshmeshmesh::quaternion::Quaternion;
+    /// use meshmeshmesh::scene::Scene;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let get_element = |guid: &str| Element::new(
+    ///     0,
+    ///     Vector::new(0.0, 0.0, 0.0),
+    ///     Quaternion::new(0.0, 0.0, 0.0, 1.0),
+    ///     String::from(guid),
+    ///     String::from("Plate"),
+    ///     Color::new(0, 120, 120, 255),
+    ///     None,
+    ///     HashMap::new(),
+    /// );
+    ///
+    /// let valid = Scene::new(String::from("1.0.0"), Vec::new(), vec![get_element("a"), get_element("b")], HashMap::new());
+    /// let invalid = Scene::new(String::from("1.0.0"), Vec::new(), vec![get_element("a"), get_element("a")], HashMap::new());
+    ///
+    /// assert_eq!(valid.check_unique_guids(), Ok(()));
+    /// assert_eq!(invalid.check_unique_guids(), Err(String::from("Elements have duplicate guids: a")));
+    /// ```
+    pub fn check_unique_guids(&self) -> Result<(), String> {
+        let duplicates = Element::get_duplicate_guids(&self.elements);
+        if duplicates.is_empty() {
+            Ok(())
+        }
+        else {
+            Err(format!("Elements have duplicate guids: {}", duplicates.join(", ")))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -444,4 +514,37 @@ mod tests {
         assert_eq!(a.eq_with_tolerance(&b, 0.001), false);
         assert_eq!(b.eq_with_tolerance(&a, 0.001), false);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_get_units_not_set() {
+        let scene = Scene::new(String::from("1.0.0"), Vec::new(), Vec::new(), HashMap::new());
+        assert_eq!(scene.get_units(), Ok(None));
+    }
+
+    #[test]
+    fn test_get_units_unknown() {
+        let mut info = HashMap::new();
+        info.insert(String::from("Units"), String::from("furlong"));
+        let scene = Scene::new(String::from("1.0.0"), Vec::new(), Vec::new(), info);
+        assert!(scene.get_units().is_err());
+    }
+    #[test]
+    fn test_check_unique_guids_with_instances() {
+        let mut scene = get_file_with_triangle_blue_plate();
+        let mut instance = get_file_with_triangle_blue_plate().elements.remove(0);
+        instance.guid = String::from("1a2b3c4d-0000-4e31-bcee-740dbda61e20");
+        instance.vector = Vector::new(20.0, 0.0, 0.0);
+        scene.elements.push(instance);
+
+        assert_eq!(scene.check_unique_guids(), Ok(()));
+    }
+
+    #[test]
+    fn test_check_unique_guids_duplicates() {
+        let mut scene = get_file_with_triangle_blue_plate();
+        let copy = get_file_with_triangle_blue_plate().elements.remove(0);
+        scene.elements.push(copy);
+
+        assert_eq!(scene.check_unique_guids(), Err(String::from("Elements have duplicate guids: d4f28792-e1e9-4e31-bcee-740dbda61e20")));
+    }
+}
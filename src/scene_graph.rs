@@ -0,0 +1,208 @@
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::mesh::Mesh;
+use crate::scene::Scene;
+
+/// Represents a node of the [SceneGraph].
+///
+/// The `local_coordinate_system` places the node relatively to its parent (or to the Global
+/// Coordinate System for root nodes). The node can reference a [Mesh] from the [SceneGraph]
+/// by its index, so many nodes can share the same [Mesh].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    /// The name of the node.
+    pub name: String,
+    /// The index of the parent node, `None` for root nodes.
+    pub parent: Option<usize>,
+    /// The transform relative to the parent node.
+    pub local_coordinate_system: LocalCoordinateSystem,
+    /// The index of the [Mesh] in [SceneGraph::meshes] placed in this node.
+    pub mesh_id: Option<usize>,
+}
+
+/// Represents a hierarchy of [Node]s with transforms, referencing shared [Mesh]es.
+///
+/// It's a bridge between deduplicated meshes and exporters that expect hierarchies, like glTF
+/// or 3MF.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+/// use meshmeshmesh::mesh::Mesh;
+/// use meshmeshmesh::point::Point;
+/// use meshmeshmesh::scene_graph::SceneGraph;
+/// use meshmeshmesh::vector::Vector;
+///
+/// let mut scene_graph = SceneGraph::new();
+/// let mesh_id = scene_graph.add_mesh(Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]));
+/// let floor = scene_graph.add_node("Floor", None, LocalCoordinateSystem::new(Point::new(0.0, 0.0, 3.0), Vector::x_unit(), Vector::y_unit()), None);
+/// scene_graph.add_node("Panel", Some(floor), LocalCoordinateSystem::new(Point::new(5.0, 0.0, 0.0), Vector::x_unit(), Vector::y_unit()), Some(mesh_id));
+///
+/// let actual = scene_graph.get_flattened_meshes().unwrap();
+///
+/// assert_eq!(actual, vec![Mesh::new(vec![5.0, 0.0, 3.0, 6.0, 0.0, 3.0, 5.0, 1.0, 3.0], vec![0, 1, 2])]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneGraph {
+    /// The list of [Mesh]es, referenced by [Node]s.
+    pub meshes: Vec<Mesh>,
+    /// The list of [Node]s.
+    pub nodes: Vec<Node>,
+}
+
+impl SceneGraph {
+    /// Returns a new, empty [SceneGraph].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::scene_graph::SceneGraph;
+    ///
+    /// let result = SceneGraph::new();
+    ///
+    /// assert!(result.meshes.is_empty());
+    /// assert!(result.nodes.is_empty());
+    /// ```
+    pub fn new() -> SceneGraph { SceneGraph { meshes: Vec::new(), nodes: Vec::new() } }
+
+    /// Adds the [Mesh] and returns its index, which can be used by [Node]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::scene_graph::SceneGraph;
+    ///
+    /// let mut scene_graph = SceneGraph::new();
+    ///
+    /// let actual = scene_graph.add_mesh(Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]));
+    ///
+    /// assert_eq!(actual, 0);
+    /// assert_eq!(scene_graph.meshes.len(), 1);
+    /// ```
+    pub fn add_mesh(&mut self, mesh: Mesh) -> usize {
+        self.meshes.push(mesh);
+        self.meshes.len() - 1
+    }
+
+    /// Adds the [Node] and returns its index.
+    ///
+    /// It panics if the `parent` or the `mesh_id` doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::scene_graph::SceneGraph;
+    ///
+    /// let mut scene_graph = SceneGraph::new();
+    ///
+    /// let root = scene_graph.add_node("Building", None, LocalCoordinateSystem::global(), None);
+    /// let child = scene_graph.add_node("Storey", Some(root), LocalCoordinateSystem::global(), None);
+    ///
+    /// assert_eq!(child, 1);
+    /// assert_eq!(scene_graph.nodes[child].parent, Some(root));
+    /// assert_eq!(scene_graph.get_children(root), vec![child]);
+    /// ```
+    pub fn add_node(&mut self, name: &str, parent: Option<usize>, local_coordinate_system: LocalCoordinateSystem, mesh_id: Option<usize>) -> usize {
+        if parent.is_some_and(|parent| parent >= self.nodes.len()) {
+            panic!("Parent node doesn't exist");
+        }
+        if mesh_id.is_some_and(|mesh_id| mesh_id >= self.meshes.len()) {
+            panic!("Mesh doesn't exist");
+        }
+
+        self.nodes.push(Node { name: name.to_string(), parent, local_coordinate_system, mesh_id });
+        self.nodes.len() - 1
+    }
+
+    /// Creates a new [SceneGraph] from the [Scene].
+    ///
+    /// [Mesh]es are shared as they are, and each [Element](crate::element::Element) becomes a
+    /// root [Node] named with its `guid`, placed with its `vector` and `rotation`.
+    ///
+    /// It returns an error if any Element references a [Mesh] `id` which doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use meshmeshmesh::color::Color;
+    /// use meshmeshmesh::element::Element;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::quaternion::Quaternion;
+    /// use meshmeshmesh::scene::Scene;
+    /// use meshmeshmesh::scene_graph::SceneGraph;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new_with_id(Some(4), vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+    /// let element = Element::new(4, Vector::new(0.0, 0.0, 2.0), Quaternion::identity(), String::from("a"),
+    ///                            String::from("Plate"), Color::new(0, 0, 255, 255), None, HashMap::new());
+    /// let scene = Scene::new(String::from("1.1.0"), vec![mesh], vec![element], HashMap::new());
+    ///
+    /// let actual = SceneGraph::from_scene(&scene).unwrap();
+    ///
+    /// assert_eq!(actual.nodes.len(), 1);
+    /// assert_eq!(actual.nodes[0].name, "a");
+    /// assert_eq!(actual.nodes[0].mesh_id, Some(0));
+    /// assert_eq!(actual.get_flattened_meshes().unwrap()[0].coordinates, vec![0.0, 0.0, 2.0, 1.0, 0.0, 2.0, 0.0, 1.0, 2.0]);
+    /// ```
+    pub fn from_scene(scene: &Scene) -> Result<SceneGraph, String> {
+        let mut scene_graph = SceneGraph::new();
+        for mesh in &scene.meshes {
+            scene_graph.add_mesh(mesh.clone());
+        }
+
+        for element in &scene.elements {
+            let mesh_id = match scene.meshes.iter().position(|mesh| mesh.id == Some(element.mesh_id)) {
+                Some(mesh_id) => mesh_id,
+                None => return Err(format!("Element {} references a missing mesh {}", element.guid, element.mesh_id)),
+            };
+            scene_graph.add_node(&element.guid, None, element.get_local_coordinate_system(), Some(mesh_id));
+        }
+
+        Ok(scene_graph)
+    }
+}
+
+impl Default for SceneGraph {
+    fn default() -> Self {
+        SceneGraph::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "Parent node doesn't exist")]
+    fn test_add_node_missing_parent_panic() {
+        let mut scene_graph = SceneGraph::new();
+        scene_graph.add_node("Orphan", Some(0), LocalCoordinateSystem::global(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Mesh doesn't exist")]
+    fn test_add_node_missing_mesh_panic() {
+        let mut scene_graph = SceneGraph::new();
+        scene_graph.add_node("Empty", None, LocalCoordinateSystem::global(), Some(0));
+    }
+
+    #[test]
+    fn test_from_scene_missing_mesh() {
+        use std::collections::HashMap;
+        use crate::color::Color;
+        use crate::element::Element;
+        use crate::quaternion::Quaternion;
+        use crate::vector::Vector;
+
+        let element = Element::new(1, Vector::zero(), Quaternion::identity(), String::from("a"),
+                                   String::from("Plate"), Color::new(0, 0, 255, 255), None, HashMap::new());
+        let scene = Scene::new(String::from("1.1.0"), vec![], vec![element], HashMap::new());
+
+        let actual = SceneGraph::from_scene(&scene);
+
+        assert_eq!(actual, Err("Element a references a missing mesh 1".to_string()));
+    }
+}
@@ -0,0 +1,254 @@
+use crate::bounding_box::BoundingBox;
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::mesh::Mesh;
+use crate::scene_graph::SceneGraph;
+
+impl SceneGraph {
+    /// Gets indices of the direct children of the given [Node](crate::scene_graph::Node).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::scene_graph::SceneGraph;
+    ///
+    /// let mut scene_graph = SceneGraph::new();
+    /// let root = scene_graph.add_node("Root", None, LocalCoordinateSystem::global(), None);
+    /// let a = scene_graph.add_node("A", Some(root), LocalCoordinateSystem::global(), None);
+    /// scene_graph.add_node("B", Some(a), LocalCoordinateSystem::global(), None);
+    /// let c = scene_graph.add_node("C", Some(root), LocalCoordinateSystem::global(), None);
+    ///
+    /// assert_eq!(scene_graph.get_children(root), vec![a, c]);
+    /// ```
+    pub fn get_children(&self, node_id: usize) -> Vec<usize> {
+        (0..self.nodes.len()).filter(|child_id| self.nodes[*child_id].parent == Some(node_id)).collect()
+    }
+
+    /// Gets the world transform of each [Node](crate::scene_graph::Node), by combining its
+    /// [LocalCoordinateSystem] with the ones of all its ancestors.
+    ///
+    /// It returns an error if a parent doesn't exist, or the hierarchy contains a cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::scene_graph::SceneGraph;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mut scene_graph = SceneGraph::new();
+    /// // Rotated by 90 degrees around Z
+    /// let root = scene_graph.add_node("Root", None, LocalCoordinateSystem::new(Point::new(10.0, 0.0, 0.0), Vector::y_unit(), Vector::x_unit().get_reversed()), None);
+    /// let child = scene_graph.add_node("Child", Some(root), LocalCoordinateSystem::new(Point::new(2.0, 0.0, 0.0), Vector::x_unit(), Vector::y_unit()), None);
+    ///
+    /// let actual = scene_graph.get_world_coordinate_systems().unwrap();
+    ///
+    /// let expected = LocalCoordinateSystem::new(Point::new(10.0, 2.0, 0.0), Vector::y_unit(), Vector::x_unit().get_reversed());
+    /// assert!(actual[child].eq_with_tolerance(&expected, 1e-12));
+    /// ```
+    pub fn get_world_coordinate_systems(&self) -> Result<Vec<LocalCoordinateSystem>, String> {
+        let number_of_nodes = self.nodes.len();
+        let mut world: Vec<Option<LocalCoordinateSystem>> = vec![None; number_of_nodes];
+
+        for node_id in 0..number_of_nodes {
+            let mut chain: Vec<usize> = Vec::new();
+            let mut current = Some(node_id);
+            while let Some(current_id) = current {
+                if world[current_id].is_some() {
+                    break;
+                }
+                if chain.len() > number_of_nodes {
+                    return Err("The scene graph hierarchy contains a cycle".to_string());
+                }
+                chain.push(current_id);
+                current = self.nodes[current_id].parent;
+                if current.is_some_and(|parent| parent >= number_of_nodes) {
+                    return Err(format!("Parent of the node {} doesn't exist", current_id));
+                }
+            }
+
+            let mut parent_world = current.map(|parent| world[parent].unwrap());
+            for chain_id in chain.into_iter().rev() {
+                let local = self.nodes[chain_id].local_coordinate_system;
+                let combined = match parent_world {
+                    Some(parent) => get_combined(&parent, &local),
+                    None => local,
+                };
+                world[chain_id] = Some(combined);
+                parent_world = Some(combined);
+            }
+        }
+
+        Ok(world.into_iter().map(|coordinate_system| coordinate_system.unwrap()).collect())
+    }
+
+    /// Creates a flat list of [Mesh]es placed in the world: one for each
+    /// [Node](crate::scene_graph::Node) with a [Mesh], in the order of the nodes.
+    ///
+    /// It returns an error in the same cases as [SceneGraph::get_world_coordinate_systems].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::scene_graph::SceneGraph;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mut scene_graph = SceneGraph::new();
+    /// let mesh_id = scene_graph.add_mesh(Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]));
+    /// for x in 0..3 {
+    ///     scene_graph.add_node("Instance", None, LocalCoordinateSystem::new(Point::new(x as f64 * 10.0, 0.0, 0.0), Vector::x_unit(), Vector::y_unit()), Some(mesh_id));
+    /// }
+    ///
+    /// let actual = scene_graph.get_flattened_meshes().unwrap();
+    ///
+    /// assert_eq!(actual.len(), 3);
+    /// assert_eq!(actual[2].coordinates, vec![20.0, 0.0, 0.0, 21.0, 0.0, 0.0, 20.0, 1.0, 0.0]);
+    /// ```
+    pub fn get_flattened_meshes(&self) -> Result<Vec<Mesh>, String> {
+        let world = self.get_world_coordinate_systems()?;
+
+        Ok(self.nodes.iter().enumerate()
+            .filter_map(|(node_id, node)| node.mesh_id.map(|mesh_id| self.meshes[mesh_id].get_in_local_coordinate_system(&world[node_id])))
+            .collect())
+    }
+
+    /// Gets the world [BoundingBox] of each [Node](crate::scene_graph::Node), covering its
+    /// own [Mesh] and the Meshes of all its descendants.
+    ///
+    /// It's `None` for nodes without any geometry below them.
+    ///
+    /// It returns an error in the same cases as [SceneGraph::get_world_coordinate_systems].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::bounding_box::BoundingBox;
+    /// use meshmeshmesh::local_coordinate_system::LocalCoordinateSystem;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::scene_graph::SceneGraph;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mut scene_graph = SceneGraph::new();
+    /// let mesh_id = scene_graph.add_mesh(Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]));
+    /// let root = scene_graph.add_node("Root", None, LocalCoordinateSystem::global(), None);
+    /// scene_graph.add_node("A", Some(root), LocalCoordinateSystem::global(), Some(mesh_id));
+    /// scene_graph.add_node("B", Some(root), LocalCoordinateSystem::new(Point::new(5.0, 0.0, 2.0), Vector::x_unit(), Vector::y_unit()), Some(mesh_id));
+    /// let empty = scene_graph.add_node("Empty", None, LocalCoordinateSystem::global(), None);
+    ///
+    /// let actual = scene_graph.get_bounding_boxes().unwrap();
+    ///
+    /// assert!(actual[root].as_ref().unwrap().eq(&BoundingBox::new(0.0, 6.0, 0.0, 1.0, 0.0, 2.0)));
+    /// assert!(actual[empty].is_none());
+    /// ```
+    pub fn get_bounding_boxes(&self) -> Result<Vec<Option<BoundingBox>>, String> {
+        let world = self.get_world_coordinate_systems()?;
+        let mut bounding_boxes: Vec<Option<BoundingBox>> = vec![None; self.nodes.len()];
+
+        for (node_id, node) in self.nodes.iter().enumerate() {
+            let mesh_id = match node.mesh_id {
+                Some(mesh_id) => mesh_id,
+                None => continue,
+            };
+            let mesh = &self.meshes[mesh_id];
+            if mesh.coordinates.is_empty() {
+                continue;
+            }
+            let bounding_box = mesh.get_in_local_coordinate_system(&world[node_id]).get_bounding_box();
+
+            // The world transforms succeeded, so the hierarchy has no cycles.
+            let mut current = Some(node_id);
+            while let Some(current_id) = current {
+                bounding_boxes[current_id] = Some(match &bounding_boxes[current_id] {
+                    Some(existing) => get_union(existing, &bounding_box),
+                    None => bounding_box,
+                });
+                current = self.nodes[current_id].parent;
+            }
+        }
+
+        Ok(bounding_boxes)
+    }
+}
+
+/// Combines the parent's world transform with the child's local one.
+fn get_combined(parent: &LocalCoordinateSystem, local: &LocalCoordinateSystem) -> LocalCoordinateSystem {
+    let parent_z = parent.get_z();
+    LocalCoordinateSystem {
+        origin: local.origin.get_in_local_coordinate_system(parent),
+        x: parent.x * local.x.x + parent.y * local.x.y + parent_z * local.x.z,
+        y: parent.x * local.y.x + parent.y * local.y.y + parent_z * local.y.z,
+    }
+}
+
+fn get_union(a: &BoundingBox, b: &BoundingBox) -> BoundingBox {
+    BoundingBox::new(a.min_x.min(b.min_x), a.max_x.max(b.max_x), a.min_y.min(b.min_y), a.max_y.max(b.max_y), a.min_z.min(b.min_z), a.max_z.max(b.max_z))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn test_get_world_coordinate_systems_matches_flattened_points() {
+        let mut scene_graph = SceneGraph::new();
+        let mesh_id = scene_graph.add_mesh(Mesh::new(vec![1.0, 2.0, 3.0, -1.0, 0.5, 0.0, 0.0, 1.0, 4.0], vec![0, 1, 2]));
+        let first = LocalCoordinateSystem::global().get_rotated_by_quaternion_around_its_origin(Quaternion::new(0.1, 0.3, -0.2, 0.927));
+        let first = LocalCoordinateSystem::new(Point::new(3.0, -2.0, 1.0), first.x, first.y);
+        let second = LocalCoordinateSystem::global().get_rotated_by_quaternion_around_its_origin(Quaternion::new(-0.4, 0.1, 0.5, 0.76));
+        let second = LocalCoordinateSystem::new(Point::new(-1.0, 4.0, 2.5), second.x, second.y);
+        let parent = scene_graph.add_node("Parent", None, first, None);
+        scene_graph.add_node("Child", Some(parent), second, Some(mesh_id));
+
+        let actual = scene_graph.get_flattened_meshes().unwrap();
+
+        let expected = scene_graph.meshes[0].get_in_local_coordinate_system(&second).get_in_local_coordinate_system(&first);
+        assert!(actual[0].eq_with_tolerance(&expected, 1e-9));
+    }
+
+    #[test]
+    fn test_get_world_coordinate_systems_cycle() {
+        let mut scene_graph = SceneGraph::new();
+        let a = scene_graph.add_node("A", None, LocalCoordinateSystem::global(), None);
+        let b = scene_graph.add_node("B", Some(a), LocalCoordinateSystem::global(), None);
+        scene_graph.nodes[a].parent = Some(b);
+
+        let actual = scene_graph.get_world_coordinate_systems();
+
+        assert_eq!(actual, Err("The scene graph hierarchy contains a cycle".to_string()));
+    }
+
+    #[test]
+    fn test_get_world_coordinate_systems_missing_parent() {
+        let mut scene_graph = SceneGraph::new();
+        let a = scene_graph.add_node("A", None, LocalCoordinateSystem::global(), None);
+        scene_graph.nodes[a].parent = Some(7);
+
+        let actual = scene_graph.get_world_coordinate_systems();
+
+        assert_eq!(actual, Err("Parent of the node 0 doesn't exist".to_string()));
+    }
+
+    #[test]
+    fn test_get_bounding_boxes_nested() {
+        let mut scene_graph = SceneGraph::new();
+        let mesh_id = scene_graph.add_mesh(Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]));
+        let offset = LocalCoordinateSystem::new(Point::new(0.0, 0.0, 10.0), Vector::x_unit(), Vector::y_unit());
+        let root = scene_graph.add_node("Root", None, offset, Some(mesh_id));
+        let middle = scene_graph.add_node("Middle", Some(root), offset, None);
+        let leaf = scene_graph.add_node("Leaf", Some(middle), offset, Some(mesh_id));
+
+        let actual = scene_graph.get_bounding_boxes().unwrap();
+
+        assert!(actual[leaf].as_ref().unwrap().eq(&BoundingBox::new(0.0, 1.0, 0.0, 1.0, 30.0, 30.0)));
+        assert!(actual[middle].as_ref().unwrap().eq(&BoundingBox::new(0.0, 1.0, 0.0, 1.0, 30.0, 30.0)));
+        assert!(actual[root].as_ref().unwrap().eq(&BoundingBox::new(0.0, 1.0, 0.0, 1.0, 10.0, 30.0)));
+    }
+}
@@ -3,6 +3,8 @@ use crate::local_coordinate_system::LocalCoordinateSystem;
 use crate::mesh::Mesh;
 use crate::quaternion::Quaternion;
 use crate::scene::Scene;
+use crate::scene_analysis::SCENE_UNITS_KEY;
+use crate::units::Units;
 use crate::vector::Vector;
 
 impl Scene {
@@ -97,6 +99,79 @@ impl Scene {
 
         panic!("Couldn't find new mesh_id for deduplication.")
     }
+
+    /// Sets the [Units] of the [Scene] by storing their abbreviation in its `info` under the
+    /// `Units` key.
+    ///
+    /// It only sets the metadata, geometry stays untouched. Use [Scene::convert_units] to scale
+    /// the geometry as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use meshmeshmesh::scene::Scene;
+    /// use meshmeshmesh::units::Units;
+    ///
+    /// let mut scene = Scene::new(String::from("1.0.0"), Vec::new(), Vec::new(), HashMap::new());
+    /// scene.set_units(Units::Feet);
+    ///
+    /// assert_eq!(scene.info["Units"], "ft");
+    /// assert_eq!(scene.get_units(), Ok(Some(Units::Feet)));
+    /// ```
+    pub fn set_units(&mut self, units: Units) {
+        self.info.insert(String::from(SCENE_UNITS_KEY), String::from(units.get_abbreviation()));
+    }
+
+    /// Converts the whole [Scene] to given [Units].
+    ///
+    /// All the [Mesh]es and the [Element](crate::element::Element)s' `vector`s are scaled, and the units stored in `info`
+    /// are updated.
+    ///
+    /// It returns an error if the [Scene] doesn't have any (valid) units set, as there is nothing
+    /// to convert from then.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use meshmeshmesh::color::Color;
+    /// use meshmeshmesh::element::Element;
+    /// use meshmeshmesh::mesh::Mesh;
+    /// use meshmeshmesh::quaternion::Quaternion;
+    /// use meshmeshmesh::scene::Scene;
+    /// use meshmeshmesh::units::Units;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let mesh = Mesh::new_with_id(Some(0), vec![0.0, 0.0, 0.0, 1000.0, 0.0, 0.0, 1000.0, 500.0, 0.0], vec![0, 1, 2]);
+    /// let element = Element::new(0, Vector::new(2000.0, 0.0, 0.0), Quaternion::identity(), String::from("d4f28792-e1e9-4e31-bcee-740dbda61e20"),
+    ///                            String::from("Plate"), Color::new(0, 120, 120, 255), None, HashMap::new());
+    /// let mut scene = Scene::new(String::from("1.0.0"), vec![mesh], vec![element], HashMap::new());
+    /// scene.set_units(Units::Millimeters);
+    ///
+    /// scene.convert_units(Units::Meters).unwrap();
+    ///
+    /// assert_eq!(scene.meshes[0].coordinates, vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.5, 0.0]);
+    /// assert_eq!(scene.elements[0].vector, Vector::new(2.0, 0.0, 0.0));
+    /// assert_eq!(scene.get_units(), Ok(Some(Units::Meters)));
+    /// ```
+    pub fn convert_units(&mut self, to: Units) -> Result<(), String> {
+        let from = match self.get_units()? {
+            Some(units) => units,
+            None => return Err(String::from("Cannot convert units of the Scene without units set")),
+        };
+
+        let factor = from.get_conversion_factor(to);
+        for mesh in self.meshes.iter_mut() {
+            mesh.convert_units(from, to);
+        }
+        for element in self.elements.iter_mut() {
+            element.vector = element.vector * factor;
+        }
+        self.set_units(to);
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -339,4 +414,27 @@ mod tests {
 
         assert!(expected.eq_with_tolerance(&actual, 0.001));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_convert_units_without_units_error() {
+        let mut scene = Scene::new(String::from("1.0.0"), Vec::new(), Vec::new(), HashMap::new());
+        assert!(scene.convert_units(Units::Meters).is_err());
+    }
+
+    #[test]
+    fn test_convert_units_roundtrip() {
+        let mesh = Mesh::new_with_id(Some(0), vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+        let element = Element::new(0, Vector::new(1.5, -2.0, 3.0), Quaternion::identity(), String::from("d4f28792-e1e9-4e31-bcee-740dbda61e20"),
+                                   String::from("Plate"), Color::new(0, 120, 120, 255), None, HashMap::new());
+        let mut scene = Scene::new(String::from("1.0.0"), vec![mesh.clone()], vec![element], HashMap::new());
+        scene.set_units(Units::Inches);
+
+        scene.convert_units(Units::Centimeters).unwrap();
+        assert!((scene.elements[0].vector.x - 3.81).abs() < 1e-12);
+        scene.convert_units(Units::Inches).unwrap();
+
+        assert!(scene.meshes[0].eq_with_tolerance(&mesh, 1e-9));
+        assert!((scene.elements[0].vector.x - 1.5).abs() < 1e-12);
+        assert_eq!(scene.info["Units"], "in");
+    }
+}
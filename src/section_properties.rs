@@ -0,0 +1,102 @@
+use crate::point2d::Point2D;
+
+/// Represents geometric properties of a planar cross-section.
+///
+/// Second moments of area are calculated about the axes going through the `centroid`, parallel
+/// to the global X and Y axes.
+///
+/// Use [Polygon2D::get_section_properties](crate::polygon2d::Polygon2D::get_section_properties)
+/// to calculate it.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::point2d::Point2D;
+/// use meshmeshmesh::polygon2d::Polygon2D;
+///
+/// // Rectangle 200 wide and 400 high
+/// let input = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(200.0, 0.0), Point2D::new(200.0, 400.0), Point2D::new(0.0, 400.0)]);
+///
+/// let result = input.get_section_properties(&vec![]);
+///
+/// assert_eq!(result.area, 80000.0);
+/// assert_eq!(result.centroid, Point2D::new(100.0, 200.0));
+/// assert!((result.i_xx - 200.0 * 400.0_f64.powi(3) / 12.0).abs() < 1e-3);
+/// assert!((result.section_modulus_x_top - 200.0 * 400.0_f64.powi(2) / 6.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SectionProperties {
+    /// The area of the section (holes excluded).
+    pub area: f64,
+    /// The centroid (center of the area) of the section.
+    pub centroid: Point2D,
+    /// The second moment of area about the centroidal axis parallel to X.
+    pub i_xx: f64,
+    /// The second moment of area about the centroidal axis parallel to Y.
+    pub i_yy: f64,
+    /// The product of area about the centroidal axes.
+    pub i_xy: f64,
+    /// The elastic section modulus about the X axis for the top fibre (max Y).
+    pub section_modulus_x_top: f64,
+    /// The elastic section modulus about the X axis for the bottom fibre (min Y).
+    pub section_modulus_x_bottom: f64,
+    /// The elastic section modulus about the Y axis for the left fibre (min X).
+    pub section_modulus_y_left: f64,
+    /// The elastic section modulus about the Y axis for the right fibre (max X).
+    pub section_modulus_y_right: f64,
+}
+
+impl PartialEq for SectionProperties {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && self.centroid == other.centroid &&
+            self.i_xx == other.i_xx && self.i_yy == other.i_yy && self.i_xy == other.i_xy &&
+            self.section_modulus_x_top == other.section_modulus_x_top &&
+            self.section_modulus_x_bottom == other.section_modulus_x_bottom &&
+            self.section_modulus_y_left == other.section_modulus_y_left &&
+            self.section_modulus_y_right == other.section_modulus_y_right
+    }
+}
+
+impl SectionProperties {
+    /// Returns the minimum elastic section modulus about the X axis.
+    ///
+    /// It's the one governing the maximum bending stress.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::polygon2d::Polygon2D;
+    ///
+    /// // Right triangle, centroid is closer to the bottom
+    /// let input = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 0.0), Point2D::new(0.0, 3.0)]);
+    ///
+    /// let result = input.get_section_properties(&vec![]);
+    ///
+    /// assert_eq!(result.get_minimum_section_modulus_x(), result.section_modulus_x_top);
+    /// ```
+    pub fn get_minimum_section_modulus_x(&self) -> f64 {
+        self.section_modulus_x_top.min(self.section_modulus_x_bottom)
+    }
+
+    /// Returns the minimum elastic section modulus about the Y axis.
+    ///
+    /// It's the one governing the maximum bending stress.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::polygon2d::Polygon2D;
+    ///
+    /// // Right triangle, centroid is closer to the left side
+    /// let input = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(3.0, 0.0), Point2D::new(0.0, 3.0)]);
+    ///
+    /// let result = input.get_section_properties(&vec![]);
+    ///
+    /// assert_eq!(result.get_minimum_section_modulus_y(), result.section_modulus_y_right);
+    /// ```
+    pub fn get_minimum_section_modulus_y(&self) -> f64 {
+        self.section_modulus_y_left.min(self.section_modulus_y_right)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::edge::Edge;
+use crate::point::Point;
+use crate::point2d::Point2D;
+
+/// Represents the straight skeleton of a planar region: the paths of vertices of its boundary
+/// shrinking with the constant speed.
+///
+/// Use [Polygon2D::get_straight_skeleton](crate::polygon2d::Polygon2D::get_straight_skeleton)
+/// to calculate it.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::edge::Edge;
+/// use meshmeshmesh::point2d::Point2D;
+/// use meshmeshmesh::polygon2d::Polygon2D;
+///
+/// let input = Polygon2D::new(vec![Point2D::new(0.0, 0.0), Point2D::new(2.0, 0.0), Point2D::new(2.0, 2.0), Point2D::new(0.0, 2.0)]);
+///
+/// let result = input.get_straight_skeleton(&[]);
+///
+/// assert_eq!(result.nodes[4], Point2D::new(1.0, 1.0));
+/// assert_eq!(result.heights[4], 1.0);
+/// assert_eq!(result.arcs.len(), 4);
+/// assert!(result.arcs.contains(&Edge::new(0, 4)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StraightSkeleton {
+    /// Nodes of the skeleton. First go vertices of the region's boundary, then nodes where
+    /// the shrinking boundary changes.
+    pub nodes: Vec<Point2D>,
+    /// Offset distances at which the shrinking boundary reaches each node, so 0.0 for vertices
+    /// of the boundary.
+    pub heights: Vec<f64>,
+    /// Arcs of the skeleton as pairs of node ids, going from the lower node to the higher one.
+    pub arcs: Vec<Edge>,
+}
+
+impl StraightSkeleton {
+    /// Creates a new [StraightSkeleton].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::straight_skeleton::StraightSkeleton;
+    ///
+    /// let result = StraightSkeleton::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)], vec![0.0, 1.0], vec![Edge::new(0, 1)]);
+    ///
+    /// assert_eq!(result.nodes, vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)]);
+    /// assert_eq!(result.heights, vec![0.0, 1.0]);
+    /// assert_eq!(result.arcs, vec![Edge::new(0, 1)]);
+    /// ```
+    pub fn new(nodes: Vec<Point2D>, heights: Vec<f64>, arcs: Vec<Edge>) -> StraightSkeleton {
+        StraightSkeleton { nodes, heights, arcs }
+    }
+
+    /// Gets nodes as 3D [Point]s with heights multiplied by the `slope` as their z coordinates,
+    /// e.g. to get ridges and hips of a roof with the constant slope.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::point2d::Point2D;
+    /// use meshmeshmesh::straight_skeleton::StraightSkeleton;
+    ///
+    /// let input = StraightSkeleton::new(vec![Point2D::new(0.0, 0.0), Point2D::new(1.0, 1.0)], vec![0.0, 1.0], vec![Edge::new(0, 1)]);
+    ///
+    /// assert_eq!(input.to_points(0.5), vec![Point::new(0.0, 0.0, 0.0), Point::new(1.0, 1.0, 0.5)]);
+    /// ```
+    pub fn to_points(&self, slope: f64) -> Vec<Point> {
+        self.nodes.iter().zip(&self.heights).map(|(node, height)| Point::new(node.x, node.y, height * slope)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_points_flat() {
+        let input = StraightSkeleton::new(vec![Point2D::new(2.0, 3.0)], vec![4.0], vec![]);
+
+        assert_eq!(input.to_points(0.0), vec![Point::new(2.0, 3.0, 0.0)]);
+    }
+}
@@ -0,0 +1,98 @@
+/// Represents distances between surfaces of 2 [Mesh](crate::mesh::Mesh)es.
+///
+/// It's created by [Mesh::distance_to](crate::mesh::Mesh::distance_to). Distances are measured
+/// from points sampled on the surface of one Mesh to the closest points on the other one, in
+/// both directions, as they're not symmetric: e.g. a small patch lies close to a big surface,
+/// but not the other way round.
+///
+/// It can be used to quantify the error introduced by simplification or decimation.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::surface_distance::SurfaceDistance;
+///
+/// let distance = SurfaceDistance {
+///     max_distance_to_other: 0.5,
+///     mean_distance_to_other: 0.1,
+///     rms_distance_to_other: 0.2,
+///     max_distance_from_other: 1.5,
+///     mean_distance_from_other: 0.3,
+///     rms_distance_from_other: 0.4,
+/// };
+///
+/// assert_eq!(distance.get_hausdorff_distance(), 1.5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SurfaceDistance {
+    /// The one-sided Hausdorff distance: the biggest distance from this Mesh to the other one.
+    pub max_distance_to_other: f64,
+    /// The area weighted mean distance from this Mesh to the other one.
+    pub mean_distance_to_other: f64,
+    /// The area weighted root mean square distance from this Mesh to the other one.
+    pub rms_distance_to_other: f64,
+    /// The one-sided Hausdorff distance: the biggest distance from the other Mesh to this one.
+    pub max_distance_from_other: f64,
+    /// The area weighted mean distance from the other Mesh to this one.
+    pub mean_distance_from_other: f64,
+    /// The area weighted root mean square distance from the other Mesh to this one.
+    pub rms_distance_from_other: f64,
+}
+
+impl PartialEq for SurfaceDistance {
+    fn eq(&self, other: &Self) -> bool {
+        self.max_distance_to_other == other.max_distance_to_other &&
+            self.mean_distance_to_other == other.mean_distance_to_other &&
+            self.rms_distance_to_other == other.rms_distance_to_other &&
+            self.max_distance_from_other == other.max_distance_from_other &&
+            self.mean_distance_from_other == other.mean_distance_from_other &&
+            self.rms_distance_from_other == other.rms_distance_from_other
+    }
+}
+
+impl SurfaceDistance {
+    /// Gets the symmetric Hausdorff distance: the bigger of both one-sided ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::surface_distance::SurfaceDistance;
+    ///
+    /// let distance = SurfaceDistance {
+    ///     max_distance_to_other: 2.5,
+    ///     mean_distance_to_other: 1.0,
+    ///     rms_distance_to_other: 1.2,
+    ///     max_distance_from_other: 0.5,
+    ///     mean_distance_from_other: 0.1,
+    ///     rms_distance_from_other: 0.2,
+    /// };
+    ///
+    /// assert_eq!(distance.get_hausdorff_distance(), 2.5);
+    /// ```
+    pub fn get_hausdorff_distance(&self) -> f64 {
+        self.max_distance_to_other.max(self.max_distance_from_other)
+    }
+
+    /// Gets the symmetric root mean square distance, which is the quadratic mean of both
+    /// one-sided ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::surface_distance::SurfaceDistance;
+    ///
+    /// let distance = SurfaceDistance {
+    ///     max_distance_to_other: 2.0,
+    ///     mean_distance_to_other: 1.0,
+    ///     rms_distance_to_other: 1.0,
+    ///     max_distance_from_other: 2.0,
+    ///     mean_distance_from_other: 1.0,
+    ///     rms_distance_from_other: 7.0,
+    /// };
+    ///
+    /// assert_eq!(distance.get_rms_distance(), 5.0);
+    /// ```
+    pub fn get_rms_distance(&self) -> f64 {
+        ((self.rms_distance_to_other * self.rms_distance_to_other + self.rms_distance_from_other * self.rms_distance_from_other) / 2.0).sqrt()
+    }
+}
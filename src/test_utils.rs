@@ -0,0 +1,33 @@
+//! Shared fixtures for unit tests.
+
+use crate::mesh::Mesh;
+
+/// Creates a closed box [Mesh] with outward facing normals, spanning from `min` to `max`.
+///
+/// Its 8 vertices go counterclockwise around the bottom, then around the top, and its
+/// 12 faces are: bottom, top, front, right, back and left (2 per side).
+fn get_box_between(min: [f64; 3], max: [f64; 3]) -> Mesh {
+    let [x0, y0, z0] = min;
+    let [x1, y1, z1] = max;
+    Mesh::new(
+        vec![x0, y0, z0, x1, y0, z0, x1, y1, z0, x0, y1, z0,
+             x0, y0, z1, x1, y0, z1, x1, y1, z1, x0, y1, z1],
+        vec![0, 2, 1, 0, 3, 2, // bottom
+             4, 5, 6, 4, 6, 7, // top
+             0, 1, 5, 0, 5, 4, // front
+             1, 2, 6, 1, 6, 5, // right
+             2, 3, 7, 2, 7, 6, // back
+             3, 0, 4, 3, 4, 7, // left
+             ]
+    )
+}
+
+/// Creates a closed box [Mesh] with the given sizes, with its minimum at the origin.
+pub(crate) fn get_box(length: f64, width: f64, height: f64) -> Mesh {
+    get_box_between([0.0, 0.0, 0.0], [length, width, height])
+}
+
+/// Creates a closed cube [Mesh] spanning from `min` to `max` in all the directions.
+pub(crate) fn get_cube(min: f64, max: f64) -> Mesh {
+    get_box_between([min; 3], [max; 3])
+}
@@ -0,0 +1,210 @@
+//! Standard synthetic meshes for benchmarks and load testing.
+//!
+//! All the generators are deterministic: the same arguments (including the `seed`) always give
+//! the same [Mesh], so the results can be compared between runs and machines.
+//!
+//! Available only with the `testmeshes` feature.
+
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::random::Random;
+
+/// Creates a welded, closed icosphere with the radius 1.0 and noisy vertices.
+///
+/// Each subdivision multiplies the number of faces by 4, starting from 20 faces of the
+/// icosahedron. Each vertex is moved along its normal by a random value in the range
+/// [-`noise`, `noise`).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::testmeshes::noisy_sphere;
+///
+/// let actual = noisy_sphere(3, 0.01, 7);
+///
+/// assert_eq!(actual.get_number_of_faces(), 20 * 4 * 4 * 4);
+/// assert!(actual.get_edges_with_missing_neighbour().is_empty());
+/// assert_eq!(actual, noisy_sphere(3, 0.01, 7));
+/// ```
+pub fn noisy_sphere(subdivisions: usize, noise: f64, seed: u64) -> Mesh {
+    let t = (1.0 + 5.0_f64.sqrt()) / 2.0;
+    let mut vertices: Vec<[f64; 3]> = vec![
+        [-1.0, t, 0.0], [1.0, t, 0.0], [-1.0, -t, 0.0], [1.0, -t, 0.0],
+        [0.0, -1.0, t], [0.0, 1.0, t], [0.0, -1.0, -t], [0.0, 1.0, -t],
+        [t, 0.0, -1.0], [t, 0.0, 1.0], [-t, 0.0, -1.0], [-t, 0.0, 1.0],
+    ];
+    let mut faces: Vec<[usize; 3]> = vec![
+        [0, 11, 5], [0, 5, 1], [0, 1, 7], [0, 7, 10], [0, 10, 11],
+        [1, 5, 9], [5, 11, 4], [11, 10, 2], [10, 7, 6], [7, 1, 8],
+        [3, 9, 4], [3, 4, 2], [3, 2, 6], [3, 6, 8], [3, 8, 9],
+        [4, 9, 5], [2, 4, 11], [6, 2, 10], [8, 6, 7], [9, 8, 1],
+    ];
+
+    for _ in 0..subdivisions {
+        let mut midpoints: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut get_midpoint = |a: usize, b: usize, vertices: &mut Vec<[f64; 3]>| {
+            *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                let (first, second) = (vertices[a], vertices[b]);
+                vertices.push([(first[0] + second[0]) / 2.0, (first[1] + second[1]) / 2.0, (first[2] + second[2]) / 2.0]);
+                vertices.len() - 1
+            })
+        };
+        let mut subdivided = Vec::with_capacity(faces.len() * 4);
+        for [a, b, c] in faces {
+            let ab = get_midpoint(a, b, &mut vertices);
+            let bc = get_midpoint(b, c, &mut vertices);
+            let ca = get_midpoint(c, a, &mut vertices);
+            subdivided.extend_from_slice(&[[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]);
+        }
+        faces = subdivided;
+    }
+
+    let mut random = Random::new(seed);
+    let mut coordinates = Vec::with_capacity(vertices.len() * 3);
+    for [x, y, z] in vertices {
+        let length = (x * x + y * y + z * z).sqrt();
+        let radius = 1.0 + noise * random.next();
+        coordinates.extend_from_slice(&[x / length * radius, y / length * radius, z / length * radius]);
+    }
+
+    Mesh::new(coordinates, faces.into_iter().flatten().collect())
+}
+
+/// Creates a tessellated slab, like the ones exported from BIM software.
+///
+/// It's a box of the given dimensions, with the bottom at Z = 0.0. Each of its 6 sides is a
+/// separate grid with `divisions` cells along each direction and its own vertices, so the
+/// [Mesh] is not welded along the slab edges, exactly as in many exported models.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::testmeshes::bim_slab;
+///
+/// let actual = bim_slab(10.0, 6.0, 0.3, 4);
+///
+/// assert_eq!(actual.get_number_of_faces(), 6 * 4 * 4 * 2);
+/// assert!((actual.get_area() - 2.0 * (60.0 + 3.0 + 1.8)).abs() < 1e-9);
+/// assert_eq!(actual.get_with_welded_vertices(0.001).get_edges_with_missing_neighbour().len(), 0);
+/// ```
+pub fn bim_slab(length: f64, width: f64, thickness: f64, divisions: usize) -> Mesh {
+    // Origin and 2 side vectors of each side, oriented so the normals point outside.
+    let sides: [([f64; 3], [f64; 3], [f64; 3]); 6] = [
+        ([0.0, 0.0, 0.0], [0.0, width, 0.0], [length, 0.0, 0.0]),
+        ([0.0, 0.0, thickness], [length, 0.0, 0.0], [0.0, width, 0.0]),
+        ([0.0, 0.0, 0.0], [length, 0.0, 0.0], [0.0, 0.0, thickness]),
+        ([length, width, 0.0], [-length, 0.0, 0.0], [0.0, 0.0, thickness]),
+        ([0.0, width, 0.0], [0.0, -width, 0.0], [0.0, 0.0, thickness]),
+        ([length, 0.0, 0.0], [0.0, width, 0.0], [0.0, 0.0, thickness]),
+    ];
+
+    let mut coordinates: Vec<f64> = Vec::new();
+    let mut indices: Vec<usize> = Vec::new();
+    for (origin, u, v) in sides {
+        let first_vertex_id = coordinates.len() / 3;
+        for j in 0..=divisions {
+            for i in 0..=divisions {
+                let (s, t) = (i as f64 / divisions as f64, j as f64 / divisions as f64);
+                coordinates.extend_from_slice(&[
+                    origin[0] + u[0] * s + v[0] * t,
+                    origin[1] + u[1] * s + v[1] * t,
+                    origin[2] + u[2] * s + v[2] * t,
+                ]);
+            }
+        }
+        for j in 0..divisions {
+            for i in 0..divisions {
+                let a = first_vertex_id + j * (divisions + 1) + i;
+                indices.extend_from_slice(&[a, a + 1, a + divisions + 2, a, a + divisions + 2, a + divisions + 1]);
+            }
+        }
+    }
+
+    Mesh::new(coordinates, indices)
+}
+
+/// Creates a terrain [Mesh] resembling a scan: a grid of `rows` x `cols` vertices with the cell
+/// size 1.0, made of a few octaves of smooth noise plus small random jitter.
+///
+/// Heights are roughly in the range [-`amplitude`, `amplitude`].
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::testmeshes::scan_like_terrain;
+///
+/// let actual = scan_like_terrain(50, 40, 5.0, 1);
+///
+/// assert_eq!(actual.get_number_of_vertices(), 50 * 40);
+/// assert_eq!(actual.get_number_of_faces(), 49 * 39 * 2);
+/// let bounding_box = actual.get_bounding_box();
+/// assert!(bounding_box.max_z <= 5.0 && bounding_box.min_z >= -5.0);
+/// ```
+pub fn scan_like_terrain(rows: usize, cols: usize, amplitude: f64, seed: u64) -> Mesh {
+    let mut random = Random::new(seed);
+    let octaves: Vec<(f64, f64, f64, f64)> = (0..4).map(|octave| {
+        let frequency = 0.05 * 2.0_f64.powi(octave);
+        (frequency, 0.5_f64.powi(octave), random.next() * 10.0, random.next() * 10.0)
+    }).collect();
+    let weights_sum: f64 = octaves.iter().map(|octave| octave.1).sum::<f64>() + 0.02;
+
+    let mut values = Vec::with_capacity(rows * cols);
+    for row in 0..rows {
+        for col in 0..cols {
+            let (x, y) = (col as f64, row as f64);
+            let mut height = 0.0;
+            for (frequency, weight, phase_x, phase_y) in &octaves {
+                height += weight * (x * frequency + phase_x).sin() * (y * frequency * 1.3 + phase_y).cos();
+            }
+            height += 0.02 * random.next();
+            values.push(height / weights_sum * amplitude);
+        }
+    }
+
+    Mesh::from_heightmap(&values, rows, cols, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_range_and_determinism() {
+        let mut a = Random::new(3);
+        let mut b = Random::new(3);
+        for _ in 0..1000 {
+            let value = a.next();
+            assert!((-1.0..1.0).contains(&value));
+            assert_eq!(value, b.next());
+        }
+    }
+
+    #[test]
+    fn test_noisy_sphere_without_noise_on_unit_sphere() {
+        let actual = noisy_sphere(2, 0.0, 0);
+
+        assert_eq!(actual.get_number_of_vertices(), 162);
+        for point in actual.to_points() {
+            assert!((point.to_vector().get_length() - 1.0).abs() < 1e-12);
+        }
+        for (normal, triangle) in actual.get_face_normal_vectors_unitized().iter().zip(actual.to_triangles()) {
+            assert!(normal.get_dot_product(&triangle.get_centroid().to_vector()) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_noisy_sphere_different_seeds() {
+        assert_ne!(noisy_sphere(1, 0.1, 1), noisy_sphere(1, 0.1, 2));
+    }
+
+    #[test]
+    fn test_bim_slab_normals_point_outside() {
+        let actual = bim_slab(4.0, 2.0, 1.0, 2);
+
+        for (normal, triangle) in actual.get_face_normal_vectors_unitized().iter().zip(actual.to_triangles()) {
+            let centroid = triangle.get_centroid();
+            let outward = crate::vector::Vector::new(centroid.x - 2.0, centroid.y - 1.0, centroid.z - 0.5);
+            assert!(normal.get_dot_product(&outward) > 0.0);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use crate::local_coordinate_system::LocalCoordinateSystem;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+
+/// Represents a general affine transformation in three-dimensional space as a 4x4 matrix.
+///
+/// The matrix is stored row by row and is applied to column vectors, so a point is transformed
+/// as `matrix * [x, y, z, 1.0]`, and the last row is `[0.0, 0.0, 0.0, 1.0]`.
+///
+/// Transforms are combined with `*`: `a * b` is the transform applying `b` first, then `a`.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::point::Point;
+/// use meshmeshmesh::transform::Transform;
+/// use meshmeshmesh::vector::Vector;
+///
+/// let scaling = Transform::new_scaling(2.0, 1.0, 3.0);
+/// let translation = Transform::new_translation(&Vector::new(1.0, 0.0, 0.0));
+///
+/// let result = translation * scaling;
+///
+/// assert_eq!(Point::new(1.0, 1.0, 1.0).get_transformed(&result), Point::new(3.0, 1.0, 3.0));
+/// ```
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct Transform {
+    /// The rows of the 4x4 matrix.
+    pub matrix: [[f64; 4]; 4],
+}
+
+impl PartialEq for Transform {
+    fn eq(&self, other: &Self) -> bool {
+        self.matrix == other.matrix
+    }
+}
+
+impl Transform {
+    /// Returns a new [Transform] from the rows of the 4x4 matrix.
+    pub fn new(matrix: [[f64; 4]; 4]) -> Transform { Transform { matrix } }
+
+    /// Returns a new identity [Transform], which doesn't change anything.
+    pub fn identity() -> Transform {
+        Transform::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a new [Transform] moving everything by the given [Vector].
+    pub fn new_translation(vector: &Vector) -> Transform {
+        Transform::new([
+            [1.0, 0.0, 0.0, vector.x],
+            [0.0, 1.0, 0.0, vector.y],
+            [0.0, 0.0, 1.0, vector.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a new [Transform] rotating around the global origin by the given unit
+    /// [Quaternion], the same way as `get_rotated_by_quaternion` methods do.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::quaternion::Quaternion;
+    /// use meshmeshmesh::transform::Transform;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let quaternion = Quaternion::new_from_axis_angle(&Vector::new(0.0, 0.0, 1.0), std::f64::consts::FRAC_PI_2);
+    ///
+    /// let result = Transform::new_rotation(quaternion);
+    ///
+    /// let actual = Point::new(1.0, 0.0, 0.0).get_transformed(&result);
+    /// assert!(actual.eq_with_tolerance(&Point::new(0.0, 1.0, 0.0), 1e-12));
+    /// ```
+    pub fn new_rotation(quaternion: Quaternion) -> Transform {
+        let Quaternion { qx: x, qy: y, qz: z, qw: w } = quaternion;
+
+        Transform::new([
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w), 2.0 * (x * z + y * w), 0.0],
+            [2.0 * (x * y + z * w), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w), 0.0],
+            [2.0 * (x * z - y * w), 2.0 * (y * z + x * w), 1.0 - 2.0 * (x * x + y * y), 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a new [Transform] rotating around the axis going through the global origin by
+    /// the angle (in radians), anticlockwise when looking against the axis.
+    pub fn new_rotation_from_axis_angle(axis: &Vector, angle: f64) -> Transform {
+        Transform::new_rotation(Quaternion::new_from_axis_angle(axis, angle))
+    }
+
+    /// Returns a new [Transform] scaling around the global origin with separate factors for
+    /// each axis.
+    ///
+    /// Negative factors mirror, and the same factor for all axes gives uniform scaling.
+    pub fn new_scaling(x: f64, y: f64, z: f64) -> Transform {
+        Transform::new([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a new [Transform] shearing along the axes: each factor says how much a
+    /// coordinate is moved per unit of another one, e.g. `xy` moves x by `xy * y`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::transform::Transform;
+    ///
+    /// let result = Transform::new_shear(0.5, 0.0, 0.0, 0.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(Point::new(0.0, 2.0, 0.0).get_transformed(&result), Point::new(1.0, 2.0, 0.0));
+    /// ```
+    pub fn new_shear(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Transform {
+        Transform::new([
+            [1.0, xy, xz, 0.0],
+            [yx, 1.0, yz, 0.0],
+            [zx, zy, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a new [Transform] doing the same as `get_in_local_coordinate_system` methods:
+    /// coordinates are treated as local ones of the [LocalCoordinateSystem] and moved
+    /// into the global space.
+    pub fn new_in_local_coordinate_system(local_coordinate_system: &LocalCoordinateSystem) -> Transform {
+        let LocalCoordinateSystem { origin, x, y } = *local_coordinate_system;
+        let z = local_coordinate_system.get_z();
+
+        Transform::new([
+            [x.x, y.x, z.x, origin.x],
+            [x.y, y.y, z.y, origin.y],
+            [x.z, y.z, z.z, origin.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a new [Transform] doing the same as `get_in_global_coordinate_system` methods:
+    /// the inverse of [Transform::new_in_local_coordinate_system] for unitized, perpendicular
+    /// axes of the [LocalCoordinateSystem].
+    pub fn new_in_global_coordinate_system(local_coordinate_system: &LocalCoordinateSystem) -> Transform {
+        let LocalCoordinateSystem { origin, x, y } = *local_coordinate_system;
+        let z = local_coordinate_system.get_z();
+        let origin = origin.to_vector();
+
+        Transform::new([
+            [x.x, x.y, x.z, -x.get_dot_product(&origin)],
+            [y.x, y.y, y.z, -y.get_dot_product(&origin)],
+            [z.x, z.y, z.z, -z.get_dot_product(&origin)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point;
+    use super::*;
+
+    #[test]
+    fn test_identity() {
+        let point = Point::new(1.5, -2.0, 3.0);
+
+        assert_eq!(point.get_transformed(&Transform::identity()), point);
+    }
+
+    #[test]
+    fn test_new_rotation_same_as_quaternion() {
+        let quaternion = Quaternion::new_from_axis_angle(&Vector::new(0.3, -1.2, 0.7), 2.1);
+        let point = Point::new(-3.8, 5.4, 1.0);
+
+        let actual = point.get_transformed(&Transform::new_rotation(quaternion));
+        let expected = point.get_rotated_by_quaternion(quaternion);
+
+        assert!(expected.eq_with_tolerance(&actual, 1e-12));
+    }
+
+    #[test]
+    fn test_new_in_local_coordinate_system_same_as_point() {
+        let local_coordinate_system = LocalCoordinateSystem::new(
+            Point::new(-43.836955, -22.211852, 10.0),
+            Vector::new(0.721276,0.692648,0.0),
+            Vector::new(-0.290878,0.3029,0.907547)
+        );
+        let point = Point::new(44.545922, 27.392431, 12.289269);
+
+        let local = point.get_transformed(&Transform::new_in_local_coordinate_system(&local_coordinate_system));
+        let global = point.get_transformed(&Transform::new_in_global_coordinate_system(&local_coordinate_system));
+
+        assert!(local.eq_with_tolerance(&point.get_in_local_coordinate_system(&local_coordinate_system), 1e-9));
+        assert!(global.eq_with_tolerance(&point.get_in_global_coordinate_system(&local_coordinate_system), 1e-9));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let input = Transform::new_translation(&Vector::new(1.0, 2.0, 3.0));
+        let input_serialized = serde_json::to_string(&input);
+        assert!(input_serialized.is_ok());
+        let input_serialized_string = input_serialized.ok().unwrap();
+        assert_eq!(input_serialized_string, "{\"matrix\":[[1.0,0.0,0.0,1.0],[0.0,1.0,0.0,2.0],[0.0,0.0,1.0,3.0],[0.0,0.0,0.0,1.0]]}");
+    }
+}
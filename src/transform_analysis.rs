@@ -0,0 +1,141 @@
+use crate::transform::Transform;
+
+impl Transform {
+    /// Compares given [Transform] to other one, but with a `f64` tolerance.
+    ///
+    /// If any value absolute difference is > tolerance, then it should return `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::transform::Transform;
+    ///
+    /// let a = Transform::new_scaling(1.0, 2.0, 3.0);
+    /// let b = Transform::new_scaling(1.0, 2.0001, 3.0);
+    ///
+    /// assert!(a.eq_with_tolerance(&b, 0.001));
+    /// assert!(!a.eq_with_tolerance(&b, 0.00001));
+    /// ```
+    pub fn eq_with_tolerance(&self, other: &Transform, tolerance: f64) -> bool {
+        self.matrix.iter().flatten().zip(other.matrix.iter().flatten()).all(|(a, b)| (a - b).abs() <= tolerance)
+    }
+
+    /// Gets the determinant of the linear (3x3) part of the [Transform].
+    ///
+    /// It says how volumes are scaled, and it's negative for transforms mirroring the space,
+    /// which turn faces inside out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::transform::Transform;
+    ///
+    /// assert_eq!(Transform::new_scaling(2.0, 3.0, 4.0).get_determinant(), 24.0);
+    /// assert_eq!(Transform::new_scaling(-1.0, 1.0, 1.0).get_determinant(), -1.0);
+    /// ```
+    pub fn get_determinant(&self) -> f64 {
+        let m = &self.matrix;
+
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// Gets the inverse [Transform], undoing this one.
+    ///
+    /// Returns `None` if the [Transform] flattens the space (its determinant is 0.0).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::transform::Transform;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Transform::new_translation(&Vector::new(1.0, 2.0, 3.0)) * Transform::new_scaling(2.0, 4.0, 0.5);
+    ///
+    /// let actual = input.get_inverse().unwrap();
+    ///
+    /// assert!((actual * input).eq_with_tolerance(&Transform::identity(), 1e-12));
+    /// assert_eq!(Transform::new_scaling(1.0, 0.0, 1.0).get_inverse(), None);
+    /// ```
+    pub fn get_inverse(&self) -> Option<Transform> {
+        let determinant = self.get_determinant();
+        if determinant == 0.0 {
+            return None;
+        }
+        let linear = self.get_linear_inverse_transposed(determinant);
+        let m = &self.matrix;
+        let mut inverse = Transform::identity();
+        for (row, inverse_row) in inverse.matrix.iter_mut().take(3).enumerate() {
+            for (column, linear_row) in linear.iter().enumerate() {
+                inverse_row[column] = linear_row[row];
+            }
+            inverse_row[3] = -linear.iter().zip(m.iter()).map(|(linear_row, m_row)| linear_row[row] * m_row[3]).sum::<f64>();
+        }
+
+        Some(inverse)
+    }
+
+    /// Gets the matrix for transforming normals: the inverse transpose of the linear (3x3)
+    /// part, which keeps them perpendicular to surfaces also for non-uniform scaling and shear.
+    ///
+    /// Returns `None` if the [Transform] flattens the space (its determinant is 0.0).
+    pub(crate) fn get_normal_matrix(&self) -> Option<[[f64; 3]; 3]> {
+        let determinant = self.get_determinant();
+        if determinant == 0.0 {
+            return None;
+        }
+
+        Some(self.get_linear_inverse_transposed(determinant))
+    }
+
+    /// Gets cofactors of the linear (3x3) part divided by the determinant.
+    fn get_linear_inverse_transposed(&self, determinant: f64) -> [[f64; 3]; 3] {
+        let m = &self.matrix;
+        let mut result = [[0.0; 3]; 3];
+        for (row, result_row) in result.iter_mut().enumerate() {
+            let (r1, r2) = ((row + 1) % 3, (row + 2) % 3);
+            for (column, value) in result_row.iter_mut().enumerate() {
+                let (c1, c2) = ((column + 1) % 3, (column + 2) % 3);
+                *value = (m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]) / determinant;
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+    use super::*;
+
+    #[test]
+    fn test_get_determinant_of_rotation() {
+        let input = Transform::new_rotation(Quaternion::new_from_axis_angle(&Vector::new(1.0, 2.0, -0.5), 0.7));
+
+        assert!((input.get_determinant() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_get_inverse_of_shear_and_rotation() {
+        let input = Transform::new_translation(&Vector::new(-4.0, 0.5, 2.0))
+            * Transform::new_rotation_from_axis_angle(&Vector::new(0.0, 1.0, 1.0), 1.2)
+            * Transform::new_shear(0.5, -0.2, 0.0, 1.5, 0.3, 0.0);
+
+        let actual = input.get_inverse().unwrap();
+
+        assert!((input * actual).eq_with_tolerance(&Transform::identity(), 1e-12));
+        assert!((actual * input).eq_with_tolerance(&Transform::identity(), 1e-12));
+    }
+
+    #[test]
+    fn test_get_normal_matrix_of_non_uniform_scaling() {
+        let input = Transform::new_scaling(2.0, 4.0, -1.0);
+
+        let actual = input.get_normal_matrix().unwrap();
+
+        assert_eq!(actual, [[0.5, 0.0, 0.0], [0.0, 0.25, 0.0], [0.0, 0.0, -1.0]]);
+    }
+}
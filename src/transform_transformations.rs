@@ -0,0 +1,56 @@
+use std::ops;
+use crate::transform::Transform;
+
+impl ops::Mul<Transform> for Transform {
+    type Output = Transform;
+
+    /// Combines a [Transform] with another one.
+    ///
+    /// The result is a new Transform applying `other` first, and then `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::transform::Transform;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let a = Transform::new_translation(&Vector::new(0.0, 0.0, 5.0));
+    /// let b = Transform::new_scaling(2.0, 2.0, 2.0);
+    ///
+    /// assert_eq!(Point::new(1.0, 1.0, 1.0).get_transformed(&(a * b)), Point::new(2.0, 2.0, 7.0));
+    /// assert_eq!(Point::new(1.0, 1.0, 1.0).get_transformed(&(b * a)), Point::new(2.0, 2.0, 12.0));
+    /// ```
+    fn mul(self, other: Transform) -> Transform {
+        let mut matrix = [[0.0; 4]; 4];
+        for (row, matrix_row) in matrix.iter_mut().enumerate() {
+            for (column, value) in matrix_row.iter_mut().enumerate() {
+                *value = (0..4).map(|k| self.matrix[row][k] * other.matrix[k][column]).sum();
+            }
+        }
+
+        Transform::new(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vector::Vector;
+    use super::*;
+
+    #[test]
+    fn test_mul_translations() {
+        let a = Transform::new_translation(&Vector::new(1.0, 2.0, 3.0));
+        let b = Transform::new_translation(&Vector::new(-4.0, 0.5, 1.0));
+
+        assert_eq!(a * b, Transform::new_translation(&Vector::new(-3.0, 2.5, 4.0)));
+    }
+
+    #[test]
+    fn test_mul_identity() {
+        let a = Transform::new_shear(0.5, -0.2, 0.0, 1.5, 0.3, 0.0);
+
+        assert_eq!(a * Transform::identity(), a);
+        assert_eq!(Transform::identity() * a, a);
+    }
+}
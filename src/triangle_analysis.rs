@@ -582,6 +582,560 @@ impl Triangle {
 
         angles
     }
+
+    /// Gets the closest [Point] on the [Triangle] (including its edges and vertices) to given
+    /// [Point].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let triangle = Triangle::new(
+    ///     Point::new(0.0, 0.0, 0.0),
+    ///     Point::new(10.0, 0.0, 0.0),
+    ///     Point::new(0.0, 10.0, 0.0));
+    ///
+    /// assert_eq!(triangle.get_closest_point(&Point::new(2.0, 3.0, 5.0)), Point::new(2.0, 3.0, 0.0));
+    /// assert_eq!(triangle.get_closest_point(&Point::new(-2.0, -3.0, 5.0)), Point::new(0.0, 0.0, 0.0));
+    /// assert_eq!(triangle.get_closest_point(&Point::new(5.0, -3.0, 1.0)), Point::new(5.0, 0.0, 0.0));
+    /// ```
+    pub fn get_closest_point(&self, point: &Point) -> Point {
+        let a = self.first_point;
+        let b = self.second_point;
+        let c = self.third_point;
+        let ab = Vector::from_2_points(&a, &b);
+        let ac = Vector::from_2_points(&a, &c);
+
+        let ap = Vector::from_2_points(&a, point);
+        let d1 = ab.get_dot_product(&ap);
+        let d2 = ac.get_dot_product(&ap);
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+
+        let bp = Vector::from_2_points(&b, point);
+        let d3 = ab.get_dot_product(&bp);
+        let d4 = ac.get_dot_product(&bp);
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = Vector::from_2_points(&c, point);
+        let d5 = ab.get_dot_product(&cp);
+        let d6 = ac.get_dot_product(&cp);
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+            return b + Vector::from_2_points(&b, &c) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = 1.0 / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+
+    /// Checks if the [Triangle] intersects the other one.
+    ///
+    /// Touching only at vertices or along shared edges is not treated as an intersection, so
+    /// neighbouring faces of a [Mesh](crate::mesh::Mesh) don't intersect, unless they fold over
+    /// each other. Coplanar overlapping [Triangle]s do intersect.
+    ///
+    /// `epsilon` is the distance below which things are treated as touching.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+    /// let crossing = Triangle::new(Point::new(2.0, 2.0, -5.0), Point::new(2.0, 2.0, 5.0), Point::new(20.0, 20.0, 0.0));
+    /// let neighbour = Triangle::new(Point::new(10.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0), Point::new(5.0, -5.0, 3.0));
+    ///
+    /// assert!(a.intersects_triangle(&crossing, 0.0001));
+    /// assert!(!a.intersects_triangle(&neighbour, 0.0001));
+    /// ```
+    pub fn intersects_triangle(&self, other: &Triangle, epsilon: f64) -> bool {
+        let self_normal = Self::get_raw_normal(self);
+        let other_normal = Self::get_raw_normal(other);
+        let self_normal_length = self_normal.get_length();
+        let other_normal_length = other_normal.get_length();
+        if self_normal_length == 0.0 || other_normal_length == 0.0 {
+            return false; // Degenerate Triangles are skipped
+        }
+
+        let distances: Vec<f64> = [other.first_point, other.second_point, other.third_point].iter()
+            .map(|p| self_normal.get_dot_product(&Vector::from_2_points(&self.first_point, p)) / self_normal_length)
+            .collect();
+        if distances.iter().all(|d| d.abs() <= epsilon) {
+            return Self::intersects_coplanar_triangle(self, other, &self_normal, epsilon);
+        }
+
+        Self::any_side_crosses_triangle(self, other, epsilon) || Self::any_side_crosses_triangle(other, self, epsilon)
+    }
+
+    fn get_raw_normal(triangle: &Triangle) -> Vector {
+        triangle.get_first_side_as_vector().get_cross_product(&triangle.get_third_side_as_vector().get_reversed())
+    }
+
+    /// Checks if any side of the first [Triangle] crosses the second one, excluding side ends.
+    fn any_side_crosses_triangle(sides_of: &Triangle, triangle: &Triangle, epsilon: f64) -> bool {
+        let points = [sides_of.first_point, sides_of.second_point, sides_of.third_point];
+        let normal = Self::get_raw_normal(triangle);
+        let normal_length = normal.get_length();
+        for i in 0..3 {
+            let start = points[i];
+            let end = points[(i + 1) % 3];
+            let start_distance = normal.get_dot_product(&Vector::from_2_points(&triangle.first_point, &start)) / normal_length;
+            let end_distance = normal.get_dot_product(&Vector::from_2_points(&triangle.first_point, &end)) / normal_length;
+            if (start_distance > -epsilon && end_distance > -epsilon) || (start_distance < epsilon && end_distance < epsilon) {
+                continue; // Not crossing the plane strictly
+            }
+            let t = start_distance / (start_distance - end_distance);
+            let crossing = start + Vector::from_2_points(&start, &end) * t;
+            if crossing.get_distance_to_point(&triangle.get_closest_point(&crossing)) <= epsilon {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn intersects_coplanar_triangle(first: &Triangle, second: &Triangle, normal: &Vector, epsilon: f64) -> bool {
+        let drop_axis = if normal.x.abs() >= normal.y.abs() && normal.x.abs() >= normal.z.abs() { 0 } else if normal.y.abs() >= normal.z.abs() { 1 } else { 2 };
+        let project = |p: &Point| -> (f64, f64) {
+            match drop_axis {
+                0 => (p.y, p.z),
+                1 => (p.z, p.x),
+                _ => (p.x, p.y),
+            }
+        };
+        let first_2d = [project(&first.first_point), project(&first.second_point), project(&first.third_point)];
+        let second_2d = [project(&second.first_point), project(&second.second_point), project(&second.third_point)];
+        let orientation = |a: (f64, f64), b: (f64, f64), c: (f64, f64)| -> f64 {
+            let length = ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt();
+            if length == 0.0 { return 0.0; }
+            ((b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)) / length // Signed distance of c from line ab
+        };
+
+        for i in 0..3 { // Proper crossings of sides
+            let (a, b) = (first_2d[i], first_2d[(i + 1) % 3]);
+            for j in 0..3 {
+                let (c, d) = (second_2d[j], second_2d[(j + 1) % 3]);
+                let o1 = orientation(a, b, c);
+                let o2 = orientation(a, b, d);
+                let o3 = orientation(c, d, a);
+                let o4 = orientation(c, d, b);
+                if ((o1 > epsilon && o2 < -epsilon) || (o1 < -epsilon && o2 > epsilon)) &&
+                    ((o3 > epsilon && o4 < -epsilon) || (o3 < -epsilon && o4 > epsilon)) {
+                    return true;
+                }
+            }
+        }
+
+        let is_strictly_inside = |p: (f64, f64), triangle: &[(f64, f64); 3]| -> bool {
+            let o1 = orientation(triangle[0], triangle[1], p);
+            let o2 = orientation(triangle[1], triangle[2], p);
+            let o3 = orientation(triangle[2], triangle[0], p);
+            (o1 > epsilon && o2 > epsilon && o3 > epsilon) || (o1 < -epsilon && o2 < -epsilon && o3 < -epsilon)
+        };
+        first_2d.iter().any(|p| is_strictly_inside(*p, &second_2d)) || second_2d.iter().any(|p| is_strictly_inside(*p, &first_2d))
+            || Self::have_same_vertices(first, second, epsilon)
+    }
+
+    fn have_same_vertices(first: &Triangle, second: &Triangle, epsilon: f64) -> bool {
+        let second_points = [second.first_point, second.second_point, second.third_point];
+        [first.first_point, first.second_point, first.third_point].iter()
+            .all(|p| second_points.iter().any(|q| p.get_distance_to_point(q) <= epsilon))
+    }
+
+    /// Gets the [Point] where the segment from `start` to `end` crosses the [Triangle]
+    /// (including its edges and vertices).
+    ///
+    /// `epsilon` is the distance below which things are treated as touching, so segment ends
+    /// closer to the Triangle's plane are treated as lying on it. Segments lying in the
+    /// Triangle's plane have no single crossing [Point], so `None` is returned for them, same as
+    /// for degenerate Triangles (see [Triangle::is_degenerate]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let triangle = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+    ///
+    /// assert_eq!(triangle.intersect_segment(&Point::new(2.0, 3.0, -1.0), &Point::new(2.0, 3.0, 4.0), 0.0001), Some(Point::new(2.0, 3.0, 0.0)));
+    /// assert_eq!(triangle.intersect_segment(&Point::new(2.0, 3.0, 1.0), &Point::new(2.0, 3.0, 4.0), 0.0001), None);
+    /// assert_eq!(triangle.intersect_segment(&Point::new(8.0, 8.0, -1.0), &Point::new(8.0, 8.0, 1.0), 0.0001), None);
+    /// ```
+    pub fn intersect_segment(&self, start: &Point, end: &Point, epsilon: f64) -> Option<Point> {
+        if self.is_degenerate() {
+            return None;
+        }
+        let normal = Self::get_raw_normal(self);
+        let normal_length = normal.get_length();
+
+        let start_distance = normal.get_dot_product(&Vector::from_2_points(&self.first_point, start)) / normal_length;
+        let end_distance = normal.get_dot_product(&Vector::from_2_points(&self.first_point, end)) / normal_length;
+        let is_start_on_plane = start_distance.abs() <= epsilon;
+        let is_end_on_plane = end_distance.abs() <= epsilon;
+        let crossing = match (is_start_on_plane, is_end_on_plane) {
+            (true, true) => return None, // Lying in the plane
+            (true, false) => *start,
+            (false, true) => *end,
+            (false, false) => {
+                if (start_distance > 0.0) == (end_distance > 0.0) {
+                    return None;
+                }
+                *start + Vector::from_2_points(start, end) * (start_distance / (start_distance - end_distance))
+            },
+        };
+
+        if self.contains_point_with_epsilon(&crossing, epsilon) {
+            Some(crossing)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Gets the segment (as its end [Point]s, in no particular order) along which the
+    /// [Triangle] intersects the other one.
+    ///
+    /// Unlike [Triangle::intersects_triangle], touching within `epsilon` counts, so Triangles
+    /// touching with a vertex give a zero length segment, and neighbours sharing an edge give
+    /// that edge. `None` is returned if Triangles don't meet, and also for coplanar Triangles
+    /// (see [Triangle::is_coplanar_to_triangle_with_epsilon]) and degenerate ones, where there is
+    /// no single segment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0), Point::new(0.0, 4.0, 0.0));
+    /// let b = Triangle::new(Point::new(1.0, -2.0, -1.0), Point::new(1.0, 6.0, -1.0), Point::new(1.0, 2.0, 1.0));
+    ///
+    /// let (start, end) = a.get_intersection_with_triangle(&b, 0.0001).unwrap();
+    ///
+    /// assert!(start.eq_with_tolerance(&Point::new(1.0, 0.0, 0.0), 1e-12) || end.eq_with_tolerance(&Point::new(1.0, 0.0, 0.0), 1e-12));
+    /// assert!(start.eq_with_tolerance(&Point::new(1.0, 3.0, 0.0), 1e-12) || end.eq_with_tolerance(&Point::new(1.0, 3.0, 0.0), 1e-12));
+    /// ```
+    pub fn get_intersection_with_triangle(&self, other: &Triangle, epsilon: f64) -> Option<(Point, Point)> {
+        if self.is_degenerate() || other.is_degenerate() || self.is_coplanar_to_triangle_with_epsilon(other, epsilon) {
+            return None;
+        }
+
+        // Ends of the segment are where sides of one Triangle cross the other one
+        let mut points = Vec::new();
+        for (triangle, sides_of) in [(self, other), (other, self)] {
+            let corners = [sides_of.first_point, sides_of.second_point, sides_of.third_point];
+            for (i, start) in corners.iter().enumerate() {
+                if let Some(point) = triangle.intersect_segment(start, &corners[(i + 1) % 3], epsilon) {
+                    points.push(point);
+                }
+            }
+        }
+
+        let mut furthest_pair = None;
+        let mut longest_distance = f64::NEG_INFINITY;
+        for (i, first) in points.iter().enumerate() {
+            for second in &points[i..] {
+                let distance = first.get_distance_to_point(second);
+                if distance > longest_distance {
+                    longest_distance = distance;
+                    furthest_pair = Some((*first, *second));
+                }
+            }
+        }
+
+        furthest_pair
+    }
+
+    /// Checks if the [Point] lies on the [Triangle] (including its edges and vertices), so its
+    /// distance to the Triangle is not bigger than `epsilon`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let triangle = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+    ///
+    /// assert!(triangle.contains_point_with_epsilon(&Point::new(2.0, 3.0, 0.00001), 0.0001));
+    /// assert!(triangle.contains_point_with_epsilon(&Point::new(5.0, 5.0, 0.0), 0.0001));
+    /// assert!(!triangle.contains_point_with_epsilon(&Point::new(2.0, 3.0, 0.1), 0.0001));
+    /// assert!(!triangle.contains_point_with_epsilon(&Point::new(6.0, 6.0, 0.0), 0.0001));
+    /// ```
+    pub fn contains_point_with_epsilon(&self, point: &Point, epsilon: f64) -> bool {
+        point.get_distance_to_point(&self.get_closest_point(point)) <= epsilon
+    }
+
+    /// Checks if the [Triangle] lies in the same plane as the other one: all the other's
+    /// vertices are not further than `epsilon` from the Triangle's plane.
+    ///
+    /// Degenerate Triangles (see [Triangle::is_degenerate]) have no plane, so `false` is
+    /// returned for them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+    /// let coplanar = Triangle::new(Point::new(20.0, 0.0, 0.0), Point::new(30.0, 0.0, 0.00001), Point::new(20.0, 10.0, 0.0));
+    /// let tilted = Triangle::new(Point::new(20.0, 0.0, 0.0), Point::new(30.0, 0.0, 1.0), Point::new(20.0, 10.0, 0.0));
+    ///
+    /// assert!(a.is_coplanar_to_triangle_with_epsilon(&coplanar, 0.0001));
+    /// assert!(!a.is_coplanar_to_triangle_with_epsilon(&tilted, 0.0001));
+    /// ```
+    pub fn is_coplanar_to_triangle_with_epsilon(&self, other: &Triangle, epsilon: f64) -> bool {
+        let normal = match self.get_normal_vector_unitized_checked() {
+            Some(normal) => normal,
+            None => return false,
+        };
+
+        [other.first_point, other.second_point, other.third_point].iter()
+            .all(|point| normal.get_dot_product(&Vector::from_2_points(&self.first_point, point)).abs() <= epsilon)
+    }
+
+    /// Checks if the [Triangle] is degenerate, which means it has (nearly) zero area, so its
+    /// normal is not defined.
+    ///
+    /// It's `true` when the sine of the angle between the first and the second side is not
+    /// bigger than `f64::EPSILON`, or when any coordinate is not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let collinear = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 1.0, 1.0), Point::new(3.0, 3.0, 3.0));
+    /// let correct = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!(collinear.is_degenerate());
+    /// assert!(!correct.is_degenerate());
+    /// ```
+    pub fn is_degenerate(&self) -> bool {
+        self.get_normal_vector_unitized_checked().is_none()
+    }
+
+    /// Gets the unitized normal [Vector] of the [Triangle], or `None` if the Triangle is
+    /// degenerate (see [Triangle::is_degenerate]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let correct = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+    /// let collapsed = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0));
+    ///
+    /// assert_eq!(correct.get_normal_vector_unitized_checked(), Some(Vector::new(0.0, 0.0, 1.0)));
+    /// assert_eq!(collapsed.get_normal_vector_unitized_checked(), None);
+    /// ```
+    pub fn get_normal_vector_unitized_checked(&self) -> Option<Vector> {
+        let first_vector = self.get_first_side_as_vector();
+        let second_vector = self.get_second_side_as_vector();
+        let cross_product = first_vector.get_cross_product(&second_vector);
+        let length = cross_product.get_length();
+
+        if !length.is_finite() || length <= f64::EPSILON * first_vector.get_length() * second_vector.get_length() {
+            return None;
+        }
+
+        Some(Vector::new(cross_product.x / length, cross_product.y / length, cross_product.z / length))
+    }
+
+    /// Gets the angle between normals of 2 [Triangle]s, or `None` if any of them is degenerate
+    /// (see [Triangle::is_degenerate]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+    /// let b = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 1.0), Point::new(1.0, 0.0, 0.0));
+    /// let collapsed = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0));
+    ///
+    /// assert!((a.get_normals_angle_checked(&b).unwrap() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// assert_eq!(a.get_normals_angle_checked(&collapsed), None);
+    /// ```
+    pub fn get_normals_angle_checked(&self, another: &Triangle) -> Option<f64> {
+        let first_vector = self.get_normal_vector_unitized_checked()?;
+        let second_vector = another.get_normal_vector_unitized_checked()?;
+
+        Some(first_vector.get_angle(&second_vector))
+    }
+
+    /// Gets the inner angles of the [Triangle] in radians: at the first, the second and the third
+    /// point.
+    ///
+    /// For degenerate Triangle (see [Triangle::is_degenerate]) angles can be `NaN`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let input = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+    ///
+    /// let actual = input.get_angles();
+    ///
+    /// assert!((actual[0] - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// assert!((actual[1] - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    /// assert!((actual[2] - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    /// ```
+    pub fn get_angles(&self) -> [f64; 3] {
+        let first_side = self.get_first_side_as_vector();
+        let second_side = self.get_second_side_as_vector();
+        let third_side = self.get_third_side_as_vector();
+
+        [
+            first_side.get_angle(&third_side.get_reversed()),
+            second_side.get_angle(&first_side.get_reversed()),
+            third_side.get_angle(&second_side.get_reversed()),
+        ]
+    }
+
+    /// Gets the smallest inner angle of the [Triangle] in radians.
+    ///
+    /// It's `PI/3` for the equilateral Triangle and `0.0` for the degenerate one
+    /// (see [Triangle::is_degenerate]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let input = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!((input.get_min_angle() - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    /// ```
+    pub fn get_min_angle(&self) -> f64 {
+        if self.is_degenerate() {
+            return 0.0;
+        }
+
+        self.get_angles().into_iter().fold(f64::INFINITY, f64::min)
+    }
+
+    /// Gets the aspect ratio of the [Triangle]: the ratio of the circumradius to the doubled
+    /// inradius.
+    ///
+    /// It's `1.0` for the equilateral Triangle and grows for the badly shaped ones, degenerate
+    /// Triangle (see [Triangle::is_degenerate]) gets `f64::INFINITY`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let equilateral = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(1.0, f64::sqrt(3.0), 0.0));
+    /// let needle = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(5.0, 0.1, 0.0));
+    ///
+    /// assert!((equilateral.get_aspect_ratio() - 1.0).abs() < 1e-12);
+    /// assert!(needle.get_aspect_ratio() > 10.0);
+    /// ```
+    pub fn get_aspect_ratio(&self) -> f64 {
+        if self.is_degenerate() {
+            return f64::INFINITY;
+        }
+
+        let [a, b, c] = self.get_side_lengths();
+        let area = self.get_area();
+
+        (a * b * c * (a + b + c)) / (16.0 * area * area) // R / 2r, where R = abc / 4A and r = 2A / (a + b + c)
+    }
+
+    /// Gets the equiangular skewness of the [Triangle]: how much its angles differ from
+    /// the angles of the equilateral Triangle.
+    ///
+    /// It's in range of `0.0` (equilateral) to `1.0` (degenerate, see [Triangle::is_degenerate]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let input = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+    ///
+    /// assert!((input.get_skewness() - 0.25).abs() < 1e-12);
+    /// ```
+    pub fn get_skewness(&self) -> f64 {
+        if self.is_degenerate() {
+            return 1.0;
+        }
+
+        let equilateral_angle = PI / 3.0;
+        let angles = self.get_angles();
+        let max_angle = angles.into_iter().fold(0.0, f64::max);
+        let min_angle = angles.into_iter().fold(f64::INFINITY, f64::min);
+
+        f64::max((max_angle - equilateral_angle) / (PI - equilateral_angle), (equilateral_angle - min_angle) / equilateral_angle).clamp(0.0, 1.0)
+    }
+
+    /// Gets the ratio of the longest to the shortest side of the [Triangle].
+    ///
+    /// It's `1.0` for the equilateral Triangle and `f64::INFINITY` if any side has zero length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::triangle::Triangle;
+    ///
+    /// let input = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0), Point::new(0.0, 3.0, 0.0));
+    ///
+    /// assert!((input.get_edge_length_ratio() - 5.0 / 3.0).abs() < 1e-12);
+    /// ```
+    pub fn get_edge_length_ratio(&self) -> f64 {
+        let side_lengths = self.get_side_lengths();
+        let longest = side_lengths.into_iter().fold(0.0, f64::max);
+        let shortest = side_lengths.into_iter().fold(f64::INFINITY, f64::min);
+
+        if shortest == 0.0 || !shortest.is_finite() {
+            return f64::INFINITY;
+        }
+
+        longest / shortest
+    }
+
+    /// Gets lengths of the first, the second and the third side of the [Triangle].
+    fn get_side_lengths(&self) -> [f64; 3] {
+        [
+            self.first_point.get_distance_to_point(&self.second_point),
+            self.second_point.get_distance_to_point(&self.third_point),
+            self.third_point.get_distance_to_point(&self.first_point),
+        ]
+    }
 }
 
 #[cfg(test)]
@@ -918,5 +1472,154 @@ mod tests {
         
         assert_eq!(expected, actual);
     }
-}
 
+    #[test]
+    fn test_get_closest_point_regions() {
+        let triangle = Triangle::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(10.0, 0.0, 0.0),
+            Point::new(0.0, 10.0, 0.0));
+
+        assert_eq!(triangle.get_closest_point(&Point::new(12.0, -1.0, 3.0)), Point::new(10.0, 0.0, 0.0));
+        assert_eq!(triangle.get_closest_point(&Point::new(-1.0, 12.0, 3.0)), Point::new(0.0, 10.0, 0.0));
+        assert_eq!(triangle.get_closest_point(&Point::new(-4.0, 5.0, 3.0)), Point::new(0.0, 5.0, 0.0));
+        assert!(triangle.get_closest_point(&Point::new(8.0, 8.0, -3.0)).eq_with_tolerance(&Point::new(5.0, 5.0, 0.0), 1e-12));
+    }
+
+    #[test]
+    fn test_intersects_triangle_crossing() {
+        let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+        let b = Triangle::new(Point::new(2.0, 2.0, -5.0), Point::new(2.0, 2.0, 5.0), Point::new(20.0, 20.0, 0.0));
+
+        assert!(a.intersects_triangle(&b, 0.0001));
+        assert!(b.intersects_triangle(&a, 0.0001));
+    }
+
+    #[test]
+    fn test_intersects_triangle_separated() {
+        let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+        let b = Triangle::new(Point::new(20.0, 20.0, -5.0), Point::new(20.0, 20.0, 5.0), Point::new(30.0, 30.0, 0.0));
+
+        assert!(!a.intersects_triangle(&b, 0.0001));
+    }
+
+    #[test]
+    fn test_intersects_triangle_neighbours_sharing_edge() {
+        let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+        let b = Triangle::new(Point::new(10.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0), Point::new(5.0, -5.0, 3.0));
+        let coplanar = Triangle::new(Point::new(10.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0), Point::new(5.0, -5.0, 0.0));
+
+        assert!(!a.intersects_triangle(&b, 0.0001));
+        assert!(!a.intersects_triangle(&coplanar, 0.0001));
+    }
+
+    #[test]
+    fn test_intersects_triangle_folded_over_neighbour() {
+        let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+        let folded = Triangle::new(Point::new(10.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0), Point::new(2.0, 5.0, 0.0));
+
+        assert!(a.intersects_triangle(&folded, 0.0001));
+    }
+
+    #[test]
+    fn test_intersect_segment_ends_on_plane() {
+        let triangle = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+
+        assert_eq!(triangle.intersect_segment(&Point::new(2.0, 3.0, 0.00001), &Point::new(2.0, 3.0, 4.0), 0.0001), Some(Point::new(2.0, 3.0, 0.00001)));
+        assert_eq!(triangle.intersect_segment(&Point::new(2.0, 3.0, 4.0), &Point::new(10.0, 0.0, 0.0), 0.0001), Some(Point::new(10.0, 0.0, 0.0)));
+        assert_eq!(triangle.intersect_segment(&Point::new(2.0, 3.0, 0.0), &Point::new(20.0, 3.0, 0.0), 0.0001), None);
+    }
+
+    #[test]
+    fn test_intersect_segment_through_edge() {
+        let triangle = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+
+        let actual = triangle.intersect_segment(&Point::new(5.0, 5.0, 2.0), &Point::new(5.0, 5.0, -2.0), 0.0001);
+
+        assert_eq!(actual, Some(Point::new(5.0, 5.0, 0.0)));
+    }
+
+    #[test]
+    fn test_intersect_segment_degenerate() {
+        let triangle = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(20.0, 0.0, 0.0));
+
+        assert_eq!(triangle.intersect_segment(&Point::new(5.0, 0.0, 2.0), &Point::new(5.0, 0.0, -2.0), 0.0001), None);
+    }
+
+    #[test]
+    fn test_get_intersection_with_triangle_shared_edge() {
+        let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+        let b = Triangle::new(Point::new(10.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0), Point::new(5.0, -5.0, 3.0));
+
+        let (start, end) = a.get_intersection_with_triangle(&b, 0.0001).unwrap();
+
+        assert_eq!(start.get_distance_to_point(&end), 10.0);
+        assert_eq!(start.y, 0.0);
+        assert_eq!(end.y, 0.0);
+    }
+
+    #[test]
+    fn test_get_intersection_with_triangle_touching_vertex() {
+        let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+        let b = Triangle::new(Point::new(2.0, 2.0, 0.0), Point::new(2.0, 6.0, 5.0), Point::new(6.0, 2.0, 5.0));
+
+        assert_eq!(a.get_intersection_with_triangle(&b, 0.0001), Some((Point::new(2.0, 2.0, 0.0), Point::new(2.0, 2.0, 0.0))));
+    }
+
+    #[test]
+    fn test_get_intersection_with_triangle_separated_and_coplanar() {
+        let a = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(10.0, 0.0, 0.0), Point::new(0.0, 10.0, 0.0));
+        let separated = Triangle::new(Point::new(20.0, 20.0, -5.0), Point::new(20.0, 20.0, 5.0), Point::new(30.0, 30.0, 0.0));
+        let coplanar = Triangle::new(Point::new(1.0, 1.0, 0.0), Point::new(5.0, 1.0, 0.0), Point::new(1.0, 5.0, 0.0));
+
+        assert_eq!(a.get_intersection_with_triangle(&separated, 0.0001), None);
+        assert_eq!(a.get_intersection_with_triangle(&coplanar, 0.0001), None);
+    }
+
+    #[test]
+    fn test_get_normal_vector_unitized_checked_tiny_but_correct() {
+        let input = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1e-9, 0.0, 0.0), Point::new(0.0, 1e-9, 0.0));
+
+        assert_eq!(input.get_normal_vector_unitized_checked(), Some(Vector::new(0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_get_normal_vector_unitized_checked_not_finite() {
+        let input = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(f64::NAN, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+
+        assert_eq!(input.get_normal_vector_unitized_checked(), None);
+        assert!(input.is_degenerate());
+    }
+
+    #[test]
+    fn test_get_angles_sum() {
+        let input = Triangle::new(Point::new(35.704653, 37.253023, -22.626602), Point::new(-38.634947, 13.199458, 23.94433), Point::new(-21.698671, -49.7235, -32.888206));
+
+        let actual: f64 = input.get_angles().iter().sum();
+
+        assert!((actual - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_quality_metrics_degenerate() {
+        let collinear = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), Point::new(3.0, 0.0, 0.0));
+        let collapsed = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0));
+
+        assert_eq!(collinear.get_min_angle(), 0.0);
+        assert_eq!(collinear.get_aspect_ratio(), f64::INFINITY);
+        assert_eq!(collinear.get_skewness(), 1.0);
+        assert_eq!(collinear.get_edge_length_ratio(), 3.0);
+        assert_eq!(collapsed.get_edge_length_ratio(), f64::INFINITY);
+        assert_eq!(collapsed.get_skewness(), 1.0);
+    }
+
+    #[test]
+    fn test_quality_metrics_equilateral() {
+        let input = Triangle::new(Point::new(0.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0), Point::new(1.0, f64::sqrt(3.0), 0.0));
+
+        assert!((input.get_min_angle() - PI / 3.0).abs() < 1e-12);
+        assert!((input.get_aspect_ratio() - 1.0).abs() < 1e-12);
+        assert!(input.get_skewness() < 1e-12);
+        assert!((input.get_edge_length_ratio() - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents length units used by the geometry.
+///
+/// Meshes don't store their units, so it's up to the user to keep track of them. Mixing inputs
+/// with different units is a common source of scale bugs (e.g. a model being 1000 times too big),
+/// so convert everything to the same [Units] before combining the geometry.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::units::Units;
+///
+/// let result = Units::Millimeters.get_conversion_factor(Units::Meters);
+///
+/// assert_eq!(result, 0.001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Units {
+    /// Millimeters (mm).
+    Millimeters,
+    /// Centimeters (cm).
+    Centimeters,
+    /// Meters (m).
+    Meters,
+    /// International feet (ft), 0.3048 m.
+    Feet,
+    /// International inches (in), 0.0254 m.
+    Inches,
+}
+
+impl Units {
+    /// Returns the length of one unit expressed in meters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::units::Units;
+    ///
+    /// assert_eq!(Units::Centimeters.get_meters_per_unit(), 0.01);
+    /// assert_eq!(Units::Feet.get_meters_per_unit(), 0.3048);
+    /// ```
+    pub fn get_meters_per_unit(&self) -> f64 {
+        match self {
+            Units::Millimeters => 0.001,
+            Units::Centimeters => 0.01,
+            Units::Meters => 1.0,
+            Units::Feet => 0.3048,
+            Units::Inches => 0.0254,
+        }
+    }
+
+    /// Returns the factor which should multiply values in these [Units] to get values in `to`
+    /// [Units].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::units::Units;
+    ///
+    /// assert_eq!(Units::Meters.get_conversion_factor(Units::Millimeters), 1000.0);
+    /// assert_eq!(Units::Feet.get_conversion_factor(Units::Inches), 12.0);
+    /// assert_eq!(Units::Inches.get_conversion_factor(Units::Inches), 1.0);
+    /// ```
+    pub fn get_conversion_factor(&self, to: Units) -> f64 {
+        if *self == to {
+            return 1.0;
+        }
+
+        // Going through the exact integer ratios where possible avoids results like 999.9999.
+        let from_micrometers = self.get_micrometers_per_unit();
+        let to_micrometers = to.get_micrometers_per_unit();
+        from_micrometers / to_micrometers
+    }
+
+    /// Returns the abbreviation of the [Units], e.g. `mm` for [Units::Millimeters].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::units::Units;
+    ///
+    /// assert_eq!(Units::Millimeters.get_abbreviation(), "mm");
+    /// assert_eq!(Units::Inches.get_abbreviation(), "in");
+    /// ```
+    pub fn get_abbreviation(&self) -> &'static str {
+        match self {
+            Units::Millimeters => "mm",
+            Units::Centimeters => "cm",
+            Units::Meters => "m",
+            Units::Feet => "ft",
+            Units::Inches => "in",
+        }
+    }
+
+    /// Creates [Units] from the abbreviation (e.g. `mm`) or the full name (e.g. `Millimeters`).
+    ///
+    /// The comparison is case-insensitive. It returns an error for unknown units.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::units::Units;
+    ///
+    /// assert_eq!(Units::from_abbreviation("mm"), Ok(Units::Millimeters));
+    /// assert_eq!(Units::from_abbreviation("Feet"), Ok(Units::Feet));
+    /// assert!(Units::from_abbreviation("yd").is_err());
+    /// ```
+    pub fn from_abbreviation(abbreviation: &str) -> Result<Units, String> {
+        match abbreviation.trim().to_lowercase().as_str() {
+            "mm" | "millimeter" | "millimeters" | "millimetre" | "millimetres" => Ok(Units::Millimeters),
+            "cm" | "centimeter" | "centimeters" | "centimetre" | "centimetres" => Ok(Units::Centimeters),
+            "m" | "meter" | "meters" | "metre" | "metres" => Ok(Units::Meters),
+            "ft" | "foot" | "feet" => Ok(Units::Feet),
+            "in" | "inch" | "inches" => Ok(Units::Inches),
+            _ => Err(format!("Unknown units: {}", abbreviation)),
+        }
+    }
+
+    fn get_micrometers_per_unit(&self) -> f64 {
+        match self {
+            Units::Millimeters => 1000.0,
+            Units::Centimeters => 10000.0,
+            Units::Meters => 1000000.0,
+            Units::Feet => 304800.0,
+            Units::Inches => 25400.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+    use super::*;
+
+    #[test]
+    fn test_get_conversion_factor_all_pairs_roundtrip() {
+        let all = [Units::Millimeters, Units::Centimeters, Units::Meters, Units::Feet, Units::Inches];
+        for from in all {
+            for to in all {
+                let there = from.get_conversion_factor(to);
+                let back = to.get_conversion_factor(from);
+                assert!((there * back - 1.0).abs() < 1e-12);
+                assert!((there - from.get_meters_per_unit() / to.get_meters_per_unit()).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_conversion_factor_exact() {
+        assert_eq!(Units::Meters.get_conversion_factor(Units::Millimeters), 1000.0);
+        assert_eq!(Units::Centimeters.get_conversion_factor(Units::Millimeters), 10.0);
+        assert_eq!(Units::Inches.get_conversion_factor(Units::Millimeters), 25.4);
+        assert_eq!(Units::Feet.get_conversion_factor(Units::Millimeters), 304.8);
+    }
+
+    #[test]
+    fn test_abbreviation_roundtrip() {
+        let all = [Units::Millimeters, Units::Centimeters, Units::Meters, Units::Feet, Units::Inches];
+        for units in all {
+            assert_eq!(Units::from_abbreviation(units.get_abbreviation()), Ok(units));
+        }
+    }
+
+    #[test]
+    fn test_serialize_deserialize() {
+        let json = to_string(&Units::Feet).unwrap();
+        assert_eq!(json, "\"Feet\"");
+        let actual: Units = from_str(&json).unwrap();
+        assert_eq!(actual, Units::Feet);
+    }
+}
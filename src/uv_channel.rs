@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// Represents texture (UV) coordinates of the [Mesh](crate::mesh::Mesh).
+///
+/// UVs can be stored per vertex, which is compact, or per face corner, which allows seams
+/// (different UVs of the same vertex on different faces) without splitting vertices.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::mesh::Mesh;
+/// use meshmeshmesh::point2d::Point2D;
+/// use meshmeshmesh::uv_channel::UvChannel;
+///
+/// let mut mesh = Mesh::new(vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0], vec![0, 1, 2]);
+/// mesh.uvs = Some(UvChannel::PerVertex(vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0]));
+///
+/// assert_eq!(mesh.get_uv_for_corner(1), Some(Point2D::new(1.0, 0.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum UvChannel {
+    /// 2 values per vertex, in the order of `coordinates`: [u0, v0, u1, v1, ... , uN, vN]
+    PerVertex(Vec<f64>),
+    /// 2 values per face corner, in the order of `indices`: [u0, v0, u1, v1, ... , uN, vN]
+    PerCorner(Vec<f64>),
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let input = UvChannel::PerCorner(vec![0.0, 0.5, 1.0, 0.25]);
+
+        let json = to_string(&input).unwrap();
+        let actual: UvChannel = from_str(&json).unwrap();
+
+        assert_eq!(json, "{\"PerCorner\":[0.0,0.5,1.0,0.25]}");
+        assert_eq!(actual, input);
+    }
+}
@@ -0,0 +1,38 @@
+/// Describes which issues are reported by [Mesh::validate](crate::mesh::Mesh::validate).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::validation_options::ValidationOptions;
+///
+/// let options = ValidationOptions::new(1e-9, true);
+///
+/// assert_eq!(options.zero_area_tolerance, 1e-9);
+/// assert!(options.allow_open_boundaries);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValidationOptions {
+    /// Faces with area smaller or equal to this value are reported as zero-area faces.
+    pub zero_area_tolerance: f64,
+    /// If `true`, boundary edges are not reported, so open meshes (e.g. terrains or single
+    /// surfaces) can still be valid.
+    pub allow_open_boundaries: bool,
+}
+
+impl ValidationOptions {
+    /// Creates new [ValidationOptions].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::validation_options::ValidationOptions;
+    ///
+    /// let options = ValidationOptions::new(0.0, false);
+    ///
+    /// assert_eq!(options.zero_area_tolerance, 0.0);
+    /// assert!(!options.allow_open_boundaries);
+    /// ```
+    pub fn new(zero_area_tolerance: f64, allow_open_boundaries: bool) -> ValidationOptions {
+        ValidationOptions { zero_area_tolerance, allow_open_boundaries }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::edge::Edge;
+
+/// Represents the issues found by [Mesh::validate](crate::mesh::Mesh::validate).
+///
+/// Every issue is listed with its location (ids of vertices, faces or edges), so invalid
+/// meshes can be fixed or rejected before they cause panics in other methods.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::validation_report::ValidationReport;
+///
+/// let report = ValidationReport {
+///     number_of_dangling_indices: 0,
+///     out_of_range_index_positions: vec![],
+///     non_finite_vertex_ids: vec![],
+///     zero_area_face_ids: vec![],
+///     duplicate_face_pairs: vec![],
+///     non_manifold_edges: vec![],
+///     inconsistent_winding_edges: vec![],
+///     boundary_edges: vec![],
+/// };
+///
+/// assert!(report.is_valid());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValidationReport {
+    /// The number of indices left at the end of `indices`, which don't make a full face.
+    pub number_of_dangling_indices: usize,
+    /// Positions in `indices` of indices pointing to vertices which don't exist.
+    pub out_of_range_index_positions: Vec<usize>,
+    /// Ids of vertices with NaN or infinite coordinates.
+    pub non_finite_vertex_ids: Vec<usize>,
+    /// Ids of faces with (almost) zero area.
+    pub zero_area_face_ids: Vec<usize>,
+    /// Pairs of ids of faces using the same 3 vertices, regardless of their order.
+    pub duplicate_face_pairs: Vec<(usize, usize)>,
+    /// Undirected edges used by more than 2 faces.
+    pub non_manifold_edges: Vec<Edge>,
+    /// Undirected edges shared by 2 faces which use them in the same direction, so these faces
+    /// have opposite orientation.
+    pub inconsistent_winding_edges: Vec<Edge>,
+    /// Undirected edges used by only 1 face. It's empty if open boundaries are allowed.
+    pub boundary_edges: Vec<Edge>,
+}
+
+impl PartialEq for ValidationReport {
+    fn eq(&self, other: &Self) -> bool {
+        self.number_of_dangling_indices == other.number_of_dangling_indices &&
+            self.out_of_range_index_positions == other.out_of_range_index_positions &&
+            self.non_finite_vertex_ids == other.non_finite_vertex_ids &&
+            self.zero_area_face_ids == other.zero_area_face_ids &&
+            self.duplicate_face_pairs == other.duplicate_face_pairs &&
+            self.non_manifold_edges == other.non_manifold_edges &&
+            self.inconsistent_winding_edges == other.inconsistent_winding_edges &&
+            self.boundary_edges == other.boundary_edges
+    }
+}
+
+impl ValidationReport {
+    /// Checks if the [Mesh](crate::mesh::Mesh) passed the validation, so no issues were found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::edge::Edge;
+    /// use meshmeshmesh::validation_report::ValidationReport;
+    ///
+    /// let report = ValidationReport {
+    ///     number_of_dangling_indices: 0,
+    ///     out_of_range_index_positions: vec![],
+    ///     non_finite_vertex_ids: vec![],
+    ///     zero_area_face_ids: vec![3],
+    ///     duplicate_face_pairs: vec![],
+    ///     non_manifold_edges: vec![Edge::new(1, 2)],
+    ///     inconsistent_winding_edges: vec![],
+    ///     boundary_edges: vec![],
+    /// };
+    ///
+    /// assert!(!report.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.number_of_dangling_indices == 0 &&
+            self.out_of_range_index_positions.is_empty() &&
+            self.non_finite_vertex_ids.is_empty() &&
+            self.zero_area_face_ids.is_empty() &&
+            self.duplicate_face_pairs.is_empty() &&
+            self.non_manifold_edges.is_empty() &&
+            self.inconsistent_winding_edges.is_empty() &&
+            self.boundary_edges.is_empty()
+    }
+}
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+use crate::vector::Vector;
+
+/// Represents a Vector object in three-dimensional space using `f32` values.
+///
+/// It's the single precision version of the [Vector], which takes half of the memory. It's useful
+/// for memory-constrained or GPU-bound use cases.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::vector32::Vector32;
+///
+/// let result = Vector32::new(1.5, -2.5, 3.0);
+/// assert_eq!(result.x, 1.5);
+/// assert_eq!(result.y, -2.5);
+/// assert_eq!(result.z, 3.0);
+/// ```
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct Vector32 {
+    /// The x-dimension of the vector.
+    pub x: f32,
+    /// The y-dimension of the vector.
+    pub y: f32,
+    /// The z-dimension of the vector.
+    pub z: f32
+}
+
+impl PartialEq for Vector32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+
+impl From<Vector> for Vector32 {
+    fn from(vector: Vector) -> Self {
+        Vector32::new(vector.x as f32, vector.y as f32, vector.z as f32)
+    }
+}
+
+impl From<Vector32> for Vector {
+    fn from(vector: Vector32) -> Self {
+        Vector::new(vector.x as f64, vector.y as f64, vector.z as f64)
+    }
+}
+
+impl Vector32 {
+    /// Creates a new [Vector32]
+    ///
+    /// # Example
+    /// ```
+    /// use meshmeshmesh::vector32::Vector32;
+    ///
+    /// let result = Vector32::new(1.5, -2.5, 3.0);
+    /// assert_eq!(result.x, 1.5);
+    /// assert_eq!(result.y, -2.5);
+    /// assert_eq!(result.z, 3.0);
+    /// ```
+    pub fn new(x: f32, y: f32, z: f32) -> Vector32 { Vector32 { x, y, z } }
+
+    /// Calculates the length of the [Vector32].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::vector32::Vector32;
+    ///
+    /// let input = Vector32::new(3.0, 0.0, 4.0);
+    ///
+    /// assert_eq!(input.get_length(), 5.0);
+    /// ```
+    pub fn get_length(&self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Calculates the dot product with the other [Vector32].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::vector32::Vector32;
+    ///
+    /// let a = Vector32::new(1.0, 2.0, 3.0);
+    /// let b = Vector32::new(-2.0, 0.5, 2.0);
+    ///
+    /// assert_eq!(a.get_dot_product(&b), 5.0);
+    /// ```
+    pub fn get_dot_product(&self, second_vector: &Vector32) -> f32 {
+        self.x * second_vector.x + self.y * second_vector.y + self.z * second_vector.z
+    }
+
+    /// Calculates the cross product with the other [Vector32].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::vector32::Vector32;
+    ///
+    /// let a = Vector32::new(1.0, 0.0, 0.0);
+    /// let b = Vector32::new(0.0, 1.0, 0.0);
+    ///
+    /// assert_eq!(a.get_cross_product(&b), Vector32::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn get_cross_product(&self, second_vector: &Vector32) -> Vector32 {
+        Vector32::new(
+            self.y * second_vector.z - self.z * second_vector.y,
+            self.z * second_vector.x - self.x * second_vector.z,
+            self.x * second_vector.y - self.y * second_vector.x,
+        )
+    }
+
+    /// Returns the unitized [Vector32]
+    ///
+    /// Unitized meaning the length will be 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::vector32::Vector32;
+    ///
+    /// let input = Vector32::new(0.0, -3.0, 4.0);
+    ///
+    /// assert_eq!(input.get_unitized(), Vector32::new(0.0, -0.6, 0.8));
+    /// ```
+    pub fn get_unitized(&self) -> Vector32 {
+        let length = self.get_length();
+
+        if length == 0.0 {
+            panic!("Cannot get unitized Vector32 if its length is 0.0")
+        }
+
+        Vector32::new(self.x / length, self.y / length, self.z / length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partialeq_false() {
+        let a = Vector32::new(1.5, -2.5, 3.0);
+        let b = Vector32::new(1.5, -2.5, 3.5);
+        assert!(!a.eq(&b));
+        assert!(!b.eq(&a));
+    }
+
+    #[test]
+    fn test_from_vector_and_back() {
+        let input = Vector::new(1.5, -2.25, 3.125);
+
+        let converted = Vector32::from(input);
+        let actual = Vector::from(converted);
+
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot get unitized Vector32 if its length is 0.0")]
+    fn test_get_unitized_zero_panic() {
+        Vector32::new(0.0, 0.0, 0.0).get_unitized();
+    }
+}
@@ -0,0 +1,120 @@
+use crate::vector::Vector;
+
+impl Vector {
+    /// Encodes the direction of the [Vector] as 2 `u16` values using octahedral mapping.
+    ///
+    /// The [Vector] is unitized first, so only its direction is kept. Octahedral encoding
+    /// distributes the precision evenly on the sphere, so 2x16 bits give angular error below
+    /// 0.01 degree. It's useful for storing normals in compact binary formats and GPU buffers,
+    /// e.g. it's used for `vertex_normals` of the [QuantizedMesh](crate::quantized_mesh::QuantizedMesh).
+    ///
+    /// Use [Vector::from_octahedral_u16] to decode it.
+    ///
+    /// It panics if the [Vector] has zero length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Vector::new(0.0, 0.0, 1.0);
+    /// let actual = input.to_octahedral_u16();
+    ///
+    /// assert_eq!(actual, [32768, 32768]);
+    /// ```
+    pub fn to_octahedral_u16(&self) -> [u16; 2] {
+        let sum = self.x.abs() + self.y.abs() + self.z.abs();
+        if sum == 0.0 {
+            panic!("Cannot encode Vector if its length is 0.0")
+        }
+
+        let mut u = self.x / sum;
+        let mut v = self.y / sum;
+        if self.z < 0.0 { // Folding the lower hemisphere over the diagonals
+            let folded_u = (1.0 - v.abs()) * Self::get_octahedral_sign(u);
+            let folded_v = (1.0 - u.abs()) * Self::get_octahedral_sign(v);
+            u = folded_u;
+            v = folded_v;
+        }
+
+        [Self::encode_octahedral_component(u), Self::encode_octahedral_component(v)]
+    }
+
+    /// Decodes the unit [Vector] from 2 `u16` values encoded with [Vector::to_octahedral_u16].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Vector::new(-0.3, 0.5, -0.8);
+    /// let encoded = input.to_octahedral_u16();
+    /// let actual = Vector::from_octahedral_u16(encoded);
+    ///
+    /// assert!(input.get_unitized().eq_with_tolerance(&actual, 0.0001));
+    /// ```
+    pub fn from_octahedral_u16(encoded: [u16; 2]) -> Vector {
+        let mut u = Self::decode_octahedral_component(encoded[0]);
+        let mut v = Self::decode_octahedral_component(encoded[1]);
+        let z = 1.0 - u.abs() - v.abs();
+        if z < 0.0 { // Unfolding the lower hemisphere
+            let folded_u = (1.0 - v.abs()) * Self::get_octahedral_sign(u);
+            let folded_v = (1.0 - u.abs()) * Self::get_octahedral_sign(v);
+            u = folded_u;
+            v = folded_v;
+        }
+
+        Vector::new(u, v, z).get_unitized()
+    }
+
+    fn get_octahedral_sign(value: f64) -> f64 {
+        if value >= 0.0 { 1.0 } else { -1.0 }
+    }
+
+    fn encode_octahedral_component(value: f64) -> u16 {
+        ((value.clamp(-1.0, 1.0) * 0.5 + 0.5) * u16::MAX as f64).round() as u16
+    }
+
+    fn decode_octahedral_component(value: u16) -> f64 {
+        (value as f64 / u16::MAX as f64) * 2.0 - 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_octahedral_u16_axes() {
+        assert_eq!(Vector::new(1.0, 0.0, 0.0).to_octahedral_u16(), [65535, 32768]);
+        assert_eq!(Vector::new(-1.0, 0.0, 0.0).to_octahedral_u16(), [0, 32768]);
+        assert_eq!(Vector::new(0.0, 1.0, 0.0).to_octahedral_u16(), [32768, 65535]);
+        assert_eq!(Vector::new(0.0, 0.0, 5.0).to_octahedral_u16(), [32768, 32768]);
+        assert_eq!(Vector::new(0.0, 0.0, -5.0).to_octahedral_u16(), [65535, 65535]);
+    }
+
+    #[test]
+    fn test_octahedral_u16_round_trip() {
+        let inputs = vec![
+            Vector::new(1.0, 0.0, 0.0),
+            Vector::new(0.0, -1.0, 0.0),
+            Vector::new(0.0, 0.0, -1.0),
+            Vector::new(0.3, -0.2, 0.9),
+            Vector::new(-5.2, -1.1, -3.7),
+            Vector::new(12.0, 7.5, -0.01),
+            Vector::new(-0.577, 0.577, -0.577),
+        ];
+
+        for input in inputs {
+            let actual = Vector::from_octahedral_u16(input.to_octahedral_u16());
+            let expected = input.get_unitized();
+            assert!(expected.get_angle(&actual) < 0.0002);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot encode Vector if its length is 0.0")]
+    fn test_to_octahedral_u16_zero_panic() {
+        Vector::zero().to_octahedral_u16();
+    }
+}
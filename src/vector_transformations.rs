@@ -1,5 +1,6 @@
 use std::ops;
 use crate::quaternion::Quaternion;
+use crate::transform::Transform;
 use crate::vector::Vector;
 
 impl Vector {
@@ -110,6 +111,34 @@ impl Vector {
         let point_rotated = point.get_rotated_by_quaternion(quaternion);
         point_rotated.to_vector()
     }
+
+    /// Returns the [Vector] transformed by the given [Transform].
+    ///
+    /// Vectors are directions, so only the linear part (rotation, scaling, shear) is applied,
+    /// and the translation is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::transform::Transform;
+    /// use meshmeshmesh::vector::Vector;
+    ///
+    /// let input = Vector::new(1.0, -2.0, 3.0);
+    /// let transform = Transform::new_translation(&Vector::new(0.5, 0.5, 0.5)) * Transform::new_scaling(2.0, 1.0, -1.0);
+    ///
+    /// let actual = input.get_transformed(&transform);
+    ///
+    /// assert_eq!(actual, Vector::new(2.0, -2.0, -3.0));
+    /// ```
+    pub fn get_transformed(&self, transform: &Transform) -> Vector {
+        let m = &transform.matrix;
+
+        Vector::new(
+            m[0][0] * self.x + m[0][1] * self.y + m[0][2] * self.z,
+            m[1][0] * self.x + m[1][1] * self.y + m[1][2] * self.z,
+            m[2][0] * self.x + m[2][1] * self.y + m[2][2] * self.z,
+        )
+    }
 }
 
 impl ops::Mul<f64> for Vector {
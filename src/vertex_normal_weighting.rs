@@ -0,0 +1,20 @@
+/// Describes how face normals are averaged into vertex normals, see
+/// [Mesh::compute_vertex_normals](crate::mesh::Mesh::compute_vertex_normals).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::vertex_normal_weighting::VertexNormalWeighting;
+///
+/// let weighting = VertexNormalWeighting::Angle;
+///
+/// assert_ne!(weighting, VertexNormalWeighting::Area);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexNormalWeighting {
+    /// Each face normal is weighted by the face area, so big faces dominate.
+    Area,
+    /// Each face normal is weighted by the face corner angle at the vertex, so the result
+    /// doesn't depend on how the surface is triangulated.
+    Angle,
+}
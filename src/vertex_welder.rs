@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::point::Point;
+
+/// Incremental builder of a welded [Mesh], used by streaming importers and
+/// [Mesh::get_with_welded_vertices_fast].
+///
+/// Vertices are looked up in a hash grid with the cell size equal to the tolerance, so adding a
+/// vertex costs O(1) on average and the whole triangle soup never has to be kept in memory.
+/// Vertices are considered equal with the same rule as [Point::eq_with_tolerance]. If there
+/// are several existing vertices within the tolerance, the latest one is used, same as in
+/// [Mesh::get_with_welded_vertices].
+pub(crate) struct VertexWelder {
+    tolerance: f64,
+    cells: HashMap<(i64, i64, i64), Vec<usize>>,
+    coordinates: Vec<f64>,
+    indices: Vec<usize>,
+}
+
+impl VertexWelder {
+    pub(crate) fn new(tolerance: f64) -> VertexWelder {
+        VertexWelder { tolerance: tolerance.max(0.0), cells: HashMap::new(), coordinates: Vec::new(), indices: Vec::new() }
+    }
+
+    /// Adds the vertex, or finds the latest existing one within the tolerance, and returns its id.
+    pub(crate) fn add_vertex(&mut self, point: &Point) -> usize {
+        let cell = self.get_cell(point);
+        let range = if self.tolerance > 0.0 { -1..=1 } else { 0..=0 };
+
+        // Cells are visited in no particular order, so all of them are checked for the latest match
+        let mut found: Option<usize> = None;
+        for dx in range.clone() {
+            for dy in range.clone() {
+                for dz in range.clone() {
+                    if let Some(vertex_ids) = self.cells.get(&(cell.0 + dx, cell.1 + dy, cell.2 + dz)) {
+                        for vertex_id in vertex_ids {
+                            let existing = Point::new(self.coordinates[vertex_id * 3], self.coordinates[vertex_id * 3 + 1], self.coordinates[vertex_id * 3 + 2]);
+                            if existing.eq_with_tolerance(point, self.tolerance) && found.is_none_or(|found| *vertex_id > found) {
+                                found = Some(*vertex_id);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(vertex_id) = found {
+            return vertex_id;
+        }
+
+        let vertex_id = self.coordinates.len() / 3;
+        self.coordinates.extend_from_slice(&[point.x, point.y, point.z]);
+        self.cells.entry(cell).or_default().push(vertex_id);
+        vertex_id
+    }
+
+    /// Adds the face made of 3 points.
+    pub(crate) fn add_face(&mut self, first: &Point, second: &Point, third: &Point) {
+        let face = [self.add_vertex(first), self.add_vertex(second), self.add_vertex(third)];
+        self.indices.extend_from_slice(&face);
+    }
+
+    pub(crate) fn into_mesh(self) -> Mesh {
+        Mesh::new(self.coordinates, self.indices)
+    }
+
+    fn get_cell(&self, point: &Point) -> (i64, i64, i64) {
+        if self.tolerance > 0.0 {
+            ((point.x / self.tolerance).floor() as i64, (point.y / self.tolerance).floor() as i64, (point.z / self.tolerance).floor() as i64)
+        } else {
+            // Exact matching, -0.0 and 0.0 are the same vertex.
+            ((point.x + 0.0).to_bits() as i64, (point.y + 0.0).to_bits() as i64, (point.z + 0.0).to_bits() as i64)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_vertex_exact() {
+        let mut welder = VertexWelder::new(0.0);
+
+        assert_eq!(welder.add_vertex(&Point::new(1.0, 2.0, 3.0)), 0);
+        assert_eq!(welder.add_vertex(&Point::new(1.0, 2.0, 3.0000001)), 1);
+        assert_eq!(welder.add_vertex(&Point::new(1.0, 2.0, 3.0)), 0);
+        assert_eq!(welder.add_vertex(&Point::new(-0.0, 0.0, 0.0)), 2);
+        assert_eq!(welder.add_vertex(&Point::new(0.0, -0.0, 0.0)), 2);
+    }
+
+    #[test]
+    fn test_add_vertex_with_tolerance_across_cells() {
+        let mut welder = VertexWelder::new(0.1);
+
+        assert_eq!(welder.add_vertex(&Point::new(0.99, 0.0, 0.0)), 0);
+        assert_eq!(welder.add_vertex(&Point::new(1.01, 0.0, 0.0)), 0);
+        assert_eq!(welder.add_vertex(&Point::new(1.2, 0.0, 0.0)), 1);
+    }
+
+    #[test]
+    fn test_into_mesh_same_as_welding() {
+        let input = Mesh::new(
+            vec![
+                0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0,
+                0.0, 0.0, 0.0, 10.0, 10.0, 0.0005, 0.0, 10.0, 0.0,
+            ],
+            vec![0, 1, 2, 3, 4, 5]);
+        let mut welder = VertexWelder::new(0.001);
+        for triangle in input.to_triangles() {
+            welder.add_face(&triangle.first_point, &triangle.second_point, &triangle.third_point);
+        }
+
+        let actual = welder.into_mesh();
+
+        assert_eq!(actual, input.get_with_welded_vertices(0.001));
+    }
+
+    #[test]
+    fn test_add_vertex_latest_of_several_within_tolerance() {
+        let mut welder = VertexWelder::new(0.1);
+
+        assert_eq!(welder.add_vertex(&Point::new(0.0, 0.0, 0.0)), 0);
+        assert_eq!(welder.add_vertex(&Point::new(0.15, 0.0, 0.0)), 1);
+        assert_eq!(welder.add_vertex(&Point::new(0.08, 0.0, 0.0)), 1);
+        assert_eq!(welder.add_vertex(&Point::new(-0.05, 0.0, 0.0)), 0);
+    }
+}
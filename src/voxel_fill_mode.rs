@@ -0,0 +1,18 @@
+/// Describes which cells are filled by [Mesh::voxelize](crate::mesh::Mesh::voxelize).
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::voxel_fill_mode::VoxelFillMode;
+///
+/// let fill_mode = VoxelFillMode::Solid;
+///
+/// assert_ne!(fill_mode, VoxelFillMode::Surface);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoxelFillMode {
+    /// Only cells overlapping faces are filled, so it works for open meshes too.
+    Surface,
+    /// Cells overlapping faces and cells inside are filled. The mesh should be closed.
+    Solid,
+}
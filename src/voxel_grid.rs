@@ -0,0 +1,67 @@
+use crate::point::Point;
+
+/// Represents a regular 3D grid of cubic cells (voxels), each filled or empty.
+///
+/// Cells are stored in `cells` in x, then y, then z order, so the cell (x, y, z) has the index
+/// `x + size_x * (y + size_y * z)`.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::point::Point;
+/// use meshmeshmesh::voxel_grid::VoxelGrid;
+///
+/// let result = VoxelGrid::new(Point::new(0.0, 0.0, 0.0), 0.5, 2, 1, 1, vec![true, false]);
+///
+/// assert_eq!(result.origin, Point::new(0.0, 0.0, 0.0));
+/// assert_eq!(result.cell_size, 0.5);
+/// assert_eq!(result.cells, vec![true, false]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoxelGrid {
+    /// The minimum corner of the grid.
+    pub origin: Point,
+    /// The length of the edge of each cell.
+    pub cell_size: f64,
+    /// The number of cells along the X axis.
+    pub size_x: usize,
+    /// The number of cells along the Y axis.
+    pub size_y: usize,
+    /// The number of cells along the Z axis.
+    pub size_z: usize,
+    /// Tells for each cell if it's filled.
+    pub cells: Vec<bool>,
+}
+
+impl VoxelGrid {
+    /// Creates a new [VoxelGrid].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::voxel_grid::VoxelGrid;
+    ///
+    /// let result = VoxelGrid::new(Point::new(1.0, 2.0, 3.0), 1.0, 1, 2, 3, vec![false; 6]);
+    ///
+    /// assert_eq!(result.origin, Point::new(1.0, 2.0, 3.0));
+    /// assert_eq!(result.cell_size, 1.0);
+    /// assert_eq!((result.size_x, result.size_y, result.size_z), (1, 2, 3));
+    /// assert_eq!(result.cells, vec![false; 6]);
+    /// ```
+    pub fn new(origin: Point, cell_size: f64, size_x: usize, size_y: usize, size_z: usize, cells: Vec<bool>) -> VoxelGrid {
+        VoxelGrid { origin, cell_size, size_x, size_y, size_z, cells }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let result = VoxelGrid::new(Point::new(0.0, 0.0, 0.0), 2.0, 1, 1, 1, vec![true]);
+
+        assert_eq!(result.size_x * result.size_y * result.size_z, result.cells.len());
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use crate::mesh::Mesh;
+use crate::point::Point;
+use crate::voxel_grid::VoxelGrid;
+
+impl VoxelGrid {
+    /// Gets the index in `cells` of the cell (x, y, z).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::voxel_grid::VoxelGrid;
+    ///
+    /// let input = VoxelGrid::new(Point::new(0.0, 0.0, 0.0), 1.0, 2, 3, 4, vec![false; 24]);
+    ///
+    /// assert_eq!(input.get_index(1, 2, 3), 1 + 2 * (2 + 3 * 3));
+    /// ```
+    pub fn get_index(&self, x: usize, y: usize, z: usize) -> usize {
+        x + self.size_x * (y + self.size_y * z)
+    }
+
+    /// Checks if the cell (x, y, z) is filled. Cells outside the grid are empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::voxel_grid::VoxelGrid;
+    ///
+    /// let input = VoxelGrid::new(Point::new(0.0, 0.0, 0.0), 1.0, 2, 1, 1, vec![false, true]);
+    ///
+    /// assert!(!input.is_filled(0, 0, 0));
+    /// assert!(input.is_filled(1, 0, 0));
+    /// assert!(!input.is_filled(2, 0, 0));
+    /// ```
+    pub fn is_filled(&self, x: usize, y: usize, z: usize) -> bool {
+        x < self.size_x && y < self.size_y && z < self.size_z && self.cells[self.get_index(x, y, z)]
+    }
+
+    /// Gets the number of filled cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::voxel_grid::VoxelGrid;
+    ///
+    /// let input = VoxelGrid::new(Point::new(0.0, 0.0, 0.0), 1.0, 3, 1, 1, vec![true, false, true]);
+    ///
+    /// assert_eq!(input.get_number_of_filled_cells(), 2);
+    /// ```
+    pub fn get_number_of_filled_cells(&self) -> usize {
+        self.cells.iter().filter(|is_filled| **is_filled).count()
+    }
+
+    /// Gets the volume of filled cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::voxel_grid::VoxelGrid;
+    ///
+    /// let input = VoxelGrid::new(Point::new(0.0, 0.0, 0.0), 0.5, 3, 1, 1, vec![true, false, true]);
+    ///
+    /// assert_eq!(input.get_volume(), 0.25);
+    /// ```
+    pub fn get_volume(&self) -> f64 {
+        self.get_number_of_filled_cells() as f64 * self.cell_size.powi(3)
+    }
+
+    /// Gets the center of the cell (x, y, z).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::voxel_grid::VoxelGrid;
+    ///
+    /// let input = VoxelGrid::new(Point::new(1.0, 0.0, 0.0), 2.0, 2, 1, 1, vec![true, true]);
+    ///
+    /// assert_eq!(input.get_cell_center(1, 0, 0), Point::new(4.0, 1.0, 1.0));
+    /// ```
+    pub fn get_cell_center(&self, x: usize, y: usize, z: usize) -> Point {
+        Point::new(
+            self.origin.x + (x as f64 + 0.5) * self.cell_size,
+            self.origin.y + (y as f64 + 0.5) * self.cell_size,
+            self.origin.z + (z as f64 + 0.5) * self.cell_size,
+        )
+    }
+
+    /// Converts the [VoxelGrid] into the [Mesh] of the boundary of filled cells.
+    ///
+    /// Only sides of filled cells next to empty ones (or to the outside of the grid) are
+    /// created, each as 2 faces pointing outside. Vertices are welded, so the [Mesh] is
+    /// watertight, although edges where only 2 cells touch diagonally are non-manifold.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::point::Point;
+    /// use meshmeshmesh::voxel_grid::VoxelGrid;
+    ///
+    /// let input = VoxelGrid::new(Point::new(0.0, 0.0, 0.0), 1.0, 2, 1, 1, vec![true, true]);
+    ///
+    /// let actual = input.to_mesh();
+    ///
+    /// assert_eq!(actual.get_number_of_vertices(), 12);
+    /// assert_eq!(actual.get_number_of_faces(), 10 * 2);
+    /// assert!((actual.get_volume().unwrap() - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn to_mesh(&self) -> Mesh {
+        let mut coordinates: Vec<f64> = Vec::new();
+        let mut indices: Vec<usize> = Vec::new();
+        let mut corner_vertex_ids: HashMap<[usize; 3], usize> = HashMap::new();
+        let mut get_vertex_id = |corner: [usize; 3], coordinates: &mut Vec<f64>| -> usize {
+            *corner_vertex_ids.entry(corner).or_insert_with(|| {
+                coordinates.extend_from_slice(&[
+                    self.origin.x + corner[0] as f64 * self.cell_size,
+                    self.origin.y + corner[1] as f64 * self.cell_size,
+                    self.origin.z + corner[2] as f64 * self.cell_size,
+                ]);
+                coordinates.len() / 3 - 1
+            })
+        };
+
+        for z in 0..self.size_z {
+            for y in 0..self.size_y {
+                for x in 0..self.size_x {
+                    if !self.cells[self.get_index(x, y, z)] {
+                        continue;
+                    }
+                    let cell = [x, y, z];
+                    for axis in 0..3 {
+                        // Other axes in cyclic order, so their cross product points along the axis
+                        let (first_axis, second_axis) = ((axis + 1) % 3, (axis + 2) % 3);
+                        for is_positive in [false, true] {
+                            let mut neighbour = cell;
+                            if is_positive {
+                                neighbour[axis] += 1;
+                            } else if cell[axis] == 0 {
+                                neighbour[axis] = usize::MAX;
+                            } else {
+                                neighbour[axis] -= 1;
+                            }
+                            if self.is_filled(neighbour[0], neighbour[1], neighbour[2]) {
+                                continue;
+                            }
+                            let mut corner = cell;
+                            corner[axis] += is_positive as usize;
+                            let mut corners = [corner; 4];
+                            corners[1][first_axis] += 1;
+                            corners[2][first_axis] += 1;
+                            corners[2][second_axis] += 1;
+                            corners[3][second_axis] += 1;
+                            if !is_positive {
+                                corners.reverse();
+                            }
+                            let ids = corners.map(|corner| get_vertex_id(corner, &mut coordinates));
+                            indices.extend_from_slice(&[ids[0], ids[1], ids[2], ids[0], ids[2], ids[3]]);
+                        }
+                    }
+                }
+            }
+        }
+
+        Mesh::new(coordinates, indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_mesh_empty() {
+        let input = VoxelGrid::new(Point::new(0.0, 0.0, 0.0), 1.0, 2, 2, 2, vec![false; 8]);
+
+        assert_eq!(input.to_mesh(), Mesh::new(vec![], vec![]));
+    }
+
+    #[test]
+    fn test_to_mesh_with_cavity() {
+        // 3 x 3 x 3 block without the middle cell
+        let mut cells = vec![true; 27];
+        cells[13] = false;
+        let input = VoxelGrid::new(Point::new(0.0, 0.0, 0.0), 1.0, 3, 3, 3, cells);
+
+        let actual = input.to_mesh();
+
+        assert_eq!(actual.get_number_of_faces(), (6 * 9 + 6) * 2);
+        assert!(actual.is_watertight());
+        assert!((actual.get_volume().unwrap() - 26.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,237 @@
+use wasm_bindgen::prelude::*;
+use crate::mesh::Mesh;
+
+/// Represents a [Mesh] exposed to JavaScript via `wasm-bindgen`.
+///
+/// Coordinates are passed as `Float64Array` and indices as `Uint32Array`, so the geometry can
+/// go straight from/to the buffers used by browser viewers, without any JSON serialization.
+///
+/// Available only with the `wasm` feature. To get a `.wasm` module, build the crate as a
+/// cdylib: `cargo rustc --release --lib --crate-type cdylib --features wasm --target wasm32-unknown-unknown`.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::wasm_mesh::WasmMesh;
+///
+/// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], &[0, 1, 2]);
+///
+/// assert_eq!(mesh.number_of_faces(), 1);
+/// assert_eq!(mesh.indices(), vec![0, 1, 2]);
+/// ```
+#[wasm_bindgen]
+pub struct WasmMesh {
+    mesh: Mesh,
+}
+
+#[wasm_bindgen]
+impl WasmMesh {
+    /// Creates a new [WasmMesh] from flat coordinates and indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::wasm_mesh::WasmMesh;
+    ///
+    /// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], &[0, 1, 2]);
+    ///
+    /// assert_eq!(mesh.coordinates(), vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0]);
+    /// ```
+    #[wasm_bindgen(constructor)]
+    pub fn new(coordinates: &[f64], indices: &[u32]) -> WasmMesh {
+        WasmMesh { mesh: Mesh::new(coordinates.to_vec(), indices.iter().map(|index| *index as usize).collect()) }
+    }
+
+    /// Returns the coordinates, organized as [x0, y0, z0, x1, y1, z1, ...].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::wasm_mesh::WasmMesh;
+    ///
+    /// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], &[0, 1, 2]);
+    ///
+    /// assert_eq!(mesh.coordinates(), vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0]);
+    /// ```
+    pub fn coordinates(&self) -> Vec<f64> {
+        self.mesh.coordinates.clone()
+    }
+
+    /// Returns the indices, 3 per face.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::wasm_mesh::WasmMesh;
+    ///
+    /// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], &[0, 2, 1]);
+    ///
+    /// assert_eq!(mesh.indices(), vec![0, 2, 1]);
+    /// ```
+    pub fn indices(&self) -> Vec<u32> {
+        self.mesh.indices.iter().map(|index| *index as u32).collect()
+    }
+
+    /// Returns the number of vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::wasm_mesh::WasmMesh;
+    ///
+    /// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], &[0, 1, 2]);
+    ///
+    /// assert_eq!(mesh.number_of_vertices(), 3);
+    /// ```
+    #[wasm_bindgen(js_name = numberOfVertices)]
+    pub fn number_of_vertices(&self) -> usize {
+        self.mesh.get_number_of_vertices()
+    }
+
+    /// Returns the number of faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::wasm_mesh::WasmMesh;
+    ///
+    /// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], &[0, 1, 2]);
+    ///
+    /// assert_eq!(mesh.number_of_faces(), 1);
+    /// ```
+    #[wasm_bindgen(js_name = numberOfFaces)]
+    pub fn number_of_faces(&self) -> usize {
+        self.mesh.get_number_of_faces()
+    }
+
+    /// Returns the bounding box as [min_x, max_x, min_y, max_y, min_z, max_z], or `undefined`
+    /// if the mesh has no vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::wasm_mesh::WasmMesh;
+    ///
+    /// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], &[0, 1, 2]);
+    ///
+    /// assert_eq!(mesh.bounding_box(), Some(vec![0.0, 10.0, -15.0, 0.0, 0.0, 0.0]));
+    /// ```
+    #[wasm_bindgen(js_name = boundingBox)]
+    pub fn bounding_box(&self) -> Option<Vec<f64>> {
+        if self.mesh.coordinates.is_empty() {
+            return None;
+        }
+
+        let bounding_box = self.mesh.get_bounding_box();
+        Some(vec![bounding_box.min_x, bounding_box.max_x, bounding_box.min_y, bounding_box.max_y, bounding_box.min_z, bounding_box.max_z])
+    }
+
+    /// Returns a new [WasmMesh] with welded vertices, see [Mesh::get_with_welded_vertices].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::wasm_mesh::WasmMesh;
+    ///
+    /// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0,
+    ///                            0.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0], &[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let actual = mesh.weld(0.001);
+    ///
+    /// assert_eq!(actual.number_of_vertices(), 4);
+    /// ```
+    pub fn weld(&self, tolerance: f64) -> WasmMesh {
+        WasmMesh { mesh: self.mesh.get_with_welded_vertices(tolerance) }
+    }
+
+    /// Splits the [WasmMesh] where the angle between faces' normals is higher than `max_angle`
+    /// (in radians), see [Mesh::split_by_face_angle].
+    ///
+    /// Resulting meshes are welded with `weld_tolerance`, unless it's negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::wasm_mesh::WasmMesh;
+    ///
+    /// // 2 faces with a 90 degree angle between them
+    /// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 0.0, 10.0], &[0, 1, 2, 0, 3, 1]);
+    ///
+    /// let actual = mesh.split_by_face_angle(0.1, 0.001);
+    ///
+    /// assert_eq!(actual.len(), 2);
+    /// ```
+    #[wasm_bindgen(js_name = splitByFaceAngle)]
+    pub fn split_by_face_angle(&self, max_angle: f64, weld_tolerance: f64) -> Vec<WasmMesh> {
+        let weld_vertices_tolerance = if weld_tolerance < 0.0 { None } else { Some(weld_tolerance) };
+        self.mesh.split_by_face_angle(max_angle, weld_vertices_tolerance).into_iter().map(|mesh| WasmMesh { mesh }).collect()
+    }
+
+    /// Returns a new simplified [WasmMesh], see [Mesh::get_planar_simplify].
+    ///
+    /// It throws an error if the simplification fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::wasm_mesh::WasmMesh;
+    ///
+    /// // Square made of 4 triangles
+    /// let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 10.0, 0.0, 5.0, 5.0, 0.0],
+    ///                          &[0, 1, 4, 1, 2, 4, 2, 3, 4, 3, 0, 4]);
+    ///
+    /// let actual = mesh.simplify(0.001, 0.01).ok().unwrap();
+    ///
+    /// assert_eq!(actual.number_of_faces(), 2);
+    /// ```
+    pub fn simplify(&self, tolerance: f64, angle_tolerance: f64) -> Result<WasmMesh, JsValue> {
+        match self.mesh.get_planar_simplify(tolerance, angle_tolerance) {
+            Ok(mesh) => Ok(WasmMesh { mesh }),
+            Err(message) => Err(JsValue::from_str(&message)),
+        }
+    }
+}
+
+impl From<Mesh> for WasmMesh {
+    fn from(mesh: Mesh) -> Self {
+        WasmMesh { mesh }
+    }
+}
+
+impl From<WasmMesh> for Mesh {
+    fn from(wasm_mesh: WasmMesh) -> Self {
+        wasm_mesh.mesh
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_mesh_and_back() {
+        let input = Mesh::new(vec![0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, -15.0, 0.0], vec![0, 1, 2]);
+
+        let actual = Mesh::from(WasmMesh::from(input.clone()));
+
+        assert_eq!(input, actual);
+    }
+
+    #[test]
+    fn test_split_by_face_angle_without_welding() {
+        let mesh = WasmMesh::new(&[0.0, 0.0, 0.0, 10.0, 0.0, 0.0, 10.0, 10.0, 0.0, 0.0, 0.0, 10.0], &[0, 1, 2, 0, 3, 1]);
+
+        let actual = mesh.split_by_face_angle(0.1, -1.0);
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual[0].number_of_faces(), 1);
+        assert_eq!(actual[1].number_of_faces(), 1);
+    }
+
+    #[test]
+    fn test_bounding_box_empty() {
+        let mesh = WasmMesh::new(&[], &[]);
+
+        assert_eq!(mesh.bounding_box(), None);
+    }
+}
@@ -0,0 +1,59 @@
+/// Represents the statistics of welding vertices of a [Mesh](crate::mesh::Mesh).
+///
+/// It's created by [Mesh::get_with_welded_vertices_with_report](crate::mesh::Mesh::get_with_welded_vertices_with_report).
+///
+/// Automated pipelines can use it to detect that the welding tolerance was set too high, e.g.
+/// when the merge distance gets close to the tolerance or many vertices collapse into one.
+///
+/// # Example
+///
+/// ```
+/// use meshmeshmesh::weld_report::WeldReport;
+///
+/// let report = WeldReport {
+///     number_of_merged_vertices: 4,
+///     max_merge_distance: 0.0,
+///     large_clusters: vec![],
+/// };
+///
+/// assert!(!report.has_large_clusters());
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeldReport {
+    /// The number of vertices removed, because they were merged into other vertices.
+    pub number_of_merged_vertices: usize,
+    /// The biggest distance between a merged vertex and the vertex it was merged into.
+    pub max_merge_distance: f64,
+    /// Ids of original vertices of clusters bigger than the given size. The first id in each
+    /// cluster is the kept vertex.
+    pub large_clusters: Vec<Vec<usize>>,
+}
+
+impl PartialEq for WeldReport {
+    fn eq(&self, other: &Self) -> bool {
+        self.number_of_merged_vertices == other.number_of_merged_vertices &&
+            self.max_merge_distance == other.max_merge_distance &&
+            self.large_clusters == other.large_clusters
+    }
+}
+
+impl WeldReport {
+    /// Checks if any cluster of merged vertices was bigger than the given size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use meshmeshmesh::weld_report::WeldReport;
+    ///
+    /// let report = WeldReport {
+    ///     number_of_merged_vertices: 7,
+    ///     max_merge_distance: 0.8,
+    ///     large_clusters: vec![vec![0, 3, 5, 6, 9, 10, 12, 13]],
+    /// };
+    ///
+    /// assert!(report.has_large_clusters());
+    /// ```
+    pub fn has_large_clusters(&self) -> bool {
+        !self.large_clusters.is_empty()
+    }
+}